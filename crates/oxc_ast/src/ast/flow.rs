@@ -0,0 +1,28 @@
+//! [Flow](https://flow.org/en/docs/types/) syntax without a TypeScript counterpart,
+//! the other Flow types are parsed into the TypeScript nodes.
+
+use oxc_allocator::Box;
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
+use serde::Serialize;
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, Span};
+
+/// [Opaque type alias](https://flow.org/en/docs/types/opaque-types/)
+/// `opaque type A: Super = Type;` or `declare opaque type A: Super;`
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct FlowOpaqueType<'a> {
+    #[serde(flatten)]
+    pub span: Span,
+    pub id: BindingIdentifier,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+    /// The type the opaque type can be used as outside of its file
+    pub supertype: Option<TSType<'a>>,
+    /// `None` for a declared opaque type
+    pub impl_type: Option<TSType<'a>>,
+    /// Valid Modifiers: `declare`, `export`
+    #[serde(skip_serializing_if = "Modifiers::is_none")]
+    pub modifiers: Modifiers<'a>,
+}
@@ -1,12 +1,13 @@
 use std::fmt::Display;
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
 use crate::{ast::*, Atom, SourceType, Span};
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct Program<'a> {
     pub span: Span,
     pub directives: Vec<'a, Directive<'a>>,
@@ -28,7 +29,7 @@ impl<'a> Program<'a> {
 }
 
 /// Section 13 Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum Expression<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
@@ -171,7 +172,9 @@ impl<'a> Expression<'a> {
 }
 
 /// Section 12.6 `IdentifierName`
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type")]
 pub struct IdentifierName {
     #[serde(flatten)]
@@ -180,7 +183,9 @@ pub struct IdentifierName {
 }
 
 /// Section 13.1 `IdentifierReference`
-#[derive(Debug, Clone, Serialize, PartialEq, Hash, Eq)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Hash, Eq, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type")]
 pub struct IdentifierReference {
     #[serde(flatten)]
@@ -189,7 +194,9 @@ pub struct IdentifierReference {
 }
 
 /// Section 13.1 `BindingIdentifier`
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type")]
 pub struct BindingIdentifier {
     #[serde(flatten)]
@@ -198,7 +205,9 @@ pub struct BindingIdentifier {
 }
 
 /// Section 13.1 `LabelIdentifier`
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type")]
 pub struct LabelIdentifier {
     #[serde(flatten)]
@@ -207,7 +216,7 @@ pub struct LabelIdentifier {
 }
 
 /// Section 13.2.2 This Expression
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ThisExpression {
     #[serde(flatten)]
@@ -215,7 +224,7 @@ pub struct ThisExpression {
 }
 
 /// Section 13.2.5 Array Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ArrayExpression<'a> {
     #[serde(flatten)]
@@ -225,7 +234,7 @@ pub struct ArrayExpression<'a> {
 }
 
 /// Section 13.2.6 Object Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ObjectExpression<'a> {
     #[serde(flatten)]
@@ -234,14 +243,14 @@ pub struct ObjectExpression<'a> {
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ObjectProperty<'a> {
     Property(Box<'a, Property<'a>>),
     SpreadProperty(Box<'a, SpreadElement<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct Property<'a> {
     #[serde(flatten)]
@@ -254,7 +263,7 @@ pub struct Property<'a> {
     pub computed: bool,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum PropertyKey<'a> {
     Identifier(Box<'a, IdentifierName>),
@@ -288,7 +297,7 @@ impl<'a> PropertyKey<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum PropertyValue<'a> {
     // for AssignmentProperty in ObjectPattern https://github.com/oxc_ast/oxc_ast/blob/master/es2015.md#objectpattern
@@ -296,7 +305,9 @@ pub enum PropertyValue<'a> {
     Expression(Expression<'a>),
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Eq, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum PropertyKind {
     Init,
@@ -305,7 +316,7 @@ pub enum PropertyKind {
 }
 
 /// Section 13.2.9 Template Literal
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TemplateLiteral<'a> {
     #[serde(flatten)]
@@ -327,7 +338,7 @@ impl<'a> TemplateLiteral<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TaggedTemplateExpression<'a> {
     #[serde(flatten)]
@@ -338,7 +349,7 @@ pub struct TaggedTemplateExpression<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TemplateElement {
     #[serde(flatten)]
@@ -347,14 +358,14 @@ pub struct TemplateElement {
     pub value: TemplateElementValue,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct TemplateElementValue {
     pub raw: Atom,
     pub cooked: Option<Atom>,
 }
 
 /// Section 13.3 Member Expression
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub enum MemberExpression<'a> {
     ComputedMemberExpression(ComputedMemberExpression<'a>),
     StaticMemberExpression(StaticMemberExpression<'a>),
@@ -400,7 +411,7 @@ impl<'a> MemberExpression<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct ComputedMemberExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -408,7 +419,7 @@ pub struct ComputedMemberExpression<'a> {
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct StaticMemberExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -416,7 +427,7 @@ pub struct StaticMemberExpression<'a> {
     pub optional: bool, // for optional chaining
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct PrivateFieldExpression<'a> {
     pub span: Span,
     pub object: Expression<'a>,
@@ -425,7 +436,7 @@ pub struct PrivateFieldExpression<'a> {
 }
 
 /// Section 13.3 Call Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct CallExpression<'a> {
     #[serde(flatten)]
@@ -472,9 +483,10 @@ impl<'a> CallExpression<'a> {
     #[must_use]
     pub fn common_js_require(&self) -> Option<&StringLiteral> {
         if let Expression::Identifier(ident) = &self.callee
-            && ident.name =="require"
+            && ident.name == "require"
             && self.arguments.len() == 1
-            && let Argument::Expression(Expression::StringLiteral(str_literal)) = &self.arguments[0] {
+            && let Argument::Expression(Expression::StringLiteral(str_literal)) = &self.arguments[0]
+        {
             Some(str_literal)
         } else {
             None
@@ -483,7 +495,7 @@ impl<'a> CallExpression<'a> {
 }
 
 /// Section 13.3 New Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct NewExpression<'a> {
     #[serde(flatten)]
@@ -496,7 +508,7 @@ pub struct NewExpression<'a> {
 
 /// Section 13.3 Meta Property
 /// `new.target` | `import.meta`
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct MetaProperty {
     #[serde(flatten)]
@@ -506,7 +518,7 @@ pub struct MetaProperty {
 }
 
 /// Section 13.3 Spread Element
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct SpreadElement<'a> {
     #[serde(flatten)]
@@ -515,7 +527,7 @@ pub struct SpreadElement<'a> {
 }
 
 /// Section 13.3 Argument
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum Argument<'a> {
     SpreadElement(Box<'a, SpreadElement<'a>>),
@@ -523,7 +535,7 @@ pub enum Argument<'a> {
 }
 
 /// Section 13.4 Update Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct UpdateExpression<'a> {
     #[serde(flatten)]
@@ -534,7 +546,7 @@ pub struct UpdateExpression<'a> {
 }
 
 /// Section 13.5 Unary Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct UnaryExpression<'a> {
     #[serde(flatten)]
@@ -545,7 +557,7 @@ pub struct UnaryExpression<'a> {
 }
 
 /// Section 13.6 - 13.13 Binary Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct BinaryExpression<'a> {
     #[serde(flatten)]
@@ -557,7 +569,7 @@ pub struct BinaryExpression<'a> {
 
 /// `RelationalExpression`[In, Yield, Await] :
 ///     [+In] `PrivateIdentifier` in `ShiftExpression`[?Yield, ?Await]
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct PrivateInExpression<'a> {
     #[serde(flatten)]
@@ -568,7 +580,7 @@ pub struct PrivateInExpression<'a> {
 }
 
 /// Section 13.13 Binary Logical Operators
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct LogicalExpression<'a> {
     #[serde(flatten)]
@@ -579,7 +591,7 @@ pub struct LogicalExpression<'a> {
 }
 
 /// Section 13.14 Conditional Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ConditionalExpression<'a> {
     #[serde(flatten)]
@@ -590,7 +602,7 @@ pub struct ConditionalExpression<'a> {
 }
 
 /// Section 13.15 Assignment Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AssignmentExpression<'a> {
     #[serde(flatten)]
@@ -601,7 +613,7 @@ pub struct AssignmentExpression<'a> {
 }
 
 /// 13.15.5 Destructuring Assignment
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum AssignmentTarget<'a> {
     SimpleAssignmentTarget(SimpleAssignmentTarget<'a>),
@@ -615,7 +627,7 @@ impl<'a> AssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum SimpleAssignmentTarget<'a> {
     AssignmentTargetIdentifier(Box<'a, IdentifierReference>),
@@ -637,14 +649,14 @@ impl<'a> SimpleAssignmentTarget<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum AssignmentTargetPattern<'a> {
     ArrayAssignmentTarget(Box<'a, ArrayAssignmentTarget<'a>>),
     ObjectAssignmentTarget(Box<'a, ObjectAssignmentTarget<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ArrayAssignmentTarget<'a> {
     #[serde(flatten)]
@@ -654,7 +666,7 @@ pub struct ArrayAssignmentTarget<'a> {
     pub trailing_comma: Option<Span>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ObjectAssignmentTarget<'a> {
     #[serde(flatten)]
@@ -663,7 +675,7 @@ pub struct ObjectAssignmentTarget<'a> {
     pub rest: Option<AssignmentTarget<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum AssignmentTargetMaybeDefault<'a> {
     AssignmentTarget(Box<'a, AssignmentTarget<'a>>),
@@ -689,7 +701,7 @@ impl<'a> AssignmentTargetMaybeDefault<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AssignmentTargetWithDefault<'a> {
     #[serde(flatten)]
@@ -698,7 +710,7 @@ pub struct AssignmentTargetWithDefault<'a> {
     pub init: Expression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum AssignmentTargetProperty<'a> {
     AssignmentTargetPropertyIdentifier(Box<'a, AssignmentTargetPropertyIdentifier<'a>>),
@@ -707,7 +719,7 @@ pub enum AssignmentTargetProperty<'a> {
 
 /// `AssignmentProperty`[Yield, Await] :
 ///     `IdentifierReference`[?Yield, ?Await] Initializer[+In, ?Yield, ?Await]opt
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AssignmentTargetPropertyIdentifier<'a> {
     #[serde(flatten)]
@@ -718,7 +730,7 @@ pub struct AssignmentTargetPropertyIdentifier<'a> {
 
 /// `AssignmentProperty`[Yield, Await] :
 ///     `PropertyName`[?Yield, ?Await] : `AssignmentElement`[?Yield, ?Await]
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AssignmentTargetPropertyProperty<'a> {
     #[serde(flatten)]
@@ -728,7 +740,7 @@ pub struct AssignmentTargetPropertyProperty<'a> {
 }
 
 /// Section 13.16 Sequence Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct SequenceExpression<'a> {
     #[serde(flatten)]
@@ -736,7 +748,7 @@ pub struct SequenceExpression<'a> {
     pub expressions: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct Super {
     #[serde(flatten)]
@@ -744,7 +756,7 @@ pub struct Super {
 }
 
 /// Section 15.8 Await Expression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AwaitExpression<'a> {
     #[serde(flatten)]
@@ -752,7 +764,7 @@ pub struct AwaitExpression<'a> {
     pub argument: Expression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ChainExpression<'a> {
     #[serde(flatten)]
@@ -760,7 +772,7 @@ pub struct ChainExpression<'a> {
     pub expression: ChainElement<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ChainElement<'a> {
     CallExpression(Box<'a, CallExpression<'a>>),
@@ -768,7 +780,7 @@ pub enum ChainElement<'a> {
 }
 
 // Section 13.2 ParenthesizedExpression
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ParenthesizedExpression<'a> {
     #[serde(flatten)]
@@ -777,7 +789,7 @@ pub struct ParenthesizedExpression<'a> {
 }
 
 /// Section 14 Statements
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum Statement<'a> {
     // Statements
@@ -799,13 +811,14 @@ pub enum Statement<'a> {
     TryStatement(Box<'a, TryStatement<'a>>),
     WhileStatement(Box<'a, WhileStatement<'a>>),
     WithStatement(Box<'a, WithStatement<'a>>),
+    InvalidStatement(Box<'a, InvalidStatement>),
 
     ModuleDeclaration(Box<'a, ModuleDeclaration<'a>>),
     Declaration(Declaration<'a>),
 }
 
 /// Section 11.2.1 Directive Prologue
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename = "ExpressionStatement")]
 pub struct Directive<'a> {
     #[serde(flatten)]
@@ -815,8 +828,16 @@ pub struct Directive<'a> {
     pub directive: &'a str,
 }
 
+impl<'a> Directive<'a> {
+    /// [Use Strict Directive](https://tc39.es/ecma262/#use-strict-directive)
+    #[must_use]
+    pub fn is_use_strict(&self) -> bool {
+        self.directive == "use strict"
+    }
+}
+
 /// Section 14.2 Block Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct BlockStatement<'a> {
     #[serde(flatten)]
@@ -825,7 +846,7 @@ pub struct BlockStatement<'a> {
 }
 
 /// Section 14.3 Declarations and the Variable Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum Declaration<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -837,6 +858,8 @@ pub enum Declaration<'a> {
     TSEnumDeclaration(Box<'a, TSEnumDeclaration<'a>>),
     TSModuleDeclaration(Box<'a, TSModuleDeclaration<'a>>),
     TSImportEqualsDeclaration(Box<'a, TSImportEqualsDeclaration<'a>>),
+
+    FlowOpaqueType(Box<'a, FlowOpaqueType<'a>>),
 }
 
 impl<'a> Declaration<'a> {
@@ -852,7 +875,7 @@ impl<'a> Declaration<'a> {
 }
 
 /// Section 14.3.2 Variable Declaration
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct VariableDeclaration<'a> {
     #[serde(flatten)]
@@ -864,23 +887,34 @@ pub struct VariableDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum VariableDeclarationKind {
     Var,
     Const,
     Let,
+    Using,
+    #[serde(rename = "await using")]
+    AwaitUsing,
 }
 
 impl VariableDeclarationKind {
     #[must_use]
     pub fn is_const(&self) -> bool {
-        matches!(self, Self::Const)
+        matches!(self, Self::Const | Self::Using | Self::AwaitUsing)
     }
 
     #[must_use]
     pub fn is_lexical(&self) -> bool {
-        matches!(self, Self::Const | Self::Let)
+        !matches!(self, Self::Var)
+    }
+
+    /// `using` and `await using` declarations from the explicit resource management proposal
+    #[must_use]
+    pub fn is_using(&self) -> bool {
+        matches!(self, Self::Using | Self::AwaitUsing)
     }
 }
 
@@ -890,12 +924,14 @@ impl Display for VariableDeclarationKind {
             Self::Var => "var",
             Self::Const => "const",
             Self::Let => "let",
+            Self::Using => "using",
+            Self::AwaitUsing => "await using",
         };
         write!(f, "{s}")
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct VariableDeclarator<'a> {
     #[serde(flatten)]
@@ -908,15 +944,23 @@ pub struct VariableDeclarator<'a> {
 }
 
 /// Section 14.4 Empty Statement
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct EmptyStatement {
     #[serde(flatten)]
     pub span: Span,
 }
 
+/// A statement which failed to parse, spanning the source skipped by the error recovery
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
+#[serde(tag = "type")]
+pub struct InvalidStatement {
+    #[serde(flatten)]
+    pub span: Span,
+}
+
 /// Section 14.5 Expression Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ExpressionStatement<'a> {
     #[serde(flatten)]
@@ -925,7 +969,7 @@ pub struct ExpressionStatement<'a> {
 }
 
 /// Section 14.6 If Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct IfStatement<'a> {
     #[serde(flatten)]
@@ -936,7 +980,7 @@ pub struct IfStatement<'a> {
 }
 
 /// Section 14.7.2 Do-While Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct DoWhileStatement<'a> {
     #[serde(flatten)]
@@ -946,7 +990,7 @@ pub struct DoWhileStatement<'a> {
 }
 
 /// Section 14.7.3 While Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct WhileStatement<'a> {
     #[serde(flatten)]
@@ -956,7 +1000,7 @@ pub struct WhileStatement<'a> {
 }
 
 /// Section 14.7.4 For Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ForStatement<'a> {
     #[serde(flatten)]
@@ -967,7 +1011,7 @@ pub struct ForStatement<'a> {
     pub body: Statement<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ForStatementInit<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -975,7 +1019,7 @@ pub enum ForStatementInit<'a> {
 }
 
 /// Section 14.7.5 For-In Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ForInStatement<'a> {
     #[serde(flatten)]
@@ -986,7 +1030,7 @@ pub struct ForInStatement<'a> {
 }
 
 /// Section 14.7.5 For-Of Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ForOfStatement<'a> {
     #[serde(flatten)]
@@ -997,7 +1041,7 @@ pub struct ForOfStatement<'a> {
     pub body: Statement<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ForStatementLeft<'a> {
     VariableDeclaration(Box<'a, VariableDeclaration<'a>>),
@@ -1005,7 +1049,7 @@ pub enum ForStatementLeft<'a> {
 }
 
 /// Section 14.8 Continue Statement
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ContinueStatement {
     #[serde(flatten)]
@@ -1014,7 +1058,7 @@ pub struct ContinueStatement {
 }
 
 /// Section 14.9 Break Statement
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct BreakStatement {
     #[serde(flatten)]
@@ -1023,7 +1067,7 @@ pub struct BreakStatement {
 }
 
 /// Section 14.10 Return Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ReturnStatement<'a> {
     #[serde(flatten)]
@@ -1032,7 +1076,7 @@ pub struct ReturnStatement<'a> {
 }
 
 /// Section 14.11 With Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct WithStatement<'a> {
     #[serde(flatten)]
@@ -1042,7 +1086,7 @@ pub struct WithStatement<'a> {
 }
 
 /// Section 14.12 Switch Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct SwitchStatement<'a> {
     #[serde(flatten)]
@@ -1051,7 +1095,7 @@ pub struct SwitchStatement<'a> {
     pub cases: Vec<'a, SwitchCase<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct SwitchCase<'a> {
     #[serde(flatten)]
@@ -1061,7 +1105,7 @@ pub struct SwitchCase<'a> {
 }
 
 /// Section 14.13 Labelled Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct LabeledStatement<'a> {
     #[serde(flatten)]
@@ -1071,7 +1115,7 @@ pub struct LabeledStatement<'a> {
 }
 
 /// Section 14.14 Throw Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ThrowStatement<'a> {
     #[serde(flatten)]
@@ -1080,7 +1124,7 @@ pub struct ThrowStatement<'a> {
 }
 
 /// Section 14.15 Try Statement
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TryStatement<'a> {
     #[serde(flatten)]
@@ -1090,7 +1134,7 @@ pub struct TryStatement<'a> {
     pub finalizer: Option<Box<'a, BlockStatement<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct CatchClause<'a> {
     #[serde(flatten)]
@@ -1100,7 +1144,7 @@ pub struct CatchClause<'a> {
 }
 
 /// Section 14.16 Debugger Statement
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct DebuggerStatement {
     #[serde(flatten)]
@@ -1108,7 +1152,7 @@ pub struct DebuggerStatement {
 }
 
 /// Section 14.3.3 Destructuring Binding Patterns
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(rename_all = "camelCase")]
 pub struct BindingPattern<'a> {
     #[serde(flatten)]
@@ -1118,7 +1162,7 @@ pub struct BindingPattern<'a> {
     pub optional: bool,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum BindingPatternKind<'a> {
     BindingIdentifier(Box<'a, BindingIdentifier>),
@@ -1140,7 +1184,7 @@ impl<'a> BindingPatternKind<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AssignmentPattern<'a> {
     #[serde(flatten)]
@@ -1149,7 +1193,7 @@ pub struct AssignmentPattern<'a> {
     pub right: Expression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ObjectPattern<'a> {
     #[serde(flatten)]
@@ -1157,14 +1201,14 @@ pub struct ObjectPattern<'a> {
     pub properties: Vec<'a, ObjectPatternProperty<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ObjectPatternProperty<'a> {
     Property(Box<'a, Property<'a>>),
     RestElement(Box<'a, RestElement<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ArrayPattern<'a> {
     #[serde(flatten)]
@@ -1172,7 +1216,7 @@ pub struct ArrayPattern<'a> {
     pub elements: Vec<'a, Option<BindingPattern<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct RestElement<'a> {
     #[serde(flatten)]
@@ -1181,7 +1225,7 @@ pub struct RestElement<'a> {
 }
 
 /// Section 15.2 Function Definitions
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(rename_all = "camelCase")]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Function<'a> {
@@ -1231,21 +1275,23 @@ impl<'a> Function<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum FunctionType {
     FunctionDeclaration,
     FunctionExpression,
     TSDeclareFunction,
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct FormalParameters<'a> {
     pub span: Span,
     pub kind: FormalParameterKind,
     pub items: Vec<'a, FormalParameter<'a>>,
 }
 
-#[derive(Debug, PartialEq, Hash, Serialize)]
+#[derive(Debug, PartialEq, Hash, Serialize, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct FormalParameter<'a> {
     #[serde(flatten)]
     pub span: Span,
@@ -1258,7 +1304,7 @@ pub struct FormalParameter<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub enum FormalParameterKind {
     /// https://tc39.es/ecma262/#prod-FormalParameters
     FormalParameter,
@@ -1277,7 +1323,7 @@ impl<'a> FormalParameters<'a> {
     }
 }
 
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct FunctionBody<'a> {
     pub span: Span,
     pub directives: Vec<'a, Directive<'a>>,
@@ -1292,7 +1338,7 @@ impl<'a> FunctionBody<'a> {
 }
 
 /// Section 15.3 Arrow Function Definitions
-#[derive(Debug, PartialEq, Hash)]
+#[derive(Debug, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct ArrowExpression<'a> {
     pub span: Span,
     pub expression: bool,
@@ -1306,7 +1352,7 @@ pub struct ArrowExpression<'a> {
 }
 
 /// Section 15.5 Generator Function Definitions
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct YieldExpression<'a> {
     #[serde(flatten)]
@@ -1316,7 +1362,7 @@ pub struct YieldExpression<'a> {
 }
 
 /// Section 15.7 Class Definitions
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(rename_all = "camelCase")]
 pub struct Class<'a> {
     pub r#type: ClassType,
@@ -1355,13 +1401,15 @@ impl<'a> Class<'a> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum ClassType {
     ClassDeclaration,
     ClassExpression,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ClassBody<'a> {
     #[serde(flatten)]
@@ -1369,7 +1417,7 @@ pub struct ClassBody<'a> {
     pub body: Vec<'a, ClassElement<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ClassElement<'a> {
     StaticBlock(Box<'a, StaticBlock<'a>>),
@@ -1379,13 +1427,16 @@ pub enum ClassElement<'a> {
     TSAbstractMethodDefinition(Box<'a, TSAbstractMethodDefinition<'a>>),
     TSAbstractPropertyDefinition(Box<'a, TSAbstractPropertyDefinition<'a>>),
     TSIndexSignature(Box<'a, TSIndexSignature<'a>>),
+    InvalidClassElement(Box<'a, InvalidClassElement>),
 }
 
 impl<'a> ClassElement<'a> {
     #[must_use]
     pub fn r#static(&self) -> bool {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => false,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::InvalidClassElement(_) => {
+                false
+            }
             Self::MethodDefinition(def) => def.r#static,
             Self::PropertyDefinition(def) => def.r#static,
             Self::AccessorProperty(def) => def.r#static,
@@ -1397,7 +1448,9 @@ impl<'a> ClassElement<'a> {
     #[must_use]
     pub fn computed(&self) -> bool {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => false,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::InvalidClassElement(_) => {
+                false
+            }
             Self::MethodDefinition(def) => def.computed,
             Self::PropertyDefinition(def) => def.computed,
             Self::AccessorProperty(def) => def.computed,
@@ -1411,6 +1464,7 @@ impl<'a> ClassElement<'a> {
         match self {
             Self::TSIndexSignature(_)
             | Self::StaticBlock(_)
+            | Self::InvalidClassElement(_)
             | Self::PropertyDefinition(_)
             | Self::AccessorProperty(_) => None,
             Self::MethodDefinition(def) => Some(def.kind),
@@ -1422,7 +1476,7 @@ impl<'a> ClassElement<'a> {
     #[must_use]
     pub fn property_key(&self) -> Option<&PropertyKey<'a>> {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => None,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::InvalidClassElement(_) => None,
             Self::MethodDefinition(def) => Some(&def.key),
             Self::PropertyDefinition(def) => Some(&def.key),
             Self::AccessorProperty(def) => Some(&def.key),
@@ -1434,7 +1488,7 @@ impl<'a> ClassElement<'a> {
     #[must_use]
     pub fn static_name(&self) -> Option<Atom> {
         match self {
-            Self::TSIndexSignature(_) | Self::StaticBlock(_) => None,
+            Self::TSIndexSignature(_) | Self::StaticBlock(_) | Self::InvalidClassElement(_) => None,
             Self::MethodDefinition(def) => def.key.static_name(),
             Self::PropertyDefinition(def) => def.key.static_name(),
             Self::AccessorProperty(def) => def.key.static_name(),
@@ -1444,7 +1498,7 @@ impl<'a> ClassElement<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 #[allow(clippy::struct_excessive_bools)]
 pub struct MethodDefinition<'a> {
@@ -1463,7 +1517,7 @@ pub struct MethodDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PropertyDefinition<'a> {
@@ -1486,7 +1540,9 @@ pub struct PropertyDefinition<'a> {
     pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Eq, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum MethodDefinitionKind {
     Constructor,
@@ -1495,7 +1551,9 @@ pub enum MethodDefinitionKind {
     Set,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type")]
 pub struct PrivateIdentifier {
     #[serde(flatten)]
@@ -1503,7 +1561,7 @@ pub struct PrivateIdentifier {
     pub name: Atom,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct StaticBlock<'a> {
     #[serde(flatten)]
@@ -1511,8 +1569,16 @@ pub struct StaticBlock<'a> {
     pub body: Vec<'a, Statement<'a>>,
 }
 
+/// A class member which failed to parse, spanning the source skipped by the error recovery
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
+#[serde(tag = "type")]
+pub struct InvalidClassElement {
+    #[serde(flatten)]
+    pub span: Span,
+}
+
 /// Section 16.2.2 Imports
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct ModuleDeclaration<'a> {
     #[serde(flatten)]
     pub span: Span,
@@ -1520,7 +1586,7 @@ pub struct ModuleDeclaration<'a> {
     pub kind: ModuleDeclarationKind<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ModuleDeclarationKind<'a> {
     ImportDeclaration(Box<'a, ImportDeclaration<'a>>),
@@ -1546,7 +1612,7 @@ impl<'a> ModuleDeclarationKind<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct AccessorProperty<'a> {
     #[serde(flatten)]
@@ -1555,9 +1621,11 @@ pub struct AccessorProperty<'a> {
     pub value: Option<Expression<'a>>,
     pub computed: bool,
     pub r#static: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<'a, Decorator<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ImportExpression<'a> {
     #[serde(flatten)]
@@ -1566,16 +1634,16 @@ pub struct ImportExpression<'a> {
     pub arguments: Vec<'a, Expression<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct ImportDeclaration<'a> {
     pub specifiers: Vec<'a, ImportDeclarationSpecifier>,
     pub source: StringLiteral,
-    pub assertions: Option<Vec<'a, ImportAttribute>>, // Some(vec![]) for empty assertion
-    pub import_kind: Option<ImportOrExportKind>,      // `import type { foo } from 'bar'`
+    pub with_clause: Option<WithClause<'a>>, // `with { type: 'json' }`
+    pub import_kind: Option<ImportOrExportKind>, // `import type { foo } from 'bar'`
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ImportDeclarationSpecifier {
     ImportSpecifier(ImportSpecifier),
@@ -1585,17 +1653,19 @@ pub enum ImportDeclarationSpecifier {
 
 // import {imported} from "source"
 // import {imported as local} from "source"
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ImportSpecifier {
     #[serde(flatten)]
     pub span: Span,
     pub imported: ModuleExportName,
     pub local: BindingIdentifier,
+    #[serde(skip_serializing_if = "ImportOrExportKind::is_value")]
+    pub import_kind: ImportOrExportKind, // `import { type foo } from 'bar'`
 }
 
 // import local from "source"
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ImportDefaultSpecifier {
     #[serde(flatten)]
@@ -1604,7 +1674,7 @@ pub struct ImportDefaultSpecifier {
 }
 
 // import * as local from "source"
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ImportNamespaceSpecifier {
     #[serde(flatten)]
@@ -1612,7 +1682,39 @@ pub struct ImportNamespaceSpecifier {
     pub local: BindingIdentifier,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+/// Import Attributes
+/// [proposal-import-attributes](https://tc39.es/proposal-import-attributes)
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
+pub struct WithClause<'a> {
+    #[serde(flatten)]
+    pub span: Span,
+    pub keyword: ImportAttributesKeyword,
+    pub attributes: Vec<'a, ImportAttribute>,
+}
+
+#[derive(
+    Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportAttributesKeyword {
+    /// `import foo from 'bar' with { type: 'json' }`
+    With,
+    /// `import foo from 'bar' assert { type: 'json' }`, from the earlier import assertions
+    /// proposal
+    Assert,
+}
+
+impl ImportAttributesKeyword {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::With => "with",
+            Self::Assert => "assert",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ImportAttribute {
     #[serde(flatten)]
@@ -1621,7 +1723,7 @@ pub struct ImportAttribute {
     pub value: StringLiteral,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ImportAttributeKey {
     Identifier(IdentifierName),
@@ -1640,12 +1742,13 @@ impl ImportAttributeKey {
 
 /// Exports
 /// [tc39/ecma262#sec-exports](https://tc39.es/ecma262/#sec-exports)
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ExportNamedDeclaration<'a> {
     pub declaration: Option<Declaration<'a>>,
     pub specifiers: Vec<'a, ExportSpecifier>,
     pub source: Option<StringLiteral>,
+    pub with_clause: Option<WithClause<'a>>, // `export { foo } from 'bar' with {}`
     pub export_kind: Option<ImportOrExportKind>, // `export type { foo }`
 }
 
@@ -1657,32 +1760,34 @@ impl<'a> ExportNamedDeclaration<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ExportDefaultDeclaration<'a> {
     pub declaration: ExportDefaultDeclarationKind<'a>,
     pub exported: ModuleExportName, // `default`
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ExportAllDeclaration<'a> {
     pub exported: Option<ModuleExportName>,
     pub source: StringLiteral,
-    pub assertions: Option<Vec<'a, ImportAttribute>>, // Some(vec![]) for empty assertion
-    pub export_kind: Option<ImportOrExportKind>,      // `export type *`
+    pub with_clause: Option<WithClause<'a>>, // `with { type: 'json' }`
+    pub export_kind: Option<ImportOrExportKind>, // `export type *`
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct ExportSpecifier {
     #[serde(flatten)]
     pub span: Span,
     pub local: ModuleExportName,
     pub exported: ModuleExportName,
+    #[serde(skip_serializing_if = "ImportOrExportKind::is_value")]
+    pub export_kind: ImportOrExportKind, // `export { type foo }`
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum ExportDefaultDeclarationKind<'a> {
     Expression(Expression<'a>),
@@ -1715,7 +1820,9 @@ impl<'a> ExportDefaultDeclarationKind<'a> {
 // support:
 //   import {"\0 any unicode" as foo} from "";
 //   export {foo as "\0 any unicode"};
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(untagged)]
 pub enum ModuleExportName {
     Identifier(IdentifierName),
@@ -1,10 +1,11 @@
 //! [`JSDoc`](https://github.com/microsoft/TypeScript/blob/54a554d8af2657630307cbfa8a3e4f3946e36507/src/compiler/types.ts#L393)
 
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
 use serde::Serialize;
 
 use crate::{ast::TSType, Span};
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct JSDocNullableType<'a> {
     #[serde(flatten)]
@@ -13,7 +14,7 @@ pub struct JSDocNullableType<'a> {
     pub postfix: bool,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct JSDocUnknownType {
     #[serde(flatten)]
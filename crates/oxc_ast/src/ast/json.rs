@@ -0,0 +1,105 @@
+//! [JSON](https://www.json.org/), JSONC and [JSON5](https://spec.json5.org/) values,
+//! a JSON document is a single value.
+
+use oxc_allocator::{Box, Vec};
+use serde::Serialize;
+
+use crate::{
+    ast::{BooleanLiteral, NullLiteral, StringLiteral},
+    Span,
+};
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum JsonValue<'a> {
+    Null(NullLiteral),
+    Boolean(BooleanLiteral),
+    Number(JsonNumber<'a>),
+    String(StringLiteral),
+    Array(Box<'a, JsonArray<'a>>),
+    Object(Box<'a, JsonObject<'a>>),
+}
+
+impl<'a> JsonValue<'a> {
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Null(lit) => lit.span,
+            Self::Boolean(lit) => lit.span,
+            Self::Number(lit) => lit.span,
+            Self::String(lit) => lit.span,
+            Self::Array(array) => array.span,
+            Self::Object(object) => object.span,
+        }
+    }
+
+    #[must_use]
+    pub fn as_object(&self) -> Option<&JsonObject<'a>> {
+        match self {
+            Self::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_array(&self) -> Option<&JsonArray<'a>> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(lit) => Some(lit.value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// A number with its sign, JSON5 also has `Infinity`, `NaN` and hexadecimal numbers
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "type", rename = "Literal")]
+pub struct JsonNumber<'a> {
+    #[serde(flatten)]
+    pub span: Span,
+    pub value: f64,
+    #[serde(skip)]
+    pub raw: &'a str,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "type", rename = "ArrayExpression")]
+pub struct JsonArray<'a> {
+    #[serde(flatten)]
+    pub span: Span,
+    pub elements: Vec<'a, JsonValue<'a>>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "type", rename = "ObjectExpression")]
+pub struct JsonObject<'a> {
+    #[serde(flatten)]
+    pub span: Span,
+    /// In source order, duplicate keys are kept
+    pub properties: Vec<'a, JsonProperty<'a>>,
+}
+
+impl<'a> JsonObject<'a> {
+    /// The value of `key`, the last one for a duplicate key as in `JSON.parse`
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&JsonValue<'a>> {
+        self.properties.iter().rev().find(|prop| prop.key.value == key).map(|prop| &prop.value)
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(tag = "type", rename = "Property")]
+pub struct JsonProperty<'a> {
+    #[serde(flatten)]
+    pub span: Span,
+    /// The identifier keys of JSON5 are read as strings
+    pub key: StringLiteral,
+    pub value: JsonValue<'a>,
+}
@@ -1,6 +1,7 @@
 //! [JSX](https://facebook.github.io/jsx)
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
@@ -11,7 +12,7 @@ use crate::{ast::*, Atom, Span};
 /// `JSXElement` :
 ///   `JSXSelfClosingElement`
 ///   `JSXOpeningElement` `JSXChildren_opt` `JSXClosingElement`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct JSXElement<'a> {
     #[serde(flatten)]
@@ -23,7 +24,7 @@ pub struct JSXElement<'a> {
 
 /// `JSXOpeningElement` :
 ///   < `JSXElementName` `JSXAttributes_opt` >
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct JSXOpeningElement<'a> {
     #[serde(flatten)]
@@ -37,7 +38,7 @@ pub struct JSXOpeningElement<'a> {
 
 /// `JSXClosingElement` :
 ///     < / `JSXElementName` >
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXClosingElement<'a> {
     #[serde(flatten)]
@@ -47,7 +48,7 @@ pub struct JSXClosingElement<'a> {
 
 /// `JSXFragment` :
 ///   < > `JSXChildren_opt` < / >
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct JSXFragment<'a> {
     #[serde(flatten)]
@@ -57,14 +58,14 @@ pub struct JSXFragment<'a> {
     pub children: Vec<'a, JSXChild<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXOpeningFragment {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXClosingFragment {
     #[serde(flatten)]
@@ -75,7 +76,7 @@ pub struct JSXClosingFragment {
 ///   `JSXIdentifier`
 ///   `JSXNamespacedName`
 ///   `JSXMemberExpression`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXElementName<'a> {
     Identifier(JSXIdentifier),
@@ -85,7 +86,7 @@ pub enum JSXElementName<'a> {
 
 /// `JSXNamespacedName` :
 ///   `JSXIdentifier` : `JSXIdentifier`
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXNamespacedName {
     #[serde(flatten)]
@@ -97,7 +98,7 @@ pub struct JSXNamespacedName {
 /// `JSXMemberExpression` :
 /// `JSXIdentifier` . `JSXIdentifier`
 /// `JSXMemberExpression` . `JSXIdentifier`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXMemberExpression<'a> {
     #[serde(flatten)]
@@ -116,14 +117,14 @@ impl<'a> JSXMemberExpression<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXMemberExpressionObject<'a> {
     Identifier(JSXIdentifier),
     MemberExpression(Box<'a, JSXMemberExpression<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXExpressionContainer<'a> {
     #[serde(flatten)]
@@ -131,14 +132,14 @@ pub struct JSXExpressionContainer<'a> {
     pub expression: JSXExpression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXExpression<'a> {
     Expression(Expression<'a>),
     EmptyExpression(JSXEmptyExpression),
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXEmptyExpression {
     #[serde(flatten)]
@@ -150,7 +151,7 @@ pub struct JSXEmptyExpression {
 /// `JSXAttributes` :
 ///   `JSXSpreadAttribute` `JSXAttributes_opt`
 ///   `JSXAttribute` `JSXAttributes_opt`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXAttributeItem<'a> {
     Attribute(Box<'a, JSXAttribute<'a>>),
@@ -159,7 +160,7 @@ pub enum JSXAttributeItem<'a> {
 
 /// `JSXAttribute` :
 ///   `JSXAttributeName` `JSXAttributeInitializer_opt`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXAttribute<'a> {
     #[serde(flatten)]
@@ -170,7 +171,7 @@ pub struct JSXAttribute<'a> {
 
 /// `JSXSpreadAttribute` :
 ///   { ... `AssignmentExpression` }
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXSpreadAttribute<'a> {
     #[serde(flatten)]
@@ -181,7 +182,7 @@ pub struct JSXSpreadAttribute<'a> {
 /// `JSXAttributeName` :
 ///   `JSXIdentifier`
 ///   `JSXNamespacedName`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXAttributeName<'a> {
     Identifier(JSXIdentifier),
@@ -194,7 +195,7 @@ pub enum JSXAttributeName<'a> {
 ///   { `AssignmentExpression` }
 ///   `JSXElement`
 ///   `JSXFragment`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXAttributeValue<'a> {
     StringLiteral(StringLiteral),
@@ -203,7 +204,7 @@ pub enum JSXAttributeValue<'a> {
     Fragment(Box<'a, JSXFragment<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXIdentifier {
     #[serde(flatten)]
@@ -218,7 +219,7 @@ pub struct JSXIdentifier {
 ///   `JSXElement`
 ///   `JSXFragment`
 ///   { `JSXChildExpression_opt` }
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum JSXChild<'a> {
     Text(JSXText),
@@ -228,7 +229,7 @@ pub enum JSXChild<'a> {
     Spread(JSXSpreadChild<'a>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXSpreadChild<'a> {
     #[serde(flatten)]
@@ -240,7 +241,7 @@ pub struct JSXSpreadChild<'a> {
 ///   `JSXTextCharacter` `JSXTextopt`
 /// `JSXTextCharacter` ::
 ///   `JSXStringCharacter` but not one of { or < or > or }
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct JSXText {
     #[serde(flatten)]
@@ -8,6 +8,7 @@ use std::{
 use bitflags::bitflags;
 use num_bigint::BigUint;
 use ordered_float::NotNan;
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
 use serde::{
     ser::{SerializeStruct, Serializer},
     Serialize,
@@ -15,7 +16,9 @@ use serde::{
 
 use crate::{Atom, Span};
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type", rename = "Literal")]
 pub struct BooleanLiteral {
     #[serde(flatten)]
@@ -30,7 +33,7 @@ impl BooleanLiteral {
     }
 }
 
-#[derive(Debug, Clone, Eq)]
+#[derive(Debug, Clone, Eq, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub struct NullLiteral {
     pub span: Span,
 }
@@ -61,7 +64,7 @@ impl Serialize for NullLiteral {
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename = "Literal")]
 pub struct NumberLiteral<'a> {
     #[serde(flatten)]
@@ -88,7 +91,9 @@ impl<'a> Hash for NumberLiteral<'a> {
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type", rename = "Literal")]
 pub struct BigintLiteral {
     #[serde(flatten)]
@@ -97,7 +102,9 @@ pub struct BigintLiteral {
     pub value: BigUint,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type", rename = "Literal")]
 pub struct RegExpLiteral {
     #[serde(flatten)]
@@ -108,7 +115,9 @@ pub struct RegExpLiteral {
     pub regex: RegExp,
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub struct RegExp {
     pub pattern: Atom,
     pub flags: RegExpFlags,
@@ -173,10 +182,14 @@ impl Serialize for RegExpFlags {
     }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub struct EmptyObject {}
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type", rename = "Literal")]
 pub struct StringLiteral {
     #[serde(flatten)]
@@ -204,7 +217,7 @@ impl StringLiteral {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 pub enum NumberBase {
     Decimal,
     Binary,
@@ -1,12 +1,16 @@
+mod flow;
 mod js;
 mod jsdoc;
+mod json;
 mod jsx;
 mod literal;
 mod operator;
 mod ts;
 
+pub use self::flow::*;
 pub use self::js::*;
 pub use self::jsdoc::*;
+pub use self::json::*;
 pub use self::jsx::*;
 pub use self::literal::*;
 pub use self::operator::*;
@@ -1,8 +1,11 @@
 use std::fmt::{Display, Formatter, Result};
 
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
 use serde::Serialize;
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum Operator {
     AssignmentOperator(AssignmentOperator),
     BinaryOperator(BinaryOperator),
@@ -41,7 +44,9 @@ impl From<UpdateOperator> for Operator {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum AssignmentOperator {
     #[serde(rename = "=")]
     Assign,
@@ -131,7 +136,9 @@ impl Display for AssignmentOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum BinaryOperator {
     #[serde(rename = "==")]
     Equality,
@@ -273,7 +280,9 @@ impl Display for BinaryOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum LogicalOperator {
     #[serde(rename = "||")]
     Or,
@@ -301,7 +310,9 @@ impl Display for LogicalOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum UnaryOperator {
     #[serde(rename = "-")]
     UnaryNegation,
@@ -361,7 +372,9 @@ impl Display for UnaryOperator {
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 pub enum UpdateOperator {
     #[serde(rename = "++")]
     Increment,
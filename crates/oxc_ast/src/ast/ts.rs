@@ -1,6 +1,7 @@
 //! [AST Spec](https://github.com/typescript-eslint/typescript-eslint/tree/main/packages/ast-spec)
 
 use oxc_allocator::{Box, Vec};
+use oxc_macros::{CloneIn, ContentEq, ContentHash, ShiftSpans};
 use serde::Serialize;
 
 #[allow(clippy::wildcard_imports)]
@@ -14,7 +15,7 @@ pub fn is_false(val: &bool) -> bool {
 
 /// `EnumDeclaration`:
 /// `const_opt` enum `BindingIdentifier` { `EnumBody_opt` }
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSEnumDeclaration<'a> {
     #[serde(flatten)]
@@ -26,7 +27,7 @@ pub struct TSEnumDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSEnumMember<'a> {
     #[serde(flatten)]
@@ -36,7 +37,7 @@ pub struct TSEnumMember<'a> {
     pub initializer: Option<Expression<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum TSEnumMemberName<'a> {
     Identifier(IdentifierName),
@@ -47,7 +48,7 @@ pub enum TSEnumMemberName<'a> {
     NumberLiteral(NumberLiteral<'a>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeAnnotation<'a> {
     #[serde(flatten)]
@@ -55,7 +56,7 @@ pub struct TSTypeAnnotation<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSLiteralType<'a> {
     #[serde(flatten)]
@@ -63,7 +64,7 @@ pub struct TSLiteralType<'a> {
     pub literal: TSLiteral<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSLiteral<'a> {
     BooleanLiteral(Box<'a, BooleanLiteral>),
@@ -76,7 +77,7 @@ pub enum TSLiteral<'a> {
     UnaryExpression(Box<'a, UnaryExpression<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSType<'a> {
     // Keyword
@@ -127,7 +128,7 @@ impl<'a> TSType<'a> {
 
 /// <https://www.typescriptlang.org/docs/handbook/2/conditional-types.html#handbook-content>
 /// `SomeType` extends `OtherType` ? `TrueType` : `FalseType`;
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSConditionalType<'a> {
     #[serde(flatten)]
@@ -140,7 +141,7 @@ pub struct TSConditionalType<'a> {
 
 /// <https://www.typescriptlang.org/docs/handbook/typescript-in-5-minutes-func.html#unions>
 /// string | string[] | (() => string) | { s: string }
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSUnionType<'a> {
     #[serde(flatten)]
@@ -150,7 +151,7 @@ pub struct TSUnionType<'a> {
 
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#intersection-types>
 /// type `ColorfulCircle` = Colorful & Circle;
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSIntersectionType<'a> {
     #[serde(flatten)]
@@ -160,7 +161,7 @@ pub struct TSIntersectionType<'a> {
 
 /// <https://www.typescriptlang.org/docs/handbook/2/keyof-types.html>
 /// keyof unique readonly
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename = "TSTypeOperator")]
 pub struct TSTypeOperatorType<'a> {
     #[serde(flatten)]
@@ -169,7 +170,7 @@ pub struct TSTypeOperatorType<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum TSTypeOperator {
     #[serde(rename = "keyof")]
@@ -194,7 +195,7 @@ impl TSTypeOperator {
 
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#the-array-type>
 /// let myArray: string[] = ["hello", "world"];
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSArrayType<'a> {
     #[serde(flatten)]
@@ -204,7 +205,7 @@ pub struct TSArrayType<'a> {
 
 /// <https://www.typescriptlang.org/docs/handbook/2/indexed-access-types.html#handbook-content>
 /// type I1 = Person["age" | "name"];
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSIndexedAccessType<'a> {
     #[serde(flatten)]
@@ -215,7 +216,7 @@ pub struct TSIndexedAccessType<'a> {
 
 /// <https://www.typescriptlang.org/docs/handbook/2/objects.html#tuple-types>
 /// type `StringNumberPair` = [string, number];
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTupleType<'a> {
     #[serde(flatten)]
@@ -223,7 +224,7 @@ pub struct TSTupleType<'a> {
     pub element_types: Vec<'a, TSTupleElement<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSNamedTupleMember<'a> {
     #[serde(flatten)]
@@ -233,7 +234,7 @@ pub struct TSNamedTupleMember<'a> {
     pub optional: bool,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSOptionalType<'a> {
     #[serde(flatten)]
@@ -241,7 +242,7 @@ pub struct TSOptionalType<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSRestType<'a> {
     #[serde(flatten)]
@@ -249,7 +250,7 @@ pub struct TSRestType<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSTupleElement<'a> {
     TSType(TSType<'a>),
@@ -258,91 +259,91 @@ pub enum TSTupleElement<'a> {
     TSNamedTupleMember(Box<'a, TSNamedTupleMember<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSAnyKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSStringKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSBooleanKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSNumberKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSNeverKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSUnknownKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSNullKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSUndefinedKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSVoidKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSSymbolKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSThisKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSObjectKeyword {
     #[serde(flatten)]
     pub span: Span,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type")]
 pub struct TSBigIntKeyword {
     #[serde(flatten)]
@@ -352,7 +353,7 @@ pub struct TSBigIntKeyword {
 /// type C = A;
 /// type D = B.a;
 /// type E = D.c.b.a;
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeReference<'a> {
     #[serde(flatten)]
@@ -362,7 +363,7 @@ pub struct TSTypeReference<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum TSTypeName<'a> {
     IdentifierName(Box<'a, IdentifierName>),
@@ -399,7 +400,7 @@ impl<'a> TSTypeName<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSQualifiedName<'a> {
     #[serde(flatten)]
@@ -408,7 +409,7 @@ pub struct TSQualifiedName<'a> {
     pub right: IdentifierName,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeParameterInstantiation<'a> {
     #[serde(flatten)]
@@ -416,7 +417,7 @@ pub struct TSTypeParameterInstantiation<'a> {
     pub params: Vec<'a, TSType<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeParameter<'a> {
     #[serde(flatten)]
@@ -432,7 +433,7 @@ pub struct TSTypeParameter<'a> {
     pub out: bool,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeParameterDeclaration<'a> {
     #[serde(flatten)]
@@ -440,7 +441,7 @@ pub struct TSTypeParameterDeclaration<'a> {
     pub params: Vec<'a, Box<'a, TSTypeParameter<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeAliasDeclaration<'a> {
     #[serde(flatten)]
@@ -454,21 +455,23 @@ pub struct TSTypeAliasDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSAbstractMethodDefinition<'a> {
     #[serde(flatten)]
     pub method_definition: MethodDefinition<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSAbstractPropertyDefinition<'a> {
     #[serde(flatten)]
     pub property_definition: PropertyDefinition<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(
+    Debug, Serialize, PartialEq, Eq, Hash, Clone, Copy, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum TSAccessibility {
     Private,
@@ -476,7 +479,7 @@ pub enum TSAccessibility {
     Public,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSClassImplements<'a> {
     #[serde(flatten)]
@@ -488,7 +491,7 @@ pub struct TSClassImplements<'a> {
 
 /// `InterfaceDeclaration`:
 ///   interface `BindingIdentifier` `TypeParameters_opt` `InterfaceExtendsClause_opt` `ObjectType`
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSInterfaceDeclaration<'a> {
     #[serde(flatten)]
@@ -504,7 +507,7 @@ pub struct TSInterfaceDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSInterfaceBody<'a> {
     #[serde(flatten)]
@@ -512,7 +515,7 @@ pub struct TSInterfaceBody<'a> {
     pub body: Vec<'a, TSSignature<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSPropertySignature<'a> {
     #[serde(flatten)]
@@ -527,7 +530,7 @@ pub struct TSPropertySignature<'a> {
     pub type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSSignature<'a> {
     TSIndexSignature(Box<'a, TSIndexSignature<'a>>),
@@ -537,7 +540,7 @@ pub enum TSSignature<'a> {
     TSMethodSignature(Box<'a, TSMethodSignature<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSIndexSignature<'a> {
     #[serde(flatten)]
@@ -546,7 +549,7 @@ pub struct TSIndexSignature<'a> {
     pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSCallSignatureDeclaration<'a> {
     #[serde(flatten)]
@@ -558,7 +561,9 @@ pub struct TSCallSignatureDeclaration<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum TSMethodSignatureKind {
     Method,
@@ -566,7 +571,7 @@ pub enum TSMethodSignatureKind {
     Set,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSMethodSignature<'a> {
     #[serde(flatten)]
@@ -583,7 +588,7 @@ pub struct TSMethodSignature<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSConstructSignatureDeclaration<'a> {
     #[serde(flatten)]
@@ -595,7 +600,7 @@ pub struct TSConstructSignatureDeclaration<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase", rename = "Identifier")]
 pub struct TSIndexSignatureName<'a> {
     #[serde(flatten)]
@@ -604,7 +609,7 @@ pub struct TSIndexSignatureName<'a> {
     pub type_annotation: Box<'a, TSTypeAnnotation<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSInterfaceHeritage<'a> {
     #[serde(flatten)]
@@ -614,7 +619,7 @@ pub struct TSInterfaceHeritage<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypePredicate<'a> {
     #[serde(flatten)]
@@ -624,14 +629,14 @@ pub struct TSTypePredicate<'a> {
     pub type_annotation: Option<Box<'a, TSTypeAnnotation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSTypePredicateName {
     Identifier(IdentifierName),
     This(TSThisKeyword),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSModuleDeclaration<'a> {
     #[serde(flatten)]
@@ -643,7 +648,7 @@ pub struct TSModuleDeclaration<'a> {
     pub modifiers: Modifiers<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum TSModuleDeclarationName {
     Identifier(IdentifierName),
@@ -660,14 +665,14 @@ impl TSModuleDeclarationName {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged)]
 pub enum TSModuleDeclarationBody<'a> {
     TSModuleDeclaration(Box<'a, TSModuleDeclaration<'a>>),
     TSModuleBlock(Box<'a, TSModuleBlock<'a>>),
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSModuleBlock<'a> {
     #[serde(flatten)]
@@ -675,15 +680,18 @@ pub struct TSModuleBlock<'a> {
     pub body: Vec<'a, Statement<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeLiteral<'a> {
     #[serde(flatten)]
     pub span: Span,
     pub members: Vec<'a, TSSignature<'a>>,
+    /// Flow exact object type `{| a: T |}`
+    #[serde(skip_serializing_if = "is_false")]
+    pub exact: bool,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSInferType<'a> {
     #[serde(flatten)]
@@ -691,7 +699,7 @@ pub struct TSInferType<'a> {
     pub type_parameter: Box<'a, TSTypeParameter<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeQuery<'a> {
     #[serde(flatten)]
@@ -701,7 +709,7 @@ pub struct TSTypeQuery<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSImportType<'a> {
     #[serde(flatten)]
@@ -712,7 +720,7 @@ pub struct TSImportType<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterInstantiation<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSFunctionType<'a> {
     #[serde(flatten)]
@@ -723,7 +731,7 @@ pub struct TSFunctionType<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSConstructorType<'a> {
     #[serde(flatten)]
@@ -735,7 +743,7 @@ pub struct TSConstructorType<'a> {
     pub type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSMappedType<'a> {
     #[serde(flatten)]
@@ -747,7 +755,7 @@ pub struct TSMappedType<'a> {
     pub readonly: TSMappedTypeModifierOperator,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSMappedTypeModifierOperator {
     // #[serde(rename = true)]
@@ -760,7 +768,7 @@ pub enum TSMappedTypeModifierOperator {
     None,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTemplateLiteralType<'a> {
     #[serde(flatten)]
@@ -769,7 +777,7 @@ pub struct TSTemplateLiteralType<'a> {
     pub types: Vec<'a, TSType<'a>>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSAsExpression<'a> {
     #[serde(flatten)]
@@ -778,7 +786,7 @@ pub struct TSAsExpression<'a> {
     pub type_annotation: TSType<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSTypeAssertion<'a> {
     #[serde(flatten)]
@@ -787,7 +795,7 @@ pub struct TSTypeAssertion<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSImportEqualsDeclaration<'a> {
     #[serde(flatten)]
@@ -798,14 +806,14 @@ pub struct TSImportEqualsDeclaration<'a> {
     pub import_kind: ImportOrExportKind,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum TSModuleReference<'a> {
     TypeName(TSTypeName<'a>),
     ExternalModuleReference(TSExternalModuleReference),
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSExternalModuleReference {
     #[serde(flatten)]
@@ -813,7 +821,7 @@ pub struct TSExternalModuleReference {
     pub expression: StringLiteral,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSNonNullExpression<'a> {
     #[serde(flatten)]
@@ -821,7 +829,7 @@ pub struct TSNonNullExpression<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct Decorator<'a> {
     #[serde(flatten)]
@@ -829,7 +837,37 @@ pub struct Decorator<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+impl<'a> Decorator<'a> {
+    /// Standard decorators are a dotted name, a call of one, or a parenthesized expression,
+    /// legacy decorators can be any left-hand side expression
+    #[must_use]
+    pub fn is_standard(&self) -> bool {
+        fn is_dotted_name(expr: &Expression) -> bool {
+            match expr {
+                Expression::Identifier(_) => true,
+                Expression::MemberExpression(member) => match &**member {
+                    MemberExpression::StaticMemberExpression(member) => {
+                        !member.optional && is_dotted_name(&member.object)
+                    }
+                    MemberExpression::PrivateFieldExpression(member) => {
+                        !member.optional && is_dotted_name(&member.object)
+                    }
+                    MemberExpression::ComputedMemberExpression(_) => false,
+                },
+                _ => false,
+            }
+        }
+        match &self.expression {
+            Expression::ParenthesizedExpression(_) => true,
+            Expression::CallExpression(call) => !call.optional && is_dotted_name(&call.callee),
+            expr => is_dotted_name(expr),
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub enum ModifierKind {
     Abstract,
@@ -849,7 +887,7 @@ pub enum ModifierKind {
     Override,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct Modifier {
     #[serde(flatten)]
@@ -857,7 +895,9 @@ pub struct Modifier {
     pub kind: ModifierKind,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash, Default)]
+#[derive(
+    Debug, Serialize, PartialEq, Eq, Hash, Default, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(transparent)]
 pub struct Modifiers<'a>(Option<Vec<'a, Modifier>>);
 
@@ -885,7 +925,7 @@ impl<'a> Modifiers<'a> {
     }
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSExportAssignment<'a> {
     #[serde(flatten)]
@@ -893,7 +933,7 @@ pub struct TSExportAssignment<'a> {
     pub expression: Expression<'a>,
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSNamespaceExportDeclaration {
     #[serde(flatten)]
@@ -901,7 +941,7 @@ pub struct TSNamespaceExportDeclaration {
     pub id: IdentifierName,
 }
 
-#[derive(Debug, Serialize, PartialEq, Hash)]
+#[derive(Debug, Serialize, PartialEq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans)]
 #[serde(tag = "type", rename_all = "camelCase")]
 pub struct TSInstantiationExpression<'a> {
     #[serde(flatten)]
@@ -910,7 +950,9 @@ pub struct TSInstantiationExpression<'a> {
     pub type_parameters: Box<'a, TSTypeParameterInstantiation<'a>>,
 }
 
-#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(
+    Debug, Serialize, Clone, Copy, PartialEq, Eq, Hash, CloneIn, ContentEq, ContentHash, ShiftSpans,
+)]
 #[serde(untagged, rename_all = "camelCase")]
 pub enum ImportOrExportKind {
     Value,
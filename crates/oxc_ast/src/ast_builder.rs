@@ -5,7 +5,7 @@
 use oxc_allocator::{Allocator, Box, String, Vec};
 
 #[allow(clippy::wildcard_imports)]
-use crate::{ast::*, Atom, SourceType, Span};
+use crate::{ast::*, Atom, GetSpan, SourceType, Span};
 
 pub struct AstBuilder<'a> {
     pub allocator: &'a Allocator,
@@ -45,6 +45,27 @@ impl<'a> AstBuilder<'a> {
         String::from_str_in(value, self.allocator).into_bump_str()
     }
 
+    /// Take the expression out of the tree, leaving a `null` literal in its place.
+    /// Used by [`crate::visit_mut::VisitMut`] passes which need ownership to rebuild a node.
+    #[must_use]
+    pub fn move_expression(&self, expr: &mut Expression<'a>) -> Expression<'a> {
+        let null_literal = self.literal_null_expression(NullLiteral { span: expr.span() });
+        std::mem::replace(expr, null_literal)
+    }
+
+    /// Take the statement out of the tree, leaving an empty statement in its place.
+    #[must_use]
+    pub fn move_statement(&self, stmt: &mut Statement<'a>) -> Statement<'a> {
+        let empty_statement = self.empty_statement(stmt.span());
+        std::mem::replace(stmt, empty_statement)
+    }
+
+    /// Take all statements out of the list, leaving it empty.
+    #[must_use]
+    pub fn move_statement_vec(&self, stmts: &mut Vec<'a, Statement<'a>>) -> Vec<'a, Statement<'a>> {
+        std::mem::replace(stmts, self.new_vec())
+    }
+
     #[must_use]
     #[inline]
     pub fn program(
@@ -165,6 +186,12 @@ impl<'a> AstBuilder<'a> {
         Statement::EmptyStatement(self.alloc(EmptyStatement { span }))
     }
 
+    #[must_use]
+    #[inline]
+    pub fn invalid_statement(&self, span: Span) -> Statement<'a> {
+        Statement::InvalidStatement(self.alloc(InvalidStatement { span }))
+    }
+
     #[must_use]
     #[inline]
     pub fn expression_statement(&self, span: Span, expression: Expression<'a>) -> Statement<'a> {
@@ -793,6 +820,12 @@ impl<'a> AstBuilder<'a> {
         ClassElement::StaticBlock(self.alloc(StaticBlock { span, body }))
     }
 
+    #[must_use]
+    #[inline]
+    pub fn invalid_class_element(&self, span: Span) -> ClassElement<'a> {
+        ClassElement::InvalidClassElement(self.alloc(InvalidClassElement { span }))
+    }
+
     #[must_use]
     #[inline]
     pub fn accessor_property(
@@ -802,6 +835,7 @@ impl<'a> AstBuilder<'a> {
         value: Option<Expression<'a>>,
         computed: bool,
         r#static: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> ClassElement<'a> {
         ClassElement::AccessorProperty(self.alloc(AccessorProperty {
             span,
@@ -809,6 +843,7 @@ impl<'a> AstBuilder<'a> {
             value,
             computed,
             r#static,
+            decorators,
         }))
     }
 
@@ -953,10 +988,10 @@ impl<'a> AstBuilder<'a> {
         &self,
         specifiers: Vec<'a, ImportDeclarationSpecifier>,
         source: StringLiteral,
-        assertions: Option<Vec<'a, ImportAttribute>>,
+        with_clause: Option<WithClause<'a>>,
         import_kind: Option<ImportOrExportKind>,
     ) -> Box<'a, ImportDeclaration<'a>> {
-        self.alloc(ImportDeclaration { specifiers, source, assertions, import_kind })
+        self.alloc(ImportDeclaration { specifiers, source, with_clause, import_kind })
     }
 
     #[must_use]
@@ -965,10 +1000,10 @@ impl<'a> AstBuilder<'a> {
         &self,
         exported: Option<ModuleExportName>,
         source: StringLiteral,
-        assertions: Option<Vec<'a, ImportAttribute>>, // Some(vec![]) for empty assertion
+        with_clause: Option<WithClause<'a>>,
         export_kind: Option<ImportOrExportKind>,
     ) -> Box<'a, ExportAllDeclaration<'a>> {
-        self.alloc(ExportAllDeclaration { exported, source, assertions, export_kind })
+        self.alloc(ExportAllDeclaration { exported, source, with_clause, export_kind })
     }
 
     #[must_use]
@@ -988,9 +1023,16 @@ impl<'a> AstBuilder<'a> {
         declaration: Option<Declaration<'a>>,
         specifiers: Vec<'a, ExportSpecifier>,
         source: Option<StringLiteral>,
+        with_clause: Option<WithClause<'a>>,
         export_kind: Option<ImportOrExportKind>, // `export type { foo }`
     ) -> Box<'a, ExportNamedDeclaration<'a>> {
-        self.alloc(ExportNamedDeclaration { declaration, specifiers, source, export_kind })
+        self.alloc(ExportNamedDeclaration {
+            declaration,
+            specifiers,
+            source,
+            with_clause,
+            export_kind,
+        })
     }
 
     /* ---------- JSX ----------------- */
@@ -1230,8 +1272,13 @@ impl<'a> AstBuilder<'a> {
 
     #[must_use]
     #[inline]
-    pub fn ts_type_literal(&self, span: Span, members: Vec<'a, TSSignature<'a>>) -> TSType<'a> {
-        TSType::TSTypeLiteral(self.alloc(TSTypeLiteral { span, members }))
+    pub fn ts_type_literal(
+        &self,
+        span: Span,
+        members: Vec<'a, TSSignature<'a>>,
+        exact: bool,
+    ) -> TSType<'a> {
+        TSType::TSTypeLiteral(self.alloc(TSTypeLiteral { span, members, exact }))
     }
 
     #[must_use]
@@ -1488,6 +1535,27 @@ impl<'a> AstBuilder<'a> {
         }))
     }
 
+    #[must_use]
+    #[inline]
+    pub fn flow_opaque_type(
+        &self,
+        span: Span,
+        id: BindingIdentifier,
+        type_parameters: Option<Box<'a, TSTypeParameterDeclaration<'a>>>,
+        supertype: Option<TSType<'a>>,
+        impl_type: Option<TSType<'a>>,
+        modifiers: Modifiers<'a>,
+    ) -> Declaration<'a> {
+        Declaration::FlowOpaqueType(self.alloc(FlowOpaqueType {
+            span,
+            id,
+            type_parameters,
+            supertype,
+            impl_type,
+            modifiers,
+        }))
+    }
+
     #[must_use]
     #[inline]
     pub fn ts_enum_declaration(
@@ -1733,6 +1801,30 @@ impl<'a> AstBuilder<'a> {
         }))
     }
 
+    /* JSON */
+    #[must_use]
+    #[inline]
+    pub fn json_array(&self, span: Span, elements: Vec<'a, JsonValue<'a>>) -> JsonValue<'a> {
+        JsonValue::Array(self.alloc(JsonArray { span, elements }))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn json_object(&self, span: Span, properties: Vec<'a, JsonProperty<'a>>) -> JsonValue<'a> {
+        JsonValue::Object(self.alloc(JsonObject { span, properties }))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn json_property(
+        &self,
+        span: Span,
+        key: StringLiteral,
+        value: JsonValue<'a>,
+    ) -> JsonProperty<'a> {
+        JsonProperty { span, key, value }
+    }
+
     /* JSDoc */
     #[must_use]
     #[inline]
@@ -27,6 +27,7 @@ pub enum AstKind<'a> {
     TryStatement(&'a TryStatement<'a>),
     WhileStatement(&'a WhileStatement<'a>),
     WithStatement(&'a WithStatement<'a>),
+    InvalidStatement(&'a InvalidStatement),
 
     SwitchCase(&'a SwitchCase<'a>),
     CatchClause(&'a CatchClause<'a>),
@@ -89,6 +90,7 @@ pub enum AstKind<'a> {
     Class(&'a Class<'a>),
     ClassHeritage(&'a Expression<'a>),
     StaticBlock(&'a StaticBlock<'a>),
+    InvalidClassElement(&'a InvalidClassElement),
     PropertyDefinition(&'a PropertyDefinition<'a>),
     MethodDefinition(&'a MethodDefinition<'a>),
 
@@ -99,6 +101,8 @@ pub enum AstKind<'a> {
     Decorator(&'a Decorator<'a>),
 
     ModuleDeclaration(&'a ModuleDeclaration<'a>),
+    WithClause(&'a WithClause<'a>),
+    ImportAttribute(&'a ImportAttribute),
 
     // JSX
     // Please make sure to add these to `is_jsx` below.
@@ -137,6 +141,9 @@ pub enum AstKind<'a> {
     TSTypeParameterInstantiation(&'a TSTypeParameterInstantiation<'a>),
 
     TSPropertySignature(&'a TSPropertySignature<'a>),
+
+    // Flow
+    FlowOpaqueType(&'a FlowOpaqueType<'a>),
 }
 
 // SAFETY: The AST is part of the bump allocator,
@@ -153,7 +160,8 @@ impl<'a> AstKind<'a> {
                     | Self::DebuggerStatement(_) | Self::EmptyStatement(_) | Self::ExpressionStatement(_)
                     | Self::LabeledStatement(_) | Self::ReturnStatement(_) | Self::SwitchStatement(_)
                     | Self::ThrowStatement(_) | Self::TryStatement(_) | Self::WithStatement(_)
-                    | Self::IfStatement(_) | Self::VariableDeclaration(_))
+                    | Self::IfStatement(_) | Self::VariableDeclaration(_)
+                    | Self::InvalidStatement(_))
     }
 
     #[must_use]
@@ -164,6 +172,7 @@ impl<'a> AstKind<'a> {
             Self::ModuleDeclaration(_) | Self::TSEnumDeclaration(_) | Self::TSModuleDeclaration(_)
                 | Self::VariableDeclaration(_) | Self::TSInterfaceDeclaration(_)
                 | Self::TSTypeAliasDeclaration(_) | Self::TSImportEqualsDeclaration(_)
+                | Self::FlowOpaqueType(_)
         )
     }
 
@@ -257,6 +266,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TryStatement(x) => x.span,
             Self::WhileStatement(x) => x.span,
             Self::WithStatement(x) => x.span,
+            Self::InvalidStatement(x) => x.span,
 
             Self::SwitchCase(x) => x.span,
             Self::CatchClause(x) => x.span,
@@ -319,6 +329,7 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::Class(x) => x.span,
             Self::ClassHeritage(x) => x.span(),
             Self::StaticBlock(x) => x.span,
+            Self::InvalidClassElement(x) => x.span,
             Self::PropertyDefinition(x) => x.span,
             Self::MethodDefinition(x) => x.span,
 
@@ -329,6 +340,8 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::Decorator(x) => x.span,
 
             Self::ModuleDeclaration(x) => x.span,
+            Self::WithClause(x) => x.span,
+            Self::ImportAttribute(x) => x.span,
 
             Self::JSXOpeningElement(x) => x.span,
             Self::JSXElementName(x) => x.span(),
@@ -363,6 +376,275 @@ impl<'a> GetSpan for AstKind<'a> {
             Self::TSTypeParameterInstantiation(x) => x.span,
 
             Self::TSPropertySignature(x) => x.span,
+
+            Self::FlowOpaqueType(x) => x.span,
+        }
+    }
+}
+
+/// [`AstKind`] without the node reference,
+/// for visitors which cannot hold a shared reference to the node such as [`crate::visit_mut::VisitMut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AstType {
+    Root,
+
+    Program,
+    Directive,
+
+    BlockStatement,
+    BreakStatement,
+    ContinueStatement,
+    DebuggerStatement,
+    DoWhileStatement,
+    EmptyStatement,
+    ExpressionStatement,
+    ForInStatement,
+    ForOfStatement,
+    ForStatement,
+    ForStatementInit,
+    IfStatement,
+    LabeledStatement,
+    ReturnStatement,
+    SwitchStatement,
+    ThrowStatement,
+    TryStatement,
+    WhileStatement,
+    WithStatement,
+    InvalidStatement,
+
+    SwitchCase,
+    CatchClause,
+    FinallyClause,
+
+    VariableDeclaration,
+    VariableDeclarator,
+
+    IdentifierName,
+    IdentifierReference,
+    BindingIdentifier,
+    LabelIdentifier,
+    PrivateIdentifier,
+
+    NumberLiteral,
+    StringLiteral,
+    BooleanLiteral,
+    NullLiteral,
+    BigintLiteral,
+    RegExpLiteral,
+    TemplateLiteral,
+
+    MetaProperty,
+    Super,
+
+    ArrayExpression,
+    ArrowExpression,
+    AssignmentExpression,
+    AwaitExpression,
+    BinaryExpression,
+    CallExpression,
+    ConditionalExpression,
+    LogicalExpression,
+    MemberExpression,
+    NewExpression,
+    ObjectExpression,
+    ParenthesizedExpression,
+    SequenceExpression,
+    TaggedTemplateExpression,
+    ThisExpression,
+    UnaryExpression,
+    UpdateExpression,
+    YieldExpression,
+
+    Property,
+    PropertyKey,
+    PropertyValue,
+    Argument,
+    AssignmentTarget,
+    SimpleAssignmentTarget,
+    AssignmentTargetWithDefault,
+    SpreadElement,
+    RestElement,
+
+    Function,
+    FunctionBody,
+    FormalParameters,
+    FormalParameter,
+
+    Class,
+    ClassHeritage,
+    StaticBlock,
+    InvalidClassElement,
+    PropertyDefinition,
+    MethodDefinition,
+
+    ArrayPattern,
+    ObjectPattern,
+    AssignmentPattern,
+
+    Decorator,
+
+    ModuleDeclaration,
+    WithClause,
+    ImportAttribute,
+
+    // JSX
+    JSXOpeningElement,
+    JSXElementName,
+
+    // TypeScript
+    TSModuleBlock,
+
+    TSAnyKeyword,
+    TSIntersectionType,
+    TSLiteralType,
+    TSMethodSignature,
+    TSNullKeyword,
+    TSTypeLiteral,
+    TSTypeReference,
+    TSUnionType,
+    TSVoidKeyword,
+
+    TSIndexedAccessType,
+
+    TSAsExpression,
+    TSNonNullExpression,
+
+    TSEnumDeclaration,
+    TSEnumMember,
+    TSImportEqualsDeclaration,
+    TSInterfaceDeclaration,
+    TSModuleDeclaration,
+    TSTypeAliasDeclaration,
+    TSTypeAnnotation,
+    TSTypeAssertion,
+    TSTypeParameter,
+    TSTypeParameterDeclaration,
+    TSTypeParameterInstantiation,
+
+    TSPropertySignature,
+
+    FlowOpaqueType,
+}
+
+impl<'a> AstKind<'a> {
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn ast_type(self) -> AstType {
+        match self {
+            Self::Root => AstType::Root,
+            Self::Program(_) => AstType::Program,
+            Self::Directive(_) => AstType::Directive,
+            Self::BlockStatement(_) => AstType::BlockStatement,
+            Self::BreakStatement(_) => AstType::BreakStatement,
+            Self::ContinueStatement(_) => AstType::ContinueStatement,
+            Self::DebuggerStatement(_) => AstType::DebuggerStatement,
+            Self::DoWhileStatement(_) => AstType::DoWhileStatement,
+            Self::EmptyStatement(_) => AstType::EmptyStatement,
+            Self::ExpressionStatement(_) => AstType::ExpressionStatement,
+            Self::ForInStatement(_) => AstType::ForInStatement,
+            Self::ForOfStatement(_) => AstType::ForOfStatement,
+            Self::ForStatement(_) => AstType::ForStatement,
+            Self::ForStatementInit(_) => AstType::ForStatementInit,
+            Self::IfStatement(_) => AstType::IfStatement,
+            Self::LabeledStatement(_) => AstType::LabeledStatement,
+            Self::ReturnStatement(_) => AstType::ReturnStatement,
+            Self::SwitchStatement(_) => AstType::SwitchStatement,
+            Self::ThrowStatement(_) => AstType::ThrowStatement,
+            Self::TryStatement(_) => AstType::TryStatement,
+            Self::WhileStatement(_) => AstType::WhileStatement,
+            Self::WithStatement(_) => AstType::WithStatement,
+            Self::InvalidStatement(_) => AstType::InvalidStatement,
+            Self::SwitchCase(_) => AstType::SwitchCase,
+            Self::CatchClause(_) => AstType::CatchClause,
+            Self::FinallyClause(_) => AstType::FinallyClause,
+            Self::VariableDeclaration(_) => AstType::VariableDeclaration,
+            Self::VariableDeclarator(_) => AstType::VariableDeclarator,
+            Self::IdentifierName(_) => AstType::IdentifierName,
+            Self::IdentifierReference(_) => AstType::IdentifierReference,
+            Self::BindingIdentifier(_) => AstType::BindingIdentifier,
+            Self::LabelIdentifier(_) => AstType::LabelIdentifier,
+            Self::PrivateIdentifier(_) => AstType::PrivateIdentifier,
+            Self::NumberLiteral(_) => AstType::NumberLiteral,
+            Self::StringLiteral(_) => AstType::StringLiteral,
+            Self::BooleanLiteral(_) => AstType::BooleanLiteral,
+            Self::NullLiteral(_) => AstType::NullLiteral,
+            Self::BigintLiteral(_) => AstType::BigintLiteral,
+            Self::RegExpLiteral(_) => AstType::RegExpLiteral,
+            Self::TemplateLiteral(_) => AstType::TemplateLiteral,
+            Self::MetaProperty(_) => AstType::MetaProperty,
+            Self::Super(_) => AstType::Super,
+            Self::ArrayExpression(_) => AstType::ArrayExpression,
+            Self::ArrowExpression(_) => AstType::ArrowExpression,
+            Self::AssignmentExpression(_) => AstType::AssignmentExpression,
+            Self::AwaitExpression(_) => AstType::AwaitExpression,
+            Self::BinaryExpression(_) => AstType::BinaryExpression,
+            Self::CallExpression(_) => AstType::CallExpression,
+            Self::ConditionalExpression(_) => AstType::ConditionalExpression,
+            Self::LogicalExpression(_) => AstType::LogicalExpression,
+            Self::MemberExpression(_) => AstType::MemberExpression,
+            Self::NewExpression(_) => AstType::NewExpression,
+            Self::ObjectExpression(_) => AstType::ObjectExpression,
+            Self::ParenthesizedExpression(_) => AstType::ParenthesizedExpression,
+            Self::SequenceExpression(_) => AstType::SequenceExpression,
+            Self::TaggedTemplateExpression(_) => AstType::TaggedTemplateExpression,
+            Self::ThisExpression(_) => AstType::ThisExpression,
+            Self::UnaryExpression(_) => AstType::UnaryExpression,
+            Self::UpdateExpression(_) => AstType::UpdateExpression,
+            Self::YieldExpression(_) => AstType::YieldExpression,
+            Self::Property(_) => AstType::Property,
+            Self::PropertyKey(_) => AstType::PropertyKey,
+            Self::PropertyValue(_) => AstType::PropertyValue,
+            Self::Argument(_) => AstType::Argument,
+            Self::AssignmentTarget(_) => AstType::AssignmentTarget,
+            Self::SimpleAssignmentTarget(_) => AstType::SimpleAssignmentTarget,
+            Self::AssignmentTargetWithDefault(_) => AstType::AssignmentTargetWithDefault,
+            Self::SpreadElement(_) => AstType::SpreadElement,
+            Self::RestElement(_) => AstType::RestElement,
+            Self::Function(_) => AstType::Function,
+            Self::FunctionBody(_) => AstType::FunctionBody,
+            Self::FormalParameters(_) => AstType::FormalParameters,
+            Self::FormalParameter(_) => AstType::FormalParameter,
+            Self::Class(_) => AstType::Class,
+            Self::ClassHeritage(_) => AstType::ClassHeritage,
+            Self::StaticBlock(_) => AstType::StaticBlock,
+            Self::InvalidClassElement(_) => AstType::InvalidClassElement,
+            Self::PropertyDefinition(_) => AstType::PropertyDefinition,
+            Self::MethodDefinition(_) => AstType::MethodDefinition,
+            Self::ArrayPattern(_) => AstType::ArrayPattern,
+            Self::ObjectPattern(_) => AstType::ObjectPattern,
+            Self::AssignmentPattern(_) => AstType::AssignmentPattern,
+            Self::Decorator(_) => AstType::Decorator,
+            Self::ModuleDeclaration(_) => AstType::ModuleDeclaration,
+            Self::WithClause(_) => AstType::WithClause,
+            Self::ImportAttribute(_) => AstType::ImportAttribute,
+            Self::JSXOpeningElement(_) => AstType::JSXOpeningElement,
+            Self::JSXElementName(_) => AstType::JSXElementName,
+            Self::TSModuleBlock(_) => AstType::TSModuleBlock,
+            Self::TSAnyKeyword(_) => AstType::TSAnyKeyword,
+            Self::TSIntersectionType(_) => AstType::TSIntersectionType,
+            Self::TSLiteralType(_) => AstType::TSLiteralType,
+            Self::TSMethodSignature(_) => AstType::TSMethodSignature,
+            Self::TSNullKeyword(_) => AstType::TSNullKeyword,
+            Self::TSTypeLiteral(_) => AstType::TSTypeLiteral,
+            Self::TSTypeReference(_) => AstType::TSTypeReference,
+            Self::TSUnionType(_) => AstType::TSUnionType,
+            Self::TSVoidKeyword(_) => AstType::TSVoidKeyword,
+            Self::TSIndexedAccessType(_) => AstType::TSIndexedAccessType,
+            Self::TSAsExpression(_) => AstType::TSAsExpression,
+            Self::TSNonNullExpression(_) => AstType::TSNonNullExpression,
+            Self::TSEnumDeclaration(_) => AstType::TSEnumDeclaration,
+            Self::TSEnumMember(_) => AstType::TSEnumMember,
+            Self::TSImportEqualsDeclaration(_) => AstType::TSImportEqualsDeclaration,
+            Self::TSInterfaceDeclaration(_) => AstType::TSInterfaceDeclaration,
+            Self::TSModuleDeclaration(_) => AstType::TSModuleDeclaration,
+            Self::TSTypeAliasDeclaration(_) => AstType::TSTypeAliasDeclaration,
+            Self::TSTypeAnnotation(_) => AstType::TSTypeAnnotation,
+            Self::TSTypeAssertion(_) => AstType::TSTypeAssertion,
+            Self::TSTypeParameter(_) => AstType::TSTypeParameter,
+            Self::TSTypeParameterDeclaration(_) => AstType::TSTypeParameterDeclaration,
+            Self::TSTypeParameterInstantiation(_) => AstType::TSTypeParameterInstantiation,
+            Self::TSPropertySignature(_) => AstType::TSPropertySignature,
+            Self::FlowOpaqueType(_) => AstType::FlowOpaqueType,
         }
     }
 }
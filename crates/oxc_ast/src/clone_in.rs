@@ -0,0 +1,77 @@
+//! Deep cloning of AST nodes into another allocator.
+
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Allocator, Box, Vec};
+
+use crate::{ast::RegExpFlags, Atom, SourceType, Span};
+
+/// Clone an AST node into the given allocator.
+///
+/// AST nodes cannot implement [`Clone`] because they own arena allocated children,
+/// `clone_in` allocates a copy of every child in `allocator` instead.
+/// The allocator can be the one that owns `self`, e.g. when a transform needs to duplicate
+/// a node (`a.b ??= c` to `a.b ?? (a.b = c)`).
+pub trait CloneIn<'new>: Sized {
+    type Cloned;
+
+    fn clone_in(&self, allocator: &'new Allocator) -> Self::Cloned;
+}
+
+impl<'old, 'new, T> CloneIn<'new> for Box<'old, T>
+where
+    T: CloneIn<'new>,
+    T::Cloned: 'new,
+{
+    type Cloned = Box<'new, T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new Allocator) -> Self::Cloned {
+        Box(allocator.alloc(self.0.clone_in(allocator)))
+    }
+}
+
+impl<'old, 'new, T> CloneIn<'new> for Vec<'old, T>
+where
+    T: CloneIn<'new>,
+    T::Cloned: 'new,
+{
+    type Cloned = Vec<'new, T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new Allocator) -> Self::Cloned {
+        Vec::from_iter_in(self.iter().map(|item| item.clone_in(allocator)), allocator)
+    }
+}
+
+impl<'new, T: CloneIn<'new>> CloneIn<'new> for Option<T> {
+    type Cloned = Option<T::Cloned>;
+
+    fn clone_in(&self, allocator: &'new Allocator) -> Self::Cloned {
+        self.as_ref().map(|value| value.clone_in(allocator))
+    }
+}
+
+impl<'old, 'new> CloneIn<'new> for &'old str {
+    type Cloned = &'new str;
+
+    fn clone_in(&self, allocator: &'new Allocator) -> Self::Cloned {
+        allocator.alloc_str(self)
+    }
+}
+
+/// Types which do not borrow from the allocator are cloned with [`Clone`].
+macro_rules! impl_clone_in {
+    ($($ty:ty),*) => {
+        $(
+            impl<'new> CloneIn<'new> for $ty {
+                type Cloned = Self;
+
+                #[inline]
+                fn clone_in(&self, _: &'new Allocator) -> Self {
+                    self.clone()
+                }
+            }
+        )*
+    };
+}
+
+impl_clone_in!(bool, u32, f64, NotNan<f64>, Atom, BigUint, Span, SourceType, RegExpFlags);
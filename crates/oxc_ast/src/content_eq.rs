@@ -0,0 +1,179 @@
+//! Structural comparison and hashing of AST nodes.
+//!
+//! [`PartialEq`] on AST nodes compares spans, which makes `a === a` have two different operands.
+//! [`ContentEq`] and [`ContentHash`] only look at the content of the nodes.
+
+use std::hash::{Hash, Hasher};
+
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Box, Vec};
+
+use crate::{ast::RegExpFlags, Atom, SourceType, Span};
+
+/// Compare two nodes while ignoring their spans.
+pub trait ContentEq {
+    fn content_eq(&self, other: &Self) -> bool;
+}
+
+/// Hash a node while ignoring its span, consistent with [`ContentEq`].
+pub trait ContentHash {
+    fn content_hash<H: Hasher>(&self, state: &mut H);
+}
+
+impl ContentEq for Span {
+    #[inline]
+    fn content_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl ContentHash for Span {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<'a, T: ContentEq> ContentEq for Box<'a, T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.0.content_eq(other.0)
+    }
+}
+
+impl<'a, T: ContentHash> ContentHash for Box<'a, T> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.content_hash(state);
+    }
+}
+
+impl<'a, T: ContentEq> ContentEq for Vec<'a, T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a.content_eq(b))
+    }
+}
+
+impl<'a, T: ContentHash> ContentHash for Vec<'a, T> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        self.iter().for_each(|item| item.content_hash(state));
+    }
+}
+
+impl<T: ContentEq> ContentEq for Option<T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => a.content_eq(b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: ContentHash> ContentHash for Option<T> {
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Some(value) = self {
+            value.content_hash(state);
+        }
+    }
+}
+
+/// Leaf types compare and hash with [`PartialEq`] and [`Hash`].
+macro_rules! impl_content_eq {
+    ($($ty:ty),*) => {
+        $(
+            impl ContentEq for $ty {
+                #[inline]
+                fn content_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+
+            impl ContentHash for $ty {
+                #[inline]
+                fn content_hash<H: Hasher>(&self, state: &mut H) {
+                    self.hash(state);
+                }
+            }
+        )*
+    };
+}
+
+impl_content_eq!(bool, u32, NotNan<f64>, Atom, BigUint, SourceType, RegExpFlags);
+
+impl<'a> ContentEq for &'a str {
+    #[inline]
+    fn content_eq(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<'a> ContentHash for &'a str {
+    #[inline]
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::hash_map::DefaultHasher, hash::Hasher};
+
+    use oxc_allocator::Allocator;
+
+    use super::{ContentEq, ContentHash};
+    #[allow(clippy::wildcard_imports)]
+    use crate::{ast::*, AstBuilder, CloneIn, Span};
+
+    /// `a.b + name` starting at `start`
+    fn expression<'a>(ast: &AstBuilder<'a>, start: u32, name: &str) -> Expression<'a> {
+        let span = Span::new(start, start + 7);
+        let ident = IdentifierReference { span: Span::new(start, start + 1), name: "a".into() };
+        let property = IdentifierName { span: Span::new(start + 2, start + 3), name: "b".into() };
+        let left =
+            ast.static_member_expression(span, ast.identifier_expression(ident), property, false);
+        let ident =
+            IdentifierReference { span: Span::new(start + 6, start + 7), name: name.into() };
+        ast.binary_expression(
+            span,
+            left,
+            BinaryOperator::Addition,
+            ast.identifier_expression(ident),
+        )
+    }
+
+    fn content_hash(expr: &Expression) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        expr.content_hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn ignores_spans() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let first = expression(&ast, 0, "c");
+        let second = expression(&ast, 10, "c");
+        let third = expression(&ast, 0, "d");
+
+        assert_ne!(first, second);
+        assert!(first.content_eq(&second));
+        assert_eq!(content_hash(&first), content_hash(&second));
+        assert!(!first.content_eq(&third));
+        assert_ne!(content_hash(&first), content_hash(&third));
+    }
+
+    #[test]
+    fn clone_in() {
+        let cloned = {
+            let allocator = Allocator::default();
+            let expr = expression(&AstBuilder::new(&allocator), 0, "c");
+            let new_allocator = Allocator::default();
+            let cloned = expr.clone_in(&new_allocator);
+            drop(allocator);
+            assert_eq!(cloned, expression(&AstBuilder::new(&new_allocator), 0, "c"));
+            content_hash(&cloned)
+        };
+        let allocator = Allocator::default();
+        assert_eq!(cloned, content_hash(&expression(&AstBuilder::new(&allocator), 5, "c")));
+    }
+}
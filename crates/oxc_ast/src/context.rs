@@ -48,6 +48,11 @@ bitflags! {
         /// Decorator context does not parse computed member expressions, e.g.
         /// `class C { @dec() ["method"]() {} }`
         const Decorator = 1 << 6;
+
+        /// [Strict Mode Code](https://tc39.es/ecma262/#sec-strict-mode-code)
+        /// Module code, class definitions and code following a "use strict" directive.
+        /// Annex B syntax is only allowed outside of it.
+        const Strict = 1 << 7;
     }
 }
 
@@ -100,6 +105,12 @@ impl Context {
         self.contains(Self::Decorator)
     }
 
+    #[must_use]
+    #[inline]
+    pub fn has_strict(self) -> bool {
+        self.contains(Self::Strict)
+    }
+
     #[must_use]
     #[inline]
     pub fn union_await_if(self, include: bool) -> Self {
@@ -160,6 +171,12 @@ impl Context {
         self.and(Self::Decorator, include)
     }
 
+    #[must_use]
+    #[inline]
+    pub fn and_strict(self, include: bool) -> Self {
+        self.and(Self::Strict, include)
+    }
+
     #[must_use]
     #[inline]
     fn and(self, flag: Self, set: bool) -> Self {
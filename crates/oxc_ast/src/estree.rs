@@ -0,0 +1,522 @@
+//! [ESTree](https://github.com/estree/estree) and
+//! [TS-ESTree](https://typescript-eslint.io/packages/typescript-estree) compatible serialization
+//!
+//! The `Serialize` implementations of the AST are close to estree, but they follow the shape of
+//! the oxc AST: identifiers keep their syntactic kind, assignment targets are distinct from
+//! binding patterns, parentheses are preserved and positions are UTF-8 byte offsets.
+//! This module rewrites the serialized output into what acorn and typescript-estree produce,
+//! so JavaScript tooling built around estree can consume it as is.
+
+use serde_json::{Map, Value};
+
+use crate::{ast::Program, SourceType};
+
+impl<'a> Program<'a> {
+    /// Serialize to an estree (or typescript-estree for TypeScript) JSON string.
+    ///
+    /// `source_text` must be the source text this program was parsed from,
+    /// it is needed for `raw`, `loc` and UTF-16 based positions.
+    ///
+    /// # Errors
+    /// Returns the error of `serde_json` when serialization fails.
+    pub fn to_estree_json(&self, source_text: &str) -> serde_json::Result<String> {
+        let value = self.to_estree_value(source_text)?;
+        let buf = std::vec::Vec::new();
+        let mut ser = serde_json::Serializer::with_formatter(buf, crate::serialize::EcmaFormatter);
+        serde::Serialize::serialize(&value, &mut ser)?;
+        String::from_utf8(ser.into_inner()).map_err(serde::ser::Error::custom)
+    }
+
+    /// # Errors
+    /// See [`Program::to_estree_json`].
+    pub fn to_estree_value(&self, source_text: &str) -> serde_json::Result<Value> {
+        let value = serde_json::to_value(self)?;
+        Ok(ESTree::new(source_text, self.source_type).convert(value))
+    }
+}
+
+/// Keys only meaningful for TypeScript, removed from JavaScript output when they carry no
+/// information.
+const TS_ONLY_KEYS: [&str; 11] = [
+    "optional",
+    "definite",
+    "declare",
+    "override",
+    "readonly",
+    "abstract",
+    "accessibility",
+    "typeAnnotation",
+    "typeParameters",
+    "returnType",
+    "decorators",
+];
+
+/// typescript-estree declarations always carry a `declare` flag
+const DECLARE_TYPES: [&str; 7] = [
+    "VariableDeclaration",
+    "FunctionDeclaration",
+    "ClassDeclaration",
+    "TSEnumDeclaration",
+    "TSModuleDeclaration",
+    "TSTypeAliasDeclaration",
+    "TSInterfaceDeclaration",
+];
+
+/// Node types of a `BindingPattern`, which carry `readonly` / `accessibility` when they are
+/// formal parameters.
+const BINDING_TYPES: [&str; 5] =
+    ["Identifier", "ObjectPattern", "ArrayPattern", "AssignmentPattern", "RestElement"];
+
+struct ESTree<'a> {
+    source_text: &'a str,
+    typescript: bool,
+    positions: Positions,
+}
+
+impl<'a> ESTree<'a> {
+    fn new(source_text: &'a str, source_type: SourceType) -> Self {
+        Self {
+            source_text,
+            typescript: source_type.is_typescript(),
+            positions: Positions::new(source_text),
+        }
+    }
+
+    fn convert(&self, value: Value) -> Value {
+        match value {
+            Value::Object(map) if map.contains_key("type") => self.convert_node(map),
+            Value::Object(map) => {
+                Value::Object(map.into_iter().map(|(k, v)| (k, self.convert(v))).collect())
+            }
+            Value::Array(array) => {
+                Value::Array(array.into_iter().map(|v| self.convert(v)).collect())
+            }
+            value => value,
+        }
+    }
+
+    fn convert_node(&self, map: Map<String, Value>) -> Value {
+        let (mut start, mut end) = (offset(&map, "start"), offset(&map, "end"));
+        // TS-ESTree declarations start at their first modifier, e.g. `declare`
+        if let Some(Value::Array(modifiers)) = map.get("modifiers") {
+            for modifier in modifiers {
+                if let Value::Object(modifier) = modifier {
+                    start = start.min(offset(modifier, "start"));
+                }
+            }
+        }
+        // The type annotation of a binding pattern is stored outside of the pattern's span,
+        // TS-ESTree includes it
+        if let Some(Value::Object(annotation)) = map.get("typeAnnotation") {
+            end = end.max(offset(annotation, "end"));
+        }
+        let ty = map["type"].as_str().unwrap_or_default().to_string();
+        let mut node = match ty.as_str() {
+            // ESTree does not have parenthesized expressions
+            "ParenthesizedExpression" => {
+                return map.get("expression").map_or(Value::Null, |e| self.convert(e.clone()));
+            }
+            "BindingIdentifier" | "IdentifierReference" | "IdentifierName" | "LabelIdentifier" => {
+                self.rename(map, "Identifier")
+            }
+            "ArrayAssignmentTarget" => {
+                self.assignment_target_pattern(map, "ArrayPattern", "elements")
+            }
+            "ObjectAssignmentTarget" => {
+                self.assignment_target_pattern(map, "ObjectPattern", "properties")
+            }
+            "AssignmentTargetWithDefault" => {
+                let mut node = self.rename(map, "AssignmentPattern");
+                rename_key(&mut node, "binding", "left");
+                rename_key(&mut node, "init", "right");
+                node
+            }
+            "AssignmentTargetPropertyIdentifier" => self.assignment_target_property_identifier(map),
+            "AssignmentTargetPropertyProperty" => {
+                let mut node = self.rename(map, "Property");
+                rename_key(&mut node, "name", "key");
+                rename_key(&mut node, "binding", "value");
+                node.insert("kind".into(), "init".into());
+                node.insert("method".into(), false.into());
+                node.insert("shorthand".into(), false.into());
+                let computed = self.source_text.as_bytes().get(start as usize) == Some(&b'[');
+                node.insert("computed".into(), computed.into());
+                node
+            }
+            _ => self.rename(map, &ty),
+        };
+
+        match ty.as_str() {
+            "ImportExpression" => {
+                let options = match remove(&mut node, "arguments") {
+                    Some(Value::Array(mut arguments)) if !arguments.is_empty() => {
+                        arguments.swap_remove(0)
+                    }
+                    _ => Value::Null,
+                };
+                node.insert("options".into(), options);
+            }
+            "ExportDefaultDeclaration" => {
+                remove(&mut node, "exported");
+            }
+            _ => {}
+        }
+        remove(&mut node, "trailingComma");
+        if let Some(with_clause) = remove(&mut node, "withClause") {
+            let attributes = match with_clause {
+                Value::Object(mut with_clause) => remove(&mut with_clause, "attributes"),
+                _ => None,
+            };
+            node.insert("attributes".into(), attributes.unwrap_or_else(|| Value::Array(vec![])));
+        }
+        if let Some(Value::Array(modifiers)) = remove(&mut node, "modifiers") {
+            self.apply_modifiers(&mut node, &modifiers);
+        }
+        if node["type"] == "Literal" {
+            self.literal(&mut node, start, end);
+        }
+        if node["type"] == "JSXText" {
+            node.insert("raw".into(), self.source_slice(start, end).into());
+        }
+        if self.typescript {
+            typescript_defaults(&mut node);
+        } else {
+            strip_typescript(&mut node);
+        }
+        self.insert_positions(&mut node, start, end);
+
+        // `readonly` and `accessibility` on a binding pattern come from a formal parameter
+        if BINDING_TYPES.contains(&node["type"].as_str().unwrap_or_default()) {
+            let readonly = remove(&mut node, "readonly").is_some_and(|v| v == Value::Bool(true));
+            if self.typescript && (readonly || node.contains_key("accessibility")) {
+                let start = self.parameter_property_start(start);
+                return Value::Object(self.parameter_property(node, readonly, start, end));
+            }
+        }
+
+        Value::Object(node)
+    }
+
+    /// Convert children, camelCase keys and set the node type.
+    /// `type`, `start` and `end` are re-inserted by `insert_positions`.
+    fn rename(&self, map: Map<String, Value>, ty: &str) -> Map<String, Value> {
+        let mut node = Map::new();
+        node.insert("type".into(), ty.into());
+        for (key, value) in map {
+            if matches!(key.as_str(), "type" | "start" | "end") {
+                continue;
+            }
+            node.insert(camel_case(&key), self.convert(value));
+        }
+        node
+    }
+
+    /// `[a, ...b] = c` and `({ a, ...b } = c)`, the rest target becomes a trailing `RestElement`
+    fn assignment_target_pattern(
+        &self,
+        map: Map<String, Value>,
+        ty: &str,
+        list: &str,
+    ) -> Map<String, Value> {
+        let rest_span = map.get("rest").filter(|rest| !rest.is_null()).map(|rest| {
+            #[allow(clippy::cast_possible_truncation)]
+            let offset = |key| rest.get(key).and_then(Value::as_u64).unwrap_or_default() as u32;
+            (offset("start"), offset("end"))
+        });
+        let mut node = self.rename(map, ty);
+        if let (Some(argument), Some((argument_start, end))) =
+            (remove(&mut node, "rest"), rest_span)
+        {
+            // The rest target does not record the position of `...`
+            let start = self.source_text[..argument_start as usize]
+                .rfind("...")
+                .map_or(argument_start, |i| u32::try_from(i).unwrap_or(argument_start));
+            let mut rest_element = Map::new();
+            rest_element.insert("type".into(), "RestElement".into());
+            rest_element.insert("argument".into(), argument);
+            self.insert_positions(&mut rest_element, start, end);
+            if let Some(Value::Array(items)) = node.get_mut(list) {
+                items.push(Value::Object(rest_element));
+            }
+        } else {
+            remove(&mut node, "rest");
+        }
+        node
+    }
+
+    /// `({ a = 1 } = b)`
+    fn assignment_target_property_identifier(&self, map: Map<String, Value>) -> Map<String, Value> {
+        let (start, end) = (offset(&map, "start"), offset(&map, "end"));
+        let mut node = self.rename(map, "Property");
+        let key = remove(&mut node, "binding").unwrap_or_default();
+        let value = match remove(&mut node, "init") {
+            Some(init) if !init.is_null() => {
+                let mut pattern = Map::new();
+                pattern.insert("type".into(), "AssignmentPattern".into());
+                pattern.insert("left".into(), key.clone());
+                pattern.insert("right".into(), init);
+                self.insert_positions(&mut pattern, start, end);
+                Value::Object(pattern)
+            }
+            _ => key.clone(),
+        };
+        node.insert("kind".into(), "init".into());
+        node.insert("key".into(), key);
+        node.insert("value".into(), value);
+        node.insert("method".into(), false.into());
+        node.insert("shorthand".into(), true.into());
+        node.insert("computed".into(), false.into());
+        node
+    }
+
+    /// `constructor(private readonly x)`
+    fn parameter_property(
+        &self,
+        mut parameter: Map<String, Value>,
+        readonly: bool,
+        start: u32,
+        end: u32,
+    ) -> Map<String, Value> {
+        let mut node = Map::new();
+        node.insert("type".into(), "TSParameterProperty".into());
+        if let Some(accessibility) = remove(&mut parameter, "accessibility") {
+            node.insert("accessibility".into(), accessibility);
+        }
+        node.insert("readonly".into(), readonly.into());
+        if let Some(decorators) = parameter.get("decorators").cloned() {
+            node.insert("decorators".into(), decorators);
+        }
+        node.insert("parameter".into(), Value::Object(parameter));
+        self.insert_positions(&mut node, start, end);
+        node
+    }
+
+    /// The formal parameter span is not serialized, so walk back over the modifiers
+    /// preceding the pattern.
+    fn parameter_property_start(&self, start: u32) -> u32 {
+        let mut prefix = self.source_slice(0, start);
+        loop {
+            let trimmed = prefix.trim_end();
+            let modifier = ["public", "private", "protected", "readonly", "override"]
+                .into_iter()
+                .find(|modifier| {
+                    trimmed.strip_suffix(modifier).is_some_and(|rest| {
+                        !rest.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '$')
+                    })
+                });
+            match modifier {
+                Some(modifier) => prefix = &trimmed[..trimmed.len() - modifier.len()],
+                None => break,
+            }
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let start = prefix.len() as u32;
+        start
+    }
+
+    /// typescript-estree represents modifiers as boolean flags (`declare`, `const`, `abstract` ...)
+    /// and `accessibility`.
+    fn apply_modifiers(&self, node: &mut Map<String, Value>, modifiers: &[Value]) {
+        if !self.typescript {
+            return;
+        }
+        for modifier in modifiers {
+            let Some(kind) = modifier["kind"]["type"].as_str() else { continue };
+            match kind {
+                "export" | "default" | "in" | "out" | "accessor" => {}
+                "public" | "private" | "protected" => {
+                    node.insert("accessibility".into(), kind.into());
+                }
+                _ => {
+                    node.insert(kind.into(), true.into());
+                }
+            }
+        }
+    }
+
+    fn literal(&self, node: &mut Map<String, Value>, start: u32, end: u32) {
+        let raw = self.source_slice(start, end);
+        // `BigintLiteral` serializes its value as a string with a trailing `n`
+        if raw.ends_with('n') && !raw.starts_with(['"', '\'']) {
+            let bigint = node["value"].as_str().unwrap_or_default().trim_end_matches('n');
+            let bigint = bigint.to_string();
+            node.insert("value".into(), Value::Null);
+            node.insert("bigint".into(), bigint.into());
+        }
+        // Print integral numbers the way `JSON.stringify` does, `1` instead of `1.0`
+        if let Some(value) = node["value"].as_f64() {
+            #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+            if value.fract() == 0.0 && value.abs() < 2f64.powi(53) {
+                node.insert("value".into(), (value as i64).into());
+            }
+        }
+        node.insert("raw".into(), raw.into());
+    }
+
+    /// `start`, `end`, `range` and `loc`, all based on UTF-16 code units like JavaScript strings.
+    fn insert_positions(&self, node: &mut Map<String, Value>, start: u32, end: u32) {
+        let mut result = Map::new();
+        result.insert("type".into(), node.get("type").cloned().unwrap_or_default());
+        let (utf16_start, utf16_end) =
+            (self.positions.to_utf16(start), self.positions.to_utf16(end));
+        result.insert("start".into(), utf16_start.into());
+        result.insert("end".into(), utf16_end.into());
+        result.insert("range".into(), Value::Array(vec![utf16_start.into(), utf16_end.into()]));
+        let mut loc = Map::new();
+        loc.insert("start".into(), self.positions.location(start));
+        loc.insert("end".into(), self.positions.location(end));
+        result.insert("loc".into(), Value::Object(loc));
+        result.extend(std::mem::take(node).into_iter().filter(|(key, _)| key != "type"));
+        *node = result;
+    }
+
+    fn source_slice(&self, start: u32, end: u32) -> &'a str {
+        self.source_text.get(start as usize..end as usize).unwrap_or_default()
+    }
+}
+
+/// Fields typescript-estree always emits, but the oxc AST only records when they are present
+fn typescript_defaults(node: &mut Map<String, Value>) {
+    for key in ["importKind", "exportKind"] {
+        if let Some(kind) = node.get_mut(key).filter(|kind| kind.is_null()) {
+            *kind = "value".into();
+        }
+    }
+    let ty = node["type"].as_str().unwrap_or_default();
+    let mut defaults = vec![];
+    if DECLARE_TYPES.contains(&ty) {
+        defaults.push(("declare", Value::Bool(false)));
+    }
+    match ty {
+        "ClassDeclaration" | "ClassExpression" => {
+            defaults.push(("abstract", Value::Bool(false)));
+        }
+        "TSEnumDeclaration" => defaults.push(("const", Value::Bool(false))),
+        _ => {}
+    }
+    if BINDING_TYPES.contains(&ty) {
+        defaults.push(("decorators", Value::Array(vec![])));
+        defaults.push(("optional", Value::Bool(false)));
+    }
+    for (key, value) in defaults {
+        if !node.contains_key(key) {
+            node.insert(key.into(), value);
+        }
+    }
+}
+
+fn strip_typescript(node: &mut Map<String, Value>) {
+    let keep_optional =
+        matches!(node["type"].as_str(), Some("MemberExpression" | "CallExpression"));
+    for key in TS_ONLY_KEYS {
+        if key == "optional" && keep_optional {
+            continue;
+        }
+        let is_empty = match node.get(key) {
+            Some(Value::Null | Value::Bool(false)) => true,
+            Some(Value::Array(array)) => array.is_empty(),
+            _ => false,
+        };
+        if is_empty {
+            remove(node, key);
+        }
+    }
+    remove(node, "importKind");
+    remove(node, "exportKind");
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn offset(map: &Map<String, Value>, key: &str) -> u32 {
+    map.get(key).and_then(Value::as_u64).unwrap_or_default() as u32
+}
+
+/// `Map::remove` does not keep the insertion order of the remaining keys
+fn remove(node: &mut Map<String, Value>, key: &str) -> Option<Value> {
+    let value = node.get_mut(key).map(Value::take);
+    if value.is_some() {
+        node.retain(|k, _| k != key);
+    }
+    value
+}
+
+fn rename_key(node: &mut Map<String, Value>, from: &str, to: &str) {
+    *node = std::mem::take(node)
+        .into_iter()
+        .map(|(key, value)| if key == from { (to.to_string(), value) } else { (key, value) })
+        .collect();
+}
+
+fn camel_case(key: &str) -> String {
+    if !key.contains('_') {
+        return key.to_string();
+    }
+    let mut result = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            result.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Converts UTF-8 byte offsets into UTF-16 offsets and line / column locations
+struct Positions {
+    /// Byte offsets of the start of each line
+    line_starts: Vec<u32>,
+    /// UTF-16 offset of every byte offset, `None` for ASCII only source text
+    utf16_offsets: Option<Vec<u32>>,
+}
+
+impl Positions {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(source_text: &str) -> Self {
+        let mut line_starts = vec![0];
+        let bytes = source_text.as_bytes();
+        for (i, c) in source_text.char_indices() {
+            let next = (i + c.len_utf8()) as u32;
+            match c {
+                '\r' if bytes.get(i + 1) == Some(&b'\n') => {}
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(next),
+                _ => {}
+            }
+        }
+
+        let utf16_offsets = (!source_text.is_ascii()).then(|| {
+            let mut offsets = Vec::with_capacity(bytes.len() + 1);
+            let mut utf16 = 0;
+            for c in source_text.chars() {
+                for _ in 0..c.len_utf8() {
+                    offsets.push(utf16);
+                }
+                utf16 += c.len_utf16() as u32;
+            }
+            offsets.push(utf16);
+            offsets
+        });
+
+        Self { line_starts, utf16_offsets }
+    }
+
+    fn to_utf16(&self, offset: u32) -> u32 {
+        self.utf16_offsets
+            .as_ref()
+            .map_or(offset, |offsets| offsets.get(offset as usize).copied().unwrap_or(offset))
+    }
+
+    /// estree `Position`: 1-based line and 0-based column
+    fn location(&self, offset: u32) -> Value {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self.to_utf16(offset) - self.to_utf16(line_start);
+        let mut position = Map::new();
+        position.insert("line".into(), line.into());
+        position.insert("column".into(), column.into());
+        Value::Object(position)
+    }
+}
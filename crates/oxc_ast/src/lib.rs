@@ -1,10 +1,16 @@
 //! AST
-//! NOTE: This is not compatible with estree.
+//! NOTE: The AST is not compatible with estree,
+//! use `Program::to_estree_json` (behind the `serde_json` feature) for an ESTree compatible output.
 
 #![feature(let_chains)]
 #![feature(is_some_and)]
 
+mod clone_in;
+mod content_eq;
+#[cfg(feature = "serde_json")]
+mod estree;
 mod serialize;
+mod shift_spans;
 
 pub mod ast;
 pub mod ast_builder;
@@ -13,11 +19,16 @@ pub mod context;
 pub mod source_type;
 pub mod span;
 pub mod syntax_directed_operations;
+pub mod traverse;
 pub mod trivia;
 pub mod visit;
+pub mod visit_mut;
 
-pub use ast_kind::AstKind;
+pub use ast_kind::{AstKind, AstType};
+pub use clone_in::CloneIn;
+pub use content_eq::{ContentEq, ContentHash};
 pub use num_bigint::BigUint;
+pub use shift_spans::ShiftSpans;
 
 pub use crate::ast_builder::*;
 pub use crate::source_type::*;
@@ -0,0 +1,62 @@
+//! Moving the spans of AST nodes.
+
+use num_bigint::BigUint;
+use ordered_float::NotNan;
+use oxc_allocator::{Box, Vec};
+
+use crate::{ast::RegExpFlags, Atom, SourceType, Span};
+
+/// Move every span of a node by the same offset.
+///
+/// Used by incremental parsing to re-use the nodes after an edit,
+/// which keep their source text but not their position.
+pub trait ShiftSpans {
+    fn shift_spans(&mut self, delta: i64);
+}
+
+impl ShiftSpans for Span {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift_spans(&mut self, delta: i64) {
+        self.start = (i64::from(self.start) + delta) as u32;
+        self.end = (i64::from(self.end) + delta) as u32;
+    }
+}
+
+impl<'a, T: ShiftSpans> ShiftSpans for Box<'a, T> {
+    fn shift_spans(&mut self, delta: i64) {
+        self.0.shift_spans(delta);
+    }
+}
+
+impl<'a, T: ShiftSpans> ShiftSpans for Vec<'a, T> {
+    fn shift_spans(&mut self, delta: i64) {
+        self.iter_mut().for_each(|item| item.shift_spans(delta));
+    }
+}
+
+impl<T: ShiftSpans> ShiftSpans for Option<T> {
+    fn shift_spans(&mut self, delta: i64) {
+        if let Some(value) = self {
+            value.shift_spans(delta);
+        }
+    }
+}
+
+/// Types without spans.
+macro_rules! impl_shift_spans {
+    ($($ty:ty),*) => {
+        $(
+            impl ShiftSpans for $ty {
+                #[inline]
+                fn shift_spans(&mut self, _: i64) {}
+            }
+        )*
+    };
+}
+
+impl_shift_spans!(bool, u32, f64, NotNan<f64>, Atom, BigUint, SourceType, RegExpFlags);
+
+impl<'a> ShiftSpans for &'a str {
+    #[inline]
+    fn shift_spans(&mut self, _: i64) {}
+}
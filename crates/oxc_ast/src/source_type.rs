@@ -6,7 +6,7 @@ use crate::context::Context;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SourceType {
-    /// JavaScript or TypeScript, default JavaScript
+    /// JavaScript, TypeScript, Flow or JSON, default JavaScript
     language: Language,
 
     /// Script or Module, default Module
@@ -19,7 +19,23 @@ pub struct SourceType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     JavaScript,
-    TypeScript { is_definition_file: bool },
+    TypeScript {
+        is_definition_file: bool,
+    },
+    /// JavaScript with Flow type annotations, which are parsed into TypeScript nodes
+    Flow,
+    /// A JSON document, which is parsed into a JSON value instead of a program
+    Json(JsonKind),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JsonKind {
+    /// [RFC 8259](https://www.rfc-editor.org/rfc/rfc8259) JSON
+    Json,
+    /// JSON with comments and trailing commas, e.g. `tsconfig.json`
+    Jsonc,
+    /// [JSON5](https://spec.json5.org/)
+    Json5,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -50,7 +66,8 @@ impl Default for SourceType {
     }
 }
 
-pub const VALID_EXTENSIONS: [&str; 8] = ["js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx"];
+pub const VALID_EXTENSIONS: [&str; 11] =
+    ["js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx", "json", "jsonc", "json5"];
 
 impl SourceType {
     #[must_use]
@@ -58,8 +75,9 @@ impl SourceType {
         let ctx = Context::default().and_ambient(self.is_typescript_definition());
         match self.module_kind {
             ModuleKind::Script => ctx,
-            // for [top-level-await](https://tc39.es/proposal-top-level-await/)
-            ModuleKind::Module => ctx.and_await(true),
+            // for [top-level-await](https://tc39.es/proposal-top-level-await/),
+            // module code is always strict mode code
+            ModuleKind::Module => ctx.and_await(true).and_strict(true),
         }
     }
 
@@ -84,6 +102,14 @@ impl SourceType {
         self.language = Language::TypeScript { is_definition_file: true };
     }
 
+    pub fn set_flow(&mut self) {
+        self.language = Language::Flow;
+    }
+
+    pub fn set_json(&mut self, kind: JsonKind) {
+        self.language = Language::Json(kind);
+    }
+
     pub fn set_jsx(&mut self) {
         self.variant = LanguageVariant::Jsx;
     }
@@ -118,6 +144,24 @@ impl SourceType {
         matches!(self.language, Language::TypeScript { is_definition_file: true })
     }
 
+    #[must_use]
+    pub fn is_flow(self) -> bool {
+        matches!(self.language, Language::Flow)
+    }
+
+    #[must_use]
+    pub fn is_json(self) -> bool {
+        matches!(self.language, Language::Json(_))
+    }
+
+    #[must_use]
+    pub fn json_kind(self) -> Option<JsonKind> {
+        match self.language {
+            Language::Json(kind) => Some(kind),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn is_jsx(self) -> bool {
         self.variant == LanguageVariant::Jsx
@@ -126,7 +170,11 @@ impl SourceType {
     /// Converts file path to `SourceType`
     /// returns `SourceTypeError::UnkownExtension` if:
     ///   * there is no file name
-    ///   * the file extension is not one of "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx"
+    ///   * the file extension is not one of "js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx",
+    ///     "json", "jsonc", "json5"
+    ///
+    /// `tsconfig.json`, `jsconfig.json` and `.eslintrc.json` are read as JSONC by their tools,
+    /// and are JSONC here as well.
     /// # Errors
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, SourceTypeError> {
         let file_name =
@@ -142,7 +190,7 @@ impl SourceType {
             .ok_or_else(|| {
                 let path = path.as_ref().to_string_lossy();
                 SourceTypeError::UnkownExtension(
-                    format!("Please provide a valid file extension for {path}: .js, .mjs, .jsx or .cjs for JavaScript, or .ts, .mts, .cts or .tsx for TypeScript"),
+                    format!("Please provide a valid file extension for {path}: .js, .mjs, .jsx or .cjs for JavaScript, .ts, .mts, .cts or .tsx for TypeScript, or .json, .jsonc or .json5 for JSON"),
                 )
             })?;
 
@@ -153,6 +201,10 @@ impl SourceType {
         let language = match extension {
             "js" | "mjs" | "cjs" | "jsx" => Language::JavaScript,
             "ts" | "mts" | "cts" | "tsx" => Language::TypeScript { is_definition_file },
+            "json" if is_jsonc_config(file_name) => Language::Json(JsonKind::Jsonc),
+            "json" => Language::Json(JsonKind::Json),
+            "jsonc" => Language::Json(JsonKind::Jsonc),
+            "json5" => Language::Json(JsonKind::Json5),
             _ => unreachable!(),
         };
 
@@ -165,6 +217,12 @@ impl SourceType {
     }
 }
 
+/// `tsconfig.json`, `tsconfig.*.json`, `jsconfig.json` and `.eslintrc.json`
+fn is_jsonc_config(file_name: &str) -> bool {
+    file_name == ".eslintrc.json"
+        || ["tsconfig.", "jsconfig."].iter().any(|prefix| file_name.starts_with(prefix))
+}
+
 #[derive(Default)]
 pub struct SourceTypeBuilder {
     source_type: SourceType,
@@ -210,4 +268,18 @@ impl SourceTypeBuilder {
         self.source_type.language = Language::TypeScript { is_definition_file: true };
         self
     }
+
+    /// turn on `Language::Flow`
+    #[must_use]
+    pub fn flow(mut self) -> Self {
+        self.source_type.language = Language::Flow;
+        self
+    }
+
+    /// turn on `Language::Json`
+    #[must_use]
+    pub fn json(mut self, kind: JsonKind) -> Self {
+        self.source_type.language = Language::Json(kind);
+        self
+    }
 }
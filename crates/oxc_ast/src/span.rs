@@ -73,6 +73,7 @@ impl<'a> GetSpan for Statement<'a> {
             Self::TryStatement(stmt) => stmt.span,
             Self::WhileStatement(stmt) => stmt.span,
             Self::WithStatement(stmt) => stmt.span,
+            Self::InvalidStatement(stmt) => stmt.span,
             Self::ModuleDeclaration(decl) => decl.span,
             Self::Declaration(decl) => decl.span(),
         }
@@ -159,6 +160,7 @@ impl<'a> GetSpan for ClassElement<'a> {
             Self::TSAbstractMethodDefinition(def) => def.method_definition.span,
             Self::TSAbstractPropertyDefinition(def) => def.property_definition.span,
             Self::TSIndexSignature(sig) => sig.span,
+            Self::InvalidClassElement(elem) => elem.span,
         }
     }
 }
@@ -212,6 +214,7 @@ impl<'a> GetSpan for Declaration<'a> {
             Self::TSEnumDeclaration(decl) => decl.span,
             Self::TSModuleDeclaration(decl) => decl.span,
             Self::TSImportEqualsDeclaration(decl) => decl.span,
+            Self::FlowOpaqueType(decl) => decl.span,
         }
     }
 }
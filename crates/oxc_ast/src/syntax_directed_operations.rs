@@ -248,7 +248,9 @@ pub trait PrivateBoundIdentifiers {
 impl<'a> PrivateBoundIdentifiers for ClassElement<'a> {
     fn private_bound_identifiers(&self) -> Option<PrivateIdentifier> {
         match self {
-            ClassElement::StaticBlock(_) | ClassElement::TSIndexSignature(_) => None,
+            ClassElement::StaticBlock(_)
+            | ClassElement::TSIndexSignature(_)
+            | ClassElement::InvalidClassElement(_) => None,
             ClassElement::MethodDefinition(def) => def.private_bound_identifiers(),
             ClassElement::PropertyDefinition(def) => def.private_bound_identifiers(),
             ClassElement::AccessorProperty(def) => def.private_bound_identifiers(),
@@ -0,0 +1,542 @@
+//! AST traversal with ancestor context, built on top of [`VisitMut`].
+//!
+//! Similar to Babel's `NodePath`, a [`Traverse`] implementation receives a [`TraverseCtx`]
+//! which knows the ancestors of the current node and the field of the parent holding it,
+//! and can insert statements before or after the current statement, or remove it.
+//! Nodes are replaced by assigning through the `&mut` reference given to the callbacks.
+
+use oxc_allocator::{Allocator, Vec};
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, ast_kind::AstType, visit_mut::VisitMut, AstBuilder, GetSpan};
+
+#[allow(unused_variables)]
+pub trait Traverse<'a> {
+    fn enter_node(&mut self, kind: AstType, ctx: &mut TraverseCtx<'a>) {}
+    fn leave_node(&mut self, kind: AstType, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {}
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {}
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {}
+}
+
+/// An ancestor of the current node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ancestor {
+    pub kind: AstType,
+    /// Field of the parent node holding this node, e.g. `callee` or `arguments`,
+    /// named after the field of the AST struct.
+    /// `None` for the root and for the content of an enum node such as [`Argument`]
+    /// or [`PropertyKey`], which is held by the enum node itself.
+    pub field: Option<&'static str>,
+    /// Index in the enclosing statement list (`Program::body`, `BlockStatement::body` ...)
+    /// when this node is one of its statements.
+    pub slot: Option<usize>,
+}
+
+pub struct TraverseCtx<'a> {
+    pub ast: AstBuilder<'a>,
+    /// Nodes entered but not left yet, from the root.
+    ancestors: std::vec::Vec<Ancestor>,
+    /// Field of the parent holding the node about to be entered.
+    field: Option<&'static str>,
+    /// One entry per statement list being visited, and per statement visited outside of one,
+    /// the innermost is last.
+    statement_lists: std::vec::Vec<StatementList<'a>>,
+}
+
+/// Edits requested for the statement currently being visited.
+struct StatementList<'a> {
+    /// Index of the statement in the list, `None` for a statement outside of a list.
+    index: Option<usize>,
+    /// Set until the statement is entered, so it is recorded as the statement's slot.
+    pending_slot: bool,
+    before: std::vec::Vec<Statement<'a>>,
+    after: std::vec::Vec<Statement<'a>>,
+    remove: bool,
+}
+
+impl<'a> StatementList<'a> {
+    fn new(index: Option<usize>) -> Self {
+        Self { index, pending_slot: false, before: vec![], after: vec![], remove: false }
+    }
+
+    /// Apply the edits to a statement outside of a list, by replacing it with a block
+    /// holding the inserted statements, or with an empty statement when it is removed.
+    fn apply(self, stmt: &mut Statement<'a>, ast: &AstBuilder<'a>) {
+        if self.before.is_empty() && self.after.is_empty() {
+            if self.remove {
+                *stmt = ast.empty_statement(stmt.span());
+            }
+            return;
+        }
+        let span = stmt.span();
+        let old = std::mem::replace(stmt, ast.empty_statement(span));
+        let mut body = ast.new_vec_with_capacity(self.before.len() + 1 + self.after.len());
+        body.extend(self.before);
+        if !self.remove {
+            body.push(old);
+        }
+        body.extend(self.after);
+        *stmt = ast.block_statement(ast.block(span, body));
+    }
+}
+
+impl<'a> TraverseCtx<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            ancestors: std::vec::Vec::new(),
+            field: None,
+            statement_lists: std::vec::Vec::new(),
+        }
+    }
+
+    /// Ancestors of the node passed to the callback, from the root.
+    #[must_use]
+    pub fn ancestors(&self) -> &[Ancestor] {
+        &self.ancestors
+    }
+
+    #[must_use]
+    pub fn parent(&self) -> Option<Ancestor> {
+        self.ancestors.last().copied()
+    }
+
+    /// Field of the parent holding the statement or expression passed to the callback,
+    /// see [`Ancestor::field`].
+    #[must_use]
+    pub fn field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    /// Find the closest ancestor of the given kind.
+    #[must_use]
+    pub fn find_ancestor(&self, kind: AstType) -> Option<Ancestor> {
+        self.ancestors.iter().rev().find(|ancestor| ancestor.kind == kind).copied()
+    }
+
+    /// Index of the current statement in its statement list,
+    /// `None` when it is not held by one, e.g. a loop body or an `if` consequent.
+    #[must_use]
+    pub fn statement_index(&self) -> Option<usize> {
+        self.statement_lists.last().and_then(|list| list.index)
+    }
+
+    /// Insert a statement before the current statement.
+    /// When called while visiting an expression, this is the statement containing it.
+    ///
+    /// When the current statement is not held by a statement list, e.g. the body of a loop
+    /// or the consequent of an `if`, it is wrapped in a block with the inserted statements.
+    ///
+    /// # Panics
+    ///
+    /// * When called outside of any statement, e.g. from `enter_node` of the `Program`.
+    pub fn insert_before(&mut self, stmt: Statement<'a>) {
+        self.statement_list().before.push(stmt);
+    }
+
+    /// Insert a statement after the current statement.
+    /// Inserted statements are not visited, see [`Self::insert_before`].
+    ///
+    /// # Panics
+    ///
+    /// * When called outside of any statement.
+    pub fn insert_after(&mut self, stmt: Statement<'a>) {
+        self.statement_list().after.push(stmt);
+    }
+
+    /// Remove the current statement once it has been visited.
+    /// A statement which is not held by a statement list is replaced by an empty statement.
+    ///
+    /// # Panics
+    ///
+    /// * When called outside of any statement.
+    pub fn remove_statement(&mut self) {
+        self.statement_list().remove = true;
+    }
+
+    fn statement_list(&mut self) -> &mut StatementList<'a> {
+        self.statement_lists.last_mut().expect("not inside a statement")
+    }
+}
+
+/// Traverse the program with ancestor context.
+pub fn traverse_mut<'a, T: Traverse<'a>>(
+    traverser: &mut T,
+    program: &mut Program<'a>,
+    allocator: &'a Allocator,
+) {
+    let mut driver = TraverseDriver { traverser, ctx: TraverseCtx::new(allocator) };
+    driver.visit_program(program);
+}
+
+struct TraverseDriver<'a, 't, T: Traverse<'a>> {
+    traverser: &'t mut T,
+    ctx: TraverseCtx<'a>,
+}
+
+impl<'a, 't, T: Traverse<'a>> VisitMut<'a> for TraverseDriver<'a, 't, T> {
+    fn enter_node(&mut self, kind: AstType) {
+        let slot = self.ctx.statement_lists.last_mut().and_then(|list| {
+            if std::mem::take(&mut list.pending_slot) {
+                list.index
+            } else {
+                None
+            }
+        });
+        self.traverser.enter_node(kind, &mut self.ctx);
+        let field = self.ctx.field.take();
+        self.ctx.ancestors.push(Ancestor { kind, field, slot });
+    }
+
+    fn leave_node(&mut self, kind: AstType) {
+        self.ctx.ancestors.pop();
+        // not taken when the last child entered no node
+        self.ctx.field = None;
+        self.traverser.leave_node(kind, &mut self.ctx);
+    }
+
+    fn enter_field(&mut self, field: &'static str) {
+        self.ctx.field = Some(field);
+    }
+
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let field = self.ctx.field.take();
+        let old_stmts = self.ctx.ast.move_statement_vec(stmts);
+        self.ctx.statement_lists.push(StatementList::new(Some(0)));
+        for (index, mut stmt) in old_stmts.into_iter().enumerate() {
+            let list = self.ctx.statement_list();
+            list.index = Some(index);
+            list.pending_slot = true;
+            self.ctx.field = field;
+            self.visit_statement(&mut stmt);
+            let list = self.ctx.statement_list();
+            list.pending_slot = false;
+            stmts.extend(list.before.drain(..));
+            if !std::mem::take(&mut list.remove) {
+                stmts.push(stmt);
+            }
+            stmts.extend(list.after.drain(..));
+        }
+        self.ctx.statement_lists.pop();
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        // A statement not directly held by a statement list, e.g. a loop body,
+        // gets its own list so edits are applied to it instead of the enclosing one.
+        let in_list = self.ctx.statement_lists.last().is_some_and(|list| list.pending_slot);
+        if !in_list {
+            self.ctx.statement_lists.push(StatementList::new(None));
+        }
+        self.traverser.enter_statement(stmt, &mut self.ctx);
+        self.visit_statement_match(stmt);
+        self.traverser.exit_statement(stmt, &mut self.ctx);
+        if !in_list {
+            let list = self.ctx.statement_lists.pop().unwrap();
+            list.apply(stmt, &self.ctx.ast);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.traverser.enter_expression(expr, &mut self.ctx);
+        self.visit_expression_match(expr);
+        self.traverser.exit_expression(expr, &mut self.ctx);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::{traverse_mut, Ancestor, Traverse, TraverseCtx};
+    #[allow(clippy::wildcard_imports)]
+    use crate::{ast::*, AstBuilder, AstType, SourceType, Span};
+
+    #[derive(Default)]
+    struct Transformer {
+        ancestors: Vec<Ancestor>,
+        field: Option<&'static str>,
+    }
+
+    impl<'a> Traverse<'a> for Transformer {
+        fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+            let Expression::Identifier(ident) = expr else { return };
+            match ident.name.as_str() {
+                "a" => ctx.remove_statement(),
+                "b" => {
+                    self.ancestors = ctx.ancestors().to_vec();
+                    self.field = ctx.field();
+                    ctx.insert_before(ctx.ast.debugger_statement(Span::default()));
+                    // replace the current node
+                    *expr = ctx.ast.this_expression(Span::default());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// `a; { b; }`
+    fn program<'a>(ast: &AstBuilder<'a>) -> Program<'a> {
+        let expression_statement = |name: &str| {
+            let ident = IdentifierReference { span: Span::default(), name: name.into() };
+            ast.expression_statement(Span::default(), ast.identifier_expression(ident))
+        };
+        let block = ast.block(Span::default(), ast.new_vec_single(expression_statement("b")));
+        let mut body = ast.new_vec();
+        body.push(expression_statement("a"));
+        body.push(ast.block_statement(block));
+        ast.program(Span::default(), ast.new_vec(), body, SourceType::default())
+    }
+
+    #[test]
+    fn ancestors_and_statement_edits() {
+        let allocator = Allocator::default();
+        let mut program = program(&AstBuilder::new(&allocator));
+        let mut transformer = Transformer::default();
+        traverse_mut(&mut transformer, &mut program, &allocator);
+
+        assert_eq!(
+            transformer.ancestors,
+            vec![
+                Ancestor { kind: AstType::Program, field: None, slot: None },
+                Ancestor { kind: AstType::BlockStatement, field: Some("body"), slot: Some(1) },
+                Ancestor { kind: AstType::ExpressionStatement, field: Some("body"), slot: Some(0) },
+            ]
+        );
+        assert_eq!(transformer.field, Some("expression"));
+
+        // `{ debugger; this; }`
+        assert_eq!(program.body.len(), 1);
+        let Statement::BlockStatement(block) = &program.body[0] else { unreachable!() };
+        assert!(matches!(block.body[0], Statement::DebuggerStatement(_)));
+        let Statement::ExpressionStatement(stmt) = &block.body[1] else { unreachable!() };
+        assert!(matches!(stmt.expression, Expression::ThisExpression(_)));
+    }
+
+    fn transform<'a>(
+        ast: &AstBuilder<'a>,
+        stmt: Statement<'a>,
+        allocator: &'a Allocator,
+    ) -> (Program<'a>, Transformer) {
+        let body = ast.new_vec_single(stmt);
+        let mut program = ast.program(Span::default(), ast.new_vec(), body, SourceType::default());
+        let mut transformer = Transformer::default();
+        traverse_mut(&mut transformer, &mut program, allocator);
+        (program, transformer)
+    }
+
+    fn expression_statement<'a>(ast: &AstBuilder<'a>, name: &str) -> Statement<'a> {
+        ast.expression_statement(Span::default(), identifier(ast, name))
+    }
+
+    #[test]
+    fn loop_body_edits() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        // `while (c) b;`
+        let stmt = ast.while_statement(
+            Span::default(),
+            identifier(&ast, "c"),
+            expression_statement(&ast, "b"),
+        );
+        let (program, transformer) = transform(&ast, stmt, &allocator);
+
+        assert_eq!(
+            transformer.ancestors,
+            vec![
+                Ancestor { kind: AstType::Program, field: None, slot: None },
+                Ancestor { kind: AstType::WhileStatement, field: Some("body"), slot: Some(0) },
+                Ancestor { kind: AstType::ExpressionStatement, field: Some("body"), slot: None },
+            ]
+        );
+        assert_eq!(transformer.field, Some("expression"));
+
+        // `while (c) { debugger; this; }`
+        assert_eq!(program.body.len(), 1);
+        let Statement::WhileStatement(stmt) = &program.body[0] else { unreachable!() };
+        let Statement::BlockStatement(block) = &stmt.body else { unreachable!() };
+        assert_eq!(block.body.len(), 2);
+        assert!(matches!(block.body[0], Statement::DebuggerStatement(_)));
+        let Statement::ExpressionStatement(stmt) = &block.body[1] else { unreachable!() };
+        assert!(matches!(stmt.expression, Expression::ThisExpression(_)));
+    }
+
+    #[test]
+    fn if_consequent_edits() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        // `if (c) a; else b;`
+        let stmt = ast.if_statement(
+            Span::default(),
+            identifier(&ast, "c"),
+            expression_statement(&ast, "a"),
+            Some(expression_statement(&ast, "b")),
+        );
+        let (program, transformer) = transform(&ast, stmt, &allocator);
+
+        assert_eq!(
+            transformer.ancestors,
+            vec![
+                Ancestor { kind: AstType::Program, field: None, slot: None },
+                Ancestor { kind: AstType::IfStatement, field: Some("body"), slot: Some(0) },
+                Ancestor {
+                    kind: AstType::ExpressionStatement,
+                    field: Some("alternate"),
+                    slot: None
+                },
+            ]
+        );
+
+        // `if (c) ; else { debugger; this; }`, the `if` itself is kept
+        assert_eq!(program.body.len(), 1);
+        let Statement::IfStatement(stmt) = &program.body[0] else { unreachable!() };
+        assert!(matches!(stmt.consequent, Statement::EmptyStatement(_)));
+        let Some(Statement::BlockStatement(block)) = &stmt.alternate else { unreachable!() };
+        assert_eq!(block.body.len(), 2);
+        assert!(matches!(block.body[0], Statement::DebuggerStatement(_)));
+    }
+
+    /// Records every node entered with the field of its parent holding it.
+    #[derive(Default)]
+    struct Recorder {
+        nodes: Vec<(AstType, Option<&'static str>)>,
+        /// Ancestors of `x` and the field holding it
+        x: Option<(Vec<Ancestor>, Option<&'static str>)>,
+    }
+
+    impl<'a> Traverse<'a> for Recorder {
+        fn enter_node(&mut self, kind: AstType, ctx: &mut TraverseCtx<'a>) {
+            self.nodes.push((kind, ctx.field()));
+        }
+
+        fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+            if matches!(expr, Expression::Identifier(ident) if ident.name == "x") {
+                self.x = Some((ctx.ancestors().to_vec(), ctx.field()));
+            }
+        }
+    }
+
+    fn identifier<'a>(ast: &AstBuilder<'a>, name: &str) -> Expression<'a> {
+        ast.identifier_expression(IdentifierReference { span: Span::default(), name: name.into() })
+    }
+
+    fn record<'a>(ast: &AstBuilder<'a>, stmt: Statement<'a>, allocator: &'a Allocator) -> Recorder {
+        let body = ast.new_vec_single(stmt);
+        let mut program = ast.program(Span::default(), ast.new_vec(), body, SourceType::default());
+        let mut recorder = Recorder::default();
+        traverse_mut(&mut recorder, &mut program, allocator);
+        recorder
+    }
+
+    #[test]
+    fn member_and_call_fields() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        // `a.b(c[d])`
+        let property = IdentifierName { span: Span::default(), name: "b".into() };
+        let callee =
+            ast.static_member_expression(Span::default(), identifier(&ast, "a"), property, false);
+        let argument = ast.computed_member_expression(
+            Span::default(),
+            identifier(&ast, "c"),
+            identifier(&ast, "d"),
+            false,
+        );
+        let arguments = ast.new_vec_single(Argument::Expression(argument));
+        let call = ast.call_expression(Span::default(), callee, arguments, false, None);
+        let recorder = record(&ast, ast.expression_statement(Span::default(), call), &allocator);
+
+        assert_eq!(
+            recorder.nodes,
+            vec![
+                (AstType::Program, None),
+                (AstType::ExpressionStatement, Some("body")),
+                (AstType::CallExpression, Some("expression")),
+                (AstType::Argument, Some("arguments")),
+                (AstType::MemberExpression, None),
+                (AstType::IdentifierReference, Some("object")),
+                (AstType::IdentifierReference, Some("expression")),
+                (AstType::MemberExpression, Some("callee")),
+                (AstType::IdentifierReference, Some("object")),
+                (AstType::IdentifierName, Some("property")),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_function_fields() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        // `function f() { return () => { x; }; }`
+        let arrow_body = ast.function_body(
+            Span::default(),
+            ast.new_vec(),
+            ast.new_vec_single(ast.expression_statement(Span::default(), identifier(&ast, "x"))),
+        );
+        let params = || {
+            ast.formal_parameters(
+                Span::default(),
+                FormalParameterKind::ArrowFormalParameters,
+                ast.new_vec(),
+            )
+        };
+        let arrow = ast.arrow_expression(
+            Span::default(),
+            false,
+            false,
+            false,
+            params(),
+            arrow_body,
+            None,
+            None,
+        );
+        let body = ast.function_body(
+            Span::default(),
+            ast.new_vec(),
+            ast.new_vec_single(ast.return_statement(Span::default(), Some(arrow))),
+        );
+        let func = ast.function(
+            FunctionType::FunctionDeclaration,
+            Span::default(),
+            Some(BindingIdentifier { span: Span::default(), name: "f".into() }),
+            false,
+            false,
+            false,
+            params(),
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let recorder = record(&ast, ast.function_declaration(func), &allocator);
+
+        assert!(recorder.nodes.contains(&(AstType::BindingIdentifier, Some("id"))));
+        assert!(recorder.nodes.contains(&(AstType::FormalParameters, Some("params"))));
+        let (ancestors, field) = recorder.x.unwrap();
+        assert_eq!(
+            ancestors,
+            vec![
+                Ancestor { kind: AstType::Program, field: None, slot: None },
+                Ancestor { kind: AstType::Function, field: Some("body"), slot: Some(0) },
+                Ancestor { kind: AstType::FunctionBody, field: Some("body"), slot: None },
+                Ancestor {
+                    kind: AstType::ReturnStatement,
+                    field: Some("statements"),
+                    slot: Some(0)
+                },
+                Ancestor { kind: AstType::ArrowExpression, field: Some("argument"), slot: None },
+                Ancestor { kind: AstType::FunctionBody, field: Some("body"), slot: None },
+                Ancestor {
+                    kind: AstType::ExpressionStatement,
+                    field: Some("statements"),
+                    slot: Some(0),
+                },
+            ]
+        );
+        assert_eq!(field, Some("expression"));
+    }
+}
@@ -12,13 +12,12 @@ pub struct Trivias {
 }
 
 #[derive(Debug, Clone, Copy)]
-#[allow(unused)]
 pub struct Comment {
     kind: CommentKind,
     end: u32,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CommentKind {
     SingleLine,
     MultiLine,
@@ -29,6 +28,17 @@ impl Comment {
     pub fn new(end: u32, kind: CommentKind) -> Self {
         Self { kind, end }
     }
+
+    #[must_use]
+    pub fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// End of the comment text, before the closing `*/` of multi-line comments.
+    #[must_use]
+    pub fn end(&self) -> u32 {
+        self.end
+    }
 }
 
 impl Trivias {
@@ -37,6 +47,11 @@ impl Trivias {
         self.comments.range(span.start..span.end).count() > 0
     }
 
+    /// All comments in source order, spans cover the comment text without the `//`, `/*` and `*/`.
+    pub fn comments(&self) -> impl Iterator<Item = (Span, CommentKind)> + '_ {
+        self.comments.iter().map(|(start, comment)| (Span::new(*start, comment.end), comment.kind))
+    }
+
     pub fn add_comment(&mut self, span: Span, kind: CommentKind) {
         let comment = Comment::new(span.end, kind);
         self.comments.insert(span.start, comment);
@@ -54,6 +54,7 @@ pub trait Visit<'a>: Sized {
             Statement::TryStatement(stmt) => self.visit_try_statement(stmt),
             Statement::WhileStatement(stmt) => self.visit_while_statement(stmt),
             Statement::WithStatement(stmt) => self.visit_with_statement(stmt),
+            Statement::InvalidStatement(stmt) => self.visit_invalid_statement(stmt),
 
             Statement::ModuleDeclaration(decl) => self.visit_module_declaration(decl),
             Statement::Declaration(decl) => self.visit_declaration(decl),
@@ -268,6 +269,12 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_invalid_statement(&mut self, stmt: &'a InvalidStatement) {
+        let kind = AstKind::InvalidStatement(stmt);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
     fn visit_directive(&mut self, directive: &'a Directive<'a>) {
         let kind = AstKind::Directive(directive);
         self.enter_node(kind);
@@ -396,7 +403,7 @@ pub trait Visit<'a>: Sized {
             ClassElement::StaticBlock(block) => self.visit_static_block(block),
             ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
             ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
-            ClassElement::AccessorProperty(_def) => { /* TODO */ }
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
             ClassElement::TSAbstractMethodDefinition(def) => {
                 self.visit_method_definition(&def.method_definition);
             }
@@ -404,6 +411,7 @@ pub trait Visit<'a>: Sized {
                 self.visit_property_definition(&def.property_definition);
             }
             ClassElement::TSIndexSignature(_def) => {}
+            ClassElement::InvalidClassElement(elem) => self.visit_invalid_class_element(elem),
         }
     }
 
@@ -414,6 +422,12 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_invalid_class_element(&mut self, elem: &'a InvalidClassElement) {
+        let kind = AstKind::InvalidClassElement(elem);
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
     fn visit_method_definition(&mut self, def: &'a MethodDefinition<'a>) {
         let kind = AstKind::MethodDefinition(def);
         self.enter_node(kind);
@@ -425,6 +439,16 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_accessor_property(&mut self, def: &'a AccessorProperty<'a>) {
+        for decorator in &def.decorators {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&def.key);
+        if let Some(value) = &def.value {
+            self.visit_expression(value);
+        }
+    }
+
     fn visit_property_definition(&mut self, def: &'a PropertyDefinition<'a>) {
         let kind = AstKind::PropertyDefinition(def);
         self.enter_node(kind);
@@ -1148,7 +1172,29 @@ pub trait Visit<'a>: Sized {
             self.visit_import_declaration_specifier(specifier);
         }
         // TODO: source
-        // TODO: assertions
+        if let Some(with_clause) = &decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
+    }
+
+    fn visit_with_clause(&mut self, with_clause: &'a WithClause<'a>) {
+        let kind = AstKind::WithClause(with_clause);
+        self.enter_node(kind);
+        for attribute in &with_clause.attributes {
+            self.visit_import_attribute(attribute);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_import_attribute(&mut self, attribute: &'a ImportAttribute) {
+        let kind = AstKind::ImportAttribute(attribute);
+        self.enter_node(kind);
+        match &attribute.key {
+            ImportAttributeKey::Identifier(ident) => self.visit_identifier_name(ident),
+            ImportAttributeKey::StringLiteral(lit) => self.visit_string_literal(lit),
+        }
+        self.visit_string_literal(&attribute.value);
+        self.leave_node(kind);
     }
 
     fn visit_import_declaration_specifier(&mut self, specifier: &'a ImportDeclarationSpecifier) {
@@ -1178,7 +1224,11 @@ pub trait Visit<'a>: Sized {
         self.visit_binding_identifier(&specifier.local);
     }
 
-    fn visit_export_all_declaration(&mut self, _decl: &'a ExportAllDeclaration<'a>) {}
+    fn visit_export_all_declaration(&mut self, decl: &'a ExportAllDeclaration<'a>) {
+        if let Some(with_clause) = &decl.with_clause {
+            self.visit_with_clause(with_clause);
+        }
+    }
 
     fn visit_export_default_declaration(&mut self, decl: &'a ExportDefaultDeclaration<'a>) {
         match &decl.declaration {
@@ -1192,8 +1242,11 @@ pub trait Visit<'a>: Sized {
     }
 
     fn visit_export_named_declaration(&mut self, decl: &'a ExportNamedDeclaration<'a>) {
-        if let Some(decl) = &decl.declaration {
-            self.visit_declaration(decl);
+        if let Some(declaration) = &decl.declaration {
+            self.visit_declaration(declaration);
+        }
+        if let Some(with_clause) = &decl.with_clause {
+            self.visit_with_clause(with_clause);
         }
     }
 
@@ -1236,6 +1289,7 @@ pub trait Visit<'a>: Sized {
             Declaration::TSInterfaceDeclaration(decl) => {
                 self.visit_ts_interface_declaration(decl);
             }
+            Declaration::FlowOpaqueType(decl) => self.visit_flow_opaque_type(decl),
         }
     }
 
@@ -1280,6 +1334,22 @@ pub trait Visit<'a>: Sized {
         self.leave_node(kind);
     }
 
+    fn visit_flow_opaque_type(&mut self, decl: &'a FlowOpaqueType<'a>) {
+        let kind = AstKind::FlowOpaqueType(decl);
+        self.enter_node(kind);
+        self.visit_binding_identifier(&decl.id);
+        if let Some(parameters) = &decl.type_parameters {
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(supertype) = &decl.supertype {
+            self.visit_ts_type(supertype);
+        }
+        if let Some(impl_type) = &decl.impl_type {
+            self.visit_ts_type(impl_type);
+        }
+        self.leave_node(kind);
+    }
+
     fn visit_ts_interface_declaration(&mut self, decl: &'a TSInterfaceDeclaration<'a>) {
         let kind = AstKind::TSInterfaceDeclaration(decl);
         self.enter_node(kind);
@@ -0,0 +1,2206 @@
+//! Mutable AST Visitor Pattern, the counterpart of [`crate::visit::Visit`].
+//!
+//! Nodes are visited through `&mut` references so a pass can change them in place,
+//! or replace, remove and insert nodes with the help of [`crate::AstBuilder`].
+//! See:
+//! * [visitor pattern](https://rust-unofficial.github.io/patterns/patterns/behavioural/visitor.html)
+//! * [rustc visitor](https://github.com/rust-lang/rust/blob/master/compiler/rustc_ast/src/visit.rs)
+
+use oxc_allocator::Vec;
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, ast_kind::AstType};
+
+pub trait VisitMut<'a>: Sized {
+    fn enter_node(&mut self, _kind: AstType) {}
+    fn leave_node(&mut self, _kind: AstType) {}
+    /// Called before visiting a child, with the name of the field of its parent which holds it
+    fn enter_field(&mut self, _field: &'static str) {}
+
+    fn visit_program(&mut self, program: &mut Program<'a>) {
+        let kind = AstType::Program;
+        self.enter_node(kind);
+        for directive in program.directives.iter_mut() {
+            self.enter_field("directives");
+            self.visit_directive(directive);
+        }
+        self.enter_field("body");
+        self.visit_statements(&mut program.body);
+        self.leave_node(kind);
+    }
+
+    /* ----------  Statement ---------- */
+
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.visit_statement_match(stmt);
+    }
+
+    fn visit_statement_match(&mut self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::BlockStatement(stmt) => self.visit_block_statement(stmt),
+            Statement::BreakStatement(stmt) => self.visit_break_statement(stmt),
+            Statement::ContinueStatement(stmt) => self.visit_continue_statement(stmt),
+            Statement::DebuggerStatement(stmt) => self.visit_debugger_statement(stmt),
+            Statement::DoWhileStatement(stmt) => self.visit_do_while_statement(stmt),
+            Statement::EmptyStatement(stmt) => self.visit_empty_statement(stmt),
+            Statement::ExpressionStatement(stmt) => self.visit_expression_statement(stmt),
+            Statement::ForInStatement(stmt) => self.visit_for_in_statement(stmt),
+            Statement::ForOfStatement(stmt) => self.visit_for_of_statement(stmt),
+            Statement::ForStatement(stmt) => self.visit_for_statement(stmt),
+            Statement::IfStatement(stmt) => self.visit_if_statement(stmt),
+            Statement::LabeledStatement(stmt) => self.visit_labeled_statement(stmt),
+            Statement::ReturnStatement(stmt) => self.visit_return_statement(stmt),
+            Statement::SwitchStatement(stmt) => self.visit_switch_statement(stmt),
+            Statement::ThrowStatement(stmt) => self.visit_throw_statement(stmt),
+            Statement::TryStatement(stmt) => self.visit_try_statement(stmt),
+            Statement::WhileStatement(stmt) => self.visit_while_statement(stmt),
+            Statement::WithStatement(stmt) => self.visit_with_statement(stmt),
+            Statement::InvalidStatement(stmt) => self.visit_invalid_statement(stmt),
+
+            Statement::ModuleDeclaration(decl) => self.visit_module_declaration(decl),
+            Statement::Declaration(decl) => self.visit_declaration(decl),
+        }
+    }
+
+    fn visit_block_statement(&mut self, stmt: &mut BlockStatement<'a>) {
+        let kind = AstType::BlockStatement;
+        self.enter_node(kind);
+        self.enter_field("body");
+        self.visit_statements(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_break_statement(&mut self, stmt: &mut BreakStatement) {
+        let kind = AstType::BreakStatement;
+        self.enter_node(kind);
+        if let Some(break_target) = &mut stmt.label {
+            self.enter_field("label");
+            self.visit_label_identifier(break_target);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_continue_statement(&mut self, stmt: &mut ContinueStatement) {
+        let kind = AstType::ContinueStatement;
+        self.enter_node(kind);
+        if let Some(continue_target) = &mut stmt.label {
+            self.enter_field("label");
+            self.visit_label_identifier(continue_target);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_debugger_statement(&mut self, _stmt: &mut DebuggerStatement) {
+        let kind = AstType::DebuggerStatement;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &mut DoWhileStatement<'a>) {
+        let kind = AstType::DoWhileStatement;
+        self.enter_node(kind);
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.enter_field("test");
+        self.visit_expression(&mut stmt.test);
+        self.leave_node(kind);
+    }
+
+    fn visit_empty_statement(&mut self, _stmt: &mut EmptyStatement) {
+        let kind = AstType::EmptyStatement;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        let kind = AstType::ExpressionStatement;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_expression(&mut stmt.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        let kind = AstType::ForStatement;
+        self.enter_node(kind);
+        if let Some(init) = &mut stmt.init {
+            self.enter_field("init");
+            self.visit_for_statement_init(init);
+        }
+        if let Some(test) = &mut stmt.test {
+            self.enter_field("test");
+            self.visit_expression(test);
+        }
+        if let Some(update) = &mut stmt.update {
+            self.enter_field("update");
+            self.visit_expression(update);
+        }
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_statement_init(&mut self, init: &mut ForStatementInit<'a>) {
+        let kind = AstType::ForStatementInit;
+        self.enter_node(kind);
+        match init {
+            ForStatementInit::VariableDeclaration(decl) => {
+                self.visit_variable_declaration(decl);
+            }
+            ForStatementInit::Expression(expr) => self.visit_expression(expr),
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        let kind = AstType::ForInStatement;
+        self.enter_node(kind);
+        self.enter_field("left");
+        self.visit_for_statement_left(&mut stmt.left);
+        self.enter_field("right");
+        self.visit_expression(&mut stmt.right);
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        let kind = AstType::ForOfStatement;
+        self.enter_node(kind);
+        self.enter_field("left");
+        self.visit_for_statement_left(&mut stmt.left);
+        self.enter_field("right");
+        self.visit_expression(&mut stmt.right);
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_for_statement_left(&mut self, left: &mut ForStatementLeft<'a>) {
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                self.visit_variable_declaration(decl);
+            }
+            ForStatementLeft::AssignmentTarget(target) => self.visit_assignment_target(target),
+        }
+    }
+
+    fn visit_if_statement(&mut self, stmt: &mut IfStatement<'a>) {
+        let kind = AstType::IfStatement;
+        self.enter_node(kind);
+        self.enter_field("test");
+        self.visit_expression(&mut stmt.test);
+        self.enter_field("consequent");
+        self.visit_statement(&mut stmt.consequent);
+        if let Some(alternate) = &mut stmt.alternate {
+            self.enter_field("alternate");
+            self.visit_statement(alternate);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &mut LabeledStatement<'a>) {
+        let kind = AstType::LabeledStatement;
+        self.enter_node(kind);
+        self.enter_field("label");
+        self.visit_label_identifier(&mut stmt.label);
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        let kind = AstType::ReturnStatement;
+        self.enter_node(kind);
+        if let Some(arg) = &mut stmt.argument {
+            self.enter_field("argument");
+            self.visit_expression(arg);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &mut SwitchStatement<'a>) {
+        let kind = AstType::SwitchStatement;
+        self.enter_node(kind);
+        self.enter_field("discriminant");
+        self.visit_expression(&mut stmt.discriminant);
+        for case in stmt.cases.iter_mut() {
+            self.enter_field("cases");
+            self.visit_switch_case(case);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_switch_case(&mut self, case: &mut SwitchCase<'a>) {
+        let kind = AstType::SwitchCase;
+        self.enter_node(kind);
+        if let Some(expr) = &mut case.test {
+            self.enter_field("test");
+            self.visit_expression(expr);
+        }
+        self.enter_field("consequent");
+        self.visit_statements(&mut case.consequent);
+        self.leave_node(kind);
+    }
+
+    fn visit_throw_statement(&mut self, stmt: &mut ThrowStatement<'a>) {
+        let kind = AstType::ThrowStatement;
+        self.enter_node(kind);
+        self.enter_field("argument");
+        self.visit_expression(&mut stmt.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_try_statement(&mut self, stmt: &mut TryStatement<'a>) {
+        let kind = AstType::TryStatement;
+        self.enter_node(kind);
+        self.enter_field("block");
+        self.visit_block_statement(&mut stmt.block);
+        if let Some(handler) = &mut stmt.handler {
+            self.enter_field("handler");
+            self.visit_catch_clause(handler);
+        }
+        if let Some(finalizer) = &mut stmt.finalizer {
+            self.enter_field("finalizer");
+            self.visit_finally_clause(finalizer);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        let kind = AstType::CatchClause;
+        self.enter_node(kind);
+        if let Some(param) = &mut clause.param {
+            self.enter_field("param");
+            self.visit_pattern(param);
+        }
+        self.enter_field("body");
+        self.visit_statements(&mut clause.body.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_finally_clause(&mut self, clause: &mut BlockStatement<'a>) {
+        let kind = AstType::FinallyClause;
+        self.enter_node(kind);
+        self.visit_block_statement(clause);
+        self.leave_node(kind);
+    }
+
+    fn visit_while_statement(&mut self, stmt: &mut WhileStatement<'a>) {
+        let kind = AstType::WhileStatement;
+        self.enter_node(kind);
+        self.enter_field("test");
+        self.visit_expression(&mut stmt.test);
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_with_statement(&mut self, stmt: &mut WithStatement<'a>) {
+        let kind = AstType::WithStatement;
+        self.enter_node(kind);
+        self.enter_field("object");
+        self.visit_expression(&mut stmt.object);
+        self.enter_field("body");
+        self.visit_statement(&mut stmt.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_invalid_statement(&mut self, _stmt: &mut InvalidStatement) {
+        let kind = AstType::InvalidStatement;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_directive(&mut self, directive: &mut Directive<'a>) {
+        let kind = AstType::Directive;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_string_literal(&mut directive.expression);
+        self.leave_node(kind);
+    }
+
+    /* ----------  Declaration ---------- */
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        let kind = AstType::VariableDeclaration;
+        self.enter_node(kind);
+        for declarator in decl.declarations.iter_mut() {
+            self.enter_field("declarations");
+            self.visit_variable_declarator(declarator);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        let kind = AstType::VariableDeclarator;
+        self.enter_node(kind);
+        self.enter_field("id");
+        self.visit_pattern(&mut declarator.id);
+        if let Some(init) = &mut declarator.init {
+            self.enter_field("init");
+            self.visit_expression(init);
+        }
+        self.leave_node(kind);
+    }
+
+    /* ----------  Function ---------- */
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        let kind = AstType::Function;
+        self.enter_node(kind);
+        if let Some(ident) = &mut func.id {
+            self.enter_field("id");
+            self.visit_binding_identifier(ident);
+        }
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.enter_field("body");
+            self.visit_function_body(body);
+        }
+        if let Some(parameters) = &mut func.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &mut func.return_type {
+            self.enter_field("return_type");
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        let kind = AstType::FunctionBody;
+        self.enter_node(kind);
+        for directive in body.directives.iter_mut() {
+            self.enter_field("directives");
+            self.visit_directive(directive);
+        }
+        self.enter_field("statements");
+        self.visit_statements(&mut body.statements);
+        self.leave_node(kind);
+    }
+
+    fn visit_formal_parameters(&mut self, params: &mut FormalParameters<'a>) {
+        let kind = AstType::FormalParameters;
+        self.enter_node(kind);
+        for param in params.items.iter_mut() {
+            self.enter_field("items");
+            self.visit_formal_parameter(param);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_formal_parameter(&mut self, param: &mut FormalParameter<'a>) {
+        let kind = AstType::FormalParameter;
+        self.enter_node(kind);
+        for decorator in param.decorators.iter_mut() {
+            self.enter_field("decorators");
+            self.visit_decorator(decorator);
+        }
+        self.enter_field("pattern");
+        self.visit_pattern(&mut param.pattern);
+        self.leave_node(kind);
+    }
+
+    /* ----------  Class ---------- */
+
+    fn visit_decorator(&mut self, decorator: &mut Decorator<'a>) {
+        let kind = AstType::Decorator;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_expression(&mut decorator.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        let kind = AstType::Class;
+        self.enter_node(kind);
+        for decorator in class.decorators.iter_mut() {
+            self.enter_field("decorators");
+            self.visit_decorator(decorator);
+        }
+        if let Some(id) = &mut class.id {
+            self.enter_field("id");
+            self.visit_binding_identifier(id);
+        }
+        if let Some(parameters) = &mut class.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+
+        if let Some(super_class) = &mut class.super_class {
+            self.enter_field("super_class");
+            self.visit_class_heritage(super_class);
+        }
+        if let Some(super_parameters) = &mut class.super_type_parameters {
+            self.enter_field("super_type_parameters");
+            self.visit_ts_type_parameter_instantiation(super_parameters);
+        }
+        self.enter_field("body");
+        self.visit_class_body(&mut class.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_class_heritage(&mut self, expr: &mut Expression<'a>) {
+        let kind = AstType::ClassHeritage;
+        self.enter_node(kind);
+        self.visit_expression(expr);
+        self.leave_node(kind);
+    }
+
+    fn visit_class_body(&mut self, body: &mut ClassBody<'a>) {
+        for elem in body.body.iter_mut() {
+            self.enter_field("body");
+            self.visit_class_element(elem);
+        }
+    }
+
+    fn visit_class_element(&mut self, elem: &mut ClassElement<'a>) {
+        match elem {
+            ClassElement::StaticBlock(block) => self.visit_static_block(block),
+            ClassElement::MethodDefinition(def) => self.visit_method_definition(def),
+            ClassElement::PropertyDefinition(def) => self.visit_property_definition(def),
+            ClassElement::AccessorProperty(def) => self.visit_accessor_property(def),
+            ClassElement::TSAbstractMethodDefinition(def) => {
+                self.visit_method_definition(&mut def.method_definition);
+            }
+            ClassElement::TSAbstractPropertyDefinition(def) => {
+                self.visit_property_definition(&mut def.property_definition);
+            }
+            ClassElement::TSIndexSignature(def) => self.visit_ts_index_signature(def),
+            ClassElement::InvalidClassElement(elem) => self.visit_invalid_class_element(elem),
+        }
+    }
+
+    fn visit_static_block(&mut self, block: &mut StaticBlock<'a>) {
+        let kind = AstType::StaticBlock;
+        self.enter_node(kind);
+        self.enter_field("body");
+        self.visit_statements(&mut block.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_invalid_class_element(&mut self, _elem: &mut InvalidClassElement) {
+        let kind = AstType::InvalidClassElement;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        let kind = AstType::MethodDefinition;
+        self.enter_node(kind);
+        for decorator in def.decorators.iter_mut() {
+            self.enter_field("decorators");
+            self.visit_decorator(decorator);
+        }
+        self.enter_field("key");
+        self.visit_property_key(&mut def.key);
+        self.enter_field("value");
+        self.visit_function(&mut def.value);
+        self.leave_node(kind);
+    }
+
+    fn visit_accessor_property(&mut self, def: &mut AccessorProperty<'a>) {
+        for decorator in def.decorators.iter_mut() {
+            self.enter_field("decorators");
+            self.visit_decorator(decorator);
+        }
+        self.enter_field("key");
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.enter_field("value");
+            self.visit_expression(value);
+        }
+    }
+
+    fn visit_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
+        let kind = AstType::PropertyDefinition;
+        self.enter_node(kind);
+        for decorator in def.decorators.iter_mut() {
+            self.enter_field("decorators");
+            self.visit_decorator(decorator);
+        }
+        self.enter_field("key");
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.enter_field("value");
+            self.visit_expression(value);
+        }
+        if let Some(annotation) = &mut def.type_annotation {
+            self.enter_field("type_annotation");
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    /* ----------  Expression ---------- */
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+    }
+
+    fn visit_expression_match(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::BigintLiteral(lit) => self.visit_bigint_literal(lit),
+            Expression::BooleanLiteral(lit) => self.visit_boolean_literal(lit),
+            Expression::NullLiteral(lit) => self.visit_null_literal(lit),
+            Expression::NumberLiteral(lit) => self.visit_number_literal(lit),
+            Expression::RegExpLiteral(lit) => self.visit_reg_expr_literal(lit),
+            Expression::StringLiteral(lit) => self.visit_string_literal(lit),
+            Expression::TemplateLiteral(lit) => self.visit_template_literal(lit),
+
+            Expression::Identifier(ident) => self.visit_identifier_reference(ident),
+            Expression::MetaProperty(meta) => self.visit_meta_property(meta),
+
+            Expression::ArrayExpression(expr) => self.visit_array_expression(expr),
+            Expression::ArrowFunctionExpression(expr) => self.visit_arrow_expression(expr),
+            Expression::AssignmentExpression(expr) => self.visit_assignment_expression(expr),
+            Expression::AwaitExpression(expr) => self.visit_await_expression(expr),
+            Expression::BinaryExpression(expr) => self.visit_binary_expression(expr),
+            Expression::CallExpression(expr) => self.visit_call_expression(expr),
+            Expression::ChainExpression(expr) => self.visit_chain_expression(expr),
+            Expression::ClassExpression(expr) => self.visit_class(expr),
+            Expression::ConditionalExpression(expr) => self.visit_conditional_expression(expr),
+            Expression::FunctionExpression(expr) => self.visit_function(expr),
+            Expression::ImportExpression(expr) => self.visit_import_expression(expr),
+            Expression::LogicalExpression(expr) => self.visit_logical_expression(expr),
+            Expression::MemberExpression(expr) => self.visit_member_expression(expr),
+            Expression::NewExpression(expr) => self.visit_new_expression(expr),
+            Expression::ObjectExpression(expr) => self.visit_object_expression(expr),
+            Expression::ParenthesizedExpression(expr) => {
+                self.visit_parenthesized_expression(expr);
+            }
+            Expression::PrivateInExpression(expr) => self.visit_private_in_expression(expr),
+            Expression::SequenceExpression(expr) => self.visit_sequence_expression(expr),
+            Expression::TaggedTemplateExpression(expr) => {
+                self.visit_tagged_template_expression(expr);
+            }
+            Expression::ThisExpression(expr) => self.visit_this_expression(expr),
+            Expression::UnaryExpression(expr) => self.visit_unary_expression(expr),
+            Expression::UpdateExpression(expr) => self.visit_update_expression(expr),
+            Expression::YieldExpression(expr) => self.visit_yield_expression(expr),
+            Expression::Super(expr) => self.visit_super(expr),
+            Expression::JSXElement(elem) => self.visit_jsx_element(elem),
+            Expression::JSXFragment(elem) => self.visit_jsx_fragment(elem),
+
+            Expression::TSAsExpression(expr) => self.visit_ts_as_expression(expr),
+            Expression::TSNonNullExpression(expr) => self.visit_ts_non_null_expression(expr),
+            Expression::TSTypeAssertion(expr) => self.visit_ts_type_assertion(expr),
+            Expression::TSInstantiationExpression(expr) => {
+                self.visit_ts_instantiation_expression(expr);
+            }
+        }
+    }
+
+    fn visit_meta_property(&mut self, _meta: &mut MetaProperty) {
+        let kind = AstType::MetaProperty;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_array_expression(&mut self, expr: &mut ArrayExpression<'a>) {
+        let kind = AstType::ArrayExpression;
+        self.enter_node(kind);
+        for elem in expr.elements.iter_mut().flatten() {
+            self.enter_field("elements");
+            self.visit_argument(elem);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_argument(&mut self, arg: &mut Argument<'a>) {
+        let kind = AstType::Argument;
+        self.enter_node(kind);
+        match arg {
+            Argument::SpreadElement(spread) => self.visit_spread_element(spread),
+            Argument::Expression(expr) => self.visit_expression(expr),
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_spread_element(&mut self, elem: &mut SpreadElement<'a>) {
+        let kind = AstType::SpreadElement;
+        self.enter_node(kind);
+        self.enter_field("argument");
+        self.visit_expression(&mut elem.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
+        let kind = AstType::AssignmentExpression;
+        self.enter_node(kind);
+        self.enter_field("left");
+        self.visit_assignment_target(&mut expr.left);
+        self.enter_field("right");
+        self.visit_expression(&mut expr.right);
+        self.leave_node(kind);
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        let kind = AstType::ArrowExpression;
+        self.enter_node(kind);
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut expr.params);
+        self.enter_field("body");
+        self.visit_function_body(&mut expr.body);
+        if let Some(parameters) = &mut expr.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_await_expression(&mut self, expr: &mut AwaitExpression<'a>) {
+        let kind = AstType::AwaitExpression;
+        self.enter_node(kind);
+        self.enter_field("argument");
+        self.visit_expression(&mut expr.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_binary_expression(&mut self, expr: &mut BinaryExpression<'a>) {
+        let kind = AstType::BinaryExpression;
+        self.enter_node(kind);
+        self.enter_field("left");
+        self.visit_expression(&mut expr.left);
+        self.enter_field("right");
+        self.visit_expression(&mut expr.right);
+        self.leave_node(kind);
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        let kind = AstType::CallExpression;
+        self.enter_node(kind);
+        for arg in expr.arguments.iter_mut() {
+            self.enter_field("arguments");
+            self.visit_argument(arg);
+        }
+        self.enter_field("callee");
+        self.visit_expression(&mut expr.callee);
+        if let Some(parameters) = &mut expr.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_chain_expression(&mut self, expr: &mut ChainExpression<'a>) {
+        self.enter_field("expression");
+        self.visit_chain_element(&mut expr.expression);
+    }
+
+    fn visit_chain_element(&mut self, elem: &mut ChainElement<'a>) {
+        match elem {
+            ChainElement::CallExpression(expr) => self.visit_call_expression(expr),
+            ChainElement::MemberExpression(expr) => self.visit_member_expression(expr),
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &mut ConditionalExpression<'a>) {
+        let kind = AstType::ConditionalExpression;
+        self.enter_node(kind);
+        self.enter_field("test");
+        self.visit_expression(&mut expr.test);
+        self.enter_field("consequent");
+        self.visit_expression(&mut expr.consequent);
+        self.enter_field("alternate");
+        self.visit_expression(&mut expr.alternate);
+        self.leave_node(kind);
+    }
+
+    fn visit_import_expression(&mut self, expr: &mut ImportExpression<'a>) {
+        self.enter_field("source");
+        self.visit_expression(&mut expr.source);
+        for arg in expr.arguments.iter_mut() {
+            self.enter_field("arguments");
+            self.visit_expression(arg);
+        }
+    }
+
+    fn visit_logical_expression(&mut self, expr: &mut LogicalExpression<'a>) {
+        let kind = AstType::LogicalExpression;
+        self.enter_node(kind);
+        self.enter_field("left");
+        self.visit_expression(&mut expr.left);
+        self.enter_field("right");
+        self.visit_expression(&mut expr.right);
+        self.leave_node(kind);
+    }
+
+    fn visit_member_expression(&mut self, expr: &mut MemberExpression<'a>) {
+        let kind = AstType::MemberExpression;
+        self.enter_node(kind);
+        match expr {
+            MemberExpression::ComputedMemberExpression(expr) => {
+                self.visit_computed_member_expression(expr);
+            }
+            MemberExpression::StaticMemberExpression(expr) => {
+                self.visit_static_member_expression(expr);
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                self.visit_private_field_expression(expr);
+            }
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_computed_member_expression(&mut self, expr: &mut ComputedMemberExpression<'a>) {
+        self.enter_field("object");
+        self.visit_expression(&mut expr.object);
+        self.enter_field("expression");
+        self.visit_expression(&mut expr.expression);
+    }
+
+    fn visit_static_member_expression(&mut self, expr: &mut StaticMemberExpression<'a>) {
+        self.enter_field("object");
+        self.visit_expression(&mut expr.object);
+        self.enter_field("property");
+        self.visit_identifier_name(&mut expr.property);
+    }
+
+    fn visit_private_field_expression(&mut self, expr: &mut PrivateFieldExpression<'a>) {
+        self.enter_field("object");
+        self.visit_expression(&mut expr.object);
+        self.enter_field("field");
+        self.visit_private_identifier(&mut expr.field);
+    }
+
+    fn visit_new_expression(&mut self, expr: &mut NewExpression<'a>) {
+        let kind = AstType::NewExpression;
+        self.enter_node(kind);
+        self.enter_field("callee");
+        self.visit_expression(&mut expr.callee);
+        if let Some(parameters) = &mut expr.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        for arg in expr.arguments.iter_mut() {
+            self.enter_field("arguments");
+            self.visit_argument(arg);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_object_expression(&mut self, expr: &mut ObjectExpression<'a>) {
+        let kind = AstType::ObjectExpression;
+        self.enter_node(kind);
+        for prop in expr.properties.iter_mut() {
+            self.enter_field("properties");
+            self.visit_object_property(prop);
+        }
+        self.leave_node(kind);
+    }
+
+    fn enter_object_expression(&mut self, _expr: &mut ObjectExpression<'a>) {}
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        match prop {
+            ObjectProperty::Property(prop) => self.visit_property(prop),
+            ObjectProperty::SpreadProperty(elem) => self.visit_spread_element(elem),
+        }
+    }
+
+    fn visit_property(&mut self, prop: &mut Property<'a>) {
+        let kind = AstType::Property;
+        self.enter_node(kind);
+        self.enter_field("key");
+        self.visit_property_key(&mut prop.key);
+        self.enter_field("value");
+        self.visit_property_value(&mut prop.value);
+        self.leave_node(kind);
+    }
+
+    fn visit_property_key(&mut self, key: &mut PropertyKey<'a>) {
+        let kind = AstType::PropertyKey;
+        self.enter_node(kind);
+        match key {
+            PropertyKey::Identifier(ident) => self.visit_identifier_name(ident),
+            PropertyKey::PrivateIdentifier(ident) => self.visit_private_identifier(ident),
+            PropertyKey::Expression(expr) => self.visit_expression(expr),
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_property_value(&mut self, value: &mut PropertyValue<'a>) {
+        let kind = AstType::PropertyValue;
+        self.enter_node(kind);
+        match value {
+            PropertyValue::Pattern(pat) => self.visit_pattern(pat),
+            PropertyValue::Expression(expr) => self.visit_expression(expr),
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_parenthesized_expression(&mut self, expr: &mut ParenthesizedExpression<'a>) {
+        let kind = AstType::ParenthesizedExpression;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_expression(&mut expr.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_private_in_expression(&mut self, expr: &mut PrivateInExpression<'a>) {
+        self.enter_field("left");
+        self.visit_private_identifier(&mut expr.left);
+        self.enter_field("right");
+        self.visit_expression(&mut expr.right);
+    }
+
+    fn visit_sequence_expression(&mut self, expr: &mut SequenceExpression<'a>) {
+        let kind = AstType::SequenceExpression;
+        self.enter_node(kind);
+        for expr in expr.expressions.iter_mut() {
+            self.enter_field("expressions");
+            self.visit_expression(expr);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        let kind = AstType::TaggedTemplateExpression;
+        self.enter_node(kind);
+        self.enter_field("tag");
+        self.visit_expression(&mut expr.tag);
+        self.enter_field("quasi");
+        self.visit_template_literal(&mut expr.quasi);
+        self.leave_node(kind);
+    }
+
+    fn visit_this_expression(&mut self, _expr: &mut ThisExpression) {
+        let kind = AstType::ThisExpression;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_unary_expression(&mut self, expr: &mut UnaryExpression<'a>) {
+        let kind = AstType::UnaryExpression;
+        self.enter_node(kind);
+        self.enter_field("argument");
+        self.visit_expression(&mut expr.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_update_expression(&mut self, expr: &mut UpdateExpression<'a>) {
+        let kind = AstType::UpdateExpression;
+        self.enter_node(kind);
+        self.enter_field("argument");
+        self.visit_simple_assignment_target(&mut expr.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_yield_expression(&mut self, expr: &mut YieldExpression<'a>) {
+        let kind = AstType::YieldExpression;
+        self.enter_node(kind);
+        if let Some(argument) = &mut expr.argument {
+            self.enter_field("argument");
+            self.visit_expression(argument);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_super(&mut self, _expr: &mut Super) {
+        let kind = AstType::Super;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_assignment_target(&mut self, target: &mut AssignmentTarget<'a>) {
+        let kind = AstType::AssignmentTarget;
+        self.enter_node(kind);
+        match target {
+            AssignmentTarget::SimpleAssignmentTarget(target) => {
+                self.visit_simple_assignment_target(target);
+            }
+            AssignmentTarget::AssignmentTargetPattern(pat) => {
+                self.visit_assignment_target_pattern(pat);
+            }
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        let kind = AstType::SimpleAssignmentTarget;
+        self.enter_node(kind);
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.visit_identifier_reference(ident);
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(expr) => {
+                self.visit_member_expression(expr);
+            }
+            SimpleAssignmentTarget::TSAsExpression(expr) => {
+                self.enter_field("expression");
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+                self.enter_field("expression");
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+                self.enter_field("expression");
+                self.visit_expression(&mut expr.expression);
+            }
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_assignment_target_pattern(&mut self, pat: &mut AssignmentTargetPattern<'a>) {
+        match pat {
+            AssignmentTargetPattern::ArrayAssignmentTarget(target) => {
+                self.visit_array_assignment_target(target);
+            }
+            AssignmentTargetPattern::ObjectAssignmentTarget(target) => {
+                self.visit_object_assignment_target(target);
+            }
+        }
+    }
+
+    fn visit_array_assignment_target(&mut self, target: &mut ArrayAssignmentTarget<'a>) {
+        for element in target.elements.iter_mut().flatten() {
+            self.enter_field("elements");
+            self.visit_assignment_target_maybe_default(element);
+        }
+        if let Some(target) = &mut target.rest {
+            self.enter_field("rest");
+            self.visit_assignment_target(target);
+        }
+    }
+
+    fn visit_assignment_target_maybe_default(
+        &mut self,
+        target: &mut AssignmentTargetMaybeDefault<'a>,
+    ) {
+        match target {
+            AssignmentTargetMaybeDefault::AssignmentTarget(target) => {
+                self.visit_assignment_target(target);
+            }
+            AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                self.visit_assignment_target_with_default(target);
+            }
+        }
+    }
+
+    fn visit_assignment_target_with_default(
+        &mut self,
+        target: &mut AssignmentTargetWithDefault<'a>,
+    ) {
+        let kind = AstType::AssignmentTargetWithDefault;
+        self.enter_node(kind);
+        self.enter_field("binding");
+        self.visit_assignment_target(&mut target.binding);
+        self.enter_field("init");
+        self.visit_expression(&mut target.init);
+        self.leave_node(kind);
+    }
+
+    fn visit_object_assignment_target(&mut self, target: &mut ObjectAssignmentTarget<'a>) {
+        for property in target.properties.iter_mut() {
+            self.enter_field("properties");
+            self.visit_assignment_target_property(property);
+        }
+        if let Some(target) = &mut target.rest {
+            self.enter_field("rest");
+            self.visit_assignment_target(target);
+        }
+    }
+
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        match property {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                self.visit_assignment_target_property_identifier(ident);
+            }
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                self.visit_assignment_target_property_property(prop);
+            }
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        ident: &mut AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.enter_field("binding");
+        self.visit_identifier_reference(&mut ident.binding);
+        if let Some(expr) = &mut ident.init {
+            self.enter_field("init");
+            self.visit_expression(expr);
+        }
+    }
+
+    fn visit_assignment_target_property_property(
+        &mut self,
+        property: &mut AssignmentTargetPropertyProperty<'a>,
+    ) {
+        self.enter_field("name");
+        self.visit_property_key(&mut property.name);
+        self.enter_field("binding");
+        self.visit_assignment_target_maybe_default(&mut property.binding);
+    }
+
+    /* ----------  Expression ---------- */
+
+    fn visit_jsx_element(&mut self, elem: &mut JSXElement<'a>) {
+        self.enter_field("opening_element");
+        self.visit_jsx_opening_element(&mut elem.opening_element);
+        for child in elem.children.iter_mut() {
+            self.enter_field("children");
+            self.visit_jsx_child(child);
+        }
+    }
+
+    fn visit_jsx_opening_element(&mut self, elem: &mut JSXOpeningElement<'a>) {
+        let kind = AstType::JSXOpeningElement;
+        self.enter_node(kind);
+        self.enter_field("name");
+        self.visit_jsx_element_name(&mut elem.name);
+        for attribute in elem.attributes.iter_mut() {
+            self.enter_field("attributes");
+            self.visit_jsx_attribute_item(attribute);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_jsx_element_name(&mut self, _name: &mut JSXElementName<'a>) {
+        let kind = AstType::JSXElementName;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_jsx_attribute_item(&mut self, item: &mut JSXAttributeItem<'a>) {
+        match item {
+            JSXAttributeItem::Attribute(attribute) => self.visit_jsx_attribute(attribute),
+            JSXAttributeItem::SpreadAttribute(attribute) => {
+                self.visit_jsx_spread_attribute(attribute);
+            }
+        }
+    }
+
+    fn visit_jsx_attribute(&mut self, attribute: &mut JSXAttribute<'a>) {
+        if let Some(value) = &mut attribute.value {
+            self.enter_field("value");
+            self.visit_jsx_attribute_value(value);
+        }
+    }
+
+    fn visit_jsx_spread_attribute(&mut self, attribute: &mut JSXSpreadAttribute<'a>) {
+        self.enter_field("argument");
+        self.visit_expression(&mut attribute.argument);
+    }
+
+    fn visit_jsx_attribute_value(&mut self, value: &mut JSXAttributeValue<'a>) {
+        match value {
+            JSXAttributeValue::ExpressionContainer(expr) => {
+                self.visit_jsx_expression_container(expr);
+            }
+            JSXAttributeValue::Element(elem) => self.visit_jsx_element(elem),
+            JSXAttributeValue::Fragment(elem) => self.visit_jsx_fragment(elem),
+            JSXAttributeValue::StringLiteral(_) => {}
+        }
+    }
+
+    fn visit_jsx_expression_container(&mut self, expr: &mut JSXExpressionContainer<'a>) {
+        self.enter_field("expression");
+        self.visit_jsx_expression(&mut expr.expression);
+    }
+
+    fn visit_jsx_expression(&mut self, expr: &mut JSXExpression<'a>) {
+        match expr {
+            JSXExpression::Expression(expr) => self.visit_expression(expr),
+            JSXExpression::EmptyExpression(_) => {}
+        }
+    }
+
+    fn visit_jsx_fragment(&mut self, elem: &mut JSXFragment<'a>) {
+        for child in elem.children.iter_mut() {
+            self.enter_field("children");
+            self.visit_jsx_child(child);
+        }
+    }
+
+    fn visit_jsx_child(&mut self, child: &mut JSXChild<'a>) {
+        match child {
+            JSXChild::Element(elem) => self.visit_jsx_element(elem),
+            JSXChild::Fragment(elem) => self.visit_jsx_fragment(elem),
+            JSXChild::ExpressionContainer(expr) => self.visit_jsx_expression_container(expr),
+            JSXChild::Spread(expr) => self.visit_jsx_spread_child(expr),
+            JSXChild::Text(_) => {}
+        }
+    }
+
+    fn visit_jsx_spread_child(&mut self, child: &mut JSXSpreadChild<'a>) {
+        self.enter_field("expression");
+        self.visit_expression(&mut child.expression);
+    }
+
+    /* ----------  Pattern ---------- */
+
+    fn visit_pattern(&mut self, pat: &mut BindingPattern<'a>) {
+        match &mut pat.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.visit_binding_identifier(ident);
+            }
+            BindingPatternKind::ObjectPattern(pat) => self.visit_object_pattern(pat),
+            BindingPatternKind::ArrayPattern(pat) => self.visit_array_pattern(pat),
+            BindingPatternKind::RestElement(pat) => self.visit_rest_element(pat),
+            BindingPatternKind::AssignmentPattern(pat) => self.visit_assignment_pattern(pat),
+        }
+        if let Some(type_annotation) = &mut pat.type_annotation {
+            self.enter_field("type_annotation");
+            self.visit_ts_type_annotation(type_annotation);
+        }
+    }
+
+    fn visit_binding_identifier(&mut self, _ident: &mut BindingIdentifier) {
+        let kind = AstType::BindingIdentifier;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_object_pattern(&mut self, pat: &mut ObjectPattern<'a>) {
+        let kind = AstType::ObjectPattern;
+        self.enter_node(kind);
+        for prop in pat.properties.iter_mut() {
+            self.enter_field("properties");
+            self.visit_object_pattern_property(prop);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_object_pattern_property(&mut self, prop: &mut ObjectPatternProperty<'a>) {
+        match prop {
+            ObjectPatternProperty::Property(prop) => self.visit_property(prop),
+            ObjectPatternProperty::RestElement(prop) => self.visit_rest_element(prop),
+        }
+    }
+
+    fn visit_array_pattern(&mut self, pat: &mut ArrayPattern<'a>) {
+        let kind = AstType::ArrayPattern;
+        self.enter_node(kind);
+        for pat in pat.elements.iter_mut().flatten() {
+            self.enter_field("elements");
+            self.visit_pattern(pat);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_rest_element(&mut self, pat: &mut RestElement<'a>) {
+        let kind = AstType::RestElement;
+        self.enter_node(kind);
+        self.enter_field("argument");
+        self.visit_pattern(&mut pat.argument);
+        self.leave_node(kind);
+    }
+
+    fn visit_assignment_pattern(&mut self, pat: &mut AssignmentPattern<'a>) {
+        let kind = AstType::AssignmentPattern;
+        self.enter_node(kind);
+        self.enter_field("left");
+        self.visit_pattern(&mut pat.left);
+        self.enter_field("right");
+        self.visit_expression(&mut pat.right);
+        self.leave_node(kind);
+    }
+
+    /* ----------  Identifier ---------- */
+
+    fn visit_identifier_reference(&mut self, _ident: &mut IdentifierReference) {
+        let kind = AstType::IdentifierReference;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_private_identifier(&mut self, _ident: &mut PrivateIdentifier) {
+        let kind = AstType::PrivateIdentifier;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_label_identifier(&mut self, _ident: &mut LabelIdentifier) {
+        let kind = AstType::LabelIdentifier;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_identifier_name(&mut self, _ident: &mut IdentifierName) {
+        let kind = AstType::IdentifierName;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    /* ----------  Literal ---------- */
+
+    fn visit_number_literal(&mut self, _lit: &mut NumberLiteral<'a>) {
+        let kind = AstType::NumberLiteral;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_boolean_literal(&mut self, _lit: &mut BooleanLiteral) {
+        let kind = AstType::BooleanLiteral;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_null_literal(&mut self, _lit: &mut NullLiteral) {
+        let kind = AstType::NullLiteral;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_bigint_literal(&mut self, _lit: &mut BigintLiteral) {
+        let kind = AstType::BigintLiteral;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_string_literal(&mut self, _lit: &mut StringLiteral) {
+        let kind = AstType::StringLiteral;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_template_literal(&mut self, lit: &mut TemplateLiteral<'a>) {
+        let kind = AstType::TemplateLiteral;
+        self.enter_node(kind);
+        for elem in lit.quasis.iter_mut() {
+            self.enter_field("quasis");
+            self.visit_template_element(elem);
+        }
+        for expr in lit.expressions.iter_mut() {
+            self.enter_field("expressions");
+            self.visit_expression(expr);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_reg_expr_literal(&mut self, _lit: &mut RegExpLiteral) {
+        let kind = AstType::RegExpLiteral;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_template_element(&mut self, _elem: &mut TemplateElement) {}
+
+    /* ----------  Module ---------- */
+
+    fn visit_module_declaration(&mut self, decl: &mut ModuleDeclaration<'a>) {
+        let kind = AstType::ModuleDeclaration;
+        self.enter_node(kind);
+        match &mut decl.kind {
+            ModuleDeclarationKind::ImportDeclaration(decl) => {
+                self.visit_import_declaration(decl);
+            }
+            ModuleDeclarationKind::ExportAllDeclaration(decl) => {
+                self.visit_export_all_declaration(decl);
+            }
+            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => {
+                self.visit_export_default_declaration(decl);
+            }
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                self.visit_export_named_declaration(decl);
+            }
+            ModuleDeclarationKind::TSExportAssignment(decl) => {
+                self.enter_field("expression");
+                self.visit_expression(&mut decl.expression);
+            }
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(_) => {}
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_import_declaration(&mut self, decl: &mut ImportDeclaration<'a>) {
+        for specifier in decl.specifiers.iter_mut() {
+            self.enter_field("specifiers");
+            self.visit_import_declaration_specifier(specifier);
+        }
+        self.enter_field("source");
+        self.visit_string_literal(&mut decl.source);
+        if let Some(with_clause) = &mut decl.with_clause {
+            self.enter_field("with_clause");
+            self.visit_with_clause(with_clause);
+        }
+    }
+
+    fn visit_with_clause(&mut self, with_clause: &mut WithClause<'a>) {
+        let kind = AstType::WithClause;
+        self.enter_node(kind);
+        for attribute in with_clause.attributes.iter_mut() {
+            self.enter_field("attributes");
+            self.visit_import_attribute(attribute);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_import_attribute(&mut self, attribute: &mut ImportAttribute) {
+        let kind = AstType::ImportAttribute;
+        self.enter_node(kind);
+        self.enter_field("key");
+        match &mut attribute.key {
+            ImportAttributeKey::Identifier(ident) => self.visit_identifier_name(ident),
+            ImportAttributeKey::StringLiteral(lit) => self.visit_string_literal(lit),
+        }
+        self.enter_field("value");
+        self.visit_string_literal(&mut attribute.value);
+        self.leave_node(kind);
+    }
+
+    fn visit_import_declaration_specifier(&mut self, specifier: &mut ImportDeclarationSpecifier) {
+        match specifier {
+            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                self.visit_import_specifier(specifier);
+            }
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                self.visit_import_default_specifier(specifier);
+            }
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                self.visit_import_name_specifier(specifier);
+            }
+        }
+    }
+
+    fn visit_import_specifier(&mut self, specifier: &mut ImportSpecifier) {
+        self.enter_field("imported");
+        self.visit_module_export_name(&mut specifier.imported);
+        self.enter_field("local");
+        self.visit_binding_identifier(&mut specifier.local);
+    }
+
+    fn visit_import_default_specifier(&mut self, specifier: &mut ImportDefaultSpecifier) {
+        self.enter_field("local");
+        self.visit_binding_identifier(&mut specifier.local);
+    }
+
+    fn visit_import_name_specifier(&mut self, specifier: &mut ImportNamespaceSpecifier) {
+        self.enter_field("local");
+        self.visit_binding_identifier(&mut specifier.local);
+    }
+
+    fn visit_module_export_name(&mut self, name: &mut ModuleExportName) {
+        match name {
+            ModuleExportName::Identifier(ident) => self.visit_identifier_name(ident),
+            ModuleExportName::StringLiteral(lit) => self.visit_string_literal(lit),
+        }
+    }
+
+    fn visit_export_all_declaration(&mut self, decl: &mut ExportAllDeclaration<'a>) {
+        self.enter_field("source");
+        self.visit_string_literal(&mut decl.source);
+        if let Some(with_clause) = &mut decl.with_clause {
+            self.enter_field("with_clause");
+            self.visit_with_clause(with_clause);
+        }
+    }
+
+    fn visit_export_default_declaration(&mut self, decl: &mut ExportDefaultDeclaration<'a>) {
+        self.enter_field("declaration");
+        match &mut decl.declaration {
+            ExportDefaultDeclarationKind::Expression(expr) => self.visit_expression(expr),
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                self.visit_function(func);
+            }
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => self.visit_class(class),
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                self.visit_ts_interface_declaration(decl);
+            }
+            ExportDefaultDeclarationKind::TSEnumDeclaration(decl) => self.visit_enum(decl),
+        }
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &mut ExportNamedDeclaration<'a>) {
+        if let Some(declaration) = &mut decl.declaration {
+            self.enter_field("declaration");
+            self.visit_declaration(declaration);
+        }
+        if let Some(source) = &mut decl.source {
+            self.enter_field("source");
+            self.visit_string_literal(source);
+        }
+        if let Some(with_clause) = &mut decl.with_clause {
+            self.enter_field("with_clause");
+            self.visit_with_clause(with_clause);
+        }
+    }
+
+    fn visit_enum_member(&mut self, member: &mut TSEnumMember<'a>) {
+        let kind = AstType::TSEnumMember;
+        self.enter_node(kind);
+
+        if let Some(initializer) = &mut member.initializer {
+            self.enter_field("initializer");
+            self.visit_expression(initializer);
+        }
+
+        self.leave_node(kind);
+    }
+
+    fn visit_enum(&mut self, decl: &mut TSEnumDeclaration<'a>) {
+        let kind = AstType::TSEnumDeclaration;
+        self.enter_node(kind);
+        self.enter_field("id");
+        self.visit_binding_identifier(&mut decl.id);
+        for member in decl.members.iter_mut() {
+            self.enter_field("members");
+            self.visit_enum_member(member);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_declaration(&mut self, decl: &mut Declaration<'a>) {
+        match decl {
+            Declaration::VariableDeclaration(decl) => self.visit_variable_declaration(decl),
+            Declaration::FunctionDeclaration(func) => self.visit_function(func),
+            Declaration::ClassDeclaration(class) => self.visit_class(class),
+            Declaration::TSModuleDeclaration(module) => {
+                self.visit_ts_module_declaration(module);
+            }
+            Declaration::TSTypeAliasDeclaration(decl) => {
+                self.visit_ts_type_alias_declaration(decl);
+            }
+            Declaration::TSEnumDeclaration(decl) => self.visit_enum(decl),
+            Declaration::TSImportEqualsDeclaration(decl) => {
+                self.visit_ts_import_equals_declaration(decl);
+            }
+            Declaration::TSInterfaceDeclaration(decl) => {
+                self.visit_ts_interface_declaration(decl);
+            }
+            Declaration::FlowOpaqueType(decl) => self.visit_flow_opaque_type(decl),
+        }
+    }
+
+    fn visit_ts_import_equals_declaration(&mut self, decl: &mut TSImportEqualsDeclaration<'a>) {
+        let kind = AstType::TSImportEqualsDeclaration;
+        self.enter_node(kind);
+        self.enter_field("id");
+        self.visit_binding_identifier(&mut decl.id);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_module_declaration(&mut self, decl: &mut TSModuleDeclaration<'a>) {
+        let kind = AstType::TSModuleDeclaration;
+        self.enter_node(kind);
+        self.enter_field("id");
+        match &mut decl.id {
+            TSModuleDeclarationName::Identifier(ident) => self.visit_identifier_name(ident),
+            TSModuleDeclarationName::StringLiteral(lit) => self.visit_string_literal(lit),
+        }
+        self.enter_field("body");
+        match &mut decl.body {
+            TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
+                self.visit_ts_module_declaration(decl);
+            }
+            TSModuleDeclarationBody::TSModuleBlock(block) => self.visit_ts_module_block(block),
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_module_block(&mut self, block: &mut TSModuleBlock<'a>) {
+        let kind = AstType::TSModuleBlock;
+        self.enter_node(kind);
+        self.enter_field("body");
+        self.visit_statements(&mut block.body);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_alias_declaration(&mut self, decl: &mut TSTypeAliasDeclaration<'a>) {
+        let kind = AstType::TSTypeAliasDeclaration;
+        self.enter_node(kind);
+        self.enter_field("id");
+        self.visit_binding_identifier(&mut decl.id);
+        if let Some(parameters) = &mut decl.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.enter_field("type_annotation");
+        self.visit_ts_type(&mut decl.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_flow_opaque_type(&mut self, decl: &mut FlowOpaqueType<'a>) {
+        let kind = AstType::FlowOpaqueType;
+        self.enter_node(kind);
+        self.enter_field("id");
+        self.visit_binding_identifier(&mut decl.id);
+        if let Some(parameters) = &mut decl.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(supertype) = &mut decl.supertype {
+            self.enter_field("supertype");
+            self.visit_ts_type(supertype);
+        }
+        if let Some(impl_type) = &mut decl.impl_type {
+            self.enter_field("impl_type");
+            self.visit_ts_type(impl_type);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_interface_declaration(&mut self, decl: &mut TSInterfaceDeclaration<'a>) {
+        let kind = AstType::TSInterfaceDeclaration;
+        self.enter_node(kind);
+        self.enter_field("id");
+        self.visit_binding_identifier(&mut decl.id);
+        if let Some(parameters) = &mut decl.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        for signature in decl.body.body.iter_mut() {
+            self.enter_field("body");
+            self.visit_ts_signature(signature);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_as_expression(&mut self, expr: &mut TSAsExpression<'a>) {
+        let kind = AstType::TSAsExpression;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_expression(&mut expr.expression);
+        self.enter_field("type_annotation");
+        self.visit_ts_type(&mut expr.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_non_null_expression(&mut self, expr: &mut TSNonNullExpression<'a>) {
+        let kind = AstType::TSNonNullExpression;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_expression(&mut expr.expression);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_assertion(&mut self, expr: &mut TSTypeAssertion<'a>) {
+        let kind = AstType::TSTypeAssertion;
+        self.enter_node(kind);
+        self.enter_field("expression");
+        self.visit_expression(&mut expr.expression);
+        self.enter_field("type_annotation");
+        self.visit_ts_type(&mut expr.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_instantiation_expression(&mut self, expr: &mut TSInstantiationExpression<'a>) {
+        self.enter_field("expression");
+        self.visit_expression(&mut expr.expression);
+        self.enter_field("type_parameters");
+        self.visit_ts_type_parameter_instantiation(&mut expr.type_parameters);
+    }
+
+    fn visit_ts_type_annotation(&mut self, annotation: &mut TSTypeAnnotation<'a>) {
+        let kind = AstType::TSTypeAnnotation;
+        self.enter_node(kind);
+        self.enter_field("type_annotation");
+        self.visit_ts_type(&mut annotation.type_annotation);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type(&mut self, ty: &mut TSType<'a>) {
+        match ty {
+            TSType::TSAnyKeyword(ty) => self.visit_ts_any_keyword(ty),
+            TSType::TSNullKeyword(ty) => self.visit_ts_null_keyword(ty),
+            TSType::TSVoidKeyword(ty) => self.visit_ts_void_keyword(ty),
+            TSType::TSIntersectionType(ty) => self.visit_ts_intersection_type(ty),
+            TSType::TSTypeReference(ty) => self.visit_ts_type_reference(ty),
+            TSType::TSUnionType(ty) => self.visit_ts_union_type(ty),
+            TSType::TSLiteralType(ty) => self.visit_ts_literal_type(ty),
+            TSType::TSArrayType(ty) => self.visit_ts_array_type(ty),
+            TSType::TSConditionalType(ty) => self.visit_ts_conditional_type(ty),
+            TSType::TSConstructorType(ty) => self.visit_ts_constructor_type(ty),
+            TSType::TSFunctionType(ty) => self.visit_ts_function_type(ty),
+            TSType::TSMappedType(ty) => self.visit_ts_mapped_type(ty),
+            TSType::TSTupleType(ty) => self.visit_ts_tuple_type(ty),
+            TSType::TSTypeOperatorType(ty) => self.visit_ts_type_operator_type(ty),
+            TSType::TSTypePredicate(ty) => self.visit_ts_type_predicate(ty),
+            TSType::TSTypeLiteral(ty) => self.visit_ts_type_literal(ty),
+            TSType::TSIndexedAccessType(ty) => self.visit_ts_indexed_access_type(ty),
+            TSType::TSImportType(ty) => self.visit_ts_import_type(ty),
+            TSType::TSInferType(ty) => self.visit_ts_infer_type(ty),
+            TSType::TSQualifiedName(name) => self.visit_ts_qualified_name(name),
+            TSType::TSTemplateLiteralType(ty) => self.visit_ts_template_literal_type(ty),
+            TSType::TSTypeQuery(ty) => self.visit_ts_type_query(ty),
+            TSType::JSDocNullableType(ty) => {
+                self.enter_field("type_annotation");
+                self.visit_ts_type(&mut ty.type_annotation);
+            }
+            TSType::TSBigIntKeyword(_)
+            | TSType::TSBooleanKeyword(_)
+            | TSType::TSNeverKeyword(_)
+            | TSType::TSNumberKeyword(_)
+            | TSType::TSObjectKeyword(_)
+            | TSType::TSStringKeyword(_)
+            | TSType::TSSymbolKeyword(_)
+            | TSType::TSThisKeyword(_)
+            | TSType::TSUndefinedKeyword(_)
+            | TSType::TSUnknownKeyword(_)
+            | TSType::JSDocUnknownType(_) => {}
+        }
+    }
+
+    fn visit_ts_type_literal(&mut self, ty: &mut TSTypeLiteral<'a>) {
+        let kind = AstType::TSTypeLiteral;
+        self.enter_node(kind);
+        for signature in ty.members.iter_mut() {
+            self.enter_field("members");
+            self.visit_ts_signature(signature);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_indexed_access_type(&mut self, ty: &mut TSIndexedAccessType<'a>) {
+        let kind = AstType::TSIndexedAccessType;
+        self.enter_node(kind);
+        self.enter_field("object_type");
+        self.visit_ts_type(&mut ty.object_type);
+        self.enter_field("index_type");
+        self.visit_ts_type(&mut ty.index_type);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_predicate(&mut self, ty: &mut TSTypePredicate<'a>) {
+        if let Some(annotation) = &mut ty.type_annotation {
+            self.enter_field("type_annotation");
+            self.visit_ts_type_annotation(annotation);
+        }
+    }
+
+    fn visit_ts_type_operator_type(&mut self, ty: &mut TSTypeOperatorType<'a>) {
+        self.enter_field("type_annotation");
+        self.visit_ts_type(&mut ty.type_annotation);
+    }
+
+    fn visit_ts_tuple_type(&mut self, ty: &mut TSTupleType<'a>) {
+        for element in ty.element_types.iter_mut() {
+            self.enter_field("element_types");
+            self.visit_ts_tuple_element(element);
+        }
+    }
+
+    fn visit_ts_tuple_element(&mut self, ty: &mut TSTupleElement<'a>) {
+        match ty {
+            TSTupleElement::TSType(ty) => self.visit_ts_type(ty),
+            TSTupleElement::TSOptionalType(ty) => {
+                self.enter_field("type_annotation");
+                self.visit_ts_type(&mut ty.type_annotation);
+            }
+            TSTupleElement::TSRestType(ty) => {
+                self.enter_field("type_annotation");
+                self.visit_ts_type(&mut ty.type_annotation);
+            }
+            TSTupleElement::TSNamedTupleMember(ty) => {
+                self.enter_field("element_type");
+                self.visit_ts_type(&mut ty.element_type);
+            }
+        };
+    }
+
+    fn visit_ts_mapped_type(&mut self, ty: &mut TSMappedType<'a>) {
+        self.enter_field("type_parameter");
+        self.visit_ts_type_parameter(&mut ty.type_parameter);
+        if let Some(name) = &mut ty.name_type {
+            self.enter_field("name_type");
+            self.visit_ts_type(name);
+        }
+        self.enter_field("type_annotation");
+        self.visit_ts_type(&mut ty.type_annotation);
+    }
+
+    fn visit_ts_function_type(&mut self, ty: &mut TSFunctionType<'a>) {
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut ty.params);
+        if let Some(parameters) = &mut ty.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.enter_field("return_type");
+        self.visit_ts_type_annotation(&mut ty.return_type);
+    }
+
+    fn visit_ts_type_parameter(&mut self, ty: &mut TSTypeParameter<'a>) {
+        let kind = AstType::TSTypeParameter;
+        self.enter_node(kind);
+        if let Some(constraint) = &mut ty.constraint {
+            self.enter_field("constraint");
+            self.visit_ts_type(constraint);
+        }
+
+        if let Some(default) = &mut ty.default {
+            self.enter_field("default");
+            self.visit_ts_type(default);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_parameter_instantiation(&mut self, ty: &mut TSTypeParameterInstantiation<'a>) {
+        let kind = AstType::TSTypeParameterInstantiation;
+        self.enter_node(kind);
+        for ts_parameter in ty.params.iter_mut() {
+            self.enter_field("params");
+            self.visit_ts_type(ts_parameter);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_parameter_declaration(&mut self, ty: &mut TSTypeParameterDeclaration<'a>) {
+        let kind = AstType::TSTypeParameterDeclaration;
+        self.enter_node(kind);
+        for ts_parameter in ty.params.iter_mut() {
+            self.enter_field("params");
+            self.visit_ts_type_parameter(ts_parameter);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_constructor_type(&mut self, ty: &mut TSConstructorType<'a>) {
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut ty.params);
+        if let Some(parameters) = &mut ty.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        self.enter_field("return_type");
+        self.visit_ts_type_annotation(&mut ty.return_type);
+    }
+
+    fn visit_ts_conditional_type(&mut self, ty: &mut TSConditionalType<'a>) {
+        self.enter_field("check_type");
+        self.visit_ts_type(&mut ty.check_type);
+        self.enter_field("extends_type");
+        self.visit_ts_type(&mut ty.extends_type);
+        self.enter_field("true_type");
+        self.visit_ts_type(&mut ty.true_type);
+        self.enter_field("false_type");
+        self.visit_ts_type(&mut ty.false_type);
+    }
+
+    fn visit_ts_array_type(&mut self, ty: &mut TSArrayType<'a>) {
+        self.enter_field("element_type");
+        self.visit_ts_type(&mut ty.element_type);
+    }
+
+    fn visit_ts_type_name(&mut self, name: &mut TSTypeName<'a>) {
+        match name {
+            TSTypeName::IdentifierName(ident) => self.visit_identifier_name(ident),
+            TSTypeName::QualifiedName(name) => self.visit_ts_qualified_name(name),
+        }
+    }
+
+    fn visit_ts_qualified_name(&mut self, name: &mut TSQualifiedName<'a>) {
+        self.enter_field("left");
+        self.visit_ts_type_name(&mut name.left);
+        self.enter_field("right");
+        self.visit_identifier_name(&mut name.right);
+    }
+
+    fn visit_ts_import_type(&mut self, ty: &mut TSImportType<'a>) {
+        self.enter_field("parameter");
+        self.visit_ts_type(&mut ty.parameter);
+        if let Some(name) = &mut ty.qualifier {
+            self.enter_field("qualifier");
+            self.visit_ts_type_name(name);
+        }
+        if let Some(parameters) = &mut ty.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
+    fn visit_ts_infer_type(&mut self, ty: &mut TSInferType<'a>) {
+        self.enter_field("type_parameter");
+        self.visit_ts_type_parameter(&mut ty.type_parameter);
+    }
+
+    fn visit_ts_template_literal_type(&mut self, ty: &mut TSTemplateLiteralType<'a>) {
+        for elem in ty.quasis.iter_mut() {
+            self.enter_field("quasis");
+            self.visit_template_element(elem);
+        }
+        for ty in ty.types.iter_mut() {
+            self.enter_field("types");
+            self.visit_ts_type(ty);
+        }
+    }
+
+    fn visit_ts_type_query(&mut self, ty: &mut TSTypeQuery<'a>) {
+        self.enter_field("expr_name");
+        self.visit_ts_type_name(&mut ty.expr_name);
+        if let Some(parameters) = &mut ty.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+    }
+
+    fn visit_ts_null_keyword(&mut self, _ty: &mut TSNullKeyword) {
+        let kind = AstType::TSNullKeyword;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_any_keyword(&mut self, _ty: &mut TSAnyKeyword) {
+        let kind = AstType::TSAnyKeyword;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_void_keyword(&mut self, _ty: &mut TSVoidKeyword) {
+        let kind = AstType::TSVoidKeyword;
+        self.enter_node(kind);
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_intersection_type(&mut self, ty: &mut TSIntersectionType<'a>) {
+        let kind = AstType::TSIntersectionType;
+        self.enter_node(kind);
+        for ty in ty.types.iter_mut() {
+            self.enter_field("types");
+            self.visit_ts_type(ty);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_type_reference(&mut self, ty: &mut TSTypeReference<'a>) {
+        let kind = AstType::TSTypeReference;
+        self.enter_node(kind);
+        self.enter_field("type_name");
+        self.visit_ts_type_name(&mut ty.type_name);
+        if let Some(parameters) = &mut ty.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_instantiation(parameters);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_union_type(&mut self, ty: &mut TSUnionType<'a>) {
+        let kind = AstType::TSUnionType;
+        self.enter_node(kind);
+        for ty in ty.types.iter_mut() {
+            self.enter_field("types");
+            self.visit_ts_type(ty);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_literal_type(&mut self, ty: &mut TSLiteralType<'a>) {
+        let kind = AstType::TSLiteralType;
+        self.enter_node(kind);
+        self.enter_field("literal");
+        match &mut ty.literal {
+            TSLiteral::BigintLiteral(lit) => self.visit_bigint_literal(lit),
+            TSLiteral::BooleanLiteral(lit) => self.visit_boolean_literal(lit),
+            TSLiteral::NullLiteral(lit) => self.visit_null_literal(lit),
+            TSLiteral::NumberLiteral(lit) => self.visit_number_literal(lit),
+            TSLiteral::RegExpLiteral(lit) => self.visit_reg_expr_literal(lit),
+            TSLiteral::StringLiteral(lit) => self.visit_string_literal(lit),
+            TSLiteral::TemplateLiteral(lit) => self.visit_template_literal(lit),
+            TSLiteral::UnaryExpression(expr) => self.visit_unary_expression(expr),
+        }
+        self.leave_node(kind);
+    }
+
+    #[allow(clippy::single_match)]
+    fn visit_ts_signature(&mut self, signature: &mut TSSignature<'a>) {
+        match signature {
+            TSSignature::TSPropertySignature(sig) => self.visit_ts_property_signature(sig),
+            TSSignature::TSCallSignatureDeclaration(sig) => {
+                self.visit_ts_call_signature_declaration(sig);
+            }
+            TSSignature::TSIndexSignature(sig) => self.visit_ts_index_signature(sig),
+            TSSignature::TSMethodSignature(sig) => self.visit_ts_method_signature(sig),
+            TSSignature::TSConstructSignatureDeclaration(sig) => {
+                self.visit_ts_construct_signature_declaration(sig);
+            }
+        }
+    }
+
+    fn visit_ts_construct_signature_declaration(
+        &mut self,
+        signature: &mut TSConstructSignatureDeclaration<'a>,
+    ) {
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut signature.params);
+        if let Some(parameters) = &mut signature.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &mut signature.return_type {
+            self.enter_field("return_type");
+            self.visit_ts_type_annotation(annotation);
+        }
+    }
+
+    fn visit_ts_method_signature(&mut self, signature: &mut TSMethodSignature<'a>) {
+        let kind = AstType::TSMethodSignature;
+        self.enter_node(kind);
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut signature.params);
+        if let Some(parameters) = &mut signature.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+        if let Some(annotation) = &mut signature.return_type {
+            self.enter_field("return_type");
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_index_signature_name(&mut self, name: &mut TSIndexSignatureName<'a>) {
+        self.enter_field("type_annotation");
+        self.visit_ts_type_annotation(&mut name.type_annotation);
+    }
+
+    fn visit_ts_index_signature(&mut self, signature: &mut TSIndexSignature<'a>) {
+        for name in signature.parameters.iter_mut() {
+            self.enter_field("parameters");
+            self.visit_ts_index_signature_name(name);
+        }
+
+        self.enter_field("type_annotation");
+        self.visit_ts_type_annotation(&mut signature.type_annotation);
+    }
+
+    fn visit_ts_property_signature(&mut self, signature: &mut TSPropertySignature<'a>) {
+        let kind = AstType::TSPropertySignature;
+        self.enter_node(kind);
+        self.enter_field("key");
+        self.visit_property_key(&mut signature.key);
+        if let Some(annotation) = &mut signature.type_annotation {
+            self.enter_field("type_annotation");
+            self.visit_ts_type_annotation(annotation);
+        }
+        self.leave_node(kind);
+    }
+
+    fn visit_ts_call_signature_declaration(
+        &mut self,
+        signature: &mut TSCallSignatureDeclaration<'a>,
+    ) {
+        self.enter_field("params");
+        self.visit_formal_parameters(&mut signature.params);
+        if let Some(parameters) = &mut signature.type_parameters {
+            self.enter_field("type_parameters");
+            self.visit_ts_type_parameter_declaration(parameters);
+        }
+
+        if let Some(annotation) = &mut signature.return_type {
+            self.enter_field("return_type");
+            self.visit_ts_type_annotation(annotation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ordered_float::NotNan;
+    use oxc_allocator::Allocator;
+
+    use super::VisitMut;
+    #[allow(clippy::wildcard_imports)]
+    use crate::{ast::*, AstBuilder, AstType, SourceType, Span};
+
+    struct Transformer<'a> {
+        ast: AstBuilder<'a>,
+        entered: usize,
+    }
+
+    impl<'a> VisitMut<'a> for Transformer<'a> {
+        fn enter_node(&mut self, kind: AstType) {
+            if kind == AstType::ExpressionStatement {
+                self.entered += 1;
+            }
+        }
+
+        fn visit_statements(&mut self, stmts: &mut oxc_allocator::Vec<'a, Statement<'a>>) {
+            // remove `debugger` and insert one after every expression statement
+            let mut body = self.ast.new_vec();
+            for mut stmt in self.ast.move_statement_vec(stmts) {
+                if matches!(stmt, Statement::DebuggerStatement(_)) {
+                    continue;
+                }
+                self.visit_statement(&mut stmt);
+                let is_expression = matches!(stmt, Statement::ExpressionStatement(_));
+                body.push(stmt);
+                if is_expression {
+                    body.push(self.ast.empty_statement(Span::default()));
+                }
+            }
+            *stmts = body;
+        }
+
+        fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+            // replace `a` with `(b)`
+            if let Expression::Identifier(ident) = expr
+                && ident.name == "a"
+            {
+                ident.name = "b".into();
+                let expression = self.ast.move_expression(expr);
+                *expr = self.ast.parenthesized_expression(Span::default(), expression);
+            }
+        }
+    }
+
+    #[test]
+    fn replace_remove_insert() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let ident = ast
+            .identifier_expression(IdentifierReference { span: Span::default(), name: "a".into() });
+        let body = {
+            let mut body = ast.new_vec();
+            body.push(ast.debugger_statement(Span::default()));
+            body.push(ast.expression_statement(Span::default(), ident));
+            body
+        };
+        let mut program = ast.program(Span::default(), ast.new_vec(), body, SourceType::default());
+
+        let mut transformer = Transformer { ast: AstBuilder::new(&allocator), entered: 0 };
+        transformer.visit_program(&mut program);
+
+        assert_eq!(transformer.entered, 1);
+        assert_eq!(program.body.len(), 2);
+        assert!(matches!(program.body[1], Statement::EmptyStatement(_)));
+        let Statement::ExpressionStatement(stmt) = &program.body[0] else { unreachable!() };
+        let Expression::ParenthesizedExpression(expr) = &stmt.expression else { unreachable!() };
+        assert!(matches!(&expr.expression, Expression::Identifier(ident) if ident.name == "b"));
+    }
+
+    struct Renamer;
+
+    impl<'a> VisitMut<'a> for Renamer {
+        fn visit_identifier_name(&mut self, ident: &mut IdentifierName) {
+            ident.name = ident.name.to_uppercase().into();
+        }
+
+        fn visit_binding_identifier(&mut self, ident: &mut BindingIdentifier) {
+            ident.name = format!("_{}", ident.name).into();
+        }
+
+        fn visit_string_literal(&mut self, lit: &mut StringLiteral) {
+            lit.value = format!("./{}", lit.value).into();
+        }
+    }
+
+    #[test]
+    fn rename_import_specifiers() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        // `import { a as b, "c" as d } from "e"`
+        let mut specifiers = ast.new_vec();
+        for (imported, local) in [
+            (
+                ModuleExportName::Identifier(IdentifierName {
+                    span: Span::default(),
+                    name: "a".into(),
+                }),
+                "b",
+            ),
+            (
+                ModuleExportName::StringLiteral(StringLiteral {
+                    span: Span::default(),
+                    value: "c".into(),
+                }),
+                "d",
+            ),
+        ] {
+            specifiers.push(ImportDeclarationSpecifier::ImportSpecifier(ImportSpecifier {
+                span: Span::default(),
+                imported,
+                local: BindingIdentifier { span: Span::default(), name: local.into() },
+                import_kind: ImportOrExportKind::Value,
+            }));
+        }
+        let source = StringLiteral { span: Span::default(), value: "e".into() };
+        let decl = ast.import_declaration(specifiers, source, None, None);
+        let kind = ModuleDeclarationKind::ImportDeclaration(decl);
+        let body = ast.new_vec_single(ast.module_declaration(Span::default(), kind));
+        let mut program = ast.program(Span::default(), ast.new_vec(), body, SourceType::default());
+
+        Renamer.visit_program(&mut program);
+
+        let Statement::ModuleDeclaration(decl) = &program.body[0] else { unreachable!() };
+        let ModuleDeclarationKind::ImportDeclaration(decl) = &decl.kind else { unreachable!() };
+        assert_eq!(decl.source.value, "./e");
+        let names = decl
+            .specifiers
+            .iter()
+            .map(|specifier| {
+                let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier else {
+                    unreachable!()
+                };
+                (specifier.imported.to_string(), specifier.local.name.to_string())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [("A".to_string(), "_b".to_string()), ("./c".to_string(), "_d".to_string())]
+        );
+    }
+
+    struct Doubler;
+
+    impl<'a> VisitMut<'a> for Doubler {
+        fn visit_number_literal(&mut self, lit: &mut NumberLiteral<'a>) {
+            lit.value *= 2.0;
+        }
+    }
+
+    #[test]
+    fn mutate_nested_expressions() {
+        let allocator = Allocator::default();
+        let ast = AstBuilder::new(&allocator);
+        let number = |value: f64| {
+            ast.literal_number_expression(NumberLiteral {
+                span: Span::default(),
+                value: NotNan::new(value).unwrap(),
+                raw: "",
+                base: NumberBase::Decimal,
+            })
+        };
+        // `f(1, [2 + 3])`
+        let sum = ast.binary_expression(
+            Span::default(),
+            number(2.0),
+            BinaryOperator::Addition,
+            number(3.0),
+        );
+        let array = ast.array_expression(
+            Span::default(),
+            ast.new_vec_single(Some(Argument::Expression(sum))),
+            None,
+        );
+        let mut arguments = ast.new_vec();
+        arguments.push(Argument::Expression(number(1.0)));
+        arguments.push(Argument::Expression(array));
+        let callee = ast
+            .identifier_expression(IdentifierReference { span: Span::default(), name: "f".into() });
+        let mut expr = ast.call_expression(Span::default(), callee, arguments, false, None);
+
+        Doubler.visit_expression(&mut expr);
+
+        let mut values = vec![];
+        let Expression::CallExpression(call) = &expr else { unreachable!() };
+        let Argument::Expression(Expression::NumberLiteral(lit)) = &call.arguments[0] else {
+            unreachable!()
+        };
+        values.push(lit.value.into_inner());
+        let Argument::Expression(Expression::ArrayExpression(array)) = &call.arguments[1] else {
+            unreachable!()
+        };
+        let Some(Argument::Expression(Expression::BinaryExpression(sum))) = &array.elements[0]
+        else {
+            unreachable!()
+        };
+        for operand in [&sum.left, &sum.right] {
+            let Expression::NumberLiteral(lit) = operand else { unreachable!() };
+            values.push(lit.value.into_inner());
+        }
+        assert_eq!(values, [2.0, 4.0, 6.0]);
+    }
+}
@@ -21,7 +21,10 @@ impl Command {
             .about("The JavaScript Oxidation Compiler")
             .subcommand_required(true)
             .arg_required_else_help(true)
-            .subcommand(Self::lint_subcommand());
+            .subcommand(Self::lint_subcommand())
+            .subcommand(Self::minify_subcommand())
+            .subcommand(Self::format_subcommand())
+            .subcommand(Self::transform_subcommand());
         Self { inner }
     }
 
@@ -81,6 +84,191 @@ impl Command {
             )
     }
 
+    fn minify_subcommand() -> ClapCommand {
+        ClapCommand::new("minify")
+            .about("Minify JavaScript and TypeScript files.")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("out-dir")
+                    .long("out-dir")
+                    .short('o')
+                    .required(false)
+                    .value_parser(ValueParser::path_buf())
+                    .help("Write minified files into this directory, mirroring the input directory structure. Minified code is printed to stdout when omitted.")
+            )
+            .arg(
+                Arg::new("mangle")
+                    .long("mangle")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .help("Shorten local variable names.")
+            )
+            .arg(
+                Arg::new("keep-fnames")
+                    .long("keep-fnames")
+                    .required(false)
+                    .requires("mangle")
+                    .action(ArgAction::SetTrue)
+                    .help("Do not mangle function names.")
+            )
+            .arg(
+                Arg::new("keep-classnames")
+                    .long("keep-classnames")
+                    .required(false)
+                    .requires("mangle")
+                    .action(ArgAction::SetTrue)
+                    .help("Do not mangle class names.")
+            )
+            .arg(
+                Arg::new("mangle-exports")
+                    .long("mangle-exports")
+                    .required(false)
+                    .requires("mangle")
+                    .action(ArgAction::SetTrue)
+                    .help("Also mangle exported bindings, the names a module exports are kept.")
+            )
+            .arg(
+                Arg::new("reserved")
+                    .long("reserved")
+                    .required(false)
+                    .requires("mangle")
+                    .action(ArgAction::Append)
+                    .help("A name which is not mangled nor given to another variable.")
+            )
+            .arg(
+                Arg::new("compress")
+                    .long("compress")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .help("Apply size-reducing syntax transformations.")
+            )
+            .arg(
+                Arg::new("path")
+                    .value_name("PATH")
+                    .num_args(1..)
+                    .required(true)
+                    .help("File or Directory paths to minify. Directories are scanned recursively.")
+                    .value_parser(ValueParser::path_buf()),
+            )
+    }
+
+    fn format_subcommand() -> ClapCommand {
+        ClapCommand::new("format")
+            .alias("fmt")
+            .about("Format JavaScript and TypeScript files.")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("check")
+                    .long("check")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with("write")
+                    .help("Print the files which are not formatted and exit with an error status if there are any. Formatted code is printed to stdout when neither --check nor --write is given.")
+            )
+            .arg(
+                Arg::new("write")
+                    .long("write")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .help("Format files in place and print the files which were changed.")
+            )
+            .arg(
+                Arg::new("path")
+                    .value_name("PATH")
+                    .num_args(1..)
+                    .required(true)
+                    .help("File or Directory paths to format. Directories are scanned recursively.")
+                    .value_parser(ValueParser::path_buf()),
+            )
+    }
+
+    fn transform_subcommand() -> ClapCommand {
+        ClapCommand::new("transform")
+            .about("Transform TypeScript and JSX files into JavaScript, optionally lowered to an older ECMAScript version.")
+            .arg_required_else_help(true)
+            .arg(
+                Arg::new("out-dir")
+                    .long("out-dir")
+                    .short('o')
+                    .required(false)
+                    .value_parser(ValueParser::path_buf())
+                    .help("Write JavaScript files into this directory, mirroring the input directory structure. Transformed code is printed to stdout when omitted.")
+            )
+            .arg(
+                Arg::new("verbatim-module-syntax")
+                    .long("verbatim-module-syntax")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .help("Only remove imports and exports marked with `type`, keep unused imports.")
+            )
+            .arg(
+                Arg::new("imports-not-used-as-values")
+                    .long("imports-not-used-as-values")
+                    .required(false)
+                    .value_parser(["remove", "preserve", "error"])
+                    .conflicts_with("verbatim-module-syntax")
+                    .help("Remove the imports only used as types, or preserve them for their side effects.")
+            )
+            .arg(
+                Arg::new("jsx")
+                    .long("jsx")
+                    .required(false)
+                    .value_parser(["classic", "automatic"])
+                    .help("Lower JSX to `React.createElement` calls (classic) or to calls imported from `react/jsx-runtime` (automatic). JSX is kept when omitted.")
+            )
+            .arg(
+                Arg::new("jsx-development")
+                    .long("jsx-development")
+                    .required(false)
+                    .action(ArgAction::SetTrue)
+                    .requires("jsx")
+                    .help("Pass the source location of the elements to React, for development builds.")
+            )
+            .arg(
+                Arg::new("jsx-import-source")
+                    .long("jsx-import-source")
+                    .required(false)
+                    .requires("jsx")
+                    .help("Module the automatic runtime is imported from, defaults to `react`.")
+            )
+            .arg(
+                Arg::new("jsx-pragma")
+                    .long("jsx-pragma")
+                    .required(false)
+                    .requires("jsx")
+                    .help("Function creating the elements with the classic runtime, defaults to `React.createElement`.")
+            )
+            .arg(
+                Arg::new("jsx-pragma-frag")
+                    .long("jsx-pragma-frag")
+                    .required(false)
+                    .requires("jsx")
+                    .help("Component of the fragments with the classic runtime, defaults to `React.Fragment`.")
+            )
+            .arg(
+                Arg::new("target")
+                    .long("target")
+                    .required(false)
+                    .value_parser(["es2015", "es2016", "es2017", "es2018", "es2019", "es2020", "es2021", "es2022", "esnext"])
+                    .help("Lower the syntax newer than this version of ECMAScript, defaults to `esnext`.")
+            )
+            .arg(
+                Arg::new("decorators")
+                    .long("decorators")
+                    .required(false)
+                    .value_parser(["legacy", "standard"])
+                    .help("Parse the decorators as TypeScript's experimental decorators (legacy) or as the ECMAScript proposal (standard), and lower them. Decorators are kept when omitted.")
+            )
+            .arg(
+                Arg::new("path")
+                    .value_name("PATH")
+                    .num_args(1..)
+                    .required(true)
+                    .help("File or Directory paths to transform. Directories are scanned recursively.")
+                    .value_parser(ValueParser::path_buf()),
+            )
+    }
+
     #[must_use]
     #[allow(clippy::missing_const_for_fn)]
     pub fn build(self) -> ClapCommand {
@@ -103,6 +291,27 @@ mod test {
         matches.unwrap().clone()
     }
 
+    fn get_minify_matches(arg: &str) -> ArgMatches {
+        let matches = Command::new().build().try_get_matches_from(arg.split(' ')).unwrap();
+        let matches = matches.subcommand_matches("minify");
+        assert!(matches.is_some());
+        matches.unwrap().clone()
+    }
+
+    fn get_format_matches(arg: &str) -> ArgMatches {
+        let matches = Command::new().build().try_get_matches_from(arg.split(' ')).unwrap();
+        let matches = matches.subcommand_matches("format");
+        assert!(matches.is_some());
+        matches.unwrap().clone()
+    }
+
+    fn get_transform_matches(arg: &str) -> ArgMatches {
+        let matches = Command::new().build().try_get_matches_from(arg.split(' ')).unwrap();
+        let matches = matches.subcommand_matches("transform");
+        assert!(matches.is_some());
+        matches.unwrap().clone()
+    }
+
     #[test]
     fn verify_command() {
         Command::new().build().debug_assert();
@@ -200,4 +409,126 @@ mod test {
 
         assert_eq!(compare, vec!["\"./test\"", "\"bar.js\""]);
     }
+
+    #[test]
+    fn test_minify_path() {
+        let matches = get_minify_matches("oxc minify foo.js");
+        assert_eq!(matches.get_one::<PathBuf>("path"), Some(&PathBuf::from("foo.js")));
+        assert_eq!(matches.get_one::<PathBuf>("out-dir"), None);
+        assert!(!matches.get_flag("mangle"));
+        assert!(!matches.get_flag("compress"));
+    }
+
+    #[test]
+    fn test_minify_out_dir() {
+        let matches = get_minify_matches("oxc minify src --out-dir dist");
+        assert_eq!(matches.get_one::<PathBuf>("path"), Some(&PathBuf::from("src")));
+        assert_eq!(matches.get_one::<PathBuf>("out-dir"), Some(&PathBuf::from("dist")));
+    }
+
+    #[test]
+    fn test_minify_flags() {
+        let matches = get_minify_matches("oxc minify --mangle --compress foo.js");
+        assert!(matches.get_flag("mangle"));
+        assert!(matches.get_flag("compress"));
+    }
+
+    #[test]
+    fn test_minify_mangle_options() {
+        let arg = "oxc minify --mangle --keep-fnames --reserved $ --reserved jQuery foo.js";
+        let matches = get_minify_matches(arg);
+        assert!(matches.get_flag("keep-fnames"));
+        assert!(!matches.get_flag("keep-classnames"));
+        assert!(!matches.get_flag("mangle-exports"));
+        assert_eq!(
+            matches.get_many::<String>("reserved").unwrap().collect::<Vec<_>>(),
+            [&"$".to_string(), &"jQuery".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_minify_mangle_options_require_mangle() {
+        let arg = "oxc minify --keep-fnames foo.js";
+        assert!(Command::new().build().try_get_matches_from(arg.split(' ')).is_err());
+    }
+
+    #[test]
+    fn test_format_path() {
+        let matches = get_format_matches("oxc format foo.js bar.ts");
+        assert_eq!(
+            matches.get_many::<PathBuf>("path").unwrap().collect::<Vec<_>>(),
+            [&PathBuf::from("foo.js"), &PathBuf::from("bar.ts")]
+        );
+        assert!(!matches.get_flag("check"));
+        assert!(!matches.get_flag("write"));
+    }
+
+    #[test]
+    fn test_format_flags() {
+        assert!(get_format_matches("oxc format --check src").get_flag("check"));
+        assert!(get_format_matches("oxc fmt --write src").get_flag("write"));
+    }
+
+    #[test]
+    fn test_format_check_conflicts_with_write() {
+        let arg = "oxc format --check --write src";
+        assert!(Command::new().build().try_get_matches_from(arg.split(' ')).is_err());
+    }
+
+    #[test]
+    fn test_transform_path() {
+        let matches = get_transform_matches("oxc transform src --out-dir dist");
+        assert_eq!(matches.get_one::<PathBuf>("path"), Some(&PathBuf::from("src")));
+        assert_eq!(matches.get_one::<PathBuf>("out-dir"), Some(&PathBuf::from("dist")));
+        assert!(!matches.get_flag("verbatim-module-syntax"));
+        assert_eq!(matches.get_one::<String>("imports-not-used-as-values"), None);
+    }
+
+    #[test]
+    fn test_transform_module_options() {
+        let matches = get_transform_matches("oxc transform --verbatim-module-syntax foo.ts");
+        assert!(matches.get_flag("verbatim-module-syntax"));
+        let matches =
+            get_transform_matches("oxc transform --imports-not-used-as-values preserve foo.ts");
+        assert_eq!(
+            matches.get_one::<String>("imports-not-used-as-values"),
+            Some(&"preserve".to_string())
+        );
+        let arg = "oxc transform --imports-not-used-as-values keep foo.ts";
+        assert!(Command::new().build().try_get_matches_from(arg.split(' ')).is_err());
+    }
+
+    #[test]
+    fn test_transform_jsx_options() {
+        let matches = get_transform_matches("oxc transform foo.tsx");
+        assert_eq!(matches.get_one::<String>("jsx"), None);
+        let matches = get_transform_matches(
+            "oxc transform --jsx automatic --jsx-development --jsx-import-source preact foo.tsx",
+        );
+        assert_eq!(matches.get_one::<String>("jsx"), Some(&"automatic".to_string()));
+        assert!(matches.get_flag("jsx-development"));
+        assert_eq!(matches.get_one::<String>("jsx-import-source"), Some(&"preact".to_string()));
+        let arg = "oxc transform --jsx-pragma h foo.tsx";
+        assert!(Command::new().build().try_get_matches_from(arg.split(' ')).is_err());
+    }
+
+    #[test]
+    fn test_transform_target() {
+        let matches = get_transform_matches("oxc transform foo.js");
+        assert_eq!(matches.get_one::<String>("target"), None);
+        let matches = get_transform_matches("oxc transform --target es2017 foo.js");
+        assert_eq!(matches.get_one::<String>("target"), Some(&"es2017".to_string()));
+        let arg = "oxc transform --target es5 foo.js";
+        assert!(Command::new().build().try_get_matches_from(arg.split(' ')).is_err());
+    }
+
+    #[test]
+    fn test_transform_decorators() {
+        let matches = get_transform_matches("oxc transform foo.ts");
+        assert_eq!(matches.get_one::<String>("decorators"), None);
+        let matches = get_transform_matches("oxc transform --decorators legacy foo.ts");
+        assert_eq!(matches.get_one::<String>("decorators"), Some(&"legacy".to_string()));
+        let arg = "oxc transform --decorators 2022-03 foo.ts";
+        assert!(Command::new().build().try_get_matches_from(arg.split(' ')).is_err());
+    }
 }
@@ -0,0 +1,128 @@
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use miette::NamedSource;
+use oxc_allocator::Allocator;
+use oxc_ast::SourceType;
+use oxc_diagnostics::Error;
+use oxc_formatter::{Formatter, FormatterOptions};
+use oxc_parser::Parser;
+use rayon::prelude::*;
+
+use crate::{CliRunResult, FormatOptions, Walk};
+
+pub struct Format {
+    pub options: FormatOptions,
+}
+
+/// Output of a single formatted file
+struct FormattedFile {
+    path: Box<Path>,
+    code: String,
+    changed: bool,
+}
+
+impl Format {
+    #[must_use]
+    pub fn new(options: FormatOptions) -> Self {
+        Self { options }
+    }
+
+    /// # Panics
+    ///
+    /// * When writing to stdout fails.
+    #[must_use]
+    pub fn run(&self) -> CliRunResult {
+        let now = std::time::Instant::now();
+
+        let paths = self
+            .options
+            .paths
+            .iter()
+            .flat_map(|path| Walk::new(path, None).iter())
+            .filter(|path| !SourceType::from_path(path).map_or(false, SourceType::is_json))
+            .collect::<Vec<_>>();
+
+        let results = paths
+            .into_par_iter()
+            .map(|path| {
+                let file = Self::format_path(path)?;
+                if self.options.write && file.changed {
+                    fs::write(&file.path, &file.code).map_err(|e| {
+                        vec![miette::miette!("Failed to write {}: {e}", file.path.display())]
+                    })?;
+                }
+                Ok(file)
+            })
+            .collect::<Vec<Result<FormattedFile, Vec<Error>>>>();
+
+        let mut buf_writer = BufWriter::new(std::io::stdout());
+        let mut number_of_files = 0;
+        let mut number_of_changed_files = 0;
+        let mut number_of_diagnostics = 0;
+
+        for result in results {
+            match result {
+                Ok(file) => {
+                    number_of_files += 1;
+                    if file.changed {
+                        number_of_changed_files += 1;
+                    }
+                    if self.options.check || self.options.write {
+                        if file.changed {
+                            writeln!(buf_writer, "{}", file.path.display()).unwrap();
+                        }
+                    } else {
+                        buf_writer.write_all(file.code.as_bytes()).unwrap();
+                    }
+                }
+                Err(diagnostics) => {
+                    for diagnostic in diagnostics {
+                        number_of_diagnostics += 1;
+                        // Diagnostics go to stderr, stdout only has the printed code
+                        eprint!("{diagnostic:?}");
+                    }
+                }
+            }
+        }
+
+        buf_writer.flush().unwrap();
+
+        CliRunResult::FormatResult {
+            duration: now.elapsed(),
+            number_of_files,
+            number_of_changed_files,
+            number_of_diagnostics,
+            check: self.options.check,
+            write: self.options.write,
+        }
+    }
+
+    fn format_path(path: Box<Path>) -> Result<FormattedFile, Vec<Error>> {
+        let source_text = fs::read_to_string(&path)
+            .map_err(|e| vec![miette::miette!("Failed to read {}: {e}", path.display())])?;
+        // `Walk` only yields files with a valid extension.
+        let source_type = SourceType::from_path(&path).unwrap();
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            let path_cow = path.to_string_lossy();
+            let source = Arc::new(NamedSource::new(path_cow, source_text.clone()));
+            return Err(ret
+                .errors
+                .into_iter()
+                .map(|error| error.with_source_code(source.clone()))
+                .collect());
+        }
+        let program = allocator.alloc(ret.program);
+        let code =
+            Formatter::new(&allocator, &source_text, &ret.trivias, FormatterOptions::default())
+                .build(program);
+        let changed = code != source_text;
+        Ok(FormattedFile { path, code, changed })
+    }
+}
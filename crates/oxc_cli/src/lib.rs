@@ -1,7 +1,10 @@
 mod command;
+mod format;
 // mod git;
+mod minify;
 mod options;
 mod result;
+mod transform;
 mod walk;
 
 use std::{
@@ -20,7 +23,15 @@ use oxc_linter::{Fixer, Linter};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 
-pub use crate::{command::Command, options::CliOptions, result::CliRunResult, walk::Walk};
+pub use crate::{
+    command::Command,
+    format::Format,
+    minify::{gzip_size, Minify},
+    options::{CliOptions, FormatOptions, MinifyOptions, TransformOptions},
+    result::CliRunResult,
+    transform::Transform,
+    walk::Walk,
+};
 
 pub struct Cli {
     pub cli_options: CliOptions,
@@ -49,7 +60,11 @@ impl Cli {
                     .cli_options
                     .paths
                     .iter()
-                    .flat_map(|path| Walk::new(path, &self.cli_options).iter())
+                    .flat_map(|path| {
+                        let ignore_path = (!self.cli_options.no_ignore)
+                            .then_some(self.cli_options.ignore_path.as_str());
+                        Walk::new(path, ignore_path).iter()
+                    })
                     .filter(|path| {
                         if self.cli_options.no_ignore {
                             return true;
@@ -127,16 +142,31 @@ impl Cli {
         let source_text = fs::read_to_string(path).expect("{name} not found");
         let allocator = Allocator::default();
         let source_type = SourceType::from_path(path).expect("incorrect {path:?}");
+        let file_name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
         let parser_source_text = source_text.clone();
-        let ret = Parser::new(&allocator, &parser_source_text, source_type).parse();
-        if !ret.errors.is_empty() {
-            return ret.errors;
-        };
+        let parser = Parser::new(&allocator, &parser_source_text, source_type);
+
+        let result = if source_type.is_json() {
+            let ret = parser.parse_json();
+            let Some(value) = ret.value.filter(|_| ret.errors.is_empty()) else {
+                return ret.errors;
+            };
+            Linter::new().run_json(&value, &source_text, &file_name)
+        } else {
+            let ret = parser.parse();
+            if !ret.errors.is_empty() {
+                return ret.errors;
+            };
+
+            let program = allocator.alloc(ret.program);
+            let trivias = Rc::new(ret.trivias);
+            let ret = SemanticBuilder::new(source_type).build(program, trivias);
+            if !ret.errors.is_empty() {
+                return ret.errors;
+            }
 
-        let program = allocator.alloc(ret.program);
-        let trivias = Rc::new(ret.trivias);
-        let semantic = SemanticBuilder::new(source_type).build(program, trivias);
-        let result = Linter::new().run(&Rc::new(semantic), &source_text, fix);
+            Linter::new().run(&Rc::new(ret.semantic), &source_text, fix)
+        };
 
         if result.is_empty() {
             return vec![];
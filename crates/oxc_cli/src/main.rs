@@ -8,7 +8,10 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use oxc_cli::{Cli, CliOptions, CliRunResult, Command};
+use oxc_cli::{
+    Cli, CliOptions, CliRunResult, Command, Format, FormatOptions, Minify, MinifyOptions,
+    Transform, TransformOptions,
+};
 use oxc_diagnostics::miette;
 
 fn main() -> CliRunResult {
@@ -19,6 +22,22 @@ fn main() -> CliRunResult {
 
     if let Some(command) = Command::new().build().get_matches().subcommand() {
         let (subcommand, matches) = command;
+
+        if subcommand == "minify" {
+            return MinifyOptions::try_from(matches)
+                .map_or(CliRunResult::None, |options| Minify::new(options).run());
+        }
+
+        if subcommand == "format" {
+            return FormatOptions::try_from(matches)
+                .map_or(CliRunResult::None, |options| Format::new(options).run());
+        }
+
+        if subcommand == "transform" {
+            return TransformOptions::try_from(matches)
+                .map_or(CliRunResult::None, |options| Transform::new(options).run());
+        }
+
         let cli_options = CliOptions::try_from(matches);
         if let Ok(cli_options) = cli_options {
             // if cli_options.fix {
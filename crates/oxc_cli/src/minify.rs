@@ -0,0 +1,159 @@
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use flate2::{write::GzEncoder, Compression};
+use miette::NamedSource;
+use oxc_ast::SourceType;
+use oxc_diagnostics::Error;
+use oxc_minifier::{ManglerOptions, Minifier, MinifierOptions};
+use rayon::prelude::*;
+
+use crate::{CliRunResult, MinifyOptions, Walk};
+
+pub struct Minify {
+    pub options: MinifyOptions,
+}
+
+/// Output of a single minified file
+struct MinifiedFile {
+    path: Box<Path>,
+    code: String,
+    original_size: usize,
+    gzip_size: usize,
+}
+
+impl Minify {
+    #[must_use]
+    pub fn new(options: MinifyOptions) -> Self {
+        Self { options }
+    }
+
+    /// # Panics
+    ///
+    /// * When writing to stdout fails.
+    #[must_use]
+    pub fn run(&self) -> CliRunResult {
+        let now = std::time::Instant::now();
+
+        // Pair each file with the root it was found under so the directory structure
+        // can be mirrored in `--out-dir`.
+        let entries = self
+            .options
+            .paths
+            .iter()
+            .flat_map(|root| Walk::new(root, None).iter().map(move |path| (root.as_path(), path)))
+            .filter(|(_, path)| !SourceType::from_path(path).map_or(false, SourceType::is_json))
+            .collect::<Vec<_>>();
+
+        let mangle = self.options.mangle.then(|| ManglerOptions {
+            keep_fnames: self.options.keep_fnames,
+            keep_classnames: self.options.keep_classnames,
+            mangle_exports: self.options.mangle_exports,
+            reserved: self.options.reserved.clone(),
+        });
+        let minifier_options = MinifierOptions { mangle, compress: self.options.compress };
+
+        let results = entries
+            .into_par_iter()
+            .map(|(root, path)| {
+                let file = Self::minify_path(path, minifier_options.clone())?;
+                if let Some(out_dir) = &self.options.out_dir {
+                    let out_path = Self::out_path(out_dir, root, &file.path);
+                    Self::write_file(&out_path, &file.code)?;
+                }
+                Ok(file)
+            })
+            .collect::<Vec<Result<MinifiedFile, Vec<Error>>>>();
+
+        let mut buf_writer = BufWriter::new(std::io::stdout());
+        let mut number_of_files = 0;
+        let mut number_of_diagnostics = 0;
+        let mut original_size = 0;
+        let mut minified_size = 0;
+        let mut gzip_size = 0;
+
+        for result in results {
+            match result {
+                Ok(file) => {
+                    number_of_files += 1;
+                    original_size += file.original_size;
+                    minified_size += file.code.len();
+                    gzip_size += file.gzip_size;
+                    if self.options.out_dir.is_none() {
+                        buf_writer.write_all(file.code.as_bytes()).unwrap();
+                        buf_writer.write_all(b"\n").unwrap();
+                    }
+                }
+                Err(diagnostics) => {
+                    for diagnostic in diagnostics {
+                        number_of_diagnostics += 1;
+                        // Diagnostics go to stderr, stdout only has the printed code
+                        eprint!("{diagnostic:?}");
+                    }
+                }
+            }
+        }
+
+        buf_writer.flush().unwrap();
+
+        CliRunResult::MinifyResult {
+            duration: now.elapsed(),
+            number_of_files,
+            number_of_diagnostics,
+            original_size,
+            minified_size,
+            gzip_size,
+        }
+    }
+
+    fn minify_path(path: Box<Path>, options: MinifierOptions) -> Result<MinifiedFile, Vec<Error>> {
+        let source_text = fs::read_to_string(&path)
+            .map_err(|e| vec![miette::miette!("Failed to read {}: {e}", path.display())])?;
+        // `Walk` only yields files with a valid extension.
+        let source_type = SourceType::from_path(&path).unwrap();
+        match Minifier::new(&source_text, source_type, options).build() {
+            Ok(code) => {
+                let gzip_size = gzip_size(&code);
+                Ok(MinifiedFile { path, code, original_size: source_text.len(), gzip_size })
+            }
+            Err(errors) => {
+                let path_cow = path.to_string_lossy();
+                let source = Arc::new(NamedSource::new(path_cow, source_text.clone()));
+                Err(errors
+                    .into_iter()
+                    .map(|error| error.with_source_code(source.clone()))
+                    .collect())
+            }
+        }
+    }
+
+    /// `root` is the path given on the command line, which is either `path` itself or one of its
+    /// ancestor directories.
+    fn out_path(out_dir: &Path, root: &Path, path: &Path) -> PathBuf {
+        match path.strip_prefix(root) {
+            Ok(relative) if !relative.as_os_str().is_empty() => out_dir.join(relative),
+            _ => out_dir.join(path.file_name().unwrap_or(path.as_os_str())),
+        }
+    }
+
+    fn write_file(out_path: &Path, code: &str) -> Result<(), Vec<Error>> {
+        let write = || {
+            if let Some(parent) = out_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(out_path, code)
+        };
+        write().map_err(|e| vec![miette::miette!("Failed to write {}: {e}", out_path.display())])
+    }
+}
+
+/// Size of the code after gzip compression, as served over the network.
+#[must_use]
+pub fn gzip_size(code: &str) -> usize {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(code.as_bytes()).and_then(|()| encoder.finish()).map_or(0, |buf| buf.len())
+}
@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use clap::ArgMatches;
 use glob::Pattern;
+use oxc_parser::DecoratorFlavor;
+use oxc_transformer::{ImportsNotUsedAsValues, JsxRuntime, ReactJsxOptions, TransformTarget};
 
 pub struct CliOptions {
     pub quiet: bool,
@@ -17,22 +19,7 @@ impl<'a> TryFrom<&'a ArgMatches> for CliOptions {
     type Error = &'a str;
 
     fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
-        let mut paths = vec![];
-
-        for path in matches.get_many::<PathBuf>("path").unwrap() {
-            let glob_result =
-                glob::glob(&path.to_string_lossy()).map_err(|_| "Failed to read glob pattern")?;
-            let globbed = glob_result
-                .map(|path_result| path_result.map_err(|_| "Failed to read path"))
-                .collect::<Result<Vec<PathBuf>, &str>>()?;
-
-            if globbed.is_empty() && path.canonicalize().is_err() {
-                return Err("Unable to find globbed files");
-            }
-
-            paths.extend(globbed);
-        }
-
+        let paths = get_paths(matches)?;
         let ignore_path = get_ignore_path(matches);
         let no_ignore = matches.get_flag("no-ignore");
         let ignore_pattern = get_ignore_pattern(matches);
@@ -49,6 +36,134 @@ impl<'a> TryFrom<&'a ArgMatches> for CliOptions {
     }
 }
 
+#[allow(clippy::struct_excessive_bools)]
+pub struct MinifyOptions {
+    pub paths: Vec<PathBuf>,
+    pub out_dir: Option<PathBuf>,
+    pub mangle: bool,
+    pub keep_fnames: bool,
+    pub keep_classnames: bool,
+    pub mangle_exports: bool,
+    pub reserved: Vec<String>,
+    pub compress: bool,
+}
+
+impl<'a> TryFrom<&'a ArgMatches> for MinifyOptions {
+    type Error = &'a str;
+
+    fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
+        Ok(Self {
+            paths: get_paths(matches)?,
+            out_dir: matches.get_one::<PathBuf>("out-dir").cloned(),
+            mangle: matches.get_flag("mangle"),
+            keep_fnames: matches.get_flag("keep-fnames"),
+            keep_classnames: matches.get_flag("keep-classnames"),
+            mangle_exports: matches.get_flag("mangle-exports"),
+            reserved: matches
+                .get_many::<String>("reserved")
+                .map(|names| names.cloned().collect())
+                .unwrap_or_default(),
+            compress: matches.get_flag("compress"),
+        })
+    }
+}
+
+pub struct FormatOptions {
+    pub paths: Vec<PathBuf>,
+    pub check: bool,
+    pub write: bool,
+}
+
+impl<'a> TryFrom<&'a ArgMatches> for FormatOptions {
+    type Error = &'a str;
+
+    fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
+        Ok(Self {
+            paths: get_paths(matches)?,
+            check: matches.get_flag("check"),
+            write: matches.get_flag("write"),
+        })
+    }
+}
+
+pub struct TransformOptions {
+    pub paths: Vec<PathBuf>,
+    pub out_dir: Option<PathBuf>,
+    pub verbatim_module_syntax: bool,
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+    pub react_jsx: Option<ReactJsxOptions>,
+    pub target: TransformTarget,
+    pub decorators: Option<DecoratorFlavor>,
+}
+
+impl<'a> TryFrom<&'a ArgMatches> for TransformOptions {
+    type Error = &'a str;
+
+    fn try_from(matches: &ArgMatches) -> Result<Self, Self::Error> {
+        let imports_not_used_as_values =
+            match matches.get_one::<String>("imports-not-used-as-values").map(String::as_str) {
+                Some("preserve") => ImportsNotUsedAsValues::Preserve,
+                Some("error") => ImportsNotUsedAsValues::Error,
+                _ => ImportsNotUsedAsValues::Remove,
+            };
+        let react_jsx = matches.get_one::<String>("jsx").map(|runtime| ReactJsxOptions {
+            runtime: match runtime.as_str() {
+                "automatic" => JsxRuntime::Automatic,
+                _ => JsxRuntime::Classic,
+            },
+            development: matches.get_flag("jsx-development"),
+            import_source: matches.get_one::<String>("jsx-import-source").cloned(),
+            pragma: matches.get_one::<String>("jsx-pragma").cloned(),
+            pragma_frag: matches.get_one::<String>("jsx-pragma-frag").cloned(),
+        });
+        let target = match matches.get_one::<String>("target").map(String::as_str) {
+            Some("es2015") => TransformTarget::ES2015,
+            Some("es2016") => TransformTarget::ES2016,
+            Some("es2017") => TransformTarget::ES2017,
+            Some("es2018") => TransformTarget::ES2018,
+            Some("es2019") => TransformTarget::ES2019,
+            Some("es2020") => TransformTarget::ES2020,
+            Some("es2021") => TransformTarget::ES2021,
+            Some("es2022") => TransformTarget::ES2022,
+            _ => TransformTarget::ESNext,
+        };
+        let decorators =
+            matches.get_one::<String>("decorators").map(|flavor| match flavor.as_str() {
+                "legacy" => DecoratorFlavor::Legacy,
+                _ => DecoratorFlavor::Standard,
+            });
+        Ok(Self {
+            paths: get_paths(matches)?,
+            out_dir: matches.get_one::<PathBuf>("out-dir").cloned(),
+            verbatim_module_syntax: matches.get_flag("verbatim-module-syntax"),
+            imports_not_used_as_values,
+            react_jsx,
+            target,
+            decorators,
+        })
+    }
+}
+
+fn get_paths(matches: &ArgMatches) -> Result<Vec<PathBuf>, &'static str> {
+    let mut paths = vec![];
+
+    for path in matches.get_many::<PathBuf>("path").unwrap() {
+        let glob_result =
+            glob::glob(&path.to_string_lossy()).map_err(|_| "Failed to read glob pattern")?;
+        let globbed = glob_result
+            .map(|path_result| path_result.map_err(|_| "Failed to read path"))
+            .collect::<Result<Vec<PathBuf>, &str>>()?;
+
+        if globbed.is_empty() && path.canonicalize().is_err() {
+            return Err("Unable to find globbed files");
+        }
+
+        paths.extend(globbed);
+    }
+
+    Ok(paths)
+}
+
 fn get_ignore_path(matches: &ArgMatches) -> String {
     matches.get_one::<String>("ignore-path").map_or(".eslintignore".to_string(), ToOwned::to_owned)
 }
@@ -16,6 +16,27 @@ pub enum CliRunResult {
         max_warnings_exceeded: bool,
         duration: std::time::Duration,
     },
+    MinifyResult {
+        number_of_files: usize,
+        number_of_diagnostics: usize,
+        original_size: usize,
+        minified_size: usize,
+        gzip_size: usize,
+        duration: std::time::Duration,
+    },
+    FormatResult {
+        number_of_files: usize,
+        number_of_changed_files: usize,
+        number_of_diagnostics: usize,
+        check: bool,
+        write: bool,
+        duration: std::time::Duration,
+    },
+    TransformResult {
+        number_of_files: usize,
+        number_of_diagnostics: usize,
+        duration: std::time::Duration,
+    },
 }
 
 impl Termination for CliRunResult {
@@ -48,6 +69,76 @@ impl Termination for CliRunResult {
 
                 ExitCode::from(0)
             }
+            Self::MinifyResult {
+                number_of_files,
+                number_of_diagnostics,
+                original_size,
+                minified_size,
+                gzip_size,
+                duration,
+            } => {
+                let ms = duration.as_millis();
+                let summary = format!(
+                    "Minified {number_of_files} files in {ms}ms.\n\
+                     Original: {original_size} bytes\n\
+                     Minified: {minified_size} bytes ({:.1}%)\n\
+                     Gzipped:  {gzip_size} bytes ({:.1}%)",
+                    percentage(minified_size, original_size),
+                    percentage(gzip_size, original_size)
+                );
+                report_to_stderr(&summary, number_of_diagnostics)
+            }
+            Self::FormatResult {
+                number_of_files,
+                number_of_changed_files,
+                number_of_diagnostics,
+                check,
+                write,
+                duration,
+            } => {
+                let ms = duration.as_millis();
+                let summary = if write {
+                    format!(
+                        "Formatted {number_of_changed_files} of {number_of_files} files in {ms}ms."
+                    )
+                } else {
+                    format!("Checked {number_of_files} files in {ms}ms.")
+                };
+                let exit_code = report_to_stderr(&summary, number_of_diagnostics);
+
+                if number_of_diagnostics == 0 && check && number_of_changed_files > 0 {
+                    eprintln!("Found {number_of_changed_files} files not formatted.");
+                    return ExitCode::from(1);
+                }
+
+                exit_code
+            }
+            Self::TransformResult { number_of_files, number_of_diagnostics, duration } => {
+                let ms = duration.as_millis();
+                let summary = format!("Transformed {number_of_files} files in {ms}ms.");
+                report_to_stderr(&summary, number_of_diagnostics)
+            }
         }
     }
 }
+
+/// Report the result of a command which prints code to stdout.
+/// It goes to stderr so the printed code can be piped.
+fn report_to_stderr(summary: &str, number_of_diagnostics: usize) -> ExitCode {
+    eprintln!("{summary}");
+
+    if number_of_diagnostics > 0 {
+        eprintln!("Found {number_of_diagnostics} diagnostics.");
+        return ExitCode::from(1);
+    }
+
+    ExitCode::from(0)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn percentage(size: usize, original_size: usize) -> f64 {
+    if original_size == 0 {
+        return 100.0;
+    }
+    size as f64 / original_size as f64 * 100.0
+}
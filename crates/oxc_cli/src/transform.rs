@@ -0,0 +1,206 @@
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use miette::NamedSource;
+use oxc_allocator::Allocator;
+use oxc_ast::SourceType;
+use oxc_diagnostics::Error;
+use oxc_parser::Parser;
+use oxc_printer::{Printer, PrinterOptions};
+use oxc_transformer::{TransformOptions as TransformerOptions, Transformer, TypeScriptOptions};
+use rayon::prelude::*;
+
+use crate::{CliRunResult, TransformOptions, Walk};
+
+pub struct Transform {
+    pub options: TransformOptions,
+}
+
+/// Output of a single transformed file
+struct TransformedFile {
+    path: Box<Path>,
+    code: String,
+}
+
+impl Transform {
+    #[must_use]
+    pub fn new(options: TransformOptions) -> Self {
+        Self { options }
+    }
+
+    /// # Panics
+    ///
+    /// * When writing to stdout fails.
+    #[must_use]
+    pub fn run(&self) -> CliRunResult {
+        let now = std::time::Instant::now();
+
+        // Declaration files only contain types and JSON is not code, there is nothing to emit.
+        let entries = self
+            .options
+            .paths
+            .iter()
+            .flat_map(|root| Walk::new(root, None).iter().map(move |path| (root.as_path(), path)))
+            .filter(|(_, path)| {
+                !SourceType::from_path(path).map_or(false, |source_type| {
+                    source_type.is_typescript_definition() || source_type.is_json()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let options = TransformerOptions {
+            typescript: TypeScriptOptions {
+                verbatim_module_syntax: self.options.verbatim_module_syntax,
+                imports_not_used_as_values: self.options.imports_not_used_as_values,
+            },
+            react_jsx: self.options.react_jsx.clone(),
+            target: self.options.target,
+            decorators: self.options.decorators,
+        };
+        let lowers_jsx = options.react_jsx.is_some();
+
+        let results = entries
+            .into_par_iter()
+            .map(|(root, path)| {
+                let file = Self::transform_path(path, options.clone())?;
+                if let Some(out_dir) = &self.options.out_dir {
+                    let out_path = Self::out_path(out_dir, root, &file.path, lowers_jsx);
+                    Self::write_file(&out_path, &file.code)?;
+                }
+                Ok(file)
+            })
+            .collect::<Vec<Result<TransformedFile, Vec<Error>>>>();
+
+        let mut buf_writer = BufWriter::new(std::io::stdout());
+        let mut number_of_files = 0;
+        let mut number_of_diagnostics = 0;
+
+        for result in results {
+            match result {
+                Ok(file) => {
+                    number_of_files += 1;
+                    if self.options.out_dir.is_none() {
+                        buf_writer.write_all(file.code.as_bytes()).unwrap();
+                    }
+                }
+                Err(diagnostics) => {
+                    for diagnostic in diagnostics {
+                        number_of_diagnostics += 1;
+                        // Diagnostics go to stderr, stdout only has the printed code
+                        eprint!("{diagnostic:?}");
+                    }
+                }
+            }
+        }
+
+        buf_writer.flush().unwrap();
+
+        CliRunResult::TransformResult {
+            duration: now.elapsed(),
+            number_of_files,
+            number_of_diagnostics,
+        }
+    }
+
+    fn transform_path(
+        path: Box<Path>,
+        options: TransformerOptions,
+    ) -> Result<TransformedFile, Vec<Error>> {
+        let source_text = fs::read_to_string(&path)
+            .map_err(|e| vec![miette::miette!("Failed to read {}: {e}", path.display())])?;
+        // `Walk` only yields files with a valid extension.
+        let source_type = SourceType::from_path(&path).unwrap();
+        let allocator = Allocator::default();
+        let mut parser = Parser::new(&allocator, &source_text, source_type);
+        if let Some(flavor) = options.decorators {
+            parser = parser.decorators(flavor);
+        }
+        let ret = parser.parse();
+        if !ret.errors.is_empty() {
+            let path_cow = path.to_string_lossy();
+            let source = Arc::new(NamedSource::new(path_cow, source_text.clone()));
+            return Err(ret
+                .errors
+                .into_iter()
+                .map(|error| error.with_source_code(source.clone()))
+                .collect());
+        }
+        let program = allocator.alloc(ret.program);
+        Transformer::new(&allocator, &source_text, &path, options).build(program);
+        let code = Printer::new(source_text.len(), PrinterOptions::default()).build(program);
+        Ok(TransformedFile { path, code })
+    }
+
+    /// `root` is the path given on the command line, which is either `path` itself or one of its
+    /// ancestor directories. TypeScript extensions are replaced by their JavaScript counterpart,
+    /// JSX extensions are replaced by `js` when JSX is lowered.
+    fn out_path(out_dir: &Path, root: &Path, path: &Path, lowers_jsx: bool) -> PathBuf {
+        let out_path = match path.strip_prefix(root) {
+            Ok(relative) if !relative.as_os_str().is_empty() => out_dir.join(relative),
+            _ => out_dir.join(path.file_name().unwrap_or(path.as_os_str())),
+        };
+        match out_path.extension().and_then(|extension| extension.to_str()) {
+            Some("ts") => out_path.with_extension("js"),
+            Some("tsx" | "jsx") if lowers_jsx => out_path.with_extension("js"),
+            // JSX is emitted as is, like `tsc --jsx preserve`
+            Some("tsx") => out_path.with_extension("jsx"),
+            Some("mts") => out_path.with_extension("mjs"),
+            Some("cts") => out_path.with_extension("cjs"),
+            _ => out_path,
+        }
+    }
+
+    fn write_file(out_path: &Path, code: &str) -> Result<(), Vec<Error>> {
+        let write = || {
+            if let Some(parent) = out_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(out_path, code)
+        };
+        write().map_err(|e| vec![miette::miette!("Failed to write {}: {e}", out_path.display())])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::Transform;
+
+    #[test]
+    fn out_path() {
+        let out_dir = Path::new("dist");
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("src"), Path::new("src/a/b.ts"), false),
+            PathBuf::from("dist/a/b.js")
+        );
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("c.tsx"), Path::new("c.tsx"), false),
+            PathBuf::from("dist/c.jsx")
+        );
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("src"), Path::new("src/d.mts"), false),
+            PathBuf::from("dist/d.mjs")
+        );
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("src"), Path::new("src/e.cts"), false),
+            PathBuf::from("dist/e.cjs")
+        );
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("src"), Path::new("src/f.jsx"), false),
+            PathBuf::from("dist/f.jsx")
+        );
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("src"), Path::new("src/f.jsx"), true),
+            PathBuf::from("dist/f.js")
+        );
+        assert_eq!(
+            Transform::out_path(out_dir, Path::new("c.tsx"), Path::new("c.tsx"), true),
+            PathBuf::from("dist/c.js")
+        );
+    }
+}
@@ -3,17 +3,16 @@ use std::path::Path;
 use ignore::{DirEntry, WalkBuilder};
 use oxc_ast::VALID_EXTENSIONS;
 
-use crate::CliOptions;
-
 pub struct Walk {
     inner: ignore::Walk,
 }
 
 impl Walk {
-    pub fn new<P: AsRef<Path>>(path: P, options: &CliOptions) -> Self {
+    /// `ignore_path` is the name of a custom ignore file (e.g. `.eslintignore`) to respect.
+    pub fn new<P: AsRef<Path>>(path: P, ignore_path: Option<&str>) -> Self {
         let mut inner = WalkBuilder::new(path);
-        if !options.no_ignore {
-            inner.add_custom_ignore_filename(&options.ignore_path);
+        if let Some(ignore_path) = ignore_path {
+            inner.add_custom_ignore_filename(ignore_path);
         }
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
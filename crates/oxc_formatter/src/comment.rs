@@ -0,0 +1,271 @@
+//! Comments
+//!
+//! Comments are not part of the AST, they are attached while formatting:
+//! a comment is printed before the first node starting after it (leading),
+//! or after the node ending on the same line (trailing).
+//! Comments left at the end of a block or a list are printed before the closing bracket (dangling).
+
+use oxc_ast::{CommentKind, Span, Trivias};
+
+use crate::{
+    doc::{Doc, BREAK_PARENT, HARDLINE, LINE, SPACE},
+    Formatter,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Comment<'a> {
+    /// Span of the full comment, including `//`, `/*` and `*/`
+    span: Span,
+    kind: CommentKind,
+    text: &'a str,
+}
+
+impl<'a> Comment<'a> {
+    fn is_block(&self) -> bool {
+        self.kind == CommentKind::MultiLine
+    }
+}
+
+pub fn collect_comments<'a>(source_text: &'a str, trivias: &Trivias) -> Vec<Comment<'a>> {
+    trivias
+        .comments()
+        .map(|(span, kind)| {
+            let span = match kind {
+                CommentKind::SingleLine => Span::new(span.start - 2, span.end),
+                CommentKind::MultiLine => Span::new(span.start - 2, span.end + 2),
+            };
+            let text = &source_text[span.start as usize..span.end as usize];
+            Comment { span, kind, text }
+        })
+        .collect()
+}
+
+/// Whether `text` has a line break after `index`, skipping spaces
+pub fn has_newline(text: &str, index: usize) -> bool {
+    text[index..].trim_start_matches([' ', '\t']).starts_with(['\n', '\r'])
+}
+
+/// Whether `text` has a line break before `index`, skipping spaces
+pub fn has_newline_backwards(text: &str, index: usize) -> bool {
+    text[..index].trim_end_matches([' ', '\t']).ends_with(['\n', '\r'])
+}
+
+/// Skip a single line break at `index`
+fn skip_newline(text: &str, index: usize) -> usize {
+    let rest = &text[index..];
+    if rest.starts_with("\r\n") {
+        index + 2
+    } else if rest.starts_with(['\n', '\r']) {
+        index + 1
+    } else {
+        index
+    }
+}
+
+/// Whether the line following the node ending at `end` is empty,
+/// skipping the separators and the comments on the same line, see `isNextLineEmpty` in Prettier.
+pub fn is_next_line_empty(text: &str, end: usize) -> bool {
+    let mut index = end;
+    loop {
+        let rest = &text[index..];
+        let trimmed = rest.trim_start_matches([' ', '\t', ',', ';']);
+        index += rest.len() - trimmed.len();
+        if trimmed.starts_with("/*")
+            && let Some(comment_end) = trimmed.find("*/")
+            && !trimmed[..comment_end].contains('\n')
+        {
+            index += comment_end + 2;
+            continue;
+        }
+        if trimmed.starts_with("//") {
+            index += trimmed.find(['\n', '\r']).unwrap_or(trimmed.len());
+        }
+        break;
+    }
+    let next_line = skip_newline(text, index);
+    next_line != index && has_newline(text, next_line)
+}
+
+/// Whether the line before `start` is empty, see `isPreviousLineEmpty` in Prettier.
+pub fn is_previous_line_empty(text: &str, start: usize) -> bool {
+    let before = text[..start].trim_end_matches([' ', '\t']);
+    let Some(before) = before.strip_suffix("\r\n").or_else(|| before.strip_suffix(['\n', '\r']))
+    else {
+        return false;
+    };
+    before.trim_end_matches([' ', '\t']).ends_with(['\n', '\r'])
+}
+
+impl<'a> Formatter<'a> {
+    fn peek_comment(&self) -> Option<Comment<'a>> {
+        self.comments.get(self.next_comment).copied()
+    }
+
+    /// Whether there are comments not printed yet before `pos`
+    pub(crate) fn has_comments_before(&self, pos: u32) -> bool {
+        self.peek_comment().map_or(false, |comment| comment.span.end <= pos)
+    }
+
+    /// Whether there is a line comment, or a comment followed by a line break, before `pos`.
+    /// Printing such comments between a keyword and an expression
+    /// would trigger automatic semicolon insertion, e.g. `return // comment`.
+    pub(crate) fn has_line_break_comments_before(&self, pos: u32) -> bool {
+        self.comments[self.next_comment..].iter().take_while(|comment| comment.span.end <= pos).any(
+            |comment| {
+                !comment.is_block()
+                    || comment.text.contains('\n')
+                    || has_newline(self.source_text, comment.span.end as usize)
+            },
+        )
+    }
+
+    /// The comments before the node starting at `start`, see `printLeadingComment` in Prettier.
+    pub(crate) fn leading_comments(&mut self, start: u32) -> Option<Doc<'a>> {
+        if !self.has_comments_before(start) {
+            return None;
+        }
+        let mut parts = vec![];
+        while let Some(comment) = self.peek_comment()
+            && comment.span.end <= start
+        {
+            self.next_comment += 1;
+            let end = comment.span.end as usize;
+            parts.push(self.print_comment(comment));
+            if comment.is_block() {
+                parts.push(if has_newline(self.source_text, end) {
+                    if has_newline_backwards(self.source_text, comment.span.start as usize) {
+                        HARDLINE
+                    } else {
+                        LINE
+                    }
+                } else {
+                    SPACE
+                });
+            } else {
+                parts.push(HARDLINE);
+            }
+            if is_next_line_empty_after_comment(self.source_text, end) {
+                parts.push(HARDLINE);
+            }
+        }
+        Some(self.array(parts))
+    }
+
+    /// Print the node starting at `start` with `format`, preceded by its leading comments.
+    /// The comments are taken first so they are not attached to a child of the node.
+    pub(crate) fn with_leading_comments(
+        &mut self,
+        start: u32,
+        format: impl FnOnce(&mut Self) -> Doc<'a>,
+    ) -> Doc<'a> {
+        let comments = self.leading_comments(start);
+        let doc = format(self);
+        comments.map_or(doc, |comments| self.array([comments, doc]))
+    }
+
+    /// The comments following the node ending at `end` on the same line,
+    /// separated only by whitespace, commas and semicolons.
+    /// See `printTrailingComment` in Prettier.
+    pub(crate) fn trailing_comments(&mut self, end: u32) -> Option<Doc<'a>> {
+        let mut parts = vec![];
+        let mut end = end as usize;
+        while let Some(comment) = self.peek_comment()
+            && comment.span.start as usize >= end
+            && self.source_text[end..comment.span.start as usize]
+                .chars()
+                .all(|c| matches!(c, ' ' | '\t' | ',' | ';'))
+        {
+            self.next_comment += 1;
+            end = comment.span.end as usize;
+            let printed = self.print_comment(comment);
+            if comment.is_block() {
+                parts.extend([SPACE, printed]);
+            } else {
+                let suffix = self.array([SPACE, printed]);
+                parts.extend([self.line_suffix(suffix), BREAK_PARENT]);
+            }
+        }
+        (!parts.is_empty()).then(|| self.array(parts))
+    }
+
+    /// Print `doc` followed by the trailing comments of the node ending at `end`.
+    /// The comments must be taken before the next node is formatted.
+    pub(crate) fn with_trailing_comments(&mut self, end: u32, doc: Doc<'a>) -> Doc<'a> {
+        self.trailing_comments(end).map_or(doc, |comments| self.array([doc, comments]))
+    }
+
+    /// The comments before `end` on their own lines, e.g. at the end of a block.
+    pub(crate) fn dangling_comments(&mut self, end: u32) -> Option<Doc<'a>> {
+        if !self.has_comments_before(end) {
+            return None;
+        }
+        let mut parts = vec![];
+        while let Some(comment) = self.peek_comment()
+            && comment.span.end <= end
+        {
+            self.next_comment += 1;
+            if !parts.is_empty() {
+                parts.push(HARDLINE);
+                if is_previous_line_empty(self.source_text, comment.span.start as usize) {
+                    parts.push(HARDLINE);
+                }
+            }
+            parts.push(self.print_comment(comment));
+            if !comment.is_block() {
+                parts.push(BREAK_PARENT);
+            }
+        }
+        Some(self.array(parts))
+    }
+
+    /// Print the dangling comments of an empty list or block, e.g. `foo(/* comment */)`.
+    /// Line comments are printed on their own lines.
+    pub(crate) fn dangling_comments_in(
+        &mut self,
+        open: &'a str,
+        end: u32,
+        close: &'a str,
+    ) -> Doc<'a> {
+        let all_inline = self.comments[self.next_comment..]
+            .iter()
+            .take_while(|comment| comment.span.end <= end)
+            .all(|comment| comment.is_block() && !comment.text.contains('\n'));
+        match self.dangling_comments(end) {
+            None => self.array([Doc::Str(open), Doc::Str(close)]),
+            Some(comments) if all_inline => self.array([Doc::Str(open), comments, Doc::Str(close)]),
+            Some(comments) => {
+                let indented = self.indent(self.array([HARDLINE, comments]));
+                self.array([Doc::Str(open), indented, HARDLINE, Doc::Str(close)])
+            }
+        }
+    }
+
+    fn print_comment(&self, comment: Comment<'a>) -> Doc<'a> {
+        if comment.is_block() && is_indentable_block_comment(comment.text) {
+            // Realign the `*` of JSDoc style comments with the current indentation
+            let lines = comment.text.lines().enumerate().map(|(i, line)| {
+                if i == 0 {
+                    Doc::Str(line.trim_end())
+                } else {
+                    self.string(&format!(" {}", line.trim()))
+                }
+            });
+            return self.join(HARDLINE, lines);
+        }
+        self.literal_text(comment.text)
+    }
+}
+
+/// Block comments where every line starts with `*`
+fn is_indentable_block_comment(text: &str) -> bool {
+    let mut lines = text.lines().skip(1).peekable();
+    lines.peek().is_some() && lines.all(|line| line.trim_start().starts_with('*'))
+}
+
+/// Whether a blank line follows the comment ending at `end`
+fn is_next_line_empty_after_comment(text: &str, end: usize) -> bool {
+    let rest = &text[end..];
+    let index = end + rest.len() - rest.trim_start_matches([' ', '\t']).len();
+    let next_line = skip_newline(text, index);
+    next_line != index && has_newline(text, next_line)
+}
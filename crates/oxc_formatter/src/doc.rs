@@ -0,0 +1,251 @@
+//! Document IR
+//!
+//! The formatted code is first described as a tree of documents,
+//! which is then laid out by the [printer](crate::printer) within the print width.
+//! See [Prettier's commands](https://github.com/prettier/prettier/blob/main/commands.md)
+//! for the meaning of each document.
+
+use oxc_ast::Atom;
+
+use crate::Formatter;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Doc<'a> {
+    Str(&'a str),
+    Array(&'a [Self]),
+    /// Increase the indentation of the lines inside
+    Indent(&'a Self),
+    /// Try to print the contents on a single line, break all its lines otherwise
+    Group(&'a Group<'a>),
+    /// Print different contents depending on whether the enclosing
+    /// (or the referenced) group is broken
+    IfBreak(&'a IfBreak<'a>),
+    /// Indent the contents when the referenced group is broken
+    IndentIfBreak(&'a Self, GroupId),
+    /// Alternate contents and separators, only breaking the separators needed
+    /// to fit the contents, like text in a paragraph
+    Fill(&'a [Self]),
+    Line(Line),
+    /// Deferred until the next newline, used by trailing line comments
+    LineSuffix(&'a Self),
+    /// Force the enclosing groups to break
+    BreakParent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line {
+    /// A space, or a newline when broken
+    Normal,
+    /// Nothing, or a newline when broken
+    Soft,
+    /// Always a newline
+    Hard,
+    /// Always a newline, without the indentation
+    Literal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupId(pub(crate) usize);
+
+#[derive(Debug)]
+pub struct Group<'a> {
+    pub contents: Doc<'a>,
+    pub should_break: bool,
+    /// Alternatives from the most flat to the most expanded, the first one is `contents`.
+    /// The printer picks the first state that fits (Prettier's `conditionalGroup`).
+    pub expanded_states: Option<&'a [Doc<'a>]>,
+    pub id: Option<GroupId>,
+    /// Whether the contents contain a forced break,
+    /// which differs from `should_break` for conditional groups
+    /// because breaks are not propagated through them.
+    will_break: bool,
+}
+
+#[derive(Debug)]
+pub struct IfBreak<'a> {
+    pub break_contents: Doc<'a>,
+    pub flat_contents: Doc<'a>,
+    pub group_id: Option<GroupId>,
+}
+
+pub const SPACE: Doc<'static> = Doc::Str(" ");
+pub const LINE: Doc<'static> = Doc::Line(Line::Normal);
+pub const SOFTLINE: Doc<'static> = Doc::Line(Line::Soft);
+pub const HARDLINE: Doc<'static> = Doc::Line(Line::Hard);
+pub const LITERALLINE: Doc<'static> = Doc::Line(Line::Literal);
+pub const BREAK_PARENT: Doc<'static> = Doc::BreakParent;
+
+impl<'a> Doc<'a> {
+    /// Whether the document contains a forced break, see `willBreak` in Prettier.
+    pub fn will_break(&self) -> bool {
+        match self {
+            Self::Str(_) => false,
+            Self::Array(docs) | Self::Fill(docs) => docs.iter().any(Doc::will_break),
+            Self::Indent(doc) | Self::IndentIfBreak(doc, _) | Self::LineSuffix(doc) => {
+                doc.will_break()
+            }
+            Self::Group(group) => group.will_break,
+            Self::IfBreak(if_break) => {
+                if_break.break_contents.will_break() || if_break.flat_contents.will_break()
+            }
+            Self::Line(line) => matches!(line, Line::Hard | Line::Literal),
+            Self::BreakParent => true,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Str(s) => s.is_empty(),
+            Self::Array(docs) => docs.iter().all(Doc::is_empty),
+            _ => false,
+        }
+    }
+}
+
+/// Concatenate documents, e.g. `array!(f, "(", expr, ")")`
+macro_rules! array {
+    ($f:expr, $($doc:expr),* $(,)?) => {{
+        let docs = [$(crate::doc::IntoDoc::into_doc($doc)),*];
+        $f.array(docs)
+    }};
+}
+
+pub(crate) use array;
+
+/// Convenience conversion for the [array] macro, so string literals can be mixed with documents.
+pub trait IntoDoc<'a> {
+    fn into_doc(self) -> Doc<'a>;
+}
+
+impl<'a> IntoDoc<'a> for Doc<'a> {
+    fn into_doc(self) -> Self {
+        self
+    }
+}
+
+impl<'a> IntoDoc<'a> for &'a str {
+    fn into_doc(self) -> Doc<'a> {
+        Doc::Str(self)
+    }
+}
+
+impl<'a> IntoDoc<'a> for &'a Atom {
+    fn into_doc(self) -> Doc<'a> {
+        Doc::Str(self)
+    }
+}
+
+/// Document builders
+impl<'a> Formatter<'a> {
+    /// Copy a string into the arena
+    pub(crate) fn string(&self, s: &str) -> Doc<'a> {
+        Doc::Str(self.allocator.alloc_str(s))
+    }
+
+    pub(crate) fn array<I>(&self, docs: I) -> Doc<'a>
+    where
+        I: IntoIterator<Item = Doc<'a>>,
+    {
+        let docs = docs.into_iter().collect::<std::vec::Vec<_>>();
+        Doc::Array(self.allocator.alloc_slice_copy(&docs))
+    }
+
+    pub(crate) fn join<I>(&self, separator: Doc<'a>, docs: I) -> Doc<'a>
+    where
+        I: IntoIterator<Item = Doc<'a>>,
+    {
+        let mut parts = vec![];
+        for (i, doc) in docs.into_iter().enumerate() {
+            if i > 0 {
+                parts.push(separator);
+            }
+            parts.push(doc);
+        }
+        self.array(parts)
+    }
+
+    pub(crate) fn indent(&self, contents: Doc<'a>) -> Doc<'a> {
+        Doc::Indent(self.allocator.alloc(contents))
+    }
+
+    pub(crate) fn group(&self, contents: Doc<'a>) -> Doc<'a> {
+        self.group_with(contents, false, None)
+    }
+
+    /// A group which breaks when the contents contain a forced break or when `should_break` is set.
+    pub(crate) fn group_with(
+        &self,
+        contents: Doc<'a>,
+        should_break: bool,
+        id: Option<GroupId>,
+    ) -> Doc<'a> {
+        let should_break = should_break || contents.will_break();
+        Doc::Group(self.allocator.alloc(Group {
+            contents,
+            should_break,
+            expanded_states: None,
+            id,
+            will_break: should_break,
+        }))
+    }
+
+    /// Try each state in order and print the first one which fits,
+    /// or the last one in break mode, see `conditionalGroup` in Prettier.
+    /// Breaks in the states are not propagated to the enclosing groups.
+    pub(crate) fn conditional_group(&self, states: &[Doc<'a>]) -> Doc<'a> {
+        let contents = states[0];
+        Doc::Group(self.allocator.alloc(Group {
+            contents,
+            should_break: false,
+            expanded_states: Some(self.allocator.alloc_slice_copy(states)),
+            id: None,
+            will_break: contents.will_break(),
+        }))
+    }
+
+    pub(crate) fn if_break(&self, break_contents: Doc<'a>, flat_contents: Doc<'a>) -> Doc<'a> {
+        self.if_group_break(break_contents, flat_contents, None)
+    }
+
+    /// Like [`Formatter::if_break`],
+    /// but checks the group `group_id` instead of the enclosing group.
+    pub(crate) fn if_group_break(
+        &self,
+        break_contents: Doc<'a>,
+        flat_contents: Doc<'a>,
+        group_id: Option<GroupId>,
+    ) -> Doc<'a> {
+        Doc::IfBreak(self.allocator.alloc(IfBreak { break_contents, flat_contents, group_id }))
+    }
+
+    pub(crate) fn indent_if_break(&self, contents: Doc<'a>, group_id: GroupId) -> Doc<'a> {
+        Doc::IndentIfBreak(self.allocator.alloc(contents), group_id)
+    }
+
+    /// `parts` alternates contents and separators: `[content, separator, content, ...]`
+    pub(crate) fn fill(&self, parts: &[Doc<'a>]) -> Doc<'a> {
+        Doc::Fill(self.allocator.alloc_slice_copy(parts))
+    }
+
+    pub(crate) fn line_suffix(&self, contents: Doc<'a>) -> Doc<'a> {
+        Doc::LineSuffix(self.allocator.alloc(contents))
+    }
+
+    pub(crate) fn next_group_id(&mut self) -> GroupId {
+        self.next_group_id += 1;
+        GroupId(self.next_group_id - 1)
+    }
+
+    /// Print `text` with its line breaks, without reindenting the following lines.
+    /// Used for template literals, where the whitespace is significant.
+    /// Line endings are normalized to `\n`.
+    pub(crate) fn literal_text(&self, text: &'a str) -> Doc<'a> {
+        if !text.contains('\n') {
+            return Doc::Str(text);
+        }
+        self.join(
+            LITERALLINE,
+            text.split('\n').map(|line| Doc::Str(line.strip_suffix('\r').unwrap_or(line))),
+        )
+    }
+}
@@ -0,0 +1,215 @@
+//! Binary and logical expressions, see `printBinaryishExpression`
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use super::Context;
+use crate::{
+    doc::{array, Doc, LINE, SOFTLINE},
+    Formatter,
+};
+
+enum Left<'a> {
+    Expression(&'a Expression<'a>),
+    PrivateIdentifier(&'a PrivateIdentifier),
+}
+
+/// The operator and operands of a binary, logical or private `in` expression
+fn binaryish<'a>(expr: &'a Expression<'a>) -> Option<(&'static str, Left<'a>, &'a Expression<'a>)> {
+    match expr {
+        Expression::BinaryExpression(binary) => {
+            Some((binary.operator.as_str(), Left::Expression(&binary.left), &binary.right))
+        }
+        Expression::LogicalExpression(logical) => {
+            Some((logical.operator.as_str(), Left::Expression(&logical.left), &logical.right))
+        }
+        Expression::PrivateInExpression(private_in) => {
+            Some(("in", Left::PrivateIdentifier(&private_in.left), &private_in.right))
+        }
+        _ => None,
+    }
+}
+
+fn operator<'a>(expr: &'a Expression<'a>) -> Option<&'static str> {
+    binaryish(expr).map(|(operator, _, _)| operator)
+}
+
+fn is_logical_operator(operator: &str) -> bool {
+    matches!(operator, "??" | "||" | "&&")
+}
+
+fn precedence(operator: &str) -> u8 {
+    match operator {
+        "??" => 1,
+        "||" => 2,
+        "&&" => 3,
+        "|" => 4,
+        "^" => 5,
+        "&" => 6,
+        "==" | "===" | "!=" | "!==" => 7,
+        "<" | ">" | "<=" | ">=" | "in" | "instanceof" => 8,
+        ">>" | "<<" | ">>>" => 9,
+        "+" | "-" => 10,
+        "*" | "/" | "%" => 11,
+        "**" => 12,
+        _ => 0,
+    }
+}
+
+fn is_multiplicative(operator: &str) -> bool {
+    matches!(operator, "*" | "/" | "%")
+}
+
+fn is_bitshift(operator: &str) -> bool {
+    matches!(operator, ">>" | "<<" | ">>>")
+}
+
+fn is_bitwise(operator: &str) -> bool {
+    is_bitshift(operator) || matches!(operator, "|" | "^" | "&")
+}
+
+/// Whether `a op1 b op2 c` can be printed as a flat chain, see `shouldFlatten`
+fn should_flatten(parent: &str, node: &str) -> bool {
+    if precedence(node) != precedence(parent) {
+        return false;
+    }
+    // x ** y ** z --> x ** (y ** z)
+    if parent == "**" {
+        return false;
+    }
+    // x == y == z --> (x == y) == z
+    if precedence(parent) == precedence("==") {
+        return false;
+    }
+    // x * y % z --> (x * y) % z
+    if (node == "%" && is_multiplicative(parent)) || (parent == "%" && is_multiplicative(node)) {
+        return false;
+    }
+    // x * y / z --> (x * y) / z
+    if node != parent && is_multiplicative(node) && is_multiplicative(parent) {
+        return false;
+    }
+    // x << y << z --> (x << y) << z
+    !(is_bitshift(parent) && is_bitshift(node))
+}
+
+impl<'a> Formatter<'a> {
+    pub(crate) fn format_binaryish(&mut self, expr: &'a Expression<'a>, ctx: Context) -> Doc<'a> {
+        let parts = self.format_binaryish_parts(expr, false);
+        match ctx {
+            Context::Test => return self.array(parts),
+            // Break inside the parentheses of `(a + b).c`
+            Context::Operand => {
+                let parts = self.indent(array!(self, SOFTLINE, self.array(parts)));
+                return self.group(array!(self, parts, SOFTLINE));
+            }
+            _ => {}
+        }
+        let Some((op, left, right)) = binaryish(expr) else { unreachable!() };
+        let should_inline = Self::should_inline_logical_expression(expr);
+        let same_precedence_sub_expression = matches!(left, Left::Expression(left)
+            if operator(left).map_or(false, |left| should_flatten(op, left)));
+        if ctx == Context::NoIndent
+            || (should_inline && !same_precedence_sub_expression)
+            || (!should_inline && ctx == Context::Assignment)
+        {
+            return self.group(self.array(parts));
+        }
+        let first_group = parts.iter().position(|part| matches!(part, Doc::Group(_)));
+        let head_len = first_group.map_or(1, |index| index + 1);
+        let has_jsx = matches!(right, Expression::JSXElement(_) | Expression::JSXFragment(_));
+        let rest_end = if has_jsx { parts.len() - 1 } else { parts.len() };
+        let rest = self.array(parts[head_len..rest_end].iter().copied());
+        let mut chain = parts[..head_len].to_vec();
+        chain.push(self.indent(rest));
+        if !has_jsx {
+            return self.group(self.array(chain));
+        }
+        let id = self.next_group_id();
+        let chain = self.group_with(self.array(chain), false, Some(id));
+        let jsx = self.indent_if_break(parts[parts.len() - 1], id);
+        self.group(array!(self, chain, jsx))
+    }
+
+    /// The operands and operators of a chain of binary expressions
+    /// with the same precedence, e.g. `a + b - c`, see `printBinaryishExpressions`.
+    fn format_binaryish_parts(&mut self, expr: &'a Expression<'a>, nested: bool) -> Vec<Doc<'a>> {
+        let Some((op, left, right)) = binaryish(expr) else { unreachable!() };
+        let mut parts = match left {
+            Left::Expression(left)
+                if operator(left).map_or(false, |left| should_flatten(op, left)) =>
+            {
+                self.format_binaryish_parts(left, true)
+            }
+            Left::Expression(left) => {
+                let left = self.format_operand(op, left, false);
+                vec![self.group(left)]
+            }
+            Left::PrivateIdentifier(ident) => vec![array!(self, "#", &ident.name)],
+        };
+        let should_inline = Self::should_inline_logical_expression(expr);
+        let right_doc = self.format_operand(op, right, true);
+        let right_doc = if should_inline {
+            array!(self, op, " ", right_doc)
+        } else {
+            array!(self, op, LINE, right_doc)
+        };
+        // Chains of the same kind of expressions break together
+        let kind = std::mem::discriminant(expr);
+        let is_same_kind = |expr: &Expression<'a>| std::mem::discriminant(expr) == kind;
+        let should_group = !nested
+            && !matches!(left, Left::Expression(left) if is_same_kind(left))
+            && !is_same_kind(right);
+        parts.push(Doc::Str(" "));
+        parts.push(if should_group { self.group(right_doc) } else { right_doc });
+        parts
+    }
+
+    fn format_operand(&mut self, op: &str, operand: &'a Expression<'a>, is_right: bool) -> Doc<'a> {
+        let ctx = match operand {
+            // `a && (<div />)` when broken
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => Context::NoIndent,
+            _ => Context::Default,
+        };
+        let doc = self.format_expression(operand, ctx);
+        if Self::binary_operand_needs_parens(op, operand, is_right) {
+            array!(self, "(", doc, ")")
+        } else {
+            doc
+        }
+    }
+
+    /// Parentheses added for clarity, e.g. `(a && b) || c` and `(a % b) + c`, see `needsParens`
+    pub(crate) fn binary_operand_needs_parens(
+        parent: &str,
+        operand: &'a Expression<'a>,
+        is_right: bool,
+    ) -> bool {
+        let Some(node) = operator(operand) else {
+            return false;
+        };
+        let (parent_precedence, node_precedence) = (precedence(parent), precedence(node));
+        if parent_precedence > node_precedence
+            || (parent_precedence == node_precedence && (is_right || !should_flatten(parent, node)))
+        {
+            return true;
+        }
+        if parent_precedence < node_precedence && node == "%" {
+            return matches!(parent, "+" | "-");
+        }
+        is_bitwise(parent) || (is_logical_operator(parent) && is_logical_operator(node))
+    }
+
+    /// `a && { b }` and `a || [b]` keep the object or the array on the same line
+    pub(crate) fn should_inline_logical_expression(expr: &Expression<'a>) -> bool {
+        let Expression::LogicalExpression(logical) = expr else {
+            return false;
+        };
+        match &logical.right {
+            Expression::ObjectExpression(object) => !object.properties.is_empty(),
+            Expression::ArrayExpression(array) => !array.elements.is_empty(),
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,716 @@
+//! Calls, member expressions and member chains
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan};
+
+use super::{Context, Format};
+use crate::{
+    comment::is_next_line_empty,
+    doc::{array, Doc, BREAK_PARENT, HARDLINE, LINE, SOFTLINE},
+    Formatter,
+};
+
+/// A node of a flattened member chain, e.g. `a`, `.b`, `(c)` in `a.b(c)`
+#[derive(Clone, Copy)]
+enum ChainNode<'a> {
+    Head(&'a Expression<'a>),
+    Call(&'a CallExpression<'a>),
+    Member(&'a MemberExpression<'a>),
+    NonNull,
+}
+
+impl<'a> ChainNode<'a> {
+    fn is_call(self) -> bool {
+        matches!(self, Self::Call(_) | Self::Head(Expression::CallExpression(_)))
+    }
+
+    fn is_member(self) -> bool {
+        matches!(self, Self::Member(_))
+    }
+
+    /// `a[0]` and `a["b"]`
+    fn is_computed_literal(self) -> bool {
+        matches!(self, Self::Member(MemberExpression::ComputedMemberExpression(member))
+            if member.expression.is_literal_expression())
+    }
+}
+
+/// Calls on the first object of the chain which are kept on the same line, e.g. `Object.keys`
+fn is_factory(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) || name.chars().all(|c| c == '$' || c == '_')
+}
+
+/// `it("does something", () => {})` and friends, see `isTestCall`
+fn is_test_call(call: &CallExpression) -> bool {
+    const PATTERNS: &[&str] = &[
+        "it",
+        "it.only",
+        "it.skip",
+        "describe",
+        "describe.only",
+        "describe.skip",
+        "test",
+        "test.only",
+        "test.skip",
+        "test.step",
+        "test.describe",
+        "test.describe.only",
+        "skip",
+        "xit",
+        "xdescribe",
+        "xtest",
+        "fit",
+        "fdescribe",
+        "ftest",
+    ];
+    if !matches!(call.arguments.len(), 2 | 3) {
+        return false;
+    }
+    let Some(callee) = callee_name(&call.callee) else {
+        return false;
+    };
+    if !PATTERNS.contains(&callee.as_str()) {
+        return false;
+    }
+    let args = &call.arguments;
+    if !matches!(
+        &args[0],
+        Argument::Expression(Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+    ) {
+        return false;
+    }
+    if args.len() == 3 && !matches!(&args[2], Argument::Expression(Expression::NumberLiteral(_))) {
+        return false;
+    }
+    match &args[1] {
+        Argument::Expression(Expression::FunctionExpression(func)) => {
+            args.len() == 2 || func.params.items.len() <= 1
+        }
+        Argument::Expression(Expression::ArrowFunctionExpression(arrow)) => {
+            args.len() == 2 || (!arrow.expression && arrow.params.items.len() <= 1)
+        }
+        _ => false,
+    }
+}
+
+/// `a.b.c` as a string
+fn callee_name(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::Identifier(ident) => Some(ident.name.to_string()),
+        Expression::MemberExpression(member) => match &**member {
+            MemberExpression::StaticMemberExpression(member) => {
+                Some(format!("{}.{}", callee_name(&member.object)?, member.property.name))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// See `printCallExpression`
+    pub(crate) fn format_call(&mut self, call: &'a CallExpression<'a>, ctx: Context) -> Doc<'a> {
+        // Keep `require("a")` and test declarations on one line
+        if (call.callee.is_specific_id("require") && call.arguments.len() == 1)
+            || is_test_call(call)
+        {
+            let callee = self.format_expression(&call.callee, Context::Operand);
+            let args = call.arguments.iter().map(|arg| arg.format(self)).collect::<Vec<_>>();
+            let args = self.join(Doc::Str(", "), args);
+            return array!(self, callee, "(", args, ")");
+        }
+        if matches!(call.callee, Expression::MemberExpression(_)) {
+            return self.format_member_chain(call, ctx);
+        }
+        let callee = self.format_expression(&call.callee, Context::Operand);
+        let optional = if call.optional { "?." } else { "" };
+        let type_arguments = self.format_type_arguments(call.type_parameters.as_deref());
+        let arguments = self.format_arguments(&call.arguments, call.span.end, false);
+        let doc = array!(self, callee, optional, type_arguments, arguments);
+        // Curried calls, e.g. `a(b)(c)`
+        if matches!(call.callee, Expression::CallExpression(_)) {
+            self.group(doc)
+        } else {
+            doc
+        }
+    }
+
+    pub(crate) fn format_new(&mut self, new: &'a NewExpression<'a>) -> Doc<'a> {
+        let callee = self.format_expression(&new.callee, Context::Operand);
+        let type_arguments = self.format_type_arguments(new.type_parameters.as_deref());
+        let arguments = self.format_arguments(&new.arguments, new.span.end, false);
+        array!(self, "new ", callee, type_arguments, arguments)
+    }
+
+    /// `import("a")` never has a trailing comma
+    pub(crate) fn format_import_expression(&mut self, import: &'a ImportExpression<'a>) -> Doc<'a> {
+        let mut args = vec![Formatter::strip_parens(&import.source).format(self)];
+        for arg in &import.arguments {
+            args.push(Formatter::strip_parens(arg).format(self));
+        }
+        let separator = array!(self, ",", LINE);
+        let args = self.join(separator, args);
+        let args = self.indent(array!(self, SOFTLINE, args));
+        self.group(array!(self, "import(", args, SOFTLINE, ")"))
+    }
+
+    /// See `printMemberExpression`
+    pub(crate) fn format_member(
+        &mut self,
+        member: &'a MemberExpression<'a>,
+        ctx: Context,
+    ) -> Doc<'a> {
+        let object = self.format_expression(member.object(), Context::Operand);
+        let lookup = self.format_member_lookup(member);
+        let should_inline = matches!(member, MemberExpression::ComputedMemberExpression(_))
+            || (matches!(
+                member.object(),
+                Expression::Identifier(_) | Expression::ThisExpression(_)
+            ) && ctx != Context::Operand);
+        if should_inline {
+            return array!(self, object, lookup);
+        }
+        let lookup = self.group(self.indent(array!(self, SOFTLINE, lookup)));
+        array!(self, object, lookup)
+    }
+
+    /// `.b`, `?.b`, `[b]` and `#b`, see `printMemberLookup`
+    fn format_member_lookup(&mut self, member: &'a MemberExpression<'a>) -> Doc<'a> {
+        let optional = member.optional();
+        match member {
+            MemberExpression::StaticMemberExpression(member) => {
+                array!(self, if optional { "?." } else { "." }, &member.property.name)
+            }
+            MemberExpression::PrivateFieldExpression(member) => {
+                array!(self, if optional { "?.#" } else { ".#" }, &member.field.name)
+            }
+            MemberExpression::ComputedMemberExpression(member) => {
+                let open = if optional { "?.[" } else { "[" };
+                let property = Formatter::strip_parens(&member.expression);
+                let property_doc = property.format(self);
+                if matches!(property, Expression::NumberLiteral(_)) {
+                    return array!(self, open, property_doc, "]");
+                }
+                let property_doc = self.indent(array!(self, SOFTLINE, property_doc));
+                self.group(array!(self, open, property_doc, SOFTLINE, "]"))
+            }
+        }
+    }
+
+    /// Print `a.b().c().d()` on one line when it fits, and one call per line otherwise,
+    /// see `printMemberChain`.
+    #[allow(clippy::too_many_lines)]
+    fn format_member_chain(&mut self, call: &'a CallExpression<'a>, ctx: Context) -> Doc<'a> {
+        // Flatten the chain, from the innermost object to the outermost call
+        let mut nodes = vec![ChainNode::Call(call)];
+        let mut expr = &call.callee;
+        loop {
+            match expr {
+                Expression::CallExpression(call)
+                    if matches!(
+                        call.callee,
+                        Expression::MemberExpression(_) | Expression::CallExpression(_)
+                    ) =>
+                {
+                    nodes.push(ChainNode::Call(call));
+                    expr = &call.callee;
+                }
+                Expression::MemberExpression(member) => {
+                    nodes.push(ChainNode::Member(member));
+                    expr = member.object();
+                }
+                Expression::TSNonNullExpression(non_null) => {
+                    nodes.push(ChainNode::NonNull);
+                    expr = &non_null.expression;
+                }
+                _ => {
+                    nodes.push(ChainNode::Head(expr));
+                    break;
+                }
+            }
+        }
+        nodes.reverse();
+
+        // Print in source order so the comments are attached to the right nodes
+        let printed = nodes
+            .iter()
+            .map(|node| match *node {
+                ChainNode::Head(expr) => self.format_expression(expr, Context::Operand),
+                ChainNode::Call(call) => {
+                    let optional = if call.optional { "?." } else { "" };
+                    let type_arguments =
+                        self.format_type_arguments(call.type_parameters.as_deref());
+                    let arguments = self.format_arguments(&call.arguments, call.span.end, false);
+                    array!(self, optional, type_arguments, arguments)
+                }
+                ChainNode::Member(member) => {
+                    // Comments between the object and the property, e.g. `a // comment \n .b()`
+                    let start = match member {
+                        MemberExpression::StaticMemberExpression(member) => {
+                            member.property.span.start
+                        }
+                        MemberExpression::PrivateFieldExpression(member) => member.field.span.start,
+                        MemberExpression::ComputedMemberExpression(member) => {
+                            member.expression.span().start
+                        }
+                    };
+                    self.with_leading_comments(start, |f| f.format_member_lookup(member))
+                }
+                ChainNode::NonNull => Doc::Str("!"),
+            })
+            .collect::<Vec<_>>();
+
+        // The first group is the head with the calls and computed accesses directly on it,
+        // e.g. `a()()` or `this.x.y` in `this.x.y.z()`
+        let mut groups: Vec<std::ops::Range<usize>> = vec![];
+        let mut i = 1;
+        while i < nodes.len() && matches!(nodes[i], ChainNode::NonNull | ChainNode::Call(_))
+            || (i < nodes.len() && nodes[i].is_computed_literal())
+        {
+            i += 1;
+        }
+        if !nodes[0].is_call() {
+            while i + 1 < nodes.len() && nodes[i].is_member() && nodes[i + 1].is_member() {
+                i += 1;
+            }
+        }
+        groups.push(0..i);
+        // The other groups are member accesses followed by calls, e.g. `.b.c()`
+        let mut start = i;
+        let mut has_seen_call = false;
+        while i < nodes.len() {
+            if has_seen_call && nodes[i].is_member() && !nodes[i].is_computed_literal() {
+                groups.push(start..i);
+                start = i;
+                has_seen_call = false;
+            }
+            if nodes[i].is_call() {
+                has_seen_call = true;
+            }
+            i += 1;
+        }
+        if start < nodes.len() {
+            groups.push(start..nodes.len());
+        }
+
+        let should_merge = groups.len() >= 2 && self.should_not_wrap(&nodes, &groups, ctx);
+        let printed_groups = groups
+            .iter()
+            .map(|range| self.array(printed[range.clone()].iter().copied()))
+            .collect::<Vec<_>>();
+        let one_line = self.array(printed_groups.iter().copied());
+        let cutoff = if should_merge { 3 } else { 2 };
+        if groups.len() <= cutoff
+            && !printed_groups[cutoff.min(groups.len())..].iter().any(Doc::will_break)
+        {
+            return self.group(one_line);
+        }
+
+        let rest_start = if should_merge { 2 } else { 1 };
+        let rest = self.join(HARDLINE, printed_groups[rest_start..].iter().copied());
+        let indented = self.indent(array!(self, HARDLINE, rest));
+        let merged = if should_merge { printed_groups[1] } else { Doc::Str("") };
+        let expanded = array!(self, printed_groups[0], merged, indented);
+
+        let calls = nodes.iter().filter_map(|node| match node {
+            ChainNode::Call(call) => Some(*call),
+            _ => None,
+        });
+        let calls = calls.collect::<Vec<_>>();
+        // Break chains with more than two calls with complex arguments,
+        // and chains where a group other than the last one breaks
+        if (calls.len() > 2
+            && calls.iter().any(|call| {
+                !call.arguments.iter().all(|arg| Self::is_simple_call_argument(arg, 0))
+            }))
+            || printed_groups[..printed_groups.len() - 1].iter().any(Doc::will_break)
+        {
+            return self.group(expanded);
+        }
+        let break_parent = if one_line.will_break() { BREAK_PARENT } else { Doc::Str("") };
+        let states = self.conditional_group(&[one_line, expanded]);
+        array!(self, break_parent, states)
+    }
+
+    /// Whether the first call is kept on the line of the head, e.g. `z.object()`,
+    /// see `shouldNotWrap`
+    fn should_not_wrap(
+        &self,
+        nodes: &[ChainNode<'a>],
+        groups: &[std::ops::Range<usize>],
+        ctx: Context,
+    ) -> bool {
+        let has_computed =
+            groups[1].clone().next().map_or(false, |i| nodes[i].is_computed_literal());
+        if groups[0].len() == 1 {
+            return match nodes[0] {
+                ChainNode::Head(Expression::ThisExpression(_)) => true,
+                ChainNode::Head(Expression::Identifier(ident)) => {
+                    is_factory(&ident.name)
+                        || (ctx == Context::ExpressionStatement
+                            && ident.name.len() <= self.options.tab_width)
+                        || has_computed
+                }
+                _ => false,
+            };
+        }
+        match nodes[groups[0].end - 1] {
+            ChainNode::Member(MemberExpression::StaticMemberExpression(member)) => {
+                is_factory(&member.property.name) || has_computed
+            }
+            _ => false,
+        }
+    }
+
+    /// Arguments which do not make the chain complex, see `isSimpleCallArgument`
+    fn is_simple_call_argument(arg: &'a Argument<'a>, depth: usize) -> bool {
+        match arg {
+            Argument::SpreadElement(_) => false,
+            Argument::Expression(expr) => Self::is_simple_expression(expr, depth),
+        }
+    }
+
+    fn is_simple_call_arguments(args: &'a [Argument<'a>], depth: usize) -> bool {
+        args.len() <= depth && args.iter().all(|arg| Self::is_simple_call_argument(arg, depth + 1))
+    }
+
+    fn is_simple_expression(expr: &'a Expression<'a>, depth: usize) -> bool {
+        let is_child_simple =
+            |expr: &'a Expression<'a>| Self::is_simple_expression(expr, depth + 1);
+        match expr {
+            Expression::RegExpLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumberLiteral(_)
+            | Expression::BigintLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::Identifier(_)
+            | Expression::ThisExpression(_)
+            | Expression::Super(_)
+            | Expression::MetaProperty(_)
+            | Expression::FunctionExpression(_)
+            | Expression::UpdateExpression(_) => true,
+            Expression::ParenthesizedExpression(expr) => {
+                Self::is_simple_expression(&expr.expression, depth)
+            }
+            Expression::TemplateLiteral(lit) => {
+                lit.quasis.iter().all(|quasi| !quasi.value.raw.contains('\n'))
+                    && lit.expressions.iter().all(is_child_simple)
+            }
+            Expression::ObjectExpression(object) => {
+                object.properties.iter().all(|prop| match prop {
+                    ObjectProperty::Property(prop) => {
+                        !prop.computed
+                            && (prop.shorthand
+                                || matches!(&prop.value,
+                                PropertyValue::Expression(value) if is_child_simple(value)))
+                    }
+                    ObjectProperty::SpreadProperty(_) => false,
+                })
+            }
+            Expression::ArrayExpression(array) => {
+                array.elements.iter().all(|element| match element {
+                    None => true,
+                    Some(Argument::Expression(expr)) => is_child_simple(expr),
+                    Some(Argument::SpreadElement(_)) => false,
+                })
+            }
+            Expression::ImportExpression(import) => is_child_simple(&import.source),
+            Expression::CallExpression(call) => {
+                Self::is_simple_expression(&call.callee, depth)
+                    && Self::is_simple_call_arguments(&call.arguments, depth)
+            }
+            Expression::NewExpression(new) => {
+                Self::is_simple_expression(&new.callee, depth)
+                    && Self::is_simple_call_arguments(&new.arguments, depth)
+            }
+            Expression::ArrowFunctionExpression(arrow) => {
+                !arrow.expression
+                    || Self::arrow_body_expression(arrow).map_or(false, |body| {
+                        !matches!(body, Expression::ConditionalExpression(_))
+                            && Self::is_simple_expression(body, depth)
+                    })
+            }
+            Expression::UnaryExpression(expr) => {
+                matches!(
+                    expr.operator,
+                    UnaryOperator::LogicalNot
+                        | UnaryOperator::UnaryNegation
+                        | UnaryOperator::UnaryPlus
+                        | UnaryOperator::BitwiseNot
+                ) && Self::is_simple_expression(&expr.argument, depth)
+            }
+            Expression::TSNonNullExpression(expr) => {
+                Self::is_simple_expression(&expr.expression, depth)
+            }
+            Expression::MemberExpression(member) => {
+                Self::is_simple_expression(member.object(), depth)
+                    && match &**member {
+                        MemberExpression::ComputedMemberExpression(member) => {
+                            Self::is_simple_expression(&member.expression, depth)
+                        }
+                        _ => true,
+                    }
+            }
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => {
+                    Self::is_simple_expression(&call.callee, depth)
+                        && Self::is_simple_call_arguments(&call.arguments, depth)
+                }
+                ChainElement::MemberExpression(member) => {
+                    Self::is_simple_expression(member.object(), depth)
+                }
+            },
+            _ => false,
+        }
+    }
+
+    /// See `printCallArguments`. `end` is the end of the call, used for dangling comments.
+    pub(crate) fn format_arguments(
+        &mut self,
+        args: &'a [Argument<'a>],
+        end: u32,
+        is_dynamic_import: bool,
+    ) -> Doc<'a> {
+        if args.is_empty() {
+            return self.dangling_comments_in("(", end, ")");
+        }
+        // useEffect(() => { ... }, [foo, bar, baz])
+        if Self::is_react_hook_call_with_deps_array(args) {
+            let first = args[0].format(self);
+            let second = args[1].format(self);
+            return array!(self, "(", first, ", ", second, ")");
+        }
+
+        let mut any_arg_empty_line = false;
+        let mut printed = vec![];
+        let mut checkpoints = vec![];
+        for (index, arg) in args.iter().enumerate() {
+            checkpoints.push(self.next_comment);
+            let doc = arg.format(self);
+            let doc = self.with_trailing_comments(arg.span().end, doc);
+            if index + 1 == args.len() {
+                printed.push(doc);
+            } else if is_next_line_empty(self.source_text, arg.span().end as usize) {
+                any_arg_empty_line = true;
+                printed.push(array!(self, doc, ",", HARDLINE, HARDLINE));
+            } else {
+                printed.push(array!(self, doc, ",", LINE));
+            }
+        }
+        let trailing_comma =
+            if !is_dynamic_import && self.options.trailing_comma.all() { "," } else { "" };
+
+        let all_args_broken_out = |f: &mut Self, printed: &[Doc<'a>]| {
+            let args = f.indent(array!(f, LINE, f.array(printed.iter().copied()), trailing_comma));
+            f.group_with(array!(f, "(", args, LINE, ")"), true, None)
+        };
+
+        if any_arg_empty_line || Self::is_function_composition_args(args) {
+            return all_args_broken_out(self, &printed);
+        }
+
+        if self.could_group_last_arg(args) {
+            if printed[..printed.len() - 1].iter().any(Doc::will_break) {
+                return all_args_broken_out(self, &printed);
+            }
+            let end_comment = self.next_comment;
+            self.next_comment = checkpoints[args.len() - 1];
+            let last = self.format_expanded_argument(&args[args.len() - 1]);
+            let last = self.with_trailing_comments(args[args.len() - 1].span().end, last);
+            self.next_comment = end_comment;
+            let mut expanded = printed[..printed.len() - 1].to_vec();
+            expanded.push(last);
+            let simple = array!(self, "(", self.array(expanded.iter().copied()), ")");
+            expanded.pop();
+            expanded.push(self.group_with(last, true, None));
+            let hugged = array!(self, "(", self.array(expanded), ")");
+            let broken_out = all_args_broken_out(self, &printed);
+            let break_parent =
+                if printed.iter().any(Doc::will_break) { BREAK_PARENT } else { Doc::Str("") };
+            let states = if last.will_break() {
+                // The hugged argument breaks anyway, skip the one line state
+                self.conditional_group(&[hugged, broken_out])
+            } else {
+                self.conditional_group(&[simple, hugged, broken_out])
+            };
+            return array!(self, break_parent, states);
+        }
+
+        if self.could_group_first_arg(args) {
+            if printed[1..].iter().any(Doc::will_break) {
+                return all_args_broken_out(self, &printed);
+            }
+            let end_comment = self.next_comment;
+            self.next_comment = checkpoints[0];
+            let first = self.format_expanded_argument(&args[0]);
+            let first = array!(self, first, ",", LINE);
+            self.next_comment = end_comment;
+            let rest = self.array(printed[1..].iter().copied());
+            let simple = array!(self, "(", first, rest, ")");
+            let hugged = array!(self, "(", self.group_with(first, true, None), rest, ")");
+            let broken_out = all_args_broken_out(self, &printed);
+            return self.conditional_group(&[simple, hugged, broken_out]);
+        }
+
+        let should_break = printed.iter().any(Doc::will_break);
+        let trailing_comma = self.if_break(Doc::Str(trailing_comma), Doc::Str(""));
+        let args = self.indent(array!(self, SOFTLINE, self.array(printed)));
+        self.group_with(array!(self, "(", args, trailing_comma, SOFTLINE, ")"), should_break, None)
+    }
+
+    /// Print the hugged argument, with the parameters of functions kept on one line
+    fn format_expanded_argument(&mut self, arg: &'a Argument<'a>) -> Doc<'a> {
+        match arg {
+            Argument::Expression(expr) => match Self::strip_parens(expr) {
+                Expression::ArrowFunctionExpression(arrow) => {
+                    self.format_arrow(arrow, Context::Default, true)
+                }
+                Expression::FunctionExpression(func) => self.format_function(func, true),
+                expr => expr.format(self),
+            },
+            Argument::SpreadElement(_) => arg.format(self),
+        }
+    }
+
+    fn is_react_hook_call_with_deps_array(args: &'a [Argument<'a>]) -> bool {
+        if args.len() != 2 {
+            return false;
+        }
+        let (
+            Argument::Expression(Expression::ArrowFunctionExpression(arrow)),
+            Argument::Expression(Expression::ArrayExpression(array)),
+        ) = (&args[0], &args[1])
+        else {
+            return false;
+        };
+        arrow.params.items.is_empty()
+            && !arrow.expression
+            && !array.elements.iter().any(Option::is_none)
+    }
+
+    /// Multiple function arguments, e.g. `compose(a => b, c => d)`, see `isFunctionCompositionArgs`
+    fn is_function_composition_args(args: &'a [Argument<'a>]) -> bool {
+        if args.len() <= 1 {
+            return false;
+        }
+        let mut count = 0;
+        for arg in args {
+            let Argument::Expression(expr) = arg else { continue };
+            match expr {
+                Expression::FunctionExpression(_) => {
+                    count += 1;
+                }
+                Expression::ArrowFunctionExpression(arrow) if !arrow.expression => {
+                    count += 1;
+                }
+                Expression::CallExpression(call)
+                    if call.arguments.iter().any(
+                        |arg| matches!(arg, Argument::Expression(expr) if expr.is_function()),
+                    ) =>
+                {
+                    return true;
+                }
+                _ => {}
+            }
+            if count > 1 {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// See `couldExpandArg`
+    fn could_expand_arg(arg: &'a Argument<'a>, arrow_chain_recursion: bool) -> bool {
+        let Argument::Expression(expr) = arg else {
+            return false;
+        };
+        Self::could_expand_expression(Self::strip_parens(expr), arrow_chain_recursion)
+    }
+
+    fn could_expand_expression(expr: &'a Expression<'a>, arrow_chain_recursion: bool) -> bool {
+        match expr {
+            Expression::ObjectExpression(object) => !object.properties.is_empty(),
+            Expression::ArrayExpression(array) => !array.elements.is_empty(),
+            Expression::TSTypeAssertion(expr) => {
+                Self::could_expand_expression(&expr.expression, false)
+            }
+            Expression::TSAsExpression(expr) => {
+                Self::could_expand_expression(&expr.expression, false)
+            }
+            Expression::FunctionExpression(_) => true,
+            Expression::ArrowFunctionExpression(arrow) => {
+                if !arrow.expression {
+                    return true;
+                }
+                // We want to avoid breaking inside composite return types but not simple keywords
+                if let Some(return_type) = &arrow.return_type
+                    && matches!(return_type.type_annotation, TSType::TSTypeReference(_))
+                {
+                    return false;
+                }
+                Self::arrow_body_expression(arrow).map_or(false, |body| match body {
+                    Expression::ArrowFunctionExpression(_)
+                    | Expression::ObjectExpression(_)
+                    | Expression::ArrayExpression(_)
+                    | Expression::TSAsExpression(_)
+                    | Expression::JSXElement(_)
+                    | Expression::JSXFragment(_) => true,
+                    Expression::CallExpression(_)
+                    | Expression::NewExpression(_)
+                    | Expression::ConditionalExpression(_) => !arrow_chain_recursion,
+                    _ => false,
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// See `couldGroupLastArg`
+    fn could_group_last_arg(&self, args: &'a [Argument<'a>]) -> bool {
+        let last = &args[args.len() - 1];
+        let penultimate = args.len().checked_sub(2).map(|i| &args[i]);
+        let kind = |arg: &'a Argument<'a>| match arg {
+            Argument::Expression(expr) => Some(std::mem::discriminant(Self::strip_parens(expr))),
+            Argument::SpreadElement(_) => None,
+        };
+        !self.has_comments_before(last.span().start)
+            && Self::could_expand_arg(last, false)
+            // If the last two arguments are of the same type, disregard hugging
+            && penultimate.map_or(true, |penultimate| kind(penultimate) != kind(last))
+            // useMemo(() => func, [foo, bar, baz])
+            && !(args.len() == 2
+                && matches!(
+                    penultimate,
+                    Some(Argument::Expression(Expression::ArrowFunctionExpression(_)))
+                )
+                && matches!(last, Argument::Expression(Expression::ArrayExpression(_))))
+            && !(args.len() > 1
+                && matches!(last, Argument::Expression(Expression::ArrayExpression(array))
+                    if Self::is_concisely_printed_array(array)))
+    }
+
+    /// See `couldGroupFirstArg`
+    fn could_group_first_arg(&self, args: &'a [Argument<'a>]) -> bool {
+        let [Argument::Expression(first), second] = args else {
+            return false;
+        };
+        let is_function = match first {
+            Expression::FunctionExpression(_) => true,
+            Expression::ArrowFunctionExpression(arrow) => !arrow.expression,
+            _ => false,
+        };
+        is_function
+            && !self.has_comments_before(first.span().start)
+            && !matches!(
+                second,
+                Argument::Expression(
+                    Expression::FunctionExpression(_)
+                        | Expression::ArrowFunctionExpression(_)
+                        | Expression::ConditionalExpression(_)
+                )
+            )
+            && Self::is_simple_call_argument(second, 0)
+            && !Self::could_expand_arg(second, false)
+    }
+}
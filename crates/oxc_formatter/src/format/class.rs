@@ -0,0 +1,270 @@
+//! Classes and class members
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan};
+
+use super::{expression::AssignmentLike, Context, Format};
+use crate::{
+    comment::{has_newline, is_next_line_empty},
+    doc::{array, Doc, HARDLINE, LINE},
+    Formatter,
+};
+
+impl<'a> Format<'a> for Class<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let decorators = f.format_decorators(&self.decorators);
+        f.format_class(self, decorators)
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Decorators are given, exports print them before `export` when they were written so
+    pub(crate) fn format_class(&mut self, class: &'a Class<'a>, decorators: Doc<'a>) -> Doc<'a> {
+        let mut parts = vec![decorators];
+        if class.modifiers.contains(ModifierKind::Declare) {
+            parts.push(Doc::Str("declare "));
+        }
+        if class.modifiers.contains(ModifierKind::Abstract) {
+            parts.push(Doc::Str("abstract "));
+        }
+        parts.push(Doc::Str("class"));
+        if let Some(id) = &class.id {
+            parts.extend([Doc::Str(" "), Doc::Str(&id.name)]);
+        }
+        parts.push(self.format_type_parameters(class.type_parameters.as_deref()));
+
+        let mut clauses = vec![];
+        if let Some(super_class) = &class.super_class {
+            let super_class = self.format_expression(super_class, Context::Operand);
+            let type_arguments = self.format_type_arguments(class.super_type_parameters.as_deref());
+            clauses.push(array!(self, LINE, "extends ", super_class, type_arguments));
+        }
+        if let Some(implements) = &class.implements {
+            let implements =
+                implements.iter().map(|implement| implement.format(self)).collect::<Vec<_>>();
+            let separator = array!(self, ",", LINE);
+            let implements = self.join(separator, implements);
+            let implements = self.indent(implements);
+            clauses.push(array!(self, LINE, "implements ", implements));
+        }
+
+        let body = self.format_class_body(&class.body);
+        if clauses.is_empty() {
+            parts.extend([Doc::Str(" "), body]);
+            return self.array(parts);
+        }
+        // class A
+        //   extends B
+        //   implements C
+        // {
+        //   method() {}
+        // }
+        let id = self.next_group_id();
+        let clauses = self.indent(self.array(clauses));
+        let head = self.group_with(array!(self, self.array(parts), clauses), false, Some(id));
+        let separator = if class.body.body.is_empty() {
+            Doc::Str(" ")
+        } else {
+            self.if_group_break(HARDLINE, Doc::Str(" "), Some(id))
+        };
+        array!(self, head, separator, body)
+    }
+}
+
+impl<'a> Format<'a> for TSClassImplements<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let expression = self.expression.format(f);
+        let type_arguments = f.format_type_arguments(self.type_parameters.as_deref());
+        array!(f, expression, type_arguments)
+    }
+}
+
+impl<'a> Format<'a> for ClassElement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::StaticBlock(block) => {
+                let body = f.format_block(block.span, &[], &block.body);
+                array!(f, "static ", body)
+            }
+            Self::MethodDefinition(method) => f.format_method_definition(method, false),
+            Self::PropertyDefinition(property) => f.format_property_definition(property, false),
+            Self::AccessorProperty(property) => {
+                let mut parts = vec![f.format_decorators(&property.decorators)];
+                if property.r#static {
+                    parts.push(Doc::Str("static "));
+                }
+                parts.push(Doc::Str("accessor "));
+                parts.push(f.format_property_key(&property.key, property.computed, false));
+                let left = f.array(parts);
+                let doc = f.format_assignment(
+                    left,
+                    Doc::Str(" ="),
+                    property.value.as_ref(),
+                    AssignmentLike::ClassProperty,
+                );
+                array!(f, doc, f.semi())
+            }
+            Self::TSAbstractMethodDefinition(method) => {
+                f.format_method_definition(&method.method_definition, true)
+            }
+            Self::TSAbstractPropertyDefinition(property) => {
+                f.format_property_definition(&property.property_definition, true)
+            }
+            Self::TSIndexSignature(signature) => {
+                let signature = signature.format(f);
+                array!(f, signature, f.semi())
+            }
+            Self::InvalidClassElement(element) => {
+                Doc::Str(&f.source_text[element.span.start as usize..element.span.end as usize])
+            }
+        }
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Decorators on their own lines when they were written so, see `printDecorators`
+    pub(crate) fn format_decorators(&mut self, decorators: &'a [Decorator<'a>]) -> Doc<'a> {
+        let Some(last) = decorators.last() else {
+            return Doc::Str("");
+        };
+        let on_own_lines = has_newline(self.source_text, last.span.end as usize);
+        let separator = if on_own_lines { HARDLINE } else { LINE };
+        let mut parts = vec![];
+        for decorator in decorators {
+            parts.extend([decorator.format(self), separator]);
+        }
+        let doc = self.array(parts);
+        if on_own_lines {
+            doc
+        } else {
+            self.group(doc)
+        }
+    }
+
+    /// See `printClassBody`, blank lines between members are preserved
+    fn format_class_body(&mut self, body: &'a ClassBody<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        for (index, element) in body.body.iter().enumerate() {
+            let span = element.span();
+            parts.push(self.with_leading_comments(span.start, |f| element.format(f)));
+            parts.extend(self.trailing_comments(span.end));
+            if index + 1 < body.body.len() {
+                parts.push(HARDLINE);
+                if is_next_line_empty(self.source_text, span.end as usize) {
+                    parts.push(HARDLINE);
+                }
+            }
+        }
+        if let Some(comments) = self.dangling_comments(body.span.end) {
+            if !parts.is_empty() {
+                parts.push(HARDLINE);
+            }
+            parts.push(comments);
+        }
+        if parts.is_empty() {
+            return Doc::Str("{}");
+        }
+        let members = self.indent(array!(self, HARDLINE, self.array(parts)));
+        array!(self, "{", members, HARDLINE, "}")
+    }
+
+    /// `declare public static abstract override readonly`, see `printModifiers`
+    #[allow(clippy::fn_params_excessive_bools, clippy::too_many_arguments)]
+    fn format_member_modifiers(
+        &mut self,
+        decorators: &'a [Decorator<'a>],
+        declare: bool,
+        accessibility: Option<TSAccessibility>,
+        r#static: bool,
+        r#abstract: bool,
+        r#override: bool,
+        readonly: bool,
+    ) -> Vec<Doc<'a>> {
+        let mut parts = vec![self.format_decorators(decorators)];
+        if declare {
+            parts.push(Doc::Str("declare "));
+        }
+        if let Some(accessibility) = accessibility {
+            parts.push(Doc::Str(match accessibility {
+                TSAccessibility::Private => "private ",
+                TSAccessibility::Protected => "protected ",
+                TSAccessibility::Public => "public ",
+            }));
+        }
+        if r#static {
+            parts.push(Doc::Str("static "));
+        }
+        if r#abstract {
+            parts.push(Doc::Str("abstract "));
+        }
+        if r#override {
+            parts.push(Doc::Str("override "));
+        }
+        if readonly {
+            parts.push(Doc::Str("readonly "));
+        }
+        parts
+    }
+
+    fn format_method_definition(
+        &mut self,
+        method: &'a MethodDefinition<'a>,
+        is_abstract: bool,
+    ) -> Doc<'a> {
+        let mut parts = self.format_member_modifiers(
+            &method.decorators,
+            false,
+            method.accessibility,
+            method.r#static,
+            is_abstract,
+            method.r#override,
+            false,
+        );
+        let key = self.format_property_key(&method.key, method.computed, false);
+        let key = if method.optional { array!(self, key, "?") } else { key };
+        let kind = match method.kind {
+            MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => "",
+            MethodDefinitionKind::Get => "get ",
+            MethodDefinitionKind::Set => "set ",
+        };
+        parts.push(self.format_method(kind, key, &method.value));
+        if method.value.body.is_none() {
+            parts.push(Doc::Str(self.semi()));
+        }
+        self.array(parts)
+    }
+
+    fn format_property_definition(
+        &mut self,
+        property: &'a PropertyDefinition<'a>,
+        is_abstract: bool,
+    ) -> Doc<'a> {
+        let mut parts = self.format_member_modifiers(
+            &property.decorators,
+            property.declare,
+            property.accessibility,
+            property.r#static,
+            is_abstract,
+            property.r#override,
+            property.readonly,
+        );
+        parts.push(self.format_property_key(&property.key, property.computed, false));
+        if property.optional {
+            parts.push(Doc::Str("?"));
+        }
+        if property.definite {
+            parts.push(Doc::Str("!"));
+        }
+        if let Some(annotation) = &property.type_annotation {
+            parts.push(annotation.format(self));
+        }
+        let left = self.array(parts);
+        let doc = self.format_assignment(
+            left,
+            Doc::Str(" ="),
+            property.value.as_ref(),
+            AssignmentLike::ClassProperty,
+        );
+        array!(self, doc, self.semi())
+    }
+}
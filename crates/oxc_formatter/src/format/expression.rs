@@ -0,0 +1,522 @@
+//! Expressions, assignments and their layouts
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan};
+
+use super::{Context, Format};
+use crate::{
+    doc::{array, Doc, LINE},
+    Formatter,
+};
+
+impl<'a> Format<'a> for Expression<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.format_expression(self, Context::Default)
+    }
+}
+
+/// The nodes printed with an assignment layout, see `printAssignment`
+#[derive(Clone, Copy)]
+pub enum AssignmentLike<'a> {
+    VariableDeclarator(&'a VariableDeclarator<'a>),
+    Assignment(&'a AssignmentExpression<'a>),
+    /// An object property, with the printed key when it is a plain string
+    Property(Option<&'a str>),
+    ClassProperty,
+}
+
+/// See `chooseLayout` in Prettier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    BreakAfterOperator,
+    NeverBreakAfterOperator,
+    BreakLhs,
+    Fluid,
+}
+
+impl<'a> Formatter<'a> {
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn format_expression(&mut self, expr: &'a Expression<'a>, ctx: Context) -> Doc<'a> {
+        let leading = self.leading_comments(expr.span().start);
+        let doc = match expr {
+            Expression::BooleanLiteral(lit) => Doc::Str(lit.as_str()),
+            Expression::NullLiteral(_) => Doc::Str("null"),
+            Expression::NumberLiteral(lit) => lit.format(self),
+            Expression::BigintLiteral(lit) => lit.format(self),
+            Expression::RegExpLiteral(lit) => lit.format(self),
+            Expression::StringLiteral(lit) => lit.format(self),
+            Expression::TemplateLiteral(lit) => lit.format(self),
+            Expression::Identifier(ident) => Doc::Str(&ident.name),
+            Expression::MetaProperty(prop) => {
+                array!(self, &prop.meta.name, ".", &prop.property.name)
+            }
+            Expression::Super(_) => Doc::Str("super"),
+            Expression::ThisExpression(_) => Doc::Str("this"),
+            Expression::ArrayExpression(array) => array.format(self),
+            Expression::ObjectExpression(object) => object.format(self),
+            Expression::ArrowFunctionExpression(arrow) => self.format_arrow(arrow, ctx, false),
+            Expression::FunctionExpression(func) => func.format(self),
+            Expression::ClassExpression(class) => class.format(self),
+            Expression::AssignmentExpression(assignment) => {
+                let left = assignment.left.format(self);
+                let operator = self.string(&format!(" {}", assignment.operator.as_str()));
+                self.format_assignment(
+                    left,
+                    operator,
+                    Some(&assignment.right),
+                    AssignmentLike::Assignment(assignment),
+                )
+            }
+            Expression::AwaitExpression(expr) => {
+                let argument = self.format_expression(&expr.argument, Context::Operand);
+                array!(self, "await ", argument)
+            }
+            Expression::YieldExpression(expr) => {
+                let keyword = if expr.delegate { "yield*" } else { "yield" };
+                expr.argument.as_ref().map_or(Doc::Str(keyword), |argument| {
+                    let argument = argument.format(self);
+                    array!(self, keyword, " ", argument)
+                })
+            }
+            Expression::BinaryExpression(_)
+            | Expression::LogicalExpression(_)
+            | Expression::PrivateInExpression(_) => self.format_binaryish(expr, ctx),
+            Expression::CallExpression(call) => self.format_call(call, ctx),
+            Expression::NewExpression(new) => self.format_new(new),
+            Expression::ImportExpression(import) => self.format_import_expression(import),
+            Expression::MemberExpression(member) => self.format_member(member, ctx),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => self.format_call(call, ctx),
+                ChainElement::MemberExpression(member) => self.format_member(member, ctx),
+            },
+            Expression::ConditionalExpression(expr) => self.format_conditional(expr, false),
+            Expression::ParenthesizedExpression(expr) => {
+                if Self::is_atomic(&expr.expression) {
+                    self.format_expression(&expr.expression, ctx)
+                } else {
+                    let inner = self.format_expression(&expr.expression, Context::Default);
+                    array!(self, "(", inner, ")")
+                }
+            }
+            Expression::SequenceExpression(expr) => self.format_sequence(expr, ctx),
+            Expression::TaggedTemplateExpression(expr) => {
+                let tag = self.format_expression(&expr.tag, Context::Operand);
+                let type_parameters = self.format_type_arguments(expr.type_parameters.as_deref());
+                let quasi = expr.quasi.format(self);
+                array!(self, tag, type_parameters, quasi)
+            }
+            Expression::UnaryExpression(expr) => expr.format(self),
+            Expression::UpdateExpression(expr) => {
+                let argument = expr.argument.format(self);
+                if expr.prefix {
+                    array!(self, expr.operator.as_str(), argument)
+                } else {
+                    array!(self, argument, expr.operator.as_str())
+                }
+            }
+            Expression::JSXElement(element) => self.format_jsx_element(element, ctx),
+            Expression::JSXFragment(fragment) => self.format_jsx_fragment(fragment, ctx),
+            Expression::TSAsExpression(expr) => {
+                let expression = self.format_expression(&expr.expression, Context::Operand);
+                let type_annotation = expr.type_annotation.format(self);
+                array!(self, expression, " as ", type_annotation)
+            }
+            Expression::TSTypeAssertion(expr) => {
+                let type_annotation = expr.type_annotation.format(self);
+                let expression = self.format_expression(&expr.expression, Context::Operand);
+                array!(self, "<", type_annotation, ">", expression)
+            }
+            Expression::TSNonNullExpression(expr) => {
+                let expression = self.format_expression(&expr.expression, Context::Operand);
+                array!(self, expression, "!")
+            }
+            Expression::TSInstantiationExpression(expr) => {
+                let expression = self.format_expression(&expr.expression, Context::Operand);
+                let type_parameters = self.format_type_arguments(Some(&expr.type_parameters));
+                array!(self, expression, type_parameters)
+            }
+        };
+        leading.map_or(doc, |comments| array!(self, comments, doc))
+    }
+
+    /// Parentheses which can be removed without changing the meaning, e.g. `(a)`
+    pub(crate) fn is_atomic(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => !matches!(ident.name.as_str(), "let" | "async"),
+            Expression::ThisExpression(_)
+            | Expression::NullLiteral(_)
+            | Expression::BooleanLiteral(_) => true,
+            Expression::ParenthesizedExpression(expr) => Self::is_atomic(&expr.expression),
+            _ => false,
+        }
+    }
+
+    /// Remove the parentheses of an expression printed where any expression but a sequence
+    /// is allowed, e.g. an initializer or an argument.
+    pub(crate) fn strip_parens(expr: &'a Expression<'a>) -> &'a Expression<'a> {
+        match expr {
+            Expression::ParenthesizedExpression(paren)
+                if !matches!(
+                    paren.expression.without_parenthesized(),
+                    Expression::SequenceExpression(_)
+                ) =>
+            {
+                Self::strip_parens(&paren.expression)
+            }
+            _ => expr,
+        }
+    }
+
+    pub(crate) fn is_binaryish(expr: &Expression<'a>) -> bool {
+        matches!(
+            expr,
+            Expression::BinaryExpression(_)
+                | Expression::LogicalExpression(_)
+                | Expression::PrivateInExpression(_)
+        )
+    }
+
+    /// Whether the statement starting with `expr` must be preceded by a semicolon
+    /// when semicolons are disabled, see `expressionNeedsASIProtection`.
+    pub(crate) fn needs_asi_protection(expr: &'a Expression<'a>) -> bool {
+        match expr {
+            Expression::ParenthesizedExpression(paren) => {
+                !Self::is_atomic(&paren.expression) || Self::needs_asi_protection(&paren.expression)
+            }
+            Expression::ArrayExpression(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::RegExpLiteral(_) => true,
+            Expression::UnaryExpression(expr) => {
+                matches!(expr.operator, UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation)
+            }
+            Expression::UpdateExpression(expr) => {
+                expr.prefix
+                    || matches!(&expr.argument,
+                        SimpleAssignmentTarget::MemberAssignmentTarget(member)
+                            if Self::needs_asi_protection(member.object()))
+            }
+            Expression::BinaryExpression(binary) => {
+                Self::binary_operand_needs_parens(binary.operator.as_str(), &binary.left, false)
+                    || Self::needs_asi_protection(&binary.left)
+            }
+            Expression::LogicalExpression(logical) => {
+                Self::binary_operand_needs_parens(logical.operator.as_str(), &logical.left, false)
+                    || Self::needs_asi_protection(&logical.left)
+            }
+            Expression::CallExpression(call) => Self::needs_asi_protection(&call.callee),
+            Expression::MemberExpression(member) => Self::needs_asi_protection(member.object()),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => Self::needs_asi_protection(&call.callee),
+                ChainElement::MemberExpression(member) => {
+                    Self::needs_asi_protection(member.object())
+                }
+            },
+            Expression::TaggedTemplateExpression(expr) => Self::needs_asi_protection(&expr.tag),
+            Expression::ConditionalExpression(expr) => Self::needs_asi_protection(&expr.test),
+            Expression::AssignmentExpression(expr) => match &expr.left {
+                AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ArrayAssignmentTarget(_),
+                ) => true,
+                AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) => Self::needs_asi_protection(member.object()),
+                _ => false,
+            },
+            Expression::SequenceExpression(expr) => {
+                Self::needs_asi_protection(&expr.expressions[0])
+            }
+            Expression::TSAsExpression(expr) => Self::needs_asi_protection(&expr.expression),
+            Expression::TSNonNullExpression(expr) => Self::needs_asi_protection(&expr.expression),
+            Expression::TSInstantiationExpression(expr) => {
+                Self::needs_asi_protection(&expr.expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// See `printTernary`, nested conditionals are grouped with the outermost one
+    fn format_conditional(&mut self, expr: &'a ConditionalExpression<'a>, nested: bool) -> Doc<'a> {
+        let test = self.format_expression(&expr.test, Context::NoIndent);
+        let consequent = match Self::strip_parens(&expr.consequent) {
+            Expression::ConditionalExpression(consequent) => {
+                // `a ? (b ? c : d) : e` when printed on one line
+                let consequent = self.format_conditional(consequent, true);
+                let open = self.if_break(Doc::Str(""), Doc::Str("("));
+                let close = self.if_break(Doc::Str(""), Doc::Str(")"));
+                array!(self, open, consequent, close)
+            }
+            consequent => self.format_expression(consequent, Context::Default),
+        };
+        let alternate = match Self::strip_parens(&expr.alternate) {
+            Expression::ConditionalExpression(alternate) => {
+                self.format_conditional(alternate, true)
+            }
+            alternate => self.format_expression(alternate, Context::Default),
+        };
+        let branches = self.indent(array!(self, LINE, "? ", consequent, LINE, ": ", alternate));
+        let doc = array!(self, test, branches);
+        if nested {
+            doc
+        } else {
+            self.group(doc)
+        }
+    }
+
+    /// See `printSequenceExpression`
+    fn format_sequence(&mut self, expr: &'a SequenceExpression<'a>, ctx: Context) -> Doc<'a> {
+        let mut printed = expr.expressions.iter().map(|expr| expr.format(self)).collect::<Vec<_>>();
+        if matches!(ctx, Context::ExpressionStatement | Context::NoIndent) {
+            let first = printed.remove(0);
+            let rest = printed.into_iter().map(|doc| array!(self, ",", LINE, doc));
+            let rest = self.array(rest);
+            return self.group(array!(self, first, self.indent(rest)));
+        }
+        let separator = array!(self, ",", LINE);
+        self.group(self.join(separator, printed))
+    }
+
+    /// Print `left operator right` with the layout chosen for `right`, see `printAssignment`.
+    /// `operator` includes the leading space, e.g. `" ="`.
+    pub(crate) fn format_assignment(
+        &mut self,
+        left: Doc<'a>,
+        operator: Doc<'a>,
+        right: Option<&'a Expression<'a>>,
+        node: AssignmentLike<'a>,
+    ) -> Doc<'a> {
+        let Some(right) = right else {
+            return left;
+        };
+        let right = Self::strip_parens(right);
+        let layout = self.choose_layout(right, node);
+        let right = self.format_expression(right, Context::Assignment);
+        self.format_assignment_layout(left, operator, right, layout)
+    }
+
+    pub(crate) fn format_assignment_layout(
+        &mut self,
+        left: Doc<'a>,
+        operator: Doc<'a>,
+        right: Doc<'a>,
+        layout: Layout,
+    ) -> Doc<'a> {
+        let left = self.group(left);
+        match layout {
+            Layout::BreakAfterOperator => {
+                let right = self.group(self.indent(array!(self, LINE, right)));
+                self.group(array!(self, left, operator, right))
+            }
+            Layout::NeverBreakAfterOperator => self.group(array!(self, left, operator, " ", right)),
+            Layout::BreakLhs => {
+                let right = self.group(right);
+                self.group(array!(self, left, operator, " ", right))
+            }
+            Layout::Fluid => {
+                let id = self.next_group_id();
+                let line = self.group_with(self.indent(LINE), false, Some(id));
+                let right = self.indent_if_break(right, id);
+                self.group(array!(self, left, operator, line, right))
+            }
+        }
+    }
+
+    fn choose_layout(&self, right: &'a Expression<'a>, node: AssignmentLike<'a>) -> Layout {
+        if let Expression::CallExpression(call) = right
+            && call.callee.is_specific_id("require")
+        {
+            return Layout::NeverBreakAfterOperator;
+        }
+        if Self::is_complex_destructuring(node) {
+            return Layout::BreakLhs;
+        }
+        // Wrapping object properties with very short keys usually doesn't add much value
+        let has_short_key = matches!(node, AssignmentLike::Property(Some(key))
+            if key.chars().count() < self.options.tab_width + 3);
+        if Self::should_break_after_operator(right, has_short_key) {
+            return Layout::BreakAfterOperator;
+        }
+        if has_short_key
+            || matches!(
+                right,
+                Expression::TemplateLiteral(_)
+                    | Expression::TaggedTemplateExpression(_)
+                    | Expression::BooleanLiteral(_)
+                    | Expression::NumberLiteral(_)
+                    | Expression::ClassExpression(_)
+            )
+        {
+            return Layout::NeverBreakAfterOperator;
+        }
+        Layout::Fluid
+    }
+
+    fn should_break_after_operator(right: &'a Expression<'a>, has_short_key: bool) -> bool {
+        if Self::is_binaryish(right) && !Self::should_inline_logical_expression(right) {
+            return true;
+        }
+        match right {
+            Expression::SequenceExpression(_) => return true,
+            Expression::ConditionalExpression(conditional) => {
+                let test = Self::strip_parens(&conditional.test);
+                return Self::is_binaryish(test) && !Self::should_inline_logical_expression(test);
+            }
+            _ => {}
+        }
+        if has_short_key {
+            return false;
+        }
+        let node = match right {
+            Expression::UnaryExpression(expr) => &expr.argument,
+            Expression::TSNonNullExpression(expr) => &expr.expression,
+            _ => right,
+        };
+        matches!(node, Expression::StringLiteral(_)) || Self::is_member_expression_chain(node)
+    }
+
+    /// `a.b.c`
+    fn is_member_expression_chain(expr: &Expression<'a>) -> bool {
+        let Expression::MemberExpression(member) = expr else {
+            return false;
+        };
+        match member.object() {
+            Expression::Identifier(_) => true,
+            object => Self::is_member_expression_chain(object),
+        }
+    }
+
+    /// `const { a, b: { c }, d = 1 } = e`
+    fn is_complex_destructuring(node: AssignmentLike<'a>) -> bool {
+        match node {
+            AssignmentLike::VariableDeclarator(decl) => match &decl.id.kind {
+                BindingPatternKind::ObjectPattern(pattern) => {
+                    pattern.properties.len() > 2
+                        && pattern.properties.iter().any(|property| match property {
+                            ObjectPatternProperty::Property(property) => {
+                                property.computed
+                                    || !property.shorthand
+                                    || !matches!(
+                                        &property.value,
+                                        PropertyValue::Pattern(BindingPattern {
+                                            kind: BindingPatternKind::BindingIdentifier(_),
+                                            ..
+                                        })
+                                    )
+                            }
+                            ObjectPatternProperty::RestElement(_) => false,
+                        })
+                }
+                _ => false,
+            },
+            AssignmentLike::Assignment(assignment) => match &assignment.left {
+                AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(target),
+                ) => {
+                    target.properties.len() > 2
+                        && target.properties.iter().any(|property| match property {
+                            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(
+                                property,
+                            ) => property.init.is_some(),
+                            AssignmentTargetProperty::AssignmentTargetPropertyProperty(_) => true,
+                        })
+                }
+                _ => false,
+            },
+            AssignmentLike::Property(_) | AssignmentLike::ClassProperty => false,
+        }
+    }
+}
+
+impl<'a> Format<'a> for UnaryExpression<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let operator = self.operator.as_str();
+        let argument = f.format_expression(&self.argument, Context::Operand);
+        // `- -a` and `+ ++a` must not be printed as `--a` and `+++a`
+        let needs_parens = match &self.argument {
+            Expression::UnaryExpression(arg) => {
+                arg.operator == self.operator && self.operator.is_arithmetic()
+            }
+            Expression::UpdateExpression(arg) => {
+                arg.prefix
+                    && matches!(
+                        (self.operator, arg.operator),
+                        (UnaryOperator::UnaryPlus, UpdateOperator::Increment)
+                            | (UnaryOperator::UnaryNegation, UpdateOperator::Decrement)
+                    )
+            }
+            _ => false,
+        };
+        let argument = if needs_parens { array!(f, "(", argument, ")") } else { argument };
+        if self.operator.is_keyword() {
+            array!(f, operator, " ", argument)
+        } else {
+            array!(f, operator, argument)
+        }
+    }
+}
+
+impl<'a> Format<'a> for AssignmentTarget<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::SimpleAssignmentTarget(target) => target.format(f),
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ArrayAssignmentTarget(
+                target,
+            )) => target.format(f),
+            Self::AssignmentTargetPattern(AssignmentTargetPattern::ObjectAssignmentTarget(
+                target,
+            )) => target.format(f),
+        }
+    }
+}
+
+impl<'a> Format<'a> for SimpleAssignmentTarget<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::AssignmentTargetIdentifier(ident) => Doc::Str(&ident.name),
+            Self::MemberAssignmentTarget(member) => f.format_member(member, Context::Default),
+            Self::TSAsExpression(expr) => {
+                let expression = f.format_expression(&expr.expression, Context::Operand);
+                let type_annotation = expr.type_annotation.format(f);
+                array!(f, expression, " as ", type_annotation)
+            }
+            Self::TSNonNullExpression(expr) => {
+                let expression = f.format_expression(&expr.expression, Context::Operand);
+                array!(f, expression, "!")
+            }
+            Self::TSTypeAssertion(expr) => {
+                let type_annotation = expr.type_annotation.format(f);
+                let expression = f.format_expression(&expr.expression, Context::Operand);
+                array!(f, "<", type_annotation, ">", expression)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for AssignmentTargetMaybeDefault<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::AssignmentTarget(target) => target.format(f),
+            Self::AssignmentTargetWithDefault(target) => {
+                let binding = target.binding.format(f);
+                let init = Formatter::strip_parens(&target.init);
+                let init = init.format(f);
+                array!(f, binding, " = ", init)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for Argument<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::SpreadElement(spread) => spread.format(f),
+            Self::Expression(expr) => Formatter::strip_parens(expr).format(f),
+        }
+    }
+}
+
+impl<'a> Format<'a> for SpreadElement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let argument = Formatter::strip_parens(&self.argument).format(f);
+        array!(f, "...", argument)
+    }
+}
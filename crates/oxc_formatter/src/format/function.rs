@@ -0,0 +1,392 @@
+//! Functions, arrow functions, methods and parameters
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use super::{Context, Format};
+use crate::{
+    doc::{array, Doc, LINE, SOFTLINE},
+    Formatter,
+};
+
+impl<'a> Format<'a> for Function<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.format_function(self, false)
+    }
+}
+
+impl<'a> Format<'a> for FormalParameter<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        for decorator in &self.decorators {
+            parts.extend([decorator.format(f), Doc::Str(" ")]);
+        }
+        if let Some(accessibility) = self.accessibility {
+            parts.push(Doc::Str(match accessibility {
+                TSAccessibility::Private => "private ",
+                TSAccessibility::Protected => "protected ",
+                TSAccessibility::Public => "public ",
+            }));
+        }
+        if self.readonly {
+            parts.push(Doc::Str("readonly "));
+        }
+        parts.push(f.format_binding_pattern(&self.pattern, true));
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for Decorator<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let expression = f.format_expression(&self.expression, Context::Operand);
+        array!(f, "@", expression)
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// See `printFunction`, the parameters are kept on one line when `expand_params` is set
+    /// because the function is hugged as the last argument of a call.
+    pub(crate) fn format_function(
+        &mut self,
+        func: &'a Function<'a>,
+        expand_params: bool,
+    ) -> Doc<'a> {
+        let mut parts = vec![];
+        if func.modifiers.contains(ModifierKind::Declare) {
+            parts.push(Doc::Str("declare "));
+        }
+        if func.r#async {
+            parts.push(Doc::Str("async "));
+        }
+        parts.push(Doc::Str(if func.generator { "function* " } else { "function " }));
+        if let Some(id) = &func.id {
+            parts.push(Doc::Str(&id.name));
+        }
+        parts.push(self.format_signature(
+            func.type_parameters.as_deref(),
+            &func.params,
+            func.return_type.as_deref(),
+            expand_params,
+        ));
+        match &func.body {
+            Some(body) => {
+                let body = self.format_block(body.span, &body.directives, &body.statements);
+                parts.extend([Doc::Str(" "), body]);
+            }
+            None => parts.push(Doc::Str(self.semi())),
+        }
+        self.array(parts)
+    }
+
+    /// Object and class methods, e.g. `get foo() {}`, see `printMethod`.
+    /// `kind` is the `get ` or `set ` prefix, and the body is omitted for signatures.
+    pub(crate) fn format_method(
+        &mut self,
+        kind: &'static str,
+        key: Doc<'a>,
+        func: &'a Function<'a>,
+    ) -> Doc<'a> {
+        let mut parts = vec![Doc::Str(kind)];
+        if func.r#async {
+            parts.push(Doc::Str("async "));
+        }
+        if func.generator {
+            parts.push(Doc::Str("*"));
+        }
+        parts.push(key);
+        parts.push(self.format_signature(
+            func.type_parameters.as_deref(),
+            &func.params,
+            func.return_type.as_deref(),
+            false,
+        ));
+        if let Some(body) = &func.body {
+            let body = self.format_block(body.span, &body.directives, &body.statements);
+            parts.extend([Doc::Str(" "), body]);
+        }
+        self.array(parts)
+    }
+
+    /// `<T>(a, b): R`
+    pub(crate) fn format_signature(
+        &mut self,
+        type_parameters: Option<&'a TSTypeParameterDeclaration<'a>>,
+        params: &'a FormalParameters<'a>,
+        return_type: Option<&'a TSTypeAnnotation<'a>>,
+        expand_params: bool,
+    ) -> Doc<'a> {
+        let type_parameters = self.format_type_parameters(type_parameters);
+        let params_doc = self.format_params(params, expand_params);
+        let return_type_doc =
+            return_type.map_or(Doc::Str(""), |return_type| return_type.format(self));
+        // function foo(a: A): {
+        //   b: B;
+        // } {}
+        let should_group_params = params.items.len() == 1
+            && type_parameters.is_empty()
+            && !params.items[0].pattern.kind.is_rest_element()
+            && (return_type_doc.will_break()
+                || matches!(
+                    return_type.map(|return_type| &return_type.type_annotation),
+                    Some(TSType::TSTypeLiteral(_) | TSType::TSMappedType(_))
+                ));
+        let params_doc = if should_group_params { self.group(params_doc) } else { params_doc };
+        let signature = self.group(array!(self, params_doc, return_type_doc));
+        array!(self, type_parameters, signature)
+    }
+
+    /// See `printFunctionParameters`
+    pub(crate) fn format_params(
+        &mut self,
+        params: &'a FormalParameters<'a>,
+        expand_params: bool,
+    ) -> Doc<'a> {
+        if params.items.is_empty() {
+            return self.dangling_comments_in("(", params.span.end, ")");
+        }
+        let printed = params
+            .items
+            .iter()
+            .map(|param| {
+                let doc = self.with_leading_comments(param.span.start, |f| param.format(f));
+                self.with_trailing_comments(param.span.end, doc)
+            })
+            .collect::<Vec<_>>();
+        if expand_params {
+            let printed = self.join(Doc::Str(", "), printed);
+            return array!(self, "(", printed, ")");
+        }
+        // function foo({
+        //   a,
+        //   b,
+        // }) {}
+        if Self::should_hug_the_only_parameter(params) {
+            return array!(self, "(", printed[0], ")");
+        }
+        let has_rest =
+            params.items.last().map_or(false, |param| param.pattern.kind.is_rest_element());
+        let trailing_comma = if !has_rest && self.options.trailing_comma.all() {
+            self.if_break(Doc::Str(","), Doc::Str(""))
+        } else {
+            Doc::Str("")
+        };
+        let separator = array!(self, ",", LINE);
+        let printed = self.join(separator, printed);
+        let printed = self.indent(array!(self, SOFTLINE, printed));
+        array!(self, "(", printed, trailing_comma, SOFTLINE, ")")
+    }
+
+    /// See `shouldHugTheOnlyFunctionParameter`
+    fn should_hug_the_only_parameter(params: &'a FormalParameters<'a>) -> bool {
+        let [param] = params.items.as_slice() else {
+            return false;
+        };
+        if !param.decorators.is_empty() || param.accessibility.is_some() || param.readonly {
+            return false;
+        }
+        match &param.pattern.kind {
+            BindingPatternKind::ObjectPattern(_) | BindingPatternKind::ArrayPattern(_) => true,
+            BindingPatternKind::BindingIdentifier(_) => matches!(
+                param
+                    .pattern
+                    .type_annotation
+                    .as_deref()
+                    .map(|annotation| &annotation.type_annotation),
+                Some(TSType::TSTypeLiteral(_) | TSType::TSMappedType(_))
+            ),
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                pattern.left.kind.is_destructuring_pattern()
+                    && match Self::strip_parens(&pattern.right) {
+                        Expression::Identifier(_) => true,
+                        Expression::ObjectExpression(object) => object.properties.is_empty(),
+                        Expression::ArrayExpression(array) => array.elements.is_empty(),
+                        _ => false,
+                    }
+            }
+            BindingPatternKind::RestElement(_) => false,
+        }
+    }
+
+    /// The expression of an arrow function without braces, e.g. `b` in `a => b`
+    pub(crate) fn arrow_body_expression(
+        arrow: &'a ArrowExpression<'a>,
+    ) -> Option<&'a Expression<'a>> {
+        if !arrow.expression {
+            return None;
+        }
+        match arrow.body.statements.first() {
+            Some(Statement::ExpressionStatement(stmt)) => {
+                Some(Self::strip_parens(&stmt.expression))
+            }
+            _ => None,
+        }
+    }
+
+    /// `async <T>(a): R =>`
+    fn format_arrow_signature(
+        &mut self,
+        arrow: &'a ArrowExpression<'a>,
+        expand_params: bool,
+    ) -> Doc<'a> {
+        let signature = self.format_signature(
+            arrow.type_parameters.as_deref(),
+            &arrow.params,
+            arrow.return_type.as_deref(),
+            expand_params,
+        );
+        if arrow.r#async {
+            array!(self, "async ", signature)
+        } else {
+            signature
+        }
+    }
+
+    /// See `printArrowFunction`. `expand_last_arg` is set when the arrow function is hugged
+    /// as the last argument of a call, which keeps its parameters on one line.
+    pub(crate) fn format_arrow(
+        &mut self,
+        arrow: &'a ArrowExpression<'a>,
+        ctx: Context,
+        expand_last_arg: bool,
+    ) -> Doc<'a> {
+        if !expand_last_arg
+            && matches!(
+                Self::arrow_body_expression(arrow),
+                Some(Expression::ArrowFunctionExpression(_))
+            )
+        {
+            return self.format_arrow_chain(arrow, ctx);
+        }
+        let signature = self.format_arrow_signature(arrow, expand_last_arg);
+        let Some(body) = Self::arrow_body_expression(arrow) else {
+            let body =
+                self.format_block(arrow.body.span, &arrow.body.directives, &arrow.body.statements);
+            return self.group(array!(self, signature, " => ", body));
+        };
+        // We want to always keep these types of nodes on the same line as the arrow
+        if Self::should_put_body_on_same_line(body) {
+            let body = self.format_arrow_body(body);
+            return self.group(array!(self, signature, " => ", body));
+        }
+        // We handle sequence expressions as the body of arrows specially,
+        // so that the required parentheses end up on their own lines
+        if let Expression::ParenthesizedExpression(paren) = body {
+            let body = paren.expression.format(self);
+            let body = self.indent(array!(self, SOFTLINE, body));
+            return self.group(array!(self, signature, " => (", body, SOFTLINE, ")"));
+        }
+        // a => a ? a : a, to avoid confusion with a <= a ? a : a
+        let should_add_parens = matches!(body, Expression::ConditionalExpression(_));
+        let body = self.format_arrow_body(body);
+        let body = if should_add_parens {
+            let open = self.if_break(Doc::Str(""), Doc::Str("("));
+            let close = self.if_break(Doc::Str(""), Doc::Str(")"));
+            array!(self, open, body, close)
+        } else {
+            body
+        };
+        let body = self.indent(array!(self, LINE, body));
+        let trailing = if expand_last_arg {
+            let comma = if self.options.trailing_comma.all() { "," } else { "" };
+            let comma = self.if_break(Doc::Str(comma), Doc::Str(""));
+            array!(self, comma, SOFTLINE)
+        } else {
+            Doc::Str("")
+        };
+        let body = self.group(array!(self, body, trailing));
+        self.group(array!(self, signature, " =>", body))
+    }
+
+    fn should_put_body_on_same_line(body: &'a Expression<'a>) -> bool {
+        matches!(
+            body,
+            Expression::ArrayExpression(_)
+                | Expression::ObjectExpression(_)
+                | Expression::ArrowFunctionExpression(_)
+                | Expression::JSXElement(_)
+                | Expression::JSXFragment(_)
+                | Expression::TemplateLiteral(_)
+                | Expression::TaggedTemplateExpression(_)
+        )
+    }
+
+    /// The expression body of an arrow function, with the parentheses needed
+    /// when it starts with an object literal, e.g. `() => ({}).toString()`
+    fn format_arrow_body(&mut self, body: &'a Expression<'a>) -> Doc<'a> {
+        let doc = self.format_expression(body, Context::NoIndent);
+        if Self::starts_with_object(body) {
+            array!(self, "(", doc, ")")
+        } else {
+            doc
+        }
+    }
+
+    /// Whether the printed expression starts with `{`
+    fn starts_with_object(expr: &'a Expression<'a>) -> bool {
+        match expr {
+            Expression::ObjectExpression(_) => true,
+            Expression::MemberExpression(member) => Self::starts_with_object(member.object()),
+            Expression::CallExpression(call) => Self::starts_with_object(&call.callee),
+            Expression::TaggedTemplateExpression(expr) => Self::starts_with_object(&expr.tag),
+            Expression::BinaryExpression(expr) => Self::starts_with_object(&expr.left),
+            Expression::LogicalExpression(expr) => Self::starts_with_object(&expr.left),
+            Expression::ConditionalExpression(expr) => Self::starts_with_object(&expr.test),
+            Expression::SequenceExpression(expr) => Self::starts_with_object(&expr.expressions[0]),
+            Expression::TSAsExpression(expr) => Self::starts_with_object(&expr.expression),
+            Expression::TSNonNullExpression(expr) => Self::starts_with_object(&expr.expression),
+            Expression::UpdateExpression(expr) if !expr.prefix => match &expr.argument {
+                SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                    Self::starts_with_object(member.object())
+                }
+                _ => false,
+            },
+            Expression::AssignmentExpression(expr) => matches!(
+                expr.left,
+                AssignmentTarget::AssignmentTargetPattern(
+                    AssignmentTargetPattern::ObjectAssignmentTarget(_)
+                )
+            ),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => Self::starts_with_object(&call.callee),
+                ChainElement::MemberExpression(member) => Self::starts_with_object(member.object()),
+            },
+            _ => false,
+        }
+    }
+
+    /// Curried arrow functions, e.g. `a => b => c`, see `printArrowChain`
+    fn format_arrow_chain(&mut self, arrow: &'a ArrowExpression<'a>, ctx: Context) -> Doc<'a> {
+        let mut signatures = vec![];
+        let mut tail = arrow;
+        loop {
+            let signature = self
+                .with_leading_comments(tail.span.start, |f| f.format_arrow_signature(tail, false));
+            signatures.push(signature);
+            match Self::arrow_body_expression(tail) {
+                Some(Expression::ArrowFunctionExpression(next)) => tail = next,
+                _ => break,
+            }
+        }
+        let body = match Self::arrow_body_expression(tail) {
+            Some(body) => self.format_arrow_body(body),
+            None => self.format_block(tail.body.span, &tail.body.directives, &tail.body.statements),
+        };
+        let tail_body = Self::arrow_body_expression(tail);
+        let should_put_body_on_separate_line = !matches!(
+            tail_body,
+            None | Some(Expression::ObjectExpression(_) | Expression::ParenthesizedExpression(_))
+        );
+        let is_assignment_rhs = ctx == Context::Assignment;
+        let separator = array!(self, " =>", LINE);
+        let signatures = self.join(separator, signatures);
+        let id = self.next_group_id();
+        let head = if is_assignment_rhs { array!(self, SOFTLINE, signatures) } else { signatures };
+        let head = self.group_with(self.indent(head), false, Some(id));
+        let body = if should_put_body_on_separate_line {
+            self.indent_if_break(array!(self, LINE, body), id)
+        } else {
+            array!(self, " ", body)
+        };
+        self.group(array!(self, head, " =>", body))
+    }
+}
@@ -0,0 +1,511 @@
+//! JSX elements, see `printJsxElement`
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan};
+
+use super::{Context, Format};
+use crate::{
+    doc::{array, Doc, HARDLINE, LINE, SOFTLINE},
+    Formatter,
+};
+
+/// `{" "}`, the whitespace kept when the children break
+const RAW_JSX_WHITESPACE: &str = "{\" \"}";
+
+/// The children are printed with `fill`, alternating contents and separators.
+/// The separators are kept apart from the documents to clean them up before printing.
+#[derive(Clone, Copy)]
+enum Child<'a> {
+    Doc(Doc<'a>),
+    Empty,
+    /// A space which becomes `{" "}` when the line breaks
+    Whitespace,
+    Line,
+    Softline,
+    Hardline,
+}
+
+impl<'a> Child<'a> {
+    fn is_empty(self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    fn is_whitespace(self) -> bool {
+        matches!(self, Self::Whitespace)
+    }
+
+    fn is_hardline(self) -> bool {
+        matches!(self, Self::Hardline)
+    }
+
+    fn is_line(self) -> bool {
+        matches!(self, Self::Softline | Self::Hardline)
+    }
+}
+
+/// Matches `matchJsxWhitespace`, other whitespace is significant in JSX text
+fn is_jsx_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\n' | '\r' | '\t')
+}
+
+/// Whitespace containing a line break is removed by JSX, see `isMeaningfulJsxText`
+fn is_meaningful_jsx_text(text: &str) -> bool {
+    text.contains(|c| !is_jsx_whitespace(c)) || !text.contains('\n')
+}
+
+/// Split `text` into words and the whitespace between them, starting and ending with a word
+fn split_jsx_words(text: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut start = 0;
+    let mut in_whitespace = false;
+    for (index, c) in text.char_indices() {
+        if is_jsx_whitespace(c) != in_whitespace {
+            words.push(&text[start..index]);
+            start = index;
+            in_whitespace = !in_whitespace;
+        }
+    }
+    words.push(&text[start..]);
+    if in_whitespace {
+        words.push("");
+    }
+    words
+}
+
+impl<'a> Formatter<'a> {
+    pub(crate) fn format_jsx_element(
+        &mut self,
+        element: &'a JSXElement<'a>,
+        ctx: Context,
+    ) -> Doc<'a> {
+        let doc = self.format_jsx_element_without_parens(element);
+        self.wrap_jsx_in_parens(doc, ctx)
+    }
+
+    pub(crate) fn format_jsx_fragment(
+        &mut self,
+        fragment: &'a JSXFragment<'a>,
+        ctx: Context,
+    ) -> Doc<'a> {
+        let doc =
+            self.format_jsx_children_of(Doc::Str("<>"), &fragment.children, Doc::Str("</>"), false);
+        self.wrap_jsx_in_parens(doc, ctx)
+    }
+
+    /// See `maybeWrapJsxElementInParens`
+    fn wrap_jsx_in_parens(&mut self, doc: Doc<'a>, ctx: Context) -> Doc<'a> {
+        if !matches!(ctx, Context::Assignment | Context::NoIndent) {
+            return doc;
+        }
+        let open = self.if_break(Doc::Str("("), Doc::Str(""));
+        let close = self.if_break(Doc::Str(")"), Doc::Str(""));
+        let contents = self.indent(array!(self, SOFTLINE, doc));
+        self.group(array!(self, open, contents, SOFTLINE, close))
+    }
+
+    fn format_jsx_element_without_parens(&mut self, element: &'a JSXElement<'a>) -> Doc<'a> {
+        let opening = self.format_jsx_opening_element(&element.opening_element);
+        let Some(closing) = &element.closing_element else {
+            return opening;
+        };
+        let name = self.format_jsx_element_name(&closing.name);
+        let closing = array!(self, "</", name, ">");
+        let has_multiple_attributes = element.opening_element.attributes.len() > 1;
+        self.format_jsx_children_of(opening, &element.children, closing, has_multiple_attributes)
+    }
+
+    /// See `printJsxElementInternal`
+    fn format_jsx_children_of(
+        &mut self,
+        opening: Doc<'a>,
+        children: &'a [JSXChild<'a>],
+        closing: Doc<'a>,
+        has_multiple_attributes: bool,
+    ) -> Doc<'a> {
+        // <div>
+        // </div>
+        let is_empty = children.iter().all(|child| {
+            matches!(child, JSXChild::Text(text) if !is_meaningful_jsx_text(self.jsx_text(text)))
+        });
+        if is_empty {
+            return array!(self, opening, closing);
+        }
+        // <div>{`template`}</div>
+        if let [JSXChild::ExpressionContainer(container)] = children
+            && let JSXExpression::Expression(
+                Expression::TemplateLiteral(_) | Expression::TaggedTemplateExpression(_),
+            ) = &container.expression
+        {
+            let child = self.format_jsx_expression_container(container, true);
+            return array!(self, opening, child, closing);
+        }
+
+        let contains_tag = children
+            .iter()
+            .any(|child| matches!(child, JSXChild::Element(_) | JSXChild::Fragment(_)));
+        let expression_count = children
+            .iter()
+            .filter(|child| matches!(child, JSXChild::ExpressionContainer(_)))
+            .count();
+        let contains_text = children.iter().any(|child| {
+            matches!(child, JSXChild::Text(text) if is_meaningful_jsx_text(self.jsx_text(text)))
+        });
+        let mut forced_break =
+            opening.will_break() || contains_tag || has_multiple_attributes || expression_count > 1;
+
+        let mut children = self.format_jsx_children(children);
+        Self::clean_jsx_children(&mut children, contains_text);
+
+        let mut multiline_children = vec![];
+        for (index, child) in children.iter().enumerate() {
+            if child.is_whitespace() {
+                if index == 1 && children[0].is_empty() {
+                    if children.len() == 2 {
+                        // Solitary whitespace
+                        multiline_children.push(Doc::Str(RAW_JSX_WHITESPACE));
+                    } else {
+                        // Leading whitespace
+                        multiline_children.push(array!(self, RAW_JSX_WHITESPACE, HARDLINE));
+                    }
+                    continue;
+                }
+                if index == children.len() - 1
+                    || (index >= 2
+                        && children[index - 1].is_empty()
+                        && children[index - 2].is_hardline())
+                {
+                    // Trailing whitespace, or whitespace after a line break
+                    multiline_children.push(Doc::Str(RAW_JSX_WHITESPACE));
+                    continue;
+                }
+            }
+            let doc = self.jsx_child_doc(*child);
+            forced_break |= doc.will_break();
+            multiline_children.push(doc);
+        }
+
+        let content = self.fill(&multiline_children);
+        let content = self.indent(array!(self, HARDLINE, content));
+        let multiline = self.group(array!(self, opening, content, HARDLINE, closing));
+        if forced_break {
+            return multiline;
+        }
+        let children = children.iter().map(|child| self.jsx_child_doc(*child)).collect::<Vec<_>>();
+        let flat = self.group(array!(self, opening, self.fill(&children), closing));
+        self.conditional_group(&[flat, multiline])
+    }
+
+    fn jsx_text(&self, text: &JSXText) -> &'a str {
+        &self.source_text[text.span.start as usize..text.span.end as usize]
+    }
+
+    fn jsx_child_doc(&self, child: Child<'a>) -> Doc<'a> {
+        match child {
+            Child::Doc(doc) => doc,
+            Child::Empty => Doc::Str(""),
+            Child::Whitespace => {
+                let raw = array!(self, RAW_JSX_WHITESPACE, SOFTLINE);
+                self.if_break(raw, Doc::Str(" "))
+            }
+            Child::Line => LINE,
+            Child::Softline => SOFTLINE,
+            Child::Hardline => HARDLINE,
+        }
+    }
+
+    /// Remove the redundant separators left by whitespace-only text, see `printJsxElementInternal`
+    fn clean_jsx_children(children: &mut Vec<Child<'a>>, contains_text: bool) {
+        let is = |children: &[Child<'a>], index: usize, f: fn(Child<'a>) -> bool| {
+            children.get(index).map_or(false, |child| f(*child))
+        };
+        let mut index = children.len().saturating_sub(1);
+        while index > 0 {
+            index -= 1;
+            let i = index;
+            let pair_of_empty_strings =
+                is(children, i, Child::is_empty) && is(children, i + 1, Child::is_empty);
+            let followed_by_empty = is(children, i + 1, Child::is_empty);
+            let pair_of_hardlines = is(children, i, Child::is_hardline)
+                && followed_by_empty
+                && is(children, i + 2, Child::is_hardline);
+            let line_followed_by_whitespace = is(children, i, Child::is_line)
+                && followed_by_empty
+                && is(children, i + 2, Child::is_whitespace);
+            let whitespace_followed_by_line = is(children, i, Child::is_whitespace)
+                && followed_by_empty
+                && is(children, i + 2, Child::is_line);
+            let double_whitespace = is(children, i, Child::is_whitespace)
+                && followed_by_empty
+                && is(children, i + 2, Child::is_whitespace);
+            let pair_of_hard_or_soft_lines = is(children, i, Child::is_line)
+                && followed_by_empty
+                && is(children, i + 2, Child::is_line)
+                && !(is(children, i, Child::is_hardline)
+                    && is(children, i + 2, Child::is_hardline));
+            if (pair_of_hardlines && contains_text)
+                || pair_of_empty_strings
+                || line_followed_by_whitespace
+                || double_whitespace
+            {
+                children.drain(i..i + 2);
+            } else if whitespace_followed_by_line || pair_of_hard_or_soft_lines {
+                children.drain(i + 1..i + 3);
+            }
+        }
+        // Trim the lines and empty strings at both ends
+        while children.last().map_or(false, |child| child.is_line() || child.is_empty()) {
+            children.pop();
+        }
+        while children.len() > 1
+            && (children[0].is_line() || children[0].is_empty())
+            && (children[1].is_line() || children[1].is_empty())
+        {
+            children.drain(..2);
+        }
+    }
+
+    /// See `printJsxChildren`, the words of texts are filled between the other children
+    fn format_jsx_children(&mut self, children: &'a [JSXChild<'a>]) -> Vec<Child<'a>> {
+        let mut parts = vec![];
+        for (index, child) in children.iter().enumerate() {
+            let next = children.get(index + 1);
+            let JSXChild::Text(text) = child else {
+                parts.push(Child::Doc(self.format_jsx_child(child)));
+                let next_text = match next {
+                    Some(JSXChild::Text(text)) if is_meaningful_jsx_text(self.jsx_text(text)) => {
+                        Some(self.jsx_text(text))
+                    }
+                    _ => None,
+                };
+                if let Some(next_text) = next_text {
+                    let first_word = next_text.trim_matches(is_jsx_whitespace);
+                    let first_word = first_word.split(is_jsx_whitespace).next().unwrap_or_default();
+                    parts.push(Self::separator_no_whitespace(first_word, child, next));
+                } else {
+                    parts.push(Child::Hardline);
+                }
+                continue;
+            };
+            let text = self.jsx_text(text);
+            if !is_meaningful_jsx_text(text) {
+                // Keep up to one blank line between the children
+                if text.matches('\n').count() > 1 {
+                    parts.extend([Child::Empty, Child::Hardline]);
+                }
+                continue;
+            }
+            let mut words = split_jsx_words(text);
+            if words[0].is_empty() {
+                parts.push(Child::Empty);
+                let whitespace = words[1];
+                if whitespace.contains('\n') {
+                    let word = words.get(2).copied().unwrap_or_default();
+                    parts.push(Self::separator_no_whitespace(word, child, next));
+                } else {
+                    parts.push(Child::Whitespace);
+                }
+                words.drain(..2);
+            }
+            let end_whitespace = if words.last().map_or(false, |word| word.is_empty()) {
+                words.pop();
+                words.pop()
+            } else {
+                None
+            };
+            if words.is_empty() {
+                continue;
+            }
+            for (index, word) in words.iter().enumerate() {
+                parts.push(if index % 2 == 1 { Child::Line } else { Child::Doc(Doc::Str(word)) });
+            }
+            let last_word = words[words.len() - 1];
+            parts.push(match end_whitespace {
+                Some(whitespace) if !whitespace.contains('\n') => Child::Whitespace,
+                _ => Self::separator_no_whitespace(last_word, child, next),
+            });
+        }
+        parts
+    }
+
+    /// See `separatorNoWhitespace` and `separatorWithWhitespace`,
+    /// self-closing elements next to text are kept on their own lines
+    fn separator_no_whitespace(
+        word: &str,
+        child: &JSXChild<'a>,
+        next: Option<&JSXChild<'a>>,
+    ) -> Child<'a> {
+        let is_self_closing = |child: Option<&JSXChild<'a>>| match child {
+            Some(JSXChild::Element(element)) => element.closing_element.is_none(),
+            _ => false,
+        };
+        if is_self_closing(Some(child)) || is_self_closing(next) {
+            if word.len() == 1 {
+                Child::Softline
+            } else {
+                Child::Hardline
+            }
+        } else {
+            Child::Softline
+        }
+    }
+
+    fn format_jsx_child(&mut self, child: &'a JSXChild<'a>) -> Doc<'a> {
+        match child {
+            JSXChild::Text(text) => Doc::Str(self.jsx_text(text)),
+            JSXChild::Element(element) => self.format_jsx_element_without_parens(element),
+            JSXChild::Fragment(fragment) => self.format_jsx_fragment(fragment, Context::Default),
+            JSXChild::ExpressionContainer(container) => {
+                self.format_jsx_expression_container(container, true)
+            }
+            JSXChild::Spread(spread) => {
+                let expression = self.format_expression(&spread.expression, Context::Default);
+                array!(self, "{...", expression, "}")
+            }
+        }
+    }
+
+    /// See `printJsxExpressionContainer`
+    fn format_jsx_expression_container(
+        &mut self,
+        container: &'a JSXExpressionContainer<'a>,
+        is_child: bool,
+    ) -> Doc<'a> {
+        let expr = match &container.expression {
+            JSXExpression::EmptyExpression(_) => {
+                return self.dangling_comments_in("{", container.span.end, "}");
+            }
+            JSXExpression::Expression(expr) => Self::strip_parens(expr),
+        };
+        let should_inline = matches!(
+            expr,
+            Expression::JSXElement(_)
+                | Expression::JSXFragment(_)
+                | Expression::ArrayExpression(_)
+                | Expression::ObjectExpression(_)
+                | Expression::ArrowFunctionExpression(_)
+                | Expression::CallExpression(_)
+                | Expression::FunctionExpression(_)
+                | Expression::TemplateLiteral(_)
+                | Expression::TaggedTemplateExpression(_)
+        ) || (is_child
+            && (matches!(expr, Expression::ConditionalExpression(_)) || Self::is_binaryish(expr)));
+        let printed = self.with_leading_comments(expr.span().start, |f| {
+            f.format_expression(expr, Context::Default)
+        });
+        if should_inline {
+            return self.group(array!(self, "{", printed, "}"));
+        }
+        let printed = self.indent(array!(self, SOFTLINE, printed));
+        self.group(array!(self, "{", printed, SOFTLINE, "}"))
+    }
+
+    /// See `printJsxOpeningElement`
+    fn format_jsx_opening_element(&mut self, element: &'a JSXOpeningElement<'a>) -> Doc<'a> {
+        let name = self.format_jsx_element_name(&element.name);
+        let type_arguments = self.format_type_arguments(element.type_parameters.as_deref());
+        let close = if element.self_closing { " />" } else { ">" };
+        if element.attributes.is_empty() {
+            return array!(self, "<", name, type_arguments, close);
+        }
+        let attributes =
+            element.attributes.iter().map(|attribute| attribute.format(self)).collect::<Vec<_>>();
+        // <div className="a">
+        if let [JSXAttributeItem::Attribute(attribute)] = element.attributes.as_slice()
+            && let Some(JSXAttributeValue::StringLiteral(value)) = &attribute.value
+            && !value.value.contains('\n')
+        {
+            return self.group(array!(self, "<", name, type_arguments, " ", attributes[0], close));
+        }
+        // Multiline strings are kept on their own lines
+        let should_break = element.attributes.iter().any(|attribute| {
+            matches!(attribute, JSXAttributeItem::Attribute(attribute)
+                if matches!(&attribute.value,
+                    Some(JSXAttributeValue::StringLiteral(value)) if value.value.contains('\n')))
+        });
+        let mut parts = vec![];
+        for attribute in attributes {
+            parts.extend([LINE, attribute]);
+        }
+        let attributes = self.indent(self.array(parts));
+        let close = if element.self_closing {
+            array!(self, LINE, "/>")
+        } else {
+            array!(self, SOFTLINE, ">")
+        };
+        self.group_with(
+            array!(self, "<", name, type_arguments, attributes, close),
+            should_break,
+            None,
+        )
+    }
+
+    fn format_jsx_element_name(&self, name: &'a JSXElementName<'a>) -> Doc<'a> {
+        match name {
+            JSXElementName::Identifier(ident) => Doc::Str(&ident.name),
+            JSXElementName::NamespacedName(name) => {
+                array!(self, &name.namespace.name, ":", &name.property.name)
+            }
+            JSXElementName::MemberExpression(expr) => self.format_jsx_member_expression(expr),
+        }
+    }
+
+    fn format_jsx_member_expression(&self, expr: &'a JSXMemberExpression<'a>) -> Doc<'a> {
+        let object = match &expr.object {
+            JSXMemberExpressionObject::Identifier(ident) => Doc::Str(&ident.name),
+            JSXMemberExpressionObject::MemberExpression(expr) => {
+                self.format_jsx_member_expression(expr)
+            }
+        };
+        array!(self, object, ".", &expr.property.name)
+    }
+
+    /// Attribute strings prefer double quotes, quotes are escaped as HTML entities,
+    /// see `printJsxAttribute`
+    fn format_jsx_string(&self, lit: &StringLiteral) -> Doc<'a> {
+        let raw = &self.source_text[lit.span.start as usize..lit.span.end as usize];
+        let content = raw[1..raw.len() - 1].replace("&apos;", "'").replace("&quot;", "\"");
+        let (quote, escaped) = if content.matches('"').count() > content.matches('\'').count() {
+            ('\'', "&apos;")
+        } else {
+            ('"', "&quot;")
+        };
+        let content = content.replace(quote, escaped);
+        self.string(&format!("{quote}{content}{quote}"))
+    }
+}
+
+impl<'a> Format<'a> for JSXAttributeItem<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::Attribute(attribute) => {
+                // The names are lexed with the whitespace before them
+                let name = match &attribute.name {
+                    JSXAttributeName::Identifier(ident) => Doc::Str(ident.name.trim_start()),
+                    JSXAttributeName::NamespacedName(name) => {
+                        array!(f, name.namespace.name.trim_start(), ":", &name.property.name)
+                    }
+                };
+                let Some(value) = &attribute.value else {
+                    return name;
+                };
+                let value = match value {
+                    JSXAttributeValue::StringLiteral(lit) => f.format_jsx_string(lit),
+                    JSXAttributeValue::ExpressionContainer(container) => {
+                        f.format_jsx_expression_container(container, false)
+                    }
+                    JSXAttributeValue::Element(element) => {
+                        f.format_jsx_element_without_parens(element)
+                    }
+                    JSXAttributeValue::Fragment(fragment) => {
+                        f.format_jsx_fragment(fragment, Context::Default)
+                    }
+                };
+                array!(f, name, "=", value)
+            }
+            Self::SpreadAttribute(attribute) => {
+                let argument = f.format_expression(&attribute.argument, Context::Default);
+                array!(f, "{...", argument, "}")
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Literals and template literals
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan, Span};
+
+use super::{Context, Format};
+use crate::{doc::Doc, printer::Printer, Formatter, FormatterOptions};
+
+impl<'a> Format<'a> for NumberLiteral<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let printed = print_number(self.raw);
+        if printed == self.raw {
+            Doc::Str(self.raw)
+        } else {
+            f.string(&printed)
+        }
+    }
+}
+
+impl<'a> Format<'a> for BigintLiteral {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let raw = &f.source_text[self.span.start as usize..self.span.end as usize];
+        f.string(&raw.to_lowercase())
+    }
+}
+
+impl<'a> Format<'a> for RegExpLiteral {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.string(&self.regex.to_string())
+    }
+}
+
+impl<'a> Format<'a> for StringLiteral {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.format_string(self.span)
+    }
+}
+
+impl<'a> Format<'a> for TemplateLiteral<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![Doc::Str("`")];
+        for (index, quasi) in self.quasis.iter().enumerate() {
+            parts.push(f.literal_text(&quasi.value.raw));
+            let Some(expr) = self.expressions.get(index) else { continue };
+            let printed = f.format_expression(expr, Context::NoIndent);
+            // Interpolations without line breaks are kept on one line, see `printTemplateLiteral`
+            let next = &self.quasis[index + 1];
+            let interpolation = &f.source_text[quasi.span.end as usize..next.span.start as usize];
+            let printed = if interpolation.contains('\n') {
+                printed
+            } else {
+                let options = FormatterOptions { print_width: usize::MAX >> 1, ..f.options };
+                let printed = Printer::new(printed, &options, f.next_group_id).build();
+                f.string(&printed)
+            };
+            parts.extend([Doc::Str("${"), printed, Doc::Str("}")]);
+        }
+        parts.push(Doc::Str("`"));
+        f.array(parts)
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Print the string literal at `span` with the preferred quotes,
+    /// unless it contains more of them than the alternate quotes, see `printString`.
+    pub(crate) fn format_string(&self, span: Span) -> Doc<'a> {
+        let raw = &self.source_text[span.start as usize..span.end as usize];
+        let content = &raw[1..raw.len() - 1];
+        let (preferred, alternate) =
+            if self.options.single_quote { ('\'', '"') } else { ('"', '\'') };
+        let enclosing = if content.matches(preferred).count() > content.matches(alternate).count() {
+            alternate
+        } else {
+            preferred
+        };
+        if raw.starts_with(enclosing) && !content.contains(['\\', enclosing]) {
+            return Doc::Str(raw);
+        }
+        self.string(&make_string(content, enclosing))
+    }
+
+    /// `expr.span()` as written in the source
+    pub(crate) fn source<T: GetSpan>(&self, node: &T) -> &'a str {
+        let span = node.span();
+        &self.source_text[span.start as usize..span.end as usize]
+    }
+}
+
+/// Enclose `content` in `quote`, escaping it and unescaping the other quote, see `makeString`
+fn make_string(content: &str, quote: char) -> String {
+    let mut result = String::with_capacity(content.len() + 2);
+    result.push(quote);
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped @ ('"' | '\'')) if escaped != quote => result.push(escaped),
+                Some(escaped) => {
+                    result.push('\\');
+                    result.push(escaped);
+                }
+                None => result.push('\\'),
+            },
+            c if c == quote => {
+                result.push('\\');
+                result.push(c);
+            }
+            c => result.push(c),
+        }
+    }
+    result.push(quote);
+    result
+}
+
+/// Normalize a number literal, e.g. `0XAB` -> `0xab`, `1.50E+10` -> `1.5e10`, `.5` -> `0.5`,
+/// see `printNumber`
+fn print_number(raw: &str) -> String {
+    let raw = raw.to_lowercase();
+    if raw.starts_with("0x") || raw.starts_with("0o") || raw.starts_with("0b") {
+        return raw;
+    }
+    let (mantissa, exponent) = match raw.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (raw.as_str(), None),
+    };
+    let mut result = String::new();
+    if mantissa.starts_with('.') {
+        result.push('0');
+    }
+    match mantissa.split_once('.') {
+        Some((integer, fraction)) => {
+            result.push_str(integer);
+            let fraction = fraction.trim_end_matches('0');
+            if !fraction.is_empty() {
+                result.push('.');
+                result.push_str(fraction);
+            }
+        }
+        None => result.push_str(mantissa),
+    }
+    if let Some(exponent) = exponent {
+        let (sign, digits) = exponent.strip_prefix('-').map_or_else(
+            || ("", exponent.strip_prefix('+').unwrap_or(exponent)),
+            |digits| ("-", digits),
+        );
+        let digits = digits.trim_start_matches('0');
+        if !digits.is_empty() {
+            result.push('e');
+            result.push_str(sign);
+            result.push_str(digits);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::{make_string, print_number};
+
+    #[test]
+    fn number() {
+        assert_eq!(print_number("0XABCDEF"), "0xabcdef");
+        assert_eq!(print_number("1.50E+10"), "1.5e10");
+        assert_eq!(print_number("1e-05"), "1e-5");
+        assert_eq!(print_number("1e+00"), "1");
+        assert_eq!(print_number(".5"), "0.5");
+        assert_eq!(print_number("5."), "5");
+        assert_eq!(print_number("1.0"), "1");
+        assert_eq!(print_number("1_000.000_1"), "1_000.000_1");
+    }
+
+    #[test]
+    fn string() {
+        assert_eq!(make_string("a'b", '"'), "\"a'b\"");
+        assert_eq!(make_string("a\\'b", '"'), "\"a'b\"");
+        assert_eq!(make_string("a\"b", '"'), "\"a\\\"b\"");
+        assert_eq!(make_string("a\\nb", '\''), "'a\\nb'");
+    }
+}
@@ -0,0 +1,144 @@
+//! Conversion of the AST into documents
+//!
+//! Ported from the estree printer of [Prettier](https://github.com/prettier/prettier/tree/main/src/language-js/print),
+//! the function names in the comments refer to the Prettier source.
+
+mod binary;
+mod call;
+mod class;
+mod expression;
+mod function;
+mod jsx;
+mod literal;
+mod module;
+mod object;
+mod statement;
+mod ts;
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan, Span};
+
+use crate::{
+    comment::is_next_line_empty,
+    doc::{array, Doc, HARDLINE},
+    Formatter,
+};
+
+pub trait Format<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a>;
+}
+
+impl<'a, T: Format<'a>> Format<'a> for oxc_allocator::Box<'a, T> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        (**self).format(f)
+    }
+}
+
+/// Where an expression is printed, for the layouts depending on the parent node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    Default,
+    /// The test of `if`, `while`, `do while` and `switch`, printed inside the parentheses
+    Test,
+    /// The callee, the object of a member expression or the argument of a unary expression
+    Operand,
+    /// Binary expressions are not indented in `return`, `throw`, arrow bodies and templates
+    NoIndent,
+    /// The right hand side of an assignment, a variable declarator or a property
+    Assignment,
+    ExpressionStatement,
+}
+
+impl<'a> Format<'a> for Program<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.format_statements(&self.directives, &self.body, self.span.end)
+            .map_or(Doc::Str(""), |body| array!(f, body, HARDLINE))
+    }
+}
+
+impl<'a> Format<'a> for Directive<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let span = self.expression.span;
+        let raw = &f.source_text[span.start as usize..span.end as usize];
+        // Only change the quotes when it does not change the directive, see `printDirective`
+        let printed = if self.directive.contains(['"', '\'']) {
+            Doc::Str(raw)
+        } else {
+            let quote = if f.options.single_quote { "'" } else { "\"" };
+            array!(f, quote, self.directive, quote)
+        };
+        array!(f, printed, f.semi())
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// `";"` when semicolons are enabled
+    pub(crate) fn semi(&self) -> &'static str {
+        if self.options.semi {
+            ";"
+        } else {
+            ""
+        }
+    }
+
+    /// Print the directives and statements of a program or a block,
+    /// followed by the comments left before `end`.
+    /// Blank lines between statements are preserved, collapsed into one.
+    pub(crate) fn format_statements(
+        &mut self,
+        directives: &'a [Directive<'a>],
+        statements: &'a [Statement<'a>],
+        end: u32,
+    ) -> Option<Doc<'a>> {
+        let mut parts = vec![];
+        let mut last_end = None;
+        let items = directives.iter().map(|directive| (directive.span, None)).chain(
+            statements
+                .iter()
+                .filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)))
+                .map(|stmt| (stmt.span(), Some(stmt))),
+        );
+        let mut directives = directives.iter();
+        for (span, stmt) in items {
+            if let Some(end) = last_end {
+                parts.push(self.statement_separator(end));
+            }
+            parts.push(self.with_leading_comments(span.start, |f| match stmt {
+                Some(stmt) => stmt.format(f),
+                None => directives.next().unwrap().format(f),
+            }));
+            if let Some(comments) = self.trailing_comments(span.end) {
+                parts.push(comments);
+            }
+            last_end = Some(span.end);
+        }
+        if self.has_comments_before(end) {
+            if let Some(end) = last_end {
+                parts.push(self.statement_separator(end));
+            }
+            parts.extend(self.dangling_comments(end));
+        }
+        (!parts.is_empty()).then(|| self.array(parts))
+    }
+
+    fn statement_separator(&self, end: u32) -> Doc<'a> {
+        if is_next_line_empty(self.source_text, end as usize) {
+            array!(self, HARDLINE, HARDLINE)
+        } else {
+            HARDLINE
+        }
+    }
+
+    /// `{ statements }`, with the statements on their own lines
+    pub(crate) fn format_block(
+        &mut self,
+        span: Span,
+        directives: &'a [Directive<'a>],
+        statements: &'a [Statement<'a>],
+    ) -> Doc<'a> {
+        self.format_statements(directives, statements, span.end).map_or(Doc::Str("{}"), |body| {
+            let body = self.indent(array!(self, HARDLINE, body));
+            array!(self, "{", body, HARDLINE, "}")
+        })
+    }
+}
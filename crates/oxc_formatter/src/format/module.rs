@@ -0,0 +1,211 @@
+//! Imports and exports
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Span};
+
+use super::{Context, Format};
+use crate::{
+    doc::{array, Doc, LINE, SOFTLINE},
+    Formatter,
+};
+
+impl<'a> Format<'a> for ModuleDeclaration<'a> {
+    #[allow(clippy::too_many_lines)]
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match &self.kind {
+            ModuleDeclarationKind::ImportDeclaration(decl) => {
+                let mut parts = vec![Doc::Str("import")];
+                if decl.import_kind == Some(ImportOrExportKind::Type) {
+                    parts.push(Doc::Str(" type"));
+                }
+                let mut standalone = vec![];
+                let mut grouped = vec![];
+                for specifier in &decl.specifiers {
+                    match specifier {
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            standalone.push(Doc::Str(&specifier.local.name));
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            standalone.push(array!(f, "* as ", &specifier.local.name));
+                        }
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            let imported = specifier.imported.format(f);
+                            let is_same = matches!(&specifier.imported,
+                                ModuleExportName::Identifier(ident)
+                                    if ident.name == specifier.local.name);
+                            grouped.push(if is_same {
+                                imported
+                            } else {
+                                array!(f, imported, " as ", &specifier.local.name)
+                            });
+                        }
+                    }
+                }
+                // `import {} from "a"` is kept, but `import "a"` has no specifiers at all
+                let source_text =
+                    &f.source_text[self.span.start as usize..decl.source.span.start as usize];
+                if !standalone.is_empty() || !grouped.is_empty() || source_text.contains('{') {
+                    let specifiers = f.format_module_specifiers(&standalone, grouped);
+                    parts.extend([Doc::Str(" "), specifiers, Doc::Str(" from")]);
+                }
+                parts.extend([Doc::Str(" "), decl.source.format(f)]);
+                parts.push(f.format_import_attributes(decl.with_clause.as_ref()));
+                parts.push(Doc::Str(f.semi()));
+                f.array(parts)
+            }
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    if let Declaration::ClassDeclaration(class) = declaration {
+                        if decorated_before_export(class, self.span) {
+                            let decorators = f.format_decorators(&class.decorators);
+                            let class = f.format_class(class, Doc::Str(""));
+                            return array!(f, decorators, "export ", class);
+                        }
+                    }
+                    let declaration = declaration.format(f);
+                    return array!(f, "export ", declaration);
+                }
+                let mut parts = vec![Doc::Str("export")];
+                if decl.export_kind == Some(ImportOrExportKind::Type) {
+                    parts.push(Doc::Str(" type"));
+                }
+                let grouped = decl
+                    .specifiers
+                    .iter()
+                    .map(|specifier| {
+                        let local = specifier.local.format(f);
+                        if specifier.local.to_string() == specifier.exported.to_string()
+                            && std::mem::discriminant(&specifier.local)
+                                == std::mem::discriminant(&specifier.exported)
+                        {
+                            local
+                        } else {
+                            let exported = specifier.exported.format(f);
+                            array!(f, local, " as ", exported)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                parts.extend([Doc::Str(" "), f.format_module_specifiers(&[], grouped)]);
+                if let Some(source) = &decl.source {
+                    parts.extend([Doc::Str(" from "), source.format(f)]);
+                    parts.push(f.format_import_attributes(decl.with_clause.as_ref()));
+                }
+                parts.push(Doc::Str(f.semi()));
+                f.array(parts)
+            }
+            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => {
+                if let ExportDefaultDeclarationKind::ClassDeclaration(class) = &decl.declaration {
+                    if decorated_before_export(class, self.span) {
+                        let decorators = f.format_decorators(&class.decorators);
+                        let class = f.format_class(class, Doc::Str(""));
+                        return array!(f, decorators, "export default ", class);
+                    }
+                }
+                let declaration = match &decl.declaration {
+                    ExportDefaultDeclarationKind::Expression(expr) => {
+                        let expr =
+                            f.format_expression(Formatter::strip_parens(expr), Context::Default);
+                        array!(f, expr, f.semi())
+                    }
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.format(f),
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => class.format(f),
+                    ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => decl.format(f),
+                    ExportDefaultDeclarationKind::TSEnumDeclaration(decl) => decl.format(f),
+                };
+                array!(f, "export default ", declaration)
+            }
+            ModuleDeclarationKind::ExportAllDeclaration(decl) => {
+                let mut parts = vec![Doc::Str("export")];
+                if decl.export_kind == Some(ImportOrExportKind::Type) {
+                    parts.push(Doc::Str(" type"));
+                }
+                parts.push(Doc::Str(" *"));
+                if let Some(exported) = &decl.exported {
+                    parts.extend([Doc::Str(" as "), exported.format(f)]);
+                }
+                parts.extend([Doc::Str(" from "), decl.source.format(f)]);
+                parts.push(f.format_import_attributes(decl.with_clause.as_ref()));
+                parts.push(Doc::Str(f.semi()));
+                f.array(parts)
+            }
+            ModuleDeclarationKind::TSExportAssignment(decl) => {
+                let expression = decl.expression.format(f);
+                array!(f, "export = ", expression, f.semi())
+            }
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(decl) => {
+                array!(f, "export as namespace ", &decl.id.name, f.semi())
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for ModuleExportName {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::Identifier(ident) => Doc::Str(&ident.name),
+            Self::StringLiteral(lit) => lit.format(f),
+        }
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// `a, * as b` followed by `{ c, d }`, see `printModuleSpecifiers`
+    fn format_module_specifiers(
+        &mut self,
+        standalone: &[Doc<'a>],
+        grouped: Vec<Doc<'a>>,
+    ) -> Doc<'a> {
+        let mut parts = vec![self.join(Doc::Str(", "), standalone.iter().copied())];
+        if standalone.is_empty() || !grouped.is_empty() {
+            if !standalone.is_empty() {
+                parts.push(Doc::Str(", "));
+            }
+            let can_break = grouped.len() > 1 || !standalone.is_empty();
+            if grouped.is_empty() {
+                parts.push(Doc::Str("{}"));
+            } else if can_break {
+                let line = if self.options.bracket_spacing { LINE } else { SOFTLINE };
+                let separator = array!(self, ",", LINE);
+                let specifiers = self.indent(array!(self, line, self.join(separator, grouped)));
+                let comma = if self.options.trailing_comma.es5() { "," } else { "" };
+                let comma = self.if_break(Doc::Str(comma), Doc::Str(""));
+                parts.push(self.group(array!(self, "{", specifiers, comma, line, "}")));
+            } else {
+                let space = if self.options.bracket_spacing { " " } else { "" };
+                parts.push(array!(self, "{", space, grouped[0], space, "}"));
+            }
+        }
+        self.array(parts)
+    }
+
+    /// ` with { type: "json" }`, keeping the `assert` keyword when it was used
+    fn format_import_attributes(&mut self, with_clause: Option<&'a WithClause<'a>>) -> Doc<'a> {
+        let Some(with_clause) = with_clause else {
+            return Doc::Str("");
+        };
+        let keyword = Doc::Str(with_clause.keyword.as_str());
+        if with_clause.attributes.is_empty() {
+            return array!(self, " ", keyword, " {}");
+        }
+        let attributes = with_clause
+            .attributes
+            .iter()
+            .map(|attribute| {
+                let key = match &attribute.key {
+                    ImportAttributeKey::Identifier(ident) => Doc::Str(&ident.name),
+                    ImportAttributeKey::StringLiteral(lit) => lit.format(self),
+                };
+                let value = attribute.value.format(self);
+                array!(self, key, ": ", value)
+            })
+            .collect::<Vec<_>>();
+        let space = if self.options.bracket_spacing { " " } else { "" };
+        let attributes = self.join(Doc::Str(", "), attributes);
+        array!(self, " ", keyword, " {", space, attributes, space, "}")
+    }
+}
+
+/// `@dec export class`, the decorators of the class start before the `export` keyword
+fn decorated_before_export(class: &Class, export_span: Span) -> bool {
+    class.decorators.first().map_or(false, |decorator| decorator.span.start < export_span.start)
+}
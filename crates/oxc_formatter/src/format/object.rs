@@ -0,0 +1,437 @@
+//! Objects, arrays and destructuring patterns
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan, Span};
+
+use super::{expression::AssignmentLike, Format};
+use crate::{
+    comment::is_next_line_empty,
+    doc::{array, Doc, HARDLINE, LINE, SOFTLINE},
+    Formatter,
+};
+
+/// A printed element of an array, `None` for holes
+struct ArrayItem<'a> {
+    doc: Option<Doc<'a>>,
+    end: u32,
+}
+
+impl<'a> Format<'a> for ObjectExpression<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut items = vec![];
+        for property in &self.properties {
+            let span = property.span();
+            let doc = f.with_leading_comments(span.start, |f| match property {
+                ObjectProperty::Property(property) => property.format(f),
+                ObjectProperty::SpreadProperty(spread) => spread.format(f),
+            });
+            items.push((f.with_trailing_comments(span.end, doc), span.end));
+        }
+        // Objects written with a line break after `{` stay expanded
+        let should_break = self.properties.first().map_or(false, |first| {
+            f.source_text[self.span.start as usize..first.span().start as usize].contains('\n')
+        });
+        f.format_object_items(self.span, items, should_break, true)
+    }
+}
+
+impl<'a> Format<'a> for Property<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        if self.shorthand {
+            return match &self.value {
+                PropertyValue::Pattern(pattern) => pattern.format(f),
+                PropertyValue::Expression(expr) => expr.format(f),
+            };
+        }
+        let key = f.format_property_key(&self.key, self.computed, true);
+        match &self.value {
+            PropertyValue::Expression(Expression::FunctionExpression(func))
+                if self.method || self.kind != PropertyKind::Init =>
+            {
+                let kind = match self.kind {
+                    PropertyKind::Init => "",
+                    PropertyKind::Get => "get ",
+                    PropertyKind::Set => "set ",
+                };
+                f.format_method(kind, key, func)
+            }
+            PropertyValue::Expression(value) => {
+                let short_key = match &self.key {
+                    PropertyKey::Identifier(ident) if !self.computed => Some(ident.name.as_str()),
+                    PropertyKey::Expression(Expression::StringLiteral(_)) if !self.computed => {
+                        Some(f.source(&self.key))
+                    }
+                    _ => None,
+                };
+                f.format_assignment(
+                    key,
+                    Doc::Str(":"),
+                    Some(value),
+                    AssignmentLike::Property(short_key),
+                )
+            }
+            PropertyValue::Pattern(pattern) => {
+                let pattern = pattern.format(f);
+                array!(f, key, ": ", pattern)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for ArrayExpression<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut items = vec![];
+        let mut end = self.span.start;
+        for element in &self.elements {
+            let doc = element.as_ref().map(|element| {
+                end = element.span().end;
+                let doc = f.with_leading_comments(element.span().start, |f| element.format(f));
+                f.with_trailing_comments(end, doc)
+            });
+            items.push(ArrayItem { doc, end });
+        }
+        // [{ a: 1, b: 2 }, { a: 3, b: 4 }] is printed as a table
+        let should_break = self.elements.len() > 1
+            && self.elements.iter().all(|element| match element {
+                Some(Argument::Expression(Expression::ObjectExpression(object))) => {
+                    object.properties.len() > 1
+                        && matches!(
+                            self.elements[0],
+                            Some(Argument::Expression(Expression::ObjectExpression(_)))
+                        )
+                }
+                Some(Argument::Expression(Expression::ArrayExpression(array))) => {
+                    array.elements.len() > 1
+                        && matches!(
+                            self.elements[0],
+                            Some(Argument::Expression(Expression::ArrayExpression(_)))
+                        )
+                }
+                _ => false,
+            });
+        let concise = Formatter::is_concisely_printed_array(self);
+        f.format_array_items(self.span, items, should_break, concise, true)
+    }
+}
+
+impl<'a> Format<'a> for BindingPattern<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.format_binding_pattern(self, false)
+    }
+}
+
+impl<'a> Format<'a> for ArrayAssignmentTarget<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut items = vec![];
+        let mut end = self.span.start;
+        for element in &self.elements {
+            let doc = element.as_ref().map(|element| {
+                end = match element {
+                    AssignmentTargetMaybeDefault::AssignmentTarget(target) => target.span().end,
+                    AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+                        target.span.end
+                    }
+                };
+                let doc = element.format(f);
+                f.with_trailing_comments(end, doc)
+            });
+            items.push(ArrayItem { doc, end });
+        }
+        if let Some(rest) = &self.rest {
+            let rest_doc = rest.format(f);
+            let doc = f.with_trailing_comments(rest.span().end, array!(f, "...", rest_doc));
+            items.push(ArrayItem { doc: Some(doc), end: rest.span().end });
+        }
+        f.format_array_items(self.span, items, false, false, self.rest.is_none())
+    }
+}
+
+impl<'a> Format<'a> for ObjectAssignmentTarget<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut items = vec![];
+        for property in &self.properties {
+            let span = match property {
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                    property.span
+                }
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                    property.span
+                }
+            };
+            let doc = f.with_leading_comments(span.start, |f| match property {
+                AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                    property.init.as_ref().map_or_else(
+                        || Doc::Str(&property.binding.name),
+                        |init| {
+                            let init = Formatter::strip_parens(init).format(f);
+                            array!(f, &property.binding.name, " = ", init)
+                        },
+                    )
+                }
+                AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                    let computed = is_computed(f, property.span, &property.name);
+                    let key = f.format_property_key(&property.name, computed, true);
+                    let binding = property.binding.format(f);
+                    array!(f, key, ": ", binding)
+                }
+            });
+            items.push((f.with_trailing_comments(span.end, doc), span.end));
+        }
+        if let Some(rest) = &self.rest {
+            let rest_doc = rest.format(f);
+            let doc = f.with_trailing_comments(rest.span().end, array!(f, "...", rest_doc));
+            items.push((doc, rest.span().end));
+        }
+        f.format_object_items(self.span, items, false, self.rest.is_none())
+    }
+}
+
+/// `AssignmentTargetPropertyProperty` does not record whether the key is computed
+fn is_computed(f: &Formatter, span: Span, key: &PropertyKey) -> bool {
+    f.source_text[span.start as usize..key.span().start as usize].contains('[')
+}
+
+impl<'a> Formatter<'a> {
+    /// `pattern` with its `?` and type annotation, as in parameters.
+    /// Object patterns written as the only parameter are not expanded like declarations are.
+    pub(crate) fn format_binding_pattern(
+        &mut self,
+        pattern: &'a BindingPattern<'a>,
+        in_params: bool,
+    ) -> Doc<'a> {
+        let doc = self.format_binding_pattern_kind(&pattern.kind, in_params);
+        let doc = if pattern.optional { array!(self, doc, "?") } else { doc };
+        pattern.type_annotation.as_ref().map_or(doc, |annotation| {
+            let annotation = annotation.format(self);
+            array!(self, doc, annotation)
+        })
+    }
+
+    /// `pattern` without its type annotation
+    pub(crate) fn format_binding_pattern_kind(
+        &mut self,
+        kind: &'a BindingPatternKind<'a>,
+        in_params: bool,
+    ) -> Doc<'a> {
+        match kind {
+            BindingPatternKind::BindingIdentifier(ident) => Doc::Str(&ident.name),
+            BindingPatternKind::ObjectPattern(object) => {
+                self.format_object_pattern(object, in_params)
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                let mut items = vec![];
+                let mut end = array.span.start;
+                for element in &array.elements {
+                    let doc = element.as_ref().map(|element| {
+                        end = element.span().end;
+                        let doc =
+                            self.with_leading_comments(element.span().start, |f| element.format(f));
+                        self.with_trailing_comments(end, doc)
+                    });
+                    items.push(ArrayItem { doc, end });
+                }
+                let can_have_trailing_comma = !array.elements.last().map_or(
+                    false,
+                    |last| matches!(last, Some(last) if last.kind.is_rest_element()),
+                );
+                self.format_array_items(array.span, items, false, false, can_have_trailing_comma)
+            }
+            BindingPatternKind::RestElement(rest) => {
+                let argument = rest.argument.format(self);
+                array!(self, "...", argument)
+            }
+            BindingPatternKind::AssignmentPattern(pattern) => {
+                let left = pattern.left.format(self);
+                let right = Self::strip_parens(&pattern.right).format(self);
+                array!(self, left, " = ", right)
+            }
+        }
+    }
+
+    fn format_object_pattern(&mut self, object: &'a ObjectPattern<'a>, in_params: bool) -> Doc<'a> {
+        let mut items = vec![];
+        for property in &object.properties {
+            let span = property.span();
+            let doc = self.with_leading_comments(span.start, |f| match property {
+                ObjectPatternProperty::Property(property) => property.format(f),
+                ObjectPatternProperty::RestElement(rest) => {
+                    let argument = rest.argument.format(f);
+                    array!(f, "...", argument)
+                }
+            });
+            items.push((self.with_trailing_comments(span.end, doc), span.end));
+        }
+        // const {
+        //   a: { b },
+        // } = c;
+        let should_break = !in_params && object.properties.iter().any(|property| match property {
+            ObjectPatternProperty::Property(property) => match &property.value {
+                PropertyValue::Pattern(pattern) => match &pattern.kind {
+                    BindingPatternKind::ObjectPattern(nested) => !nested.properties.is_empty(),
+                    BindingPatternKind::AssignmentPattern(assignment) => matches!(
+                        &assignment.left.kind,
+                        BindingPatternKind::ObjectPattern(nested) if !nested.properties.is_empty()
+                    ),
+                    _ => false,
+                },
+                PropertyValue::Expression(_) => false,
+            },
+            ObjectPatternProperty::RestElement(_) => false,
+        });
+        let can_have_trailing_comma =
+            !matches!(object.properties.last(), Some(ObjectPatternProperty::RestElement(_)));
+        self.format_object_items(object.span, items, should_break, can_have_trailing_comma)
+    }
+
+    /// `{ a, b }`, see `printObject`.
+    /// The items already include their trailing comments, with the end of the item.
+    pub(crate) fn format_object_items(
+        &mut self,
+        span: Span,
+        items: Vec<(Doc<'a>, u32)>,
+        should_break: bool,
+        can_have_trailing_comma: bool,
+    ) -> Doc<'a> {
+        if items.is_empty() {
+            return self.dangling_comments_in("{", span.end, "}");
+        }
+        let mut parts = vec![];
+        let len = items.len();
+        for (index, (doc, end)) in items.into_iter().enumerate() {
+            parts.push(doc);
+            if index + 1 < len {
+                parts.extend([Doc::Str(","), LINE]);
+                if is_next_line_empty(self.source_text, end as usize) {
+                    parts.push(HARDLINE);
+                }
+            }
+        }
+        if let Some(comments) = self.dangling_comments(span.end) {
+            parts.extend([LINE, comments]);
+        }
+        let trailing_comma = if can_have_trailing_comma && self.options.trailing_comma.es5() {
+            self.if_break(Doc::Str(","), Doc::Str(""))
+        } else {
+            Doc::Str("")
+        };
+        let line = if self.options.bracket_spacing { LINE } else { SOFTLINE };
+        let contents = self.indent(array!(self, line, self.array(parts)));
+        self.group_with(array!(self, "{", contents, trailing_comma, line, "}"), should_break, None)
+    }
+
+    /// `[a, b]`, see `printArrayItems`.
+    /// The items already include their trailing comments, see [`Formatter::format_object_items`].
+    fn format_array_items(
+        &mut self,
+        span: Span,
+        items: Vec<ArrayItem<'a>>,
+        should_break: bool,
+        concise: bool,
+        can_have_trailing_comma: bool,
+    ) -> Doc<'a> {
+        if items.is_empty() {
+            return self.dangling_comments_in("[", span.end, "]");
+        }
+        // A trailing hole needs its comma, `[a, ,]`
+        let needs_forced_trailing_comma = items.last().map_or(false, |item| item.doc.is_none());
+        let id = self.next_group_id();
+        let trailing_comma = if !can_have_trailing_comma {
+            Doc::Str("")
+        } else if needs_forced_trailing_comma {
+            Doc::Str(",")
+        } else if !self.options.trailing_comma.es5() {
+            Doc::Str("")
+        } else if concise {
+            self.if_group_break(Doc::Str(","), Doc::Str(""), Some(id))
+        } else {
+            self.if_break(Doc::Str(","), Doc::Str(""))
+        };
+        let len = items.len();
+        let mut parts = vec![];
+        for (index, item) in items.into_iter().enumerate() {
+            let doc = item.doc.unwrap_or(Doc::Str(""));
+            let is_last = index + 1 == len;
+            let next_line_empty = is_next_line_empty(self.source_text, item.end as usize);
+            if concise {
+                // Numbers are filled like words in a paragraph
+                parts.push(array!(self, doc, if is_last { trailing_comma } else { Doc::Str(",") }));
+                if !is_last {
+                    // A trailing line comment ends the line
+                    parts.push(if next_line_empty {
+                        array!(self, HARDLINE, HARDLINE)
+                    } else if doc.will_break() {
+                        HARDLINE
+                    } else {
+                        LINE
+                    });
+                }
+            } else {
+                parts.push(doc);
+                if !is_last {
+                    parts.extend([Doc::Str(","), LINE]);
+                    if next_line_empty {
+                        parts.push(SOFTLINE);
+                    }
+                }
+            }
+        }
+        let (items, trailing_comma) = if concise {
+            (self.fill(&parts), Doc::Str(""))
+        } else {
+            (self.array(parts), trailing_comma)
+        };
+        let comments = self.dangling_comments(span.end);
+        let comments = comments.map_or(Doc::Str(""), |comments| array!(self, LINE, comments));
+        let contents = self.indent(array!(self, SOFTLINE, items, trailing_comma, comments));
+        self.group_with(array!(self, "[", contents, SOFTLINE, "]"), should_break, Some(id))
+    }
+
+    /// Arrays of numbers, see `isConciselyPrintedArray`
+    pub(crate) fn is_concisely_printed_array(array: &ArrayExpression<'a>) -> bool {
+        array.elements.len() > 1
+            && array.elements.iter().all(|element| match element {
+                Some(Argument::Expression(Expression::NumberLiteral(_))) => true,
+                Some(Argument::Expression(Expression::UnaryExpression(unary))) => {
+                    matches!(
+                        unary.operator,
+                        UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation
+                    ) && matches!(unary.argument, Expression::NumberLiteral(_))
+                }
+                _ => false,
+            })
+    }
+
+    /// See `printPropertyKey`, quotes are removed when not needed.
+    /// Quoted keys of class properties are kept because TypeScript treats them differently.
+    pub(crate) fn format_property_key(
+        &mut self,
+        key: &'a PropertyKey<'a>,
+        computed: bool,
+        unquote: bool,
+    ) -> Doc<'a> {
+        match key {
+            _ if computed => {
+                let expr = match key {
+                    PropertyKey::Expression(expr) => Self::strip_parens(expr).format(self),
+                    _ => unreachable!(),
+                };
+                array!(self, "[", expr, "]")
+            }
+            PropertyKey::Identifier(ident) => Doc::Str(&ident.name),
+            PropertyKey::PrivateIdentifier(ident) => array!(self, "#", &ident.name),
+            PropertyKey::Expression(Expression::StringLiteral(lit))
+                if unquote && is_es5_identifier_name(&lit.value) =>
+            {
+                Doc::Str(&lit.value)
+            }
+            PropertyKey::Expression(expr) => expr.format(self),
+        }
+    }
+}
+
+/// ASCII approximation of `isEs5IdentifierName`
+fn is_es5_identifier_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().map_or(false, |c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
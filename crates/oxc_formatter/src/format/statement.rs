@@ -0,0 +1,393 @@
+//! Statements
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan};
+
+use super::{Context, Format};
+use crate::{
+    comment::is_next_line_empty,
+    doc::{array, Doc, HARDLINE, LINE, SOFTLINE},
+    Formatter,
+};
+
+impl<'a> Format<'a> for Statement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::BlockStatement(stmt) => stmt.format(f),
+            Self::BreakStatement(stmt) => {
+                let label =
+                    stmt.label.as_ref().map_or(Doc::Str(""), |label| array!(f, " ", &label.name));
+                array!(f, "break", label, f.semi())
+            }
+            Self::ContinueStatement(stmt) => {
+                let label =
+                    stmt.label.as_ref().map_or(Doc::Str(""), |label| array!(f, " ", &label.name));
+                array!(f, "continue", label, f.semi())
+            }
+            Self::DebuggerStatement(_) => array!(f, "debugger", f.semi()),
+            Self::DoWhileStatement(stmt) => stmt.format(f),
+            Self::EmptyStatement(_) => Doc::Str(""),
+            Self::ExpressionStatement(stmt) => stmt.format(f),
+            Self::ForInStatement(stmt) => stmt.format(f),
+            Self::ForOfStatement(stmt) => stmt.format(f),
+            Self::ForStatement(stmt) => stmt.format(f),
+            Self::IfStatement(stmt) => stmt.format(f),
+            Self::LabeledStatement(stmt) => {
+                if matches!(stmt.body, Statement::EmptyStatement(_)) {
+                    return array!(f, &stmt.label.name, ":;");
+                }
+                let body = f.format_statement(&stmt.body);
+                array!(f, &stmt.label.name, ": ", body)
+            }
+            Self::ReturnStatement(stmt) => {
+                f.format_return_or_throw("return", stmt.argument.as_ref())
+            }
+            Self::ThrowStatement(stmt) => f.format_return_or_throw("throw", Some(&stmt.argument)),
+            Self::SwitchStatement(stmt) => stmt.format(f),
+            Self::TryStatement(stmt) => stmt.format(f),
+            Self::WhileStatement(stmt) => {
+                let test = f.format_test(&stmt.test);
+                let body = f.adjust_clause(&stmt.body, false);
+                f.group(array!(f, "while (", test, ")", body))
+            }
+            Self::WithStatement(stmt) => {
+                let object = stmt.object.format(f);
+                let body = f.adjust_clause(&stmt.body, false);
+                f.group(array!(f, "with (", object, ")", body))
+            }
+            // the source skipped by the error recovery is kept as it is
+            Self::InvalidStatement(stmt) => {
+                Doc::Str(&f.source_text[stmt.span.start as usize..stmt.span.end as usize])
+            }
+            Self::ModuleDeclaration(decl) => decl.format(f),
+            Self::Declaration(decl) => decl.format(f),
+        }
+    }
+}
+
+impl<'a> Format<'a> for Declaration<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::VariableDeclaration(decl) => {
+                let decl = f.format_variable_declaration(decl, false);
+                array!(f, decl, f.semi())
+            }
+            Self::FunctionDeclaration(func) => func.format(f),
+            Self::ClassDeclaration(class) => class.format(f),
+            Self::TSTypeAliasDeclaration(decl) => decl.format(f),
+            Self::TSInterfaceDeclaration(decl) => decl.format(f),
+            Self::TSEnumDeclaration(decl) => decl.format(f),
+            Self::TSModuleDeclaration(decl) => decl.format(f),
+            Self::TSImportEqualsDeclaration(decl) => decl.format(f),
+            Self::FlowOpaqueType(decl) => decl.format(f),
+        }
+    }
+}
+
+impl<'a> Format<'a> for BlockStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        f.format_block(self.span, &[], &self.body)
+    }
+}
+
+impl<'a> Format<'a> for ExpressionStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let expression = f.format_expression(&self.expression, Context::ExpressionStatement);
+        // Without semicolons, a statement starting with `(`, `[`, `` ` ``, `+`, `-` or `/`
+        // would continue the previous statement
+        if !f.options.semi && Formatter::needs_asi_protection(&self.expression) {
+            return array!(f, ";", expression);
+        }
+        array!(f, expression, f.semi())
+    }
+}
+
+impl<'a> Format<'a> for DoWhileStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let clause = f.adjust_clause(&self.body, false);
+        let body = f.group(array!(f, "do", clause));
+        let separator = if matches!(self.body, Statement::BlockStatement(_)) {
+            Doc::Str(" ")
+        } else {
+            HARDLINE
+        };
+        let test = f.format_test(&self.test);
+        array!(f, body, separator, "while (", test, ")", f.semi())
+    }
+}
+
+impl<'a> Format<'a> for ForStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let body = f.adjust_clause(&self.body, false);
+        if self.init.is_none() && self.test.is_none() && self.update.is_none() {
+            return f.group(array!(f, "for (;;)", body));
+        }
+        let init = match &self.init {
+            Some(ForStatementInit::VariableDeclaration(decl)) => {
+                f.format_variable_declaration(decl, true)
+            }
+            Some(ForStatementInit::Expression(expr)) => {
+                f.format_expression(expr, Context::NoIndent)
+            }
+            None => Doc::Str(""),
+        };
+        let test = self.test.as_ref().map_or(Doc::Str(""), |test| {
+            let test = Formatter::strip_parens(test);
+            f.format_expression(test, Context::NoIndent)
+        });
+        let update = self.update.as_ref().map_or(Doc::Str(""), |update| {
+            let update = Formatter::strip_parens(update);
+            f.format_expression(update, Context::NoIndent)
+        });
+        let header = f.indent(array!(f, SOFTLINE, init, ";", LINE, test, ";", LINE, update));
+        let header = f.group(array!(f, header, SOFTLINE));
+        f.group(array!(f, "for (", header, ")", body))
+    }
+}
+
+impl<'a> Format<'a> for ForInStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let left = f.format_for_left(&self.left);
+        let right = self.right.format(f);
+        let body = f.adjust_clause(&self.body, false);
+        f.group(array!(f, "for (", left, " in ", right, ")", body))
+    }
+}
+
+impl<'a> Format<'a> for ForOfStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let left = f.format_for_left(&self.left);
+        let right = self.right.format(f);
+        let body = f.adjust_clause(&self.body, false);
+        let keyword = if self.r#await { "for await (" } else { "for (" };
+        f.group(array!(f, keyword, left, " of ", right, ")", body))
+    }
+}
+
+impl<'a> Format<'a> for IfStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let test = f.format_test(&self.test);
+        let consequent = f.adjust_clause(&self.consequent, false);
+        let opening = f.group(array!(f, "if (", test, ")", consequent));
+        let Some(alternate) = &self.alternate else {
+            return f.group(opening);
+        };
+        let mut parts = vec![opening];
+        let comments = f.trailing_comments(self.consequent.span().end);
+        let has_comments = comments.is_some();
+        parts.extend(comments);
+        let else_on_same_line =
+            matches!(self.consequent, Statement::BlockStatement(_)) && !has_comments;
+        parts.push(if else_on_same_line { Doc::Str(" ") } else { HARDLINE });
+        if let Some(comments) = f.dangling_comments(alternate.span().start) {
+            parts.extend([comments, HARDLINE]);
+        }
+        let is_else_if = matches!(alternate, Statement::IfStatement(_));
+        let alternate = f.adjust_clause(alternate, is_else_if);
+        parts.extend([Doc::Str("else"), f.group(alternate)]);
+        f.group(f.array(parts))
+    }
+}
+
+impl<'a> Format<'a> for SwitchStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let discriminant = Formatter::strip_parens(&self.discriminant);
+        let discriminant = f.format_expression(discriminant, Context::Test);
+        let discriminant = f.indent(array!(f, SOFTLINE, discriminant));
+        let header = f.group(array!(f, "switch (", discriminant, SOFTLINE, ")"));
+        let mut cases = vec![];
+        for (index, case) in self.cases.iter().enumerate() {
+            if index > 0 {
+                cases.push(HARDLINE);
+            }
+            cases.push(f.with_leading_comments(case.span.start, |f| case.format(f)));
+            cases.extend(f.trailing_comments(case.span.end));
+            if index + 1 < self.cases.len()
+                && is_next_line_empty(f.source_text, case.span.end as usize)
+            {
+                cases.push(HARDLINE);
+            }
+        }
+        if let Some(comments) = f.dangling_comments(self.span.end) {
+            if !cases.is_empty() {
+                cases.push(HARDLINE);
+            }
+            cases.push(comments);
+        }
+        if cases.is_empty() {
+            return array!(f, header, " {", HARDLINE, "}");
+        }
+        let cases = f.indent(array!(f, HARDLINE, f.array(cases)));
+        array!(f, header, " {", cases, HARDLINE, "}")
+    }
+}
+
+impl<'a> Format<'a> for SwitchCase<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        if let Some(test) = &self.test {
+            parts.extend([Doc::Str("case "), test.format(f), Doc::Str(":")]);
+        } else {
+            parts.push(Doc::Str("default:"));
+        }
+        let mut consequent =
+            self.consequent.iter().filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)));
+        let first = consequent.next();
+        let single_block =
+            matches!(first, Some(Statement::BlockStatement(_))) && consequent.next().is_none();
+        let end = self.consequent.last().map_or(self.span.end, |stmt| stmt.span().end);
+        if let Some(body) = f.format_statements(&[], &self.consequent, end) {
+            if single_block {
+                parts.extend([Doc::Str(" "), body]);
+            } else {
+                parts.push(f.indent(array!(f, HARDLINE, body)));
+            }
+        }
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TryStatement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![Doc::Str("try "), self.block.format(f)];
+        if let Some(handler) = &self.handler {
+            parts.extend([Doc::Str(" "), handler.format(f)]);
+        }
+        if let Some(finalizer) = &self.finalizer {
+            parts.extend([Doc::Str(" finally "), finalizer.format(f)]);
+        }
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for CatchClause<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let body = self.body.format(f);
+        if let Some(param) = &self.param {
+            let param = param.format(f);
+            array!(f, "catch (", param, ") ", body)
+        } else {
+            array!(f, "catch ", body)
+        }
+    }
+}
+
+impl<'a> Format<'a> for VariableDeclarator<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let id = f.format_binding_pattern_kind(&self.id.kind, false);
+        let id = if self.definite { array!(f, id, "!") } else { id };
+        let id = self.id.type_annotation.as_ref().map_or(id, |annotation| {
+            let annotation = annotation.format(f);
+            array!(f, id, annotation)
+        });
+        f.format_assignment(
+            id,
+            Doc::Str(" ="),
+            self.init.as_ref(),
+            super::expression::AssignmentLike::VariableDeclarator(self),
+        )
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Print a nested statement with its leading comments
+    pub(crate) fn format_statement(&mut self, stmt: &'a Statement<'a>) -> Doc<'a> {
+        self.with_leading_comments(stmt.span().start, |f| stmt.format(f))
+    }
+
+    /// The body of `if`, `for` and `while`, see `adjustClause`
+    fn adjust_clause(&mut self, stmt: &'a Statement<'a>, force_space: bool) -> Doc<'a> {
+        if matches!(stmt, Statement::EmptyStatement(_)) {
+            return Doc::Str(";");
+        }
+        let clause = self.format_statement(stmt);
+        if force_space || matches!(stmt, Statement::BlockStatement(_)) {
+            return array!(self, " ", clause);
+        }
+        self.indent(array!(self, LINE, clause))
+    }
+
+    /// The test of `if`, `while` and `do while`, which breaks inside the parentheses
+    fn format_test(&mut self, test: &'a Expression<'a>) -> Doc<'a> {
+        let test = Self::strip_parens(test);
+        let test = self.format_expression(test, Context::Test);
+        let test = self.indent(array!(self, SOFTLINE, test));
+        self.group(array!(self, test, SOFTLINE))
+    }
+
+    /// See `printReturnOrThrowArgument`
+    fn format_return_or_throw(
+        &mut self,
+        keyword: &'static str,
+        argument: Option<&'a Expression<'a>>,
+    ) -> Doc<'a> {
+        let Some(argument) = argument else {
+            return array!(self, keyword, self.semi());
+        };
+        // `return ( // comment` keeps the comment after the keyword without inserting a semicolon
+        if self.has_line_break_comments_before(argument.span().start) {
+            let argument = self.format_expression(argument, Context::NoIndent);
+            let argument = self.indent(array!(self, HARDLINE, argument));
+            return array!(self, keyword, " (", argument, HARDLINE, ")", self.semi());
+        }
+        let argument = Self::strip_parens(argument);
+        let doc = self.format_expression(argument, Context::NoIndent);
+        let doc = if Self::is_binaryish(argument)
+            || matches!(argument, Expression::SequenceExpression(_))
+        {
+            let open = self.if_break(Doc::Str("("), Doc::Str(""));
+            let close = self.if_break(Doc::Str(")"), Doc::Str(""));
+            let doc = self.indent(array!(self, SOFTLINE, doc));
+            self.group(array!(self, " ", open, doc, SOFTLINE, close))
+        } else {
+            array!(self, " ", doc)
+        };
+        array!(self, keyword, doc, self.semi())
+    }
+
+    /// `var a = 1, b = 2` without the semicolon, which is omitted in `for` loops
+    pub(crate) fn format_variable_declaration(
+        &mut self,
+        decl: &'a VariableDeclaration<'a>,
+        in_for_loop: bool,
+    ) -> Doc<'a> {
+        let has_value = decl.declarations.iter().any(|decl| decl.init.is_some());
+        let mut printed = vec![];
+        for declarator in &decl.declarations {
+            printed
+                .push(self.with_leading_comments(declarator.span.start, |f| declarator.format(f)));
+        }
+        let mut parts = vec![];
+        if decl.modifiers.contains(ModifierKind::Declare) {
+            parts.push(Doc::Str("declare "));
+        }
+        parts.push(Doc::Str(match decl.kind {
+            VariableDeclarationKind::Var => "var",
+            VariableDeclarationKind::Let => "let",
+            VariableDeclarationKind::Const => "const",
+            VariableDeclarationKind::Using => "using",
+            VariableDeclarationKind::AwaitUsing => "await using",
+        }));
+        let mut printed = printed.into_iter();
+        if let Some(first) = printed.next() {
+            let first = if decl.declarations.len() > 1 { self.indent(first) } else { first };
+            parts.extend([Doc::Str(" "), first]);
+        }
+        // Declarations with values are printed on their own lines
+        let separator = if has_value && !in_for_loop { HARDLINE } else { LINE };
+        let rest = printed.map(|doc| array!(self, ",", separator, doc)).collect::<Vec<_>>();
+        if !rest.is_empty() {
+            parts.push(self.indent(self.array(rest)));
+        }
+        self.group(self.array(parts))
+    }
+
+    fn format_for_left(&mut self, left: &'a ForStatementLeft<'a>) -> Doc<'a> {
+        match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                self.format_variable_declaration(decl, true)
+            }
+            ForStatementLeft::AssignmentTarget(target) => target.format(self),
+        }
+    }
+}
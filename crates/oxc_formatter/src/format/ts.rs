@@ -0,0 +1,750 @@
+//! TypeScript types and declarations
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, GetSpan};
+
+use super::{
+    expression::{AssignmentLike, Layout},
+    Context, Format,
+};
+use crate::{
+    comment::is_next_line_empty,
+    doc::{array, Doc, HARDLINE, LINE, SOFTLINE},
+    Formatter,
+};
+
+/// How tightly a type binds, types binding looser than their position are parenthesized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TypePrecedence {
+    /// `A extends B ? C : D`, `() => A`, `new () => A`
+    Lowest,
+    /// `A | B`
+    Union,
+    /// `A & B`
+    Intersection,
+    /// `keyof A`, `infer A`
+    Prefix,
+    /// `A[]`, `A[B]`, and all other types
+    Postfix,
+}
+
+impl TypePrecedence {
+    fn of(ty: &TSType<'_>) -> Self {
+        match ty {
+            TSType::TSConditionalType(_)
+            | TSType::TSFunctionType(_)
+            | TSType::TSConstructorType(_)
+            | TSType::TSTypePredicate(_) => Self::Lowest,
+            TSType::TSUnionType(_) => Self::Union,
+            TSType::TSIntersectionType(_) => Self::Intersection,
+            TSType::TSTypeOperatorType(_) | TSType::TSInferType(_) => Self::Prefix,
+            _ => Self::Postfix,
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSTypeAnnotation<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let type_annotation = self.type_annotation.format(f);
+        array!(f, ": ", type_annotation)
+    }
+}
+
+impl<'a> Format<'a> for TSType<'a> {
+    #[allow(clippy::too_many_lines)]
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::TSAnyKeyword(_) => Doc::Str("any"),
+            Self::TSBigIntKeyword(_) => Doc::Str("bigint"),
+            Self::TSBooleanKeyword(_) => Doc::Str("boolean"),
+            Self::TSNeverKeyword(_) => Doc::Str("never"),
+            Self::TSNullKeyword(_) => Doc::Str("null"),
+            Self::TSNumberKeyword(_) => Doc::Str("number"),
+            Self::TSObjectKeyword(_) => Doc::Str("object"),
+            Self::TSStringKeyword(_) => Doc::Str("string"),
+            Self::TSSymbolKeyword(_) => Doc::Str("symbol"),
+            Self::TSThisKeyword(_) => Doc::Str("this"),
+            Self::TSUndefinedKeyword(_) => Doc::Str("undefined"),
+            Self::TSUnknownKeyword(_) => Doc::Str("unknown"),
+            Self::TSVoidKeyword(_) => Doc::Str("void"),
+            Self::JSDocUnknownType(_) => Doc::Str("?"),
+            Self::JSDocNullableType(ty) => {
+                let type_annotation =
+                    f.format_type_with_precedence(&ty.type_annotation, TypePrecedence::Postfix);
+                if ty.postfix {
+                    array!(f, type_annotation, "?")
+                } else {
+                    array!(f, "?", type_annotation)
+                }
+            }
+            Self::TSArrayType(ty) => {
+                let element_type =
+                    f.format_type_with_precedence(&ty.element_type, TypePrecedence::Postfix);
+                array!(f, element_type, "[]")
+            }
+            Self::TSIndexedAccessType(ty) => {
+                let object_type =
+                    f.format_type_with_precedence(&ty.object_type, TypePrecedence::Postfix);
+                let index_type = ty.index_type.format(f);
+                array!(f, object_type, "[", index_type, "]")
+            }
+            Self::TSConditionalType(ty) => f.format_conditional_type(ty, false),
+            Self::TSFunctionType(ty) => {
+                let signature =
+                    f.format_signature(ty.type_parameters.as_deref(), &ty.params, None, false);
+                let return_type = ty.return_type.type_annotation.format(f);
+                f.group(array!(f, signature, " => ", return_type))
+            }
+            Self::TSConstructorType(ty) => {
+                let signature =
+                    f.format_signature(ty.type_parameters.as_deref(), &ty.params, None, false);
+                let return_type = ty.return_type.type_annotation.format(f);
+                let keyword = if ty.r#abstract { "abstract new " } else { "new " };
+                f.group(array!(f, keyword, signature, " => ", return_type))
+            }
+            Self::TSImportType(ty) => {
+                let mut parts = vec![];
+                if ty.is_type_of {
+                    parts.push(Doc::Str("typeof "));
+                }
+                parts.extend([Doc::Str("import("), ty.parameter.format(f), Doc::Str(")")]);
+                if let Some(qualifier) = &ty.qualifier {
+                    parts.extend([Doc::Str("."), qualifier.format(f)]);
+                }
+                parts.push(f.format_type_arguments(ty.type_parameters.as_deref()));
+                f.array(parts)
+            }
+            Self::TSInferType(ty) => {
+                let type_parameter = ty.type_parameter.format(f);
+                array!(f, "infer ", type_parameter)
+            }
+            Self::TSUnionType(ty) => f.format_union_type(ty),
+            Self::TSIntersectionType(ty) => {
+                let types = ty
+                    .types
+                    .iter()
+                    .map(|ty| f.format_type_with_precedence(ty, TypePrecedence::Prefix))
+                    .collect::<Vec<_>>();
+                let separator = array!(f, " &", LINE);
+                let types = f.join(separator, types);
+                f.group(f.indent(types))
+            }
+            Self::TSLiteralType(ty) => match &ty.literal {
+                TSLiteral::BooleanLiteral(lit) => {
+                    Doc::Str(if lit.value { "true" } else { "false" })
+                }
+                TSLiteral::NullLiteral(_) => Doc::Str("null"),
+                TSLiteral::NumberLiteral(lit) => lit.format(f),
+                TSLiteral::BigintLiteral(lit) => lit.format(f),
+                TSLiteral::RegExpLiteral(lit) => lit.format(f),
+                TSLiteral::StringLiteral(lit) => lit.format(f),
+                TSLiteral::TemplateLiteral(lit) => lit.format(f),
+                TSLiteral::UnaryExpression(expr) => {
+                    let argument = f.format_expression(&expr.argument, Context::Operand);
+                    array!(f, expr.operator.as_str(), argument)
+                }
+            },
+            Self::TSMappedType(ty) => f.format_mapped_type(ty),
+            Self::TSQualifiedName(name) => {
+                let left = name.left.format(f);
+                array!(f, left, ".", &name.right.name)
+            }
+            Self::TSTemplateLiteralType(ty) => {
+                let mut parts = vec![Doc::Str("`")];
+                for (index, quasi) in ty.quasis.iter().enumerate() {
+                    parts.push(f.literal_text(&quasi.value.raw));
+                    if let Some(ty) = ty.types.get(index) {
+                        parts.extend([Doc::Str("${"), ty.format(f), Doc::Str("}")]);
+                    }
+                }
+                parts.push(Doc::Str("`"));
+                f.array(parts)
+            }
+            Self::TSTupleType(ty) => {
+                if ty.element_types.is_empty() {
+                    return f.dangling_comments_in("[", ty.span.end, "]");
+                }
+                let types = ty.element_types.iter().map(|ty| ty.format(f)).collect::<Vec<_>>();
+                let has_rest =
+                    matches!(ty.element_types.last(), Some(TSTupleElement::TSRestType(_)));
+                let trailing_comma = if !has_rest && f.options.trailing_comma.all() {
+                    f.if_break(Doc::Str(","), Doc::Str(""))
+                } else {
+                    Doc::Str("")
+                };
+                let separator = array!(f, ",", LINE);
+                let types = f.join(separator, types);
+                let types = f.indent(array!(f, SOFTLINE, types));
+                f.group(array!(f, "[", types, trailing_comma, SOFTLINE, "]"))
+            }
+            Self::TSTypeLiteral(ty) => {
+                // Written on multiple lines, the literal stays expanded like an object
+                let should_break = ty.members.first().map_or(false, |member| {
+                    let between =
+                        &f.source_text[ty.span.start as usize..member.span().start as usize];
+                    between.contains('\n')
+                });
+                f.format_type_members(ty.span, &ty.members, should_break, ty.exact)
+            }
+            Self::TSTypeOperatorType(ty) => {
+                let operator = match ty.operator {
+                    TSTypeOperator::Keyof => "keyof ",
+                    TSTypeOperator::Unique => "unique ",
+                    TSTypeOperator::Readonly => "readonly ",
+                };
+                let type_annotation =
+                    f.format_type_with_precedence(&ty.type_annotation, TypePrecedence::Prefix);
+                array!(f, operator, type_annotation)
+            }
+            Self::TSTypePredicate(ty) => {
+                let mut parts = vec![];
+                if ty.asserts {
+                    parts.push(Doc::Str("asserts "));
+                }
+                parts.push(match &ty.parameter_name {
+                    TSTypePredicateName::Identifier(ident) => Doc::Str(&ident.name),
+                    TSTypePredicateName::This(_) => Doc::Str("this"),
+                });
+                if let Some(type_annotation) = &ty.type_annotation {
+                    parts.extend([Doc::Str(" is "), type_annotation.type_annotation.format(f)]);
+                }
+                f.array(parts)
+            }
+            Self::TSTypeQuery(ty) => {
+                let expr_name = ty.expr_name.format(f);
+                let type_arguments = f.format_type_arguments(ty.type_parameters.as_deref());
+                array!(f, "typeof ", expr_name, type_arguments)
+            }
+            Self::TSTypeReference(ty) => {
+                let type_name = ty.type_name.format(f);
+                let type_arguments = f.format_type_arguments(ty.type_parameters.as_deref());
+                array!(f, type_name, type_arguments)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSTupleElement<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::TSType(ty) => ty.format(f),
+            Self::TSOptionalType(ty) => {
+                let type_annotation =
+                    f.format_type_with_precedence(&ty.type_annotation, TypePrecedence::Postfix);
+                array!(f, type_annotation, "?")
+            }
+            Self::TSRestType(ty) => {
+                let type_annotation = ty.type_annotation.format(f);
+                array!(f, "...", type_annotation)
+            }
+            Self::TSNamedTupleMember(member) => {
+                // The parser does not record the rest of `[...rest: T[]]`
+                let span = member.span;
+                let rest =
+                    if f.source_text[span.start as usize..span.end as usize].starts_with("...") {
+                        "..."
+                    } else {
+                        ""
+                    };
+                let optional = if member.optional { "?" } else { "" };
+                let element_type = member.element_type.format(f);
+                array!(f, rest, &member.label.name, optional, ": ", element_type)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSTypeName<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::IdentifierName(ident) => Doc::Str(&ident.name),
+            Self::QualifiedName(name) => {
+                let left = name.left.format(f);
+                array!(f, left, ".", &name.right.name)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSTypeParameter<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        if self.r#in {
+            parts.push(Doc::Str("in "));
+        }
+        if self.out {
+            parts.push(Doc::Str("out "));
+        }
+        parts.push(Doc::Str(&self.name.name));
+        if let Some(constraint) = &self.constraint {
+            parts.extend([Doc::Str(" extends "), constraint.format(f)]);
+        }
+        if let Some(default) = &self.default {
+            parts.extend([Doc::Str(" = "), default.format(f)]);
+        }
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSSignature<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        match self {
+            Self::TSIndexSignature(signature) => signature.format(f),
+            Self::TSPropertySignature(signature) => {
+                let mut parts = vec![];
+                if signature.readonly {
+                    parts.push(Doc::Str("readonly "));
+                }
+                parts.push(f.format_property_key(&signature.key, signature.computed, true));
+                if signature.optional {
+                    parts.push(Doc::Str("?"));
+                }
+                if let Some(type_annotation) = &signature.type_annotation {
+                    parts.push(type_annotation.format(f));
+                }
+                f.array(parts)
+            }
+            Self::TSCallSignatureDeclaration(signature) => f.format_signature(
+                signature.type_parameters.as_deref(),
+                &signature.params,
+                signature.return_type.as_deref(),
+                false,
+            ),
+            Self::TSConstructSignatureDeclaration(signature) => {
+                let signature = f.format_signature(
+                    signature.type_parameters.as_deref(),
+                    &signature.params,
+                    signature.return_type.as_deref(),
+                    false,
+                );
+                array!(f, "new ", signature)
+            }
+            Self::TSMethodSignature(signature) => {
+                let kind = match signature.kind {
+                    TSMethodSignatureKind::Method => "",
+                    TSMethodSignatureKind::Get => "get ",
+                    TSMethodSignatureKind::Set => "set ",
+                };
+                let key = f.format_property_key(&signature.key, signature.computed, true);
+                let optional = if signature.optional { "?" } else { "" };
+                let rest = f.format_signature(
+                    signature.type_parameters.as_deref(),
+                    &signature.params,
+                    signature.return_type.as_deref(),
+                    false,
+                );
+                array!(f, kind, key, optional, rest)
+            }
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSIndexSignature<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let type_annotation = parameter.type_annotation.format(f);
+                array!(f, &parameter.name, type_annotation)
+            })
+            .collect::<Vec<_>>();
+        let parameters = f.join(Doc::Str(", "), parameters);
+        let type_annotation = self.type_annotation.format(f);
+        array!(f, "[", parameters, "]", type_annotation)
+    }
+}
+
+impl<'a> Format<'a> for TSTypeAliasDeclaration<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let declare = if self.modifiers.contains(ModifierKind::Declare) { "declare " } else { "" };
+        let type_parameters = f.format_type_parameters(self.type_parameters.as_deref());
+        let left = array!(f, declare, "type ", &self.id.name, type_parameters);
+        // type A =
+        //   | B
+        //   | C;
+        let layout = if matches!(self.type_annotation, TSType::TSUnionType(_)) {
+            Layout::BreakLhs
+        } else {
+            Layout::Fluid
+        };
+        let right = self.type_annotation.format(f);
+        let doc = f.format_assignment_layout(left, Doc::Str(" ="), right, layout);
+        array!(f, doc, f.semi())
+    }
+}
+
+impl<'a> Format<'a> for FlowOpaqueType<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let declare = if self.modifiers.contains(ModifierKind::Declare) { "declare " } else { "" };
+        let type_parameters = f.format_type_parameters(self.type_parameters.as_deref());
+        let mut parts = vec![array!(f, declare, "opaque type ", &self.id.name, type_parameters)];
+        if let Some(supertype) = &self.supertype {
+            parts.extend([Doc::Str(": "), supertype.format(f)]);
+        }
+        if let Some(impl_type) = &self.impl_type {
+            parts.extend([Doc::Str(" = "), impl_type.format(f)]);
+        }
+        parts.push(Doc::Str(f.semi()));
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSInterfaceDeclaration<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        if self.modifiers.contains(ModifierKind::Declare) {
+            parts.push(Doc::Str("declare "));
+        }
+        parts.extend([Doc::Str("interface "), Doc::Str(&self.id.name)]);
+        parts.push(f.format_type_parameters(self.type_parameters.as_deref()));
+        if let Some(extends) = &self.extends
+            && !extends.is_empty()
+        {
+            let extends = extends
+                .iter()
+                .map(|heritage| {
+                    let expression = f.format_expression(&heritage.expression, Context::Operand);
+                    let type_arguments =
+                        f.format_type_arguments(heritage.type_parameters.as_deref());
+                    array!(f, expression, type_arguments)
+                })
+                .collect::<Vec<_>>();
+            let separator = array!(f, ",", LINE);
+            let extends = f.indent(f.join(separator, extends));
+            let extends = f.group(f.indent(array!(f, LINE, "extends ", extends)));
+            parts.push(extends);
+        }
+        parts.push(Doc::Str(" "));
+        parts.push(f.format_type_members(self.body.span, &self.body.body, true, false));
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSEnumDeclaration<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        if self.modifiers.contains(ModifierKind::Declare) {
+            parts.push(Doc::Str("declare "));
+        }
+        if self.modifiers.contains(ModifierKind::Const) {
+            parts.push(Doc::Str("const "));
+        }
+        parts.extend([Doc::Str("enum "), Doc::Str(&self.id.name), Doc::Str(" ")]);
+        let members = self
+            .members
+            .iter()
+            .map(|member| {
+                let doc = f.with_leading_comments(member.span.start, |f| {
+                    let id = match &member.id {
+                        TSEnumMemberName::Identifier(ident) => Doc::Str(&ident.name),
+                        TSEnumMemberName::StringLiteral(lit) => lit.format(f),
+                        TSEnumMemberName::NumberLiteral(lit) => lit.format(f),
+                        TSEnumMemberName::ComputedPropertyName(expr) => {
+                            let expr = expr.format(f);
+                            array!(f, "[", expr, "]")
+                        }
+                    };
+                    f.format_assignment(
+                        id,
+                        Doc::Str(" ="),
+                        member.initializer.as_ref(),
+                        AssignmentLike::ClassProperty,
+                    )
+                });
+                (f.with_trailing_comments(member.span.end, doc), member.span.end)
+            })
+            .collect::<Vec<_>>();
+        parts.push(f.format_object_items(self.span, members, true, true));
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSModuleDeclaration<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        if self.modifiers.contains(ModifierKind::Declare) {
+            parts.push(Doc::Str("declare "));
+        }
+        // `declare global {}` has no keyword, `module A {}` is kept as written
+        let id_start = match &self.id {
+            TSModuleDeclarationName::Identifier(ident) => ident.span.start,
+            TSModuleDeclarationName::StringLiteral(lit) => lit.span.start,
+        };
+        let head = &f.source_text[self.span.start as usize..id_start as usize];
+        if head.trim_end().ends_with("namespace") {
+            parts.push(Doc::Str("namespace "));
+        } else if head.trim_end().ends_with("module") {
+            parts.push(Doc::Str("module "));
+        }
+        let mut decl = self;
+        loop {
+            parts.push(match &decl.id {
+                TSModuleDeclarationName::Identifier(ident) => Doc::Str(&ident.name),
+                TSModuleDeclarationName::StringLiteral(lit) => lit.format(f),
+            });
+            match &decl.body {
+                // namespace A.B {}
+                TSModuleDeclarationBody::TSModuleDeclaration(body) => {
+                    parts.push(Doc::Str("."));
+                    decl = body;
+                }
+                TSModuleDeclarationBody::TSModuleBlock(block) => {
+                    parts.push(Doc::Str(" "));
+                    parts.push(f.format_block(block.span, &[], &block.body));
+                    break;
+                }
+            }
+        }
+        f.array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSImportEqualsDeclaration<'a> {
+    fn format(&'a self, f: &mut Formatter<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        if self.is_export {
+            parts.push(Doc::Str("export "));
+        }
+        parts.push(Doc::Str("import "));
+        if self.import_kind == ImportOrExportKind::Type {
+            parts.push(Doc::Str("type "));
+        }
+        parts.extend([Doc::Str(&self.id.name), Doc::Str(" = ")]);
+        parts.push(match &*self.module_reference {
+            TSModuleReference::TypeName(name) => name.format(f),
+            TSModuleReference::ExternalModuleReference(reference) => {
+                let expression = reference.expression.format(f);
+                array!(f, "require(", expression, ")")
+            }
+        });
+        parts.push(Doc::Str(f.semi()));
+        f.array(parts)
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// `<A, B>` of type references, calls and heritage clauses, see `printTypeParameters`
+    pub(crate) fn format_type_arguments(
+        &mut self,
+        type_arguments: Option<&'a TSTypeParameterInstantiation<'a>>,
+    ) -> Doc<'a> {
+        let Some(type_arguments) = type_arguments else {
+            return Doc::Str("");
+        };
+        let params =
+            type_arguments.params.iter().map(|param| param.format(self)).collect::<Vec<_>>();
+        // Map<string, { a: A; b: B }>
+        let should_inline = params.len() == 1
+            && matches!(
+                type_arguments.params[0],
+                TSType::TSTypeLiteral(_) | TSType::TSMappedType(_)
+            )
+            || type_arguments.params.iter().all(Self::is_keyword_type);
+        self.format_type_list(params, should_inline, false)
+    }
+
+    /// `<T extends U = V>` of declarations, see `printTypeParameters`
+    pub(crate) fn format_type_parameters(
+        &mut self,
+        type_parameters: Option<&'a TSTypeParameterDeclaration<'a>>,
+    ) -> Doc<'a> {
+        let Some(type_parameters) = type_parameters else {
+            return Doc::Str("");
+        };
+        let params =
+            type_parameters.params.iter().map(|param| param.format(self)).collect::<Vec<_>>();
+        let should_inline = type_parameters.params.len() == 1
+            && type_parameters.params[0].constraint.is_none()
+            && type_parameters.params[0].default.is_none();
+        self.format_type_list(params, should_inline, true)
+    }
+
+    fn format_type_list(
+        &mut self,
+        params: Vec<Doc<'a>>,
+        should_inline: bool,
+        can_have_trailing_comma: bool,
+    ) -> Doc<'a> {
+        if params.is_empty() {
+            return Doc::Str("<>");
+        }
+        if should_inline {
+            let params = self.join(Doc::Str(", "), params);
+            return array!(self, "<", params, ">");
+        }
+        let trailing_comma = if can_have_trailing_comma && self.options.trailing_comma.all() {
+            self.if_break(Doc::Str(","), Doc::Str(""))
+        } else {
+            Doc::Str("")
+        };
+        let separator = array!(self, ",", LINE);
+        let params = self.join(separator, params);
+        let params = self.indent(array!(self, SOFTLINE, params));
+        self.group(array!(self, "<", params, trailing_comma, SOFTLINE, ">"))
+    }
+
+    fn is_keyword_type(ty: &TSType<'a>) -> bool {
+        matches!(
+            ty,
+            TSType::TSAnyKeyword(_)
+                | TSType::TSBigIntKeyword(_)
+                | TSType::TSBooleanKeyword(_)
+                | TSType::TSNeverKeyword(_)
+                | TSType::TSNullKeyword(_)
+                | TSType::TSNumberKeyword(_)
+                | TSType::TSObjectKeyword(_)
+                | TSType::TSStringKeyword(_)
+                | TSType::TSSymbolKeyword(_)
+                | TSType::TSThisKeyword(_)
+                | TSType::TSUndefinedKeyword(_)
+                | TSType::TSUnknownKeyword(_)
+                | TSType::TSVoidKeyword(_)
+        )
+    }
+
+    /// Print `ty`, wrapped in parentheses when it binds looser than `precedence`
+    fn format_type_with_precedence(
+        &mut self,
+        ty: &'a TSType<'a>,
+        precedence: TypePrecedence,
+    ) -> Doc<'a> {
+        let doc = ty.format(self);
+        if TypePrecedence::of(ty) < precedence {
+            array!(self, "(", doc, ")")
+        } else {
+            doc
+        }
+    }
+
+    /// See `printUnionType`
+    fn format_union_type(&mut self, ty: &'a TSUnionType<'a>) -> Doc<'a> {
+        let types = ty
+            .types
+            .iter()
+            .map(|ty| {
+                self.with_leading_comments(ty.span().start, |f| {
+                    f.format_type_with_precedence(ty, TypePrecedence::Intersection)
+                })
+            })
+            .collect::<Vec<_>>();
+        // A | { a: A }
+        let should_hug = ty.types.len() == 2
+            && ty.types.iter().filter(|ty| matches!(ty, TSType::TSTypeLiteral(_))).count() == 1
+            && ty.types.iter().any(|ty| {
+                matches!(
+                    ty,
+                    TSType::TSNullKeyword(_)
+                        | TSType::TSUndefinedKeyword(_)
+                        | TSType::TSVoidKeyword(_)
+                )
+            });
+        if should_hug {
+            return self.join(Doc::Str(" | "), types);
+        }
+        let separator = array!(self, LINE, "| ");
+        let types = self.join(separator, types);
+        let leading = self.if_break(Doc::Str("| "), Doc::Str(""));
+        let leading = array!(self, SOFTLINE, leading);
+        self.group(self.indent(array!(self, leading, types)))
+    }
+
+    /// See `printTernary`, a chain of conditional types in the false branches is always broken
+    fn format_conditional_type(
+        &mut self,
+        ty: &'a TSConditionalType<'a>,
+        in_chain: bool,
+    ) -> Doc<'a> {
+        let check_type = self.format_type_with_precedence(&ty.check_type, TypePrecedence::Union);
+        let extends_type =
+            self.format_type_with_precedence(&ty.extends_type, TypePrecedence::Union);
+        let true_type = ty.true_type.format(self);
+        let should_break = in_chain || matches!(ty.false_type, TSType::TSConditionalType(_));
+        let false_type = match &ty.false_type {
+            TSType::TSConditionalType(false_type) => self.format_conditional_type(false_type, true),
+            false_type => false_type.format(self),
+        };
+        let consequent = self.indent(array!(self, LINE, "? ", true_type));
+        let alternate = self.indent(array!(self, LINE, ": ", false_type));
+        let doc = array!(self, check_type, " extends ", extends_type, consequent, alternate);
+        self.group_with(doc, should_break, None)
+    }
+
+    /// `{ readonly [K in T as N]?: V }`, see `printMappedType`
+    fn format_mapped_type(&mut self, ty: &'a TSMappedType<'a>) -> Doc<'a> {
+        let mut parts = vec![];
+        parts.push(Doc::Str(match ty.readonly {
+            TSMappedTypeModifierOperator::True => "readonly ",
+            TSMappedTypeModifierOperator::Plus => "+readonly ",
+            TSMappedTypeModifierOperator::Minus => "-readonly ",
+            TSMappedTypeModifierOperator::None => "",
+        }));
+        parts.extend([Doc::Str("["), Doc::Str(&ty.type_parameter.name.name), Doc::Str(" in ")]);
+        if let Some(constraint) = &ty.type_parameter.constraint {
+            parts.push(constraint.format(self));
+        }
+        if let Some(name_type) = &ty.name_type {
+            parts.extend([Doc::Str(" as "), name_type.format(self)]);
+        }
+        parts.push(Doc::Str("]"));
+        parts.push(Doc::Str(match ty.optional {
+            TSMappedTypeModifierOperator::True => "?",
+            TSMappedTypeModifierOperator::Plus => "+?",
+            TSMappedTypeModifierOperator::Minus => "-?",
+            TSMappedTypeModifierOperator::None => "",
+        }));
+        parts.extend([Doc::Str(": "), ty.type_annotation.format(self)]);
+        parts.push(self.if_break(Doc::Str(self.semi()), Doc::Str("")));
+        let should_break = has_newline_after_brace(self.source_text, ty.span.start);
+        let line = if self.options.bracket_spacing { LINE } else { SOFTLINE };
+        let contents = self.indent(array!(self, line, self.array(parts)));
+        self.group_with(array!(self, "{", contents, line, "}"), should_break, None)
+    }
+
+    /// Members of interfaces and type literals, separated by `;`
+    fn format_type_members(
+        &mut self,
+        span: oxc_ast::Span,
+        members: &'a [TSSignature<'a>],
+        should_break: bool,
+        exact: bool,
+    ) -> Doc<'a> {
+        // Flow exact object type `{| a: T |}`
+        let (open, close) = if exact { ("{|", "|}") } else { ("{", "}") };
+        if members.is_empty() {
+            return self.dangling_comments_in(open, span.end, close);
+        }
+        let separator = if self.options.semi { ";" } else { "" };
+        let mut parts = vec![];
+        for (index, member) in members.iter().enumerate() {
+            let member_span = member.span();
+            parts.push(self.with_leading_comments(member_span.start, |f| member.format(f)));
+            let is_last = index + 1 == members.len();
+            if is_last {
+                parts.push(self.if_break(Doc::Str(separator), Doc::Str("")));
+            } else {
+                parts.push(self.if_break(Doc::Str(separator), Doc::Str(";")));
+            }
+            parts.extend(self.trailing_comments(member_span.end));
+            if !is_last {
+                parts.push(LINE);
+                if is_next_line_empty(self.source_text, member_span.end as usize) {
+                    parts.push(HARDLINE);
+                }
+            }
+        }
+        if let Some(comments) = self.dangling_comments(span.end) {
+            parts.extend([LINE, comments]);
+        }
+        let line = if self.options.bracket_spacing { LINE } else { SOFTLINE };
+        let contents = self.indent(array!(self, line, self.array(parts)));
+        self.group_with(array!(self, open, contents, line, close), should_break, None)
+    }
+}
+
+/// `{` followed by a line break, the object-like type was written expanded
+fn has_newline_after_brace(source_text: &str, start: u32) -> bool {
+    source_text[start as usize..]
+        .split_once('{')
+        .map_or(false, |(_, rest)| rest.trim_start_matches([' ', '\t']).starts_with(['\n', '\r']))
+}
@@ -0,0 +1,241 @@
+//! Opinionated code formatter with Prettier compatible output
+//!
+//! The AST is converted into a [document](doc::Doc) made of groups, indentations and lines,
+//! which is then printed by fitting the groups within the print width,
+//! see [Prettier](https://prettier.io) and Philip Wadler's "A prettier printer".
+
+#![feature(let_chains)]
+
+mod comment;
+mod doc;
+mod format;
+mod printer;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::Program, Trivias};
+
+use crate::{
+    comment::{collect_comments, Comment},
+    format::Format,
+    printer::Printer,
+};
+
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct FormatterOptions {
+    /// The line width the formatter will try to fit the code in
+    pub print_width: usize,
+    /// Number of spaces per indentation level
+    pub tab_width: usize,
+    /// Indent with tabs instead of spaces
+    pub use_tabs: bool,
+    /// Print semicolons at the end of statements,
+    /// otherwise only where they are required by automatic semicolon insertion.
+    pub semi: bool,
+    /// Use single quotes instead of double quotes
+    pub single_quote: bool,
+    pub trailing_comma: TrailingComma,
+    /// Print spaces between brackets in object literals, e.g. `{ foo: bar }`
+    pub bracket_spacing: bool,
+}
+
+impl Default for FormatterOptions {
+    fn default() -> Self {
+        Self {
+            print_width: 80,
+            tab_width: 2,
+            use_tabs: false,
+            semi: true,
+            single_quote: false,
+            trailing_comma: TrailingComma::All,
+            bracket_spacing: true,
+        }
+    }
+}
+
+/// Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailingComma {
+    /// Trailing commas wherever possible, including function parameters and calls
+    All,
+    /// Trailing commas where valid in ES5 (objects, arrays, etc.)
+    Es5,
+    None,
+}
+
+impl TrailingComma {
+    /// Trailing commas in arrays, objects, imports and exports
+    fn es5(self) -> bool {
+        matches!(self, Self::All | Self::Es5)
+    }
+
+    /// Trailing commas in function parameters, arguments and type parameters
+    fn all(self) -> bool {
+        matches!(self, Self::All)
+    }
+}
+
+pub struct Formatter<'a> {
+    allocator: &'a Allocator,
+
+    source_text: &'a str,
+
+    options: FormatterOptions,
+
+    /// Comments in source order
+    comments: Vec<Comment<'a>>,
+
+    /// Index of the first comment not printed yet
+    next_comment: usize,
+
+    next_group_id: usize,
+}
+
+impl<'a> Formatter<'a> {
+    /// The documents are allocated in `allocator`, it does not need to be the one used for parsing.
+    pub fn new(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        trivias: &Trivias,
+        options: FormatterOptions,
+    ) -> Self {
+        Self {
+            allocator,
+            source_text,
+            options,
+            comments: collect_comments(source_text, trivias),
+            next_comment: 0,
+            next_group_id: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn build(mut self, program: &'a Program<'a>) -> String {
+        let doc = program.format(&mut self);
+        Printer::new(doc, &self.options, self.next_group_id).build()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use crate::{Formatter, FormatterOptions};
+
+    fn format(source_text: &str, path: &str, options: FormatterOptions) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(Path::new(path)).unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}");
+        let program = allocator.alloc(ret.program);
+        Formatter::new(&allocator, source_text, &ret.trivias, options).build(program)
+    }
+
+    fn test_with(source_text: &str, expected: &str, path: &str, options: FormatterOptions) {
+        let formatted = format(source_text, path, options);
+        assert_eq!(formatted, expected);
+        assert_eq!(format(&formatted, path, options), formatted, "not idempotent");
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        test_with(source_text, expected, "test.js", FormatterOptions::default());
+    }
+
+    #[test]
+    fn statements() {
+        test("", "");
+        test("let a=1,b", "let a = 1,\n  b;\n");
+        test("if(a)b();else{c()}", "if (a) b();\nelse {\n  c();\n}\n");
+        test("for(let i=0;i<n;i++){}", "for (let i = 0; i < n; i++) {}\n");
+        test("a()\n\n\n\nb()\nc()", "a();\n\nb();\nc();\n");
+        test("function f(a,b=1,...c){return a}", "function f(a, b = 1, ...c) {\n  return a;\n}\n");
+    }
+
+    #[test]
+    fn line_width() {
+        test(
+            "const result = someFunction(argumentNumberOne, argumentNumberTwo, argumentNumberThree);",
+            "const result = someFunction(\n  argumentNumberOne,\n  argumentNumberTwo,\n  argumentNumberThree,\n);\n",
+        );
+        test(
+            "const object = {alpha: 'aaaaaaaaaaaaaaa', beta: 'bbbbbbbbbbbbbbbbbbb', gamma: 'ccccccccccc'}",
+            "const object = {\n  alpha: \"aaaaaaaaaaaaaaa\",\n  beta: \"bbbbbbbbbbbbbbbbbbb\",\n  gamma: \"ccccccccccc\",\n};\n",
+        );
+        test("const object = {\n  a: 1 }", "const object = {\n  a: 1,\n};\n");
+        test("const object = { a: 1,\n}", "const object = { a: 1 };\n");
+        test(
+            "promise.then(result => console.log(result)).catch(error => handleErrorProperly(error))",
+            "promise\n  .then((result) => console.log(result))\n  .catch((error) => handleErrorProperly(error));\n",
+        );
+    }
+
+    #[test]
+    fn options() {
+        let options =
+            FormatterOptions { semi: false, single_quote: true, ..FormatterOptions::default() };
+        test_with("let a = \"b\";\n[c].d()", "let a = 'b'\n;[c].d()\n", "test.js", options);
+        let options = FormatterOptions { use_tabs: true, ..FormatterOptions::default() };
+        test_with("if (a) { b() }", "if (a) {\n\tb();\n}\n", "test.js", options);
+    }
+
+    #[test]
+    fn comments() {
+        test("// a\nlet b; // c\n/* d */ e();", "// a\nlet b; // c\n/* d */ e();\n");
+        test("function f() {\n  // empty\n}", "function f() {\n  // empty\n}\n");
+        test("call(a, // first\n b)", "call(\n  a, // first\n  b,\n);\n");
+    }
+
+    #[test]
+    fn typescript() {
+        let test = |source_text, expected| {
+            test_with(source_text, expected, "test.ts", FormatterOptions::default());
+        };
+        test("let a:string|number[]", "let a: string | number[];\n");
+        test(
+            "interface A<T> extends B { a?: T, m(): void }",
+            "interface A<T> extends B {\n  a?: T;\n  m(): void;\n}\n",
+        );
+        test("enum E { A = 1, B }", "enum E {\n  A = 1,\n  B,\n}\n");
+        test(
+            "type A = 'aaaaaaaaaaaaaaaaaa' | 'bbbbbbbbbbbbbbbbbbbbbbb' | 'ccccccccccccccccccccccc'",
+            "type A =\n  | \"aaaaaaaaaaaaaaaaaa\"\n  | \"bbbbbbbbbbbbbbbbbbbbbbb\"\n  | \"ccccccccccccccccccccccc\";\n",
+        );
+    }
+
+    #[test]
+    fn decorators() {
+        test(
+            "@a\nexport class A {\n  @b accessor c = 1;\n}",
+            "@a\nexport class A {\n  @b accessor c = 1;\n}\n",
+        );
+        test("export @a class B {}", "export @a class B {}\n");
+        test("@a export default class {}", "@a export default class {}\n");
+    }
+
+    #[test]
+    fn import_attributes() {
+        test(
+            "import a from 'a' with {type:'json'}",
+            "import a from \"a\" with { type: \"json\" };\n",
+        );
+        test("import 'b' assert {type:'css'}", "import \"b\" assert { type: \"css\" };\n");
+        test("export {c} from 'c' with {}", "export { c } from \"c\" with {};\n");
+    }
+
+    #[test]
+    fn jsx() {
+        let test = |source_text, expected| {
+            test_with(source_text, expected, "test.jsx", FormatterOptions::default());
+        };
+        test("<div className='a'>hello</div>", "<div className=\"a\">hello</div>;\n");
+        test("<br />", "<br />;\n");
+        test(
+            "const a = <div><span>aaaaaaaaaaaaaaaaaaa</span><span>bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb</span></div>",
+            "const a = (\n  <div>\n    <span>aaaaaaaaaaaaaaaaaaa</span>\n    <span>bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb</span>\n  </div>\n);\n",
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Document printer
+//!
+//! Lays out a [Doc] within the print width,
+//! ported from [Prettier](https://github.com/prettier/prettier/blob/main/src/document/printer.js).
+
+use crate::{
+    doc::{Doc, GroupId, Line},
+    FormatterOptions,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Break,
+    Flat,
+}
+
+#[derive(Clone, Copy)]
+struct Command<'a> {
+    indent: usize,
+    mode: Mode,
+    doc: Doc<'a>,
+}
+
+impl<'a> Command<'a> {
+    fn new(indent: usize, mode: Mode, doc: Doc<'a>) -> Self {
+        Self { indent, mode, doc }
+    }
+}
+
+pub struct Printer<'a, 'b> {
+    options: &'b FormatterOptions,
+    out: String,
+    /// Width of the current line
+    pos: usize,
+    cmds: Vec<Command<'a>>,
+    line_suffixes: Vec<Command<'a>>,
+    /// The mode each group with an id was printed in
+    group_modes: Vec<Option<Mode>>,
+    should_remeasure: bool,
+}
+
+impl<'a, 'b> Printer<'a, 'b> {
+    pub fn new(doc: Doc<'a>, options: &'b FormatterOptions, group_count: usize) -> Self {
+        Self {
+            options,
+            out: String::new(),
+            pos: 0,
+            cmds: vec![Command::new(0, Mode::Break, doc)],
+            line_suffixes: vec![],
+            group_modes: vec![None; group_count],
+            should_remeasure: false,
+        }
+    }
+
+    pub fn build(mut self) -> String {
+        while let Some(Command { indent, mode, doc }) = self.cmds.pop() {
+            match doc {
+                Doc::Str(s) => {
+                    self.out.push_str(s);
+                    self.pos += width(s);
+                }
+                Doc::Array(docs) => {
+                    self.cmds.extend(docs.iter().rev().map(|doc| Command::new(indent, mode, *doc)));
+                }
+                Doc::Indent(doc) => self.cmds.push(Command::new(indent + 1, mode, *doc)),
+                Doc::Group(group) => {
+                    let mode = match mode {
+                        Mode::Flat if !self.should_remeasure => {
+                            let mode = if group.should_break { Mode::Break } else { Mode::Flat };
+                            self.cmds.push(Command::new(indent, mode, group.contents));
+                            mode
+                        }
+                        _ => {
+                            self.should_remeasure = false;
+                            let next = Command::new(indent, Mode::Flat, group.contents);
+                            let remaining = self.remaining_width();
+                            if !group.should_break && self.fits(next, remaining, false) {
+                                self.cmds.push(next);
+                            } else if let Some(states) = group.expanded_states {
+                                let most_expanded = states[states.len() - 1];
+                                if group.should_break {
+                                    self.cmds.push(Command::new(
+                                        indent,
+                                        Mode::Break,
+                                        most_expanded,
+                                    ));
+                                } else {
+                                    let state = states[1..].iter().find_map(|state| {
+                                        let cmd = Command::new(indent, Mode::Flat, *state);
+                                        self.fits(cmd, remaining, false).then_some(cmd)
+                                    });
+                                    self.cmds.push(state.unwrap_or_else(|| {
+                                        Command::new(indent, Mode::Break, most_expanded)
+                                    }));
+                                }
+                            } else {
+                                self.cmds.push(Command::new(indent, Mode::Break, group.contents));
+                            }
+                            self.cmds[self.cmds.len() - 1].mode
+                        }
+                    };
+                    if let Some(GroupId(id)) = group.id {
+                        self.group_modes[id] = Some(mode);
+                    }
+                }
+                Doc::Fill(parts) => self.print_fill(indent, mode, parts),
+                Doc::IfBreak(if_break) => {
+                    let group_mode = if_break.group_id.map_or(mode, |id| self.group_mode(id));
+                    let contents = match group_mode {
+                        Mode::Break => if_break.break_contents,
+                        Mode::Flat => if_break.flat_contents,
+                    };
+                    self.cmds.push(Command::new(indent, mode, contents));
+                }
+                Doc::IndentIfBreak(doc, group_id) => {
+                    let indent = match self.group_mode(group_id) {
+                        Mode::Break => indent + 1,
+                        Mode::Flat => indent,
+                    };
+                    self.cmds.push(Command::new(indent, mode, *doc));
+                }
+                Doc::LineSuffix(doc) => self.line_suffixes.push(Command::new(indent, mode, *doc)),
+                Doc::Line(line) => self.print_line(indent, mode, line),
+                Doc::BreakParent => {}
+            }
+            if self.cmds.is_empty() && !self.line_suffixes.is_empty() {
+                self.flush_line_suffixes();
+            }
+        }
+        self.out
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn remaining_width(&self) -> isize {
+        self.options.print_width as isize - self.pos as isize
+    }
+
+    fn group_mode(&self, id: GroupId) -> Mode {
+        self.group_modes[id.0].unwrap_or(Mode::Flat)
+    }
+
+    fn flush_line_suffixes(&mut self) {
+        self.cmds.extend(self.line_suffixes.drain(..).rev());
+    }
+
+    fn print_line(&mut self, indent: usize, mode: Mode, line: Line) {
+        if mode == Mode::Flat {
+            match line {
+                Line::Normal => {
+                    self.out.push(' ');
+                    self.pos += 1;
+                    return;
+                }
+                Line::Soft => return,
+                Line::Hard | Line::Literal => self.should_remeasure = true,
+            }
+        }
+        if !self.line_suffixes.is_empty() {
+            self.cmds.push(Command::new(indent, mode, Doc::Line(line)));
+            self.flush_line_suffixes();
+            return;
+        }
+        if line == Line::Literal {
+            self.out.push('\n');
+            self.pos = 0;
+        } else {
+            self.trim();
+            self.out.push('\n');
+            if self.options.use_tabs {
+                self.out.extend(std::iter::repeat('\t').take(indent));
+                self.pos = indent * self.options.tab_width;
+            } else {
+                let width = indent * self.options.tab_width;
+                self.out.extend(std::iter::repeat(' ').take(width));
+                self.pos = width;
+            }
+        }
+    }
+
+    /// Remove the trailing whitespace of the current line
+    fn trim(&mut self) {
+        let trimmed = self.out.trim_end_matches([' ', '\t']).len();
+        self.out.truncate(trimmed);
+    }
+
+    fn print_fill(&mut self, indent: usize, mode: Mode, parts: &'a [Doc<'a>]) {
+        let remaining = self.remaining_width();
+        let Some(&content) = parts.first() else { return };
+        let content_flat = Command::new(indent, Mode::Flat, content);
+        let content_break = Command::new(indent, Mode::Break, content);
+        let content_fits = self.fits_alone(content_flat, remaining);
+        let Some(&whitespace) = parts.get(1) else {
+            self.cmds.push(if content_fits { content_flat } else { content_break });
+            return;
+        };
+        let whitespace_flat = Command::new(indent, Mode::Flat, whitespace);
+        let whitespace_break = Command::new(indent, Mode::Break, whitespace);
+        if parts.len() == 2 {
+            if content_fits {
+                self.cmds.extend([whitespace_flat, content_flat]);
+            } else {
+                self.cmds.extend([whitespace_break, content_break]);
+            }
+            return;
+        }
+        let remaining_cmd = Command::new(indent, mode, Doc::Fill(&parts[2..]));
+        let first_and_second = Command::new(indent, Mode::Flat, Doc::Array(&parts[0..3]));
+        if self.fits_alone(first_and_second, remaining) {
+            self.cmds.extend([remaining_cmd, whitespace_flat, content_flat]);
+        } else if content_fits {
+            self.cmds.extend([remaining_cmd, whitespace_break, content_flat]);
+        } else {
+            self.cmds.extend([remaining_cmd, whitespace_break, content_break]);
+        }
+    }
+
+    /// Whether `next` fits in the flat mode, without the rest commands.
+    fn fits_alone(&self, next: Command<'a>, width: isize) -> bool {
+        self.fits_impl(next, &[], width, true)
+    }
+
+    /// Whether `next` and the rest commands up to the first possible line break fit in `width`.
+    fn fits(&self, next: Command<'a>, width: isize, must_be_flat: bool) -> bool {
+        self.fits_impl(next, &self.cmds, width, must_be_flat)
+    }
+
+    fn fits_impl(
+        &self,
+        next: Command<'a>,
+        rest_commands: &[Command<'a>],
+        mut width: isize,
+        must_be_flat: bool,
+    ) -> bool {
+        let mut rest_idx = rest_commands.len();
+        let mut cmds = vec![(next.mode, next.doc)];
+        while width >= 0 {
+            let Some((mode, doc)) = cmds.pop() else {
+                if rest_idx == 0 {
+                    return true;
+                }
+                rest_idx -= 1;
+                let cmd = rest_commands[rest_idx];
+                cmds.push((cmd.mode, cmd.doc));
+                continue;
+            };
+            match doc {
+                Doc::Str(s) => width -= width_of(s),
+                Doc::Array(docs) | Doc::Fill(docs) => {
+                    cmds.extend(docs.iter().rev().map(|doc| (mode, *doc)));
+                }
+                Doc::Indent(doc) | Doc::IndentIfBreak(doc, _) => cmds.push((mode, *doc)),
+                Doc::Group(group) => {
+                    if must_be_flat && group.should_break {
+                        return false;
+                    }
+                    let group_mode = if group.should_break { Mode::Break } else { mode };
+                    let contents = match group.expanded_states {
+                        Some(states) if group_mode == Mode::Break => states[states.len() - 1],
+                        _ => group.contents,
+                    };
+                    cmds.push((group_mode, contents));
+                }
+                Doc::IfBreak(if_break) => {
+                    let group_mode = if_break.group_id.map_or(mode, |id| self.group_mode(id));
+                    let contents = match group_mode {
+                        Mode::Break => if_break.break_contents,
+                        Mode::Flat => if_break.flat_contents,
+                    };
+                    cmds.push((mode, contents));
+                }
+                Doc::Line(line) => {
+                    if mode == Mode::Break || matches!(line, Line::Hard | Line::Literal) {
+                        return true;
+                    }
+                    if line == Line::Normal {
+                        width -= 1;
+                    }
+                }
+                Doc::LineSuffix(_) | Doc::BreakParent => {}
+            }
+        }
+        false
+    }
+}
+
+fn width(s: &str) -> usize {
+    s.chars().count()
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn width_of(s: &str) -> isize {
+    width(s) as isize
+}
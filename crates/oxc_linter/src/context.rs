@@ -85,3 +85,35 @@ impl<'a> LintContext<'a> {
         node.parent().and_then(|node_id| self.nodes().get(node_id))
     }
 }
+
+/// The context of a JSON document, which has no semantic information
+pub struct JsonLintContext<'a> {
+    source_text: &'a str,
+
+    /// File name of the document, for the rules of a specific file such as `package.json`
+    file_name: &'a str,
+
+    diagnostics: RefCell<Vec<Message<'a>>>,
+}
+
+impl<'a> JsonLintContext<'a> {
+    pub fn new(source_text: &'a str, file_name: &'a str) -> Self {
+        Self { source_text, file_name, diagnostics: RefCell::new(vec![]) }
+    }
+
+    pub fn source_text(&self) -> &'a str {
+        self.source_text
+    }
+
+    pub fn file_name(&self) -> &'a str {
+        self.file_name
+    }
+
+    pub fn into_message(self) -> Vec<Message<'a>> {
+        self.diagnostics.into_inner()
+    }
+
+    pub fn diagnostic<T: Into<Error>>(&self, diagnostic: T) {
+        self.diagnostics.borrow_mut().push(Message::new(diagnostic.into(), None));
+    }
+}
@@ -11,12 +11,13 @@ mod rules;
 use std::{fs, rc::Rc};
 
 use autofix::Message;
+use oxc_ast::ast::JsonValue;
 pub(crate) use oxc_semantic::AstNode;
 use oxc_semantic::Semantic;
 
 pub use crate::autofix::Fixer;
 use crate::{
-    context::LintContext,
+    context::{JsonLintContext, LintContext},
     rules::{RuleEnum, RULES},
 };
 
@@ -90,6 +91,23 @@ impl Linter {
         ctx.into_message()
     }
 
+    /// Lint a JSON document, `file_name` is the name of its file, e.g. `package.json`
+    #[must_use]
+    pub fn run_json<'a>(
+        &self,
+        value: &JsonValue<'a>,
+        source_text: &'a str,
+        file_name: &'a str,
+    ) -> Vec<Message<'a>> {
+        let ctx = JsonLintContext::new(source_text, file_name);
+
+        for rule in &self.rules {
+            rule.run_on_json(value, &ctx);
+        }
+
+        ctx.into_message()
+    }
+
     fn read_rules_configuration() -> Option<serde_json::Map<String, serde_json::Value>> {
         fs::read_to_string(".eslintrc.json")
             .ok()
@@ -98,3 +116,47 @@ impl Linter {
             .and_then(|v| v.as_object().cloned())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+
+    use crate::{rules::RULES, Linter};
+
+    /// JSON rules only run on JSON documents, and the other rules only on programs
+    #[test]
+    fn json_and_program_rules() {
+        let allocator = Allocator::default();
+        let linter = Linter::from_rules(RULES.to_vec());
+
+        let source_text = r#"{ "dependencies": { "b": "1", "a": "1" }, "c": 1, "c": 2 }"#;
+        let source_type = SourceType::from_path("package.json").unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse_json();
+        let messages = linter.run_json(&ret.value.unwrap(), source_text, "package.json");
+        let errors = messages.iter().map(|m| m.error.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "jsonc(no-dupe-keys): Duplicate key \"c\"",
+                "package-json(sort-dependencies): \"dependencies\" are not sorted alphabetically"
+            ]
+        );
+
+        let source_text = r#"let a = { "c": 1, "c": 2 }; a == 1; debugger;"#;
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_type).build(program, Rc::new(ret.trivias)).semantic;
+        let messages = linter.run(&Rc::new(semantic), source_text, false);
+        let errors = messages.iter().map(|m| m.error.to_string()).collect::<Vec<_>>();
+        assert_eq!(errors.len(), 2, "{errors:?}");
+        assert!(errors[0].starts_with("eslint(eqeqeq)"), "{errors:?}");
+        assert!(errors[1].starts_with("eslint(no-debugger)"), "{errors:?}");
+    }
+}
@@ -1,6 +1,11 @@
 use std::fmt::Debug;
 
-use crate::{context::LintContext, AstNode};
+use oxc_ast::ast::JsonValue;
+
+use crate::{
+    context::{JsonLintContext, LintContext},
+    AstNode,
+};
 
 pub enum RuleCategory {
     Correctness,
@@ -14,7 +19,11 @@ pub trait Rule: Sized + Default + Debug {
         Self::default()
     }
 
-    fn run<'a>(&self, node: &AstNode<'a>, _ctx: &LintContext<'a>);
+    /// Run on every AST node of a JavaScript or TypeScript program
+    fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
+
+    /// Run once on the root value of a JSON document
+    fn run_on_json<'a>(&self, _value: &JsonValue<'a>, _ctx: &JsonLintContext<'a>) {}
 }
 
 pub trait RuleMeta {
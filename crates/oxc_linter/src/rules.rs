@@ -7,4 +7,6 @@ oxc_macros::declare_all_lint_rules! {
     no_empty,
     no_empty_pattern,
     deepscan::uninvoked_array_callback,
+    jsonc::no_dupe_keys,
+    package_json::sort_dependencies,
 }
@@ -0,0 +1,82 @@
+use oxc_ast::{ast::JsonValue, Atom, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use rustc_hash::FxHashMap;
+
+use crate::{context::JsonLintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("jsonc(no-dupe-keys): Duplicate key {0:?}")]
+#[diagnostic(help("only the value of the last key is used"))]
+struct NoDupeKeysDiagnostic(
+    Atom,
+    #[label("{0:?} is first defined here")] Span,
+    #[label("and redefined here")] Span,
+);
+
+/// `https://ota-meshi.github.io/eslint-plugin-jsonc/rules/no-dupe-keys.html`
+#[derive(Debug, Default, Clone)]
+pub struct NoDupeKeys;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow duplicate keys in the objects of a JSON document
+    ///
+    /// ### Why is this bad?
+    /// JSON parsers silently keep the value of the last key,
+    /// the earlier values are most likely a mistake from merging or copying.
+    ///
+    /// ### Example
+    /// ```json
+    /// { "name": "a", "version": "1.0.0", "name": "b" }
+    /// ```
+    NoDupeKeys,
+    correctness
+);
+
+impl Rule for NoDupeKeys {
+    fn run_on_json<'a>(&self, value: &JsonValue<'a>, ctx: &JsonLintContext<'a>) {
+        match value {
+            JsonValue::Object(object) => {
+                let mut keys = FxHashMap::default();
+                for prop in &object.properties {
+                    let key = &prop.key;
+                    if let Some(first) = keys.get(key.value.as_str()) {
+                        ctx.diagnostic(NoDupeKeysDiagnostic(key.value.clone(), *first, key.span));
+                    } else {
+                        keys.insert(key.value.as_str(), key.span);
+                    }
+                    self.run_on_json(&prop.value, ctx);
+                }
+            }
+            JsonValue::Array(array) => {
+                for element in &array.elements {
+                    self.run_on_json(element, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"{ "a": 1, "b": { "a": 2 } }"#, None),
+        (r#"[{ "a": 1 }, { "a": 2 }]"#, None),
+        (r#"{ "a": 1, "A": 2, "a ": 3 }"#, None),
+    ];
+
+    let fail = vec![
+        (r#"{ "a": 1, "a": 2 }"#, None),
+        (r#"{ "a": { "b": 1, "c": [{ "d": 1, "d": 2 }], "b": 2 } }"#, None),
+        (r#"{ "a": 1, "a": 2, "a": 3 }"#, None),
+    ];
+
+    Tester::new(NoDupeKeys::NAME, pass, fail).with_path("no_dupe_keys.json").test_and_snapshot();
+}
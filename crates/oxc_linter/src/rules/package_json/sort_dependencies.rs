@@ -0,0 +1,85 @@
+use oxc_ast::{ast::JsonValue, Atom, Span};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+
+use crate::{context::JsonLintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("package-json(sort-dependencies): {0:?} are not sorted alphabetically")]
+#[diagnostic(severity(warning), help("`npm install` sorts them, run it or sort them by hand"))]
+struct SortDependenciesDiagnostic(Atom, Atom, Atom, #[label("{1:?} should be before {2:?}")] Span);
+
+/// The dependency collections of a `package.json`
+const DEPENDENCIES: [&str; 4] =
+    ["dependencies", "devDependencies", "optionalDependencies", "peerDependencies"];
+
+#[derive(Debug, Default, Clone)]
+pub struct SortDependencies;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require the dependencies of a `package.json` to be sorted alphabetically
+    ///
+    /// ### Why is this bad?
+    /// Package managers sort the dependencies they add,
+    /// unsorted dependencies lead to noisy diffs and are hard to look through.
+    ///
+    /// ### Example
+    /// ```json
+    /// { "dependencies": { "react": "^18.0.0", "lodash": "^4.0.0" } }
+    /// ```
+    SortDependencies,
+    nursery
+);
+
+impl Rule for SortDependencies {
+    fn run_on_json<'a>(&self, value: &JsonValue<'a>, ctx: &JsonLintContext<'a>) {
+        if ctx.file_name() != "package.json" {
+            return;
+        }
+        let Some(package) = value.as_object() else { return };
+        for prop in &package.properties {
+            if !DEPENDENCIES.contains(&prop.key.value.as_str()) {
+                continue;
+            }
+            let Some(dependencies) = prop.value.as_object() else { continue };
+            // Only the first unsorted dependency is reported, sorting moves the others as well
+            let unsorted = dependencies
+                .properties
+                .windows(2)
+                .find(|pair| pair[0].key.value > pair[1].key.value);
+            if let Some([prev, next]) = unsorted {
+                ctx.diagnostic(SortDependenciesDiagnostic(
+                    prop.key.value.clone(),
+                    next.key.value.clone(),
+                    prev.key.value.clone(),
+                    next.key.span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"{ "dependencies": { "@babel/core": "*", "lodash": "*", "react": "*" } }"#, None),
+        (r#"{ "scripts": { "test": "jest", "build": "tsc" } }"#, None),
+        (r#"{ "devDependencies": {}, "dependencies": { "a": "*" } }"#, None),
+    ];
+
+    let fail = vec![
+        (r#"{ "dependencies": { "react": "*", "lodash": "*" } }"#, None),
+        (
+            r#"{ "devDependencies": { "a": "*", "c": "*", "b": "*" }, "peerDependencies": { "z": "*", "@y/y": "*" } }"#,
+            None,
+        ),
+    ];
+
+    Tester::new(SortDependencies::NAME, pass, fail).with_path("package.json").test_and_snapshot();
+}
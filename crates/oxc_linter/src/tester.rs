@@ -13,6 +13,8 @@ pub struct Tester {
     rule_name: &'static str,
     expect_pass: Vec<(String, Option<Value>)>,
     expect_fail: Vec<(String, Option<Value>)>,
+    /// Path of the tested source, which selects its `SourceType`
+    path: PathBuf,
     snapshot: String,
 }
 
@@ -24,7 +26,15 @@ impl Tester {
     ) -> Self {
         let expect_pass = expect_pass.into_iter().map(|(s, r)| (s.into(), r)).collect::<Vec<_>>();
         let expect_fail = expect_fail.into_iter().map(|(s, r)| (s.into(), r)).collect::<Vec<_>>();
-        Self { rule_name, expect_pass, expect_fail, snapshot: String::new() }
+        let path = PathBuf::from(rule_name.replace('-', "_")).with_extension("tsx");
+        Self { rule_name, expect_pass, expect_fail, path, snapshot: String::new() }
+    }
+
+    /// Test the rule on a file other than `{rule_name}.tsx`, e.g. `package.json`
+    #[must_use]
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.path = path.into();
+        self
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -55,22 +65,27 @@ impl Tester {
     }
 
     fn run(&mut self, source_text: &str, config: Option<Value>) -> bool {
-        let name = self.rule_name.replace('-', "_");
         let allocator = Allocator::default();
-        let path = PathBuf::from(name).with_extension("tsx");
-        let source_type = SourceType::from_path(&path).expect("incorrect {path:?}");
-        let ret = Parser::new(&allocator, source_text, source_type).parse();
-        assert!(ret.errors.is_empty(), "{:?}", &ret.errors);
-        let program = allocator.alloc(ret.program);
-        let trivias = Rc::new(ret.trivias);
-        let semantic = SemanticBuilder::new(source_type).build(program, trivias);
-        let semantic = Rc::new(semantic);
+        let path = &self.path;
+        let source_type = SourceType::from_path(path).expect("incorrect {path:?}");
         let rule = RULES
             .iter()
             .find(|rule| rule.name() == self.rule_name)
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name));
-        let rule = rule.read_json(config);
-        let result = Linter::from_rules(vec![rule]).run(&semantic, source_text, false);
+        let linter = Linter::from_rules(vec![rule.read_json(config)]);
+        let result = if source_type.is_json() {
+            let ret = Parser::new(&allocator, source_text, source_type).parse_json();
+            assert!(ret.errors.is_empty(), "{:?}", &ret.errors);
+            let file_name = path.file_name().and_then(std::ffi::OsStr::to_str).unwrap();
+            linter.run_json(&ret.value.unwrap(), source_text, file_name)
+        } else {
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            assert!(ret.errors.is_empty(), "{:?}", &ret.errors);
+            let program = allocator.alloc(ret.program);
+            let trivias = Rc::new(ret.trivias);
+            let semantic = SemanticBuilder::new(source_type).build(program, trivias).semantic;
+            linter.run(&Rc::new(semantic), source_text, false)
+        };
         if result.is_empty() {
             return true;
         }
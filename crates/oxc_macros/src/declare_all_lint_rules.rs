@@ -82,7 +82,9 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
         #(#mod_stmts)*
         #(#use_stmts)*
 
-        use crate::{context::LintContext, rule::Rule, rule::RuleMeta, AstNode};
+        use oxc_ast::ast::JsonValue;
+
+        use crate::{context::{JsonLintContext, LintContext}, rule::Rule, rule::RuleMeta, AstNode};
 
         #[derive(Debug, Clone)]
         #[allow(clippy::enum_variant_names)]
@@ -110,6 +112,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
                 }
             }
+
+            pub fn run_on_json<'a>(&self, value: &JsonValue<'a>, ctx: &JsonLintContext<'a>) {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_json(value, ctx)),*
+                }
+            }
         }
 
         lazy_static::lazy_static! {
@@ -0,0 +1,246 @@
+//! Derives for the AST traits defined in `oxc_ast`: `CloneIn`, `ContentEq`, `ContentHash` and `ShiftSpans`.
+//! The generated code refers to the traits through `crate::`, so they can only be used inside `oxc_ast`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Ident, Lifetime};
+
+/// Unions are reported as a compile error on the deriving type.
+fn union_error(input: &DeriveInput) -> TokenStream {
+    syn::Error::new_spanned(input, "unions are not supported").to_compile_error()
+}
+
+/// The lifetime of the cloned node.
+fn new_lifetime() -> Lifetime {
+    Lifetime::new("'new", Span::call_site())
+}
+
+pub fn derive_clone_in(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let new_lifetime = new_lifetime();
+    let cloned = if input.generics.lifetimes().next().is_some() {
+        quote! { #name<#new_lifetime> }
+    } else {
+        quote! { #name }
+    };
+    let lifetimes = input.generics.lifetimes();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, construct) = destructure(&quote! { #name }, &data.fields, |field| {
+                quote! { crate::CloneIn::clone_in(#field, allocator) }
+            });
+            quote! {
+                let #pattern = self;
+                #construct
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (pattern, construct) =
+                    destructure(&quote! { #name::#ident }, &variant.fields, |field| {
+                        quote! { crate::CloneIn::clone_in(#field, allocator) }
+                    });
+                quote! { #pattern => #construct }
+            });
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        Data::Union(_) => return union_error(input),
+    };
+
+    quote! {
+        impl<#(#lifetimes,)* #new_lifetime> crate::CloneIn<#new_lifetime> for #name #ty_generics {
+            type Cloned = #cloned;
+
+            #[allow(clippy::redundant_clone)]
+            fn clone_in(&self, allocator: &#new_lifetime oxc_allocator::Allocator) -> Self::Cloned {
+                #body
+            }
+        }
+    }
+}
+
+pub fn derive_content_eq(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let compare = |fields: &Fields| {
+        let left = pattern(&quote! {}, fields, "left");
+        let right = pattern(&quote! {}, fields, "right");
+        let comparisons = field_bindings(fields, "left")
+            .into_iter()
+            .zip(field_bindings(fields, "right"))
+            .map(|(left, right)| quote! { crate::ContentEq::content_eq(#left, #right) });
+        (left, right, quote! { true #(&& #comparisons)* })
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (left, right, comparison) = compare(&data.fields);
+            quote! {
+                let #name #left = self;
+                let #name #right = other;
+                #comparison
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let (left, right, comparison) = compare(&variant.fields);
+                quote! { (#name::#ident #left, #name::#ident #right) => #comparison }
+            });
+            let fallback = (data.variants.len() > 1).then(|| quote! { _ => false, });
+            quote! {
+                match (self, other) {
+                    #(#arms,)*
+                    #fallback
+                }
+            }
+        }
+        Data::Union(_) => return union_error(input),
+    };
+
+    quote! {
+        impl #impl_generics crate::ContentEq for #name #ty_generics #where_clause {
+            #[allow(clippy::match_same_arms)]
+            fn content_eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+    }
+}
+
+pub fn derive_content_hash(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let hash = |fields: &Fields| {
+        let hashes = field_bindings(fields, "field")
+            .into_iter()
+            .map(|field| quote! { crate::ContentHash::content_hash(#field, state); });
+        quote! { #(#hashes)* }
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern(&quote! { #name }, &data.fields, "field");
+            let hashes = hash(&data.fields);
+            quote! {
+                let #pattern = self;
+                #hashes
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let pattern = pattern(&quote! { #name::#ident }, &variant.fields, "field");
+                let hashes = hash(&variant.fields);
+                quote! { #pattern => { #hashes } }
+            });
+            quote! {
+                std::hash::Hash::hash(&std::mem::discriminant(self), state);
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => return union_error(input),
+    };
+
+    quote! {
+        impl #impl_generics crate::ContentHash for #name #ty_generics #where_clause {
+            fn content_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                #body
+            }
+        }
+    }
+}
+
+pub fn derive_shift_spans(input: &DeriveInput) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let shift = |fields: &Fields| {
+        let shifts = field_bindings(fields, "field")
+            .into_iter()
+            .map(|field| quote! { crate::ShiftSpans::shift_spans(#field, delta); });
+        quote! { #(#shifts)* }
+    };
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern(&quote! { #name }, &data.fields, "field");
+            let shifts = shift(&data.fields);
+            quote! {
+                let #pattern = self;
+                #shifts
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let pattern = pattern(&quote! { #name::#ident }, &variant.fields, "field");
+                let shifts = shift(&variant.fields);
+                quote! { #pattern => { #shifts } }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => return union_error(input),
+    };
+
+    quote! {
+        impl #impl_generics crate::ShiftSpans for #name #ty_generics #where_clause {
+            #[allow(unused_variables, clippy::match_same_arms)]
+            fn shift_spans(&mut self, delta: i64) {
+                #body
+            }
+        }
+    }
+}
+
+/// Bindings for every field, named `{prefix}_{index}`.
+fn field_bindings(fields: &Fields, prefix: &str) -> Vec<Ident> {
+    (0..fields.len()).map(|i| format_ident!("{}_{}", prefix, i)).collect()
+}
+
+/// A pattern binding all fields of `path` to `{prefix}_{index}`.
+fn pattern(path: &TokenStream, fields: &Fields, prefix: &str) -> TokenStream {
+    let bindings = field_bindings(fields, prefix);
+    match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| field.ident.as_ref().unwrap());
+            quote! { #path { #(#idents: #bindings),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#bindings),* ) },
+        Fields::Unit => quote! { #path },
+    }
+}
+
+/// Returns a pattern binding all fields,
+/// and an expression constructing `path` from the bindings mapped with `map`.
+fn destructure(
+    path: &TokenStream,
+    fields: &Fields,
+    map: impl Fn(&Ident) -> TokenStream,
+) -> (TokenStream, TokenStream) {
+    let values = field_bindings(fields, "field").iter().map(map).collect::<Vec<_>>();
+    let construct = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| field.ident.as_ref().unwrap());
+            quote! { #path { #(#idents: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path ( #(#values),* ) },
+        Fields::Unit => quote! { #path },
+    };
+    (pattern(path, fields, "field"), construct)
+}
@@ -2,6 +2,7 @@ use syn::parse_macro_input;
 
 mod declare_all_lint_rules;
 mod declare_oxc_lint;
+mod derive_ast;
 
 /// Macro used to declare an oxc lint rule
 ///
@@ -58,3 +59,35 @@ pub fn declare_all_lint_rules(input: proc_macro::TokenStream) -> proc_macro::Tok
 
     declare_all_lint_rules::declare_all_lint_rules(metadata).into()
 }
+
+/// Derive `CloneIn` for an AST node, cloning it into another allocator.
+/// Only usable inside `oxc_ast`.
+#[proc_macro_derive(CloneIn)]
+pub fn derive_clone_in(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    derive_ast::derive_clone_in(&input).into()
+}
+
+/// Derive `ContentEq` for an AST node, comparing it structurally while ignoring spans.
+/// Only usable inside `oxc_ast`.
+#[proc_macro_derive(ContentEq)]
+pub fn derive_content_eq(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    derive_ast::derive_content_eq(&input).into()
+}
+
+/// Derive `ContentHash` for an AST node, hashing it while ignoring spans.
+/// Only usable inside `oxc_ast`.
+#[proc_macro_derive(ContentHash)]
+pub fn derive_content_hash(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    derive_ast::derive_content_hash(&input).into()
+}
+
+/// Derive `ShiftSpans` for an AST node, moving all of its spans by the same offset.
+/// Only usable inside `oxc_ast`.
+#[proc_macro_derive(ShiftSpans)]
+pub fn derive_shift_spans(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    derive_ast::derive_shift_spans(&input).into()
+}
@@ -0,0 +1,354 @@
+//! Constant Folding
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{GetSpan, Span, ast::*};
+
+use super::{
+    Compressor,
+    util::{
+        ValueType, get_boolean_value, get_comparison_value, get_number_value, get_string_value,
+        get_typeof, get_unary_number_value, get_value_type, is_pure, starts_with_statement_keyword,
+        to_int32, to_uint32,
+    },
+};
+
+impl<'a> Compressor<'a> {
+    pub(super) fn fold_expression(&self, expr: &mut Expression<'a>) {
+        let folded = match expr {
+            Expression::UnaryExpression(expr) => self.fold_unary_expression(expr),
+            Expression::BinaryExpression(expr) => self.fold_binary_expression(expr),
+            Expression::LogicalExpression(expr) => self.fold_logical_expression(expr),
+            Expression::ConditionalExpression(expr) => self.fold_conditional_expression(expr),
+            Expression::SequenceExpression(expr) => Self::fold_sequence_expression(expr),
+            _ => None,
+        };
+        if let Some(folded) = folded {
+            *expr = folded;
+        }
+        self.compress_boolean_operands(expr);
+    }
+
+    /// Removes `!!` from an expression whose value is only used for its truthiness,
+    /// i.e. the test of `if`, loops and `? :`, and the argument of `!`.
+    pub(super) fn compress_condition(&self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::UnaryExpression(unary)
+                if unary.operator == UnaryOperator::LogicalNot
+                    && matches!(&unary.argument, Expression::UnaryExpression(argument)
+                        if argument.operator == UnaryOperator::LogicalNot) =>
+            {
+                let Expression::UnaryExpression(argument) = &mut unary.argument else {
+                    unreachable!()
+                };
+                *expr = self.ast.move_expression(&mut argument.argument);
+                self.compress_condition(expr);
+            }
+            Expression::LogicalExpression(logical)
+                if logical.operator != LogicalOperator::Coalesce =>
+            {
+                self.compress_condition(&mut logical.left);
+                self.compress_condition(&mut logical.right);
+            }
+            Expression::ConditionalExpression(conditional) => {
+                self.compress_condition(&mut conditional.consequent);
+                self.compress_condition(&mut conditional.alternate);
+            }
+            Expression::SequenceExpression(sequence) => {
+                if let Some(last) = sequence.expressions.last_mut() {
+                    self.compress_condition(last);
+                }
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                self.compress_condition(&mut expr.expression);
+            }
+            _ => {}
+        }
+    }
+
+    /// Prints `true` and `false` as `!0` and `!1` where a unary expression is allowed.
+    pub(super) fn compress_boolean(&self, expr: &mut Expression<'a>) {
+        if let Expression::BooleanLiteral(lit) = expr {
+            let value = if lit.value { 0.0 } else { 1.0 };
+            let argument = self.number_literal(lit.span, value).unwrap();
+            *expr = self.ast.unary_expression(lit.span, UnaryOperator::LogicalNot, true, argument);
+        }
+    }
+
+    fn compress_boolean_operands(&self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::BinaryExpression(expr) => {
+                if expr.operator != BinaryOperator::Exponential {
+                    self.compress_boolean(&mut expr.left);
+                }
+                self.compress_boolean(&mut expr.right);
+            }
+            Expression::LogicalExpression(expr) => {
+                self.compress_boolean(&mut expr.left);
+                self.compress_boolean(&mut expr.right);
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.compress_boolean(&mut expr.consequent);
+                self.compress_boolean(&mut expr.alternate);
+            }
+            Expression::SequenceExpression(expr) => {
+                expr.expressions.iter_mut().for_each(|expr| self.compress_boolean(expr));
+            }
+            Expression::AssignmentExpression(expr) => self.compress_boolean(&mut expr.right),
+            Expression::CallExpression(expr) => {
+                self.compress_boolean_arguments(&mut expr.arguments);
+            }
+            Expression::NewExpression(expr) => {
+                self.compress_boolean_arguments(&mut expr.arguments);
+            }
+            Expression::ArrayExpression(expr) => {
+                for element in expr.elements.iter_mut().flatten() {
+                    if let Argument::Expression(expr) = element {
+                        self.compress_boolean(expr);
+                    }
+                }
+            }
+            Expression::ObjectExpression(expr) => {
+                for property in expr.properties.iter_mut() {
+                    if let ObjectProperty::Property(property) = property
+                        && let PropertyValue::Expression(expr) = &mut property.value
+                    {
+                        self.compress_boolean(expr);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn compress_boolean_arguments(&self, arguments: &mut Vec<'a, Argument<'a>>) {
+        for argument in arguments.iter_mut() {
+            if let Argument::Expression(expr) = argument {
+                self.compress_boolean(expr);
+            }
+        }
+    }
+
+    /// `(1)` -> `1`, used for operands which are never the object of a member expression.
+    fn remove_literal_parens(&self, expr: &mut Expression<'a>) {
+        if let Expression::ParenthesizedExpression(paren) = expr
+            && matches!(
+                paren.expression.without_parenthesized(),
+                Expression::BooleanLiteral(_)
+                    | Expression::NullLiteral(_)
+                    | Expression::NumberLiteral(_)
+                    | Expression::BigintLiteral(_)
+                    | Expression::StringLiteral(_)
+            )
+        {
+            *expr = self.ast.move_expression(&mut paren.expression);
+            self.remove_literal_parens(expr);
+        }
+    }
+
+    fn fold_unary_expression(&self, expr: &mut UnaryExpression<'a>) -> Option<Expression<'a>> {
+        self.remove_literal_parens(&mut expr.argument);
+        match expr.operator {
+            UnaryOperator::LogicalNot => {
+                self.compress_condition(&mut expr.argument);
+                get_boolean_value(&expr.argument)
+                    .map(|value| self.boolean_literal(expr.span, !value))
+            }
+            UnaryOperator::Typeof => {
+                get_typeof(&expr.argument).map(|value| self.string_literal(expr.span, value))
+            }
+            UnaryOperator::Void => {
+                let is_zero =
+                    matches!(&expr.argument, Expression::NumberLiteral(lit) if lit.value == 0.0);
+                (!is_zero && is_pure(&expr.argument)).then(|| {
+                    let zero = self.number_literal(expr.span, 0.0).unwrap();
+                    self.ast.unary_expression(expr.span, UnaryOperator::Void, true, zero)
+                })
+            }
+            UnaryOperator::UnaryNegation
+                if matches!(expr.argument, Expression::NumberLiteral(_)) =>
+            {
+                None
+            }
+            UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus | UnaryOperator::BitwiseNot => {
+                get_unary_number_value(expr.operator, &expr.argument)
+                    .and_then(|value| self.number_literal(expr.span, value))
+            }
+            UnaryOperator::Delete => None,
+        }
+    }
+
+    fn fold_binary_expression(&self, expr: &mut BinaryExpression<'a>) -> Option<Expression<'a>> {
+        self.remove_literal_parens(&mut expr.left);
+        self.remove_literal_parens(&mut expr.right);
+        let span = expr.span;
+        match expr.operator {
+            op if op.is_equality() || op.is_compare() => {
+                get_comparison_value(op, &expr.left, &expr.right)
+                    .map(|value| self.boolean_literal(span, value))
+            }
+            BinaryOperator::Addition => self.fold_addition(expr),
+            BinaryOperator::Subtraction
+            | BinaryOperator::Multiplication
+            | BinaryOperator::Division
+            | BinaryOperator::Remainder
+            | BinaryOperator::Exponential => {
+                let left = get_number_value(&expr.left)?;
+                let right = get_number_value(&expr.right)?;
+                let value = match expr.operator {
+                    BinaryOperator::Subtraction => left - right,
+                    BinaryOperator::Multiplication => left * right,
+                    BinaryOperator::Division => left / right,
+                    BinaryOperator::Remainder => left % right,
+                    _ => fold_exponential(left, right)?,
+                };
+                self.number_literal(span, value)
+            }
+            BinaryOperator::ShiftLeft
+            | BinaryOperator::ShiftRight
+            | BinaryOperator::ShiftRightZeroFill
+            | BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOR
+            | BinaryOperator::BitwiseXOR => {
+                let left = get_number_value(&expr.left)?;
+                let right = get_number_value(&expr.right)?;
+                let shift = to_uint32(right) & 31;
+                let value = match expr.operator {
+                    BinaryOperator::ShiftLeft => f64::from(to_int32(left) << shift),
+                    BinaryOperator::ShiftRight => f64::from(to_int32(left) >> shift),
+                    BinaryOperator::ShiftRightZeroFill => f64::from(to_uint32(left) >> shift),
+                    BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+                    BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+                    _ => f64::from(to_int32(left) ^ to_int32(right)),
+                };
+                self.number_literal(span, value)
+            }
+            _ => None,
+        }
+    }
+
+    fn fold_addition(&self, expr: &mut BinaryExpression<'a>) -> Option<Expression<'a>> {
+        let left_type = get_value_type(&expr.left);
+        let right_type = get_value_type(&expr.right);
+        if (left_type == Some(ValueType::String) || right_type == Some(ValueType::String))
+            && let Some(left) = get_string_value(&expr.left)
+            && let Some(right) = get_string_value(&expr.right)
+        {
+            return Some(self.string_literal(expr.span, &format!("{left}{right}")));
+        }
+        // `x + "a" + "b"` -> `x + "ab"`, `x + "a"` is always a string
+        if let Expression::BinaryExpression(left) = &mut expr.left
+            && left.operator == BinaryOperator::Addition
+            && get_value_type(&left.right) == Some(ValueType::String)
+            && right_type.is_some()
+        {
+            let value =
+                format!("{}{}", get_string_value(&left.right)?, get_string_value(&expr.right)?);
+            left.right = self.string_literal(left.right.span(), &value);
+            left.span = expr.span;
+            return Some(self.ast.move_expression(&mut expr.left));
+        }
+        let left = get_number_value(&expr.left)?;
+        let right = get_number_value(&expr.right)?;
+        self.number_literal(expr.span, left + right)
+    }
+
+    fn fold_logical_expression(&self, expr: &mut LogicalExpression<'a>) -> Option<Expression<'a>> {
+        self.remove_literal_parens(&mut expr.left);
+        self.remove_literal_parens(&mut expr.right);
+        let keep_left = match expr.operator {
+            LogicalOperator::And => !get_boolean_value(&expr.left)?,
+            LogicalOperator::Or => get_boolean_value(&expr.left)?,
+            LogicalOperator::Coalesce => {
+                !matches!(get_value_type(&expr.left)?, ValueType::Null | ValueType::Undefined)
+            }
+        };
+        Some(self.ast.move_expression(if keep_left { &mut expr.left } else { &mut expr.right }))
+    }
+
+    fn fold_conditional_expression(
+        &self,
+        expr: &mut ConditionalExpression<'a>,
+    ) -> Option<Expression<'a>> {
+        self.compress_condition(&mut expr.test);
+        self.remove_literal_parens(&mut expr.test);
+        let test = get_boolean_value(&expr.test)?;
+        Some(self.ast.move_expression(if test {
+            &mut expr.consequent
+        } else {
+            &mut expr.alternate
+        }))
+    }
+
+    /// Removes unused side effect free expressions, `(0, a(), b)` -> `(a(), b)`
+    fn fold_sequence_expression(expr: &mut SequenceExpression<'a>) -> Option<Expression<'a>> {
+        let (last, rest) = expr.expressions.split_last()?;
+        if rest.iter().all(is_pure) {
+            // `(0, a.b)()` calls `a.b` without `this` and `(0, eval)()` is an indirect eval
+            let last = last.without_parenthesized();
+            if matches!(last, Expression::MemberExpression(_)) || last.is_specific_id("eval") {
+                return None;
+            }
+        }
+        remove_pure_expressions(&mut expr.expressions, true);
+        (expr.expressions.len() == 1).then(|| expr.expressions.pop()).flatten()
+    }
+
+    pub(super) fn boolean_literal(&self, span: Span, value: bool) -> Expression<'a> {
+        self.ast.literal_boolean_expression(BooleanLiteral { span, value })
+    }
+
+    pub(super) fn string_literal(&self, span: Span, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral { span, value: value.into() })
+    }
+
+    /// Returns `None` for values which have no literal, i.e. `NaN`, `Infinity` and `-0`.
+    pub(super) fn number_literal(&self, span: Span, value: f64) -> Option<Expression<'a>> {
+        if !value.is_finite() || (value == 0.0 && value.is_sign_negative()) {
+            return None;
+        }
+        let raw = self.ast.new_str(&super::util::number_to_string(value.abs()));
+        let literal = self.ast.literal_number_expression(NumberLiteral::new(
+            span,
+            value.abs(),
+            raw,
+            NumberBase::Decimal,
+        ));
+        Some(if value < 0.0 {
+            self.ast.unary_expression(span, UnaryOperator::UnaryNegation, true, literal)
+        } else {
+            literal
+        })
+    }
+}
+
+/// Removes the side effect free expressions of a sequence, except for the last one
+/// when `keep_last` is set. Nothing is removed when it would move an expression like
+/// `{}.a` to the start of an expression statement, where it would parse as a block.
+pub(super) fn remove_pure_expressions(expressions: &mut Vec<'_, Expression<'_>>, keep_last: bool) {
+    let last = expressions.len().saturating_sub(1);
+    let is_kept = |index: usize, expr: &Expression| (keep_last && index == last) || !is_pure(expr);
+    let first = expressions.iter().enumerate().position(|(index, expr)| is_kept(index, expr));
+    if let Some(first) = first
+        && first > 0
+        && starts_with_statement_keyword(&expressions[first])
+    {
+        return;
+    }
+    let mut index = 0;
+    expressions.retain(|expr| {
+        let kept = is_kept(index, expr);
+        index += 1;
+        kept
+    });
+}
+
+/// `**` is only folded for integers, `Math.pow` is implementation-approximated.
+#[allow(clippy::cast_possible_truncation)]
+fn fold_exponential(base: f64, exponent: f64) -> Option<f64> {
+    if base.fract() != 0.0 || exponent.fract() != 0.0 || !(0.0..=64.0).contains(&exponent) {
+        return None;
+    }
+    let value = base.powi(exponent as i32);
+    (value.abs() <= 9_007_199_254_740_992.0).then_some(value)
+}
@@ -0,0 +1,345 @@
+//! Compressor
+//!
+//! Size-reducing transformations applied to the AST before printing:
+//! constant folding, dead code elimination and joining of variable declarations.
+//!
+//! The printer only prints the parentheses present in the AST as `ParenthesizedExpression`,
+//! so every transformation either produces a literal or moves an expression into a position
+//! with the same or a lower precedence than the one it was parsed in.
+
+mod fold;
+mod util;
+
+use oxc_allocator::{Allocator, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    AstBuilder, GetSpan, Span, ast::*, syntax_directed_operations::BoundNames, visit_mut::VisitMut,
+};
+
+use self::{
+    fold::remove_pure_expressions,
+    util::{get_boolean_value, is_pure},
+};
+
+pub struct Compressor<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> Compressor<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator) -> Self {
+        Self { ast: AstBuilder::new(allocator) }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+    }
+
+    fn compress_statement(&self, stmt: &mut Statement<'a>) {
+        match stmt {
+            Statement::IfStatement(if_stmt) => {
+                self.compress_condition(&mut if_stmt.test);
+                if let Some(test) = get_boolean_value(&if_stmt.test) {
+                    *stmt = self.fold_if_statement(if_stmt, test);
+                }
+            }
+            Statement::WhileStatement(stmt) => self.compress_loop_test(&mut stmt.test),
+            Statement::DoWhileStatement(stmt) => self.compress_loop_test(&mut stmt.test),
+            Statement::ForStatement(stmt) => {
+                if let Some(test) = &mut stmt.test {
+                    self.compress_loop_test(test);
+                }
+            }
+            Statement::ExpressionStatement(expr_stmt) => {
+                if Self::remove_unused_expression(&mut expr_stmt.expression) {
+                    *stmt = self.ast.empty_statement(expr_stmt.span);
+                }
+            }
+            Statement::ReturnStatement(stmt) => {
+                if let Some(argument) = &mut stmt.argument {
+                    self.compress_boolean(argument);
+                }
+            }
+            Statement::ThrowStatement(stmt) => self.compress_boolean(&mut stmt.argument),
+            _ => {}
+        }
+    }
+
+    fn compress_loop_test(&self, test: &mut Expression<'a>) {
+        self.compress_condition(test);
+        self.compress_boolean(test);
+    }
+
+    /// `if (true) a; else b` -> `a`, `if (false) a; else b` -> `b`
+    fn fold_if_statement(&self, if_stmt: &mut IfStatement<'a>, test: bool) -> Statement<'a> {
+        let consequent = self.ast.move_statement(&mut if_stmt.consequent);
+        let alternate = if_stmt.alternate.take();
+        let (kept, removed) =
+            if test { (Some(consequent), alternate) } else { (alternate, Some(consequent)) };
+
+        let mut names = vec![];
+        if let Some(removed) = &removed {
+            collect_var_names(removed, &mut names);
+        }
+
+        let mut body = self.ast.new_vec();
+        body.extend(kept);
+        body.extend(self.var_declaration(if_stmt.span, names));
+        // A function declaration in an `if` body is scoped to it, keep it in a block
+        if body.len() == 1 && !matches!(body[0], Statement::Declaration(_)) {
+            return body.pop().unwrap();
+        }
+        if body.is_empty() {
+            return self.ast.empty_statement(if_stmt.span);
+        }
+        self.ast.block_statement(self.ast.block(if_stmt.span, body))
+    }
+
+    /// Removes side effect free parts of an expression statement.
+    /// Returns `true` when nothing is left.
+    fn remove_unused_expression(expr: &mut Expression<'a>) -> bool {
+        if is_pure(expr) {
+            return true;
+        }
+        if let Expression::SequenceExpression(sequence) = expr {
+            remove_pure_expressions(&mut sequence.expressions, false);
+            if sequence.expressions.len() == 1 {
+                *expr = sequence.expressions.pop().unwrap();
+            }
+        }
+        false
+    }
+
+    /// Removes empty statements, unreachable code and unnecessary blocks,
+    /// and joins consecutive variable declarations.
+    fn compress_statements(&self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let mut result: Vec<'a, Statement<'a>> = self.ast.new_vec_with_capacity(stmts.len());
+        let mut unreachable = false;
+        let mut names = vec![];
+
+        for stmt in self.ast.move_statement_vec(stmts) {
+            if unreachable {
+                // Function declarations are hoisted, and so are module declarations
+                match stmt {
+                    Statement::Declaration(Declaration::FunctionDeclaration(_))
+                    | Statement::ModuleDeclaration(_) => result.push(stmt),
+                    _ => collect_var_names(&stmt, &mut names),
+                }
+                continue;
+            }
+            unreachable = self.push_statement(&mut result, stmt);
+        }
+
+        if let Some(span) = result.last().map(GetSpan::span)
+            && let Some(decl) = self.var_declaration(span, names)
+        {
+            self.push_statement(&mut result, decl);
+        }
+        *stmts = result;
+    }
+
+    /// Pushes `stmt` into the list, merging blocks into it and joining variable declarations.
+    /// Returns `true` when the statements after `stmt` are unreachable.
+    fn push_statement(&self, stmts: &mut Vec<'a, Statement<'a>>, mut stmt: Statement<'a>) -> bool {
+        match &mut stmt {
+            Statement::EmptyStatement(_) => return false,
+            Statement::BlockStatement(block) if can_flatten_block(&block.body) => {
+                let mut unreachable = false;
+                for stmt in self.ast.move_statement_vec(&mut block.body) {
+                    unreachable |= self.push_statement(stmts, stmt);
+                }
+                return unreachable;
+            }
+            // `var a = 1; var b = 2` -> `var a = 1, b = 2`
+            Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+                if let Some(Statement::Declaration(Declaration::VariableDeclaration(prev))) =
+                    stmts.last_mut()
+                    && prev.kind == decl.kind
+                    && prev.modifiers.is_none()
+                    && decl.modifiers.is_none()
+                {
+                    prev.span = Span::new(prev.span.start, decl.span.end);
+                    prev.declarations.append(&mut decl.declarations);
+                    return false;
+                }
+            }
+            _ => {}
+        }
+        let unreachable = is_jump_statement(&stmt);
+        stmts.push(stmt);
+        unreachable
+    }
+
+    /// `var a, b` for the given names, used to keep hoisted bindings of removed code
+    fn var_declaration(
+        &self,
+        span: Span,
+        names: std::vec::Vec<BindingIdentifier>,
+    ) -> Option<Statement<'a>> {
+        if names.is_empty() {
+            return None;
+        }
+        let mut declarations = self.ast.new_vec_with_capacity(names.len());
+        for ident in names {
+            let kind = self.ast.binding_identifier(ident);
+            let id = self.ast.binding_pattern(kind, None, false);
+            declarations.push(self.ast.variable_declarator(
+                span,
+                VariableDeclarationKind::Var,
+                id,
+                None,
+                false,
+            ));
+        }
+        let decl = self.ast.variable_declaration(
+            span,
+            VariableDeclarationKind::Var,
+            declarations,
+            Modifiers::empty(),
+        );
+        Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+    }
+}
+
+impl<'a> VisitMut<'a> for Compressor<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+        self.compress_statements(stmts);
+    }
+
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        self.visit_statement_match(stmt);
+        self.compress_statement(stmt);
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        self.visit_pattern(&mut declarator.id);
+        if let Some(init) = &mut declarator.init {
+            self.visit_expression(init);
+            self.compress_boolean(init);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        self.visit_formal_parameters(&mut expr.params);
+        if expr.expression
+            && let Some(Statement::ExpressionStatement(stmt)) = expr.body.statements.first_mut()
+        {
+            // The body of `() => expr` is a single statement holding the return value
+            self.visit_expression(&mut stmt.expression);
+            self.compress_boolean(&mut stmt.expression);
+        } else {
+            self.visit_function_body(&mut expr.body);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        self.fold_expression(expr);
+    }
+}
+
+/// Statements after which the rest of the statement list is unreachable
+fn is_jump_statement(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_)
+    )
+}
+
+/// A block can be merged into its parent list when it declares no block scoped bindings.
+fn can_flatten_block(stmts: &[Statement]) -> bool {
+    stmts.iter().all(|stmt| match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+            decl.kind == VariableDeclarationKind::Var
+        }
+        Statement::Declaration(_) => false,
+        _ => true,
+    })
+}
+
+/// Collects the `var` bindings declared in `stmt`, which are hoisted out of it.
+/// Function declarations are included since they are hoisted as `var` bindings
+/// out of blocks in sloppy mode.
+fn collect_var_names(stmt: &Statement, names: &mut std::vec::Vec<BindingIdentifier>) {
+    match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => {
+            collect_declaration_var_names(decl, names);
+        }
+        Statement::Declaration(Declaration::FunctionDeclaration(func)) => {
+            if let Some(id) = &func.id {
+                push_var_name(id, names);
+            }
+        }
+        Statement::BlockStatement(block) => {
+            block.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+        }
+        Statement::IfStatement(stmt) => {
+            collect_var_names(&stmt.consequent, names);
+            if let Some(alternate) = &stmt.alternate {
+                collect_var_names(alternate, names);
+            }
+        }
+        Statement::ForStatement(stmt) => {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init {
+                collect_declaration_var_names(decl, names);
+            }
+            collect_var_names(&stmt.body, names);
+        }
+        Statement::ForInStatement(stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+                collect_declaration_var_names(decl, names);
+            }
+            collect_var_names(&stmt.body, names);
+        }
+        Statement::ForOfStatement(stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+                collect_declaration_var_names(decl, names);
+            }
+            collect_var_names(&stmt.body, names);
+        }
+        Statement::WhileStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::DoWhileStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::LabeledStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::WithStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::SwitchStatement(stmt) => {
+            for case in &stmt.cases {
+                case.consequent.iter().for_each(|stmt| collect_var_names(stmt, names));
+            }
+        }
+        Statement::TryStatement(stmt) => {
+            stmt.block.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+            if let Some(handler) = &stmt.handler {
+                handler.body.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+            }
+            if let Some(finalizer) = &stmt.finalizer {
+                finalizer.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_declaration_var_names(
+    decl: &VariableDeclaration,
+    names: &mut std::vec::Vec<BindingIdentifier>,
+) {
+    if decl.kind == VariableDeclarationKind::Var {
+        for declarator in &decl.declarations {
+            for ident in declarator.id.bound_names() {
+                push_var_name(ident, names);
+            }
+        }
+    }
+}
+
+fn push_var_name(ident: &BindingIdentifier, names: &mut std::vec::Vec<BindingIdentifier>) {
+    if !names.iter().any(|name| name.name == ident.name) {
+        names.push(ident.clone());
+    }
+}
@@ -0,0 +1,382 @@
+use std::borrow::Cow;
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+/// The type of a side effect free expression which evaluates to a primitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    Undefined,
+    Null,
+    Boolean,
+    Number,
+    Bigint,
+    String,
+}
+
+impl ValueType {
+    /// `typeof` of a value of this type
+    pub fn type_of(self) -> &'static str {
+        match self {
+            Self::Undefined => "undefined",
+            Self::Null => "object",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+            Self::Bigint => "bigint",
+            Self::String => "string",
+        }
+    }
+
+    fn is_numeric(self) -> bool {
+        matches!(self, Self::Undefined | Self::Null | Self::Boolean | Self::Number)
+    }
+}
+
+/// Returns the type of `expr` when it is side effect free and evaluates to a primitive.
+///
+/// Operations which may throw, e.g. mixing `BigInt` with other types, return `None`.
+pub fn get_value_type(expr: &Expression) -> Option<ValueType> {
+    match expr {
+        Expression::BooleanLiteral(_) => Some(ValueType::Boolean),
+        Expression::NullLiteral(_) => Some(ValueType::Null),
+        Expression::NumberLiteral(_) => Some(ValueType::Number),
+        Expression::BigintLiteral(_) => Some(ValueType::Bigint),
+        Expression::StringLiteral(_) => Some(ValueType::String),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => Some(ValueType::String),
+        Expression::ParenthesizedExpression(expr) => get_value_type(&expr.expression),
+        Expression::SequenceExpression(expr) => {
+            let (last, rest) = expr.expressions.split_last()?;
+            rest.iter().all(is_pure).then(|| get_value_type(last)).flatten()
+        }
+        Expression::UnaryExpression(expr) => match expr.operator {
+            UnaryOperator::Void => is_pure(&expr.argument).then_some(ValueType::Undefined),
+            UnaryOperator::LogicalNot => is_pure(&expr.argument).then_some(ValueType::Boolean),
+            UnaryOperator::Typeof => is_pure(&expr.argument).then_some(ValueType::String),
+            UnaryOperator::UnaryNegation | UnaryOperator::BitwiseNot => {
+                match get_value_type(&expr.argument)? {
+                    ValueType::Bigint => Some(ValueType::Bigint),
+                    _ => Some(ValueType::Number),
+                }
+            }
+            UnaryOperator::UnaryPlus => match get_value_type(&expr.argument)? {
+                ValueType::Bigint => None,
+                _ => Some(ValueType::Number),
+            },
+            UnaryOperator::Delete => None,
+        },
+        Expression::BinaryExpression(expr) => {
+            let left = get_value_type(&expr.left)?;
+            let right = get_value_type(&expr.right)?;
+            match expr.operator {
+                op if op.is_equality() || op.is_compare() => Some(ValueType::Boolean),
+                BinaryOperator::Addition => match (left, right) {
+                    (ValueType::String, _) | (_, ValueType::String) => Some(ValueType::String),
+                    (ValueType::Bigint, _) | (_, ValueType::Bigint) => None,
+                    _ => Some(ValueType::Number),
+                },
+                BinaryOperator::In | BinaryOperator::Instanceof => None,
+                _ => (left != ValueType::Bigint && right != ValueType::Bigint)
+                    .then_some(ValueType::Number),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Side effect free expressions can be removed when their value is unused.
+pub fn is_pure(expr: &Expression) -> bool {
+    if get_value_type(expr).is_some() {
+        return true;
+    }
+    match expr {
+        Expression::RegExpLiteral(_)
+        | Expression::ThisExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_) => true,
+        Expression::ParenthesizedExpression(expr) => is_pure(&expr.expression),
+        Expression::SequenceExpression(expr) => expr.expressions.iter().all(is_pure),
+        Expression::LogicalExpression(expr) => is_pure(&expr.left) && is_pure(&expr.right),
+        Expression::ConditionalExpression(expr) => {
+            is_pure(&expr.test) && is_pure(&expr.consequent) && is_pure(&expr.alternate)
+        }
+        Expression::UnaryExpression(expr) => {
+            matches!(
+                expr.operator,
+                UnaryOperator::Void | UnaryOperator::LogicalNot | UnaryOperator::Typeof
+            ) && is_pure(&expr.argument)
+        }
+        Expression::BinaryExpression(expr) => {
+            matches!(
+                expr.operator,
+                BinaryOperator::StrictEquality | BinaryOperator::StrictInequality
+            ) && is_pure(&expr.left)
+                && is_pure(&expr.right)
+        }
+        Expression::ArrayExpression(expr) => expr.elements.iter().all(|element| match element {
+            None => true,
+            Some(Argument::Expression(expr)) => is_pure(expr),
+            Some(Argument::SpreadElement(_)) => false,
+        }),
+        Expression::ObjectExpression(expr) => {
+            expr.properties.iter().all(|property| match property {
+                ObjectProperty::Property(property) => {
+                    !property.computed
+                        && match &property.value {
+                            PropertyValue::Expression(expr) => is_pure(expr),
+                            PropertyValue::Pattern(_) => false,
+                        }
+                }
+                ObjectProperty::SpreadProperty(_) => false,
+            })
+        }
+        _ => false,
+    }
+}
+
+/// [`ToBoolean`](https://tc39.es/ecma262/#sec-toboolean) of a side effect free expression
+pub fn get_boolean_value(expr: &Expression) -> Option<bool> {
+    match expr {
+        Expression::BooleanLiteral(lit) => Some(lit.value),
+        Expression::NullLiteral(_) => Some(false),
+        Expression::NumberLiteral(lit) => Some(lit.value != 0.0),
+        Expression::BigintLiteral(lit) => Some(lit.value != 0u32.into()),
+        Expression::StringLiteral(lit) => Some(!lit.value.is_empty()),
+        Expression::TemplateLiteral(_) => get_string_value(expr).map(|value| !value.is_empty()),
+        Expression::RegExpLiteral(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ArrowFunctionExpression(_) => Some(true),
+        Expression::ArrayExpression(_) | Expression::ObjectExpression(_) => {
+            is_pure(expr).then_some(true)
+        }
+        Expression::ParenthesizedExpression(expr) => get_boolean_value(&expr.expression),
+        Expression::SequenceExpression(expr) => {
+            let (last, rest) = expr.expressions.split_last()?;
+            rest.iter().all(is_pure).then(|| get_boolean_value(last)).flatten()
+        }
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::Void => is_pure(&unary.argument).then_some(false),
+            UnaryOperator::LogicalNot => get_boolean_value(&unary.argument).map(|value| !value),
+            UnaryOperator::Typeof => is_pure(&unary.argument).then_some(true),
+            _ => get_number_value(expr).map(|value| value != 0.0 && !value.is_nan()),
+        },
+        _ => None,
+    }
+}
+
+/// [`ToNumber`](https://tc39.es/ecma262/#sec-tonumber) of a side effect free expression
+///
+/// Strings are not converted.
+pub fn get_number_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(lit) => Some(*lit.value),
+        Expression::BooleanLiteral(lit) => Some(if lit.value { 1.0 } else { 0.0 }),
+        Expression::NullLiteral(_) => Some(0.0),
+        Expression::ParenthesizedExpression(expr) => get_number_value(&expr.expression),
+        Expression::UnaryExpression(unary) => {
+            get_unary_number_value(unary.operator, &unary.argument)
+        }
+        _ => None,
+    }
+}
+
+/// `ToNumber` of `operator argument`
+pub fn get_unary_number_value(operator: UnaryOperator, argument: &Expression) -> Option<f64> {
+    match operator {
+        UnaryOperator::UnaryNegation => get_number_value(argument).map(|value| -value),
+        UnaryOperator::UnaryPlus => get_number_value(argument),
+        UnaryOperator::BitwiseNot => {
+            get_number_value(argument).map(|value| f64::from(!to_int32(value)))
+        }
+        UnaryOperator::LogicalNot => {
+            get_boolean_value(argument).map(|value| if value { 0.0 } else { 1.0 })
+        }
+        UnaryOperator::Void => is_pure(argument).then_some(f64::NAN),
+        UnaryOperator::Typeof | UnaryOperator::Delete => None,
+    }
+}
+
+/// [`ToString`](https://tc39.es/ecma262/#sec-tostring) of a side effect free primitive
+pub fn get_string_value<'b>(expr: &'b Expression) -> Option<Cow<'b, str>> {
+    match expr {
+        Expression::StringLiteral(lit) => Some(Cow::Borrowed(lit.value.as_str())),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            lit.quasis.first()?.value.cooked.as_ref().map(|cooked| Cow::Borrowed(cooked.as_str()))
+        }
+        Expression::BooleanLiteral(lit) => {
+            Some(Cow::Borrowed(if lit.value { "true" } else { "false" }))
+        }
+        Expression::NullLiteral(_) => Some(Cow::Borrowed("null")),
+        Expression::BigintLiteral(lit) => Some(Cow::Owned(lit.value.to_string())),
+        Expression::NumberLiteral(lit) => Some(Cow::Owned(number_to_string(*lit.value))),
+        Expression::ParenthesizedExpression(expr) => get_string_value(&expr.expression),
+        Expression::UnaryExpression(unary) => match unary.operator {
+            UnaryOperator::Void => is_pure(&unary.argument).then_some(Cow::Borrowed("undefined")),
+            UnaryOperator::Typeof => get_typeof(&unary.argument).map(Cow::Borrowed),
+            UnaryOperator::LogicalNot => get_boolean_value(expr)
+                .map(|value| Cow::Borrowed(if value { "true" } else { "false" })),
+            _ => get_number_value(expr).map(|value| Cow::Owned(number_to_string(value))),
+        },
+        _ => None,
+    }
+}
+
+/// The result of `typeof expr` when it is known and `expr` is side effect free
+pub fn get_typeof(expr: &Expression) -> Option<&'static str> {
+    if let Some(value_type) = get_value_type(expr) {
+        return Some(value_type.type_of());
+    }
+    match expr {
+        Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_) => {
+            Some("function")
+        }
+        Expression::RegExpLiteral(_)
+        | Expression::ArrayExpression(_)
+        | Expression::ObjectExpression(_) => is_pure(expr).then_some("object"),
+        Expression::ParenthesizedExpression(expr) => get_typeof(&expr.expression),
+        _ => None,
+    }
+}
+
+/// Folds `left op right` to a boolean for the equality and relational operators.
+pub fn get_comparison_value(
+    operator: BinaryOperator,
+    left: &Expression,
+    right: &Expression,
+) -> Option<bool> {
+    let left_type = get_value_type(left)?;
+    let right_type = get_value_type(right)?;
+    match operator {
+        BinaryOperator::StrictEquality => strict_equals(left, left_type, right, right_type),
+        BinaryOperator::StrictInequality => {
+            strict_equals(left, left_type, right, right_type).map(|value| !value)
+        }
+        BinaryOperator::Equality => loose_equals(left, left_type, right, right_type),
+        BinaryOperator::Inequality => {
+            loose_equals(left, left_type, right, right_type).map(|value| !value)
+        }
+        BinaryOperator::LessThan => less_than(left, left_type, right, right_type, false),
+        BinaryOperator::GreaterThan => less_than(right, right_type, left, left_type, false),
+        BinaryOperator::LessEqualThan => less_than(left, left_type, right, right_type, true),
+        BinaryOperator::GreaterEqualThan => less_than(right, right_type, left, left_type, true),
+        _ => None,
+    }
+}
+
+fn strict_equals(
+    left: &Expression,
+    left_type: ValueType,
+    right: &Expression,
+    right_type: ValueType,
+) -> Option<bool> {
+    if left_type != right_type {
+        return Some(false);
+    }
+    match left_type {
+        ValueType::Undefined | ValueType::Null => Some(true),
+        ValueType::Boolean | ValueType::Number =>
+        {
+            #[allow(clippy::float_cmp)]
+            Some(get_number_value(left)? == get_number_value(right)?)
+        }
+        ValueType::String => Some(get_string_value(left)? == get_string_value(right)?),
+        ValueType::Bigint => None,
+    }
+}
+
+fn loose_equals(
+    left: &Expression,
+    left_type: ValueType,
+    right: &Expression,
+    right_type: ValueType,
+) -> Option<bool> {
+    if left_type == right_type {
+        return strict_equals(left, left_type, right, right_type);
+    }
+    match (left_type, right_type) {
+        (ValueType::Undefined | ValueType::Null, ValueType::Undefined | ValueType::Null) => {
+            Some(true)
+        }
+        (ValueType::Undefined | ValueType::Null, _)
+        | (_, ValueType::Undefined | ValueType::Null) => Some(false),
+        (ValueType::Boolean | ValueType::Number, ValueType::Boolean | ValueType::Number) =>
+        {
+            #[allow(clippy::float_cmp)]
+            Some(get_number_value(left)? == get_number_value(right)?)
+        }
+        _ => None,
+    }
+}
+
+/// `left < right`, or `left <= right` when `or_equal` is set
+fn less_than(
+    left: &Expression,
+    left_type: ValueType,
+    right: &Expression,
+    right_type: ValueType,
+    or_equal: bool,
+) -> Option<bool> {
+    if left_type.is_numeric() && right_type.is_numeric() {
+        let left = get_number_value(left)?;
+        let right = get_number_value(right)?;
+        return Some(if or_equal { left <= right } else { left < right });
+    }
+    if left_type == ValueType::String && right_type == ValueType::String {
+        // Strings are compared by their UTF-16 code units
+        let ordering =
+            get_string_value(left)?.encode_utf16().cmp(get_string_value(right)?.encode_utf16());
+        return Some(if or_equal { ordering.is_le() } else { ordering.is_lt() });
+    }
+    None
+}
+
+/// [`Number::toString`](https://tc39.es/ecma262/#sec-numeric-types-number-tostring)
+pub fn number_to_string(value: f64) -> String {
+    ryu_js::Buffer::new().format(value).to_string()
+}
+
+/// [`ToInt32`](https://tc39.es/ecma262/#sec-toint32)
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+pub fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    (value.trunc().rem_euclid(4_294_967_296.0) as u32) as i32
+}
+
+/// [`ToUint32`](https://tc39.es/ecma262/#sec-touint32)
+#[allow(clippy::cast_sign_loss)]
+pub fn to_uint32(value: f64) -> u32 {
+    to_int32(value) as u32
+}
+
+/// Returns whether a statement beginning with `expr` would be parsed as something else,
+/// e.g. a block, a function declaration or a class declaration.
+pub fn starts_with_statement_keyword(expr: &Expression) -> bool {
+    match expr {
+        Expression::ObjectExpression(_)
+        | Expression::FunctionExpression(_)
+        | Expression::ClassExpression(_)
+        | Expression::ChainExpression(_)
+        | Expression::TSAsExpression(_)
+        | Expression::TSNonNullExpression(_)
+        | Expression::TSInstantiationExpression(_) => true,
+        Expression::Identifier(ident) => ident.name == "let",
+        Expression::MemberExpression(expr) => starts_with_statement_keyword(expr.object()),
+        Expression::CallExpression(expr) => starts_with_statement_keyword(&expr.callee),
+        Expression::TaggedTemplateExpression(expr) => starts_with_statement_keyword(&expr.tag),
+        Expression::BinaryExpression(expr) => starts_with_statement_keyword(&expr.left),
+        Expression::LogicalExpression(expr) => starts_with_statement_keyword(&expr.left),
+        Expression::ConditionalExpression(expr) => starts_with_statement_keyword(&expr.test),
+        Expression::SequenceExpression(expr) => {
+            expr.expressions.first().map_or(false, starts_with_statement_keyword)
+        }
+        Expression::AssignmentExpression(expr) => !matches!(
+            expr.left,
+            AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(_)
+            )
+        ),
+        Expression::UpdateExpression(expr) => !expr.prefix,
+        _ => false,
+    }
+}
@@ -0,0 +1,306 @@
+//! ECMAScript Minifier
+//!
+//! Parses the source text and prints it back with `minify_whitespace` enabled.
+//! Mangling and compression are opt-in passes run between parsing and printing.
+
+#![feature(let_chains)]
+
+mod compressor;
+mod mangler;
+
+use std::rc::Rc;
+
+use oxc_allocator::Allocator;
+use oxc_ast::SourceType;
+use oxc_diagnostics::Error;
+use oxc_parser::Parser;
+use oxc_printer::{Printer, PrinterOptions};
+use oxc_semantic::SemanticBuilder;
+
+pub use crate::{
+    compressor::Compressor,
+    mangler::{Mangler, ManglerOptions},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct MinifierOptions {
+    /// Shorten local binding names, see [`Mangler`].
+    pub mangle: Option<ManglerOptions>,
+
+    /// Apply size-reducing syntax transformations,
+    /// see [`Compressor`].
+    pub compress: bool,
+}
+
+pub struct Minifier<'a> {
+    source_text: &'a str,
+    source_type: SourceType,
+    options: MinifierOptions,
+}
+
+impl<'a> Minifier<'a> {
+    #[must_use]
+    pub fn new(source_text: &'a str, source_type: SourceType, options: MinifierOptions) -> Self {
+        Self { source_text, source_type, options }
+    }
+
+    #[must_use]
+    pub fn options(&self) -> &MinifierOptions {
+        &self.options
+    }
+
+    /// Returns the minified code.
+    ///
+    /// # Errors
+    ///
+    /// * Syntax errors from the parser, the source text is not printed when there are any.
+    pub fn build(self) -> Result<String, Vec<Error>> {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, self.source_text, self.source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+
+        let program = allocator.alloc(ret.program);
+        if self.options.compress {
+            Compressor::new(&allocator).build(program);
+        }
+
+        let program = &*program;
+        let printer_options =
+            PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        let mut printer = Printer::new(self.source_text.len(), printer_options);
+        if let Some(options) = self.options.mangle {
+            let semantic = SemanticBuilder::new(self.source_type)
+                .build(program, Rc::new(ret.trivias))
+                .semantic;
+            printer = printer.with_mangled_names(Mangler::new(options).build(&semantic));
+        }
+        Ok(printer.build(program))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn minify(source_text: &str) -> String {
+        Minifier::new(source_text, SourceType::default(), MinifierOptions::default())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn smoke_test() {
+        assert_eq!(minify(""), "");
+    }
+
+    #[test]
+    fn whitespace() {
+        assert_eq!(
+            minify("function foo ( a, b ) {\n    return a + b;\n}\n"),
+            "function foo(a,b){return a+b}"
+        );
+    }
+
+    fn compress(source_text: &str) -> String {
+        let options = MinifierOptions { compress: true, ..MinifierOptions::default() };
+        Minifier::new(source_text, SourceType::default(), options).build().unwrap()
+    }
+
+    #[test]
+    fn fold_constants() {
+        assert_eq!(compress("x = 1 + 2 * 3"), "x=7");
+        assert_eq!(compress("x = (1 + 2) * x"), "x=3*x");
+        assert_eq!(compress("x = 1 - 3"), "x=-2");
+        assert_eq!(compress("x = a - (0 - 1)"), "x=a-(-1)");
+        assert_eq!(compress("x = 1 / 0; y = 0 / 0; z = 0 * -1"), "x=1/0;y=0/0;z=0*-1");
+        assert_eq!(compress("x = 2 ** 10; y = 2 ** -1"), "x=1024;y=2**-1");
+        assert_eq!(
+            compress("x = -1 >>> 0; y = 1 << 31; z = ~5"),
+            "x=4294967295;y=-2147483648;z=-6"
+        );
+        assert_eq!(compress("x = 'a' + 'b' + 1 + null"), "x='ab1null'");
+        assert_eq!(compress("x = 1 + 2 + 'a'"), "x='3a'");
+        assert_eq!(compress("x = y + 'a' + 'b'"), "x=y+'ab'");
+        assert_eq!(compress("x = typeof 1; y = typeof function() {}"), "x='number';y='function'");
+        assert_eq!(compress("x = 1 === 1; y = null == void 0; z = 'a' < 'b'"), "x=!0;y=!0;z=!0");
+        assert_eq!(compress("x = 1 == '1'"), "x=1=='1'");
+        assert_eq!(compress("x = true && y; z = 0 || y; w = null ?? y"), "x=y;z=y;w=y");
+        assert_eq!(compress("x = 'a' ? b : c"), "x=b");
+        assert_eq!(compress("x = void 'a'"), "x=void 0");
+        assert_eq!(compress("x = (1 + 2).toFixed()"), "x=(3).toFixed()");
+    }
+
+    #[test]
+    fn booleans() {
+        assert_eq!(compress("function f() { return true }"), "function f(){return !0}");
+        assert_eq!(compress("var a = false, b = [true]"), "var a=!1,b=[!0]");
+        assert_eq!(compress("true.toString()"), "true.toString()");
+        assert_eq!(compress("if (!!a) b(); while (!!a && !!b) c()"), "if(a){b()}while(a&&b)c()");
+        assert_eq!(compress("x = !!a"), "x=!!a");
+        assert_eq!(compress("x = !!!a"), "x=!a");
+    }
+
+    #[test]
+    fn dead_code() {
+        assert_eq!(compress("if (false) a(); else b()"), "b()");
+        assert_eq!(compress("if ('a' === 'a') { a() } else { b() }"), "a()");
+        assert_eq!(compress("if (0) { var a = 1; function f() {} }"), "var a,f");
+        assert_eq!(compress("if (1) { let a } "), "{let a}");
+        assert_eq!(
+            compress("function f() { return 1; a(); var b = 2; function c() {} }"),
+            "function f(){return 1;function c(){}var b}"
+        );
+        assert_eq!(compress("for (;;) { if (a) { break } b() }"), "for(;;){if(a){break}b()}");
+        assert_eq!(compress("for (;;) { { break } b() }"), "for(;;){break}");
+        assert_eq!(compress("a: { a(); break a; b() }"), "a:{a();break a}");
+    }
+
+    #[test]
+    fn unused_expressions() {
+        assert_eq!(compress("1; 'a'; x; [1, {}]; (function() {})"), "x");
+        assert_eq!(compress("a(), 1, b()"), "a(),b()");
+        assert_eq!(compress("0, a()"), "a()");
+        assert_eq!(compress("x = (0, a)"), "x=(a)");
+        assert_eq!(compress("x = (0, a.b)()"), "x=(0,a.b)()");
+        assert_eq!(compress("0, {}.a"), "0,{}.a");
+        assert_eq!(compress("0, function() {}()"), "0,function(){}()");
+        assert_eq!(compress("x = () => 1"), "x=()=>1");
+    }
+
+    #[test]
+    fn join_declarations() {
+        assert_eq!(
+            compress("var a = 1; var b; let c; let d; const e = 1"),
+            "var a=1,b;let c,d;const e=1"
+        );
+        assert_eq!(compress("var a; { var b } var c"), "var a,b,c");
+        assert_eq!(compress("var a; a(); var b"), "var a;a();var b");
+    }
+
+    fn mangle_with(source_text: &str, source_type: SourceType, options: ManglerOptions) -> String {
+        let options = MinifierOptions { mangle: Some(options), ..MinifierOptions::default() };
+        Minifier::new(source_text, source_type, options).build().unwrap()
+    }
+
+    fn mangle(source_text: &str) -> String {
+        mangle_with(source_text, SourceType::default(), ManglerOptions::default())
+    }
+
+    #[test]
+    fn mangle_locals() {
+        assert_eq!(
+            mangle("function foo(x, y) { var z = x + y; return z * console }"),
+            "function foo(a,b){var c=a+b;return c*console}"
+        );
+        assert_eq!(
+            mangle("var top = 1; function f() { let a = top; return a }"),
+            "var top=1;function f(){let a=top;return a}"
+        );
+        assert_eq!(
+            mangle("function f(foo) { return function g(bar) { return foo + bar } }"),
+            "function f(a){return function c(b){return a+b}}"
+        );
+        assert_eq!(
+            mangle("function f() { { let x = 1 } { let y = 2 } }"),
+            "function f(){{let a=1}{let a=2}}"
+        );
+        assert_eq!(
+            mangle("function f(a) { return function(b) { return a + b + c } }"),
+            "function f(a){return function(b){return a+b+c}}"
+        );
+        assert_eq!(
+            mangle("function f() { try {} catch (err) { throw err } }"),
+            "function f(){try{}catch(a){throw a}}"
+        );
+        assert_eq!(mangle("function f(foo) { return { foo } }"), "function f(a){return {foo:a}}");
+        assert_eq!(mangle("function f(foo) { ({ foo } = x) }"), "function f(a){({foo:a}=x)}");
+        assert_eq!(
+            mangle("let x = class Foo { m() { return Foo } }; Foo"),
+            "let x=class a{m(){return a}};Foo"
+        );
+    }
+
+    #[test]
+    fn mangle_dynamic_scopes() {
+        assert_eq!(
+            mangle("function f(foo) { function g(bar) { eval('') } }"),
+            "function f(foo){function g(bar){eval('')}}"
+        );
+        assert_eq!(
+            mangle("function f(foo) { with (obj) { foo } }"),
+            "function f(foo){with(obj){foo}}"
+        );
+        assert_eq!(mangle("function f(foo) { (0, eval)('') }"), "function f(a){(0,eval)('')}");
+    }
+
+    #[test]
+    fn mangle_block_functions() {
+        assert_eq!(
+            mangle("function f() { { function foo() {} } return foo }"),
+            "function f(){{function a(){}}return a}"
+        );
+        assert_eq!(
+            mangle("function f() { let foo; { function foo() {} foo() } return foo }"),
+            "function f(){let a;{function b(){}b()}return a}"
+        );
+        assert_eq!(
+            mangle("function f() { 'use strict'; { function foo() {} } return foo }"),
+            "function f(){\"use strict\";{function a(){}}return foo}"
+        );
+        assert_eq!(
+            mangle("class A { m() { { function foo() {} } return foo } }"),
+            "class A{m(){{function a(){}}return foo}}"
+        );
+    }
+
+    #[test]
+    fn mangle_options() {
+        let source_text = "function f() { function foo() {} class Bar {} return [foo, Bar] }";
+        assert_eq!(mangle(source_text), "function f(){function a(){}class b{}return [a,b]}");
+        let options = ManglerOptions { keep_fnames: true, ..ManglerOptions::default() };
+        assert_eq!(
+            mangle_with(source_text, SourceType::default(), options),
+            "function f(){function foo(){}class a{}return [foo,a]}"
+        );
+        let options = ManglerOptions { keep_classnames: true, ..ManglerOptions::default() };
+        assert_eq!(
+            mangle_with(source_text, SourceType::default(), options),
+            "function f(){function a(){}class Bar{}return [a,Bar]}"
+        );
+        let options = ManglerOptions { reserved: vec!["a".into()], ..ManglerOptions::default() };
+        assert_eq!(
+            mangle_with("function f(foo, a) { return foo + a }", SourceType::default(), options),
+            "function f(b,a){return b+a}"
+        );
+    }
+
+    #[test]
+    fn mangle_module() {
+        let source_type = SourceType::builder().module().build();
+        let source_text =
+            "import { foo } from 'a'; let bar = foo; export { bar }; export let baz = bar";
+        assert_eq!(
+            mangle_with(source_text, source_type, ManglerOptions::default()),
+            "import {foo as a} from 'a';let bar=a;export {bar};export let baz=bar"
+        );
+        let options = ManglerOptions { mangle_exports: true, ..ManglerOptions::default() };
+        assert_eq!(
+            mangle_with(source_text, source_type, options.clone()),
+            "import {foo as b} from 'a';let a=b;export {a as bar};let c=a;export {c as baz}"
+        );
+        let source_text = "export function foo() {} export const { x, y: [z] } = foo(); x + z";
+        assert_eq!(
+            mangle_with(source_text, source_type, options),
+            "function a(){}export {a as foo};const {x:b,y:[c]}=a();export {b as x,c as z};b+c"
+        );
+    }
+
+    #[test]
+    fn syntax_error() {
+        let ret =
+            Minifier::new("let = ;", SourceType::default(), MinifierOptions::default()).build();
+        assert!(ret.is_err());
+    }
+}
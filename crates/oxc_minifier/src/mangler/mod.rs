@@ -0,0 +1,223 @@
+//! Mangler
+//!
+//! Renames local bindings to the shortest available names.
+//!
+//! Every renamable symbol is given a slot: the symbols of a scope take the slots after the ones
+//! used by its enclosing scopes, so sibling scopes share slots and a nested scope never reuses
+//! the slot of a binding it can see.
+//! Slots are then named by how often their symbols are used,
+//! the most used slot gets the shortest name.
+//! Adapted from [esbuild](https://github.com/evanw/esbuild/blob/main/internal/renamer/renamer.go)
+
+use std::cmp::Reverse;
+
+use oxc_ast::Atom;
+use oxc_semantic::{ScopeId, Semantic, Symbol};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+#[derive(Debug, Default, Clone)]
+pub struct ManglerOptions {
+    /// Keep the names of functions, for code that relies on `Function.prototype.name`.
+    pub keep_fnames: bool,
+
+    /// Keep the names of classes.
+    pub keep_classnames: bool,
+
+    /// Also rename exported bindings.
+    /// The names a module exports are kept: `export let foo` is printed as `let a;export {a as foo}`.
+    pub mangle_exports: bool,
+
+    /// Names that are never renamed nor given to a binding.
+    pub reserved: Vec<String>,
+}
+
+pub struct Mangler {
+    options: ManglerOptions,
+}
+
+impl Mangler {
+    #[must_use]
+    pub fn new(options: ManglerOptions) -> Self {
+        Self { options }
+    }
+
+    /// Returns the new names keyed by the start of every identifier to rename,
+    /// see `Printer::with_mangled_names`.
+    #[must_use]
+    pub fn build(self, semantic: &Semantic) -> FxHashMap<u32, Atom> {
+        let scopes = semantic.scopes();
+        let symbols = semantic.symbols();
+        let dynamic_scopes = Self::dynamic_scopes(semantic);
+
+        let root_scope_id = scopes.root_scope_id();
+        let is_module = semantic.source_type().is_module();
+        let is_renamable = |symbol: &Symbol| {
+            // Top level bindings of a script are globals
+            (is_module || symbol.scope_id() != root_scope_id)
+                && !dynamic_scopes.contains(&symbol.scope_id())
+                && !(self.options.keep_fnames && symbol.is_function())
+                && !(self.options.keep_classnames && symbol.is_class())
+                && (self.options.mangle_exports || !symbol.is_export())
+                && !self.options.reserved.iter().any(|name| name == symbol.name().as_str())
+        };
+
+        // Names that must not be given to any binding,
+        // because they are read from the global scope or kept by a binding.
+        let mut reserved_names = symbols
+            .references()
+            .filter(|reference| reference.is_global())
+            .map(|reference| reference.name().clone())
+            .collect::<FxHashSet<_>>();
+        reserved_names.extend(
+            symbols
+                .iter()
+                .filter(|(_, symbol)| !is_renamable(symbol))
+                .map(|(_, symbol)| symbol.name().clone()),
+        );
+        reserved_names.extend(self.options.reserved.iter().map(Atom::from));
+
+        // Assign slots, starting each scope after the slots of its parent
+        let mut symbol_slots = vec![None; symbols.len()];
+        let mut slot_frequencies: Vec<usize> = vec![];
+        let mut stack = vec![(root_scope_id, 0)];
+        while let Some((scope_id, mut slot)) = stack.pop() {
+            let mut bindings = scopes[scope_id]
+                .bindings()
+                .values()
+                .copied()
+                .filter(|symbol_id| is_renamable(&symbols[*symbol_id]))
+                // A sloppy mode block function is also bound in the enclosing function
+                .filter(|symbol_id| symbol_slots[symbol_id.index()].is_none())
+                .collect::<Vec<_>>();
+            // Declaration order, the hash map order is not deterministic
+            bindings.sort_unstable();
+            for symbol_id in bindings {
+                let symbol = &symbols[symbol_id];
+                if slot == slot_frequencies.len() {
+                    slot_frequencies.push(0);
+                }
+                slot_frequencies[slot] += symbol.declarations().len() + symbol.references().len();
+                symbol_slots[symbol_id.index()] = Some(slot);
+                slot += 1;
+            }
+            for child_id in scopes.children(scope_id) {
+                stack.push((child_id.into(), slot));
+            }
+        }
+
+        // Give the shortest names to the most frequently used slots
+        let mut slots = (0..slot_frequencies.len()).collect::<Vec<_>>();
+        slots.sort_by_key(|slot| Reverse(slot_frequencies[*slot]));
+        let mut slot_names = vec![Atom::default(); slots.len()];
+        let mut n = 0;
+        for slot in slots {
+            slot_names[slot] = loop {
+                let name = base54(n);
+                n += 1;
+                if !is_keyword(&name) && !reserved_names.contains(&name) {
+                    break name;
+                }
+            };
+        }
+
+        let mut mangled_names = FxHashMap::default();
+        for (symbol_id, symbol) in symbols.iter() {
+            let Some(slot) = symbol_slots[symbol_id.index()] else { continue };
+            let name = &slot_names[slot];
+            if name == symbol.name() {
+                continue;
+            }
+            for span in symbol.declarations() {
+                mangled_names.insert(span.start, name.clone());
+            }
+            for reference in symbols.resolved_references(symbol_id) {
+                mangled_names.insert(reference.span().start, name.clone());
+            }
+        }
+        mangled_names
+    }
+
+    /// Scopes containing a direct `eval` or a `with` statement, and their enclosing scopes.
+    /// Their bindings can be accessed by names which are only known at runtime.
+    fn dynamic_scopes(semantic: &Semantic) -> FxHashSet<ScopeId> {
+        let scopes = semantic.scopes();
+        let mut dynamic_scopes = FxHashSet::default();
+        for scope_id in scopes.root_scope_id().descendants(scopes) {
+            let scope = scopes[scope_id].get();
+            if scope.has_direct_eval() || scope.has_with_statement() {
+                dynamic_scopes.extend(scope_id.ancestors(scopes).map(ScopeId::from));
+            }
+        }
+        dynamic_scopes
+    }
+}
+
+/// `a`, `b`, ..., `$`, `_`, `aa`, `ba`, ...
+fn base54(mut n: usize) -> Atom {
+    const FIRST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_";
+    const REST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+    let mut name = String::new();
+    name.push(FIRST[n % FIRST.len()] as char);
+    n /= FIRST.len();
+    while n > 0 {
+        n -= 1;
+        name.push(REST[n % REST.len()] as char);
+        n /= REST.len();
+    }
+    Atom::from(name)
+}
+
+/// Reserved words, and names which cannot be declared in strict mode
+fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "arguments"
+            | "await"
+            | "break"
+            | "case"
+            | "catch"
+            | "class"
+            | "const"
+            | "continue"
+            | "debugger"
+            | "default"
+            | "delete"
+            | "do"
+            | "else"
+            | "enum"
+            | "eval"
+            | "export"
+            | "extends"
+            | "false"
+            | "finally"
+            | "for"
+            | "function"
+            | "if"
+            | "implements"
+            | "import"
+            | "in"
+            | "instanceof"
+            | "interface"
+            | "let"
+            | "new"
+            | "null"
+            | "package"
+            | "private"
+            | "protected"
+            | "public"
+            | "return"
+            | "static"
+            | "super"
+            | "switch"
+            | "this"
+            | "throw"
+            | "true"
+            | "try"
+            | "typeof"
+            | "var"
+            | "void"
+            | "while"
+            | "with"
+            | "yield"
+    )
+}
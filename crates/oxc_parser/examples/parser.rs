@@ -8,9 +8,11 @@ use oxc_parser::Parser;
 // create a `test.js`,
 // run `cargo run -p oxc_parser --example parser`
 // or `cargo watch -x "run -p oxc_parser --example parser"`
+// pass `--estree` to print the ESTree compatible AST
 
 fn main() {
     let name = env::args().nth(1).unwrap_or_else(|| "test.js".to_string());
+    let estree = env::args().any(|arg| arg == "--estree");
     let path = Path::new(&name);
     let source_text = std::fs::read_to_string(path).unwrap_or_else(|_| panic!("{name} not found"));
     let allocator = Allocator::default();
@@ -18,7 +20,12 @@ fn main() {
     let ret = Parser::new(&allocator, &source_text, source_type).parse();
 
     if ret.errors.is_empty() {
-        println!("{}", serde_json::to_string_pretty(&ret.program).unwrap());
+        if estree {
+            let value = ret.program.to_estree_value(&source_text).unwrap();
+            println!("{}", serde_json::to_string_pretty(&value).unwrap());
+        } else {
+            println!("{}", serde_json::to_string_pretty(&ret.program).unwrap());
+        }
         println!("Parsed Successfully.");
     } else {
         for error in ret.errors {
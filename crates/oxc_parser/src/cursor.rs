@@ -11,6 +11,7 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    tokens_pos: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -105,6 +106,9 @@ impl<'a> Parser<'a> {
             self.error(diagnostics::EscapedKeyword(span));
         }
         self.prev_token_end = self.token.end;
+        if let Some(tokens) = &mut self.tokens {
+            tokens.push(self.token.clone());
+        }
         self.token = self.lexer.next_token();
     }
 
@@ -171,6 +175,18 @@ impl<'a> Parser<'a> {
         Ok(())
     }
 
+    /// Expect the closing `Kind` of a block, function body or class body,
+    /// a missing one at the end of the file is reported without giving up the parsed body
+    /// # Errors
+    pub fn expect_closing(&mut self, kind: Kind) -> Result<()> {
+        if self.at(Kind::Eof) {
+            let range = self.current_range();
+            self.error(diagnostics::ExpectToken(kind.to_str(), self.cur_kind().to_str(), range));
+            return Ok(());
+        }
+        self.expect(kind)
+    }
+
     #[must_use]
     pub fn current_range(&self) -> Span {
         let cur_token = self.cur_token();
@@ -179,7 +195,7 @@ impl<'a> Parser<'a> {
                 if self.prev_token_end < cur_token.end {
                     Span::new(self.prev_token_end, self.prev_token_end)
                 } else {
-                    Span::new(self.prev_token_end - 1, self.prev_token_end)
+                    Span::new(self.prev_token_end.saturating_sub(1), self.prev_token_end)
                 }
             }
             _ => cur_token.span(),
@@ -206,7 +222,9 @@ impl<'a> Parser<'a> {
 
     /// Tell lexer to read a regex
     pub fn read_regex(&mut self) {
+        let is_on_new_line = self.token.is_on_new_line;
         self.token = self.lexer.next_regex(self.cur_kind());
+        self.token.is_on_new_line = is_on_new_line;
     }
 
     /// Tell lexer to read a template substitution tail
@@ -218,7 +236,7 @@ impl<'a> Parser<'a> {
 
     /// Tell lexer to re-read a jsx identifier
     pub fn re_lex_jsx_identifier(&mut self) {
-        self.token = self.lexer.next_jsx_identifier(self.prev_token_end);
+        self.token = self.lexer.next_jsx_identifier(self.token.start);
     }
 
     pub fn re_lex_right_angle(&mut self) -> Kind {
@@ -259,17 +277,26 @@ impl<'a> Parser<'a> {
             cur_token: self.token.clone(),
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.borrow().len(),
+            tokens_pos: self.tokens.as_ref().map_or(0, Vec::len),
         }
     }
 
     pub fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos: errors_lens } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos: errors_lens,
+            tokens_pos,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.borrow_mut().truncate(errors_lens);
+        if let Some(tokens) = &mut self.tokens {
+            tokens.truncate(tokens_pos);
+        }
     }
 
     /// # Errors
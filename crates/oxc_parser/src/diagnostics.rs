@@ -4,11 +4,6 @@ use oxc_diagnostics::{
     thiserror::Error,
 };
 
-#[derive(Debug, Error, Diagnostic)]
-#[error("Flow is not supported")]
-#[diagnostic()]
-pub struct Flow(#[label] pub Span);
-
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unexpected token")]
 #[diagnostic()]
@@ -105,6 +100,13 @@ pub struct LegacyOctal(
     #[label("'0'-prefixed octal literals and octal escape sequences are deprecated")] pub Span,
 );
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Octal escape sequences are not allowed in strict mode")]
+#[diagnostic(help("for octal escape sequences use the '\\x' prefix instead"))]
+pub struct LegacyOctalEscape(
+    #[label("'0'-prefixed octal literals and octal escape sequences are deprecated")] pub Span,
+);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Decimals with leading zeros are not allowed in strict mode")]
 #[diagnostic(help("remove the leading zero"))]
@@ -207,6 +209,15 @@ pub struct Redeclaration(
     #[label("It can not be redeclared here")] pub Span,
 );
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Duplicate import attribute {0:?}")]
+#[diagnostic()]
+pub struct DuplicateImportAttribute(
+    pub Atom,
+    #[label("`{0}` is first declared here")] pub Span,
+    #[label("and declared again here")] pub Span,
+);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("{0:?} is disallowed as a lexically bound name")]
 #[diagnostic()]
@@ -363,6 +374,70 @@ pub struct ForAwait(
     #[label("await can only be used in conjunction with `for...of` statements")] pub Span,
 );
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("`using` declarations are not allowed at the top level of a script")]
+#[diagnostic(help("Wrap the declaration in a block or parse the file as a module"))]
+pub struct UsingDeclarationInScript(
+    #[label("`using` declarations are not allowed at the top level of a script")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("The left-hand side of a `for...in` statement cannot be a `using` declaration")]
+#[diagnostic()]
+pub struct UsingDeclarationInForIn(
+    #[label("The left-hand side of a `for...in` statement cannot be a `using` declaration")]
+    pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`using` declarations are not allowed directly in a `case` or `default` clause")]
+#[diagnostic(help("Wrap the declaration in a block"))]
+pub struct UsingDeclarationInSwitchCase(
+    #[label("`using` declarations are not allowed directly in a `case` or `default` clause")]
+    pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("`using` declarations may not have binding patterns")]
+#[diagnostic()]
+pub struct UsingDeclarationBindingPattern(
+    #[label("`using` declarations may not have binding patterns")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Missing initializer in `using` declaration")]
+#[diagnostic()]
+pub struct MissingInitializerInUsing(#[label("`using` declaration needs an initializer")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators are not valid here")]
+#[diagnostic()]
+pub struct DecoratorsNotValidHere(#[label("Decorators are not valid here")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators may not appear after `export` or `export default` with legacy decorators")]
+#[diagnostic(help("Move the decorators before `export`"))]
+pub struct DecoratorsAfterExport(#[label("Decorators must come before `export`")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Decorators may not appear both before and after `export`")]
+#[diagnostic()]
+pub struct DecoratorsBeforeAndAfterExport(
+    #[label("Decorators were already given before `export`")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid decorator expression")]
+#[diagnostic(help(
+    "A decorator is a dotted name, optionally called, anything else must be wrapped in parentheses"
+))]
+pub struct InvalidDecoratorExpression(#[label("Wrap this expression in parentheses")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Parameter decorators are only valid with legacy decorators")]
+#[diagnostic()]
+pub struct ParameterDecorators(#[label("Parameter decorators are not valid here")] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("Cannot use new with dynamic import")]
 #[diagnostic()]
@@ -766,3 +841,43 @@ pub struct JSXExpressionsMayNotUseTheCommaOperator(
     #[label("JSX expressions may not use the comma operator. Did you mean to write an array?")]
     pub Span,
 );
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Comments are not allowed in JSON")]
+#[diagnostic(help("use JSONC or JSON5 for comments"))]
+pub struct JsonComment(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Trailing commas are not allowed in JSON")]
+#[diagnostic(help("remove the trailing comma"))]
+pub struct JsonTrailingComma(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Strings must be double quoted in JSON")]
+#[diagnostic()]
+pub struct JsonSingleQuotedString(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Property keys must be strings in JSON")]
+#[diagnostic(help("wrap the key in double quotes"))]
+pub struct JsonUnquotedKey(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid number in JSON")]
+#[diagnostic()]
+pub struct JsonInvalidNumber(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid escape sequence in JSON string")]
+#[diagnostic()]
+pub struct JsonInvalidEscape(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Control characters must be escaped in JSON strings")]
+#[diagnostic()]
+pub struct JsonControlCharacter(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("A JSON source cannot be parsed as a program")]
+#[diagnostic(help("use `Parser::parse_json` for JSON sources"))]
+pub struct JsonProgram(#[label] pub Span);
@@ -0,0 +1,142 @@
+//! [Flow](https://flow.org/en/docs/types/)
+//!
+//! Flow type annotations share the TypeScript parser,
+//! only the syntax without a TypeScript counterpart is parsed here.
+
+#![allow(clippy::missing_errors_doc)]
+
+use oxc_ast::{ast::*, Span};
+use oxc_diagnostics::Result;
+
+use crate::lexer::Kind;
+use crate::Parser;
+
+impl<'a> Parser<'a> {
+    /// [Opaque type alias](https://flow.org/en/docs/types/opaque-types/)
+    /// `opaque type A: Super = Type;`, the type is omitted in `declare opaque type A: Super;`
+    pub fn parse_flow_opaque_type(
+        &mut self,
+        span: Span,
+        modifiers: Modifiers<'a>,
+    ) -> Result<Declaration<'a>> {
+        self.expect(Kind::Opaque)?;
+        self.expect(Kind::Type)?;
+
+        let id = self.parse_binding_identifier()?;
+        let params = self.parse_ts_type_parameters()?;
+        let supertype = if self.eat(Kind::Colon) { Some(self.parse_ts_type()?) } else { None };
+        let impl_type = if modifiers.contains(ModifierKind::Declare) {
+            None
+        } else {
+            self.expect(Kind::Eq)?;
+            Some(self.parse_ts_type()?)
+        };
+
+        self.asi()?;
+        let span = self.end_span(span);
+        Ok(self.ast.flow_opaque_type(span, id, params, supertype, impl_type, modifiers))
+    }
+
+    /// [Exact object type](https://flow.org/en/docs/types/objects/#exact-and-inexact-object-types)
+    /// `{| a: T |}`
+    pub fn parse_flow_exact_object_type(&mut self) -> Result<TSType<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let mut members = self.ast.new_vec();
+        // `{||}` is lexed as `{`, `||` and `}`
+        if !self.eat(Kind::Pipe2) {
+            self.expect(Kind::Pipe)?;
+            while !self.at(Kind::Pipe) && !self.at(Kind::Eof) {
+                members.push(self.parse_ts_type_signature()?);
+            }
+            self.expect(Kind::Pipe)?;
+        }
+        self.expect(Kind::RCurly)?;
+        Ok(self.ast.ts_type_literal(self.end_span(span), members, /* exact */ true))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::*, SourceType};
+
+    use crate::Parser;
+
+    #[test]
+    fn flow_types() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().jsx().flow().build();
+        let source = "import type { Node } from 'react';
+type Props = {| +children: ?Node, onClick?: (string) => void |};
+type Empty = {||};
+export opaque type Id: string = string;
+declare opaque type Token;
+function first<T: Object>(items: Array<T>, fallback: ?T | null): ?T {
+  return items[0] ?? fallback;
+}
+export default function App(props: Props): Node {
+  return <div>{props.children}</div>;
+}";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let body = &ret.program.body;
+        assert_eq!(body.len(), 7);
+
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(props)) = &body[1] else {
+            panic!("{:?}", body[1])
+        };
+        let TSType::TSTypeLiteral(object) = &props.type_annotation else { panic!() };
+        assert!(object.exact);
+        let TSSignature::TSPropertySignature(children) = &object.members[0] else { panic!() };
+        assert!(children.readonly);
+        let annotation = &children.type_annotation.as_ref().unwrap().type_annotation;
+        assert!(matches!(annotation, TSType::JSDocNullableType(_)));
+
+        let Statement::ModuleDeclaration(decl) = &body[3] else { panic!("{:?}", body[3]) };
+        let ModuleDeclarationKind::ExportNamedDeclaration(export) = &decl.kind else { panic!() };
+        let Some(Declaration::FlowOpaqueType(opaque)) = &export.declaration else { panic!() };
+        assert!(opaque.supertype.is_some() && opaque.impl_type.is_some());
+        let Statement::Declaration(Declaration::FlowOpaqueType(opaque)) = &body[4] else {
+            panic!("{:?}", body[4])
+        };
+        assert!(opaque.supertype.is_none() && opaque.impl_type.is_none());
+
+        // `?T | null` is a union of the maybe type and `null`
+        let Statement::Declaration(Declaration::FunctionDeclaration(func)) = &body[5] else {
+            panic!("{:?}", body[5])
+        };
+        let fallback = &func.params.items[1].pattern.type_annotation.as_ref().unwrap();
+        assert!(matches!(fallback.type_annotation, TSType::TSUnionType(_)));
+        let constraint = &func.type_parameters.as_ref().unwrap().params[0].constraint;
+        assert!(constraint.is_some());
+    }
+
+    #[test]
+    fn flow_syntax_in_javascript() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().build();
+        let ret = Parser::new(&allocator, "opaque type A = string;", source_type).parse();
+        assert!(!ret.errors.is_empty());
+        let source_type = SourceType::builder().module().typescript().build();
+        let ret = Parser::new(&allocator, "type A = {| a: string |};", source_type).parse();
+        assert!(!ret.errors.is_empty());
+    }
+
+    #[test]
+    fn typescript_syntax_in_flow() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().flow().build();
+        for source in [
+            "enum A { B }",
+            "namespace A {}",
+            "let a = b as string;",
+            "let a = b!;",
+            "abstract class A {}",
+            "class A { private a: string; }",
+        ] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+    }
+}
@@ -0,0 +1,440 @@
+//! Incremental parsing
+//!
+//! After an edit, the statements around the edit are parsed again, starting from the statement
+//! before the edit, until the parser reaches the start of a statement after the edit.
+//! The parser is then in the same state as the previous parse was at that statement,
+//! so the remaining statements are re-used with their spans moved.
+//! An edit inside the body of a top-level function declaration only reparses the statements of the body.
+//!
+//! A full parse is done when the previous parse has errors, or when the parser never gets back
+//! to a statement boundary of the previous parse, e.g. after an edit opening a template literal or a comment.
+
+use oxc_allocator::Vec;
+use oxc_ast::{
+    ast::{Declaration, Directive, Expression, FunctionBody, Program, Statement},
+    GetSpan, ShiftSpans, Span, Trivias,
+};
+
+use crate::{lexer::Kind, merge_comments, Parser, ParserReturn, Token};
+
+/// Replacement of `span` in the previous source by `text`
+#[derive(Debug, Clone, Copy)]
+pub struct TextEdit<'e> {
+    pub span: Span,
+    pub text: &'e str,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edit {
+    start: u32,
+    end: u32,
+    delta: i64,
+}
+
+impl Edit {
+    /// Offset in the new source of an offset after the edit in the previous source
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn shift(self, offset: u32) -> u32 {
+        (i64::from(offset) + self.delta) as u32
+    }
+}
+
+struct Reparsed {
+    /// Offset where the lexer restarted
+    start: u32,
+    /// Offset in the previous source from which the previous parse is re-used
+    reused_from: u32,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse the source resulting from applying `edit` to the source of `previous`,
+    /// re-using the statements of `previous` which are not affected by the edit.
+    /// `previous` must come from parsing the source before the edit with the same source type and options.
+    #[must_use]
+    pub fn reparse(self, previous: ParserReturn<'a>, edit: &TextEdit) -> ParserReturn<'a> {
+        let Some(edit) = self.check_edit(&previous, edit) else { return self.parse() };
+        let ParserReturn { mut program, trivias, tokens, .. } = previous;
+        let use_strict = program.directives.iter().any(Directive::is_use_strict);
+        for in_function_body in [true, false] {
+            let mut parser = self.fork();
+            parser.ctx = parser.ctx.and_strict(parser.ctx.has_strict() || use_strict);
+            let reparsed = if in_function_body {
+                parser.reparse_function_body(&mut program, edit)
+            } else {
+                parser.reparse_statements(
+                    &mut program.body,
+                    &program.directives,
+                    0,
+                    program.span.end,
+                    edit,
+                )
+            };
+            let Some(reparsed) = reparsed else { continue };
+            return parser.finish_reparse(program, &trivias, &tokens, edit, &reparsed);
+        }
+        self.parse()
+    }
+
+    fn check_edit(&self, previous: &ParserReturn<'a>, edit: &TextEdit) -> Option<Edit> {
+        let TextEdit { span, text } = *edit;
+        let previous_len = previous.program.span.end;
+        // An edit of the leading comments may add or remove the `@flow` pragma
+        let code_start = (previous.program.directives.first().map(|directive| directive.span))
+            .or_else(|| previous.program.body.first().map(GetSpan::span))
+            .map_or(previous_len, |span| span.start);
+        if !previous.errors.is_empty()
+            || previous.program.source_type != self.source_type
+            || self.source_type.is_javascript() && span.start <= code_start
+            || self.tokens.is_some() && previous.tokens.is_empty()
+            || span.start > span.end
+            || span.end > previous_len
+        {
+            return None;
+        }
+        let delta = i64::from(u32::try_from(text.len()).ok()?) - i64::from(span.len());
+        let inserted_at = span.start as usize..span.start as usize + text.len();
+        (usize::try_from(i64::from(previous_len) + delta).ok() == Some(self.source.len())
+            && self.source.get(inserted_at) == Some(text))
+        .then_some(Edit { start: span.start, end: span.end, delta })
+    }
+
+    /// A new parser for the same source and options
+    fn fork(&self) -> Self {
+        let mut parser = Self::new(self.ast.allocator, self.source, self.source_type);
+        parser.ctx = self.ctx;
+        parser.decorators = self.decorators;
+        parser.tokens = self.tokens.as_ref().map(|_| std::vec::Vec::new());
+        parser
+    }
+
+    /// Reparse statements of the body of the top-level function declaration containing the edit
+    fn reparse_function_body(&mut self, program: &mut Program<'a>, edit: Edit) -> Option<Reparsed> {
+        let index = program.body.iter().position(|stmt| stmt.span().end >= edit.start)?;
+        let Statement::Declaration(Declaration::FunctionDeclaration(func)) =
+            &mut program.body[index]
+        else {
+            return None;
+        };
+        if !func.modifiers.is_none() {
+            return None;
+        }
+        self.ctx = self.ctx.and_await(func.r#async).and_yield(func.generator).and_return(true);
+        let body = func.body.as_mut()?;
+        let use_strict = body.directives.iter().any(Directive::is_use_strict);
+        self.ctx = self.ctx.and_strict(self.ctx.has_strict() || use_strict);
+        // The braces of the body must not be edited
+        let Span { start, end } = body.span;
+        if edit.start <= start || edit.end >= end {
+            return None;
+        }
+
+        let FunctionBody { span, directives, statements } = &mut **body;
+        let reparsed = self.reparse_statements(statements, directives, start + 1, end - 1, edit)?;
+
+        span.end = edit.shift(end);
+        func.span.end = edit.shift(func.span.end);
+        program.body.iter_mut().skip(index + 1).for_each(|stmt| stmt.shift_spans(edit.delta));
+        Some(reparsed)
+    }
+
+    /// Reparse `statements` from the statement before the edit until a statement after the edit,
+    /// the statements and their `directives` are between `list_start` and `list_end`,
+    /// the start and end of the source or the `{` and `}` of a function body.
+    fn reparse_statements(
+        &mut self,
+        statements: &mut Vec<'a, Statement<'a>>,
+        directives: &[Directive<'a>],
+        list_start: u32,
+        list_end: u32,
+        edit: Edit,
+    ) -> Option<Reparsed> {
+        // A function body starts after its `{`
+        let is_top_level = list_start == 0;
+        // Where a statement or directive ends depends on the token after it,
+        // so the statement before the edited ones is reparsed as well
+        let first = statements
+            .iter()
+            .position(|stmt| stmt.span().end >= edit.start)
+            .unwrap_or(statements.len());
+        let from = match first.checked_sub(1) {
+            Some(from) => from,
+            None if directives.is_empty() => 0,
+            None => return None,
+        };
+        let start = from.checked_sub(1).map_or_else(
+            || directives.last().map_or(list_start, |directive| directive.span.end),
+            |previous| statements[previous].span().end,
+        );
+        self.lexer.seek(start);
+        self.token = self.lexer.next_token();
+        self.prev_token_end = start;
+
+        let mut reparsed = self.ast.new_vec();
+        let mut next = from + 1;
+        let reused_from = loop {
+            let offset = self.cur_token().start;
+            while let Some(stmt) = statements.get(next) {
+                let stmt_start = stmt.span().start;
+                if stmt_start >= edit.end && edit.shift(stmt_start) >= offset {
+                    break;
+                }
+                next += 1;
+            }
+            if let Some(stmt) = statements.get(next) {
+                if edit.shift(stmt.span().start) == offset {
+                    break stmt.span().start;
+                }
+            }
+            if self.at(Kind::Eof) || !is_top_level && self.at(Kind::RCurly) {
+                if offset != edit.shift(list_end) {
+                    return None;
+                }
+                break list_end;
+            }
+            let stmt = self.parse_statement_list_item_or_recover();
+            if is_top_level {
+                self.check_top_level_statement(&stmt);
+            }
+            reparsed.push(stmt);
+        };
+
+        // A string literal at the start of the statements would be a directive,
+        // whether reparsed or reused
+        if from == 0
+            && matches!(reparsed.first().or_else(|| statements.get(next)),
+                Some(Statement::ExpressionStatement(stmt))
+                if matches!(stmt.expression, Expression::StringLiteral(_)))
+        {
+            return None;
+        }
+
+        let mut previous = std::mem::replace(statements, self.ast.new_vec()).into_iter();
+        statements.extend(previous.by_ref().take(from));
+        statements.extend(reparsed);
+        for mut stmt in previous.skip(next - from) {
+            stmt.shift_spans(edit.delta);
+            statements.push(stmt);
+        }
+        Some(Reparsed { start, reused_from })
+    }
+
+    /// Combine the comments, tokens and errors of the reparsed statements with the re-used ones
+    #[allow(clippy::cast_possible_truncation)]
+    fn finish_reparse(
+        mut self,
+        mut program: Program<'a>,
+        previous_trivias: &Trivias,
+        previous_tokens: &[Token],
+        edit: Edit,
+        reparsed: &Reparsed,
+    ) -> ParserReturn<'a> {
+        program.span = Span::new(0, self.source.len() as u32);
+
+        let mut trivias = self.lexer.trivia_builder.build();
+        for (mut span, kind) in previous_trivias.comments() {
+            // Comment spans skip the leading `//`, `/*` or `#!`
+            let start = span.start - 2;
+            if start >= reparsed.reused_from {
+                span.shift_spans(edit.delta);
+            } else if start >= reparsed.start {
+                continue;
+            }
+            trivias.add_comment(span, kind);
+        }
+
+        let tokens = self.tokens.take().map_or_else(std::vec::Vec::new, |tokens| {
+            let previous_tokens = previous_tokens
+                .iter()
+                .filter(|token| !matches!(token.kind, Kind::Comment | Kind::MultiLineComment));
+            let mut all = previous_tokens
+                .clone()
+                .take_while(|token| token.start < reparsed.start)
+                .cloned()
+                .collect::<std::vec::Vec<_>>();
+            all.extend(tokens);
+            let reused = previous_tokens.skip_while(|token| token.start < reparsed.reused_from);
+            for (i, token) in reused.enumerate() {
+                let mut token = token.clone();
+                token.start = edit.shift(token.start);
+                token.end = edit.shift(token.end);
+                // The token before it may have moved to another line
+                if i == 0 {
+                    token.is_on_new_line = self.token.is_on_new_line;
+                }
+                all.push(token);
+            }
+            merge_comments(all, &trivias)
+        });
+
+        let errors = self.errors.borrow_mut().drain(..).collect();
+        ParserReturn { program, errors, trivias, tokens }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{
+        ast::{Declaration, Statement, VariableDeclaration},
+        SourceType, Span,
+    };
+
+    use super::TextEdit;
+    use crate::{Parser, ParserReturn};
+
+    /// Apply the edit and compare the reparse with a full parse
+    fn reparse<'a>(
+        allocator: &'a Allocator,
+        previous: ParserReturn<'a>,
+        source: &str,
+        edit: TextEdit,
+    ) -> ParserReturn<'a> {
+        let source_type = SourceType::builder().module().jsx().build();
+        let mut new_source = source.to_string();
+        new_source.replace_range(edit.span.start as usize..edit.span.end as usize, edit.text);
+        let new_source = allocator.alloc_str(&new_source);
+
+        let ret = Parser::new(allocator, new_source, source_type)
+            .preserve_tokens(true)
+            .reparse(previous, &edit);
+        let expected =
+            Parser::new(allocator, new_source, source_type).preserve_tokens(true).parse();
+        // Compared with `Debug` for the `NaN` values of invalid numbers
+        assert_eq!(
+            format!("{:?}", ret.program),
+            format!("{:?}", expected.program),
+            "{source:?} {edit:?}"
+        );
+        assert_eq!(
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            expected.errors.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "{source:?} {edit:?}"
+        );
+        assert_eq!(
+            ret.trivias.comments().collect::<Vec<_>>(),
+            expected.trivias.comments().collect::<Vec<_>>(),
+            "{source:?} {edit:?}"
+        );
+        assert_eq!(
+            format!("{:?}", ret.tokens),
+            format!("{:?}", expected.tokens),
+            "{source:?} {edit:?}"
+        );
+        ret
+    }
+
+    fn parse<'a>(allocator: &'a Allocator, source: &'a str) -> ParserReturn<'a> {
+        let source_type = SourceType::builder().module().jsx().build();
+        Parser::new(allocator, source, source_type).preserve_tokens(true).parse()
+    }
+
+    #[test]
+    fn reuse_statements() {
+        let allocator = Allocator::default();
+        let source = "let a = 1;\nlet b = 2;\nlet c = 3;\nfunction f() {\n  a;\n  b;\n}\nc;";
+        let previous = parse(&allocator, source);
+        let Statement::Declaration(Declaration::VariableDeclaration(declaration)) =
+            &previous.program.body[2]
+        else {
+            panic!()
+        };
+        let reused = &**declaration as *const VariableDeclaration as usize;
+        let edit = TextEdit { span: Span::new(19, 20), text: "22" };
+        let ret = reparse(&allocator, previous, source, edit);
+        let Statement::Declaration(Declaration::VariableDeclaration(declaration)) =
+            &ret.program.body[2]
+        else {
+            panic!()
+        };
+        assert_eq!(&**declaration as *const VariableDeclaration as usize, reused);
+
+        let source = "let a = 1;\nlet b = 22;\nlet c = 3;\nfunction f() {\n  a;\n  b;\n}\nc;";
+        let edit = TextEdit { span: Span::new(52, 52), text: " + 1" };
+        reparse(&allocator, ret, source, edit);
+    }
+
+    #[test]
+    fn context_boundaries() {
+        let allocator = Allocator::default();
+        let source = "a;\nb;\nc;\nd;";
+        for text in
+            ["`", "/*", "//", "'", "{", "}", "(", "\"use strict\";", "/", "<div>", "// @flow\n"]
+        {
+            for offset in 0..=u32::try_from(source.len()).unwrap() {
+                let edit = TextEdit { span: Span::new(offset, offset), text };
+                reparse(&allocator, parse(&allocator, source), source, edit);
+            }
+        }
+    }
+
+    /// Random edits of sample sources, each one applied to the result of the previous one
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn fuzz() {
+        const SOURCES: [&str; 4] = [
+            "'use strict';\nimport a from 'a';\nlet x = a / 2; /* c */\nconst r = /re/g.test(`${x}t`);\nfunction f(p) {\n  // c\n  if (p) { return p; }\n  return x;\n}\nexport class C { m() { return f(1); } }\n",
+            "async function* g() {\n  yield await 1;\n  let y = <div>{x}</div>;\n  label: for (;;) break label;\n}\nvar o = { a, b: [1, 2], ...c };\nx = y\n(z)\n++i\n",
+            "#!/usr/bin/env node\nfunction h() {\n  'use strict';\n  let s = `a${`b${c}`}`;\n  return () => s;\n}\nh();\n",
+            "a\n/b/g\nc = d / e / f\ntry { g() } catch { h() } finally { i }\nswitch (j) { case 1: k; default: l }\n",
+        ];
+        const TEXTS: [&str; 26] = [
+            "",
+            "a",
+            "1",
+            " ",
+            "\n",
+            ";",
+            "{",
+            "}",
+            "(",
+            ")",
+            "`",
+            "${",
+            "/*",
+            "*/",
+            "//",
+            "/",
+            "'",
+            "+ 1",
+            "x;\n",
+            "{}",
+            "function k() {",
+            "return 1;",
+            "<div>",
+            "let",
+            "/* c */",
+            "'use strict';",
+        ];
+
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = |n: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % n as u64) as usize
+        };
+
+        let allocator = Allocator::default();
+        for original in SOURCES {
+            // Start over from time to time, before the source is only strings and comments
+            for _ in 0..20 {
+                let mut source = original.to_string();
+                let mut previous = parse(&allocator, allocator.alloc_str(&source));
+                for _ in 0..50 {
+                    let start = random(source.len() + 1);
+                    let end = (start + random(4)).min(source.len());
+                    let text = TEXTS[random(TEXTS.len())];
+                    let edit = TextEdit { span: Span::new(start as u32, end as u32), text };
+                    let ret = reparse(&allocator, previous, &source, edit);
+                    // Keep editing a valid source, a previous parse with errors is never re-used
+                    if ret.errors.is_empty() {
+                        source.replace_range(start..end, text);
+                        previous = ret;
+                    } else {
+                        previous = parse(&allocator, allocator.alloc_str(&source));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -19,7 +19,7 @@ impl<'a> Parser<'a> {
             Kind::LBrack => self.parse_array_binding_pattern(),
             _ => self.parse_binding_pattern_identifier(),
         }?;
-        if self.ts_enabled() {
+        if self.types_enabled() {
             let optional = self.eat(Kind::Question);
             let (type_annotation, definite) = self.parse_ts_variable_annotation()?;
             Ok((self.ast.binding_pattern(kind, type_annotation, optional), definite))
@@ -2,8 +2,8 @@ use oxc_allocator::{Box, Vec};
 use oxc_ast::{ast::*, context::StatementContext, syntax_directed_operations::PropName, Span};
 use oxc_diagnostics::Result;
 
-use super::list::ClassElements;
-use crate::{diagnostics, lexer::Kind, list::NormalList, Parser};
+use super::{function::FunctionKind, list::ClassElements};
+use crate::{diagnostics, lexer::Kind, list::NormalList, DecoratorFlavor, Parser};
 
 type Extends<'a> =
     Vec<'a, (Expression<'a>, Option<Box<'a, TSTypeParameterInstantiation<'a>>>, Span)>;
@@ -12,6 +12,105 @@ type Implements<'a> = Vec<'a, Box<'a, TSClassImplements<'a>>>;
 
 /// Section 15.7 Class Definitions
 impl<'a> Parser<'a> {
+    /// Eat the decorators of a class declaration, reporting them when they do not decorate a class
+    pub fn eat_class_decorators(&mut self) -> Result<()> {
+        self.eat_decorators()?;
+        if !self.state.decorators.is_empty() && !self.at_decorated_class() {
+            self.report_decorators();
+        }
+        Ok(())
+    }
+
+    /// `export @dec class` and `export default @dec class`, which are standard decorators
+    pub fn eat_decorators_after_export(&mut self) -> Result<()> {
+        if !self.at(Kind::At) {
+            return Ok(());
+        }
+        let decorated_before_export = !self.state.decorators.is_empty();
+        let span = self.start_span();
+        self.eat_decorators()?;
+        let span = self.end_span(span);
+        if decorated_before_export {
+            self.error(diagnostics::DecoratorsBeforeAndAfterExport(span));
+        } else if self.decorators == DecoratorFlavor::Legacy {
+            self.error(diagnostics::DecoratorsAfterExport(span));
+        }
+        if !self.at_decorated_class() {
+            self.report_decorators();
+        }
+        Ok(())
+    }
+
+    /// Whether the decorators before the current token decorate a class,
+    /// possibly through `export`, `export default` and TypeScript modifiers
+    fn at_decorated_class(&mut self) -> bool {
+        self.lookahead(|p| {
+            if p.eat(Kind::Export) {
+                p.bump(Kind::Default);
+                // decorators after `export` are checked once they are eaten
+                if p.at(Kind::At) {
+                    return true;
+                }
+            }
+            if p.ts_enabled() {
+                while matches!(p.cur_kind(), Kind::Abstract | Kind::Declare) {
+                    p.bump_any();
+                }
+            }
+            p.at(Kind::Class)
+        })
+    }
+
+    /// Consume misplaced decorators with an error
+    pub fn report_decorators(&mut self) {
+        let decorators = self.state.consume_decorators();
+        self.check_no_decorators(&decorators);
+    }
+
+    fn check_no_decorators(&mut self, decorators: &[Decorator<'a>]) {
+        if let Some(span) = decorators_span(decorators) {
+            self.error(diagnostics::DecoratorsNotValidHere(span));
+        }
+    }
+
+    /// Parameter decorators are only valid on class methods with legacy decorators
+    pub fn check_parameter_decorators(&mut self, params: &FormalParameters<'a>) {
+        for param in &params.items {
+            if let Some(span) = decorators_span(&param.decorators) {
+                self.error(diagnostics::ParameterDecorators(span));
+            }
+        }
+    }
+
+    /// Legacy decorators cannot decorate class expressions or their members
+    fn check_legacy_class_expression(&mut self, body: &ClassBody<'a>) {
+        for element in &body.body {
+            match element {
+                ClassElement::MethodDefinition(method) => {
+                    self.check_no_decorators(&method.decorators);
+                    for param in &method.value.params.items {
+                        self.check_no_decorators(&param.decorators);
+                    }
+                }
+                ClassElement::TSAbstractMethodDefinition(method) => {
+                    self.check_no_decorators(&method.method_definition.decorators);
+                }
+                ClassElement::PropertyDefinition(property) => {
+                    self.check_no_decorators(&property.decorators);
+                }
+                ClassElement::TSAbstractPropertyDefinition(property) => {
+                    self.check_no_decorators(&property.property_definition.decorators);
+                }
+                ClassElement::AccessorProperty(property) => {
+                    self.check_no_decorators(&property.decorators);
+                }
+                ClassElement::StaticBlock(_)
+                | ClassElement::TSIndexSignature(_)
+                | ClassElement::InvalidClassElement(_) => {}
+            }
+        }
+    }
+
     // `start_span` points at the start of all decoractors and `class` keyword.
     pub fn parse_class_statement(
         &mut self,
@@ -48,6 +147,18 @@ impl<'a> Parser<'a> {
         Ok(self.ast.class_expression(class))
     }
 
+    /// `@dec class {}`, only standard decorators can decorate class expressions
+    pub fn parse_decorated_class_expression(&mut self) -> Result<Expression<'a>> {
+        self.eat_decorators()?;
+        if !self.at(Kind::Class) {
+            return self.unexpected();
+        }
+        if self.decorators == DecoratorFlavor::Legacy {
+            self.report_decorators();
+        }
+        self.parse_class_expression()
+    }
+
     fn parse_class(
         &mut self,
         start_span: Span,
@@ -59,6 +170,10 @@ impl<'a> Parser<'a> {
         let decorators = self.state.consume_decorators();
         let start_span = decorators.iter().next().map_or(start_span, |d| d.span);
 
+        // All parts of a class are strict mode code
+        let has_strict = self.ctx.has_strict();
+        self.ctx = self.ctx.and_strict(true);
+
         let id = if self.cur_kind().is_binding_identifier() && !self.at(Kind::Implements) {
             Some(self.parse_binding_identifier()?)
         } else {
@@ -66,7 +181,7 @@ impl<'a> Parser<'a> {
         };
 
         let type_parameters =
-            if self.ts_enabled() { self.parse_ts_type_parameters()? } else { None };
+            if self.types_enabled() { self.parse_ts_type_parameters()? } else { None };
         let (extends, implements) = self.parse_heritage_clause()?;
         let mut super_class = None;
         let mut super_type_parameters = None;
@@ -78,6 +193,11 @@ impl<'a> Parser<'a> {
             }
         }
         let body = self.parse_class_body()?;
+        self.ctx = self.ctx.and_strict(has_strict);
+
+        if r#type == ClassType::ClassExpression && self.decorators == DecoratorFlavor::Legacy {
+            self.check_legacy_class_expression(&body);
+        }
 
         Ok(self.ast.class(
             r#type,
@@ -163,6 +283,8 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
 
         self.eat_decorators()?;
+        // taken right away, a class expression in the key or the value has decorators of its own
+        let decorators = self.state.consume_decorators();
 
         let mut kind = MethodDefinitionKind::Method;
         let mut r#async = false;
@@ -172,13 +294,6 @@ impl<'a> Parser<'a> {
 
         let modifier = self.parse_class_element_modifiers(false);
 
-        let accessor = matches!(
-            self.peek_kind(),
-            // js can use [prop] or "prop" to define a property,
-            // so we need to check `LBrack` and `Str`
-            Kind::Ident | Kind::PrivateIdentifier | Kind::LBrack | Kind::Str
-        ) && self.eat(Kind::Accessor);
-
         let accessibility = modifier.accessibility();
 
         let declare = modifier.declare();
@@ -190,6 +305,7 @@ impl<'a> Parser<'a> {
         if self.at(Kind::Static) {
             // static { block }
             if self.peek_at(Kind::LCurly) {
+                self.check_no_decorators(&decorators);
                 self.bump(Kind::Static);
                 return self.parse_class_static_block(span);
             }
@@ -203,6 +319,16 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // accessor ..., after `static`
+        let accessor = key_name.is_none()
+            && matches!(
+                self.peek_kind(),
+                // js can use [prop] or "prop" to define a property,
+                // so we need to check `LBrack` and `Str`
+                Kind::Ident | Kind::PrivateIdentifier | Kind::LBrack | Kind::Str
+            )
+            && self.eat(Kind::Accessor);
+
         // async ...
         if key_name.is_none() && self.at(Kind::Async) && !self.peek_at(Kind::Question) {
             if self.peek_kind().is_class_element_name_start() || self.peek_at(Kind::Star) {
@@ -214,6 +340,7 @@ impl<'a> Parser<'a> {
         }
 
         if self.is_at_ts_index_signature_member() {
+            self.check_no_decorators(&decorators);
             if let TSSignature::TSIndexSignature(sig) = self.parse_ts_index_signature_member()? {
                 return Ok(ClassElement::TSIndexSignature(sig));
             }
@@ -255,12 +382,19 @@ impl<'a> Parser<'a> {
             if private_ident.name == "constructor" {
                 self.error(diagnostics::PrivateNameConstructor(private_ident.span));
             }
+            if self.decorators == DecoratorFlavor::Legacy {
+                self.check_no_decorators(&decorators);
+            }
         }
 
         if accessor {
+            if self.decorators == DecoratorFlavor::Legacy {
+                self.check_no_decorators(&decorators);
+            }
+
             self.parse_ts_type_annotation()?;
 
-            return self.parse_class_accessor_property(span, key, computed, r#static);
+            return self.parse_class_accessor_property(span, key, computed, r#static, decorators);
         }
 
         // LAngle for start of type parameters `foo<T>`
@@ -278,6 +412,7 @@ impl<'a> Parser<'a> {
                 r#abstract,
                 accessibility,
                 optional,
+                decorators,
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if r#static && name == "prototype" {
@@ -309,6 +444,7 @@ impl<'a> Parser<'a> {
                 accessibility,
                 optional,
                 definite,
+                decorators,
             )?;
             if let Some((name, span)) = definition.prop_name() {
                 if name == "constructor" {
@@ -346,6 +482,7 @@ impl<'a> Parser<'a> {
         r#abstract: bool,
         accessibility: Option<TSAccessibility>,
         optional: bool,
+        mut decorators: Vec<'a, Decorator<'a>>,
     ) -> Result<ClassElement<'a>> {
         let kind = if !r#static
             && !computed
@@ -356,9 +493,23 @@ impl<'a> Parser<'a> {
             kind
         };
 
-        let decorators = self.state.consume_decorators();
+        if kind == MethodDefinitionKind::Constructor {
+            self.check_no_decorators(&decorators);
+            decorators.clear();
+        }
 
-        let value = self.parse_method(r#async, generator)?;
+        let value = self.parse_function(
+            self.start_span(),
+            None,
+            r#async,
+            generator,
+            FunctionKind::ClassMethod,
+            Modifiers::empty(),
+        )?;
+
+        if self.decorators == DecoratorFlavor::Standard {
+            self.check_parameter_decorators(&value.params);
+        }
 
         if kind == MethodDefinitionKind::Get && !value.params.is_empty() {
             self.error(diagnostics::GetterParameters(value.params.span));
@@ -413,9 +564,10 @@ impl<'a> Parser<'a> {
         accessibility: Option<TSAccessibility>,
         optional: bool,
         definite: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> Result<ClassElement<'a>> {
         let type_annotation =
-            if self.ts_enabled() { self.parse_ts_type_annotation()? } else { None };
+            if self.types_enabled() { self.parse_ts_type_annotation()? } else { None };
         let value = if self.eat(Kind::Eq) {
             // let current_flags = self.scope.current_flags();
             // self.scope.set_current_flags(self.scope.current_flags());
@@ -440,7 +592,7 @@ impl<'a> Parser<'a> {
             accessibility,
             optional,
             definite,
-            decorators: self.state.consume_decorators(),
+            decorators,
         };
 
         if r#abstract {
@@ -471,9 +623,22 @@ impl<'a> Parser<'a> {
         key: PropertyKey<'a>,
         computed: bool,
         r#static: bool,
+        decorators: Vec<'a, Decorator<'a>>,
     ) -> Result<ClassElement<'a>> {
         let value =
             self.eat(Kind::Eq).then(|| self.parse_assignment_expression_base()).transpose()?;
-        Ok(self.ast.accessor_property(self.end_span(span), key, value, computed, r#static))
+        Ok(self.ast.accessor_property(
+            self.end_span(span),
+            key,
+            value,
+            computed,
+            r#static,
+            decorators,
+        ))
     }
 }
+
+fn decorators_span(decorators: &[Decorator]) -> Option<Span> {
+    let (first, last) = (decorators.first()?, decorators.last()?);
+    Some(Span::new(first.span.start, last.span.end))
+}
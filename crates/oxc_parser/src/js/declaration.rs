@@ -41,6 +41,28 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// `UsingDeclaration` :
+    ///     using [no `LineTerminator` here] `BindingList`
+    /// `AwaitUsingDeclaration` :
+    ///     await [no `LineTerminator` here] using [no `LineTerminator` here] `BindingList`
+    pub fn is_at_using_declaration(&mut self) -> bool {
+        let n = match self.cur_kind() {
+            Kind::Using if !self.cur_token().escaped => 1,
+            Kind::Await
+                if self.ctx.has_await()
+                    && !self.cur_token().escaped
+                    && self.peek_at(Kind::Using)
+                    && !self.peek_token().escaped
+                    && !self.peek_token().is_on_new_line =>
+            {
+                2
+            }
+            _ => return false,
+        };
+        let token = self.nth(n);
+        token.kind.is_binding_identifier() && !token.is_on_new_line
+    }
+
     pub fn parse_variable_declaration(
         &mut self,
         start_span: Span,
@@ -51,6 +73,11 @@ impl<'a> Parser<'a> {
             Kind::Var => VariableDeclarationKind::Var,
             Kind::Const => VariableDeclarationKind::Const,
             Kind::Let => VariableDeclarationKind::Let,
+            Kind::Using => VariableDeclarationKind::Using,
+            Kind::Await => {
+                self.bump_any(); // bump `await`, `using` is bumped below
+                VariableDeclarationKind::AwaitUsing
+            }
             _ => return self.unexpected(),
         };
         self.bump_any();
@@ -86,6 +113,10 @@ impl<'a> Parser<'a> {
         let init =
             self.eat(Kind::Eq).then(|| self.parse_assignment_expression_base()).transpose()?;
 
+        if kind.is_using() && !matches!(id.kind, BindingPatternKind::BindingIdentifier(_)) {
+            self.error(diagnostics::UsingDeclarationBindingPattern(id.span()));
+        }
+
         if init.is_none() && decl_ctx.parent == VariableDeclarationParent::Statement {
             // LexicalBinding[In, Yield, Await] :
             //   BindingIdentifier[?Yield, ?Await] Initializer[?In, ?Yield, ?Await] opt
@@ -96,6 +127,8 @@ impl<'a> Parser<'a> {
             } else if kind == VariableDeclarationKind::Const && !self.ctx.has_ambient() {
                 // It is a Syntax Error if Initializer is not present and IsConstantDeclaration of the LexicalDeclaration containing this LexicalBinding is true.
                 self.error(diagnostics::MissinginitializerInConst(id.span()));
+            } else if kind.is_using() {
+                self.error(diagnostics::MissingInitializerInUsing(id.span()));
             }
         }
 
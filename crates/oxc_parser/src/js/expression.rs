@@ -152,6 +152,7 @@ impl<'a> Parser<'a> {
             Kind::Function => self.parse_function_expression(span, false),
             // ClassExpression
             Kind::Class => self.parse_class_expression(),
+            Kind::At => self.parse_decorated_class_expression(),
             // This
             Kind::This => Ok(self.parse_this_expression()),
             // TemplateLiteral
@@ -183,9 +184,11 @@ impl<'a> Parser<'a> {
 
     fn parse_parenthesized_expression(&mut self, span: Span) -> Result<Expression<'a>> {
         let has_in = self.ctx.has_in();
-        self.ctx = self.ctx.and_in(true);
+        // `@(a[b])` decorators can use any expression in parentheses
+        let has_decorator = self.ctx.has_decorator();
+        self.ctx = self.ctx.and_in(true).and_decorator(false);
         let list = SequenceExpressionList::parse(self)?;
-        self.ctx = self.ctx.and_in(has_in);
+        self.ctx = self.ctx.and_in(has_in).and_decorator(has_decorator);
 
         let mut expressions = list.elements;
 
@@ -268,7 +271,20 @@ impl<'a> Parser<'a> {
         let value = self.cur_token().value.as_number();
         let raw = self.cur_src();
         self.bump_any();
-        Ok(NumberLiteral::new(self.end_span(span), value, raw, base))
+        let span = self.end_span(span);
+        // Annex B legacy octal literals `010` and decimals with a leading zero `08`
+        if self.ctx.has_strict()
+            && raw.len() > 1
+            && raw.starts_with('0')
+            && raw.as_bytes()[1].is_ascii_digit()
+        {
+            if base == NumberBase::Octal {
+                self.error(diagnostics::LegacyOctal(span));
+            } else {
+                self.error(diagnostics::LeadingZeroDecimal(span));
+            }
+        }
+        Ok(NumberLiteral::new(span, value, raw, base))
     }
 
     pub fn parse_literal_bigint(&mut self) -> Result<BigintLiteral> {
@@ -288,6 +304,12 @@ impl<'a> Parser<'a> {
             _ => return self.unexpected(),
         };
         self.bump_any();
+        // The pattern starts after the opening `/`
+        let pattern =
+            oxc_regexp::Parser::new(self.ast.allocator, &r.pattern, r.flags, span.start + 1);
+        if let Err(error) = pattern.parse() {
+            self.error(error);
+        }
         Ok(RegExpLiteral {
             span: self.end_span(span),
             value: EmptyObject {},
@@ -304,7 +326,34 @@ impl<'a> Parser<'a> {
         };
         let span = self.start_span();
         self.bump_any();
-        Ok(StringLiteral { span: self.end_span(span), value })
+        let span = self.end_span(span);
+        if self.ctx.has_strict() {
+            self.check_legacy_escapes(span);
+        }
+        Ok(StringLiteral { span, value })
+    }
+
+    /// Annex B `LegacyOctalEscapeSequence` `"\07"` and `NonOctalDecimalEscapeSequence` `"\8"`
+    /// of the string literal at `span`, which are not allowed in strict mode code
+    pub fn check_legacy_escapes(&mut self, span: Span) {
+        let bytes = &self.source.as_bytes()[span.start as usize..span.end as usize];
+        let mut i = 0;
+        while i + 1 < bytes.len() {
+            if bytes[i] != b'\\' {
+                i += 1;
+                continue;
+            }
+            #[allow(clippy::cast_possible_truncation)]
+            let escape = Span::new(span.start + i as u32, span.start + i as u32 + 2);
+            match (bytes[i + 1], bytes.get(i + 2)) {
+                (b'0', Some(b'0'..=b'9')) | (b'1'..=b'7', _) => {
+                    self.error(diagnostics::LegacyOctalEscape(escape));
+                }
+                (b'8' | b'9', _) => self.error(diagnostics::NonOctalDecimalEscapeSequence(escape)),
+                _ => {}
+            }
+            i += 2;
+        }
     }
 
     /// Section Array Expression `https://tc39.es/ecma262/#prod-ArrayLiteral`
@@ -520,7 +569,7 @@ impl<'a> Parser<'a> {
                     self.bump_any();
                     self.ast.ts_non_null_expression(self.end_span(lhs_span), lhs)
                 }
-                Kind::LAngle | Kind::ShiftLeft if self.ts_enabled() => {
+                Kind::LAngle | Kind::ShiftLeft if self.types_enabled() => {
                     if let Some(arguments) = self.parse_ts_type_arguments_in_expression()? {
                         lhs = Expression::TSInstantiationExpression(self.ast.alloc(
                             TSInstantiationExpression {
@@ -655,7 +704,7 @@ impl<'a> Parser<'a> {
                         type_arguments.take(),
                     )?;
                 }
-                Kind::LAngle | Kind::ShiftLeft if self.ts_enabled() => {
+                Kind::LAngle | Kind::ShiftLeft if self.types_enabled() => {
                     let result = self.try_parse(|p| {
                         let arguments = p.parse_ts_type_arguments()?;
                         if p.at(Kind::RAngle) {
@@ -684,6 +733,16 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // `f<T>` without a call is an instantiation expression
+        if let Some(type_parameters) = type_arguments {
+            lhs =
+                Expression::TSInstantiationExpression(self.ast.alloc(TSInstantiationExpression {
+                    span: self.end_span(lhs_span),
+                    expression: lhs,
+                    type_parameters,
+                }));
+        }
+
         Ok(lhs)
     }
 
@@ -807,7 +866,7 @@ impl<'a> Parser<'a> {
             // RelationalExpression[In, Yield, Await] :
             // [+In] RelationalExpression[+In, ?Yield, ?Await] in ShiftExpression[?Yield, ?Await]
             if kind == Kind::In && !self.ctx.has_in()
-                || (kind == Kind::As && self.cur_token().is_on_new_line)
+                || (kind == Kind::As && (!self.ts_enabled() || self.cur_token().is_on_new_line))
             {
                 break;
             }
@@ -30,6 +30,8 @@ pub enum FunctionKind {
     Expression,
     DefaultExport,
     TSDeclaration,
+    /// Class methods and constructors, the only functions with parameter decorators
+    ClassMethod,
 }
 
 impl FunctionKind {
@@ -64,16 +66,19 @@ impl<'a> Parser<'a> {
         if save_decorator_context {
             self.ctx = self.ctx.and_decorator(false);
         }
+        // A "use strict" directive only applies to this function
+        let has_strict = self.ctx.has_strict();
 
         let (directives, statements) = self.with_context(Context::Return, |p| {
             p.parse_directives_and_statements(/* is_top_level */ false)
-        })?;
+        });
 
         if save_decorator_context {
             self.ctx = self.ctx.and_decorator(true);
         }
+        self.ctx = self.ctx.and_strict(has_strict);
 
-        self.expect(Kind::RCurly)?;
+        self.expect_closing(Kind::RCurly)?;
         Ok(self.ast.function_body(self.end_span(span), directives, statements))
     }
 
@@ -102,6 +107,9 @@ impl<'a> Parser<'a> {
         let type_parameters = self.parse_ts_type_parameters()?;
 
         let params = self.parse_formal_parameters(FormalParameterKind::FormalParameter)?;
+        if func_kind != FunctionKind::ClassMethod {
+            self.check_parameter_decorators(&params);
+        }
 
         let return_type = self.parse_ts_return_type_annotation()?;
 
@@ -120,7 +128,9 @@ impl<'a> Parser<'a> {
                 FunctionKind::Declaration { .. } | FunctionKind::DefaultExport => {
                     FunctionType::FunctionDeclaration
                 }
-                FunctionKind::Expression { .. } => FunctionType::FunctionExpression,
+                FunctionKind::Expression { .. } | FunctionKind::ClassMethod => {
+                    FunctionType::FunctionExpression
+                }
                 FunctionKind::TSDeclaration { .. } => FunctionType::TSDeclareFunction,
             }
         };
@@ -153,16 +163,20 @@ impl<'a> Parser<'a> {
             FunctionKind::Declaration { single_statement: stmt_ctx.is_single_statement() };
         let decl = self.parse_function_impl(func_kind)?;
         if stmt_ctx.is_single_statement() {
+            let span = Span::new(decl.span.start, decl.params.span.end);
             if decl.r#async {
-                self.error(diagnostics::AsyncFunctionDeclaration(Span::new(
-                    decl.span.start,
-                    decl.params.span.end,
-                )));
+                self.error(diagnostics::AsyncFunctionDeclaration(span));
             } else if decl.generator {
-                self.error(diagnostics::GeneratorFunctionDeclaration(Span::new(
-                    decl.span.start,
-                    decl.params.span.end,
-                )));
+                self.error(diagnostics::GeneratorFunctionDeclaration(span));
+            } else if self.ctx.has_strict() {
+                if stmt_ctx == StatementContext::Label {
+                    self.error(diagnostics::FunctionsCannotBeLabelled(span));
+                } else {
+                    self.error(diagnostics::FunctionDeclarationStrict(span));
+                }
+            // Annex B `if (a) function f() {}` and labelled functions `l: function f() {}`
+            } else if !matches!(stmt_ctx, StatementContext::If | StatementContext::Label) {
+                self.error(diagnostics::FunctionDeclarationNonStrict(span));
             }
         }
 
@@ -240,6 +240,7 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     fn parse_element(&mut self, p: &mut Parser<'a>) -> Result<()> {
         let span = p.start_span();
         p.eat_decorators()?;
+        let decorators = p.state.consume_decorators();
 
         let modifiers = p.parse_class_element_modifiers(true);
         let accessibility = modifiers.accessibility();
@@ -247,7 +248,7 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
 
         let pattern = match p.cur_kind() {
             Kind::Dot3 => p.parse_rest_element().map(|rest| p.ast.rest_element_pattern(rest))?,
-            Kind::This if p.ts_enabled() => {
+            Kind::This if p.types_enabled() => {
                 p.parse_ts_this_parameter()?;
                 // don't add this to ast fow now, the ast span shouldn't be in BindingIdentifier
                 return Ok(());
@@ -255,7 +256,6 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
             _ => p.parse_binding_element()?,
         };
 
-        let decorators = p.state.consume_decorators();
         let formal_parameter =
             p.ast.formal_parameter(p.end_span(span), pattern, accessibility, readonly, decorators);
         self.elements.push(formal_parameter);
@@ -264,14 +264,14 @@ impl<'a> SeparatedList<'a> for FormalParameterList<'a> {
     }
 }
 
-/// Assert Entries
-/// `https://tc39.es/proposal-import-assertions`
-pub struct AssertEntries<'a> {
+/// Import Attributes
+/// `https://tc39.es/proposal-import-attributes`
+pub struct ImportAttributeList<'a> {
     pub elements: Vec<'a, ImportAttribute>,
     keys: FxHashMap<Atom, Span>,
 }
 
-impl<'a> SeparatedList<'a> for AssertEntries<'a> {
+impl<'a> SeparatedList<'a> for ImportAttributeList<'a> {
     fn new(p: &Parser<'a>) -> Self {
         Self { elements: p.ast.new_vec(), keys: FxHashMap::default() }
     }
@@ -292,7 +292,7 @@ impl<'a> SeparatedList<'a> for AssertEntries<'a> {
         };
 
         if let Some(old_span) = self.keys.get(&key.as_atom()) {
-            p.error(diagnostics::Redeclaration(key.as_atom(), *old_span, key.span()));
+            p.error(diagnostics::DuplicateImportAttribute(key.as_atom(), *old_span, key.span()));
         } else {
             self.keys.insert(key.as_atom(), key.span());
         }
@@ -332,7 +332,7 @@ impl<'a> SeparatedList<'a> for ExportNamedSpecifiers<'a> {
         // export { type as as }       // name: `type`    type-export: `false` (aliased to `as`)
         // export { type as as as }    // name: `as`      type-export: `true`, aliased to `as`
         let mut export_kind = ImportOrExportKind::Value;
-        if p.ts_enabled() && p.at(Kind::Type) {
+        if p.types_enabled() && p.at(Kind::Type) {
             if p.peek_at(Kind::As) {
                 if p.nth_at(2, Kind::As) {
                     if p.nth_at(3, Kind::Str) || p.nth_kind(3).is_identifier_name() {
@@ -352,7 +352,8 @@ impl<'a> SeparatedList<'a> for ExportNamedSpecifiers<'a> {
 
         let local = p.parse_module_export_name()?;
         let exported = if p.eat(Kind::As) { p.parse_module_export_name()? } else { local.clone() };
-        let element = ExportSpecifier { span: p.end_span(specifier_span), local, exported };
+        let element =
+            ExportSpecifier { span: p.end_span(specifier_span), local, exported, export_kind };
         self.elements.push(element);
         Ok(())
     }
@@ -437,7 +438,7 @@ impl<'a> NormalList<'a> for ClassElements<'a> {
         if p.at(self.close()) {
             return Ok(());
         }
-        let element = p.parse_class_element()?;
+        let element = p.parse_class_element_or_recover();
 
         if let Some(private_ident) = element.private_bound_identifiers() {
             self.on_declare_private_property(
@@ -3,7 +3,7 @@ use oxc_ast::{ast::*, context::Context, Span};
 use oxc_diagnostics::Result;
 
 use super::function::FunctionKind;
-use super::list::{AssertEntries, ExportNamedSpecifiers};
+use super::list::{ExportNamedSpecifiers, ImportAttributeList};
 use crate::{diagnostics, lexer::Kind, list::SeparatedList, Parser};
 
 impl<'a> Parser<'a> {
@@ -55,14 +55,14 @@ impl<'a> Parser<'a> {
         };
 
         let source = self.parse_literal_string()?;
-        let assertions = self.parse_import_attributes()?;
+        let with_clause = self.parse_import_attributes()?;
         self.asi()?;
 
         let span = self.end_span(span);
         let kind = ModuleDeclarationKind::ImportDeclaration(self.ast.import_declaration(
             specifiers,
             source,
-            assertions,
+            with_clause,
             import_kind,
         ));
 
@@ -148,20 +148,27 @@ impl<'a> Parser<'a> {
         Ok(specifiers)
     }
 
-    // import assertion
-    // https://tc39.es/proposal-import-assertions
-    fn parse_import_attributes(&mut self) -> Result<Option<Vec<'a, ImportAttribute>>> {
-        if !self.at(Kind::Assert) || self.cur_token().is_on_new_line {
-            return Ok(None);
-        }
+    // WithClause :
+    //   with { }
+    //   with { WithEntries ,opt }
+    // https://tc39.es/proposal-import-attributes
+    // `assert` is the keyword of the earlier import assertions proposal,
+    // it is not allowed after a line terminator.
+    fn parse_import_attributes(&mut self) -> Result<Option<WithClause<'a>>> {
+        let keyword = match self.cur_kind() {
+            Kind::With => ImportAttributesKeyword::With,
+            Kind::Assert if !self.cur_token().is_on_new_line => ImportAttributesKeyword::Assert,
+            _ => return Ok(None),
+        };
+        let span = self.start_span();
         self.bump_any();
 
         let ctx = self.ctx;
         self.ctx = Context::default();
-        let entries = AssertEntries::parse(self)?.elements;
+        let attributes = ImportAttributeList::parse(self)?.elements;
         self.ctx = ctx;
 
-        Ok(Some(entries))
+        Ok(Some(WithClause { span: self.end_span(span), keyword, attributes }))
     }
 
     pub fn parse_ts_export_assignment_declaration(
@@ -209,7 +216,7 @@ impl<'a> Parser<'a> {
             Kind::LCurly => self
                 .parse_export_named_specifiers()
                 .map(ModuleDeclarationKind::ExportNamedDeclaration),
-            Kind::Type if self.peek_at(Kind::LCurly) && self.ts_enabled() => self
+            Kind::Type if self.peek_at(Kind::LCurly) && self.types_enabled() => self
                 .parse_export_named_specifiers()
                 .map(ModuleDeclarationKind::ExportNamedDeclaration),
             Kind::Type if self.peek_at(Kind::Star) => {
@@ -247,6 +254,7 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let with_clause = if source.is_some() { self.parse_import_attributes()? } else { None };
 
         // ExportDeclaration : export NamedExports ;
         // * It is a Syntax Error if ReferencedBindings of NamedExports contains any StringLiterals.
@@ -263,7 +271,7 @@ impl<'a> Parser<'a> {
         }
 
         self.asi()?;
-        Ok(self.ast.export_named_declaration(None, specifiers, source, export_kind))
+        Ok(self.ast.export_named_declaration(None, specifiers, source, with_clause, export_kind))
     }
 
     // export Declaration
@@ -271,7 +279,7 @@ impl<'a> Parser<'a> {
         let start_span = self.start_span();
         // For tc39/proposal-decorators
         // For more information, please refer to https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport
-        self.eat_decorators()?;
+        self.eat_decorators_after_export()?;
         let modifiers = if self.ts_enabled() {
             self.eat_modifiers_before_declaration().1
         } else {
@@ -279,7 +287,13 @@ impl<'a> Parser<'a> {
         };
 
         let declaration = self.parse_declaration(start_span, modifiers)?;
-        Ok(self.ast.export_named_declaration(Some(declaration), self.ast.new_vec(), None, None))
+        Ok(self.ast.export_named_declaration(
+            Some(declaration),
+            self.ast.new_vec(),
+            None,
+            None,
+            None,
+        ))
     }
 
     // export default HoistableDeclaration[~Yield, +Await, +Default]
@@ -292,7 +306,7 @@ impl<'a> Parser<'a> {
         let start_span = self.start_span();
         // For tc39/proposal-decorators
         // For more information, please refer to https://babeljs.io/docs/babel-plugin-proposal-decorators#decoratorsbeforeexport
-        self.eat_decorators()?;
+        self.eat_decorators_after_export()?;
         let declaration = match self.cur_kind() {
             Kind::Class => self
                 .parse_class_declaration(start_span, /* modifiers */ Modifiers::empty())
@@ -342,9 +356,9 @@ impl<'a> Parser<'a> {
         let exported = self.eat(Kind::As).then(|| self.parse_module_export_name()).transpose()?;
         self.expect(Kind::From)?;
         let source = self.parse_literal_string()?;
-        let assertions = self.parse_import_attributes()?;
+        let with_clause = self.parse_import_attributes()?;
         self.asi()?;
-        Ok(self.ast.export_all_declaration(exported, source, assertions, export_kind))
+        Ok(self.ast.export_all_declaration(exported, source, with_clause, export_kind))
     }
 
     // ImportSpecifier :
@@ -354,7 +368,7 @@ impl<'a> Parser<'a> {
         let specifier_span = self.start_span();
         let peek_kind = self.peek_kind();
         let mut import_kind = ImportOrExportKind::Value;
-        if self.ts_enabled() && self.at(Kind::Type) {
+        if self.types_enabled() && self.at(Kind::Type) {
             if self.peek_at(Kind::As) {
                 if self.nth_at(2, Kind::As) {
                     if self.nth_kind(3).is_identifier_name() {
@@ -381,7 +395,7 @@ impl<'a> Parser<'a> {
             let imported = IdentifierName { span: local.span, name: local.name.clone() };
             (ModuleExportName::Identifier(imported), local)
         };
-        Ok(ImportSpecifier { span: self.end_span(specifier_span), imported, local })
+        Ok(ImportSpecifier { span: self.end_span(specifier_span), imported, local, import_kind })
     }
 
     // ModuleExportName :
@@ -403,7 +417,7 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_import_or_export_kind(&mut self) -> Option<ImportOrExportKind> {
-        if !self.ts_enabled() {
+        if !self.types_enabled() {
             return None;
         }
 
@@ -21,52 +21,74 @@ impl<'a> Parser<'a> {
     pub fn parse_directives_and_statements(
         &mut self,
         is_top_level: bool,
-    ) -> Result<(Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>)> {
+    ) -> (Vec<'a, Directive<'a>>, Vec<'a, Statement<'a>>) {
         let mut directives = self.ast.new_vec();
         let mut statements = self.ast.new_vec();
 
         let mut expecting_diretives = true;
         while !self.at(Kind::Eof) {
-            match self.cur_kind() {
-                Kind::RCurly if !is_top_level => break,
-                Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
-                    let stmt = self.parse_import_declaration()?;
-                    statements.push(stmt);
-                }
-                Kind::Export => {
-                    let stmt = self.parse_export_declaration()?;
-                    statements.push(stmt);
-                }
-                Kind::At => {
-                    self.eat_decorators()?;
-                    continue;
-                }
-                _ => {
-                    let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
-
-                    // Section 11.2.1 Directive Prologue
-                    // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
-                    // All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/main/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
-                    if expecting_diretives {
-                        if let Statement::ExpressionStatement(expr) = &stmt {
-                            if let Expression::StringLiteral(string) = &expr.expression {
-                                let src = &self.source
-                                    [string.span.start as usize + 1..string.span.end as usize - 1];
-                                let directive =
-                                    self.ast.directive(expr.span, (*string).clone(), src);
-                                directives.push(directive);
-                                continue;
+            if self.at(Kind::RCurly) && !is_top_level {
+                break;
+            }
+            let stmt = self.parse_statement_list_item_or_recover();
+
+            // Section 11.2.1 Directive Prologue
+            // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
+            // All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/main/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
+            if expecting_diretives {
+                if let Statement::ExpressionStatement(expr) = &stmt {
+                    if let Expression::StringLiteral(string) = &expr.expression {
+                        let src = &self.source
+                            [string.span.start as usize + 1..string.span.end as usize - 1];
+                        let directive = self.ast.directive(expr.span, (*string).clone(), src);
+                        // The directives before "use strict" are strict mode code as well
+                        if directive.is_use_strict() && !self.ctx.has_strict() {
+                            self.ctx = self.ctx.and_strict(true);
+                            let spans = directives
+                                .iter()
+                                .map(|directive: &Directive| directive.expression.span)
+                                .collect::<std::vec::Vec<_>>();
+                            for span in spans {
+                                self.check_legacy_escapes(span);
                             }
                         }
-                        expecting_diretives = false;
+                        directives.push(directive);
+                        continue;
                     }
-
-                    statements.push(stmt);
                 }
-            };
+                expecting_diretives = false;
+            }
+
+            if is_top_level {
+                self.check_top_level_statement(&stmt);
+            }
+
+            statements.push(stmt);
         }
 
-        Ok((directives, statements))
+        (directives, statements)
+    }
+
+    /// `using` declarations are not allowed at the top level of a script
+    pub fn check_top_level_statement(&mut self, stmt: &Statement<'a>) {
+        if let Statement::Declaration(Declaration::VariableDeclaration(decl)) = stmt {
+            if decl.kind.is_using() && !self.source_type.is_module() {
+                self.error(diagnostics::UsingDeclarationInScript(decl.span));
+            }
+        }
+    }
+
+    /// A statement of a statement list, including module items
+    pub fn parse_statement_list_item_or_recover(&mut self) -> Statement<'a> {
+        match self.cur_kind() {
+            Kind::Import if !matches!(self.peek_kind(), Kind::Dot | Kind::LParen) => {
+                self.parse_statement_or_recover(Self::parse_import_declaration)
+            }
+            Kind::Export => self.parse_statement_or_recover(Self::parse_export_declaration),
+            _ => self.parse_statement_or_recover(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            }),
+        }
     }
 
     /// `StatementListItem`[Yield, Await, Return] :
@@ -79,7 +101,7 @@ impl<'a> Parser<'a> {
         let start_span = self.start_span();
 
         if self.at(Kind::At) {
-            self.eat_decorators()?;
+            self.eat_class_decorators()?;
         }
 
         match self.cur_kind() {
@@ -108,15 +130,21 @@ impl<'a> Parser<'a> {
                 self.parse_variable_statement(stmt_ctx)
             }
             Kind::Let if !self.cur_token().escaped => self.parse_let(stmt_ctx),
+            Kind::Using | Kind::Await if self.is_at_using_declaration() => {
+                self.parse_variable_statement(stmt_ctx)
+            }
             _ if self.at_function_with_async() => self.parse_function_declaration(stmt_ctx),
-            _ if self.ts_enabled() && self.at_start_of_ts_declaration() => {
+            _ if self.types_enabled() && self.at_start_of_ts_declaration() => {
                 self.parse_ts_declaration_statement(start_span)
             }
-            _ => self.parse_expression_or_labeled_statment(),
+            _ => self.parse_expression_or_labeled_statment(stmt_ctx),
         }
     }
 
-    fn parse_expression_or_labeled_statment(&mut self) -> Result<Statement<'a>> {
+    fn parse_expression_or_labeled_statment(
+        &mut self,
+        stmt_ctx: StatementContext,
+    ) -> Result<Statement<'a>> {
         let span = self.start_span();
         let expr = self.parse_expression()?;
         if let Expression::Identifier(ident) = &expr {
@@ -125,22 +153,43 @@ impl<'a> Parser<'a> {
             if self.eat(Kind::Colon) {
                 let label = LabelIdentifier { span: ident.span, name: ident.name.clone() };
                 let body = self.parse_statement_list_item(StatementContext::Label)?;
+                if !matches!(stmt_ctx, StatementContext::StatementList | StatementContext::Label) {
+                    self.check_labelled_function(&body);
+                }
                 return Ok(self.ast.labeled_statement(self.end_span(span), label, body));
             }
         }
         self.parse_expression_statement(span, expr)
     }
 
+    /// Annex B labelled functions are only allowed where a declaration is,
+    /// e.g. not in `if (a) l: function f() {}` and `while (a) l: function f() {}`
+    fn check_labelled_function(&mut self, body: &Statement<'a>) {
+        let mut body = body;
+        while let Statement::LabeledStatement(stmt) = body {
+            body = &stmt.body;
+        }
+        if let Statement::Declaration(Declaration::FunctionDeclaration(func)) = body {
+            // Reported as a labelled function in strict mode code
+            if !self.ctx.has_strict() {
+                let span = Span::new(func.span.start, func.params.span.end);
+                self.error(diagnostics::FunctionDeclarationNonStrict(span));
+            }
+        }
+    }
+
     /// Section 14.2 Block Statement
     pub fn parse_block(&mut self) -> Result<Box<'a, BlockStatement<'a>>> {
         let span = self.start_span();
         self.expect(Kind::LCurly)?;
         let mut body = self.ast.new_vec();
         while !self.at(Kind::RCurly) && !self.at(Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_or_recover(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            });
             body.push(stmt);
         }
-        self.expect(Kind::RCurly)?;
+        self.expect_closing(Kind::RCurly)?;
         Ok(self.ast.block(self.end_span(span), body))
     }
 
@@ -233,11 +282,16 @@ impl<'a> Parser<'a> {
             return self.parse_for_loop(span, None, r#await);
         }
 
-        // for (let | for (const | for (var
-        // disallow for (let in ..)
+        // for (let | for (const | for (var | for (using | for (await using
+        // disallow for (let in ..), `for (using of x)` assigns to `using` while
+        // `for (using of of x)` and `for (using of = x;;)` declare `of`
+        let is_using_of = self.at(Kind::Using)
+            && self.peek_at(Kind::Of)
+            && !matches!(self.nth_kind(2), Kind::Of | Kind::Eq | Kind::Semicolon | Kind::Colon);
         if self.at(Kind::Const)
             || self.at(Kind::Var)
             || (self.at(Kind::Let) && self.peek_kind().is_after_let())
+            || (self.is_at_using_declaration() && !is_using_of)
         {
             let start_span = self.start_span();
             let init_declaration = self.without_context(Context::In, |p| {
@@ -250,6 +304,10 @@ impl<'a> Parser<'a> {
 
             let kind = self.cur_kind();
 
+            if kind == Kind::In && init_declaration.kind.is_using() {
+                self.error(diagnostics::UsingDeclarationInForIn(init_declaration.span));
+            }
+
             // for (.. a in) for (.. a of)
             if matches!(kind, Kind::In | Kind::Of) {
                 let init = ForStatementLeft::VariableDeclaration(init_declaration);
@@ -417,7 +475,14 @@ impl<'a> Parser<'a> {
         self.expect(Kind::Colon)?;
         let mut consequent = self.ast.new_vec();
         while !matches!(self.cur_kind(), Kind::Case | Kind::Default | Kind::RCurly | Kind::Eof) {
-            let stmt = self.parse_statement_list_item(StatementContext::StatementList)?;
+            let stmt = self.parse_statement_or_recover(|p| {
+                p.parse_statement_list_item(StatementContext::StatementList)
+            });
+            if let Statement::Declaration(Declaration::VariableDeclaration(decl)) = &stmt
+                && decl.kind.is_using()
+            {
+                self.error(diagnostics::UsingDeclarationInSwitchCase(decl.span));
+            }
             consequent.push(stmt);
         }
         Ok(self.ast.switch_case(self.end_span(span), test, consequent))
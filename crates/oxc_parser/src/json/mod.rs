@@ -0,0 +1,358 @@
+//! [JSON](https://www.json.org/), JSONC and [JSON5](https://spec.json5.org/)
+//!
+//! JSON documents are read by the JavaScript lexer,
+//! the tokens are checked against the JSON grammar of the `SourceType`.
+
+#![allow(clippy::missing_errors_doc)]
+
+use oxc_ast::{ast::*, context::Context, CommentKind, JsonKind, Span};
+use oxc_diagnostics::Result;
+
+use crate::{diagnostics, lexer::Kind, JsonParserReturn, Parser, TokenValue};
+
+impl<'a> Parser<'a> {
+    /// Parser entry point for a JSON `SourceType`
+    /// A document with a syntax error inside of its value has no value,
+    /// errors which leave the value intact (e.g. a comment in JSON) are only stored inside `errors`.
+    #[must_use]
+    pub fn parse_json(mut self) -> JsonParserReturn<'a> {
+        let kind = self.source_type.json_kind().unwrap_or(JsonKind::Json);
+        // Numbers and strings are checked against the JSON grammar instead
+        self.ctx = Context::default();
+        self.token = self.lexer.next_token();
+        let value = match self.parse_json_document(kind) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.error(error);
+                None
+            }
+        };
+        let trivias = self.lexer.trivia_builder.build();
+        if kind == JsonKind::Json {
+            for (span, comment_kind) in trivias.comments() {
+                // Comment spans skip the leading `//` or `/*`, and the trailing `*/`
+                let end =
+                    if comment_kind == CommentKind::MultiLine { span.end + 2 } else { span.end };
+                let error = diagnostics::JsonComment(Span::new(span.start - 2, end));
+                self.errors.borrow_mut().push(error.into());
+            }
+        }
+        let errors = self.errors.borrow_mut().drain(..).collect();
+        JsonParserReturn { value, errors, trivias }
+    }
+
+    fn parse_json_document(&mut self, kind: JsonKind) -> Result<JsonValue<'a>> {
+        let value = self.parse_json_value(kind)?;
+        if !self.at(Kind::Eof) {
+            return self.unexpected();
+        }
+        Ok(value)
+    }
+
+    fn parse_json_value(&mut self, kind: JsonKind) -> Result<JsonValue<'a>> {
+        match self.cur_kind() {
+            Kind::LCurly => self.parse_json_object(kind),
+            Kind::LBrack => self.parse_json_array(kind),
+            Kind::Str => self.parse_json_string(kind).map(JsonValue::String),
+            Kind::True | Kind::False => self.parse_literal_boolean().map(JsonValue::Boolean),
+            Kind::Null => Ok(JsonValue::Null(self.parse_literal_null())),
+            _ => self.parse_json_number(kind).map(JsonValue::Number),
+        }
+    }
+
+    fn parse_json_object(&mut self, kind: JsonKind) -> Result<JsonValue<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LCurly)?;
+        let mut properties = self.ast.new_vec();
+        while !self.at(Kind::RCurly) {
+            if !properties.is_empty() {
+                self.expect(Kind::Comma)?;
+                if self.at(Kind::RCurly) {
+                    self.check_json_trailing_comma(kind);
+                    break;
+                }
+            }
+            let span = self.start_span();
+            let key = self.parse_json_property_key(kind)?;
+            self.expect(Kind::Colon)?;
+            let value = self.parse_json_value(kind)?;
+            properties.push(self.ast.json_property(self.end_span(span), key, value));
+        }
+        self.expect(Kind::RCurly)?;
+        Ok(self.ast.json_object(self.end_span(span), properties))
+    }
+
+    /// JSON5 keys can also be an `IdentifierName`, reserved words included
+    fn parse_json_property_key(&mut self, kind: JsonKind) -> Result<StringLiteral> {
+        if self.at(Kind::Str) {
+            return self.parse_json_string(kind);
+        }
+        let IdentifierName { span, name } = self.parse_identifier_name()?;
+        if kind != JsonKind::Json5 {
+            self.error(diagnostics::JsonUnquotedKey(span));
+        }
+        Ok(StringLiteral { span, value: name })
+    }
+
+    fn parse_json_array(&mut self, kind: JsonKind) -> Result<JsonValue<'a>> {
+        let span = self.start_span();
+        self.expect(Kind::LBrack)?;
+        let mut elements = self.ast.new_vec();
+        while !self.at(Kind::RBrack) {
+            if !elements.is_empty() {
+                self.expect(Kind::Comma)?;
+                if self.at(Kind::RBrack) {
+                    self.check_json_trailing_comma(kind);
+                    break;
+                }
+            }
+            elements.push(self.parse_json_value(kind)?);
+        }
+        self.expect(Kind::RBrack)?;
+        Ok(self.ast.json_array(self.end_span(span), elements))
+    }
+
+    /// JSONC allows trailing commas as well, `tsconfig.json` is commonly written with them
+    fn check_json_trailing_comma(&mut self, kind: JsonKind) {
+        if kind == JsonKind::Json {
+            let comma = Span::new(self.prev_token_end - 1, self.prev_token_end);
+            self.error(diagnostics::JsonTrailingComma(comma));
+        }
+    }
+
+    fn parse_json_string(&mut self, kind: JsonKind) -> Result<StringLiteral> {
+        let lit = self.parse_literal_string()?;
+        let bytes = &self.source.as_bytes()[lit.span.start as usize..lit.span.end as usize];
+        if bytes[0] == b'\'' && kind != JsonKind::Json5 {
+            self.error(diagnostics::JsonSingleQuotedString(lit.span));
+        }
+        let mut i = 1;
+        while i < bytes.len() - 1 {
+            #[allow(clippy::cast_possible_truncation)]
+            let start = lit.span.start + i as u32;
+            match bytes[i] {
+                b'\\' => {
+                    if !is_json_escape(bytes[i + 1], bytes.get(i + 2).copied(), kind) {
+                        self.error(diagnostics::JsonInvalidEscape(Span::new(start, start + 2)));
+                    }
+                    i += 2;
+                }
+                // JSON5 strings only exclude the line terminators, which end a string
+                b'\0'..=b'\x1f' if kind != JsonKind::Json5 => {
+                    self.error(diagnostics::JsonControlCharacter(Span::new(start, start + 1)));
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+        Ok(lit)
+    }
+
+    /// A number with an optional `-` sign, JSON5 also has a `+` sign, `Infinity` and `NaN`
+    fn parse_json_number(&mut self, kind: JsonKind) -> Result<JsonNumber<'a>> {
+        let span = self.start_span();
+        let negative = self.at(Kind::Minus);
+        if negative || (kind == JsonKind::Json5 && self.at(Kind::Plus)) {
+            self.bump_any();
+            // The sign is part of the number token
+            if self.prev_token_end != self.cur_token().start {
+                self.error(diagnostics::JsonInvalidNumber(self.end_span(span)));
+            }
+        }
+        let value = match self.cur_kind() {
+            Kind::Ident if kind == JsonKind::Json5 && self.cur_src() == "Infinity" => f64::INFINITY,
+            Kind::Ident if kind == JsonKind::Json5 && self.cur_src() == "NaN" => f64::NAN,
+            token if token.is_number() => match self.cur_token().value {
+                TokenValue::Number(value) => value,
+                // BigInt
+                _ => return Err(diagnostics::JsonInvalidNumber(self.current_range()).into()),
+            },
+            _ => return self.unexpected(),
+        };
+        let raw = self.cur_src();
+        if self.cur_kind().is_number() && !is_json_number(raw, kind) {
+            self.error(diagnostics::JsonInvalidNumber(self.current_range()));
+        }
+        self.bump_any();
+        let span = self.end_span(span);
+        let raw = &self.source[span.start as usize..span.end as usize];
+        let value = if negative { -value } else { value };
+        Ok(JsonNumber { span, value, raw })
+    }
+}
+
+/// The escape after a `\` in a JSON string, followed by `next`
+fn is_json_escape(c: u8, next: Option<u8>, kind: JsonKind) -> bool {
+    if kind == JsonKind::Json5 {
+        // Every character other than a digit escapes itself, `\0` is only the null character
+        return match c {
+            b'0' => !next.is_some_and(|next| next.is_ascii_digit()),
+            b'1'..=b'9' => false,
+            _ => true,
+        };
+    }
+    matches!(c, b'"' | b'\\' | b'/' | b'b' | b'f' | b'n' | b'r' | b't' | b'u')
+}
+
+/// The number of RFC 8259 `int [ frac ] [ exp ]` without its sign,
+/// JSON5 also has hexadecimal numbers and a fraction without leading or trailing digits, `.5` and `5.`
+fn is_json_number(raw: &str, kind: JsonKind) -> bool {
+    let is_json5 = kind == JsonKind::Json5;
+    if raw.contains('_') {
+        return false;
+    }
+    if let Some(digits) = raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+        return is_json5 && !digits.is_empty();
+    }
+    let digits = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+
+    let int = &raw[..digits(raw)];
+    if int.len() > 1 && int.starts_with('0') {
+        return false;
+    }
+    let mut rest = &raw[int.len()..];
+    let mut frac_len = 0;
+    if let Some(frac) = rest.strip_prefix('.') {
+        frac_len = digits(frac);
+        if frac_len == 0 && !is_json5 {
+            return false;
+        }
+        rest = &frac[frac_len..];
+    }
+    if int.is_empty() && !(is_json5 && frac_len > 0) {
+        return false;
+    }
+    if let Some(exp) = rest.strip_prefix(['e', 'E']) {
+        let exp = exp.strip_prefix(['+', '-']).unwrap_or(exp);
+        return !exp.is_empty() && digits(exp) == exp.len();
+    }
+    rest.is_empty()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::JsonValue, JsonKind, SourceType};
+
+    use crate::Parser;
+
+    fn errors(source: &str, kind: JsonKind) -> Vec<String> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().json(kind).build();
+        let ret = Parser::new(&allocator, source, source_type).parse_json();
+        ret.errors.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn json_value() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("package.json").unwrap();
+        let source = r#"{
+  "name": "a",
+  "version": "1.0.0",
+  "private": true,
+  "files": ["dist", null],
+  "size": -1.5e3,
+  "name": "b"
+}"#;
+        let ret = Parser::new(&allocator, source, source_type).parse_json();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let object = ret.value.as_ref().unwrap().as_object().unwrap();
+        assert_eq!(object.properties.len(), 6);
+        assert_eq!(object.get("name").and_then(JsonValue::as_str), Some("b"));
+        let files = object.get("files").unwrap().as_array().unwrap();
+        assert_eq!(files.elements.len(), 2);
+        let Some(JsonValue::Number(size)) = object.get("size") else { panic!() };
+        assert_eq!((size.value, size.raw), (-1500.0, "-1.5e3"));
+        let version = &object.properties[1];
+        let span = version.span;
+        assert_eq!(&source[span.start as usize..span.end as usize], r#""version": "1.0.0""#);
+        assert_eq!(version.key.value, "version");
+    }
+
+    #[test]
+    fn json5_value() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("config.json5").unwrap();
+        let source = "// JSON5
+{
+  unquoted: 'single',
+  'escapes': '\\x41\\
+b',
+  hex: 0xFF, fraction: .5, trailing: 5., positive: +1,
+  special: [Infinity, -Infinity, NaN],
+}";
+        let ret = Parser::new(&allocator, source, source_type).parse_json();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let object = ret.value.as_ref().unwrap().as_object().unwrap();
+        assert_eq!(object.get("unquoted").and_then(JsonValue::as_str), Some("single"));
+        assert_eq!(object.get("escapes").and_then(JsonValue::as_str), Some("Ab"));
+        let Some(JsonValue::Number(hex)) = object.get("hex") else { panic!() };
+        assert!((hex.value - 255.0).abs() < f64::EPSILON);
+        let special = object.get("special").unwrap().as_array().unwrap();
+        let JsonValue::Number(infinity) = &special.elements[1] else { panic!() };
+        assert!(infinity.value.is_infinite() && infinity.value.is_sign_negative());
+    }
+
+    #[test]
+    fn json_errors() {
+        assert_eq!(
+            errors("// comment\n[1, /* comment */ 2,]", JsonKind::Json),
+            [
+                "Trailing commas are not allowed in JSON",
+                "Comments are not allowed in JSON",
+                "Comments are not allowed in JSON"
+            ]
+        );
+        assert!(errors("// comment\n[1, /* comment */ 2,]", JsonKind::Jsonc).is_empty());
+        assert_eq!(
+            errors("{ a: 'b' }", JsonKind::Jsonc),
+            ["Property keys must be strings in JSON", "Strings must be double quoted in JSON"]
+        );
+        assert_eq!(
+            errors(r#"["\x41", "\'", "a	b", "A\/"]"#, JsonKind::Json),
+            [
+                "Invalid escape sequence in JSON string",
+                "Invalid escape sequence in JSON string",
+                "Control characters must be escaped in JSON strings"
+            ]
+        );
+        assert_eq!(
+            errors(r"['\01', '\1', '\0', '\a']", JsonKind::Json5),
+            ["Invalid escape sequence in JSON string", "Invalid escape sequence in JSON string"]
+        );
+        for number in ["0", "-0.5E+2", "10e-1"] {
+            assert!(errors(number, JsonKind::Json).is_empty(), "{number}");
+        }
+        for number in ["01", "1.", ".1", "0x1", "1_0", "- 1", "1n"] {
+            assert_eq!(errors(number, JsonKind::Json), ["Invalid number in JSON"], "{number}");
+        }
+        assert_eq!(errors("+1", JsonKind::Json), ["Unexpected token"]);
+        for number in ["1.", ".1", "+1", "0x1", "Infinity", "-NaN"] {
+            assert!(errors(number, JsonKind::Json5).is_empty(), "{number}");
+        }
+        for number in ["01", "0o1", "08", "+ 1"] {
+            assert_eq!(errors(number, JsonKind::Json5), ["Invalid number in JSON"], "{number}");
+        }
+        assert_eq!(errors("[Infinity]", JsonKind::Json), ["Unexpected token"]);
+
+        // A syntax error inside of the value leaves no value
+        for source in ["", "{", "[1 2]", "{\"a\" 1}", "[1,,2]", "{} {}", "<!-- a\n1"] {
+            let allocator = Allocator::default();
+            let source_type = SourceType::builder().json(JsonKind::Json).build();
+            let ret = Parser::new(&allocator, source, source_type).parse_json();
+            assert!(ret.value.is_none(), "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}: {:?}", ret.errors);
+        }
+    }
+
+    #[test]
+    fn json_program() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path("package.json").unwrap();
+        let ret = Parser::new(&allocator, "{}", source_type).parse();
+        assert!(ret.program.is_empty());
+        let errors: Vec<_> = ret.errors.iter().map(ToString::to_string).collect();
+        assert_eq!(errors, ["A JSON source cannot be parsed as a program"]);
+    }
+}
@@ -331,7 +331,10 @@ impl<'a> Parser<'a> {
 
     fn parse_jsx_attribute_value(&mut self) -> Result<JSXAttributeValue<'a>> {
         match self.cur_kind() {
-            Kind::Str => self.parse_literal_string().map(JSXAttributeValue::StringLiteral),
+            // JSX strings do not have escape sequences
+            Kind::Str => self
+                .without_context(Context::Strict, Self::parse_literal_string)
+                .map(JSXAttributeValue::StringLiteral),
             Kind::LCurly => {
                 let expr = self.parse_jsx_expression_container(false)?;
                 Ok(JSXAttributeValue::ExpressionContainer(expr))
@@ -60,6 +60,7 @@ pub enum Kind {
     Set,
     Target, // new.target
     Accessor,
+    Using,
     // TypeScript Contextual Keywords
     Abstract,
     As,
@@ -91,6 +92,8 @@ pub enum Kind {
     Global,
     BigInt,
     Override,
+    // Flow Contextual Keywords
+    Opaque,
     // Future keywords (strict mode reserved words)
     Implements,
     Interface,
@@ -232,6 +235,7 @@ static KW_SUPER: Atom = Atom::new_inline("super");
 static KW_THROW: Atom = Atom::new_inline("throw");
 static KW_WHILE: Atom = Atom::new_inline("while");
 static KW_YIELD: Atom = Atom::new_inline("yield");
+static KW_USING: Atom = Atom::new_inline("using");
 static KW_ASSERT: Atom = Atom::new_inline("assert");
 static KW_BIGINT: Atom = Atom::new_inline("bigint");
 static KW_DELETE: Atom = Atom::new_inline("delete");
@@ -241,6 +245,7 @@ static KW_IMPORT: Atom = Atom::new_inline("import");
 static KW_MODULE: Atom = Atom::new_inline("module");
 static KW_NUMBER: Atom = Atom::new_inline("number");
 static KW_OBJECT: Atom = Atom::new_inline("object");
+static KW_OPAQUE: Atom = Atom::new_inline("opaque");
 static KW_PUBLIC: Atom = Atom::new_inline("public");
 static KW_RETURN: Atom = Atom::new_inline("return");
 static KW_STATIC: Atom = Atom::new_inline("static");
@@ -444,10 +449,10 @@ impl Kind {
     #[must_use]
     #[rustfmt::skip]
     pub fn is_contextual_keyword(self) -> bool {
-        matches!(self, Async | From | Get | Meta | Of | Set | Target | Accessor | Abstract | As | Asserts
+        matches!(self, Async | From | Get | Meta | Of | Set | Target | Accessor | Using | Abstract | As | Asserts
             | Assert | Any | Boolean | Constructor | Declare | Infer | Intrinsic | Is | KeyOf | Module
             | Namespace | Never | Out | Readonly | Require | Number | Object | Satisfies | String
-            | Symbol | Type | Undefined | Unique | Unknown | Global | BigInt | Override)
+            | Symbol | Type | Undefined | Unique | Unknown | Global | BigInt | Override | Opaque)
     }
 
     #[must_use]
@@ -528,6 +533,7 @@ impl Kind {
             "throw" => Some((Throw, &KW_THROW)),
             "while" => Some((While, &KW_WHILE)),
             "yield" => Some((Yield, &KW_YIELD)),
+            "using" => Some((Using, &KW_USING)),
 
             "assert" => Some((Assert, &KW_ASSERT)),
             "bigint" => Some((BigInt, &KW_BIGINT)),
@@ -538,6 +544,7 @@ impl Kind {
             "module" => Some((Module, &KW_MODULE)),
             "number" => Some((Number, &KW_NUMBER)),
             "object" => Some((Object, &KW_OBJECT)),
+            "opaque" => Some((Opaque, &KW_OPAQUE)),
             "public" => Some((Public, &KW_PUBLIC)),
             "return" => Some((Return, &KW_RETURN)),
             "static" => Some((Static, &KW_STATIC)),
@@ -636,6 +643,7 @@ impl Kind {
             Set => "set",
             Asserts => "asserts",
             Accessor => "accessor",
+            Using => "using",
             Abstract => "abstract",
             Readonly => "readonly",
             Declare => "declare",
@@ -747,6 +755,7 @@ impl Kind {
             Unknown => "unknown",
             Global => "global",
             BigInt => "bigint",
+            Opaque => "opaque",
         }
     }
 
@@ -122,6 +122,16 @@ impl<'a> Lexer<'a> {
         self.lookahead.clear();
     }
 
+    /// Continue lexing from `offset`, which must be the end of a token
+    pub fn seek(&mut self, offset: u32) {
+        self.current = LexerCheckpoint {
+            chars: self.source[offset as usize..].chars(),
+            token: Token { is_on_new_line: offset == 0, ..Token::default() },
+            errors_pos: self.errors.borrow().len(),
+        };
+        self.lookahead.clear();
+    }
+
     /// Find the nth lookahead token lazily
     pub fn lookahead(&mut self, n: u8) -> &Token {
         let n = n as usize;
@@ -199,6 +209,7 @@ impl<'a> Lexer<'a> {
     }
 
     pub fn next_right_angle(&mut self) -> Token {
+        self.current.token.start = self.offset() - 1;
         let kind = self.read_right_angle();
         self.lookahead.clear();
         self.finish_next(kind)
@@ -214,8 +225,9 @@ impl<'a> Lexer<'a> {
     }
 
     /// Expand the current token for `JSXIdentifier`
-    pub fn next_jsx_identifier(&mut self, prev_len: u32) -> Token {
-        let kind = self.read_jsx_identifier(prev_len);
+    pub fn next_jsx_identifier(&mut self, start_offset: u32) -> Token {
+        self.current.token.start = start_offset;
+        let kind = self.read_jsx_identifier(start_offset);
         self.lookahead.clear();
         self.finish_next(kind)
     }
@@ -497,14 +509,19 @@ impl<'a> Lexer<'a> {
 
     /// Section 12.4 Single Line Comment
     #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
     fn skip_single_line_comment(&mut self) -> Kind {
+        let mut end = None;
         while let Some(c) = self.current.chars.next().as_ref() {
             if is_line_terminator(*c) {
+                // the line terminator is not part of the comment
+                end = Some(self.offset() - c.len_utf8() as u32);
                 break;
             }
         }
         self.current.token.is_on_new_line = true;
-        self.trivia_builder.add_single_line_comment(self.current.token.start, self.offset());
+        let end = end.unwrap_or_else(|| self.offset());
+        self.trivia_builder.add_single_line_comment(self.current.token.start, end);
         Kind::Comment
     }
 
@@ -529,7 +546,7 @@ impl<'a> Lexer<'a> {
             return Kind::Eof;
         }
 
-        self.trivia_builder.add_single_line_comment(self.current.token.start, self.offset());
+        self.trivia_builder.add_multi_line_comment(self.current.token.start, self.offset());
         Kind::MultiLineComment
     }
 
@@ -586,6 +603,7 @@ impl<'a> Lexer<'a> {
         } else if self.peek() == '!'
             // SingleLineHTMLOpenComment `<!--` in script mode
             && self.source_type.is_script()
+            && !self.source_type.is_json()
             && self.remaining().starts_with("!--")
         {
             None
@@ -644,6 +662,7 @@ impl<'a> Lexer<'a> {
             // SingleLineHTMLCloseComment `-->` in script mode
             if self.current.token.is_on_new_line
                 && self.source_type.is_script()
+                && !self.source_type.is_json()
                 && self.next_eq('>')
             {
                 None
@@ -1023,6 +1042,7 @@ impl<'a> Lexer<'a> {
         pattern.push_str(&start[..start.len() - self.current.chars.as_str().len() - 1]);
 
         let mut flags = RegExpFlags::empty();
+        let flags_start = self.offset();
 
         while let ch @ ('$' | '_' | 'a'..='z' | 'A'..='Z' | '0'..='9') = self.peek() {
             self.current.chars.next();
@@ -1052,6 +1072,11 @@ impl<'a> Lexer<'a> {
             flags |= flag;
         }
 
+        if flags.contains(RegExpFlags::U | RegExpFlags::V) {
+            let span = Span::new(flags_start, self.offset());
+            self.error(diagnostics::RegExpFlagUAndV(span));
+        }
+
         self.current.token.value =
             TokenValue::RegExp(RegExp { pattern: Atom::from(pattern.as_str()), flags });
 
@@ -1100,8 +1125,8 @@ impl<'a> Lexer<'a> {
     ///   `IdentifierStart`
     ///   `JSXIdentifier` `IdentifierPart`
     ///   `JSXIdentifier` [no `WhiteSpace` or Comment here] -
-    fn read_jsx_identifier(&mut self, prev_len: u32) -> Kind {
-        let prev_str = &self.source[prev_len as usize..self.offset() as usize];
+    fn read_jsx_identifier(&mut self, start_offset: u32) -> Kind {
+        let prev_str = &self.source[start_offset as usize..self.offset() as usize];
 
         let mut builder = AutoCow::new(self);
         loop {
@@ -1135,11 +1160,11 @@ impl<'a> Lexer<'a> {
     /// `JSXFragment`
     /// { `JSXChildExpressionopt` }
     fn read_jsx_child(&mut self) -> Kind {
+        let mut builder = AutoCow::new(self);
         match self.current.chars.next() {
             Some('<') => Kind::LAngle,
             Some('{') => Kind::LCurly,
             Some(c) => {
-                let mut builder = AutoCow::new(self);
                 builder.push_matching(c);
                 loop {
                     // `>` and `}` are errors in TypeScript but not Babel
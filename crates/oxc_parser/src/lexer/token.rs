@@ -61,6 +61,8 @@ impl Default for TokenValue {
 }
 
 impl TokenValue {
+    /// # Panics
+    ///   * The value is not a `Number`
     #[must_use]
     pub fn as_number(&self) -> f64 {
         match self {
@@ -69,6 +71,8 @@ impl TokenValue {
         }
     }
 
+    /// # Panics
+    ///   * The value is not a `BigInt`
     #[must_use]
     pub fn as_bigint(&self) -> BigUint {
         match self {
@@ -77,6 +81,8 @@ impl TokenValue {
         }
     }
 
+    /// # Panics
+    ///   * The value is not a `RegExp`
     #[must_use]
     pub fn as_regex(&self) -> RegExp {
         match self {
@@ -16,7 +16,6 @@ impl TriviaBuilder {
         self.trivias.add_comment(Span::new(start + 2, end), CommentKind::SingleLine);
     }
 
-    #[allow(unused)]
     pub fn add_multi_line_comment(&mut self, start: u32, end: u32) {
         // skip leading `/*` and trailing */
         self.trivias.add_comment(Span::new(start + 2, end - 2), CommentKind::MultiLine);
@@ -1,14 +1,18 @@
-//! Recursive Descent Parser for ECMAScript and TypeScript
+//! Recursive Descent Parser for ECMAScript, TypeScript and Flow
 
 #![allow(clippy::wildcard_imports)] // allow for use `oxc_ast::ast::*`
 #![feature(portable_simd)]
 #![feature(slice_as_chunks)]
 
 mod cursor;
+mod incremental;
 mod list;
+mod recovery;
 mod state;
 
+mod flow;
 mod js;
+mod json;
 mod jsx;
 mod ts;
 
@@ -16,19 +20,46 @@ mod diagnostics;
 mod lexer;
 
 use oxc_allocator::Allocator;
-use oxc_ast::{ast::Program, context::Context, AstBuilder, SourceType, Span, Trivias};
+use oxc_ast::{
+    ast::{JsonValue, Program},
+    context::Context,
+    AstBuilder, CommentKind, SourceType, Span, Trivias,
+};
 use oxc_diagnostics::{Diagnostics, Error, Result};
 
-use crate::{
-    lexer::{Kind, Lexer, Token},
-    state::ParserState,
+pub use crate::{
+    incremental::TextEdit,
+    lexer::{Kind, RegExp, Token, TokenValue},
 };
+use crate::{lexer::Lexer, state::ParserState};
 
 #[derive(Debug)]
 pub struct ParserReturn<'a> {
     pub program: Program<'a>,
     pub errors: Vec<Error>,
     pub trivias: Trivias,
+    /// All tokens and comments in source order, see [`Parser::preserve_tokens`]
+    pub tokens: Vec<Token>,
+}
+
+/// The result of [`Parser::parse_json`]
+#[derive(Debug)]
+pub struct JsonParserReturn<'a> {
+    /// `None` when the value has a syntax error
+    pub value: Option<JsonValue<'a>>,
+    pub errors: Vec<Error>,
+    pub trivias: Trivias,
+}
+
+/// The decorators proposal the source is written for, they differ in where decorators can appear
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecoratorFlavor {
+    /// TC39 decorators (2023): `export @dec class`, decorated class expressions, private members
+    /// and `accessor` fields, but no parameter decorators
+    Standard,
+    /// TypeScript `experimentalDecorators`: decorators before `export`, parameter decorators
+    /// on the methods and constructor of class declarations
+    Legacy,
 }
 
 pub struct Parser<'a> {
@@ -58,9 +89,20 @@ pub struct Parser<'a> {
 
     /// Ast builder for creating AST spans
     ast: AstBuilder<'a>,
+
+    /// Every token moved past, when preserving tokens
+    tokens: Option<Vec<Token>>,
+
+    /// Where decorators are allowed
+    decorators: DecoratorFlavor,
+
+    /// Flow type annotations are parsed, for a Flow source type or a `@flow` pragma
+    flow: bool,
 }
 
 impl<'a> Parser<'a> {
+    /// A JavaScript source with a `@flow` pragma in its leading comments is parsed as Flow,
+    /// the `Program` keeps the given source type.
     #[must_use]
     pub fn new(allocator: &'a Allocator, source: &'a str, source_type: SourceType) -> Self {
         let errors = Diagnostics::default();
@@ -74,59 +116,72 @@ impl<'a> Parser<'a> {
             state: ParserState::new(allocator),
             ctx: source_type.default_context(),
             ast: AstBuilder::new(allocator),
+            tokens: None,
+            decorators: DecoratorFlavor::Standard,
+            flow: source_type.is_flow() || (source_type.is_javascript() && has_flow_pragma(source)),
         }
     }
 
+    /// Sources are parsed with standard decorators by default,
+    /// TypeScript written for `experimentalDecorators` needs `DecoratorFlavor::Legacy`.
+    #[must_use]
+    pub fn decorators(mut self, flavor: DecoratorFlavor) -> Self {
+        self.decorators = flavor;
+        self
+    }
+
     #[must_use]
     pub fn allow_return_outside_function(mut self, allow: bool) -> Self {
         self.ctx = self.ctx.and_return(allow);
         self
     }
 
+    /// Record the tokens of the source into [`ParserReturn::tokens`].
+    /// The tokens are read by the parser, which tells a regular expression from a division,
+    /// a template continuation from a `}` and JSX text from JavaScript.
+    /// Comments are merged in as `Kind::Comment` and `Kind::MultiLineComment` tokens
+    /// spanning their delimiters.
+    #[must_use]
+    pub fn preserve_tokens(mut self, preserve: bool) -> Self {
+        self.tokens = preserve.then(Vec::new);
+        self
+    }
+
     /// Parser main entry point
-    /// Returns an empty `Program` on unrecoverable error,
-    /// Recoverable errors are stored inside `errors`.
+    /// Syntax errors are recovered at statement and class member boundaries,
+    /// the returned `Program` keeps the valid parts of the source and all errors are stored inside `errors`.
+    /// A JSON `SourceType` is not a program, it is reported as an error,
+    /// use [`Parser::parse_json`] for JSON sources instead.
     #[must_use]
     pub fn parse(mut self) -> ParserReturn<'a> {
-        let program = match self.parse_program() {
-            Ok(program) => program,
-            Err(error) => {
-                self.error(self.flow_error().unwrap_or(error));
-                let program = self.ast.program(
-                    Span::default(),
-                    self.ast.new_vec(),
-                    self.ast.new_vec(),
-                    self.source_type,
-                );
-                program
-            }
-        };
+        let program = self.parse_program();
         let errors = self.errors.borrow_mut().drain(..).collect();
         let trivias = self.lexer.trivia_builder.build();
-        ParserReturn { program, errors, trivias }
+        let tokens =
+            self.tokens.take().map_or_else(Vec::new, |tokens| merge_comments(tokens, &trivias));
+        ParserReturn { program, errors, trivias, tokens }
     }
 
     #[allow(clippy::cast_possible_truncation)]
-    fn parse_program(&mut self) -> Result<Program<'a>> {
-        // initialize cur_token and prev_token by moving onto the first token
-        self.bump_any();
+    fn parse_program(&mut self) -> Program<'a> {
+        let span = Span::new(0, self.source.len() as u32);
+        if self.source_type.is_json() {
+            self.error(diagnostics::JsonProgram(span));
+            return self.ast.program(
+                span,
+                self.ast.new_vec(),
+                self.ast.new_vec(),
+                self.source_type,
+            );
+        }
+
+        // initialize cur_token by reading the first token, prev_token_end stays at 0
+        self.token = self.lexer.next_token();
 
         let (directives, statements) =
-            self.parse_directives_and_statements(/* is_top_level */ true)?;
+            self.parse_directives_and_statements(/* is_top_level */ true);
 
-        let span = Span::new(0, self.source.len() as u32);
-        Ok(self.ast.program(span, directives, statements, self.source_type))
-    }
-
-    /// Check for Flow declaration if the file cannot be parsed.
-    /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
-    fn flow_error(&self) -> Option<Error> {
-        if self.source_type.is_javascript()
-            && (self.source.starts_with("// @flow") || self.source.starts_with("/* @flow */"))
-        {
-            return Some(diagnostics::Flow(Span::new(0, 8)).into());
-        }
-        None
+        self.ast.program(span, directives, statements, self.source_type)
     }
 
     /// Return error info at current token
@@ -146,15 +201,91 @@ impl<'a> Parser<'a> {
         self.errors.borrow_mut().push(error.into());
     }
 
+    /// TypeScript only syntax, e.g. `enum`, `namespace`, `as` and access modifiers
     #[must_use]
     fn ts_enabled(&self) -> bool {
         self.source_type.is_typescript()
     }
+
+    /// Type annotations are parsed for TypeScript and Flow
+    #[must_use]
+    fn types_enabled(&self) -> bool {
+        self.ts_enabled() || self.flow
+    }
+
+    #[must_use]
+    fn flow_enabled(&self) -> bool {
+        self.flow
+    }
+}
+
+/// Check for the Flow declaration, which must be in a comment
+/// [before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow),
+/// e.g. `// @flow` or a `/** @flow */` docblock.
+#[must_use]
+pub fn has_flow_pragma(source: &str) -> bool {
+    let mut rest = source.trim_start();
+    loop {
+        let comment = if let Some(line) = rest.strip_prefix("//") {
+            let end = line.find(|c| c == '\n' || c == '\r').unwrap_or(line.len());
+            rest = &line[end..];
+            &line[..end]
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            let Some(end) = block.find("*/") else { return false };
+            rest = &block[end + 2..];
+            &block[..end]
+        } else {
+            return false;
+        };
+        let is_pragma = comment.match_indices("@flow").any(|(i, _)| {
+            !comment[i + 5..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        });
+        if is_pragma {
+            return true;
+        }
+        rest = rest.trim_start();
+    }
+}
+
+/// Merge the comments into the tokens, both are in source order
+fn merge_comments(tokens: Vec<Token>, trivias: &Trivias) -> Vec<Token> {
+    let mut comments = trivias
+        .comments()
+        .map(|(span, kind)| match kind {
+            // Comment spans skip the leading `//`, `/*` or `#!`, and the trailing `*/`
+            CommentKind::SingleLine => Token {
+                kind: Kind::Comment,
+                start: span.start - 2,
+                end: span.end,
+                ..Token::default()
+            },
+            CommentKind::MultiLine => Token {
+                kind: Kind::MultiLineComment,
+                start: span.start - 2,
+                end: span.end + 2,
+                ..Token::default()
+            },
+        })
+        .peekable();
+    let mut merged = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        while let Some(comment) = comments.next_if(|comment| comment.start < token.start) {
+            merged.push(comment);
+        }
+        merged.push(token);
+    }
+    merged.extend(comments);
+    merged
 }
 
 #[cfg(test)]
 mod test {
 
+    use oxc_ast::ast::{
+        ClassElement, Declaration, Expression, ForStatementInit, ForStatementLeft,
+        ImportAttributesKeyword, ModuleDeclarationKind, Statement, TSType, VariableDeclarationKind,
+    };
+
     use super::*;
 
     #[test]
@@ -168,17 +299,444 @@ mod test {
     }
 
     #[test]
-    fn flow_error() {
+    fn flow_pragma() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        for source in ["// @flow\nlet a: ?string;", "/**\n * @flow strict\n */\nlet a: ?string;"] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.program.source_type, source_type);
+            assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        }
+
+        for source in [
+            "// @noflow\nlet a: ?string;",
+            "// @flowtype\nlet a: ?string;",
+            "let a: ?string; // @flow",
+        ] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.errors.is_empty());
+        }
+    }
+
+    #[test]
+    fn regular_expression_error() {
         let allocator = Allocator::default();
         let source_type = SourceType::default();
-        let source = "// @flow\nasdf adsf";
+        let source = "let a = /(?<a>x)(?<a>y)/;\nlet b = /\\p{Foo}/u;\nlet c = /a/uv;";
         let ret = Parser::new(&allocator, source, source_type).parse();
-        assert!(ret.program.is_empty());
-        assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
+        let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "Invalid regular expression: Duplicate capture group name",
+                "Invalid regular expression: Invalid property name",
+                "The 'u' and 'v' regular expression flags cannot be enabled at the same time",
+            ]
+        );
+        let labels =
+            ret.errors[0].labels().unwrap().map(|label| label.offset()).collect::<Vec<_>>();
+        assert_eq!(labels, [12, 19]);
+    }
 
-        let source = "/* @flow */\n asdf asdf";
+    #[test]
+    fn recover_statements() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = 1;\nlet b = ;\nfoo bar baz\nfunction f() {\n  if (a { b() }\n  return a;\n}\nlet c = 3;";
         let ret = Parser::new(&allocator, source, source_type).parse();
-        assert!(ret.program.is_empty());
-        assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
+        assert_eq!(ret.errors.len(), 3);
+        let body = &ret.program.body;
+        assert_eq!(body.len(), 5);
+        assert!(matches!(body[0], Statement::Declaration(_)));
+        let Statement::InvalidStatement(stmt) = &body[1] else { panic!("{:?}", body[1]) };
+        assert_eq!(&source[stmt.span.start as usize..stmt.span.end as usize], "let b = ;");
+        let Statement::InvalidStatement(stmt) = &body[2] else { panic!("{:?}", body[2]) };
+        assert_eq!(&source[stmt.span.start as usize..stmt.span.end as usize], "foo bar baz");
+        let Statement::Declaration(Declaration::FunctionDeclaration(func)) = &body[3] else {
+            panic!("{:?}", body[3])
+        };
+        let statements = &func.body.as_ref().unwrap().statements;
+        assert!(matches!(statements[0], Statement::InvalidStatement(_)));
+        assert!(matches!(statements[1], Statement::ReturnStatement(_)));
+        assert!(matches!(body[4], Statement::Declaration(_)));
+    }
+
+    #[test]
+    fn recover_class_elements() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "class A {\n  a = 1;\n  b( {}\n  c() {}\n  d = ;\n  e\n}";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 2);
+        let Statement::Declaration(Declaration::ClassDeclaration(class)) = &ret.program.body[0]
+        else {
+            panic!("{:?}", ret.program.body)
+        };
+        let elements = &class.body.body;
+        assert_eq!(elements.len(), 5);
+        let ClassElement::InvalidClassElement(element) = &elements[1] else {
+            panic!("{:?}", elements[1])
+        };
+        assert_eq!(&source[element.span.start as usize..element.span.end as usize], "b( {}");
+        let ClassElement::InvalidClassElement(element) = &elements[3] else {
+            panic!("{:?}", elements[3])
+        };
+        assert_eq!(&source[element.span.start as usize..element.span.end as usize], "d = ;");
+    }
+
+    #[test]
+    fn recover_templates_and_regular_expressions() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "let a = 1 2 + `${ {} }}`;\nlet b = 1 2, /}/;\nlet c = 3;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 2);
+        let body = &ret.program.body;
+        assert_eq!(body.len(), 3);
+        let Statement::InvalidStatement(stmt) = &body[0] else { panic!("{:?}", body[0]) };
+        assert_eq!(
+            &source[stmt.span.start as usize..stmt.span.end as usize],
+            "let a = 1 2 + `${ {} }}`;"
+        );
+        let Statement::InvalidStatement(stmt) = &body[1] else { panic!("{:?}", body[1]) };
+        assert_eq!(&source[stmt.span.start as usize..stmt.span.end as usize], "let b = 1 2, /}/;");
+        assert!(matches!(body[2], Statement::Declaration(_)));
+    }
+
+    #[test]
+    fn recover_end_of_file() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "function f() {\n  if (a) {\n    foo(";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 3);
+        let Statement::Declaration(Declaration::FunctionDeclaration(func)) = &ret.program.body[0]
+        else {
+            panic!("{:?}", ret.program.body)
+        };
+        assert!(matches!(func.body.as_ref().unwrap().statements[0], Statement::IfStatement(_)));
+    }
+
+    #[test]
+    fn js_doc_unknown_type() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().typescript().build();
+        let source = "type A = (a: ?, b: ?string) => void;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let Statement::Declaration(Declaration::TSTypeAliasDeclaration(decl)) =
+            &ret.program.body[0]
+        else {
+            panic!("{:?}", ret.program.body)
+        };
+        let TSType::TSFunctionType(func) = &decl.type_annotation else {
+            panic!("{:?}", decl.type_annotation)
+        };
+        let types = func
+            .params
+            .items
+            .iter()
+            .map(|param| &param.pattern.type_annotation.as_ref().unwrap().type_annotation)
+            .collect::<Vec<_>>();
+        assert!(matches!(types[0], TSType::JSDocUnknownType(_)));
+        assert!(matches!(types[1], TSType::JSDocNullableType(_)));
+    }
+
+    #[test]
+    fn using_declarations() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().build();
+        let source = "using a = b;\nawait using c = d, e = f;\nfor (using g of h) {}\nfor (await using i of j) {}\n{ using k = l; }\nusing\nm = n;\nusing[o];\nfor (using of p) {}\nfor (using of of q) {}\nfor (using of = r;;) {}";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert_eq!(ret.program.body.len(), 11);
+        let kinds = ret
+            .program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::Declaration(Declaration::VariableDeclaration(decl)) => Some(decl.kind),
+                Statement::ForOfStatement(stmt) => match &stmt.left {
+                    ForStatementLeft::VariableDeclaration(decl) => Some(decl.kind),
+                    ForStatementLeft::AssignmentTarget(_) => None,
+                },
+                Statement::ForStatement(stmt) => match &stmt.init {
+                    Some(ForStatementInit::VariableDeclaration(decl)) => Some(decl.kind),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                VariableDeclarationKind::Using,
+                VariableDeclarationKind::AwaitUsing,
+                VariableDeclarationKind::Using,
+                VariableDeclarationKind::AwaitUsing,
+                VariableDeclarationKind::Using,
+                VariableDeclarationKind::Using,
+            ]
+        );
+
+        let errors = |source: &str, source_type: SourceType| {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        };
+        let script = SourceType::default();
+        assert_eq!(
+            errors("using a = b;", script),
+            ["`using` declarations are not allowed at the top level of a script"]
+        );
+        assert!(errors("{ using a = b; }", script).is_empty());
+        assert_eq!(
+            errors("{ using a = b, { c } = d; }", source_type),
+            ["`using` declarations may not have binding patterns"]
+        );
+        assert_eq!(
+            errors("{ using a; }", source_type),
+            ["Missing initializer in `using` declaration"]
+        );
+        assert_eq!(
+            errors("for (using a in b) {}", source_type),
+            ["The left-hand side of a `for...in` statement cannot be a `using` declaration"]
+        );
+        assert_eq!(
+            errors("switch (a) { case 1: using b = c; default: using d = e; }", source_type),
+            [
+                "`using` declarations are not allowed directly in a `case` or `default` clause",
+                "`using` declarations are not allowed directly in a `case` or `default` clause",
+            ]
+        );
+        assert!(errors("switch (a) { case 1: { using b = c; } }", source_type).is_empty());
+    }
+
+    #[test]
+    fn decorators() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().build();
+        let errors = |source: &str, flavor: DecoratorFlavor| {
+            let ret = Parser::new(&allocator, source, source_type).decorators(flavor).parse();
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        };
+        let standard = DecoratorFlavor::Standard;
+        let legacy = DecoratorFlavor::Legacy;
+
+        let source = "@a export class A {}\nexport default @b.c class {}\nlet d = @e() class {\n  @f #g = 1;\n  @(h[0]) static accessor i;\n  @j static k() {}\n}";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let Statement::Declaration(Declaration::VariableDeclaration(decl)) = &ret.program.body[2]
+        else {
+            unreachable!()
+        };
+        let Some(Expression::ClassExpression(class)) = &decl.declarations[0].init else {
+            unreachable!()
+        };
+        assert_eq!(class.decorators.len(), 1);
+        let counts = class
+            .body
+            .body
+            .iter()
+            .map(|element| match element {
+                ClassElement::PropertyDefinition(def) => def.decorators.len(),
+                ClassElement::AccessorProperty(def) if def.r#static => def.decorators.len(),
+                ClassElement::MethodDefinition(def) => def.decorators.len(),
+                _ => 0,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(counts, [1, 1, 1]);
+
+        assert!(errors("@a export class A { b(@c d) {} constructor(@e f) {} }", legacy).is_empty());
+        assert_eq!(
+            errors("export @a class A {}", legacy),
+            ["Decorators may not appear after `export` or `export default` with legacy decorators"]
+        );
+        assert_eq!(
+            errors("@a export @b class A {}", standard),
+            ["Decorators may not appear both before and after `export`"]
+        );
+        assert_eq!(
+            errors("class A { b(@c d) {} }", standard),
+            ["Parameter decorators are only valid with legacy decorators"]
+        );
+        assert_eq!(
+            errors("function a(@b c) {}", legacy),
+            ["Parameter decorators are only valid with legacy decorators"]
+        );
+        assert_eq!(
+            errors("class A { @b accessor c; @d #e() {} }", legacy),
+            ["Decorators are not valid here", "Decorators are not valid here"]
+        );
+        assert_eq!(
+            errors("let a = class { @b c() {} }", legacy),
+            ["Decorators are not valid here"]
+        );
+        assert_eq!(errors("@a function b() {}", standard), ["Decorators are not valid here"]);
+        assert_eq!(
+            errors("class A { @b static {} @c constructor() {} }", standard),
+            ["Decorators are not valid here", "Decorators are not valid here"]
+        );
+        assert_eq!(errors("@a?.b class B {}", standard), ["Invalid decorator expression"]);
+        assert!(errors("@a?.b class B {}", legacy).is_empty());
+
+        let typescript = SourceType::builder().module().typescript().build();
+        let ret = Parser::new(&allocator, "export @dec class C {}", typescript).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    }
+
+    #[test]
+    fn import_attributes() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().build();
+        let source = "import a from 'a' with { type: 'json' };\nimport 'b' assert { type: 'css' };\nexport * from 'c' with { 'type': 'json' };\nexport { d } from 'd' with {};\nimport('e', { with: { type: 'json' } });\nimport f from 'f'\nwith { type: 'json' };";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let clauses = ret
+            .program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ModuleDeclaration(decl) => match &decl.kind {
+                    ModuleDeclarationKind::ImportDeclaration(decl) => decl.with_clause.as_ref(),
+                    ModuleDeclarationKind::ExportAllDeclaration(decl) => decl.with_clause.as_ref(),
+                    ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                        decl.with_clause.as_ref()
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .map(|clause| (clause.keyword, clause.attributes.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            clauses,
+            [
+                (ImportAttributesKeyword::With, 1),
+                (ImportAttributesKeyword::Assert, 1),
+                (ImportAttributesKeyword::With, 1),
+                (ImportAttributesKeyword::With, 0),
+                (ImportAttributesKeyword::With, 1),
+            ]
+        );
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[4] else { unreachable!() };
+        let Expression::ImportExpression(expr) = &stmt.expression else { unreachable!() };
+        assert_eq!(expr.arguments.len(), 1);
+
+        let errors = |source: &str| {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            errors("import a from 'a' with { type: 'json', 'type': 'css' };"),
+            ["Duplicate import attribute \"type\""]
+        );
+        // `assert` is not allowed after a line terminator
+        assert!(!errors("import a from 'a'\nassert { type: 'json' };").is_empty());
+    }
+
+    #[test]
+    fn annex_b() {
+        let allocator = Allocator::default();
+        let errors = |source: &str, source_type: SourceType| {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        };
+        let script = SourceType::default();
+        let module = SourceType::builder().module().build();
+
+        let source = "a = 010 + 08 + '\\07\\8';\nif (b) function c() {} else function d() {}\ne: f: function g() {}\nh = 1 <!-- comment\n--> comment\n";
+        assert!(errors(source, script).is_empty());
+        assert_eq!(
+            errors("'use strict'; a = 010 + 08 + 0.1 + 0;", script),
+            [
+                "Octal literals are not allowed in strict mode",
+                "Decimals with leading zeros are not allowed in strict mode"
+            ]
+        );
+        assert_eq!(
+            errors("a = '\\07 \\0 \\\\1 \\8';", module),
+            ["Octal escape sequences are not allowed in strict mode", "Invalid escape sequence"]
+        );
+        // the directives before "use strict" are strict mode code as well
+        assert_eq!(
+            errors("function a() { '\\01'; 'use strict'; }", script),
+            ["Octal escape sequences are not allowed in strict mode"]
+        );
+        // "use strict" only applies to its function, class bodies are strict mode code
+        assert!(errors("function a() { 'use strict'; } b = 010;", script).is_empty());
+        assert_eq!(
+            errors("class A { b() { return 010; } } c = 010;", script),
+            ["Octal literals are not allowed in strict mode"]
+        );
+        assert!(errors("<a b='\\07' />", SourceType::builder().module().jsx().build()).is_empty());
+
+        assert_eq!(errors("if (a) function b() {}", module), ["Invalid function declaration"]);
+        assert_eq!(errors("a: function b() {}", module), ["Functions cannot be labelled"]);
+        assert_eq!(errors("while (a) function b() {}", script), ["Invalid function declaration"]);
+        assert_eq!(errors("if (a) b: function c() {}", script), ["Invalid function declaration"]);
+        assert_eq!(
+            errors("for (;;) a: b: function c() {}", script),
+            ["Invalid function declaration"]
+        );
+        assert_eq!(
+            errors("if (a) async function b() {}", script),
+            ["Async functions can only be declared at the top level or inside a block"]
+        );
+
+        // HTML-like comments are only comments in scripts
+        assert!(!errors("<!-- a\n", module).is_empty());
+        assert!(!errors("a\n--> b\n", module).is_empty());
+    }
+
+    #[test]
+    fn tokens() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "#!/usr/bin/env node\na = b / c; /* d */\n/e/g.test(`${f}g`) // h";
+        let ret = Parser::new(&allocator, source, source_type).preserve_tokens(true).parse();
+        let tokens = ret
+            .tokens
+            .iter()
+            .map(|token| (token.kind, &source[token.start as usize..token.end as usize]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tokens,
+            [
+                (Kind::Comment, "#!/usr/bin/env node"),
+                (Kind::Ident, "a"),
+                (Kind::Eq, "="),
+                (Kind::Ident, "b"),
+                (Kind::Slash, "/"),
+                (Kind::Ident, "c"),
+                (Kind::Semicolon, ";"),
+                (Kind::MultiLineComment, "/* d */"),
+                (Kind::RegExp, "/e/g"),
+                (Kind::Dot, "."),
+                (Kind::Ident, "test"),
+                (Kind::LParen, "("),
+                (Kind::TemplateHead, "`${"),
+                (Kind::Ident, "f"),
+                (Kind::TemplateTail, "}g`"),
+                (Kind::RParen, ")"),
+                (Kind::Comment, "// h"),
+            ]
+        );
+        assert!(matches!(&ret.tokens[8].value, TokenValue::RegExp(regex) if regex.pattern == "e"));
+        assert!(ret.tokens[8].is_on_new_line);
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.tokens.is_empty());
+    }
+
+    #[test]
+    fn tokens_after_rewind() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().typescript().build();
+        let source = "let f = (a: number): number => a; let g = (a);";
+        let ret = Parser::new(&allocator, source, source_type).preserve_tokens(true).parse();
+        assert!(ret.errors.is_empty());
+        let starts = ret.tokens.iter().map(|token| token.start).collect::<Vec<_>>();
+        assert!(starts.windows(2).all(|w| w[0] < w[1]), "{starts:?}");
+        assert_eq!(ret.tokens.len(), 20);
     }
 }
@@ -17,7 +17,7 @@ pub trait NormalList<'a> {
         while !p.at(self.close()) && !p.at(Kind::Eof) {
             self.parse_element(p)?;
         }
-        p.expect(self.close())?;
+        p.expect_closing(self.close())?;
         Ok(())
     }
 }
@@ -0,0 +1,149 @@
+//! Error recovery
+//!
+//! A syntax error inside a statement or a class member does not abort the whole parse.
+//! The error is reported, the tokens up to the next statement or class member boundary are skipped,
+//! and parsing resumes from there:
+//!
+//! * a broken statement is kept as an `InvalidStatement` spanning the skipped source
+//! * a broken class member is kept as an `InvalidClassElement` spanning the skipped source
+//!
+//! A boundary is a `;` or a `}` outside of the skipped braces and template substitutions,
+//! or a token on a new line which starts a statement or a class member.
+//! Templates and regular expressions are re-lexed while skipping,
+//! so a `}` or `;` inside of them is never taken for a boundary.
+
+use oxc_ast::{ast::*, context::Context, Span};
+use oxc_diagnostics::{Error, Result};
+
+use crate::{
+    lexer::{Kind, LexerContext},
+    Parser,
+};
+
+impl<'a> Parser<'a> {
+    /// Parse a statement with `parse`,
+    /// or return an `InvalidStatement` covering the broken source when it fails
+    pub fn parse_statement_or_recover(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Statement<'a>>,
+    ) -> Statement<'a> {
+        let span = self.start_span();
+        let ctx = self.ctx;
+        match parse(self) {
+            Ok(stmt) => stmt,
+            Err(error) => {
+                self.recover(span, ctx, error, at_statement_start);
+                self.ast.invalid_statement(self.end_span(span))
+            }
+        }
+    }
+
+    /// Parse a class member,
+    /// or return an `InvalidClassElement` covering the broken source when it fails
+    pub fn parse_class_element_or_recover(&mut self) -> ClassElement<'a> {
+        let span = self.start_span();
+        let ctx = self.ctx;
+        match self.parse_class_element() {
+            Ok(element) => element,
+            Err(error) => {
+                self.recover(span, ctx, error, at_class_element_start);
+                self.ast.invalid_class_element(self.end_span(span))
+            }
+        }
+    }
+
+    fn recover(&mut self, span: Span, ctx: Context, error: Error, at_start: fn(Kind) -> bool) {
+        self.error(error);
+        // Reset the state left behind by the failed parse
+        self.ctx = ctx;
+        self.lexer.set_context(LexerContext::Regular);
+        self.state.consume_decorators();
+
+        // Always make progress, the broken source may start at a stray token
+        if self.cur_token().start == span.start && !self.at(Kind::Eof) {
+            self.bump_any();
+        }
+
+        // Brace depth, and the brace depths of the open template substitutions
+        let mut depth = 0u32;
+        let mut templates = vec![];
+        let mut prev_kind = Kind::Undetermined;
+        loop {
+            let top_level = depth == 0 && templates.is_empty();
+            match self.cur_kind() {
+                Kind::Eof => break,
+                kind if top_level && self.cur_token().is_on_new_line && at_start(kind) => break,
+                Kind::Semicolon if top_level => {
+                    self.bump_any();
+                    break;
+                }
+                Kind::RCurly if templates.last() == Some(&depth) => {
+                    self.re_lex_template_substitution_tail();
+                    if self.at(Kind::TemplateTail) {
+                        templates.pop();
+                    }
+                }
+                Kind::RCurly if depth == 0 => break,
+                Kind::RCurly => depth -= 1,
+                Kind::LCurly => depth += 1,
+                Kind::TemplateHead => templates.push(depth),
+                Kind::Slash | Kind::SlashEq if !ends_expression(prev_kind) => self.read_regex(),
+                _ => {}
+            }
+            prev_kind = self.cur_kind();
+            self.bump_any();
+        }
+    }
+}
+
+/// Tokens after which a `/` is a division rather than the start of a regular expression
+fn ends_expression(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::RParen
+            | Kind::RBrack
+            | Kind::RCurly
+            | Kind::Plus2
+            | Kind::Minus2
+            | Kind::NoSubstitutionTemplate
+            | Kind::TemplateTail
+            | Kind::This
+            | Kind::Super
+    ) || kind.is_literal()
+        || (kind.is_identifier_name() && !kind.is_reserved_keyword())
+}
+
+/// Keywords which begin a statement on a new line
+fn at_statement_start(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Kind::Var
+            | Kind::Let
+            | Kind::Const
+            | Kind::Function
+            | Kind::Async
+            | Kind::Class
+            | Kind::If
+            | Kind::For
+            | Kind::While
+            | Kind::Do
+            | Kind::Return
+            | Kind::Try
+            | Kind::Throw
+            | Kind::Switch
+            | Kind::Break
+            | Kind::Continue
+            | Kind::Debugger
+            | Kind::With
+            | Kind::Import
+            | Kind::Export
+            | Kind::Interface
+            | Kind::Enum
+            | Kind::Declare
+    )
+}
+
+/// Tokens which begin a class member on a new line
+fn at_class_element_start(kind: Kind) -> bool {
+    kind.is_class_element_name_start() || matches!(kind, Kind::Star | Kind::At)
+}
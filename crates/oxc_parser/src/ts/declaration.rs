@@ -5,38 +5,43 @@ impl<'a> Parser<'a> {
     fn at_start_of_ts_declaration_worker(&mut self) -> bool {
         loop {
             match self.cur_kind() {
-                Kind::Var | Kind::Let | Kind::Const | Kind::Function | Kind::Class | Kind::Enum => {
-                    return true;
-                }
+                Kind::Var | Kind::Let | Kind::Const | Kind::Function | Kind::Class => return true,
+                Kind::Enum => return self.ts_enabled(),
                 Kind::Interface | Kind::Type => {
                     self.bump_any();
                     return self.cur_kind().is_binding_identifier()
                         && !self.cur_token().is_on_new_line;
                 }
-                Kind::Module | Kind::Namespace => {
+                Kind::Opaque if self.flow_enabled() => {
+                    self.bump_any();
+                    return self.at(Kind::Type) && !self.cur_token().is_on_new_line;
+                }
+                Kind::Module | Kind::Namespace if self.ts_enabled() => {
                     self.bump_any();
                     return !self.cur_token().is_on_new_line
                         && (self.cur_kind().is_binding_identifier()
                             || self.cur_kind() == Kind::Str);
                 }
-                Kind::Abstract
+                kind @ (Kind::Abstract
                 | Kind::Accessor
                 | Kind::Async
                 | Kind::Declare
                 | Kind::Private
                 | Kind::Protected
                 | Kind::Public
-                | Kind::Readonly => {
+                | Kind::Readonly)
+                    if self.ts_enabled() || matches!(kind, Kind::Async | Kind::Declare) =>
+                {
                     self.bump_any();
                     if self.cur_token().is_on_new_line {
                         return false;
                     }
                 }
-                Kind::Global => {
+                Kind::Global if self.ts_enabled() => {
                     self.bump_any();
                     return matches!(self.cur_kind(), Kind::Ident | Kind::LCurly | Kind::Export);
                 }
-                Kind::Import => {
+                Kind::Import if self.ts_enabled() => {
                     self.bump_any();
                     return matches!(self.cur_kind(), Kind::Str | Kind::Star | Kind::LCurly)
                         || self.cur_kind().is_identifier();
@@ -2,7 +2,7 @@ use oxc_allocator::Box;
 use oxc_ast::{
     ast::*,
     context::{Context, StatementContext},
-    Span,
+    GetSpan, Span,
 };
 use oxc_diagnostics::Result;
 
@@ -14,7 +14,7 @@ use crate::js::declaration::{VariableDeclarationContext, VariableDeclarationPare
 use crate::js::function::FunctionKind;
 use crate::lexer::Kind;
 use crate::list::{NormalList, SeparatedList};
-use crate::Parser;
+use crate::{diagnostics, DecoratorFlavor, Parser};
 
 impl<'a> Parser<'a> {
     /** ------------------- Enum ------------------ */
@@ -231,7 +231,7 @@ impl<'a> Parser<'a> {
             self.expect(Kind::LCurly)?;
 
             while !self.eat(Kind::RCurly) && !self.at(Kind::Eof) {
-                let stmt = self.parse_ts_module_item()?;
+                let stmt = self.parse_statement_or_recover(Self::parse_ts_module_item);
                 statements.push(stmt);
             }
         }
@@ -260,7 +260,6 @@ impl<'a> Parser<'a> {
             TSModuleDeclarationBody::TSModuleDeclaration(decl)
         } else {
             let block = self.parse_ts_module_block()?;
-            self.asi()?;
             TSModuleDeclarationBody::TSModuleBlock(block)
         };
 
@@ -326,6 +325,10 @@ impl<'a> Parser<'a> {
             return true;
         }
 
+        if self.peek_at(Kind::Opaque) && self.flow_enabled() {
+            return true;
+        }
+
         if self.peek_at(Kind::Async) && self.nth_at(2, Kind::Function) {
             return true;
         }
@@ -361,10 +364,10 @@ impl<'a> Parser<'a> {
         modifiers: Modifiers<'a>,
     ) -> Result<Declaration<'a>> {
         match self.cur_kind() {
-            Kind::Namespace | Kind::Module => self
+            Kind::Namespace | Kind::Module if self.ts_enabled() => self
                 .parse_ts_namespace_or_module_declaration(modifiers)
                 .map(Declaration::TSModuleDeclaration),
-            Kind::Global => {
+            Kind::Global if self.ts_enabled() => {
                 let decl = if self.peek_at(Kind::LCurly) {
                     // valid syntax for
                     // declare global { }
@@ -375,14 +378,19 @@ impl<'a> Parser<'a> {
                 Ok(Declaration::TSModuleDeclaration(decl))
             }
             Kind::Type => self.parse_ts_type_alias_declaration(start_span, modifiers),
-            Kind::Enum => self.parse_ts_enum_declaration(start_span, modifiers),
+            Kind::Opaque if self.flow_enabled() => {
+                self.parse_flow_opaque_type(start_span, modifiers)
+            }
+            Kind::Enum if self.ts_enabled() => {
+                self.parse_ts_enum_declaration(start_span, modifiers)
+            }
             Kind::Interface if self.is_at_interface_declaration() => {
                 self.parse_ts_interface_declaration(start_span, modifiers)
             }
             Kind::Class => self
                 .parse_class_declaration(start_span, modifiers)
                 .map(Declaration::ClassDeclaration),
-            Kind::Import => {
+            Kind::Import if self.ts_enabled() => {
                 self.bump_any();
                 self.parse_ts_import_equals_declaration(start_span, true)
             }
@@ -439,9 +447,9 @@ impl<'a> Parser<'a> {
         is_export: bool,
     ) -> Result<Declaration<'a>> {
         let import_kind = if !self.peek_at(Kind::Eq) && self.eat(Kind::Type) {
-            ImportOrExportKind::Value
-        } else {
             ImportOrExportKind::Type
+        } else {
+            ImportOrExportKind::Value
         };
 
         let id = self.parse_binding_identifier()?;
@@ -486,15 +494,13 @@ impl<'a> Parser<'a> {
         let in_decorator = self.ctx.has_decorator();
         self.ctx = self.ctx.and_decorator(true);
 
-        let mut decorators = self.ast.new_vec();
         while self.at(Kind::At) {
             let decorator = self.parse_decorator()?;
-            decorators.push(decorator);
+            self.state.decorators.push(decorator);
         }
 
         self.ctx = self.ctx.and_decorator(in_decorator);
 
-        self.state.decorators = decorators;
         Ok(())
     }
 
@@ -502,7 +508,11 @@ impl<'a> Parser<'a> {
         let span = self.start_span();
         self.bump_any(); // bump @
         let expr = self.with_context(Context::Decorator, Self::parse_lhs_expression)?;
-        Ok(self.ast.decorator(self.end_span(span), expr))
+        let decorator = self.ast.decorator(self.end_span(span), expr);
+        if self.decorators == DecoratorFlavor::Standard && !decorator.is_standard() {
+            self.error(diagnostics::InvalidDecoratorExpression(decorator.expression.span()));
+        }
+        Ok(decorator)
     }
 
     pub fn eat_modifiers_before_declaration(&mut self) -> (ModifierFlags, Modifiers<'a>) {
@@ -122,7 +122,7 @@ impl<'a> Parser<'a> {
     pub fn parse_ts_type_parameters(
         &mut self,
     ) -> Result<Option<Box<'a, TSTypeParameterDeclaration<'a>>>> {
-        if !self.ts_enabled() {
+        if !self.types_enabled() {
             return Ok(None);
         }
         if !self.at(Kind::LAngle) {
@@ -176,7 +176,8 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_ts_type_constraint(&mut self) -> Result<Option<TSType<'a>>> {
-        if !self.at(Kind::Extends) {
+        // Flow bound `<T: Bound>`
+        if !(self.at(Kind::Extends) || self.flow_enabled() && self.at(Kind::Colon)) {
             return Ok(None);
         }
         self.bump_any();
@@ -240,10 +241,11 @@ impl<'a> Parser<'a> {
             Ok(self.ast.ts_union_type(self.end_span(span), types))
         } else {
             let first = self.parse_ts_intersection_type()?;
-            if self.at(Kind::Pipe) {
+            if self.at_union_pipe() {
                 let mut types = self.ast.new_vec();
                 types.push(first);
-                while self.eat(Kind::Pipe) {
+                while self.at_union_pipe() {
+                    self.bump_any();
                     types.push(self.parse_ts_intersection_type()?);
                 }
                 Ok(self.ast.ts_union_type(self.end_span(span), types))
@@ -253,6 +255,11 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// The `|` of a Flow exact object type `{| a: T |}` closes the type
+    fn at_union_pipe(&mut self) -> bool {
+        self.at(Kind::Pipe) && !(self.flow_enabled() && self.peek_at(Kind::RCurly))
+    }
+
     // test ts ts_intersection_type
     // type A = string & number;
     // type B = & A & void & null;
@@ -350,6 +357,11 @@ impl<'a> Parser<'a> {
                 result
             }
             Kind::LBrack => self.parse_ts_tuple_type(),
+            Kind::LCurly
+                if self.flow_enabled() && matches!(self.peek_kind(), Kind::Pipe | Kind::Pipe2) =>
+            {
+                self.parse_flow_exact_object_type()
+            }
             Kind::LCurly => {
                 if self.is_at_mapped_type() {
                     self.parse_ts_mapped_type()
@@ -669,7 +681,7 @@ impl<'a> Parser<'a> {
         let mut member_list = TSInterfaceOrObjectBodyList::new(self);
         member_list.parse(self)?;
 
-        Ok(self.ast.ts_type_literal(self.end_span(span), member_list.body))
+        Ok(self.ast.ts_type_literal(self.end_span(span), member_list.body, /* exact */ false))
     }
 
     fn parse_ts_literal_type(&mut self) -> Result<TSType<'a>> {
@@ -831,7 +843,7 @@ impl<'a> Parser<'a> {
     pub fn parse_ts_return_type_annotation(
         &mut self,
     ) -> Result<Option<Box<'a, TSTypeAnnotation<'a>>>> {
-        if !self.ts_enabled() {
+        if !self.types_enabled() {
             return Ok(None);
         }
         if !self.at(Kind::Colon) {
@@ -961,7 +973,9 @@ impl<'a> Parser<'a> {
 
     pub fn parse_ts_property_or_method_signature_member(&mut self) -> Result<TSSignature<'a>> {
         let span = self.start_span();
-        let readonly = self.at(Kind::Readonly) && self.is_next_at_type_member_name();
+        // Flow covariant property `+a: T`
+        let readonly = (self.at(Kind::Readonly) && self.is_next_at_type_member_name())
+            || (self.flow_enabled() && self.at(Kind::Plus));
 
         if readonly {
             self.bump_any();
@@ -1085,18 +1099,23 @@ impl<'a> Parser<'a> {
         flags
     }
 
+    /// `?` alone is the unknown type, `?T` is nullable, which is the Flow maybe type.
     fn parse_js_doc_unknown_or_nullable_type(&mut self) -> Result<TSType<'a>> {
         let span = self.start_span();
         self.bump_any(); // bump `?`
-        let type_annotation = self.parse_ts_type()?;
-        let span = self.end_span(span);
         if matches!(
             self.cur_kind(),
             Kind::Comma | Kind::RCurly | Kind::RParen | Kind::RAngle | Kind::Eq | Kind::Pipe
         ) {
-            Ok(self.ast.js_doc_unknown_type(span))
-        } else {
-            Ok(self.ast.js_doc_nullable_type(span, type_annotation, /* postfix */ false))
+            return Ok(self.ast.js_doc_unknown_type(self.end_span(span)));
         }
+        // `?A | B` is `(?A) | B` in Flow, but `?() => A` is a maybe function
+        let type_annotation = if self.flow_enabled() && !self.is_at_function_type() {
+            self.parse_ts_primary_type()?
+        } else {
+            self.parse_ts_type()?
+        };
+        let span = self.end_span(span);
+        Ok(self.ast.js_doc_nullable_type(span, type_annotation, /* postfix */ false))
     }
 }
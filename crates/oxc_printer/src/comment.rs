@@ -0,0 +1,197 @@
+//! Comment preservation
+//!
+//! Comments are not part of the AST, they are printed in source order before the first
+//! statement or expression which starts after them, or after the statement ending on the same line.
+//! With `minify_whitespace`, only legal comments and annotations are kept.
+
+use oxc_ast::{CommentKind, Span, Trivias};
+
+use crate::Printer;
+
+pub struct PrintedComment {
+    /// Span of the full comment, including `//`, `/*` and `*/`
+    span: Span,
+    kind: CommentKind,
+    text: String,
+    /// End of the code before the comment, when it is on the same line.
+    /// Used for finding trailing comments.
+    same_line_code_end: Option<u32>,
+}
+
+impl PrintedComment {
+    fn is_single_line(&self) -> bool {
+        self.kind == CommentKind::SingleLine
+    }
+
+    /// Multi-line comments without line breaks can be printed between any two tokens
+    /// without changing automatic semicolon insertion.
+    fn is_inline(&self) -> bool {
+        !self.is_single_line() && !self.text.contains(['\n', '\r', '\u{2028}', '\u{2029}'])
+    }
+}
+
+/// Legal comments: `/*! ... */`, `//! ...` and comments containing `@license` or `@preserve`.
+fn is_legal_comment(text: &str) -> bool {
+    text.starts_with('!') || text.contains("@license") || text.contains("@preserve")
+}
+
+/// Annotations read by bundlers, e.g. `/*#__PURE__*/` and `/* webpackChunkName: "chunk" */`.
+fn is_annotation_comment(text: &str) -> bool {
+    let text = text.trim_start();
+    text.starts_with("#__")
+        || text.starts_with("@__")
+        || text.starts_with("webpack")
+        || text.starts_with("@vite-ignore")
+}
+
+/// Collect the comments to print from the source text.
+#[allow(clippy::cast_possible_truncation)]
+pub fn collect_comments(
+    source_text: &str,
+    trivias: &Trivias,
+    minify_whitespace: bool,
+) -> Vec<PrintedComment> {
+    trivias
+        .comments()
+        .filter_map(|(span, kind)| {
+            let content = &source_text[span.start as usize..span.end as usize];
+            if minify_whitespace && !is_legal_comment(content) && !is_annotation_comment(content) {
+                return None;
+            }
+            let span = match kind {
+                CommentKind::SingleLine => Span::new(span.start - 2, span.end),
+                CommentKind::MultiLine => Span::new(span.start - 2, span.end + 2),
+            };
+            let before = source_text[..span.start as usize].trim_end_matches([' ', '\t']);
+            let same_line_code_end = (!before.is_empty() && !before.ends_with(['\n', '\r']))
+                .then_some(before.len() as u32);
+            let text = source_text[span.start as usize..span.end as usize].to_string();
+            Some(PrintedComment { span, kind, text, same_line_code_end })
+        })
+        .collect()
+}
+
+impl Printer {
+    fn next_comment(&self) -> Option<&PrintedComment> {
+        self.comments.get(self.next_comment)
+    }
+
+    /// Print the comments before `start` on their own lines,
+    /// used before statements and at the end of blocks.
+    pub(crate) fn print_leading_comments(&mut self, start: u32) {
+        while let Some(comment) = self.next_comment()
+            && comment.span.end <= start
+        {
+            let is_single_line = comment.is_single_line();
+            let text = std::mem::take(&mut self.comments[self.next_comment].text);
+            self.next_comment += 1;
+            if self.options.minify_whitespace {
+                self.print_comment_text(&text);
+                if is_single_line {
+                    self.print(b'\n');
+                }
+            } else {
+                self.print_indent();
+                self.print_comment_text(&text);
+                self.print(b'\n');
+            }
+        }
+    }
+
+    /// Print the comments before an expression starting at `start`,
+    /// stopping at the first comment which cannot be printed inline.
+    pub(crate) fn print_inline_comments(&mut self, start: u32) {
+        while let Some(comment) = self.next_comment()
+            && comment.span.end <= start
+            && comment.is_inline()
+        {
+            let text = std::mem::take(&mut self.comments[self.next_comment].text);
+            self.next_comment += 1;
+            self.print_comment_text(&text);
+            self.print_space();
+        }
+    }
+
+    /// Print the comments following the statement ending at `end` on the same line.
+    /// Minified code has no lines, the comments are printed before the next statement instead.
+    pub(crate) fn print_trailing_comments(&mut self, end: u32) {
+        if self.options.minify_whitespace {
+            return;
+        }
+        let mut end = end;
+        // `a; /* b */ // c`, each comment follows the previous one.
+        while let Some(comment) = self.next_comment()
+            && comment.same_line_code_end == Some(end)
+        {
+            let is_single_line = comment.is_single_line();
+            end = comment.span.end;
+            let text = std::mem::take(&mut self.comments[self.next_comment].text);
+            self.next_comment += 1;
+            let ends_with_newline = self.last_char() == Some(&b'\n');
+            if ends_with_newline {
+                self.code.pop();
+            }
+            self.print(b' ');
+            self.print_comment_text(&text);
+            if ends_with_newline || is_single_line {
+                self.print(b'\n');
+            }
+        }
+    }
+
+    fn print_comment_text(&mut self, text: &str) {
+        // `a / /* b */ c` must not become a single-line comment
+        if self.last_char() == Some(&b'/') {
+            self.print(b' ');
+        }
+        self.print_str(text.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use crate::{Printer, PrinterOptions};
+
+    fn print(source_text: &str, minify_whitespace: bool) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        assert!(ret.errors.is_empty());
+        let options = PrinterOptions { minify_whitespace, ..PrinterOptions::default() };
+        Printer::new(source_text.len(), options)
+            .with_comments(source_text, &ret.trivias)
+            .build(&ret.program)
+    }
+
+    #[test]
+    fn leading_and_trailing() {
+        let source_text =
+            "// a\nfoo(); // b\n/* c */ bar(/* d */ 1);\nfunction f() {\n  // e\n}\n// f\n";
+        let printed = print(source_text, false);
+        assert_eq!(
+            printed,
+            "// a\nfoo(); // b\n/* c */\nbar(/* d */ 1);\nfunction f () {\n    // e\n}\n// f\n"
+        );
+        assert_eq!(print(&printed, false), printed);
+    }
+
+    #[test]
+    fn asi_hazards() {
+        // a single-line comment must not be moved between `return` and its argument
+        let printed = print("function f() {\n  return ( // a\n    1\n  );\n}\n", false);
+        assert_eq!(printed, "function f () {\n    return (1);\n    // a\n}\n");
+        assert_eq!(print("a / /*! b */ c;", true), "a/ /*! b */c");
+    }
+
+    #[test]
+    fn minify_keeps_legal_comments_and_annotations() {
+        let source_text = "/*! legal */\n// @license MIT\n// comment\nconst a = /*#__PURE__*/ foo(); /* comment */\nimport(/* webpackChunkName: \"b\" */ 'b');\n";
+        assert_eq!(
+            print(source_text, true),
+            "/*! legal */ // @license MIT\nconst a=/*#__PURE__*/foo();import(/* webpackChunkName: \"b\" */'b')"
+        );
+    }
+}
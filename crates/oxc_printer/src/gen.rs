@@ -1,8 +1,8 @@
-use oxc_allocator::{Box, Vec};
+use oxc_allocator::Box;
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, GetSpan};
 
-use crate::{Printer, Separator};
+use crate::Printer;
 
 pub trait Gen {
     fn gen(&self, p: &mut Printer);
@@ -26,22 +26,27 @@ impl<'a> Gen for Program<'a> {
             p.print_semicolon_if_needed();
             stmt.gen(p);
         }
+        p.print_leading_comments(u32::MAX);
     }
 }
 
 impl<'a> Gen for Directive<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_leading_comments(self.span.start);
         p.print_indent();
         p.print(b'"');
         p.print_str(self.directive.as_bytes());
         p.print(b'"');
         p.print_semicolon();
         p.print_newline();
+        p.print_trailing_comments(self.span.end);
     }
 }
 
 impl<'a> Gen for Statement<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_leading_comments(self.span().start);
+        p.add_source_mapping(self.span().start);
         match self {
             Self::BlockStatement(stmt) => stmt.gen(p),
             Self::BreakStatement(stmt) => stmt.gen(p),
@@ -55,15 +60,24 @@ impl<'a> Gen for Statement<'a> {
             Self::ForStatement(stmt) => stmt.gen(p),
             Self::IfStatement(stmt) => stmt.gen(p),
             Self::LabeledStatement(stmt) => stmt.gen(p),
-            Self::ModuleDeclaration(decl) => decl.gen(p),
+            Self::ModuleDeclaration(decl) => {
+                p.print_indent();
+                decl.gen(p);
+            }
             Self::ReturnStatement(stmt) => stmt.gen(p),
             Self::SwitchStatement(stmt) => stmt.gen(p),
             Self::ThrowStatement(stmt) => stmt.gen(p),
             Self::TryStatement(stmt) => stmt.gen(p),
             Self::WhileStatement(stmt) => stmt.gen(p),
             Self::WithStatement(stmt) => stmt.gen(p),
-            Self::Declaration(decl) => decl.gen(p),
+            // the source skipped by the error recovery is not printed
+            Self::InvalidStatement(_) => {}
+            Self::Declaration(decl) => {
+                p.print_indent();
+                decl.gen(p);
+            }
         }
+        p.print_trailing_comments(self.span().end);
     }
 }
 
@@ -272,7 +286,7 @@ impl Gen for ContinueStatement {
         p.print_indent();
         p.print_str(b"continue");
         if let Some(label) = &self.label {
-            p.print_space();
+            p.print(b' ');
             label.gen(p);
         }
         p.print_semicolon_after_statement();
@@ -284,7 +298,7 @@ impl Gen for BreakStatement {
         p.print_indent();
         p.print_str(b"break");
         if let Some(label) = &self.label {
-            p.print_space();
+            p.print(b' ');
             label.gen(p);
         }
         p.print_semicolon_after_statement();
@@ -431,6 +445,8 @@ impl<'a> Gen for ModuleDeclarationKind<'a> {
             Self::ExportAllDeclaration(decl) => decl.gen(p),
             Self::ExportDefaultDeclaration(decl) => decl.gen(p),
             Self::ExportNamedDeclaration(decl) => decl.gen(p),
+            Self::TSExportAssignment(decl) if p.print_typescript() => decl.gen(p),
+            Self::TSNamespaceExportDeclaration(decl) if p.print_typescript() => decl.gen(p),
             Self::TSExportAssignment(_) | Self::TSNamespaceExportDeclaration(_) => {}
         }
     }
@@ -440,12 +456,10 @@ impl<'a> Gen for Declaration<'a> {
     fn gen(&self, p: &mut Printer) {
         match self {
             Self::VariableDeclaration(stmt) => {
-                p.print_indent();
                 stmt.gen(p);
                 p.print_semicolon_after_statement();
             }
             Self::FunctionDeclaration(stmt) => {
-                p.print_indent();
                 stmt.gen(p);
                 p.print_newline();
             }
@@ -453,21 +467,44 @@ impl<'a> Gen for Declaration<'a> {
                 declaration.gen(p);
                 p.print_newline();
             }
-            Self::TSTypeAliasDeclaration(_)
-            | Self::TSInterfaceDeclaration(_)
-            | Self::TSEnumDeclaration(_)
-            | Self::TSModuleDeclaration(_)
-            | Self::TSImportEqualsDeclaration(_) => {}
+            _ if !p.print_typescript() => {}
+            Self::TSTypeAliasDeclaration(decl) => {
+                decl.gen(p);
+                p.print_semicolon_after_statement();
+            }
+            Self::TSInterfaceDeclaration(decl) => {
+                decl.gen(p);
+                p.print_newline();
+            }
+            Self::TSEnumDeclaration(decl) => {
+                decl.gen(p);
+                p.print_newline();
+            }
+            Self::TSModuleDeclaration(decl) => {
+                decl.gen(p);
+                p.print_newline();
+            }
+            Self::TSImportEqualsDeclaration(decl) => {
+                decl.gen(p);
+                p.print_semicolon_after_statement();
+            }
+            Self::FlowOpaqueType(decl) => {
+                decl.gen(p);
+                p.print_semicolon_after_statement();
+            }
         }
     }
 }
 
 impl<'a> Gen for VariableDeclaration<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
         p.print_str(match self.kind {
             VariableDeclarationKind::Const => b"const",
             VariableDeclarationKind::Let => b"let",
             VariableDeclarationKind::Var => b"var",
+            VariableDeclarationKind::Using => b"using",
+            VariableDeclarationKind::AwaitUsing => b"await using",
         });
         p.print(b' ');
         p.print_list(&self.declarations);
@@ -476,7 +513,11 @@ impl<'a> Gen for VariableDeclaration<'a> {
 
 impl<'a> Gen for VariableDeclarator<'a> {
     fn gen(&self, p: &mut Printer) {
-        self.id.gen(p);
+        self.id.kind.gen(p);
+        if self.definite && p.print_typescript() {
+            p.print(b'!');
+        }
+        p.print_type_annotation(self.id.type_annotation.as_deref());
         if let Some(init) = &self.init {
             p.print_space();
             p.print_equal();
@@ -488,6 +529,7 @@ impl<'a> Gen for VariableDeclarator<'a> {
 
 impl<'a> Gen for Function<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
         if self.r#async {
             p.print_str(b"async");
             p.print(b' ');
@@ -501,15 +543,28 @@ impl<'a> Gen for Function<'a> {
                 p.print(b' ');
             }
             id.gen(p);
+            p.print_type_parameters(self.type_parameters.as_deref());
             p.print_space();
+        } else {
+            p.print_type_parameters(self.type_parameters.as_deref());
         }
         p.print(b'(');
         self.params.gen(p);
         p.print(b')');
-        p.print_space();
-        if let Some(body) = &self.body {
+        p.print_type_annotation(self.return_type.as_deref());
+        print_function_body(self.body.as_deref(), p);
+    }
+}
+
+/// Overloads and declared functions have no body.
+fn print_function_body(body: Option<&FunctionBody<'_>>, p: &mut Printer) {
+    match body {
+        Some(body) => {
+            p.print_space();
             body.gen(p);
         }
+        None if p.print_typescript() => p.print_semicolon(),
+        None => p.print_space(),
     }
 }
 
@@ -526,6 +581,7 @@ impl<'a> Gen for FunctionBody<'a> {
             p.print_semicolon_if_needed();
             stmt.gen(p);
         }
+        p.print_leading_comments(self.span.end);
         p.dedent();
         p.print_indent();
         p.print(b'}');
@@ -535,6 +591,11 @@ impl<'a> Gen for FunctionBody<'a> {
 
 impl<'a> Gen for FormalParameter<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_decorators(&self.decorators);
+        p.print_accessibility(self.accessibility);
+        if self.readonly && p.print_typescript() {
+            p.print_str(b"readonly ");
+        }
         self.pattern.gen(p);
     }
 }
@@ -548,11 +609,12 @@ impl<'a> Gen for FormalParameters<'a> {
 impl<'a> Gen for ImportDeclaration<'a> {
     fn gen(&self, p: &mut Printer) {
         p.print_str(b"import ");
+        if self.import_kind == Some(ImportOrExportKind::Type) && p.print_typescript() {
+            p.print_str(b"type ");
+        }
         if self.specifiers.is_empty() {
-            p.print(b'\'');
-            p.print_str(self.source.value.as_bytes());
-            p.print(b'\'');
-            self.assertions.gen(p);
+            self.source.gen(p);
+            self.with_clause.gen(p);
             p.print_semicolon_after_statement();
             return;
         }
@@ -597,6 +659,9 @@ impl<'a> Gen for ImportDeclaration<'a> {
                         p.print(b'{');
                     }
                     p.print_space();
+                    if spec.import_kind.is_type() && p.print_typescript() {
+                        p.print_str(b"type ");
+                    }
 
                     let imported_name = match &spec.imported {
                         ModuleExportName::Identifier(identifier) => {
@@ -609,9 +674,9 @@ impl<'a> Gen for ImportDeclaration<'a> {
                         }
                     };
 
-                    let local_name = spec.local.name.as_bytes();
+                    let local_name = p.mangled_name(spec.local.span).unwrap_or(&spec.local.name);
 
-                    if imported_name != local_name {
+                    if imported_name != local_name.as_bytes() {
                         p.print_str(b" as ");
                         spec.local.gen(p);
                     }
@@ -624,18 +689,24 @@ impl<'a> Gen for ImportDeclaration<'a> {
         }
         p.print_str(b" from ");
         self.source.gen(p);
-        self.assertions.gen(p);
+        self.with_clause.gen(p);
         p.print_semicolon_after_statement();
     }
 }
 
-impl<'a> Gen for Option<Vec<'a, ImportAttribute>> {
+impl<'a> Gen for Option<WithClause<'a>> {
     fn gen(&self, p: &mut Printer) {
-        if let Some(assertions) = &self {
+        if let Some(with_clause) = &self {
             p.print_space();
-            p.print_str(b"assert");
+            p.print_str(with_clause.keyword.as_str().as_bytes());
             p.print_space();
-            p.print_block(assertions, Separator::Comma);
+            p.print(b'{');
+            if !with_clause.attributes.is_empty() {
+                p.print_space();
+                p.print_list(&with_clause.attributes);
+                p.print_space();
+            }
+            p.print(b'}');
         };
     }
 }
@@ -644,7 +715,7 @@ impl Gen for ImportAttribute {
     fn gen(&self, p: &mut Printer) {
         match &self.key {
             ImportAttributeKey::Identifier(identifier) => {
-                p.print_str(identifier.name.as_bytes());
+                p.print_identifier(&identifier.name);
             }
             ImportAttributeKey::StringLiteral(literal) => literal.gen(p),
         };
@@ -656,33 +727,78 @@ impl Gen for ImportAttribute {
 
 impl<'a> Gen for ExportNamedDeclaration<'a> {
     fn gen(&self, p: &mut Printer) {
-        p.print_str(b"export ");
-        match &self.declaration {
-            Some(decl) => decl.gen(p),
-            None => {
-                p.print(b'{');
-                if !self.specifiers.is_empty() {
-                    p.print_space();
-                    p.print_list(&self.specifiers);
-                    p.print_space();
+        if let Some(decl) = &self.declaration {
+            // A mangled binding is still exported under its name: `let a=1;export {a as foo}`
+            let names = decl.bound_names();
+            if names.iter().any(|ident| p.mangled_name(ident.span).is_some()) {
+                decl.gen(p);
+                p.print_semicolon_if_needed();
+                p.print_indent();
+                p.print_str(b"export {");
+                for (index, ident) in names.iter().enumerate() {
+                    if index != 0 {
+                        p.print_comma();
+                        p.print_space();
+                    }
+                    p.print_symbol(ident.span, &ident.name);
+                    if p.mangled_name(ident.span).is_some() {
+                        p.print_str(b" as ");
+                        p.print_identifier(&ident.name);
+                    }
                 }
                 p.print(b'}');
-                if let Some(source) = &self.source {
-                    p.print_space();
-                    p.print_str(b"from");
-                    p.print_space();
-                    source.gen(p);
-                }
                 p.print_semicolon_after_statement();
+                return;
+            }
+        }
+        if let Some(Declaration::ClassDeclaration(class)) = &self.declaration {
+            // decorators before `export` are valid with both standard and legacy decorators
+            p.print_decorators(&class.decorators);
+            p.print_str(b"export ");
+            print_class(class, p);
+            p.print_newline();
+            return;
+        }
+        p.print_str(b"export ");
+        if let Some(decl) = &self.declaration {
+            decl.gen(p);
+        } else {
+            if self.is_typescript_syntax() && p.print_typescript() {
+                p.print_str(b"type ");
+            }
+            p.print(b'{');
+            if !self.specifiers.is_empty() {
+                p.print_space();
+                p.print_list(&self.specifiers);
+                p.print_space();
+            }
+            p.print(b'}');
+            if let Some(source) = &self.source {
+                p.print_space();
+                p.print_str(b"from");
+                p.print_space();
+                source.gen(p);
+                self.with_clause.gen(p);
             }
+            p.print_semicolon_after_statement();
         }
     }
 }
 
 impl Gen for ExportSpecifier {
     fn gen(&self, p: &mut Printer) {
-        self.local.gen(p);
-        if self.local.name() != self.exported.name() {
+        if self.export_kind.is_type() && p.print_typescript() {
+            p.print_str(b"type ");
+        }
+        let mut renamed = false;
+        match &self.local {
+            ModuleExportName::Identifier(ident) => {
+                renamed = p.mangled_name(ident.span).is_some();
+                p.print_symbol(ident.span, &ident.name);
+            }
+            ModuleExportName::StringLiteral(literal) => literal.gen(p),
+        }
+        if renamed || self.local.name() != self.exported.name() {
             p.print_str(b" as ");
             self.exported.gen(p);
         }
@@ -693,7 +809,7 @@ impl Gen for ModuleExportName {
     fn gen(&self, p: &mut Printer) {
         match self {
             Self::Identifier(identifier) => {
-                p.print_str(identifier.name.as_bytes());
+                p.print_identifier(&identifier.name);
             }
             Self::StringLiteral(literal) => literal.gen(p),
         };
@@ -703,6 +819,9 @@ impl Gen for ModuleExportName {
 impl<'a> Gen for ExportAllDeclaration<'a> {
     fn gen(&self, p: &mut Printer) {
         p.print_str(b"export");
+        if self.export_kind == Some(ImportOrExportKind::Type) && p.print_typescript() {
+            p.print_str(b" type");
+        }
         p.print_space();
         p.print(b'*');
 
@@ -715,7 +834,7 @@ impl<'a> Gen for ExportAllDeclaration<'a> {
         p.print_str(b" from");
         p.print_space();
         self.source.gen(p);
-        self.assertions.gen(p);
+        self.with_clause.gen(p);
 
         p.print_semicolon_after_statement();
     }
@@ -723,6 +842,13 @@ impl<'a> Gen for ExportAllDeclaration<'a> {
 
 impl<'a> Gen for ExportDefaultDeclaration<'a> {
     fn gen(&self, p: &mut Printer) {
+        if let ExportDefaultDeclarationKind::ClassDeclaration(class) = &self.declaration {
+            p.print_decorators(&class.decorators);
+            p.print_str(b"export default ");
+            print_class(class, p);
+            p.print_newline();
+            return;
+        }
         p.print_str(b"export default ");
         self.declaration.gen(p);
     }
@@ -734,8 +860,22 @@ impl<'a> Gen for ExportDefaultDeclarationKind<'a> {
                 expr.gen(p);
                 p.print_semicolon_after_statement();
             }
-            Self::FunctionDeclaration(fun) => fun.gen(p),
-            Self::ClassDeclaration(value) => value.gen(p),
+            Self::FunctionDeclaration(fun) => {
+                fun.gen(p);
+                p.print_newline();
+            }
+            Self::ClassDeclaration(value) => {
+                value.gen(p);
+                p.print_newline();
+            }
+            Self::TSInterfaceDeclaration(decl) if p.print_typescript() => {
+                decl.gen(p);
+                p.print_newline();
+            }
+            Self::TSEnumDeclaration(decl) if p.print_typescript() => {
+                decl.gen(p);
+                p.print_newline();
+            }
             Self::TSInterfaceDeclaration(_) | Self::TSEnumDeclaration(_) => {}
         }
     }
@@ -743,6 +883,8 @@ impl<'a> Gen for ExportDefaultDeclarationKind<'a> {
 
 impl<'a> Gen for Expression<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_inline_comments(self.span().start);
+        p.add_source_mapping(self.span().start);
         match self {
             Self::BooleanLiteral(lit) => lit.gen(p),
             Self::NullLiteral(lit) => lit.gen(p),
@@ -779,35 +921,35 @@ impl<'a> Gen for Expression<'a> {
             Self::ClassExpression(expr) => expr.gen(p),
             Self::JSXElement(el) => el.gen(p),
             Self::JSXFragment(fragment) => fragment.gen(p),
-            Self::TSAsExpression(expr) => expr.expression.gen(p),
-            Self::TSTypeAssertion(expr) => expr.expression.gen(p),
-            Self::TSNonNullExpression(expr) => expr.expression.gen(p),
-            Self::TSInstantiationExpression(expr) => expr.expression.gen(p),
+            Self::TSAsExpression(expr) => expr.gen(p),
+            Self::TSTypeAssertion(expr) => expr.gen(p),
+            Self::TSNonNullExpression(expr) => expr.gen(p),
+            Self::TSInstantiationExpression(expr) => expr.gen(p),
         }
     }
 }
 
 impl Gen for IdentifierReference {
     fn gen(&self, p: &mut Printer) {
-        p.print_identifier(self.name.as_bytes());
+        p.print_symbol(self.span, &self.name);
     }
 }
 
 impl Gen for IdentifierName {
     fn gen(&self, p: &mut Printer) {
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
 impl Gen for BindingIdentifier {
     fn gen(&self, p: &mut Printer) {
-        p.print_identifier(self.name.as_bytes());
+        p.print_symbol(self.span, &self.name);
     }
 }
 
 impl Gen for LabelIdentifier {
     fn gen(&self, p: &mut Printer) {
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
@@ -839,7 +981,10 @@ impl Gen for BigintLiteral {
 impl Gen for RegExpLiteral {
     fn gen(&self, p: &mut Printer) {
         p.print(b'/');
-        p.print_str(self.regex.pattern.as_bytes());
+        // Astral characters can only be escaped with `\u{...}` in unicode mode,
+        // a surrogate pair matches the same code units otherwise.
+        let allow_braces = self.regex.flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        p.print_raw(&self.regex.pattern, allow_braces);
         p.print(b'/');
         p.print_str(self.regex.flags.to_string().as_bytes());
     }
@@ -847,11 +992,7 @@ impl Gen for RegExpLiteral {
 
 impl Gen for StringLiteral {
     fn gen(&self, p: &mut Printer) {
-        p.print(b'\'');
-        for c in self.value.chars() {
-            p.print_str(c.escape_default().to_string().as_bytes());
-        }
-        p.print(b'\'');
+        p.print_quoted_string(&self.value);
     }
 }
 
@@ -888,6 +1029,11 @@ impl<'a> Gen for StaticMemberExpression<'a> {
         self.object.gen(p);
         if self.optional {
             p.print(b'?');
+        } else if let Expression::NumberLiteral(lit) = &self.object {
+            // `1.a` is parsed as the number `1.` followed by `a`
+            if lit.raw.bytes().all(|b| b.is_ascii_digit() || b == b'_') {
+                p.print(b' ');
+            }
         }
         p.print(b'.');
         self.property.gen(p);
@@ -912,6 +1058,7 @@ impl<'a> Gen for CallExpression<'a> {
         if self.optional {
             p.print_str(b"?.");
         }
+        p.print_type_arguments(self.type_parameters.as_deref());
         p.print(b'(');
         p.print_list(&self.arguments);
         p.print(b')');
@@ -1013,13 +1160,12 @@ impl<'a> Gen for Property<'a> {
                 if self.computed {
                     p.print(b']');
                 }
+                p.print_type_parameters(func.type_parameters.as_deref());
                 p.print(b'(');
                 func.params.gen(p);
                 p.print(b')');
-                p.print_space();
-                if let Some(body) = &func.body {
-                    body.gen(p);
-                }
+                p.print_type_annotation(func.return_type.as_deref());
+                print_function_body(func.body.as_deref(), p);
                 return;
             }
         }
@@ -1031,7 +1177,11 @@ impl<'a> Gen for Property<'a> {
         if self.computed {
             p.print(b']');
         }
-        if self.shorthand {
+        // `{ a }` is printed as `{ a: b }` when the binding is renamed
+        let shorthand = self.shorthand
+            && !matches!(&self.key, PropertyKey::Identifier(key)
+                if p.mangled_name(key.span).is_some());
+        if shorthand {
             if let PropertyValue::Pattern(pat) = &self.value {
                 if let BindingPatternKind::AssignmentPattern(pattern) = &pat.kind {
                     p.print_space();
@@ -1073,9 +1223,23 @@ impl<'a> Gen for ArrowExpression<'a> {
             p.print_str(b"async");
             p.print_space();
         }
+        if let Some(type_parameters) = &self.type_parameters
+            && p.print_typescript()
+        {
+            p.print(b'<');
+            p.print_list(&type_parameters.params);
+            // `<T,>() => {}` is not parsed as a JSX element in TSX files
+            if let [param] = type_parameters.params.as_slice()
+                && param.constraint.is_none()
+            {
+                p.print_comma();
+            }
+            p.print(b'>');
+        }
         p.print(b'(');
         self.params.gen(p);
         p.print(b')');
+        p.print_type_annotation(self.return_type.as_deref());
         p.print_space();
         p.print_str(b"=>");
         p.print_space();
@@ -1214,9 +1378,9 @@ impl<'a> Gen for SimpleAssignmentTarget<'a> {
         match self {
             Self::AssignmentTargetIdentifier(ident) => ident.gen(p),
             Self::MemberAssignmentTarget(member_expr) => member_expr.gen(p),
-            Self::TSAsExpression(expr) => expr.expression.gen(p),
-            Self::TSNonNullExpression(expr) => expr.expression.gen(p),
-            Self::TSTypeAssertion(expr) => expr.expression.gen(p),
+            Self::TSAsExpression(expr) => expr.gen(p),
+            Self::TSNonNullExpression(expr) => expr.gen(p),
+            Self::TSTypeAssertion(expr) => expr.gen(p),
         }
     }
 }
@@ -1297,6 +1461,11 @@ impl<'a> Gen for AssignmentTargetProperty<'a> {
 
 impl<'a> Gen for AssignmentTargetPropertyIdentifier<'a> {
     fn gen(&self, p: &mut Printer) {
+        if p.mangled_name(self.binding.span).is_some() {
+            p.print_identifier(&self.binding.name);
+            p.print_colon();
+            p.print_space();
+        }
         self.binding.gen(p);
         if let Some(expr) = &self.init {
             p.print_space();
@@ -1361,7 +1530,7 @@ impl<'a> Gen for TemplateLiteral<'a> {
         let mut expressions = self.expressions.iter();
 
         for quasi in &self.quasis {
-            p.print_str(quasi.value.raw.as_bytes());
+            p.print_raw(&quasi.value.raw, true);
 
             if let Some(expr) = expressions.next() {
                 p.print_str(b"${");
@@ -1377,6 +1546,7 @@ impl<'a> Gen for TemplateLiteral<'a> {
 impl<'a> Gen for TaggedTemplateExpression<'a> {
     fn gen(&self, p: &mut Printer) {
         self.tag.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
         self.quasi.gen(p);
     }
 }
@@ -1407,6 +1577,7 @@ impl<'a> Gen for NewExpression<'a> {
     fn gen(&self, p: &mut Printer) {
         p.print_str(b"new ");
         self.callee.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
         p.print(b'(');
         p.print_list(&self.arguments);
         p.print(b')');
@@ -1423,36 +1594,74 @@ impl Gen for MetaProperty {
 
 impl<'a> Gen for Class<'a> {
     fn gen(&self, p: &mut Printer) {
-        p.print_str(b"class");
-        if let Some(id) = &self.id {
-            p.print(b' ');
-            id.gen(p);
-        }
-        if let Some(super_class) = self.super_class.as_ref() {
-            p.print_str(b" extends ");
-            super_class.gen(p);
+        p.print_decorators(&self.decorators);
+        print_class(self, p);
+    }
+}
+
+/// A class without its decorators, which exports print before `export`
+fn print_class(class: &Class<'_>, p: &mut Printer) {
+    p.print_modifiers(&class.modifiers);
+    p.print_str(b"class");
+    if let Some(id) = &class.id {
+        p.print(b' ');
+        id.gen(p);
+    }
+    p.print_type_parameters(class.type_parameters.as_deref());
+    if let Some(super_class) = class.super_class.as_ref() {
+        p.print_str(b" extends ");
+        super_class.gen(p);
+        p.print_type_arguments(class.super_type_parameters.as_deref());
+    }
+    if let Some(implements) = &class.implements
+        && p.print_typescript()
+    {
+        p.print_str(b" implements ");
+        p.print_list(implements);
+    }
+    p.print_space();
+    p.print(b'{');
+    p.print_newline();
+    p.indent();
+    for item in &class.body.body {
+        if matches!(item, ClassElement::InvalidClassElement(_)) {
+            continue;
         }
-        p.print_space();
-        p.print(b'{');
-        p.print_newline();
-        p.indent();
-        for item in &self.body.body {
-            p.print_semicolon_if_needed();
-            p.print_indent();
-            item.gen(p);
-            if matches!(
+        p.print_semicolon_if_needed();
+        p.print_indent();
+        item.gen(p);
+        if matches!(
+            item,
+            ClassElement::PropertyDefinition(_) | ClassElement::AccessorProperty(_)
+        ) || (p.print_typescript()
+            && matches!(
                 item,
-                ClassElement::PropertyDefinition(_) | ClassElement::AccessorProperty(_)
-            ) {
-                p.print_semicolon_after_statement();
-            } else {
-                p.print_newline();
-            }
+                ClassElement::TSAbstractPropertyDefinition(_)
+                    | ClassElement::TSIndexSignature(_)
+            ))
+        {
+            p.print_semicolon_after_statement();
+        } else {
+            p.print_newline();
+        }
+    }
+    p.needs_semicolon = false;
+    p.dedent();
+    p.print_indent();
+    p.print(b'}');
+}
+
+impl<'a> Gen for Decorator<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'@');
+        // the parentheses of `@(a[0])` may not be in the AST
+        if self.is_standard() {
+            self.expression.gen(p);
+        } else {
+            p.print(b'(');
+            self.expression.gen(p);
+            p.print(b')');
         }
-        p.needs_semicolon = false;
-        p.dedent();
-        p.print_indent();
-        p.print(b'}');
     }
 }
 
@@ -1463,9 +1672,15 @@ impl<'a> Gen for ClassElement<'a> {
             Self::MethodDefinition(elem) => elem.gen(p),
             Self::PropertyDefinition(elem) => elem.gen(p),
             Self::AccessorProperty(elem) => elem.gen(p),
-            Self::TSAbstractMethodDefinition(_)
-            | Self::TSAbstractPropertyDefinition(_)
-            | Self::TSIndexSignature(_) => {}
+            Self::InvalidClassElement(_) => {}
+            _ if !p.print_typescript() => {}
+            Self::TSAbstractMethodDefinition(elem) => {
+                print_method_definition(&elem.method_definition, true, p);
+            }
+            Self::TSAbstractPropertyDefinition(elem) => {
+                print_property_definition(&elem.property_definition, true, p);
+            }
+            Self::TSIndexSignature(elem) => elem.gen(p),
         }
     }
 }
@@ -1476,10 +1691,19 @@ impl Gen for JSXIdentifier {
     }
 }
 
+/// `Foo` in `<Foo>` refers to a binding, which may be renamed
+fn print_jsx_reference(ident: &JSXIdentifier, p: &mut Printer) {
+    if let Some(mangled_name) = p.mangled_name(ident.span).cloned() {
+        p.print_str(mangled_name.as_bytes());
+    } else {
+        ident.gen(p);
+    }
+}
+
 impl<'a> Gen for JSXMemberExpressionObject<'a> {
     fn gen(&self, p: &mut Printer) {
         match self {
-            Self::Identifier(ident) => ident.gen(p),
+            Self::Identifier(ident) => print_jsx_reference(ident, p),
             Self::MemberExpression(member_expr) => member_expr.gen(p),
         }
     }
@@ -1496,7 +1720,7 @@ impl<'a> Gen for JSXMemberExpression<'a> {
 impl<'a> Gen for JSXElementName<'a> {
     fn gen(&self, p: &mut Printer) {
         match self {
-            Self::Identifier(identifier) => identifier.gen(p),
+            Self::Identifier(identifier) => print_jsx_reference(identifier, p),
             Self::NamespacedName(namespaced_name) => namespaced_name.gen(p),
             Self::MemberExpression(member_expr) => member_expr.gen(p),
         }
@@ -1581,6 +1805,7 @@ impl<'a> Gen for JSXOpeningElement<'a> {
     fn gen(&self, p: &mut Printer) {
         p.print_str(b"<");
         self.name.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
         for attr in &self.attributes {
             attr.gen(p);
         }
@@ -1671,6 +1896,7 @@ impl<'a> Gen for StaticBlock<'a> {
             p.print_semicolon_if_needed();
             stmt.gen(p);
         }
+        p.print_leading_comments(self.span.end);
         p.dedent();
         p.needs_semicolon = false;
         p.print_indent();
@@ -1680,64 +1906,114 @@ impl<'a> Gen for StaticBlock<'a> {
 
 impl<'a> Gen for MethodDefinition<'a> {
     fn gen(&self, p: &mut Printer) {
-        if self.r#static {
-            p.print_str(b"static ");
-        }
+        print_method_definition(self, false, p);
+    }
+}
 
-        match &self.kind {
-            MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {}
-            MethodDefinitionKind::Get => p.print_str(b"get "),
-            MethodDefinitionKind::Set => p.print_str(b"set "),
+fn print_method_definition(method: &MethodDefinition<'_>, r#abstract: bool, p: &mut Printer) {
+    p.print_decorators(&method.decorators);
+    p.print_accessibility(method.accessibility);
+    if method.r#static {
+        p.print_str(b"static ");
+    }
+    if p.print_typescript() {
+        if r#abstract {
+            p.print_str(b"abstract ");
         }
-
-        if self.value.r#async {
-            p.print_str(b"async ");
+        if method.r#override {
+            p.print_str(b"override ");
         }
+    }
 
-        if self.value.generator {
-            p.print_str(b"*");
-        }
+    match &method.kind {
+        MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {}
+        MethodDefinitionKind::Get => p.print_str(b"get "),
+        MethodDefinitionKind::Set => p.print_str(b"set "),
+    }
 
-        if self.computed {
-            p.print(b'[');
-        }
-        self.key.gen(p);
-        if self.computed {
-            p.print(b']');
-        }
-        p.print(b'(');
-        self.value.params.gen(p);
-        p.print(b')');
-        p.print_space();
-        if let Some(body) = &self.value.body {
-            body.gen(p);
-        }
+    if method.value.r#async {
+        p.print_str(b"async ");
+    }
+
+    if method.value.generator {
+        p.print_str(b"*");
     }
+
+    if method.computed {
+        p.print(b'[');
+    }
+    method.key.gen(p);
+    if method.computed {
+        p.print(b']');
+    }
+    if method.optional && p.print_typescript() {
+        p.print(b'?');
+    }
+    p.print_type_parameters(method.value.type_parameters.as_deref());
+    p.print(b'(');
+    method.value.params.gen(p);
+    p.print(b')');
+    p.print_type_annotation(method.value.return_type.as_deref());
+    print_function_body(method.value.body.as_deref(), p);
 }
 
 impl<'a> Gen for PropertyDefinition<'a> {
     fn gen(&self, p: &mut Printer) {
-        if self.r#static {
-            p.print_str(b"static ");
+        print_property_definition(self, false, p);
+    }
+}
+
+fn print_property_definition(
+    property: &PropertyDefinition<'_>,
+    r#abstract: bool,
+    p: &mut Printer,
+) {
+    p.print_decorators(&property.decorators);
+    if property.declare && p.print_typescript() {
+        p.print_str(b"declare ");
+    }
+    p.print_accessibility(property.accessibility);
+    if property.r#static {
+        p.print_str(b"static ");
+    }
+    if p.print_typescript() {
+        if r#abstract {
+            p.print_str(b"abstract ");
         }
-        if self.computed {
-            p.print(b'[');
+        if property.r#override {
+            p.print_str(b"override ");
         }
-        self.key.gen(p);
-        if self.computed {
-            p.print(b']');
+        if property.readonly {
+            p.print_str(b"readonly ");
         }
-        if let Some(value) = &self.value {
-            p.print_space();
-            p.print_equal();
-            p.print_space();
-            value.gen(p);
+    }
+    if property.computed {
+        p.print(b'[');
+    }
+    property.key.gen(p);
+    if property.computed {
+        p.print(b']');
+    }
+    if p.print_typescript() {
+        if property.optional {
+            p.print(b'?');
+        }
+        if property.definite {
+            p.print(b'!');
         }
     }
+    p.print_type_annotation(property.type_annotation.as_deref());
+    if let Some(value) = &property.value {
+        p.print_space();
+        p.print_equal();
+        p.print_space();
+        value.gen(p);
+    }
 }
 
 impl<'a> Gen for AccessorProperty<'a> {
     fn gen(&self, p: &mut Printer) {
+        p.print_decorators(&self.decorators);
         if self.r#static {
             p.print_str(b"static ");
         }
@@ -1761,18 +2037,28 @@ impl<'a> Gen for AccessorProperty<'a> {
 impl Gen for PrivateIdentifier {
     fn gen(&self, p: &mut Printer) {
         p.print(b'#');
-        p.print_str(self.name.as_bytes());
+        p.print_identifier(&self.name);
     }
 }
 
 impl<'a> Gen for BindingPattern<'a> {
     fn gen(&self, p: &mut Printer) {
-        match &self.kind {
-            BindingPatternKind::BindingIdentifier(ident) => ident.gen(p),
-            BindingPatternKind::ObjectPattern(pattern) => pattern.gen(p),
-            BindingPatternKind::RestElement(elem) => elem.gen(p),
-            BindingPatternKind::ArrayPattern(pattern) => pattern.gen(p),
-            BindingPatternKind::AssignmentPattern(pattern) => pattern.gen(p),
+        self.kind.gen(p);
+        if self.optional && p.print_typescript() {
+            p.print(b'?');
+        }
+        p.print_type_annotation(self.type_annotation.as_deref());
+    }
+}
+
+impl<'a> Gen for BindingPatternKind<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match self {
+            Self::BindingIdentifier(ident) => ident.gen(p),
+            Self::ObjectPattern(pattern) => pattern.gen(p),
+            Self::RestElement(elem) => elem.gen(p),
+            Self::ArrayPattern(pattern) => pattern.gen(p),
+            Self::AssignmentPattern(pattern) => pattern.gen(p),
         }
     }
 }
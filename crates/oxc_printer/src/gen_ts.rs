@@ -0,0 +1,973 @@
+//! TypeScript code generation, enabled by [`crate::PrinterOptions::enable_typescript`]
+//!
+//! Parentheses around types are not kept in the AST,
+//! they are printed from the precedence of the nested types.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use crate::{gen::Gen, Printer};
+
+impl Printer {
+    #[inline]
+    pub(crate) fn print_typescript(&self) -> bool {
+        self.options.enable_typescript
+    }
+
+    /// `: type`
+    pub(crate) fn print_type_annotation(&mut self, annotation: Option<&TSTypeAnnotation<'_>>) {
+        if let Some(annotation) = annotation
+            && self.print_typescript()
+        {
+            self.print_colon();
+            self.print_space();
+            annotation.gen(self);
+        }
+    }
+
+    /// `<T extends U = V>`
+    pub(crate) fn print_type_parameters(
+        &mut self,
+        type_parameters: Option<&TSTypeParameterDeclaration<'_>>,
+    ) {
+        if let Some(type_parameters) = type_parameters
+            && self.print_typescript()
+        {
+            type_parameters.gen(self);
+        }
+    }
+
+    /// `<T, U>`
+    pub(crate) fn print_type_arguments(
+        &mut self,
+        type_arguments: Option<&TSTypeParameterInstantiation<'_>>,
+    ) {
+        if let Some(type_arguments) = type_arguments
+            && self.print_typescript()
+        {
+            type_arguments.gen(self);
+        }
+    }
+
+    /// Modifiers of declarations, `export`, `default` and `async` are printed by the declarations.
+    pub(crate) fn print_modifiers(&mut self, modifiers: &Modifiers<'_>) {
+        if !self.print_typescript() {
+            return;
+        }
+        if modifiers.contains(ModifierKind::Declare) {
+            self.print_str(b"declare ");
+        }
+        if modifiers.contains(ModifierKind::Abstract) {
+            self.print_str(b"abstract ");
+        }
+        if modifiers.contains(ModifierKind::Const) {
+            self.print_str(b"const ");
+        }
+    }
+
+    pub(crate) fn print_accessibility(&mut self, accessibility: Option<TSAccessibility>) {
+        if let Some(accessibility) = accessibility
+            && self.print_typescript()
+        {
+            self.print_str(match accessibility {
+                TSAccessibility::Private => b"private ",
+                TSAccessibility::Protected => b"protected ",
+                TSAccessibility::Public => b"public ",
+            });
+        }
+    }
+
+    /// Members of interfaces and type literals, terminated by `;`.
+    fn print_signatures(&mut self, signatures: &[TSSignature<'_>]) {
+        self.print(b'{');
+        if signatures.is_empty() {
+            self.print(b'}');
+            return;
+        }
+        self.indent();
+        for signature in signatures {
+            self.print_newline();
+            self.print_indent();
+            signature.gen(self);
+            self.print_semicolon();
+        }
+        self.dedent();
+        self.print_newline();
+        self.print_indent();
+        self.print(b'}');
+    }
+
+    /// Print `ty`, wrapped in parentheses when it binds looser than `precedence`.
+    fn print_type_with_precedence(&mut self, ty: &TSType<'_>, precedence: TypePrecedence) {
+        let needs_parens = TypePrecedence::of(ty) < precedence;
+        if needs_parens {
+            self.print(b'(');
+        }
+        ty.gen(self);
+        if needs_parens {
+            self.print(b')');
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TypePrecedence {
+    /// `A extends B ? C : D`, `() => A`, `new () => A`
+    Lowest,
+    /// `A | B`
+    Union,
+    /// `A & B`
+    Intersection,
+    /// `keyof A`, `infer A`
+    Prefix,
+    /// `A[]`, `A[B]`, and all other types
+    Postfix,
+}
+
+impl TypePrecedence {
+    fn of(ty: &TSType<'_>) -> Self {
+        match ty {
+            TSType::TSConditionalType(_)
+            | TSType::TSFunctionType(_)
+            | TSType::TSConstructorType(_)
+            | TSType::TSTypePredicate(_) => Self::Lowest,
+            TSType::TSUnionType(_) => Self::Union,
+            TSType::TSIntersectionType(_) => Self::Intersection,
+            TSType::TSTypeOperatorType(_) | TSType::TSInferType(_) => Self::Prefix,
+            _ => Self::Postfix,
+        }
+    }
+}
+
+impl<'a> Gen for TSTypeAnnotation<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.type_annotation.gen(p);
+    }
+}
+
+impl<'a> Gen for TSType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match self {
+            Self::TSAnyKeyword(_) => p.print_str(b"any"),
+            Self::TSBigIntKeyword(_) => p.print_str(b"bigint"),
+            Self::TSBooleanKeyword(_) => p.print_str(b"boolean"),
+            Self::TSNeverKeyword(_) => p.print_str(b"never"),
+            Self::TSNullKeyword(_) => p.print_str(b"null"),
+            Self::TSNumberKeyword(_) => p.print_str(b"number"),
+            Self::TSObjectKeyword(_) => p.print_str(b"object"),
+            Self::TSStringKeyword(_) => p.print_str(b"string"),
+            Self::TSSymbolKeyword(_) => p.print_str(b"symbol"),
+            Self::TSThisKeyword(_) => p.print_str(b"this"),
+            Self::TSUndefinedKeyword(_) => p.print_str(b"undefined"),
+            Self::TSUnknownKeyword(_) => p.print_str(b"unknown"),
+            Self::TSVoidKeyword(_) => p.print_str(b"void"),
+            Self::TSArrayType(ty) => ty.gen(p),
+            Self::TSConditionalType(ty) => ty.gen(p),
+            Self::TSConstructorType(ty) => ty.gen(p),
+            Self::TSFunctionType(ty) => ty.gen(p),
+            Self::TSImportType(ty) => ty.gen(p),
+            Self::TSIndexedAccessType(ty) => ty.gen(p),
+            Self::TSInferType(ty) => ty.gen(p),
+            Self::TSIntersectionType(ty) => ty.gen(p),
+            Self::TSLiteralType(ty) => ty.gen(p),
+            Self::TSMappedType(ty) => ty.gen(p),
+            Self::TSQualifiedName(ty) => ty.gen(p),
+            Self::TSTemplateLiteralType(ty) => ty.gen(p),
+            Self::TSTupleType(ty) => ty.gen(p),
+            Self::TSTypeLiteral(ty) => ty.gen(p),
+            Self::TSTypeOperatorType(ty) => ty.gen(p),
+            Self::TSTypePredicate(ty) => ty.gen(p),
+            Self::TSTypeQuery(ty) => ty.gen(p),
+            Self::TSTypeReference(ty) => ty.gen(p),
+            Self::TSUnionType(ty) => ty.gen(p),
+            Self::JSDocNullableType(ty) => ty.gen(p),
+            Self::JSDocUnknownType(_) => p.print(b'?'),
+        }
+    }
+}
+
+impl<'a> Gen for TSArrayType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_type_with_precedence(&self.element_type, TypePrecedence::Postfix);
+        p.print_str(b"[]");
+    }
+}
+
+impl<'a> Gen for TSConditionalType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_type_with_precedence(&self.check_type, TypePrecedence::Union);
+        p.print_str(b" extends ");
+        p.print_type_with_precedence(&self.extends_type, TypePrecedence::Union);
+        p.print_space();
+        p.print(b'?');
+        p.print_space();
+        self.true_type.gen(p);
+        p.print_space();
+        p.print_colon();
+        p.print_space();
+        self.false_type.gen(p);
+    }
+}
+
+impl<'a> Gen for TSConstructorType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if self.r#abstract {
+            p.print_str(b"abstract ");
+        }
+        p.print_str(b"new ");
+        p.print_type_parameters(self.type_parameters.as_deref());
+        p.print(b'(');
+        self.params.gen(p);
+        p.print(b')');
+        p.print_space();
+        p.print_str(b"=>");
+        p.print_space();
+        self.return_type.gen(p);
+    }
+}
+
+impl<'a> Gen for TSFunctionType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_type_parameters(self.type_parameters.as_deref());
+        p.print(b'(');
+        self.params.gen(p);
+        p.print(b')');
+        p.print_space();
+        p.print_str(b"=>");
+        p.print_space();
+        self.return_type.gen(p);
+    }
+}
+
+impl<'a> Gen for TSImportType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if self.is_type_of {
+            p.print_str(b"typeof ");
+        }
+        p.print_str(b"import(");
+        self.parameter.gen(p);
+        p.print(b')');
+        if let Some(qualifier) = &self.qualifier {
+            p.print(b'.');
+            qualifier.gen(p);
+        }
+        p.print_type_arguments(self.type_parameters.as_deref());
+    }
+}
+
+impl<'a> Gen for TSIndexedAccessType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_type_with_precedence(&self.object_type, TypePrecedence::Postfix);
+        p.print(b'[');
+        self.index_type.gen(p);
+        p.print(b']');
+    }
+}
+
+impl<'a> Gen for TSInferType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_str(b"infer ");
+        self.type_parameter.gen(p);
+    }
+}
+
+impl<'a> Gen for TSIntersectionType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        for (index, ty) in self.types.iter().enumerate() {
+            if index != 0 {
+                p.print_space();
+                p.print(b'&');
+                p.print_space();
+            }
+            p.print_type_with_precedence(ty, TypePrecedence::Prefix);
+        }
+    }
+}
+
+impl<'a> Gen for TSUnionType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        for (index, ty) in self.types.iter().enumerate() {
+            if index != 0 {
+                p.print_space();
+                p.print(b'|');
+                p.print_space();
+            }
+            p.print_type_with_precedence(ty, TypePrecedence::Intersection);
+        }
+    }
+}
+
+impl<'a> Gen for TSLiteralType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match &self.literal {
+            TSLiteral::BooleanLiteral(lit) => lit.gen(p),
+            TSLiteral::NullLiteral(lit) => lit.gen(p),
+            TSLiteral::NumberLiteral(lit) => lit.gen(p),
+            TSLiteral::BigintLiteral(lit) => lit.gen(p),
+            TSLiteral::RegExpLiteral(lit) => lit.gen(p),
+            TSLiteral::StringLiteral(lit) => lit.gen(p),
+            TSLiteral::TemplateLiteral(lit) => lit.gen(p),
+            TSLiteral::UnaryExpression(expr) => expr.gen(p),
+        }
+    }
+}
+
+impl<'a> Gen for TSMappedType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'{');
+        p.print_space();
+        match self.readonly {
+            TSMappedTypeModifierOperator::True => p.print_str(b"readonly "),
+            TSMappedTypeModifierOperator::Plus => p.print_str(b"+readonly "),
+            TSMappedTypeModifierOperator::Minus => p.print_str(b"-readonly "),
+            TSMappedTypeModifierOperator::None => {}
+        }
+        p.print(b'[');
+        self.type_parameter.name.gen(p);
+        if let Some(constraint) = &self.type_parameter.constraint {
+            p.print_str(b" in ");
+            constraint.gen(p);
+        }
+        if let Some(name_type) = &self.name_type {
+            p.print_str(b" as ");
+            name_type.gen(p);
+        }
+        p.print(b']');
+        match self.optional {
+            TSMappedTypeModifierOperator::True => p.print(b'?'),
+            TSMappedTypeModifierOperator::Plus => p.print_str(b"+?"),
+            TSMappedTypeModifierOperator::Minus => p.print_str(b"-?"),
+            TSMappedTypeModifierOperator::None => {}
+        }
+        p.print_colon();
+        p.print_space();
+        self.type_annotation.gen(p);
+        p.print_space();
+        p.print(b'}');
+    }
+}
+
+impl<'a> Gen for TSTypeName<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match self {
+            Self::IdentifierName(ident) => ident.gen(p),
+            Self::QualifiedName(name) => name.gen(p),
+        }
+    }
+}
+
+impl<'a> Gen for TSQualifiedName<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.left.gen(p);
+        p.print(b'.');
+        self.right.gen(p);
+    }
+}
+
+impl<'a> Gen for TSTemplateLiteralType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'`');
+        let mut types = self.types.iter();
+        for quasi in &self.quasis {
+            p.print_str(quasi.value.raw.as_bytes());
+            if let Some(ty) = types.next() {
+                p.print_str(b"${");
+                ty.gen(p);
+                p.print(b'}');
+            }
+        }
+        p.print(b'`');
+    }
+}
+
+impl<'a> Gen for TSTupleType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'[');
+        p.print_list(&self.element_types);
+        p.print(b']');
+    }
+}
+
+impl<'a> Gen for TSTupleElement<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match self {
+            Self::TSType(ty) => ty.gen(p),
+            Self::TSOptionalType(ty) => {
+                p.print_type_with_precedence(&ty.type_annotation, TypePrecedence::Postfix);
+                p.print(b'?');
+            }
+            Self::TSRestType(ty) => {
+                p.print_ellipsis();
+                ty.type_annotation.gen(p);
+            }
+            Self::TSNamedTupleMember(member) => {
+                member.label.gen(p);
+                if member.optional {
+                    p.print(b'?');
+                }
+                p.print_colon();
+                p.print_space();
+                member.element_type.gen(p);
+            }
+        }
+    }
+}
+
+impl<'a> Gen for TSTypeLiteral<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if !self.exact {
+            p.print_signatures(&self.members);
+            return;
+        }
+        p.print_str(b"{|");
+        for (index, member) in self.members.iter().enumerate() {
+            if index > 0 {
+                p.print_comma();
+                p.print_space();
+            }
+            member.gen(p);
+        }
+        p.print_str(b"|}");
+    }
+}
+
+impl<'a> Gen for TSTypeOperatorType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_str(match self.operator {
+            TSTypeOperator::Keyof => b"keyof ",
+            TSTypeOperator::Unique => b"unique ",
+            TSTypeOperator::Readonly => b"readonly ",
+        });
+        p.print_type_with_precedence(&self.type_annotation, TypePrecedence::Prefix);
+    }
+}
+
+impl<'a> Gen for TSTypePredicate<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if self.asserts {
+            p.print_str(b"asserts ");
+        }
+        match &self.parameter_name {
+            TSTypePredicateName::Identifier(ident) => ident.gen(p),
+            TSTypePredicateName::This(_) => p.print_str(b"this"),
+        }
+        if let Some(annotation) = &self.type_annotation {
+            p.print_str(b" is ");
+            annotation.gen(p);
+        }
+    }
+}
+
+impl<'a> Gen for TSTypeQuery<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_str(b"typeof ");
+        self.expr_name.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
+    }
+}
+
+impl<'a> Gen for TSTypeReference<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.type_name.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
+    }
+}
+
+impl<'a> Gen for JSDocNullableType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if self.postfix {
+            p.print_type_with_precedence(&self.type_annotation, TypePrecedence::Postfix);
+            p.print(b'?');
+        } else {
+            p.print(b'?');
+            p.print_type_with_precedence(&self.type_annotation, TypePrecedence::Postfix);
+        }
+    }
+}
+
+impl<'a> Gen for TSTypeParameter<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if self.r#in {
+            p.print_str(b"in ");
+        }
+        if self.out {
+            p.print_str(b"out ");
+        }
+        self.name.gen(p);
+        if let Some(constraint) = &self.constraint {
+            p.print_str(b" extends ");
+            constraint.gen(p);
+        }
+        if let Some(default) = &self.default {
+            p.print_space();
+            p.print_equal();
+            p.print_space();
+            default.gen(p);
+        }
+    }
+}
+
+impl<'a> Gen for TSTypeParameterDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        // `a < <T>(b: T) => b` must not become a shift
+        if p.last_char() == Some(&b'<') {
+            p.print(b' ');
+        }
+        p.print(b'<');
+        p.print_list(&self.params);
+        p.print(b'>');
+    }
+}
+
+impl<'a> Gen for TSTypeParameterInstantiation<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'<');
+        p.print_list(&self.params);
+        p.print(b'>');
+    }
+}
+
+impl<'a> Gen for TSSignature<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match self {
+            Self::TSIndexSignature(signature) => signature.gen(p),
+            Self::TSPropertySignature(signature) => signature.gen(p),
+            Self::TSCallSignatureDeclaration(signature) => signature.gen(p),
+            Self::TSConstructSignatureDeclaration(signature) => signature.gen(p),
+            Self::TSMethodSignature(signature) => signature.gen(p),
+        }
+    }
+}
+
+impl<'a> Gen for TSIndexSignature<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'[');
+        p.print_list(&self.parameters);
+        p.print(b']');
+        p.print_colon();
+        p.print_space();
+        self.type_annotation.gen(p);
+    }
+}
+
+impl<'a> Gen for TSIndexSignatureName<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_identifier(&self.name);
+        p.print_colon();
+        p.print_space();
+        self.type_annotation.gen(p);
+    }
+}
+
+impl<'a> Gen for TSPropertySignature<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if self.readonly {
+            p.print_str(b"readonly ");
+        }
+        if self.computed {
+            p.print(b'[');
+        }
+        self.key.gen(p);
+        if self.computed {
+            p.print(b']');
+        }
+        if self.optional {
+            p.print(b'?');
+        }
+        p.print_type_annotation(self.type_annotation.as_deref());
+    }
+}
+
+impl<'a> Gen for TSCallSignatureDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_type_parameters(self.type_parameters.as_deref());
+        p.print(b'(');
+        self.params.gen(p);
+        p.print(b')');
+        p.print_type_annotation(self.return_type.as_deref());
+    }
+}
+
+impl<'a> Gen for TSConstructSignatureDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_str(b"new ");
+        p.print_type_parameters(self.type_parameters.as_deref());
+        p.print(b'(');
+        self.params.gen(p);
+        p.print(b')');
+        p.print_type_annotation(self.return_type.as_deref());
+    }
+}
+
+impl<'a> Gen for TSMethodSignature<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match self.kind {
+            TSMethodSignatureKind::Method => {}
+            TSMethodSignatureKind::Get => p.print_str(b"get "),
+            TSMethodSignatureKind::Set => p.print_str(b"set "),
+        }
+        if self.computed {
+            p.print(b'[');
+        }
+        self.key.gen(p);
+        if self.computed {
+            p.print(b']');
+        }
+        if self.optional {
+            p.print(b'?');
+        }
+        p.print_type_parameters(self.type_parameters.as_deref());
+        p.print(b'(');
+        self.params.gen(p);
+        p.print(b')');
+        p.print_type_annotation(self.return_type.as_deref());
+    }
+}
+
+impl<'a> Gen for TSTypeAliasDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
+        p.print_str(b"type ");
+        self.id.gen(p);
+        p.print_type_parameters(self.type_parameters.as_deref());
+        p.print_space();
+        p.print_equal();
+        p.print_space();
+        self.type_annotation.gen(p);
+    }
+}
+
+impl<'a> Gen for FlowOpaqueType<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
+        p.print_str(b"opaque type ");
+        self.id.gen(p);
+        p.print_type_parameters(self.type_parameters.as_deref());
+        if let Some(supertype) = &self.supertype {
+            p.print_colon();
+            p.print_space();
+            supertype.gen(p);
+        }
+        if let Some(impl_type) = &self.impl_type {
+            p.print_space();
+            p.print_equal();
+            p.print_space();
+            impl_type.gen(p);
+        }
+    }
+}
+
+impl<'a> Gen for TSInterfaceDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
+        p.print_str(b"interface ");
+        self.id.gen(p);
+        p.print_type_parameters(self.type_parameters.as_deref());
+        if let Some(extends) = &self.extends {
+            p.print_str(b" extends ");
+            p.print_list(extends);
+        }
+        p.print_space();
+        p.print_signatures(&self.body.body);
+    }
+}
+
+impl<'a> Gen for TSInterfaceHeritage<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.expression.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
+    }
+}
+
+impl<'a> Gen for TSClassImplements<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.expression.gen(p);
+        p.print_type_arguments(self.type_parameters.as_deref());
+    }
+}
+
+impl<'a> Gen for TSEnumDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
+        p.print_str(b"enum ");
+        self.id.gen(p);
+        p.print_space();
+        p.print(b'{');
+        if self.members.is_empty() {
+            p.print(b'}');
+            return;
+        }
+        p.indent();
+        for (index, member) in self.members.iter().enumerate() {
+            if index != 0 {
+                p.print_comma();
+            }
+            p.print_newline();
+            p.print_indent();
+            member.gen(p);
+        }
+        p.dedent();
+        p.print_newline();
+        p.print_indent();
+        p.print(b'}');
+    }
+}
+
+impl<'a> Gen for TSEnumMember<'a> {
+    fn gen(&self, p: &mut Printer) {
+        match &self.id {
+            TSEnumMemberName::Identifier(ident) => ident.gen(p),
+            TSEnumMemberName::StringLiteral(lit) => lit.gen(p),
+            TSEnumMemberName::NumberLiteral(lit) => lit.gen(p),
+            TSEnumMemberName::ComputedPropertyName(expr) => {
+                p.print(b'[');
+                expr.gen(p);
+                p.print(b']');
+            }
+        }
+        if let Some(initializer) = &self.initializer {
+            p.print_space();
+            p.print_equal();
+            p.print_space();
+            initializer.gen(p);
+        }
+    }
+}
+
+impl<'a> Gen for TSModuleDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_modifiers(&self.modifiers);
+        match &self.id {
+            // `declare global { }`
+            TSModuleDeclarationName::Identifier(ident) if ident.name == "global" => {
+                p.print_str(b"global");
+            }
+            TSModuleDeclarationName::Identifier(ident) => {
+                p.print_str(b"namespace ");
+                ident.gen(p);
+            }
+            TSModuleDeclarationName::StringLiteral(lit) => {
+                p.print_str(b"module ");
+                lit.gen(p);
+            }
+        }
+        // `namespace A.B.C { }`
+        let mut body = &self.body;
+        loop {
+            match body {
+                TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
+                    p.print(b'.');
+                    decl.id.gen(p);
+                    body = &decl.body;
+                }
+                TSModuleDeclarationBody::TSModuleBlock(block) => {
+                    p.print_space();
+                    block.gen(p);
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl Gen for TSModuleDeclarationName {
+    fn gen(&self, p: &mut Printer) {
+        match self {
+            Self::Identifier(ident) => ident.gen(p),
+            Self::StringLiteral(lit) => lit.gen(p),
+        }
+    }
+}
+
+impl<'a> Gen for TSModuleBlock<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print(b'{');
+        p.print_newline();
+        p.indent();
+        for stmt in &self.body {
+            p.print_semicolon_if_needed();
+            stmt.gen(p);
+        }
+        p.print_leading_comments(self.span.end);
+        p.dedent();
+        p.needs_semicolon = false;
+        p.print_indent();
+        p.print(b'}');
+    }
+}
+
+impl<'a> Gen for TSImportEqualsDeclaration<'a> {
+    fn gen(&self, p: &mut Printer) {
+        // `export` is printed by the enclosing `ExportNamedDeclaration`
+        p.print_str(b"import ");
+        if self.import_kind.is_type() {
+            p.print_str(b"type ");
+        }
+        self.id.gen(p);
+        p.print_space();
+        p.print_equal();
+        p.print_space();
+        match &*self.module_reference {
+            TSModuleReference::TypeName(name) => name.gen(p),
+            TSModuleReference::ExternalModuleReference(reference) => {
+                p.print_str(b"require(");
+                reference.expression.gen(p);
+                p.print(b')');
+            }
+        }
+    }
+}
+
+impl<'a> Gen for TSExportAssignment<'a> {
+    fn gen(&self, p: &mut Printer) {
+        p.print_str(b"export");
+        p.print_space();
+        p.print_equal();
+        p.print_space();
+        self.expression.gen(p);
+        p.print_semicolon_after_statement();
+    }
+}
+
+impl Gen for TSNamespaceExportDeclaration {
+    fn gen(&self, p: &mut Printer) {
+        p.print_str(b"export as namespace ");
+        self.id.gen(p);
+        p.print_semicolon_after_statement();
+    }
+}
+
+impl<'a> Gen for TSAsExpression<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.expression.gen(p);
+        if p.print_typescript() {
+            p.print_str(b" as ");
+            self.type_annotation.gen(p);
+        }
+    }
+}
+
+impl<'a> Gen for TSTypeAssertion<'a> {
+    fn gen(&self, p: &mut Printer) {
+        if p.print_typescript() {
+            // `a < <T>b` must not become a shift
+            if p.last_char() == Some(&b'<') {
+                p.print(b' ');
+            }
+            p.print(b'<');
+            self.type_annotation.gen(p);
+            p.print(b'>');
+        }
+        self.expression.gen(p);
+    }
+}
+
+impl<'a> Gen for TSNonNullExpression<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.expression.gen(p);
+        if p.print_typescript() {
+            p.print(b'!');
+        }
+    }
+}
+
+impl<'a> Gen for TSInstantiationExpression<'a> {
+    fn gen(&self, p: &mut Printer) {
+        self.expression.gen(p);
+        p.print_type_arguments(Some(&self.type_parameters));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use crate::{Printer, PrinterOptions};
+
+    fn print(source_text: &str, path: &str, options: PrinterOptions) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::from_path(Path::new(path)).unwrap();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}");
+        Printer::new(source_text.len(), options).build(&ret.program)
+    }
+
+    fn test(source_text: &str, expected: &str) {
+        let options = PrinterOptions { enable_typescript: true, ..PrinterOptions::default() };
+        let printed = print(source_text, "test.ts", options);
+        assert_eq!(printed, expected);
+        assert_eq!(print(&printed, "test.ts", options), printed);
+        let options = PrinterOptions { minify_whitespace: true, ..options };
+        let minified = print(source_text, "test.ts", options);
+        assert_eq!(print(&minified, "test.ts", options), minified);
+    }
+
+    #[test]
+    fn types() {
+        test("let a: string | number[];", "let a: string | number[];\n");
+        test("type A = (B | C)[] & (() => void);", "type A = (B | C)[] & (() => void);\n");
+        test("type A = (keyof B)[];", "type A = (keyof B)[];\n");
+        test(
+            "type A<T> = T extends (infer U)[] ? U : never;",
+            "type A<T> = T extends (infer U)[] ? U : never;\n",
+        );
+        test(
+            "type A<T> = { readonly [K in keyof T]?: T[K] };",
+            "type A<T> = { readonly [K in keyof T]?: T[K] };\n",
+        );
+        test("type A = [a: string, b?: number];", "type A = [a: string, b?: number];\n");
+        test("type A = typeof import('a').B<C>;", "type A = typeof import('a').B<C>;\n");
+    }
+
+    #[test]
+    fn declarations() {
+        test(
+            "interface A<T> extends B { a?: T; m(): void }",
+            "interface A<T> extends B {\n    a?: T;\n    m(): void;\n}\n",
+        );
+        test("enum E { A = 1, B }", "enum E {\n    A = 1,\n    B\n}\n");
+        test(
+            "declare namespace A.B { let c: number; }",
+            "declare namespace A.B {\n    let c: number;\n}\n",
+        );
+        test("import type A = require('a');", "import type A = require('a');\n");
+        test("export type * from 'a';", "export type * from 'a';\n");
+        test("import { type A, B } from 'a';", "import { type A, B } from 'a';\n");
+        test("export { type A, B as C };", "export { type A, B as C };\n");
+    }
+
+    #[test]
+    fn classes_and_functions() {
+        test(
+            "abstract class A<T> extends B<T> { private readonly a?: T; abstract m(): void; }",
+            "abstract class A<T> extends B<T> {\n    private readonly a?: T;\n    abstract m(): void;\n}\n",
+        );
+        test("class A implements B, C {}", "class A implements B, C {\n}\n");
+        test(
+            "function f<T>(a?: T): asserts a is T {}",
+            "function f<T> (a?: T): asserts a is T {\n}\n",
+        );
+        test("let f = f<string>;", "let f = f<string>;\n");
+        test("let a = b! as unknown as C;", "let a = b! as unknown as C;\n");
+    }
+
+    #[test]
+    fn strip_types() {
+        let source_text =
+            "let a: string = b as string;\ninterface A {}\nfunction f<T>(a: T): void {}\n";
+        let printed = print(source_text, "test.ts", PrinterOptions::default());
+        assert_eq!(printed, "let a = b;\nfunction f (a) {\n}\n");
+    }
+
+    #[test]
+    fn tsx_arrow() {
+        let options = PrinterOptions { enable_typescript: true, ..PrinterOptions::default() };
+        let printed = print("let f = <T,>(a: T) => a;", "test.tsx", options);
+        assert_eq!(printed, "let f = <T,>(a: T) => a;\n");
+        assert_eq!(print(&printed, "test.tsx", options), printed);
+    }
+}
@@ -3,22 +3,76 @@
 
 #![feature(let_chains)]
 
+mod comment;
 mod gen;
+mod gen_ts;
+mod sourcemap;
+
+use std::io::Write;
 
 #[allow(clippy::wildcard_imports)]
-use oxc_ast::ast::*;
+use oxc_ast::{ast::*, Atom, Span, Trivias};
+use rustc_hash::FxHashMap;
 
-use crate::gen::Gen;
+pub use crate::sourcemap::{SourceMap, SourceMapBuilder, Token};
+use crate::{
+    comment::{collect_comments, PrintedComment},
+    gen::Gen,
+};
 
 #[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct PrinterOptions {
     pub minify_whitespace: bool,
     pub indentation: u8,
+    /// Indent with one tab per level instead of `indentation` spaces
+    pub use_tabs: bool,
+    /// Print TypeScript syntax: type annotations, declarations, decorators, modifiers ...
+    /// When disabled, type annotations are stripped and TypeScript declarations are not printed.
+    pub enable_typescript: bool,
+    /// Preferred quote for string literals,
+    /// the other quote is used when it needs fewer escapes.
+    pub quote: Quote,
+    /// Escape non-ASCII characters in strings, identifiers, regular expressions and templates.
+    pub ascii_only: bool,
+    /// Start a new line after a `,` or `;` once the line is longer than this many bytes,
+    /// for tools which cannot handle the very long lines of minified code.
+    pub max_line_len: Option<usize>,
 }
 
 impl Default for PrinterOptions {
     fn default() -> Self {
-        Self { minify_whitespace: false, indentation: 4 }
+        Self {
+            minify_whitespace: false,
+            indentation: 4,
+            use_tabs: false,
+            enable_typescript: false,
+            quote: Quote::Single,
+            ascii_only: false,
+            max_line_len: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quote {
+    Single,
+    Double,
+}
+
+impl Quote {
+    const fn as_byte(self) -> u8 {
+        match self {
+            Self::Single => b'\'',
+            Self::Double => b'"',
+        }
+    }
+
+    const fn other(self) -> Self {
+        match self {
+            Self::Single => Self::Double,
+            Self::Double => Self::Single,
+        }
     }
 }
 
@@ -31,10 +85,29 @@ pub struct Printer {
     /// Current indentation tracking
     indentation: u8,
 
+    /// Start of the last line of `code`, only kept up to date for `max_line_len`
+    line_start: usize,
+
     // states
     needs_semicolon: bool,
     prev_op_end: usize,
     prev_op: Option<Operator>,
+
+    /// Set by `with_source_map`
+    source_map: Option<SourceMapBuilder>,
+
+    /// Set by `with_comments`, in source order
+    comments: Vec<PrintedComment>,
+    /// Index of the first comment not printed yet
+    next_comment: usize,
+
+    /// Set by `with_mangled_names`
+    mangled_names: FxHashMap<u32, Atom>,
+}
+
+pub struct PrinterReturn {
+    pub code: String,
+    pub source_map: Option<SourceMap>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -56,16 +129,53 @@ impl Printer {
             options,
             code: Vec::with_capacity(capacity),
             indentation: 0,
+            line_start: 0,
             needs_semicolon: false,
             prev_op_end: 0,
             prev_op: None,
+            source_map: None,
+            comments: vec![],
+            next_comment: 0,
+            mangled_names: FxHashMap::default(),
         }
     }
 
+    /// Print the comments collected by the parser.
+    /// With `minify_whitespace`, only legal comments (`/*! ... */`, `@license`, `@preserve`)
+    /// and annotations (`/*#__PURE__*/`, `/* webpackChunkName: "name" */`) are printed.
+    #[must_use]
+    pub fn with_comments(mut self, source_text: &str, trivias: &Trivias) -> Self {
+        self.comments = collect_comments(source_text, trivias, self.options.minify_whitespace);
+        self
+    }
+
+    /// Generate a source map for the printed code, see [`Printer::build_with_source_map`].
+    #[must_use]
+    pub fn with_source_map(mut self, source_name: &str, source_text: &str) -> Self {
+        self.source_map = Some(SourceMapBuilder::new(source_name, source_text));
+        self
+    }
+
+    /// Print new names for identifiers, keyed by the start of the identifier in the source text.
+    /// The source map keeps the original names.
+    #[must_use]
+    pub fn with_mangled_names(mut self, names: FxHashMap<u32, Atom>) -> Self {
+        self.mangled_names = names;
+        self
+    }
+
     #[must_use]
-    pub fn build(mut self, program: &Program<'_>) -> String {
+    pub fn build(self, program: &Program<'_>) -> String {
+        self.build_with_source_map(program).code
+    }
+
+    /// Returns the printed code,
+    /// and the source map when it is enabled by [`Printer::with_source_map`].
+    #[must_use]
+    pub fn build_with_source_map(mut self, program: &Program<'_>) -> PrinterReturn {
         program.gen(&mut self);
-        unsafe { String::from_utf8_unchecked(self.code) }
+        let code = unsafe { String::from_utf8_unchecked(self.code) };
+        PrinterReturn { code, source_map: self.source_map.map(SourceMapBuilder::into_source_map) }
     }
 
     #[must_use]
@@ -112,11 +222,26 @@ impl Printer {
     #[inline]
     pub fn print_semicolon(&mut self) {
         self.print(b';');
+        self.print_newline_if_too_long();
     }
 
     #[inline]
     pub fn print_comma(&mut self) {
         self.print(b',');
+        self.print_newline_if_too_long();
+    }
+
+    /// Break the line when it exceeds `max_line_len`.
+    /// Only called after `,` and `;`, where a line break can never change the meaning of the code.
+    fn print_newline_if_too_long(&mut self) {
+        let Some(max_line_len) = self.options.max_line_len else { return };
+        if let Some(pos) = self.code[self.line_start..].iter().rposition(|&b| b == b'\n') {
+            self.line_start += pos + 1;
+        }
+        if self.code.len() - self.line_start > max_line_len {
+            self.print(b'\n');
+            self.line_start = self.code.len();
+        }
     }
 
     fn print_space_before_operator(&mut self, next: Operator) {
@@ -155,7 +280,7 @@ impl Printer {
         if self.options.minify_whitespace {
             self.needs_semicolon = true;
         } else {
-            self.print_semicolon();
+            self.print(b';');
             self.print(b'\n');
         }
     }
@@ -183,7 +308,15 @@ impl Printer {
     }
 
     pub fn print_indent(&mut self) {
-        if !self.options.minify_whitespace {
+        if self.options.minify_whitespace {
+            return;
+        }
+        if self.options.use_tabs {
+            let levels = self.indentation / self.options.indentation.max(1);
+            for _ in 0..levels {
+                self.print(b'\t');
+            }
+        } else {
             for _ in 0..self.indentation {
                 self.print(b' ');
             }
@@ -197,7 +330,7 @@ impl Printer {
             item.gen(self);
             match separator {
                 Separator::Semicolon => self.print_semicolon(),
-                Separator::Comma => self.print(b','),
+                Separator::Comma => self.print_comma(),
                 Separator::None => {}
             }
             if index != len - 1 {
@@ -229,6 +362,7 @@ impl Printer {
             self.print_semicolon_if_needed();
             item.gen(self);
         }
+        self.print_leading_comments(stmt.span.end);
         self.dedent();
         self.needs_semicolon = false;
         self.print_indent();
@@ -261,8 +395,145 @@ impl Printer {
         }
     }
 
-    pub fn print_identifier(&mut self, name: &[u8]) {
-        self.print_str(name);
+    fn print_decorators(&mut self, decorators: &[Decorator<'_>]) {
+        for decorator in decorators {
+            decorator.gen(self);
+            self.print(b' ');
+        }
+    }
+
+    pub fn print_identifier(&mut self, name: &str) {
+        if !self.options.ascii_only || name.is_ascii() {
+            self.print_str(name.as_bytes());
+            return;
+        }
+        for c in name.chars() {
+            if c.is_ascii() {
+                self.print(c as u8);
+            } else {
+                // Surrogate pairs are not allowed in identifiers
+                self.print_unicode_escape(c, true);
+            }
+        }
+    }
+
+    /// Print `value` as a string literal with the preferred quote,
+    /// unless the other quote needs fewer escapes.
+    pub fn print_quoted_string(&mut self, value: &str) {
+        let count = |quote: Quote| value.bytes().filter(|&b| b == quote.as_byte()).count();
+        let preferred = self.options.quote;
+        let quote = if count(preferred) > count(preferred.other()) {
+            preferred.other().as_byte()
+        } else {
+            preferred.as_byte()
+        };
+        self.print(quote);
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => self.print_str(b"\\\\"),
+                '\n' => self.print_str(b"\\n"),
+                '\r' => self.print_str(b"\\r"),
+                '\t' => self.print_str(b"\\t"),
+                '\u{8}' => self.print_str(b"\\b"),
+                '\u{b}' => self.print_str(b"\\v"),
+                '\u{c}' => self.print_str(b"\\f"),
+                // `\0` followed by a digit would be read as a legacy octal escape
+                '\0' if !chars.peek().map_or(false, char::is_ascii_digit) => {
+                    self.print_str(b"\\0");
+                }
+                // Line terminators before ES2019
+                '\u{2028}' | '\u{2029}' => self.print_unicode_escape(c, false),
+                c if c as u32 == u32::from(quote) => {
+                    self.print(b'\\');
+                    self.print(quote);
+                }
+                c if c.is_ascii_control() => {
+                    write!(self.code, "\\x{:02X}", c as u32).unwrap();
+                }
+                c if c.is_ascii() => self.print(c as u8),
+                // Surrogate pairs are valid in every edition, unlike `\u{...}`
+                c if self.options.ascii_only => self.print_unicode_escape(c, false),
+                c => self.print_str(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            }
+        }
+        self.print(quote);
+    }
+
+    /// Print the raw source of a template element or a regular expression pattern,
+    /// with non-ASCII characters escaped when `ascii_only` is enabled.
+    pub fn print_raw(&mut self, raw: &str, allow_braces: bool) {
+        if !self.options.ascii_only || raw.is_ascii() {
+            self.print_str(raw.as_bytes());
+            return;
+        }
+        let mut escaped = false;
+        for c in raw.chars() {
+            if c.is_ascii() {
+                self.print(c as u8);
+                escaped = c == '\\' && !escaped;
+                continue;
+            }
+            if escaped && matches!(c, '\u{2028}' | '\u{2029}') {
+                // A line continuation in a template
+                self.print(b'\n');
+            } else {
+                if escaped {
+                    // `\é` is an identity escape of `é`, its backslash starts the new escape
+                    self.code.pop();
+                }
+                self.print_unicode_escape(c, allow_braces);
+            }
+            escaped = false;
+        }
+    }
+
+    /// `\uXXXX`, characters outside the Basic Multilingual Plane are printed as `\u{XXXXX}`
+    /// when `allow_braces` is set, and as a surrogate pair otherwise.
+    fn print_unicode_escape(&mut self, c: char, allow_braces: bool) {
+        if allow_braces && c as u32 > 0xFFFF {
+            write!(self.code, "\\u{{{:X}}}", c as u32).unwrap();
+            return;
+        }
+        for unit in c.encode_utf16(&mut [0; 2]) {
+            write!(self.code, "\\u{unit:04X}").unwrap();
+        }
+    }
+
+    /// The name given to the identifier at `span` by [`Printer::with_mangled_names`]
+    #[must_use]
+    pub fn mangled_name(&self, span: Span) -> Option<&Atom> {
+        self.mangled_names.get(&span.start)
+    }
+
+    /// Print the identifier at `span` under its mangled name if it has one
+    pub fn print_symbol(&mut self, span: Span, name: &Atom) {
+        if let Some(mangled_name) = self.mangled_names.get(&span.start).cloned() {
+            self.add_source_mapping_for_name(span, &mangled_name);
+            self.print_identifier(&mangled_name);
+            return;
+        }
+        self.add_source_mapping_for_name(span, name);
+        self.print_identifier(name);
+    }
+
+    /// Map the current output position to `original_offset` in the source text.
+    #[inline]
+    pub fn add_source_mapping(&mut self, original_offset: u32) {
+        if let Some(source_map) = &mut self.source_map {
+            source_map.add_mapping(&self.code, original_offset, None);
+        }
+    }
+
+    /// Map the current output position to the identifier at `span`,
+    /// recording its original name when it is printed as `name`.
+    pub fn add_source_mapping_for_name(&mut self, span: Span, name: &str) {
+        if let Some(source_map) = &mut self.source_map {
+            let original_name =
+                source_map.source_slice(span.start, span.end).filter(|original| *original != name);
+            let original_name = original_name.map(ToString::to_string);
+            source_map.add_mapping(&self.code, span.start, original_name.as_deref());
+        }
     }
 
     #[must_use]
@@ -270,3 +541,130 @@ impl Printer {
         self.code.last()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use crate::{Printer, PrinterOptions, Quote};
+
+    fn print(source_text: &str, options: PrinterOptions) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        assert!(ret.errors.is_empty(), "{source_text}");
+        Printer::new(source_text.len(), options).build(&ret.program)
+    }
+
+    fn minify(source_text: &str, options: PrinterOptions) -> String {
+        print(source_text, PrinterOptions { minify_whitespace: true, ..options })
+    }
+
+    #[test]
+    fn quote() {
+        let single = PrinterOptions::default();
+        let double = PrinterOptions { quote: Quote::Double, ..single };
+        assert_eq!(minify(r#"a = "b""#, single), "a='b'");
+        assert_eq!(minify("a = 'b'", double), r#"a="b""#);
+        assert_eq!(minify(r#"a = "it's""#, single), r#"a="it's""#);
+        assert_eq!(minify(r#"a = 'say "hi"'"#, double), r#"a='say "hi"'"#);
+        assert_eq!(minify(r#"a = 'it\'s "a"'"#, single), r#"a='it\'s "a"'"#);
+        assert_eq!(minify(r"a = '\n\\\0\x01\u2028 é'", single), r"a='\n\\\0\x01\u2028 é'");
+        assert_eq!(minify(r"a = '\x001'", single), r"a='\x001'");
+    }
+
+    #[test]
+    fn ascii_only() {
+        let options = PrinterOptions { ascii_only: true, ..PrinterOptions::default() };
+        assert_eq!(minify("a = 'é😀'", options), r"a='\u00E9\uD83D\uDE00'");
+        assert_eq!(minify("let café = 1", options), r"let caf\u00E9=1");
+        assert_eq!(minify("a.ü = b", options), r"a.\u00FC=b");
+        assert_eq!(minify("a = /é[😀]/g", options), r"a=/\u00E9[\uD83D\uDE00]/g");
+        assert_eq!(minify(r"a = /\é/", options), r"a=/\u00E9/");
+        assert_eq!(minify("a = /😀/u", options), r"a=/\u{1F600}/u");
+        assert_eq!(minify("a = `é${b}😀`", options), r"a=`\u00E9${b}\u{1F600}`");
+        assert_eq!(minify(r"a = `\\é`", options), r"a=`\\\u00E9`");
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(
+            minify("a: for (;;) { continue a; break a; }", PrinterOptions::default()),
+            "a:for(;;){continue a;break a}"
+        );
+    }
+
+    #[test]
+    fn using_declarations() {
+        let options = PrinterOptions::default();
+        assert_eq!(
+            minify("async () => { using a = b; await using c = d; for (using e of f); }", options),
+            "async()=>{using a=b;await using c=d;for(using e of f);}"
+        );
+    }
+
+    #[test]
+    fn decorators() {
+        let options = PrinterOptions::default();
+        assert_eq!(
+            minify("export @a @b.c() class A { @d accessor e = 1; @(f[0]) static #g() {} }", options),
+            "@a @b.c() export class A{@d accessor e=1;@(f[0]) static #g(){}}"
+        );
+        assert_eq!(
+            minify("export default @a class {}; b = @c class {}", options),
+            "@a export default class{};b=@c class{}"
+        );
+    }
+
+    #[test]
+    fn import_attributes() {
+        let options = PrinterOptions::default();
+        assert_eq!(
+            minify(
+                "import a from 'a' with { type: 'json' }; import 'b' assert { type: 'css' };",
+                options
+            ),
+            "import a from 'a'with{type:'json'};import 'b'assert{type:'css'}"
+        );
+        assert_eq!(
+            minify(
+                "export * from 'c' with { 'type': 'json' }; export { d } from 'd' with {};",
+                options
+            ),
+            "export* from'c'with{'type':'json'};export {d}from'd'with{}"
+        );
+        assert_eq!(
+            minify("import('e', { with: { type: 'json' } })", options),
+            "import('e',{with:{type:'json'}})"
+        );
+    }
+
+    #[test]
+    fn number_member() {
+        let options = PrinterOptions::default();
+        assert_eq!(
+            minify("1 .toString(); 1.5.toFixed(); 0x1.a", options),
+            "1 .toString();1.5.toFixed();0x1.a"
+        );
+    }
+
+    #[test]
+    fn max_line_len() {
+        let options = PrinterOptions { max_line_len: Some(10), ..PrinterOptions::default() };
+        assert_eq!(
+            minify("let a = [1, 2, 3, 4, 5, 6, 7, 8]; b(); c();", options),
+            "let a=[1,2,\n3,4,5,6,7,8];\nb();c()"
+        );
+        assert_eq!(minify("a = 'a, b, c, d, e, f'", options), "a='a, b, c, d, e, f'");
+    }
+
+    #[test]
+    fn use_tabs() {
+        let options = PrinterOptions { use_tabs: true, ..PrinterOptions::default() };
+        assert_eq!(
+            print("if (a) { if (b) { c() } }", options),
+            "if (a) {\n\tif (b) {\n\t\tc();\n\t}\n}\n"
+        );
+    }
+}
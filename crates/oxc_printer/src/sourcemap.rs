@@ -0,0 +1,511 @@
+//! Source Map v3 generation
+//!
+//! Spec: <https://sourcemaps.info/spec.html>
+//!
+//! Mappings are recorded as the printer writes nodes, and are only encoded into
+//! the VLQ `mappings` string when the source map is built.
+//! Columns are counted in UTF-16 code units, the same as browsers do.
+
+use serde::{Deserialize, Serialize};
+
+/// A Source Map v3, as serialized to `.map` files.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMap {
+    pub version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_root: Option<String>,
+    pub sources: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources_content: Option<Vec<Option<String>>>,
+    #[serde(default)]
+    pub names: Vec<String>,
+    pub mappings: String,
+}
+
+/// A decoded mapping segment, all positions are 0-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub generated_line: u32,
+    pub generated_column: u32,
+    /// Index into `sources`, `None` for segments without an original position.
+    pub source_id: Option<u32>,
+    pub original_line: u32,
+    pub original_column: u32,
+    /// Index into `names`.
+    pub name_id: Option<u32>,
+}
+
+impl SourceMap {
+    /// Parse a source map from JSON.
+    ///
+    /// # Errors
+    ///
+    /// * The input is not a valid source map JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// # Panics
+    ///
+    /// * Never, serializing strings and numbers cannot fail.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    /// A `data:` URL, to be used in a `//# sourceMappingURL=` comment.
+    #[must_use]
+    pub fn to_data_url(&self) -> String {
+        let mut url = String::from("data:application/json;charset=utf-8;base64,");
+        encode_base64(self.to_json().as_bytes(), &mut url);
+        url
+    }
+
+    /// Decode the `mappings`, tokens are sorted by their generated position.
+    /// Invalid segments are skipped.
+    #[must_use]
+    pub fn tokens(&self) -> Vec<Token> {
+        decode_mappings(&self.mappings)
+    }
+
+    /// Find the token covering a generated position, i.e. the closest one before it on the same line.
+    #[must_use]
+    pub fn lookup_token(tokens: &[Token], line: u32, column: u32) -> Option<&Token> {
+        let index = tokens.partition_point(|token| {
+            (token.generated_line, token.generated_column) <= (line, column)
+        });
+        let token = tokens.get(index.checked_sub(1)?)?;
+        (token.generated_line == line).then_some(token)
+    }
+
+    /// Compose this map with the `input` map of the source it was generated from,
+    /// so the result maps the generated code back to the sources of `input`.
+    ///
+    /// Names from `input` take precedence over names in this map.
+    /// Positions which cannot be found in `input` are dropped.
+    #[must_use]
+    pub fn compose(&self, input: &Self) -> Self {
+        let input_tokens = input.tokens();
+        let mut names = NameTable::default();
+        let tokens = self
+            .tokens()
+            .into_iter()
+            .filter_map(|token| {
+                token.source_id?;
+                let original =
+                    Self::lookup_token(&input_tokens, token.original_line, token.original_column)?;
+                let source_id = original.source_id?;
+                let name = original
+                    .name_id
+                    .and_then(|id| input.names.get(id as usize))
+                    .or_else(|| token.name_id.and_then(|id| self.names.get(id as usize)));
+                Some(Token {
+                    source_id: Some(source_id),
+                    original_line: original.original_line,
+                    original_column: original.original_column,
+                    name_id: name.map(|name| names.add(name)),
+                    ..token
+                })
+            })
+            .collect::<Vec<_>>();
+        Self {
+            version: 3,
+            file: self.file.clone(),
+            source_root: input.source_root.clone(),
+            sources: input.sources.clone(),
+            sources_content: input.sources_content.clone(),
+            names: names.names,
+            mappings: encode_mappings(&tokens),
+        }
+    }
+}
+
+#[derive(Default)]
+struct NameTable {
+    names: Vec<String>,
+    ids: std::collections::HashMap<String, u32>,
+}
+
+impl NameTable {
+    #[allow(clippy::cast_possible_truncation)]
+    fn add(&mut self, name: &str) -> u32 {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = self.names.len() as u32;
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+}
+
+/// Records mappings while printing a single source file.
+pub struct SourceMapBuilder {
+    source_name: String,
+    source_text: String,
+    /// Byte offsets of the start of every line in the source text.
+    line_offsets: Vec<u32>,
+    names: NameTable,
+    tokens: Vec<Token>,
+
+    /// Generated position of `generated_offset`,
+    /// so the output is only scanned once for line breaks.
+    generated_offset: usize,
+    generated_line: u32,
+    generated_column: u32,
+}
+
+impl SourceMapBuilder {
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(source_name: &str, source_text: &str) -> Self {
+        let line_offsets = std::iter::once(0)
+            .chain(source_text.match_indices('\n').map(|(offset, _)| offset as u32 + 1))
+            .collect();
+        Self {
+            source_name: source_name.to_string(),
+            source_text: source_text.to_string(),
+            line_offsets,
+            names: NameTable::default(),
+            tokens: vec![],
+            generated_offset: 0,
+            generated_line: 0,
+            generated_column: 0,
+        }
+    }
+
+    /// Map the end of `code` to `original_offset` in the source text.
+    /// `name` is the original name of an identifier, recorded when it was printed with a different name.
+    pub fn add_mapping(&mut self, code: &[u8], original_offset: u32, name: Option<&str>) {
+        self.update_generated_position(code);
+        let (original_line, original_column) = self.original_position(original_offset);
+        let token = Token {
+            generated_line: self.generated_line,
+            generated_column: self.generated_column,
+            source_id: Some(0),
+            original_line,
+            original_column,
+            name_id: name.map(|name| self.names.add(name)),
+        };
+        // Nested nodes starting at the same position, keep the innermost one.
+        if let Some(last) = self.tokens.last_mut()
+            && last.generated_line == token.generated_line
+            && last.generated_column == token.generated_column
+        {
+            *last = token;
+        } else {
+            self.tokens.push(token);
+        }
+    }
+
+    /// The text of the source at `start..end`, used to check whether an identifier was renamed.
+    #[must_use]
+    pub fn source_slice(&self, start: u32, end: u32) -> Option<&str> {
+        self.source_text.get(start as usize..end as usize)
+    }
+
+    #[must_use]
+    pub fn into_source_map(self) -> SourceMap {
+        SourceMap {
+            version: 3,
+            file: None,
+            source_root: None,
+            sources: vec![self.source_name],
+            sources_content: Some(vec![Some(self.source_text)]),
+            names: self.names.names,
+            mappings: encode_mappings(&self.tokens),
+        }
+    }
+
+    fn update_generated_position(&mut self, code: &[u8]) {
+        for &byte in &code[self.generated_offset..] {
+            match byte {
+                b'\n' => {
+                    self.generated_line += 1;
+                    self.generated_column = 0;
+                }
+                // UTF-8 continuation bytes
+                0x80..=0xBF => {}
+                // Leading byte of a 4 byte sequence, a surrogate pair in UTF-16
+                0xF0..=0xFF => self.generated_column += 2,
+                _ => self.generated_column += 1,
+            }
+        }
+        self.generated_offset = code.len();
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn original_position(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_offsets.partition_point(|&line_offset| line_offset <= offset) - 1;
+        let line_offset = self.line_offsets[line] as usize;
+        let column = self
+            .source_text
+            .get(line_offset..offset as usize)
+            .map_or(0, |text| text.encode_utf16().count() as u32);
+        (line as u32, column)
+    }
+}
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[allow(clippy::cast_possible_truncation)]
+fn base64_value(ch: u8) -> Option<u32> {
+    BASE64_CHARS.iter().position(|&c| c == ch).map(|value| value as u32)
+}
+
+fn encode_mappings(tokens: &[Token]) -> String {
+    let mut mappings = String::new();
+    let mut generated_line = 0;
+    let mut prev_generated_column = 0;
+    let mut prev_source_id = 0;
+    let mut prev_original_line = 0;
+    let mut prev_original_column = 0;
+    let mut prev_name_id = 0;
+    for token in tokens {
+        if token.generated_line == generated_line {
+            if !mappings.is_empty() && !mappings.ends_with(';') {
+                mappings.push(',');
+            }
+        } else {
+            for _ in generated_line..token.generated_line {
+                mappings.push(';');
+            }
+            generated_line = token.generated_line;
+            prev_generated_column = 0;
+        }
+        encode_vlq(i64::from(token.generated_column) - prev_generated_column, &mut mappings);
+        prev_generated_column = i64::from(token.generated_column);
+        let Some(source_id) = token.source_id else { continue };
+        encode_vlq(i64::from(source_id) - prev_source_id, &mut mappings);
+        encode_vlq(i64::from(token.original_line) - prev_original_line, &mut mappings);
+        encode_vlq(i64::from(token.original_column) - prev_original_column, &mut mappings);
+        prev_source_id = i64::from(source_id);
+        prev_original_line = i64::from(token.original_line);
+        prev_original_column = i64::from(token.original_column);
+        if let Some(name_id) = token.name_id {
+            encode_vlq(i64::from(name_id) - prev_name_id, &mut mappings);
+            prev_name_id = i64::from(name_id);
+        }
+    }
+    mappings
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn decode_mappings(mappings: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut source_id = 0;
+    let mut original_line = 0;
+    let mut original_column = 0;
+    let mut name_id = 0;
+    for (generated_line, line) in mappings.split(';').enumerate() {
+        let mut generated_column = 0;
+        for segment in line.split(',').filter(|segment| !segment.is_empty()) {
+            let Some(fields) = decode_vlq_segment(segment) else { continue };
+            generated_column += fields[0];
+            let mut token = Token {
+                generated_line: generated_line as u32,
+                generated_column: generated_column as u32,
+                source_id: None,
+                original_line: 0,
+                original_column: 0,
+                name_id: None,
+            };
+            if fields.len() >= 4 {
+                source_id += fields[1];
+                original_line += fields[2];
+                original_column += fields[3];
+                token.source_id = Some(source_id as u32);
+                token.original_line = original_line as u32;
+                token.original_column = original_column as u32;
+            }
+            if fields.len() >= 5 {
+                name_id += fields[4];
+                token.name_id = Some(name_id as u32);
+            }
+            tokens.push(token);
+        }
+    }
+    tokens.sort_by_key(|token| (token.generated_line, token.generated_column));
+    tokens
+}
+
+/// Encode a signed value as base64 VLQ, the sign is stored in the least significant bit.
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+fn encode_vlq(value: i64, out: &mut String) {
+    let mut vlq = if value < 0 { ((-value) << 1) | 1 } else { value << 1 } as u64;
+    loop {
+        let mut digit = vlq & 0b1_1111;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0b10_0000;
+        }
+        out.push(BASE64_CHARS[digit as usize] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn decode_vlq_segment(segment: &str) -> Option<Vec<i64>> {
+    let mut fields = vec![];
+    let mut value = 0i64;
+    let mut shift = 0;
+    for ch in segment.bytes() {
+        let digit = i64::from(base64_value(ch)?);
+        value += (digit & 0b1_1111) << shift;
+        if digit & 0b10_0000 == 0 {
+            fields.push(if value & 1 == 1 { -(value >> 1) } else { value >> 1 });
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 5;
+            if shift > 60 {
+                return None;
+            }
+        }
+    }
+    (shift == 0 && !fields.is_empty()).then_some(fields)
+}
+
+fn encode_base64(bytes: &[u8], out: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_CHARS[(n >> (18 - 6 * i)) as usize & 0b11_1111] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use super::{decode_vlq_segment, encode_vlq, SourceMap, SourceMapBuilder, Token};
+    use crate::{Printer, PrinterOptions};
+
+    #[test]
+    fn vlq() {
+        for value in [0, 1, -1, 15, 16, -16, 1000, -123_456] {
+            let mut encoded = String::new();
+            encode_vlq(value, &mut encoded);
+            assert_eq!(decode_vlq_segment(&encoded), Some(vec![value]));
+        }
+        let mut encoded = String::new();
+        encode_vlq(16, &mut encoded);
+        assert_eq!(encoded, "gB");
+    }
+
+    #[test]
+    fn builder() {
+        let source_text = "let a;\n  b";
+        let mut builder = SourceMapBuilder::new("input.js", source_text);
+        builder.add_mapping(b"", 0, None);
+        builder.add_mapping(b"let a;\n", 9, Some("b"));
+        let source_map = builder.into_source_map();
+        assert_eq!(source_map.mappings, "AAAA;AACEA");
+        assert_eq!(source_map.names, vec!["b"]);
+        assert_eq!(
+            source_map.tokens()[1],
+            Token {
+                generated_line: 1,
+                generated_column: 0,
+                source_id: Some(0),
+                original_line: 1,
+                original_column: 2,
+                name_id: Some(0)
+            }
+        );
+        let json = source_map.to_json();
+        assert_eq!(SourceMap::from_json(&json).unwrap(), source_map);
+    }
+
+    #[test]
+    fn compose() {
+        // original.ts -> input.js -> output.js
+        let input = SourceMap {
+            version: 3,
+            sources: vec!["original.ts".to_string()],
+            names: vec!["foo".to_string()],
+            mappings: "AAAA;AACEA".to_string(),
+            ..SourceMap::default()
+        };
+        let output = SourceMap {
+            version: 3,
+            sources: vec!["input.js".to_string()],
+            mappings: "AAAA,EACA".to_string(),
+            ..SourceMap::default()
+        };
+        let composed = output.compose(&input);
+        assert_eq!(composed.sources, vec!["original.ts"]);
+        assert_eq!(composed.names, vec!["foo"]);
+        let tokens = composed.tokens();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!((tokens[1].generated_column, tokens[1].original_line), (2, 1));
+        assert_eq!(tokens[1].original_column, 2);
+    }
+
+    #[test]
+    fn printer() {
+        let source_text = "let foo = 1;\n\nfoo(  ü, bar);\n";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let options = PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        let ret = Printer::new(source_text.len(), options)
+            .with_source_map("input.js", source_text)
+            .build_with_source_map(&ret.program);
+        assert_eq!(ret.code, "let foo=1;foo(ü,bar)");
+
+        let source_map = ret.source_map.unwrap();
+        assert_eq!(source_map.sources, vec!["input.js"]);
+        assert_eq!(source_map.sources_content, Some(vec![Some(source_text.to_string())]));
+        assert!(source_map.names.is_empty());
+        let tokens = source_map.tokens();
+        let original = |column| {
+            let token = SourceMap::lookup_token(&tokens, 0, column).unwrap();
+            (token.original_line, token.original_column)
+        };
+        // `foo` in `let foo`
+        assert_eq!(original(4), (0, 4));
+        // `1`
+        assert_eq!(original(8), (0, 10));
+        // `foo(`
+        assert_eq!(original(10), (2, 0));
+        // `ü`
+        assert_eq!(original(14), (2, 6));
+        // `bar`, columns are counted in UTF-16
+        assert_eq!(original(16), (2, 9));
+    }
+
+    #[test]
+    fn mangled_names() {
+        let source_text = "let foo = 1;\nfoo();\n";
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        let options = PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        let names = [(4, "a".into()), (13, "a".into())].into_iter().collect();
+        let ret = Printer::new(source_text.len(), options)
+            .with_source_map("input.js", source_text)
+            .with_mangled_names(names)
+            .build_with_source_map(&ret.program);
+        assert_eq!(ret.code, "let a=1;a()");
+
+        let source_map = ret.source_map.unwrap();
+        assert_eq!(source_map.names, vec!["foo"]);
+        let tokens = source_map.tokens();
+        for (column, original) in [(4, (0, 4)), (8, (1, 0))] {
+            let token = SourceMap::lookup_token(&tokens, 0, column).unwrap();
+            assert_eq!((token.original_line, token.original_column), original);
+            assert_eq!(token.name_id, Some(0));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Regular Expression Pattern AST
+//!
+//! Node names follow the grammar of [Patterns](https://tc39.es/ecma262/#sec-patterns),
+//! all spans are offsets into the source text containing the regular expression literal.
+
+use oxc_allocator::{Box, Vec};
+use oxc_ast::{Atom, Span};
+
+/// `Pattern` : `Disjunction`
+#[derive(Debug)]
+pub struct Pattern<'a> {
+    pub span: Span,
+    pub body: Disjunction<'a>,
+}
+
+/// `Disjunction` : `Alternative` | `Disjunction`
+#[derive(Debug)]
+pub struct Disjunction<'a> {
+    pub span: Span,
+    pub body: Vec<'a, Alternative<'a>>,
+}
+
+/// `Alternative` : `Term`*
+#[derive(Debug)]
+pub struct Alternative<'a> {
+    pub span: Span,
+    pub body: Vec<'a, Term<'a>>,
+}
+
+#[derive(Debug)]
+pub enum Term<'a> {
+    /// `^` `$` `\b` `\B`
+    BoundaryAssertion(BoundaryAssertion),
+    /// `(?=...)` `(?!...)` `(?<=...)` `(?<!...)`
+    LookAroundAssertion(Box<'a, LookAroundAssertion<'a>>),
+    /// `a*` `a+` `a?` `a{1,2}`
+    Quantifier(Box<'a, Quantifier<'a>>),
+    Character(Character),
+    /// `.`
+    Dot(Span),
+    /// `\d` `\D` `\s` `\S` `\w` `\W`
+    CharacterClassEscape(CharacterClassEscape),
+    /// `\p{...}` `\P{...}`
+    UnicodePropertyEscape(Box<'a, UnicodePropertyEscape>),
+    /// `[...]`
+    CharacterClass(Box<'a, CharacterClass<'a>>),
+    /// `(...)` `(?<name>...)`
+    CapturingGroup(Box<'a, CapturingGroup<'a>>),
+    /// `(?:...)`
+    IgnoreGroup(Box<'a, IgnoreGroup<'a>>),
+    /// `\1`
+    IndexedReference(IndexedReference),
+    /// `\k<name>`
+    NamedReference(Box<'a, NamedReference>),
+}
+
+impl<'a> Term<'a> {
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::BoundaryAssertion(term) => term.span,
+            Self::LookAroundAssertion(term) => term.span,
+            Self::Quantifier(term) => term.span,
+            Self::Character(term) => term.span,
+            Self::Dot(span) => *span,
+            Self::CharacterClassEscape(term) => term.span,
+            Self::UnicodePropertyEscape(term) => term.span,
+            Self::CharacterClass(term) => term.span,
+            Self::CapturingGroup(term) => term.span,
+            Self::IgnoreGroup(term) => term.span,
+            Self::IndexedReference(term) => term.span,
+            Self::NamedReference(term) => term.span,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BoundaryAssertion {
+    pub span: Span,
+    pub kind: BoundaryAssertionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryAssertionKind {
+    /// `^`
+    Start,
+    /// `$`
+    End,
+    /// `\b`
+    Boundary,
+    /// `\B`
+    NegativeBoundary,
+}
+
+#[derive(Debug)]
+pub struct LookAroundAssertion<'a> {
+    pub span: Span,
+    pub kind: LookAroundAssertionKind,
+    pub body: Disjunction<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookAroundAssertionKind {
+    /// `(?=...)`
+    Lookahead,
+    /// `(?!...)`
+    NegativeLookahead,
+    /// `(?<=...)`
+    Lookbehind,
+    /// `(?<!...)`
+    NegativeLookbehind,
+}
+
+/// `min` and `max` are `u32::MAX` for a count too large to represent
+#[derive(Debug)]
+pub struct Quantifier<'a> {
+    pub span: Span,
+    pub min: u32,
+    /// `None` for an unbounded quantifier
+    pub max: Option<u32>,
+    pub greedy: bool,
+    pub body: Term<'a>,
+}
+
+/// A single character, written as is or escaped
+#[derive(Debug, Clone, Copy)]
+pub struct Character {
+    pub span: Span,
+    pub kind: CharacterKind,
+    /// Code point, or a lone surrogate
+    pub value: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterKind {
+    /// `a`
+    Symbol,
+    /// `\n` `\t`, `\b` in a character class
+    SingleEscape,
+    /// `\cJ`
+    ControlLetter,
+    /// `\0`
+    Null,
+    /// `\012`, Annex B only
+    Octal,
+    /// `\x0A`
+    HexadecimalEscape,
+    /// `\u000A` `\u{A}`
+    UnicodeEscape,
+    /// `\.` `\/`
+    Identifier,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterClassEscape {
+    pub span: Span,
+    pub kind: CharacterClassEscapeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClassEscapeKind {
+    /// `\d`
+    D,
+    /// `\D`
+    NegativeD,
+    /// `\s`
+    S,
+    /// `\S`
+    NegativeS,
+    /// `\w`
+    W,
+    /// `\W`
+    NegativeW,
+}
+
+/// `\p{Name}` `\p{Name=Value}`
+#[derive(Debug)]
+pub struct UnicodePropertyEscape {
+    pub span: Span,
+    pub negative: bool,
+    /// Property of strings, e.g. `\p{RGI_Emoji}`, `v` flag only
+    pub strings: bool,
+    pub name: Atom,
+    pub value: Option<Atom>,
+}
+
+#[derive(Debug)]
+pub struct CharacterClass<'a> {
+    pub span: Span,
+    pub negative: bool,
+    pub kind: CharacterClassContentsKind,
+    pub body: Vec<'a, CharacterClassContents<'a>>,
+}
+
+/// Set operation of a character class, only `Union` is possible without the `v` flag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharacterClassContentsKind {
+    /// `[ab]`
+    Union,
+    /// `[a&&b]`
+    Intersection,
+    /// `[a--b]`
+    Subtraction,
+}
+
+#[derive(Debug)]
+pub enum CharacterClassContents<'a> {
+    /// `a-z`
+    CharacterClassRange(Box<'a, CharacterClassRange>),
+    CharacterClassEscape(CharacterClassEscape),
+    UnicodePropertyEscape(Box<'a, UnicodePropertyEscape>),
+    Character(Character),
+    /// `[[a-z]--x]`, `v` flag only
+    NestedCharacterClass(Box<'a, CharacterClass<'a>>),
+    /// `[\q{abc|d}]`, `v` flag only
+    ClassStringDisjunction(Box<'a, ClassStringDisjunction<'a>>),
+}
+
+#[derive(Debug)]
+pub struct CharacterClassRange {
+    pub span: Span,
+    pub min: Character,
+    pub max: Character,
+}
+
+/// `\q{abc|d}`
+#[derive(Debug)]
+pub struct ClassStringDisjunction<'a> {
+    pub span: Span,
+    /// Contains a string which is not a single character
+    pub strings: bool,
+    pub body: Vec<'a, ClassString<'a>>,
+}
+
+#[derive(Debug)]
+pub struct ClassString<'a> {
+    pub span: Span,
+    pub body: Vec<'a, Character>,
+}
+
+#[derive(Debug)]
+pub struct CapturingGroup<'a> {
+    pub span: Span,
+    pub name: Option<Atom>,
+    pub body: Disjunction<'a>,
+}
+
+#[derive(Debug)]
+pub struct IgnoreGroup<'a> {
+    pub span: Span,
+    pub body: Disjunction<'a>,
+}
+
+/// `\1`
+#[derive(Debug)]
+pub struct IndexedReference {
+    pub span: Span,
+    pub index: u32,
+}
+
+/// `\k<name>`
+#[derive(Debug)]
+pub struct NamedReference {
+    pub span: Span,
+    pub name: Atom,
+}
@@ -0,0 +1,106 @@
+use oxc_ast::Span;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Unmatched ')'")]
+#[diagnostic()]
+pub struct UnmatchedParen(#[label("Unmatched ')'")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Unterminated group")]
+#[diagnostic()]
+pub struct UnterminatedGroup(#[label("Unterminated group")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid group")]
+#[diagnostic()]
+pub struct InvalidGroup(#[label("Invalid group")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Nothing to repeat")]
+#[diagnostic()]
+pub struct NothingToRepeat(#[label("Nothing to repeat")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Numbers out of order in quantifier")]
+#[diagnostic()]
+pub struct QuantifierOutOfOrder(#[label("Numbers out of order in quantifier")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Lone quantifier brackets")]
+#[diagnostic()]
+pub struct LoneQuantifierBrackets(#[label("Lone quantifier brackets")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid escape")]
+#[diagnostic()]
+pub struct InvalidEscape(#[label("Invalid escape")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid Unicode escape")]
+#[diagnostic()]
+pub struct InvalidUnicodeEscape(#[label("Invalid Unicode escape")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid decimal escape")]
+#[diagnostic()]
+pub struct InvalidDecimalEscape(#[label("There is no capturing group with this number")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid property name")]
+#[diagnostic()]
+pub struct InvalidPropertyName(#[label("Invalid property name")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid capture group name")]
+#[diagnostic()]
+pub struct InvalidCaptureGroupName(#[label("Invalid capture group name")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Duplicate capture group name")]
+#[diagnostic()]
+pub struct DuplicateCaptureGroupName(
+    pub String,
+    #[label("`{0}` is first captured here")] pub Span,
+    #[label("and captured again here")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid named capture referenced")]
+#[diagnostic()]
+pub struct InvalidNamedReference(#[label("There is no capturing group with this name")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Unterminated character class")]
+#[diagnostic()]
+pub struct UnterminatedCharacterClass(#[label("Unterminated character class")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid character class")]
+#[diagnostic()]
+pub struct InvalidCharacterClass(#[label("A class escape cannot be a bound of a range")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Range out of order in character class")]
+#[diagnostic()]
+pub struct RangeOutOfOrder(#[label("Range out of order in character class")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid set operation in character class")]
+#[diagnostic()]
+pub struct InvalidSetOperation(#[label("Invalid set operation in character class")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Invalid character in character class")]
+#[diagnostic()]
+pub struct InvalidClassSetCharacter(#[label("This character must be escaped")] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression: Negated character class may contain strings")]
+#[diagnostic()]
+pub struct NegatedClassWithStrings(
+    #[label("Negated character class may contain strings")] pub Span,
+);
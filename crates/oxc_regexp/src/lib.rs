@@ -0,0 +1,168 @@
+//! Regular Expression Parser
+//!
+//! Parses the pattern of a regular expression literal into a [`ast::Pattern`]
+//! and reports its [early errors](https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors),
+//! for the parser to validate literals and for lint rules and the minifier to inspect them.
+//!
+//! ```ignore
+//! let pattern = Parser::new(&allocator, &regex.pattern, regex.flags, span.start + 1).parse()?;
+//! ```
+
+#![allow(clippy::wildcard_imports)] // allow for use `crate::ast::*`
+
+pub mod ast;
+mod diagnostics;
+mod parser;
+mod unicode_property;
+
+pub use crate::parser::Parser;
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::{ast::RegExpFlags, Span};
+
+    use crate::{ast::*, Parser};
+
+    fn parse<'a>(
+        allocator: &'a Allocator,
+        source: &'a str,
+        flags: &str,
+    ) -> oxc_diagnostics::Result<Pattern<'a>> {
+        let flags = flags.chars().fold(RegExpFlags::empty(), |flags, c| {
+            flags
+                | match c {
+                    'u' => RegExpFlags::U,
+                    'v' => RegExpFlags::V,
+                    'i' => RegExpFlags::I,
+                    _ => unreachable!(),
+                }
+        });
+        Parser::new(allocator, source, flags, 0).parse()
+    }
+
+    #[test]
+    fn valid() {
+        let allocator = Allocator::default();
+        let cases = [
+            ("", ""),
+            ("a|b|", ""),
+            ("^a*?b+c?$", ""),
+            ("a{1}b{1,}c{1,2}?", ""),
+            ("(a)(?:b)(?<name>c)\\1\\k<name>", ""),
+            ("(?=a)(?!b)(?<=c)(?<!d)\\b\\B", ""),
+            ("[a-z\\d\\-]\\w\\S.", "u"),
+            ("\\p{L}\\P{Script=Greek}\\p{gc=Lu}\\p{ASCII_Hex_Digit}", "u"),
+            ("\\u{1F600}\\uD83D\\uDE00\\x41\\cJ\\0", "u"),
+            ("\\/\\.\\*", "u"),
+            ("(?<$\\u{61}>.)\\k<$a>", "u"),
+            // Annex B
+            ("{", ""),
+            ("a{", ""),
+            ("a{1", ""),
+            ("}]", ""),
+            ("\\8\\a\\c\\u\\x\\p{Foo}", ""),
+            ("\\1(a)", ""),
+            ("\\2(a)", ""),
+            ("\\07\\377", ""),
+            ("\\k", ""),
+            ("[\\d-a][\\c1\\c_]", ""),
+            ("(?=a)*", ""),
+            // `v` flag
+            ("[[a-z]--[aeiou]]", "v"),
+            ("[\\p{L}&&\\p{ASCII}]", "v"),
+            ("[\\q{abc|d}a-c]", "v"),
+            ("[\\p{RGI_Emoji}\\q{}]", "v"),
+            ("[^\\q{a|b}]", "v"),
+            ("[\\&\\-\\!]", "v"),
+            ("[]", "v"),
+        ];
+        for (source, flags) in cases {
+            if let Err(error) = parse(&allocator, source, flags) {
+                panic!("/{source}/{flags} should be valid: {error}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let allocator = Allocator::default();
+        let cases = [
+            ("(?<a>x)(?<a>y)", "", "Duplicate capture group name"),
+            ("\\p{Foo}", "u", "Invalid property name"),
+            ("\\p{Script=Foo}", "u", "Invalid property name"),
+            ("\\P{RGI_Emoji}", "v", "Invalid property name"),
+            ("\\p{RGI_Emoji}", "u", "Invalid property name"),
+            ("(", "", "Unterminated group"),
+            ("(?<=a", "", "Unterminated group"),
+            ("a)", "", "Unmatched ')'"),
+            ("(?a)", "", "Invalid group"),
+            ("*", "", "Nothing to repeat"),
+            ("a**", "", "Nothing to repeat"),
+            ("{1}", "", "Nothing to repeat"),
+            ("^*", "", "Nothing to repeat"),
+            ("(?<=a)*", "", "Nothing to repeat"),
+            ("(?=a)*", "u", "Nothing to repeat"),
+            ("a{2,1}", "", "Numbers out of order in quantifier"),
+            ("{", "u", "Lone quantifier brackets"),
+            ("]", "u", "Lone quantifier brackets"),
+            ("\\a", "u", "Invalid escape"),
+            ("\\c", "u", "Invalid escape"),
+            ("\\", "", "Invalid escape"),
+            ("\\u{110000}", "u", "Invalid Unicode escape"),
+            ("\\1", "u", "Invalid decimal escape"),
+            ("(?<1>a)", "", "Invalid capture group name"),
+            ("(?<a>a)\\k<b>", "", "Invalid named capture referenced"),
+            ("\\k<a>", "u", "Invalid named capture referenced"),
+            ("(?<a>a)\\k", "", "Invalid capture group name"),
+            ("[a", "", "Unterminated character class"),
+            ("[z-a]", "", "Range out of order in character class"),
+            ("[\\d-a]", "u", "Invalid character class"),
+            ("[a&&b--c]", "v", "Invalid set operation in character class"),
+            ("[a-z&&b]", "v", "Invalid set operation in character class"),
+            ("[a&&&b]", "v", "Invalid set operation in character class"),
+            ("[(]", "v", "Invalid character in character class"),
+            ("[a!!b]", "v", "Invalid character in character class"),
+            ("[^\\q{ab}]", "v", "Negated character class may contain strings"),
+            ("[^[\\p{RGI_Emoji}]]", "v", "Negated character class may contain strings"),
+        ];
+        for (source, flags, message) in cases {
+            match parse(&allocator, source, flags) {
+                Ok(_) => panic!("/{source}/{flags} should be invalid"),
+                Err(error) => assert_eq!(
+                    error.to_string(),
+                    format!("Invalid regular expression: {message}"),
+                    "/{source}/{flags}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn pattern() {
+        let allocator = Allocator::default();
+        let pattern = parse(&allocator, "a(?<n>b|[^c-e])+?", "").unwrap();
+        let terms = &pattern.body.body[0].body;
+        assert!(matches!(terms[0], Term::Character(Character { value: 0x61, .. })));
+        let Term::Quantifier(quantifier) = &terms[1] else { panic!("{:?}", terms[1]) };
+        assert_eq!((quantifier.min, quantifier.max, quantifier.greedy), (1, None, false));
+        let Term::CapturingGroup(group) = &quantifier.body else { panic!("{quantifier:?}") };
+        assert_eq!(group.name.as_deref(), Some("n"));
+        assert_eq!(group.body.body.len(), 2);
+        let Term::CharacterClass(class) = &group.body.body[1].body[0] else { panic!("{group:?}") };
+        assert!(class.negative);
+        assert!(matches!(class.body[0], CharacterClassContents::CharacterClassRange(_)));
+    }
+
+    #[test]
+    fn span_offset() {
+        let allocator = Allocator::default();
+        let source = "(?<a>x)(?<a>y)";
+        let error = Parser::new(&allocator, source, RegExpFlags::empty(), 10).parse().unwrap_err();
+        let labels = error.labels().unwrap().map(|label| label.offset()).collect::<Vec<_>>();
+        assert_eq!(labels, vec![13, 20]);
+
+        let pattern = Parser::new(&allocator, "ab", RegExpFlags::empty(), 10).parse().unwrap();
+        assert_eq!(pattern.span, Span::new(10, 12));
+    }
+}
@@ -0,0 +1,1009 @@
+//! Recursive Descent Parser for [Patterns](https://tc39.es/ecma262/#sec-patterns),
+//! with the [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) grammar
+//! when neither the `u` nor the `v` flag is set.
+
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_ast::{ast::RegExpFlags, Atom, Span};
+use oxc_diagnostics::Result;
+use rustc_hash::FxHashMap;
+use unicode_id_start::{is_id_continue, is_id_start};
+
+use crate::{ast::*, diagnostics, unicode_property};
+
+pub struct Parser<'a> {
+    allocator: &'a Allocator,
+
+    /// Source text of the pattern, between the slashes of the literal
+    source: &'a str,
+
+    /// Offset of the pattern in the source text of the literal, added to all spans
+    span_offset: u32,
+
+    /// Byte index of the current character in `source`
+    index: usize,
+
+    /// The `u` or `v` flag
+    unicode_mode: bool,
+
+    /// The `v` flag
+    unicode_sets_mode: bool,
+
+    /// `\k` is a named reference, with the `u` or `v` flag or a named group in the pattern
+    named_groups: bool,
+
+    /// Number of capturing groups in the pattern
+    group_count: u32,
+
+    group_names: FxHashMap<Atom, Span>,
+
+    named_references: std::vec::Vec<(Atom, Span)>,
+}
+
+impl<'a> Parser<'a> {
+    #[must_use]
+    pub fn new(
+        allocator: &'a Allocator,
+        source: &'a str,
+        flags: RegExpFlags,
+        span_offset: u32,
+    ) -> Self {
+        let unicode_sets_mode = flags.contains(RegExpFlags::V);
+        Self {
+            allocator,
+            source,
+            span_offset,
+            index: 0,
+            unicode_mode: unicode_sets_mode || flags.contains(RegExpFlags::U),
+            unicode_sets_mode,
+            named_groups: false,
+            group_count: 0,
+            group_names: FxHashMap::default(),
+            named_references: vec![],
+        }
+    }
+
+    /// Parser main entry point
+    /// # Errors
+    /// The first early error of the pattern
+    pub fn parse(mut self) -> Result<Pattern<'a>> {
+        let (group_count, has_named_groups) = self.scan_capturing_groups();
+        self.group_count = group_count;
+        self.named_groups = self.unicode_mode || has_named_groups;
+
+        let body = self.parse_disjunction()?;
+        if self.peek().is_some() {
+            // `parse_term` consumes everything except a `)` without a group
+            return Err(diagnostics::UnmatchedParen(self.span_of_char()).into());
+        }
+
+        for (name, span) in &self.named_references {
+            if !self.group_names.contains_key(name) {
+                return Err(diagnostics::InvalidNamedReference(*span).into());
+            }
+        }
+
+        Ok(Pattern { span: self.span(0), body })
+    }
+
+    /// Count the capturing groups before parsing, `\1` is a reference only when the group exists,
+    /// and `\k` is a named reference only when the pattern has a named group
+    fn scan_capturing_groups(&self) -> (u32, bool) {
+        let bytes = self.source.as_bytes();
+        let mut count = 0;
+        let mut has_named_groups = false;
+        let mut class_depth = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 1,
+                b'[' if class_depth == 0 || self.unicode_sets_mode => class_depth += 1,
+                b']' if class_depth > 0 => class_depth -= 1,
+                b'(' if class_depth == 0 => match bytes.get(i + 1..i + 3) {
+                    Some([b'?', b'<']) if !matches!(bytes.get(i + 3), Some(b'=' | b'!')) => {
+                        count += 1;
+                        has_named_groups = true;
+                    }
+                    Some([b'?', _]) => {}
+                    _ => count += 1,
+                },
+                _ => {}
+            }
+            i += 1;
+        }
+        (count, has_named_groups)
+    }
+
+    /// `Disjunction` :: `Alternative` | `Disjunction`
+    fn parse_disjunction(&mut self) -> Result<Disjunction<'a>> {
+        let start = self.index;
+        let mut body = Vec::new_in(self.allocator);
+        loop {
+            body.push(self.parse_alternative()?);
+            if !self.eat('|') {
+                break;
+            }
+        }
+        Ok(Disjunction { span: self.span(start), body })
+    }
+
+    /// `Alternative` :: `Term`*
+    fn parse_alternative(&mut self) -> Result<Alternative<'a>> {
+        let start = self.index;
+        let mut body = Vec::new_in(self.allocator);
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            body.push(self.parse_term()?);
+        }
+        Ok(Alternative { span: self.span(start), body })
+    }
+
+    /// `Term` :: `Assertion` | `Atom` `Quantifier`?
+    fn parse_term(&mut self) -> Result<Term<'a>> {
+        let start = self.index;
+        if let Some(assertion) = self.parse_assertion()? {
+            // Annex B: lookaheads are quantifiable
+            if !self.unicode_mode && is_lookahead(&assertion) {
+                return self.parse_quantifier(start, assertion);
+            }
+            return Ok(assertion);
+        }
+        let atom = self.parse_atom()?;
+        self.parse_quantifier(start, atom)
+    }
+
+    /// `Assertion` :: `^` `$` `\b` `\B` `(?=` `(?!` `(?<=` `(?<!`
+    fn parse_assertion(&mut self) -> Result<Option<Term<'a>>> {
+        let start = self.index;
+        let kind = if self.eat('^') {
+            BoundaryAssertionKind::Start
+        } else if self.eat('$') {
+            BoundaryAssertionKind::End
+        } else if self.eat_str("\\b") {
+            BoundaryAssertionKind::Boundary
+        } else if self.eat_str("\\B") {
+            BoundaryAssertionKind::NegativeBoundary
+        } else {
+            let kind = if self.eat_str("(?=") {
+                LookAroundAssertionKind::Lookahead
+            } else if self.eat_str("(?!") {
+                LookAroundAssertionKind::NegativeLookahead
+            } else if self.eat_str("(?<=") {
+                LookAroundAssertionKind::Lookbehind
+            } else if self.eat_str("(?<!") {
+                LookAroundAssertionKind::NegativeLookbehind
+            } else {
+                return Ok(None);
+            };
+            let body = self.parse_disjunction()?;
+            self.expect_group_end(start)?;
+            let assertion = LookAroundAssertion { span: self.span(start), kind, body };
+            return Ok(Some(Term::LookAroundAssertion(self.alloc(assertion))));
+        };
+        Ok(Some(Term::BoundaryAssertion(BoundaryAssertion { span: self.span(start), kind })))
+    }
+
+    /// `Atom` :: `PatternCharacter` `.` `\AtomEscape` `CharacterClass` `(GroupSpecifier Disjunction)` `(?:Disjunction)`
+    fn parse_atom(&mut self) -> Result<Term<'a>> {
+        let start = self.index;
+        match self.peek() {
+            Some('.') => {
+                self.bump();
+                Ok(Term::Dot(self.span(start)))
+            }
+            Some('\\') => self.parse_atom_escape(),
+            Some('[') => {
+                let class = self.parse_character_class()?;
+                Ok(Term::CharacterClass(self.alloc(class)))
+            }
+            Some('(') => self.parse_group(),
+            Some('*' | '+' | '?') => Err(diagnostics::NothingToRepeat(self.span_of_char()).into()),
+            Some('{') if self.at_braced_quantifier() => {
+                Err(diagnostics::NothingToRepeat(self.span_of_char()).into())
+            }
+            // Annex B: `ExtendedPatternCharacter`
+            Some('{' | '}' | ']') if self.unicode_mode => {
+                Err(diagnostics::LoneQuantifierBrackets(self.span_of_char()).into())
+            }
+            Some(c) => {
+                self.bump();
+                Ok(Term::Character(self.character(start, CharacterKind::Symbol, c)))
+            }
+            None => unreachable!("`parse_alternative` stops at the end of the pattern"),
+        }
+    }
+
+    /// `Quantifier` :: `QuantifierPrefix` `?`?
+    fn parse_quantifier(&mut self, start: usize, body: Term<'a>) -> Result<Term<'a>> {
+        let quantifier_start = self.index;
+        let (min, max) = match self.peek() {
+            Some('{') => match self.parse_braced_quantifier() {
+                Some(quantifier) => quantifier,
+                // A literal `{` in Annex B, an error for the next term otherwise
+                None => return Ok(body),
+            },
+            Some(c @ ('*' | '+' | '?')) => {
+                self.bump();
+                match c {
+                    '*' => (0, None),
+                    '+' => (1, None),
+                    _ => (0, Some(1)),
+                }
+            }
+            _ => return Ok(body),
+        };
+        if max.map_or(false, |max| min > max) {
+            return Err(diagnostics::QuantifierOutOfOrder(self.span(quantifier_start)).into());
+        }
+        let greedy = !self.eat('?');
+        let quantifier = Quantifier { span: self.span(start), min, max, greedy, body };
+        Ok(Term::Quantifier(self.alloc(quantifier)))
+    }
+
+    /// `{n}` `{n,}` `{n,m}`, the position is restored when it is not a quantifier
+    fn parse_braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        let checkpoint = self.index;
+        self.bump(); // bump `{`
+        let quantifier = self.parse_decimal_digits().and_then(|min| {
+            let max = if self.eat(',') {
+                if self.peek() == Some('}') {
+                    None
+                } else {
+                    Some(self.parse_decimal_digits()?)
+                }
+            } else {
+                Some(min)
+            };
+            self.eat('}').then_some((min, max))
+        });
+        if quantifier.is_none() {
+            self.index = checkpoint;
+        }
+        quantifier
+    }
+
+    fn at_braced_quantifier(&mut self) -> bool {
+        let checkpoint = self.index;
+        let quantifier = self.parse_braced_quantifier();
+        self.index = checkpoint;
+        quantifier.is_some()
+    }
+
+    /// Saturates at `u32::MAX`
+    fn parse_decimal_digits(&mut self) -> Option<u32> {
+        let mut value: Option<u32> = None;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.bump();
+            value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+        }
+        value
+    }
+
+    /// `(GroupSpecifier Disjunction)` `(?:Disjunction)`
+    fn parse_group(&mut self) -> Result<Term<'a>> {
+        let start = self.index;
+        self.bump(); // bump `(`
+
+        if self.eat_str("?:") {
+            let body = self.parse_disjunction()?;
+            self.expect_group_end(start)?;
+            let group = IgnoreGroup { span: self.span(start), body };
+            return Ok(Term::IgnoreGroup(self.alloc(group)));
+        }
+
+        let name = if self.eat('?') {
+            // Assertions were parsed before, a named group is the only group left
+            if self.peek() != Some('<') {
+                return Err(diagnostics::InvalidGroup(self.span(start)).into());
+            }
+            let (name, span) = self.parse_group_name()?;
+            if let Some(first) = self.group_names.get(&name) {
+                return Err(
+                    diagnostics::DuplicateCaptureGroupName(name.to_string(), *first, span).into()
+                );
+            }
+            self.group_names.insert(name.clone(), span);
+            Some(name)
+        } else {
+            None
+        };
+
+        let body = self.parse_disjunction()?;
+        self.expect_group_end(start)?;
+        let group = CapturingGroup { span: self.span(start), name, body };
+        Ok(Term::CapturingGroup(self.alloc(group)))
+    }
+
+    fn expect_group_end(&mut self, start: usize) -> Result<()> {
+        if !self.eat(')') {
+            return Err(diagnostics::UnterminatedGroup(self.span(start)).into());
+        }
+        Ok(())
+    }
+
+    /// `GroupName` :: `<` `RegExpIdentifierName` `>`
+    /// Returns the name and its span, without the angle brackets
+    fn parse_group_name(&mut self) -> Result<(Atom, Span)> {
+        let start = self.index;
+        self.bump(); // bump `<`
+        let name_start = self.index;
+        let mut name = String::new();
+        loop {
+            let c = match self.peek() {
+                Some('>') if !name.is_empty() => break,
+                Some('\\') => {
+                    self.bump();
+                    if self.eat('u') {
+                        self.parse_unicode_escape(/* unicode */ true).and_then(char::from_u32)
+                    } else {
+                        None
+                    }
+                }
+                Some(c) => {
+                    self.bump();
+                    Some(c)
+                }
+                None => None,
+            };
+            match c {
+                Some(c) if name.is_empty() && (c == '$' || c == '_' || is_id_start(c)) => {
+                    name.push(c);
+                }
+                Some(c)
+                    if !name.is_empty()
+                        && (c == '$'
+                            || c == '\u{200c}'
+                            || c == '\u{200d}'
+                            || is_id_continue(c)) =>
+                {
+                    name.push(c);
+                }
+                _ => return Err(diagnostics::InvalidCaptureGroupName(self.span(start)).into()),
+            }
+        }
+        let span = self.span(name_start);
+        self.bump(); // bump `>`
+        Ok((Atom::from(name), span))
+    }
+
+    /// `AtomEscape` :: `DecimalEscape` `CharacterClassEscape` `CharacterEscape` `k GroupName`
+    fn parse_atom_escape(&mut self) -> Result<Term<'a>> {
+        let start = self.index;
+        self.bump(); // bump `\`
+        match self.peek() {
+            None => return Err(diagnostics::InvalidEscape(self.span(start)).into()),
+            Some('1'..='9') => {
+                let checkpoint = self.index;
+                let index = self.parse_decimal_digits().unwrap_or_default();
+                if index <= self.group_count {
+                    let reference = IndexedReference { span: self.span(start), index };
+                    return Ok(Term::IndexedReference(reference));
+                }
+                if self.unicode_mode {
+                    return Err(diagnostics::InvalidDecimalEscape(self.span(start)).into());
+                }
+                // Annex B: a legacy octal escape, or an identity escape of `8` and `9`
+                self.index = checkpoint;
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                return Ok(Term::CharacterClassEscape(self.parse_character_class_escape(start)));
+            }
+            Some('p' | 'P') if self.unicode_mode => {
+                let escape = self.parse_unicode_property_escape(start)?;
+                return Ok(Term::UnicodePropertyEscape(self.alloc(escape)));
+            }
+            Some('k') if self.named_groups => {
+                self.bump();
+                if self.peek() != Some('<') {
+                    return Err(diagnostics::InvalidCaptureGroupName(self.span(start)).into());
+                }
+                let (name, _) = self.parse_group_name()?;
+                let span = self.span(start);
+                self.named_references.push((name.clone(), span));
+                return Ok(Term::NamedReference(self.alloc(NamedReference { span, name })));
+            }
+            _ => {}
+        }
+        let character = self.parse_character_escape(start, /* in_class */ false)?;
+        Ok(Term::Character(character))
+    }
+
+    /// `CharacterClassEscape` :: `d` `D` `s` `S` `w` `W`
+    fn parse_character_class_escape(&mut self, start: usize) -> CharacterClassEscape {
+        let kind = match self.bump() {
+            Some('d') => CharacterClassEscapeKind::D,
+            Some('D') => CharacterClassEscapeKind::NegativeD,
+            Some('s') => CharacterClassEscapeKind::S,
+            Some('S') => CharacterClassEscapeKind::NegativeS,
+            Some('w') => CharacterClassEscapeKind::W,
+            Some('W') => CharacterClassEscapeKind::NegativeW,
+            _ => unreachable!(),
+        };
+        CharacterClassEscape { span: self.span(start), kind }
+    }
+
+    /// `CharacterClassEscape` :: `p{ UnicodePropertyValueExpression }` `P{ UnicodePropertyValueExpression }`
+    fn parse_unicode_property_escape(&mut self, start: usize) -> Result<UnicodePropertyEscape> {
+        let negative = self.bump() == Some('P');
+        let mut name = None;
+        let mut value = None;
+        if self.eat('{') {
+            name = self.parse_unicode_property_name();
+            if name.is_some() && self.eat('=') {
+                value = self.parse_unicode_property_name();
+            }
+        }
+        let (Some(name), true) = (name, self.eat('}')) else {
+            return Err(diagnostics::InvalidPropertyName(self.span(start)).into());
+        };
+
+        let mut strings = false;
+        let valid = match &value {
+            Some(value) => unicode_property::is_valid_name_and_value(name, value),
+            None if unicode_property::is_valid_lone_name(name) => true,
+            None => {
+                strings = self.unicode_sets_mode && unicode_property::is_property_of_strings(name);
+                strings && !negative
+            }
+        };
+        if !valid {
+            return Err(diagnostics::InvalidPropertyName(self.span(start)).into());
+        }
+
+        Ok(UnicodePropertyEscape {
+            span: self.span(start),
+            negative,
+            strings,
+            name: Atom::from(name),
+            value: value.map(Atom::from),
+        })
+    }
+
+    fn parse_unicode_property_name(&mut self) -> Option<&'a str> {
+        let start = self.index;
+        while self.peek().map_or(false, |c| c.is_ascii_alphanumeric() || c == '_') {
+            self.bump();
+        }
+        (self.index > start).then(|| &self.source[start..self.index])
+    }
+
+    /// `CharacterEscape`, with the identity escapes of Annex B without the `u` or `v` flag
+    fn parse_character_escape(&mut self, start: usize, in_class: bool) -> Result<Character> {
+        let Some(c) = self.bump() else {
+            return Err(diagnostics::InvalidEscape(self.span(start)).into());
+        };
+        let (kind, value) = match c {
+            'f' => (CharacterKind::SingleEscape, 0x0C),
+            'n' => (CharacterKind::SingleEscape, 0x0A),
+            'r' => (CharacterKind::SingleEscape, 0x0D),
+            't' => (CharacterKind::SingleEscape, 0x09),
+            'v' => (CharacterKind::SingleEscape, 0x0B),
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.bump();
+                    (CharacterKind::ControlLetter, letter as u32 % 32)
+                }
+                // Annex B: `ClassControlLetter`
+                Some(letter)
+                    if in_class
+                        && !self.unicode_mode
+                        && (letter.is_ascii_digit() || letter == '_') =>
+                {
+                    self.bump();
+                    (CharacterKind::ControlLetter, letter as u32 % 32)
+                }
+                _ if self.unicode_mode => {
+                    return Err(diagnostics::InvalidEscape(self.span(start)).into());
+                }
+                // Annex B: the `\` is a character, and the `c` is read next
+                _ => {
+                    self.index = start + 1;
+                    return Ok(self.character(start, CharacterKind::Symbol, '\\'));
+                }
+            },
+            '0' if !self.peek().map_or(false, |c| c.is_ascii_digit()) => (CharacterKind::Null, 0),
+            // Annex B: `LegacyOctalEscapeSequence`
+            '0'..='7' if !self.unicode_mode => {
+                let mut value = c.to_digit(8).unwrap();
+                let max_digits = if value <= 3 { 2 } else { 1 };
+                for _ in 0..max_digits {
+                    match self.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            self.bump();
+                            value = value * 8 + digit;
+                        }
+                        None => break,
+                    }
+                }
+                (CharacterKind::Octal, value)
+            }
+            'x' => match self.parse_hex_digits(2) {
+                Some(value) => (CharacterKind::HexadecimalEscape, value),
+                None if self.unicode_mode => {
+                    return Err(diagnostics::InvalidEscape(self.span(start)).into());
+                }
+                None => (CharacterKind::Identifier, 'x' as u32),
+            },
+            'u' => match self.parse_unicode_escape(self.unicode_mode) {
+                Some(value) => (CharacterKind::UnicodeEscape, value),
+                None if self.unicode_mode => {
+                    return Err(diagnostics::InvalidUnicodeEscape(self.span(start)).into());
+                }
+                None => (CharacterKind::Identifier, 'u' as u32),
+            },
+            c if self.unicode_mode => {
+                if !(is_syntax_character(c) || c == '/' || (in_class && c == '-')) {
+                    return Err(diagnostics::InvalidEscape(self.span(start)).into());
+                }
+                (CharacterKind::Identifier, c as u32)
+            }
+            // Annex B: `IdentityEscape` is any character other than `c`, and `k` with named groups
+            'k' if self.named_groups => {
+                return Err(diagnostics::InvalidEscape(self.span(start)).into());
+            }
+            c => (CharacterKind::Identifier, c as u32),
+        };
+        Ok(Character { span: self.span(start), kind, value })
+    }
+
+    /// `RegExpUnicodeEscapeSequence` after the `\u`,
+    /// the position is restored when it is not an escape
+    fn parse_unicode_escape(&mut self, unicode: bool) -> Option<u32> {
+        let checkpoint = self.index;
+        if unicode && self.eat('{') {
+            let value = self.parse_hex_digits(usize::MAX).filter(|value| *value <= 0x10_FFFF);
+            if let (Some(value), true) = (value, self.eat('}')) {
+                return Some(value);
+            }
+            self.index = checkpoint;
+            return None;
+        }
+        let Some(lead) = self.parse_hex_digits(4) else {
+            self.index = checkpoint;
+            return None;
+        };
+        if unicode && (0xD800..=0xDBFF).contains(&lead) {
+            let trail_checkpoint = self.index;
+            if self.eat_str("\\u") {
+                match self.parse_hex_digits(4) {
+                    Some(trail) if (0xDC00..=0xDFFF).contains(&trail) => {
+                        return Some(0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00));
+                    }
+                    _ => self.index = trail_checkpoint,
+                }
+            }
+        }
+        Some(lead)
+    }
+
+    /// Exactly `count` hex digits, or as many as possible for `usize::MAX`
+    fn parse_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let checkpoint = self.index;
+        let mut value: u32 = 0;
+        let mut digits = 0;
+        while digits < count {
+            let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) else { break };
+            self.bump();
+            value = value.saturating_mul(16).saturating_add(digit);
+            digits += 1;
+        }
+        if digits == 0 || (count != usize::MAX && digits != count) {
+            self.index = checkpoint;
+            return None;
+        }
+        Some(value)
+    }
+
+    /// `CharacterClass` :: `[` `^`? `ClassContents` `]`
+    fn parse_character_class(&mut self) -> Result<CharacterClass<'a>> {
+        let start = self.index;
+        self.bump(); // bump `[`
+        let negative = self.eat('^');
+        if self.unicode_sets_mode {
+            return self.parse_class_set_expression(start, negative);
+        }
+
+        let mut body = Vec::new_in(self.allocator);
+        loop {
+            match self.peek() {
+                None => {
+                    return Err(diagnostics::UnterminatedCharacterClass(self.span(start)).into())
+                }
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                _ => {}
+            }
+            let atom_start = self.index;
+            let min = self.parse_class_atom()?;
+            if self.peek() != Some('-') || matches!(self.peek_nth(1), None | Some(']')) {
+                body.push(min);
+                continue;
+            }
+            let dash_start = self.index;
+            self.bump(); // bump `-`
+            let max = self.parse_class_atom()?;
+            match (min, max) {
+                (
+                    CharacterClassContents::Character(min),
+                    CharacterClassContents::Character(max),
+                ) => {
+                    if min.value > max.value {
+                        return Err(diagnostics::RangeOutOfOrder(self.span(atom_start)).into());
+                    }
+                    let range = CharacterClassRange { span: self.span(atom_start), min, max };
+                    body.push(CharacterClassContents::CharacterClassRange(self.alloc(range)));
+                }
+                _ if self.unicode_mode => {
+                    return Err(diagnostics::InvalidCharacterClass(self.span(atom_start)).into());
+                }
+                // Annex B: `[\d-a]` is `\d`, `-` and `a`
+                (min, max) => {
+                    let dash = Character {
+                        span: self.span_at(dash_start, dash_start + 1),
+                        kind: CharacterKind::Symbol,
+                        value: '-' as u32,
+                    };
+                    body.push(min);
+                    body.push(CharacterClassContents::Character(dash));
+                    body.push(max);
+                }
+            }
+        }
+
+        let kind = CharacterClassContentsKind::Union;
+        Ok(CharacterClass { span: self.span(start), negative, kind, body })
+    }
+
+    /// `ClassAtom` :: `-` `ClassAtomNoDash`
+    fn parse_class_atom(&mut self) -> Result<CharacterClassContents<'a>> {
+        let start = self.index;
+        match self.peek() {
+            Some('\\') => {
+                self.bump();
+                self.parse_class_escape(start)
+            }
+            Some(c) => {
+                self.bump();
+                Ok(CharacterClassContents::Character(self.character(
+                    start,
+                    CharacterKind::Symbol,
+                    c,
+                )))
+            }
+            None => Err(diagnostics::UnterminatedCharacterClass(self.span(start)).into()),
+        }
+    }
+
+    /// `ClassEscape` :: `b` `-` `CharacterClassEscape` `CharacterEscape`
+    fn parse_class_escape(&mut self, start: usize) -> Result<CharacterClassContents<'a>> {
+        match self.peek() {
+            Some('b') => {
+                self.bump();
+                let character = Character {
+                    span: self.span(start),
+                    kind: CharacterKind::SingleEscape,
+                    value: 0x08,
+                };
+                Ok(CharacterClassContents::Character(character))
+            }
+            Some('d' | 'D' | 's' | 'S' | 'w' | 'W') => {
+                Ok(CharacterClassContents::CharacterClassEscape(
+                    self.parse_character_class_escape(start),
+                ))
+            }
+            Some('p' | 'P') if self.unicode_mode => {
+                let escape = self.parse_unicode_property_escape(start)?;
+                Ok(CharacterClassContents::UnicodePropertyEscape(self.alloc(escape)))
+            }
+            _ => {
+                let character = self.parse_character_escape(start, /* in_class */ true)?;
+                Ok(CharacterClassContents::Character(character))
+            }
+        }
+    }
+
+    /// `ClassSetExpression` :: `ClassUnion` `ClassIntersection` `ClassSubtraction`, with the `v` flag
+    fn parse_class_set_expression(
+        &mut self,
+        start: usize,
+        negative: bool,
+    ) -> Result<CharacterClass<'a>> {
+        let mut body = Vec::new_in(self.allocator);
+        let mut kind = CharacterClassContentsKind::Union;
+
+        if !self.eat(']') {
+            let first = self.parse_class_set_union_element(start)?;
+            let operator = if self.at_str("&&") {
+                kind = CharacterClassContentsKind::Intersection;
+                "&&"
+            } else if self.at_str("--") {
+                kind = CharacterClassContentsKind::Subtraction;
+                "--"
+            } else {
+                ""
+            };
+
+            if operator.is_empty() {
+                // `ClassUnion` :: (`ClassSetRange` | `ClassSetOperand`)*
+                body.push(first);
+                loop {
+                    if self.eat(']') {
+                        break;
+                    }
+                    if self.at_str("&&") || self.at_str("--") {
+                        return Err(diagnostics::InvalidSetOperation(
+                            self.span_at(self.index, self.index + 2),
+                        )
+                        .into());
+                    }
+                    body.push(self.parse_class_set_union_element(start)?);
+                }
+            } else {
+                // `ClassIntersection` :: `ClassSetOperand` `&&` `ClassSetOperand` ...
+                // `ClassSubtraction` :: `ClassSetOperand` `--` `ClassSetOperand` ...
+                if matches!(first, CharacterClassContents::CharacterClassRange(_)) {
+                    return Err(diagnostics::InvalidSetOperation(self.span(start)).into());
+                }
+                body.push(first);
+                loop {
+                    if self.eat(']') {
+                        break;
+                    }
+                    let operator_start = self.index;
+                    if !self.eat_str(operator) {
+                        return Err(diagnostics::InvalidSetOperation(self.span_of_char()).into());
+                    }
+                    // `&&&` is not an operator followed by `&`
+                    if operator == "&&" && self.peek() == Some('&') {
+                        return Err(diagnostics::InvalidSetOperation(
+                            self.span_at(operator_start, self.index + 1),
+                        )
+                        .into());
+                    }
+                    body.push(self.parse_class_set_operand(start)?);
+                }
+            }
+        }
+
+        let class = CharacterClass { span: self.span(start), negative, kind, body };
+        if negative && may_contain_strings(&class) {
+            return Err(diagnostics::NegatedClassWithStrings(class.span).into());
+        }
+        Ok(class)
+    }
+
+    /// `ClassSetRange` | `ClassSetOperand`
+    fn parse_class_set_union_element(
+        &mut self,
+        class_start: usize,
+    ) -> Result<CharacterClassContents<'a>> {
+        let start = self.index;
+        let operand = self.parse_class_set_operand(class_start)?;
+        let CharacterClassContents::Character(min) = operand else { return Ok(operand) };
+        if self.peek() != Some('-') || self.at_str("--") {
+            return Ok(operand);
+        }
+        self.bump(); // bump `-`
+        let CharacterClassContents::Character(max) = self.parse_class_set_operand(class_start)?
+        else {
+            return Err(diagnostics::InvalidCharacterClass(self.span(start)).into());
+        };
+        if min.value > max.value {
+            return Err(diagnostics::RangeOutOfOrder(self.span(start)).into());
+        }
+        let range = CharacterClassRange { span: self.span(start), min, max };
+        Ok(CharacterClassContents::CharacterClassRange(self.alloc(range)))
+    }
+
+    /// `ClassSetOperand` :: `NestedClass` `ClassStringDisjunction` `ClassSetCharacter`
+    fn parse_class_set_operand(
+        &mut self,
+        class_start: usize,
+    ) -> Result<CharacterClassContents<'a>> {
+        let start = self.index;
+        match self.peek() {
+            Some('[') => {
+                let class = self.parse_character_class()?;
+                Ok(CharacterClassContents::NestedCharacterClass(self.alloc(class)))
+            }
+            Some('\\') => {
+                self.bump();
+                match self.peek() {
+                    Some('q') if self.peek_nth(1) == Some('{') => {
+                        let disjunction =
+                            self.parse_class_string_disjunction(start, class_start)?;
+                        Ok(CharacterClassContents::ClassStringDisjunction(self.alloc(disjunction)))
+                    }
+                    Some('d' | 'D' | 's' | 'S' | 'w' | 'W' | 'p' | 'P') => {
+                        self.parse_class_escape(start)
+                    }
+                    _ => {
+                        self.index = start;
+                        Ok(CharacterClassContents::Character(
+                            self.parse_class_set_character(class_start)?,
+                        ))
+                    }
+                }
+            }
+            _ => {
+                Ok(CharacterClassContents::Character(self.parse_class_set_character(class_start)?))
+            }
+        }
+    }
+
+    /// `ClassStringDisjunction` :: `\q{` `ClassString` (`|` `ClassString`)* `}`
+    fn parse_class_string_disjunction(
+        &mut self,
+        start: usize,
+        class_start: usize,
+    ) -> Result<ClassStringDisjunction<'a>> {
+        self.bump(); // bump `q`
+        self.bump(); // bump `{`
+        let mut body = Vec::new_in(self.allocator);
+        let mut strings = false;
+        loop {
+            let string_start = self.index;
+            let mut characters = Vec::new_in(self.allocator);
+            while !matches!(self.peek(), None | Some('|' | '}')) {
+                characters.push(self.parse_class_set_character(class_start)?);
+            }
+            strings |= characters.len() != 1;
+            body.push(ClassString { span: self.span(string_start), body: characters });
+            if self.eat('}') {
+                break;
+            }
+            if !self.eat('|') {
+                return Err(diagnostics::UnterminatedCharacterClass(self.span(class_start)).into());
+            }
+        }
+        Ok(ClassStringDisjunction { span: self.span(start), strings, body })
+    }
+
+    /// `ClassSetCharacter` ::
+    ///   `SourceCharacter` but not `ClassSetSyntaxCharacter` or `ClassSetReservedDoublePunctuator`
+    ///   `\` `CharacterEscape` | `\` `ClassSetReservedPunctuator` | `\b`
+    fn parse_class_set_character(&mut self, class_start: usize) -> Result<Character> {
+        let start = self.index;
+        match self.peek() {
+            None => Err(diagnostics::UnterminatedCharacterClass(self.span(class_start)).into()),
+            Some('\\') => {
+                self.bump();
+                match self.peek() {
+                    Some('b') => {
+                        self.bump();
+                        Ok(Character {
+                            span: self.span(start),
+                            kind: CharacterKind::SingleEscape,
+                            value: 0x08,
+                        })
+                    }
+                    Some(c) if is_class_set_reserved_punctuator(c) => {
+                        self.bump();
+                        Ok(self.character(start, CharacterKind::Identifier, c))
+                    }
+                    _ => self.parse_character_escape(start, /* in_class */ true),
+                }
+            }
+            Some(c) => {
+                if is_class_set_syntax_character(c) {
+                    return Err(diagnostics::InvalidClassSetCharacter(self.span_of_char()).into());
+                }
+                if self.peek_nth(1) == Some(c) && is_class_set_reserved_double_punctuator(c) {
+                    return Err(diagnostics::InvalidClassSetCharacter(
+                        self.span_at(start, start + 2),
+                    )
+                    .into());
+                }
+                self.bump();
+                Ok(self.character(start, CharacterKind::Symbol, c))
+            }
+        }
+    }
+
+    fn alloc<T>(&self, value: T) -> Box<'a, T> {
+        Box(self.allocator.alloc(value))
+    }
+
+    fn character(&self, start: usize, kind: CharacterKind, c: char) -> Character {
+        Character { span: self.span(start), kind, value: c as u32 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.index..].chars().next()
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.source[self.index..].chars().nth(n)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.index += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.index += c.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    fn at_str(&self, s: &str) -> bool {
+        self.source[self.index..].starts_with(s)
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.at_str(s) {
+            self.index += s.len();
+            return true;
+        }
+        false
+    }
+
+    /// Span from `start` to the current position
+    fn span(&self, start: usize) -> Span {
+        self.span_at(start, self.index)
+    }
+
+    /// Span of the current character
+    fn span_of_char(&self) -> Span {
+        let len = self.peek().map_or(0, char::len_utf8);
+        self.span_at(self.index, self.index + len)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn span_at(&self, start: usize, end: usize) -> Span {
+        Span::new(self.span_offset + start as u32, self.span_offset + end as u32)
+    }
+}
+
+fn is_lookahead(term: &Term) -> bool {
+    matches!(term, Term::LookAroundAssertion(assertion) if matches!(
+        assertion.kind,
+        LookAroundAssertionKind::Lookahead | LookAroundAssertionKind::NegativeLookahead
+    ))
+}
+
+/// Whether the contents of a character class of the `v` flag may match a string of more than one character
+fn may_contain_strings(class: &CharacterClass) -> bool {
+    let contains_strings = |contents: &CharacterClassContents| match contents {
+        CharacterClassContents::UnicodePropertyEscape(escape) => escape.strings,
+        // A negated class with strings is an error
+        CharacterClassContents::NestedCharacterClass(class) => {
+            !class.negative && may_contain_strings(class)
+        }
+        CharacterClassContents::ClassStringDisjunction(disjunction) => disjunction.strings,
+        CharacterClassContents::CharacterClassRange(_)
+        | CharacterClassContents::CharacterClassEscape(_)
+        | CharacterClassContents::Character(_) => false,
+    };
+    match class.kind {
+        CharacterClassContentsKind::Union => class.body.iter().any(contains_strings),
+        CharacterClassContentsKind::Intersection => class.body.iter().all(contains_strings),
+        CharacterClassContentsKind::Subtraction => {
+            class.body.first().map_or(false, contains_strings)
+        }
+    }
+}
+
+/// `SyntaxCharacter` :: one of `^ $ \ . * + ? ( ) [ ] { } |`
+const fn is_syntax_character(c: char) -> bool {
+    matches!(c, '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|')
+}
+
+/// `ClassSetSyntaxCharacter` :: one of `( ) [ ] { } / - \ |`
+const fn is_class_set_syntax_character(c: char) -> bool {
+    matches!(c, '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|')
+}
+
+/// `ClassSetReservedPunctuator` :: one of ``& - ! # % , : ; < = > @ ` ~``
+const fn is_class_set_reserved_punctuator(c: char) -> bool {
+    matches!(c, '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~')
+}
+
+/// `ClassSetReservedDoublePunctuator` :: one of these characters twice
+fn is_class_set_reserved_double_punctuator(c: char) -> bool {
+    "&!#$%*+,.:;<=>?@^`~".contains(c)
+}
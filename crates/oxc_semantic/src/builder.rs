@@ -1,18 +1,34 @@
 //! Semantic Builder
 //! This builds:
 //!   * The untyped and flattened ast nodes into an indextree
+//!   * The scope tree and the symbol table, with every reference resolved to its binding
 
 use std::rc::Rc;
 
-use oxc_ast::{ast::Program, visit::Visit, AstKind, SourceType, Trivias};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*, syntax_directed_operations::BoundNames, visit::Visit, AstKind, Atom, SourceType, Span,
+    Trivias,
+};
+use oxc_diagnostics::Error;
+use rustc_hash::FxHashSet;
 
 use crate::{
+    diagnostics,
     node::{AstNodeId, AstNodes, NodeFlags, SemanticNode},
-    scope::ScopeBuilder,
+    scope::{Scope, ScopeBuilder, ScopeId},
+    symbol::{Reference, Symbol, SymbolFlags, SymbolId, SymbolTable},
     Semantic,
 };
 
+pub struct SemanticBuilderReturn<'a> {
+    pub semantic: Semantic<'a>,
+    pub errors: Vec<Error>,
+}
+
 pub struct SemanticBuilder<'a> {
+    source_type: SourceType,
+
     // states
     current_node_id: AstNodeId,
     current_node_flags: NodeFlags,
@@ -20,6 +36,20 @@ pub struct SemanticBuilder<'a> {
     // builders
     nodes: AstNodes<'a>,
     scope: ScopeBuilder,
+    symbols: SymbolTable,
+
+    /// Names exported by `export { a }` and `export var a`, marked after all bindings are known
+    exported_names: Vec<Atom>,
+
+    /// Plain function declarations in blocks of sloppy mode code, with the scope of their block.
+    /// They are also bound in the enclosing function once all bindings are known,
+    /// see [`SemanticBuilder::hoist_block_functions`].
+    block_functions: Vec<(&'a BindingIdentifier, ScopeId)>,
+
+    /// Names of function expressions, the declarations of their body shadow them
+    function_expression_names: FxHashSet<SymbolId>,
+
+    errors: Vec<Error>,
 }
 
 impl<'a> SemanticBuilder<'a> {
@@ -30,17 +60,49 @@ impl<'a> SemanticBuilder<'a> {
         let semantic_node =
             SemanticNode::new(AstKind::Root, scope.current_scope_id, NodeFlags::empty());
         let current_node_id = nodes.new_node(semantic_node).into();
-        Self { current_node_id, nodes, scope, current_node_flags: NodeFlags::empty() }
+        Self {
+            source_type,
+            current_node_id,
+            nodes,
+            scope,
+            symbols: SymbolTable::default(),
+            exported_names: vec![],
+            block_functions: vec![],
+            function_expression_names: FxHashSet::default(),
+            errors: vec![],
+            current_node_flags: NodeFlags::empty(),
+        }
     }
 
     #[must_use]
-    pub fn build(mut self, program: &'a Program<'a>, trivias: Rc<Trivias>) -> Semantic<'a> {
+    pub fn build(
+        mut self,
+        program: &'a Program<'a>,
+        trivias: Rc<Trivias>,
+    ) -> SemanticBuilderReturn<'a> {
+        if program.directives.iter().any(Directive::is_use_strict) {
+            let root_scope_id = self.scope.scopes.root_scope_id();
+            self.scope.scopes[root_scope_id].strict_mode = true;
+        }
         // AST pass
         self.visit_program(program);
-        Semantic { nodes: self.nodes, trivias }
+        self.hoist_block_functions();
+        self.resolve_references();
+        self.mark_exports();
+        let semantic = Semantic {
+            source_type: self.source_type,
+            nodes: self.nodes,
+            scopes: self.scope.scopes,
+            symbols: self.symbols,
+            trivias,
+        };
+        SemanticBuilderReturn { semantic, errors: self.errors }
     }
 
     fn create_ast_node(&mut self, kind: AstKind<'a>) {
+        if matches!(kind, AstKind::Class(_)) {
+            self.current_node_flags |= NodeFlags::Class;
+        }
         let ast_node =
             SemanticNode::new(kind, self.scope.current_scope_id, self.current_node_flags);
         let node_id = self.nodes.new_node(ast_node);
@@ -51,11 +113,26 @@ impl<'a> SemanticBuilder<'a> {
     fn pop_ast_node(&mut self) {
         self.current_node_id =
             self.nodes[self.current_node_id.indextree_id()].parent().unwrap().into();
+        if self.current_node_flags.contains(NodeFlags::Class)
+            && !self.nodes[self.current_node_id].in_class()
+        {
+            self.current_node_flags -= NodeFlags::Class;
+        }
     }
 
     fn try_enter_scope(&mut self, kind: AstKind<'a>) {
         if let Some(flags) = ScopeBuilder::scope_flags_from_ast_kind(kind) {
-            self.scope.enter(flags);
+            let directives = match kind {
+                AstKind::Function(func) => func.body.as_ref().map(|body| &body.directives),
+                AstKind::ArrowExpression(expr) => Some(&expr.body.directives),
+                _ => None,
+            };
+            // Class code is always strict mode code
+            let strict_mode = matches!(kind, AstKind::Class(_))
+                || self.current_node_flags.contains(NodeFlags::Class)
+                || directives
+                    .is_some_and(|directives| directives.iter().any(Directive::is_use_strict));
+            self.scope.enter(flags, strict_mode);
         }
     }
 
@@ -66,21 +143,343 @@ impl<'a> SemanticBuilder<'a> {
             self.scope.leave();
         }
     }
+
+    /// Declare the bindings introduced by `kind`, and record the identifiers it references.
+    fn bind(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::VariableDeclarator(decl) => {
+                let (scope_id, flags) = if decl.kind == VariableDeclarationKind::Var {
+                    (self.scope.current_var_scope_id(), SymbolFlags::FunctionScopedVariable)
+                } else {
+                    (self.scope.current_scope_id, SymbolFlags::BlockScopedVariable)
+                };
+                for ident in decl.id.bound_names() {
+                    self.declare(ident, scope_id, flags);
+                }
+            }
+            AstKind::FormalParameters(params) => {
+                let scope_id = self.scope.current_scope_id;
+                for ident in params.bound_names() {
+                    self.declare(ident, scope_id, SymbolFlags::FunctionScopedVariable);
+                }
+            }
+            // The name of a function expression is only visible inside of it
+            AstKind::Function(func) if func.is_expression() => {
+                if let Some(ident) = &func.id {
+                    let symbol_id =
+                        self.declare(ident, self.scope.current_scope_id, SymbolFlags::Function);
+                    self.function_expression_names.insert(symbol_id);
+                }
+            }
+            // The name of a class expression is only visible inside of it
+            AstKind::Class(class) if class.is_expression() => {
+                if let Some(ident) = &class.id {
+                    self.declare(ident, self.scope.current_scope_id, SymbolFlags::Class);
+                }
+            }
+            AstKind::CatchClause(clause) => {
+                for ident in clause.param.bound_names() {
+                    self.declare(ident, self.scope.current_scope_id, SymbolFlags::CatchVariable);
+                }
+            }
+            AstKind::ModuleDeclaration(decl) => self.bind_module_declaration(decl),
+            AstKind::IdentifierReference(ident) => self.reference(ident.span, &ident.name),
+            AstKind::JSXElementName(name) => self.reference_jsx_element_name(name),
+            AstKind::CallExpression(call) if call.callee.is_specific_id("eval") => {
+                self.scope.scopes[self.scope.current_scope_id].has_direct_eval = true;
+            }
+            AstKind::WithStatement(_) => {
+                self.scope.scopes[self.scope.current_scope_id].has_with_statement = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Function declarations are bound in the enclosing scope, before entering the function scope.
+    fn bind_function_declaration(&mut self, func: &'a Function<'a>) {
+        let Some(ident) = &func.id else { return };
+        self.declare(ident, self.scope.current_scope_id, SymbolFlags::Function);
+        let scope = self.scope.current_scope();
+        if !scope.is_var() && !scope.strict_mode() && !func.r#async && !func.generator {
+            self.block_functions.push((ident, self.scope.current_scope_id));
+        }
+    }
+
+    /// In sloppy mode, a function declared in a block is also bound in the enclosing function,
+    /// unless a `var` declaration of its name would be an early error there.
+    /// See [Block-Level Function Declarations Web Legacy Compatibility Semantics](https://tc39.es/ecma262/#sec-block-level-function-declarations-web-legacy-compatibility-semantics)
+    fn hoist_block_functions(&mut self) {
+        let block_functions = std::mem::take(&mut self.block_functions);
+        for (ident, block_scope_id) in block_functions {
+            let scopes = &self.scope.scopes;
+            let mut var_scope_id = None;
+            for scope_id in scopes.ancestors(block_scope_id).skip(1).map(ScopeId::from) {
+                let scope = &scopes[scope_id];
+                let flags = scope.get_binding(&ident.name).map(|id| self.symbols[id].flags);
+                if scope.is_var() {
+                    // `var` and function declarations of the same name are allowed
+                    let lexical = SymbolFlags::BlockScopedVariable | SymbolFlags::Class;
+                    if !flags.is_some_and(|flags| flags.intersects(lexical)) {
+                        var_scope_id = Some(scope_id);
+                    }
+                    break;
+                }
+                // Catch parameters can be redeclared by `var`,
+                // see [VariableStatements in Catch Blocks](https://tc39.es/ecma262/#sec-variablestatements-in-catch-blocks)
+                if flags.is_some_and(|flags| !flags.contains(SymbolFlags::CatchVariable)) {
+                    break;
+                }
+            }
+            let Some(var_scope_id) = var_scope_id else { continue };
+            // The block and the enclosing function share one symbol
+            let symbol_id = self.scope.scopes[block_scope_id].get_binding(&ident.name).unwrap();
+            match self.scope.scopes[var_scope_id].get_binding(&ident.name) {
+                Some(var_symbol_id) if var_symbol_id == symbol_id => {}
+                // Merged into the `var` or function of the same name, the block symbol is unused
+                Some(var_symbol_id) => {
+                    let declarations =
+                        std::mem::take(&mut self.symbols.symbol_mut(symbol_id).declarations);
+                    let var_symbol = self.symbols.symbol_mut(var_symbol_id);
+                    var_symbol.flags |= SymbolFlags::Function;
+                    var_symbol.declarations.extend(declarations);
+                    self.scope.scopes[block_scope_id]
+                        .bindings
+                        .insert(ident.name.clone(), var_symbol_id);
+                }
+                None => {
+                    self.symbols.symbol_mut(symbol_id).scope_id = var_scope_id;
+                    self.scope.scopes[var_scope_id].bindings.insert(ident.name.clone(), symbol_id);
+                }
+            }
+        }
+    }
+
+    fn bind_module_declaration(&mut self, decl: &'a ModuleDeclaration<'a>) {
+        let root_scope_id = self.scope.scopes.root_scope_id();
+        match &decl.kind {
+            ModuleDeclarationKind::ImportDeclaration(decl) => {
+                for ident in decl.bound_names() {
+                    self.declare(ident, root_scope_id, SymbolFlags::Import);
+                }
+            }
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) if decl.source.is_none() => {
+                for ident in decl.bound_names() {
+                    self.exported_names.push(ident.name.clone());
+                }
+                for specifier in &decl.specifiers {
+                    if let ModuleExportName::Identifier(ident) = &specifier.local {
+                        self.reference(ident.span, &ident.name);
+                        self.exported_names.push(ident.name.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `<Foo />` and `<foo.bar />` refer to bindings, `<div />` is an intrinsic element.
+    fn reference_jsx_element_name(&mut self, name: &JSXElementName) {
+        match name {
+            JSXElementName::Identifier(ident) => {
+                if !ident.name.starts_with(|c: char| c.is_ascii_lowercase())
+                    && !ident.name.contains('-')
+                {
+                    self.reference(ident.span, &ident.name);
+                }
+            }
+            JSXElementName::MemberExpression(expr) => {
+                let mut object = &expr.object;
+                while let JSXMemberExpressionObject::MemberExpression(expr) = object {
+                    object = &expr.object;
+                }
+                if let JSXMemberExpressionObject::Identifier(ident) = object {
+                    self.reference(ident.span, &ident.name);
+                }
+            }
+            JSXElementName::NamespacedName(_) => {}
+        }
+    }
+
+    fn declare(
+        &mut self,
+        ident: &BindingIdentifier,
+        scope_id: ScopeId,
+        flags: SymbolFlags,
+    ) -> SymbolId {
+        let scope = &self.scope.scopes[scope_id];
+        if let Some(symbol_id) = scope.get_binding(&ident.name) {
+            let shadowed = self.function_expression_names.contains(&symbol_id)
+                && Self::is_lexical(flags, scope);
+            if !shadowed {
+                let symbol = &self.symbols[symbol_id];
+                if self.is_redeclaration(symbol.flags, flags, scope) {
+                    let error =
+                        diagnostics::Redeclaration(ident.name.clone(), symbol.span, ident.span);
+                    self.errors.push(error.into());
+                }
+                let symbol = self.symbols.symbol_mut(symbol_id);
+                symbol.flags |= flags;
+                symbol.declarations.push(ident.span);
+                return symbol_id;
+            }
+        }
+        let symbol = Symbol::new(ident.name.clone(), ident.span, scope_id, flags);
+        let symbol_id = self.symbols.add_symbol(symbol);
+        self.scope.scopes[scope_id].bindings.insert(ident.name.clone(), symbol_id);
+        symbol_id
+    }
+
+    /// `let`, `const`, classes, imports and the functions of a block are lexical declarations,
+    /// see [LexicallyDeclaredNames](https://tc39.es/ecma262/#sec-static-semantics-lexicallydeclarednames)
+    fn is_lexical(flags: SymbolFlags, scope: &Scope) -> bool {
+        let lexical = SymbolFlags::BlockScopedVariable | SymbolFlags::Class | SymbolFlags::Import;
+        flags.intersects(lexical) || (flags.contains(SymbolFlags::Function) && !scope.is_var())
+    }
+
+    /// A lexical declaration can not share its name with another declaration of its scope.
+    fn is_redeclaration(&self, existing: SymbolFlags, flags: SymbolFlags, scope: &Scope) -> bool {
+        if !Self::is_lexical(existing, scope) && !Self::is_lexical(flags, scope) {
+            return false;
+        }
+        // Repeated block functions are allowed in sloppy mode, and are overloads in TypeScript.
+        // See [Changes to Block Static Semantics: Early Errors](https://tc39.es/ecma262/#sec-block-duplicates-allowed-static-semantics)
+        let functions = existing == SymbolFlags::Function && flags == SymbolFlags::Function;
+        !(functions && (!scope.strict_mode() || self.source_type.is_typescript()))
+    }
+
+    fn reference(&mut self, span: Span, name: &Atom) {
+        let reference = Reference::new(span, name.clone(), self.scope.current_scope_id);
+        self.symbols.add_reference(reference);
+    }
+
+    /// References are resolved once all scopes are complete, because bindings are hoisted.
+    fn resolve_references(&mut self) {
+        let scopes = &self.scope.scopes;
+        self.symbols
+            .resolve_references(|reference| scopes.resolve(reference.scope_id, &reference.name));
+    }
+
+    fn mark_exports(&mut self) {
+        let root_scope = &self.scope.scopes[self.scope.scopes.root_scope_id()];
+        for name in &self.exported_names {
+            if let Some(symbol_id) = root_scope.get_binding(name) {
+                self.symbols.symbol_mut(symbol_id).flags |= SymbolFlags::Export;
+            }
+        }
+    }
 }
 
 impl<'a> Visit<'a> for SemanticBuilder<'a> {
     // Setup all the context for the binder,
     // the order is important here.
     fn enter_node(&mut self, kind: AstKind<'a>) {
+        match kind {
+            AstKind::Function(func) if func.is_declaration() => {
+                self.bind_function_declaration(func);
+            }
+            AstKind::Class(class) if class.is_declaration() => {
+                if let Some(ident) = &class.id {
+                    self.declare(ident, self.scope.current_scope_id, SymbolFlags::Class);
+                }
+            }
+            _ => {}
+        }
+
         // create new self.scope.current_scope_id
         self.try_enter_scope(kind);
 
         // create new self.current_node_id
         self.create_ast_node(kind);
+
+        self.bind(kind);
     }
 
     fn leave_node(&mut self, kind: AstKind<'a>) {
         self.pop_ast_node();
         self.try_leave_scope(kind);
     }
+
+    fn visit_jsx_element(&mut self, elem: &'a JSXElement<'a>) {
+        self.visit_jsx_opening_element(&elem.opening_element);
+        for child in &elem.children {
+            self.visit_jsx_child(child);
+        }
+        // The closing tag is not visited as an AST node, but it names the same binding
+        if let Some(closing_element) = &elem.closing_element {
+            self.reference_jsx_element_name(&closing_element.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::SourceType;
+    use oxc_parser::Parser;
+
+    use super::SemanticBuilder;
+
+    fn errors(source: &str) -> Vec<String> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source}: {:?}", ret.errors);
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_type).build(program, Rc::new(ret.trivias));
+        ret.errors.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn redeclarations() {
+        for source in [
+            "let f; let f;",
+            "let f; var f;",
+            "class f {} function f() {}",
+            "function g(f) { let f; }",
+            "{ function f() {} let f; }",
+            "{ let f; function f() {} }",
+            "{ function f() {} class f {} }",
+            "'use strict'; { function f() {} function f() {} }",
+        ] {
+            assert_eq!(
+                errors(source),
+                ["Identifier `\"f\"` has already been declared"],
+                "{source}"
+            );
+        }
+
+        for source in [
+            "var f; var f; function f() {}",
+            "function g(f) { var f; function f() {} }",
+            "{ function f() {} function f() {} }",
+            "let f; { function f() {} }",
+            "var f; { function f() {} } { function f() {} }",
+            "(function f() { let f; })",
+            "try {} catch (f) { var f; }",
+        ] {
+            assert!(errors(source).is_empty(), "{source}");
+        }
+    }
+
+    /// A sloppy mode block function is bound in its block and in the enclosing function
+    #[test]
+    fn block_functions() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "var f; { function f() {} f; } f; { let g; { function g() {} } } g;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let ret = SemanticBuilder::new(source_type).build(program, Rc::new(ret.trivias));
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        let symbols = ret.semantic.symbols();
+        let references = symbols.references().collect::<Vec<_>>();
+        assert_eq!(references.len(), 3);
+        let f = references[0].symbol_id().unwrap();
+        assert_eq!(references[1].symbol_id(), Some(f));
+        assert_eq!(symbols[f].declarations().len(), 2);
+        // `var g` would be an early error in the outer block, so `g` stays in its block
+        assert_eq!(references[2].symbol_id(), None);
+    }
 }
@@ -1,26 +1,52 @@
+#![feature(is_some_and)]
+
 mod builder;
+mod diagnostics;
 mod node;
 mod scope;
+mod symbol;
 
 use std::rc::Rc;
 
-pub use builder::SemanticBuilder;
+pub use builder::{SemanticBuilder, SemanticBuilderReturn};
 pub use node::{AstNode, AstNodes};
-use oxc_ast::Trivias;
-pub use scope::{Scope, ScopeTree};
+use oxc_ast::{SourceType, Trivias};
+pub use scope::{Scope, ScopeFlags, ScopeId, ScopeTree};
+pub use symbol::{Reference, ReferenceId, Symbol, SymbolFlags, SymbolId, SymbolTable};
 
 pub struct Semantic<'a> {
+    source_type: SourceType,
+
     nodes: AstNodes<'a>,
 
+    scopes: ScopeTree,
+
+    symbols: SymbolTable,
+
     trivias: Rc<Trivias>,
 }
 
 impl<'a> Semantic<'a> {
+    #[must_use]
+    pub fn source_type(&self) -> &SourceType {
+        &self.source_type
+    }
+
     #[must_use]
     pub fn nodes(&self) -> &AstNodes<'a> {
         &self.nodes
     }
 
+    #[must_use]
+    pub fn scopes(&self) -> &ScopeTree {
+        &self.scopes
+    }
+
+    #[must_use]
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
     #[must_use]
     pub fn trivias(&self) -> &Trivias {
         &self.trivias
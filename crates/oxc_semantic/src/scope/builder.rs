@@ -19,14 +19,12 @@ impl ScopeBuilder {
         Self { scopes, current_scope_id }
     }
 
-    pub fn enter(&mut self, flags: ScopeFlags) {
-        // Inherit strict mode for functions
-        // https://tc39.es/ecma262/#sec-strict-mode-code
-        let mut strict_mode = self.scopes[self.scopes.root_scope_id()].strict_mode;
+    /// `strict_mode` is set for a function with a "use strict" directive or inside of a class,
+    /// every other scope inherits strict mode from its parent.
+    /// See [Strict Mode Code](https://tc39.es/ecma262/#sec-strict-mode-code)
+    pub fn enter(&mut self, flags: ScopeFlags, strict_mode: bool) {
         let parent_scope = self.current_scope();
-        if !strict_mode && parent_scope.is_function() && parent_scope.strict_mode {
-            strict_mode = true;
-        }
+        let strict_mode = strict_mode || parent_scope.strict_mode;
 
         // inherit flags for non-function scopes
         let flags = if flags.contains(ScopeFlags::Function) {
@@ -52,6 +50,16 @@ impl ScopeBuilder {
         &self.scopes[self.current_scope_id]
     }
 
+    /// The closest function, class static block or the program scope,
+    /// which `var` declarations are hoisted to.
+    #[must_use]
+    pub fn current_var_scope_id(&self) -> ScopeId {
+        self.scopes
+            .ancestors(self.current_scope_id)
+            .find(|id| self.scopes[*id].get().is_var())
+            .map_or_else(|| self.scopes.root_scope_id(), ScopeId::from)
+    }
+
     #[must_use]
     pub fn scope_flags_from_ast_kind(kind: AstKind) -> Option<ScopeFlags> {
         match kind {
@@ -60,6 +68,7 @@ impl ScopeBuilder {
             AstKind::StaticBlock(_) => Some(ScopeFlags::ClassStaticBlock),
             AstKind::TSModuleBlock(_) => Some(ScopeFlags::TsModuleBlock),
             AstKind::BlockStatement(_)
+            | AstKind::Class(_)
             | AstKind::CatchClause(_)
             | AstKind::ForStatement(_)
             | AstKind::ForInStatement(_)
@@ -2,7 +2,7 @@ use std::ops::Deref;
 
 use indextree::NodeId;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ScopeId(NodeId);
 
 impl ScopeId {
@@ -9,9 +9,12 @@ mod tree;
 
 use bitflags::bitflags;
 pub use builder::*;
+use oxc_ast::Atom;
+use rustc_hash::FxHashMap;
 pub use tree::ScopeTree;
 
 pub use self::id::ScopeId;
+use crate::symbol::SymbolId;
 
 #[derive(Debug, Clone)]
 pub struct Scope {
@@ -20,6 +23,15 @@ pub struct Scope {
     pub(crate) strict_mode: bool,
 
     pub flags: ScopeFlags,
+
+    /// Bindings declared in this scope
+    pub(crate) bindings: FxHashMap<Atom, SymbolId>,
+
+    /// Contains a direct `eval(...)` call, which can access every binding in scope by name
+    pub(crate) has_direct_eval: bool,
+
+    /// Contains a `with` statement, which can shadow every binding in scope by name
+    pub(crate) has_with_statement: bool,
 }
 
 bitflags! {
@@ -41,7 +53,33 @@ bitflags! {
 impl Scope {
     #[must_use]
     pub fn new(flags: ScopeFlags, strict_mode: bool) -> Self {
-        Self { strict_mode, flags }
+        Self {
+            strict_mode,
+            flags,
+            bindings: FxHashMap::default(),
+            has_direct_eval: false,
+            has_with_statement: false,
+        }
+    }
+
+    #[must_use]
+    pub fn bindings(&self) -> &FxHashMap<Atom, SymbolId> {
+        &self.bindings
+    }
+
+    #[must_use]
+    pub fn get_binding(&self, name: &Atom) -> Option<SymbolId> {
+        self.bindings.get(name).copied()
+    }
+
+    #[must_use]
+    pub fn has_direct_eval(&self) -> bool {
+        self.has_direct_eval
+    }
+
+    #[must_use]
+    pub fn has_with_statement(&self) -> bool {
+        self.has_with_statement
     }
 
     #[must_use]
@@ -49,6 +87,11 @@ impl Scope {
         self.strict_mode
     }
 
+    #[must_use]
+    pub fn is_var(&self) -> bool {
+        self.flags.intersects(ScopeFlags::VAR)
+    }
+
     #[must_use]
     pub fn is_top(&self) -> bool {
         self.flags.intersects(ScopeFlags::Top)
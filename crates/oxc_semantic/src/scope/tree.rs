@@ -1,9 +1,10 @@
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
-use indextree::{Ancestors, Arena, Node, NodeId};
+use indextree::{Ancestors, Arena, Children, Node, NodeId};
+use oxc_ast::Atom;
 
 use super::{Scope, ScopeFlags, ScopeId};
-use crate::node::AstNode;
+use crate::{node::AstNode, symbol::SymbolId};
 
 #[derive(Debug)]
 pub struct ScopeTree {
@@ -31,6 +32,17 @@ impl ScopeTree {
         scope_id.ancestors(&self.scopes)
     }
 
+    #[must_use]
+    pub fn children(&self, scope_id: ScopeId) -> Children<'_, Scope> {
+        scope_id.children(&self.scopes)
+    }
+
+    /// Find the binding `name` refers to in `scope_id`, searching the enclosing scopes outwards.
+    #[must_use]
+    pub fn resolve(&self, scope_id: ScopeId, name: &Atom) -> Option<SymbolId> {
+        self.ancestors(scope_id).find_map(|id| self.scopes[id].get().get_binding(name))
+    }
+
     #[must_use]
     pub fn node_scope(&self, node: &AstNode) -> &Scope {
         self.scopes[node.get().scope_id().indextree_id()].get()
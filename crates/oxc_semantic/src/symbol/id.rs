@@ -0,0 +1,31 @@
+/// Index of a [`Symbol`](super::Symbol) in the [`SymbolTable`](super::SymbolTable)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolId(usize);
+
+impl SymbolId {
+    #[must_use]
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Index of a [`Reference`](super::Reference) in the [`SymbolTable`](super::SymbolTable)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReferenceId(usize);
+
+impl ReferenceId {
+    #[must_use]
+    pub fn new(index: usize) -> Self {
+        Self(index)
+    }
+
+    #[must_use]
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
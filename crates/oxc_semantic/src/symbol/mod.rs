@@ -0,0 +1,106 @@
+//! ECMAScript Symbol Table
+//! A symbol is created for every binding declared by the program,
+//! see [Declarations](https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement)
+#![allow(non_upper_case_globals)]
+
+mod id;
+mod reference;
+mod table;
+
+use bitflags::bitflags;
+use oxc_ast::{Atom, Span};
+
+pub use self::{
+    id::{ReferenceId, SymbolId},
+    reference::Reference,
+    table::SymbolTable,
+};
+use crate::scope::ScopeId;
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    /// Name of the binding
+    pub(crate) name: Atom,
+
+    /// Span of the first declaration
+    pub(crate) span: Span,
+
+    /// Scope the binding is declared in,
+    /// the enclosing function or program for `var` declarations
+    pub(crate) scope_id: ScopeId,
+
+    pub(crate) flags: SymbolFlags,
+
+    /// Spans of every declaration, redeclarations by `var` and functions are allowed
+    pub(crate) declarations: Vec<Span>,
+
+    pub(crate) references: Vec<ReferenceId>,
+}
+
+bitflags! {
+    #[derive(Default)]
+    pub struct SymbolFlags: u16 {
+        /// `var` declarations and parameters
+        const FunctionScopedVariable = 1 << 0;
+        /// `let` and `const` declarations
+        const BlockScopedVariable    = 1 << 1;
+        const Function               = 1 << 2;
+        const Class                  = 1 << 3;
+        const CatchVariable          = 1 << 4;
+        const Import                 = 1 << 5;
+        /// Exported by `export var a`, `export function a() {}` or `export { a }`
+        const Export                 = 1 << 6;
+    }
+}
+
+impl Symbol {
+    #[must_use]
+    pub fn new(name: Atom, span: Span, scope_id: ScopeId, flags: SymbolFlags) -> Self {
+        Self { name, span, scope_id, flags, declarations: vec![span], references: vec![] }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &Atom {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    #[must_use]
+    pub fn scope_id(&self) -> ScopeId {
+        self.scope_id
+    }
+
+    #[must_use]
+    pub fn flags(&self) -> SymbolFlags {
+        self.flags
+    }
+
+    #[must_use]
+    pub fn declarations(&self) -> &[Span] {
+        &self.declarations
+    }
+
+    #[must_use]
+    pub fn references(&self) -> &[ReferenceId] {
+        &self.references
+    }
+
+    #[must_use]
+    pub fn is_function(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Function)
+    }
+
+    #[must_use]
+    pub fn is_class(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Class)
+    }
+
+    #[must_use]
+    pub fn is_export(&self) -> bool {
+        self.flags.intersects(SymbolFlags::Export)
+    }
+}
@@ -0,0 +1,50 @@
+use oxc_ast::{Atom, Span};
+
+use super::SymbolId;
+use crate::scope::ScopeId;
+
+/// An identifier read or written by the program, e.g. `a` in `a = b`
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub(crate) span: Span,
+
+    pub(crate) name: Atom,
+
+    /// Scope the identifier appears in
+    pub(crate) scope_id: ScopeId,
+
+    /// `None` for references to globals
+    pub(crate) symbol_id: Option<SymbolId>,
+}
+
+impl Reference {
+    #[must_use]
+    pub fn new(span: Span, name: Atom, scope_id: ScopeId) -> Self {
+        Self { span, name, scope_id, symbol_id: None }
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &Atom {
+        &self.name
+    }
+
+    #[must_use]
+    pub fn scope_id(&self) -> ScopeId {
+        self.scope_id
+    }
+
+    #[must_use]
+    pub fn symbol_id(&self) -> Option<SymbolId> {
+        self.symbol_id
+    }
+
+    #[must_use]
+    pub fn is_global(&self) -> bool {
+        self.symbol_id.is_none()
+    }
+}
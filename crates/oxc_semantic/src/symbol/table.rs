@@ -0,0 +1,81 @@
+use std::ops::Index;
+
+use super::{Reference, ReferenceId, Symbol, SymbolId};
+
+/// All symbols declared by the program and all references to them
+#[derive(Debug, Default)]
+pub struct SymbolTable {
+    symbols: Vec<Symbol>,
+
+    references: Vec<Reference>,
+}
+
+impl SymbolTable {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, &Symbol)> + '_ {
+        self.symbols.iter().enumerate().map(|(index, symbol)| (SymbolId::new(index), symbol))
+    }
+
+    pub fn references(&self) -> impl Iterator<Item = &Reference> + '_ {
+        self.references.iter()
+    }
+
+    /// References to `symbol_id`
+    pub fn resolved_references(
+        &self,
+        symbol_id: SymbolId,
+    ) -> impl Iterator<Item = &Reference> + '_ {
+        self[symbol_id].references.iter().map(|reference_id| &self[*reference_id])
+    }
+
+    pub(crate) fn add_symbol(&mut self, symbol: Symbol) -> SymbolId {
+        let symbol_id = SymbolId::new(self.symbols.len());
+        self.symbols.push(symbol);
+        symbol_id
+    }
+
+    pub(crate) fn add_reference(&mut self, reference: Reference) -> ReferenceId {
+        let reference_id = ReferenceId::new(self.references.len());
+        self.references.push(reference);
+        reference_id
+    }
+
+    pub(crate) fn symbol_mut(&mut self, symbol_id: SymbolId) -> &mut Symbol {
+        &mut self.symbols[symbol_id.index()]
+    }
+
+    /// Link every reference to the symbol returned by `resolve`
+    pub(crate) fn resolve_references(&mut self, resolve: impl Fn(&Reference) -> Option<SymbolId>) {
+        for (index, reference) in self.references.iter_mut().enumerate() {
+            reference.symbol_id = resolve(reference);
+            if let Some(symbol_id) = reference.symbol_id {
+                self.symbols[symbol_id.index()].references.push(ReferenceId::new(index));
+            }
+        }
+    }
+}
+
+impl Index<SymbolId> for SymbolTable {
+    type Output = Symbol;
+
+    fn index(&self, id: SymbolId) -> &Self::Output {
+        &self.symbols[id.index()]
+    }
+}
+
+impl Index<ReferenceId> for SymbolTable {
+    type Output = Reference;
+
+    fn index(&self, id: ReferenceId) -> &Self::Output {
+        &self.references[id.index()]
+    }
+}
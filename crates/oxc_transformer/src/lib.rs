@@ -0,0 +1,934 @@
+//! Transformer
+//!
+//! Rewrites the AST into plain JavaScript before it is printed,
+//! each source language extension is lowered by its own pass:
+//!
+//! * [`ReactJsx`]: lowers JSX to React function calls
+//! * [`TypeScript`]: removes types and lowers `enum` and `namespace` declarations,
+//!   also removes the types of Flow
+//! * [`Lowering`]: lowers the syntax newer than the [`TransformTarget`] and decorators, runs last
+
+#![feature(let_chains)]
+
+mod lowering;
+mod react_jsx;
+mod typescript;
+
+use std::path::{Path, PathBuf};
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::Program;
+use oxc_parser::{has_flow_pragma, DecoratorFlavor};
+
+pub use crate::{
+    lowering::{Lowering, TransformTarget},
+    react_jsx::{JsxRuntime, ReactJsx, ReactJsxOptions},
+    typescript::{ImportsNotUsedAsValues, TypeScript, TypeScriptOptions},
+};
+
+#[derive(Debug, Default, Clone)]
+pub struct TransformOptions {
+    /// Options for the TypeScript pass, which runs on TypeScript and Flow source types only.
+    pub typescript: TypeScriptOptions,
+
+    /// Options for the JSX pass, which runs on JSX source types before the TypeScript pass
+    /// so the imports referenced by the lowered elements are kept. JSX is kept as is when `None`.
+    pub react_jsx: Option<ReactJsxOptions>,
+
+    /// Version of ECMAScript the output runs on, newer syntax is lowered for all source types.
+    pub target: TransformTarget,
+
+    /// Flavour of the decorators to lower for all source types, which should be the flavour
+    /// they were parsed with. Decorators are kept when `None`.
+    pub decorators: Option<DecoratorFlavor>,
+}
+
+pub struct Transformer<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    source_path: PathBuf,
+    options: TransformOptions,
+}
+
+impl<'a> Transformer<'a> {
+    #[must_use]
+    pub fn new(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_path: &Path,
+        options: TransformOptions,
+    ) -> Self {
+        Self { allocator, source_text, source_path: source_path.to_path_buf(), options }
+    }
+
+    pub fn build(self, program: &mut Program<'a>) {
+        if program.source_type.is_jsx()
+            && let Some(options) = self.options.react_jsx
+        {
+            ReactJsx::new(self.allocator, self.source_text, &self.source_path, options)
+                .build(program);
+        }
+        if program.source_type.is_typescript()
+            || program.source_type.is_flow()
+            || program.source_type.is_javascript() && has_flow_pragma(self.source_text)
+        {
+            TypeScript::new(self.allocator, self.options.typescript).build(program);
+        }
+        Lowering::new(self.allocator, self.options.target)
+            .decorators(self.options.decorators)
+            .build(program);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::{
+        ast::{Declaration, Statement},
+        SourceType,
+    };
+    use oxc_parser::Parser;
+    use oxc_printer::{Printer, PrinterOptions};
+
+    use super::*;
+
+    fn transform_source(
+        source_text: &str,
+        source_type: SourceType,
+        options: TransformOptions,
+    ) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let program = allocator.alloc(ret.program);
+        Transformer::new(&allocator, source_text, Path::new("src/App.jsx"), options).build(program);
+        let printer_options =
+            PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        Printer::new(source_text.len(), printer_options).build(program)
+    }
+
+    fn transform_with(source_text: &str, options: TypeScriptOptions) -> String {
+        let source_type = SourceType::builder().module().typescript().build();
+        let options = TransformOptions { typescript: options, ..TransformOptions::default() };
+        transform_source(source_text, source_type, options)
+    }
+
+    fn transform(source_text: &str) -> String {
+        transform_with(source_text, TypeScriptOptions::default())
+    }
+
+    #[test]
+    fn javascript_is_unchanged() {
+        let source_type = SourceType::builder().module().build();
+        let options = TransformOptions::default();
+        assert_eq!(
+            transform_source("import { A } from 'a';", source_type, options),
+            "import {A} from 'a'"
+        );
+    }
+
+    #[test]
+    fn strip_types() {
+        assert_eq!(transform("let a: number = b as any;"), "let a=b");
+        assert_eq!(transform("let a = <T>b!;"), "let a=b");
+        assert_eq!(transform("let f = () => <T>{};"), "let f=()=>({})");
+        assert_eq!(transform("a! = b; (c as any).d = e;"), "a=b;(c).d=e");
+        assert_eq!(
+            transform("function f<T>(this: T, a?: T, ...b: T[]): T { return a; }"),
+            "function f(a,...b){return a}"
+        );
+        assert_eq!(transform("let a = f<string>(b); new C<T>();"), "let a=f(b);new C()");
+        assert_eq!(transform("let a!: string;"), "let a");
+    }
+
+    #[test]
+    fn remove_declarations() {
+        assert_eq!(
+            transform(
+                "type A = string; interface B {} declare const c: A; declare function d(): void;
+                declare class E {} declare enum F {} declare namespace G {} declare module 'h' {}
+                declare global {} a;"
+            ),
+            "a"
+        );
+        assert_eq!(transform("function f(a: string): void; function f(a) {}"), "function f(a){}");
+    }
+
+    #[test]
+    fn classes() {
+        assert_eq!(
+            transform(
+                "abstract class A<T> extends B<T> implements C {
+                    declare a: T; private b?: string = ''; [key: string]: any;
+                    abstract m(): void; n(): void; n() {}
+                }"
+            ),
+            "class A extends B{b='';n(){}}"
+        );
+        assert_eq!(
+            transform("class A { constructor(public a, private readonly b = 1, c) {} }"),
+            "class A{constructor(a,b=1,c){this.a=a;this.b=b}}"
+        );
+        assert_eq!(
+            transform(
+                "class A extends B { constructor(public a) { 'use strict'; f(); super(); g(); } }"
+            ),
+            "class A extends B{constructor(a){\"use strict\";f();super();this.a=a;g()}}"
+        );
+    }
+
+    #[test]
+    fn elide_imports() {
+        assert_eq!(
+            transform("import A, { B, C } from 'a'; import D from 'd'; let b: B = A;"),
+            "import A from 'a';let b=A"
+        );
+        assert_eq!(transform("import type { A } from 'a'; import * as B from 'b';"), "export {}");
+        assert_eq!(transform("import 'a'; import {} from 'b';"), "import 'a';import 'b'");
+        assert_eq!(
+            transform("import { A, type B } from 'a'; new A();"),
+            "import {A} from 'a';new A()"
+        );
+        assert_eq!(
+            transform("import { A } from 'a'; export { A };"),
+            "import {A} from 'a';export {A}"
+        );
+        assert_eq!(transform("import { A } from 'a'; let b: typeof A;"), "let b;export {}");
+
+        let tsx = SourceType::builder().module().typescript().jsx().build();
+        assert_eq!(
+            transform_source("import { A } from 'a'; <A.B />;", tsx, TransformOptions::default()),
+            "import {A} from 'a';<A.B/>"
+        );
+        assert_eq!(
+            transform_source(
+                "import React from 'react'; <div />;",
+                tsx,
+                TransformOptions::default()
+            ),
+            "import React from 'react';<div/>"
+        );
+    }
+
+    #[test]
+    fn imports_not_used_as_values() {
+        let preserve = TypeScriptOptions {
+            imports_not_used_as_values: ImportsNotUsedAsValues::Preserve,
+            ..TypeScriptOptions::default()
+        };
+        assert_eq!(
+            transform_with("import { A, B } from 'a'; let b: B = A;", preserve),
+            "import {A} from 'a';let b=A"
+        );
+        assert_eq!(
+            transform_with("import { A } from 'a'; let a: A;", preserve),
+            "import 'a';let a"
+        );
+        assert_eq!(transform_with("import type { A } from 'a';", preserve), "export {}");
+
+        let verbatim =
+            TypeScriptOptions { verbatim_module_syntax: true, ..TypeScriptOptions::default() };
+        assert_eq!(
+            transform_with("import { A, type B } from 'a'; import type C from 'c';", verbatim),
+            "import {A} from 'a'"
+        );
+        assert_eq!(transform_with("import { type A } from 'a';", verbatim), "import 'a'");
+        assert_eq!(transform_with("import type { A } from 'a';", verbatim), "export {}");
+        assert_eq!(transform_with("import A = require('a');", verbatim), "var A=require('a')");
+    }
+
+    #[test]
+    fn remove_type_exports() {
+        assert_eq!(
+            transform(
+                "interface A {} type B = A; class C {} import type D from 'd';
+                export { A, B as E, C, D }; export type { F } from 'f'; export { type G } from 'g';"
+            ),
+            "class C{}export {C}"
+        );
+        assert_eq!(transform("export type * from 'a'; export * from 'b';"), "export* from'b'");
+        assert_eq!(transform("export default interface A {}"), "export {}");
+        assert_eq!(transform("interface A {} class A {} export { A };"), "class A{}export {A}");
+        assert_eq!(transform("export as namespace A;"), "export {}");
+    }
+
+    #[test]
+    fn import_equals() {
+        assert_eq!(transform("import a = require('a'); a();"), "var a=require('a');a()");
+        assert_eq!(transform("import a = require('a');"), "");
+        assert_eq!(transform("import type a = require('a');"), "");
+        assert_eq!(transform("export import b = A.B.C;"), "export var b=A.B.C");
+        assert_eq!(transform("export = a;"), "module.exports=a");
+    }
+
+    #[test]
+    fn enums() {
+        assert_eq!(
+            transform("enum E { A, B = 5, C, D = 'd', 'E' = D, F = B << 1 | C, G = `g`, H = -A }"),
+            "var E;(function(E){E[E['A']=0]='A';E[E['B']=5]='B';E[E['C']=6]='C';E['D']='d';\
+             E['E']='d';E[E['F']=14]='F';E['G']='g';E[E['H']=-0]='H'})(E||(E={}))"
+        );
+        assert_eq!(
+            transform("enum E { A = f(), B, C = A + 1, D = 'a'.length }"),
+            "var E;(function(E){E[E['A']=f()]='A';E[E['B']=1+E.A]='B';E[E['C']=E.A+1]='C';\
+             E[E['D']='a'.length]='D'})(E||(E={}))"
+        );
+        assert_eq!(
+            transform("enum E { A = 1 / 0, B = -A, C = 0 / 0, D = E.A + 1, E = E['B'] }"),
+            "var E;(function(E){E[E['A']=Infinity]='A';E[E['B']=-Infinity]='B';E[E['C']=NaN]='C';\
+             E[E['D']=Infinity]='D';E[E['E']=-Infinity]='E'})(E||(E={}))"
+        );
+        assert_eq!(
+            transform("export enum E { A } enum E { B = 1 }"),
+            "export var E;(function(E){E[E['A']=0]='A'})(E||(E={}));\
+             (function(E){E[E['B']=1]='B'})(E||(E={}))"
+        );
+    }
+
+    #[test]
+    fn const_enums() {
+        assert_eq!(
+            transform(
+                "const enum E { A = 1, B = A * -2, C = 'c' } let a = [E.A, E['B'], E.C, E.B.toString(), E.D];"
+            ),
+            "let a=[1,-2,'c',(-2).toString(),E.D]"
+        );
+        assert_eq!(
+            transform("const enum E { A = 1 } enum F { A = E.A << 1 } f(E.A);"),
+            "var F;(function(F){F[F['A']=2]='A'})(F||(F={}));f(1)"
+        );
+        assert_eq!(
+            transform("export const enum E { A = 1 } f(E.A);"),
+            "export var E;(function(E){E[E['A']=1]='A'})(E||(E={}));f(1)"
+        );
+    }
+
+    #[test]
+    fn namespaces() {
+        assert_eq!(
+            transform(
+                "namespace N { export const a = 1, b = a + 1; export function f() { return a; }
+                export class C {} const c = a; let d = () => { let a = 2; return a; }; }"
+            ),
+            "var N;(function(N){N.a=1;N.b=N.a+1;function f(){return N.a}N.f=f;class C{}N.C=C;\
+             const c=N.a;let d=()=>{let a=2;return a}})(N||(N={}))"
+        );
+        assert_eq!(
+            transform("namespace A.B { export let c = 1; }"),
+            "var A;(function(A){let B;(function(B){B.c=1})(B=A.B||(A.B={}))})(A||(A={}))"
+        );
+        assert_eq!(
+            transform(
+                "export namespace N { export namespace M { export enum E { A } } export import X = M.E; }"
+            ),
+            "export var N;(function(N){let M;(function(M){let E;(function(E){E[E['A']=0]='A'})\
+             (E=M.E||(M.E={}))})(M=N.M||(N.M={}));N.X=M.E})(N||(N={}))"
+        );
+        assert_eq!(
+            transform("namespace N { export interface A {} type B = A; declare const c: B; }"),
+            ""
+        );
+        assert_eq!(
+            transform(
+                "function N() {} namespace N { export let a = 1; } namespace N { a++; ({ a } = b); x = { a }; }"
+            ),
+            "function N(){}(function(N){N.a=1})(N||(N={}));\
+             (function(N){N.a++;({a:N.a}=b);x={a:N.a}})(N||(N={}))"
+        );
+        assert_eq!(
+            transform("namespace N { export const { a, b: [c] } = d; f(a, c); }"),
+            "var N;(function(N){const {a,b:[c]}=d;N.a=a;N.c=c;f(a,c)})(N||(N={}))"
+        );
+    }
+
+    fn transform_jsx(source_text: &str, options: ReactJsxOptions) -> String {
+        let source_type = SourceType::builder().module().jsx().build();
+        let options = TransformOptions { react_jsx: Some(options), ..TransformOptions::default() };
+        transform_source(source_text, source_type, options)
+    }
+
+    fn automatic() -> ReactJsxOptions {
+        ReactJsxOptions { runtime: JsxRuntime::Automatic, ..ReactJsxOptions::default() }
+    }
+
+    #[test]
+    fn jsx_classic() {
+        let classic = ReactJsxOptions::default;
+        assert_eq!(transform_jsx("<div />;", classic()), "React.createElement('div',null)");
+        assert_eq!(
+            transform_jsx("<Foo a=\"b\" c={d} e {...f} g-h=\"i\" x:y=\"z\" />;", classic()),
+            "React.createElement(Foo,{a:'b',c:d,e:true,...f,'g-h':'i','x:y':'z'})"
+        );
+        assert_eq!(
+            transform_jsx("<a.b.c />; <this.A />; <svg:path />; <my-element />;", classic()),
+            "React.createElement(a.b.c,null);React.createElement(this.A,null);\
+            React.createElement('svg:path',null);React.createElement('my-element',null)"
+        );
+        assert_eq!(
+            transform_jsx(
+                "<div>\n  hello   world\n  {a}{/* b */}<c>{<d />}</c>\n</div>;",
+                classic()
+            ),
+            "React.createElement('div',null,'hello   world',a,\
+            React.createElement('c',null,React.createElement('d',null)))"
+        );
+        assert_eq!(
+            transform_jsx("<><A /></>;", classic()),
+            "React.createElement(React.Fragment,null,React.createElement(A,null))"
+        );
+        let preact = ReactJsxOptions {
+            pragma: Some("h".to_string()),
+            pragma_frag: Some("Fragment".to_string()),
+            ..ReactJsxOptions::default()
+        };
+        assert_eq!(transform_jsx("<>a</>;", preact), "h(Fragment,null,'a')");
+    }
+
+    #[test]
+    fn jsx_text() {
+        let classic = ReactJsxOptions::default;
+        assert_eq!(
+            transform_jsx("<div>  a  \n\n  b\t\n  </div>;", classic()),
+            "React.createElement('div',null,'  a b')"
+        );
+        assert_eq!(
+            transform_jsx("<div>\n  \n</div>;", classic()),
+            "React.createElement('div',null)"
+        );
+        assert_eq!(
+            transform_jsx(
+                "<div title=\"&quot;a&quot; &#38; &#x26; &#x; &foo;\">&lt;&unknown;&amp</div>;",
+                classic()
+            ),
+            "React.createElement('div',{title:'\"a\" & & &#x; &foo;'},'<&unknown;&amp')"
+        );
+        assert_eq!(
+            transform_jsx("<div title=\"a\n    b\" />;", classic()),
+            "React.createElement('div',{title:'a b'})"
+        );
+    }
+
+    #[test]
+    fn jsx_automatic() {
+        assert_eq!(
+            transform_jsx("<div key=\"a\" b>{c}</div>;", automatic()),
+            "import {jsx as _jsx} from 'react/jsx-runtime';_jsx('div',{b:true,children:c},'a')"
+        );
+        assert_eq!(
+            transform_jsx("<><A />b</>;", automatic()),
+            "import {Fragment as _Fragment,jsx as _jsx,jsxs as _jsxs} from 'react/jsx-runtime';\
+            _jsxs(_Fragment,{children:[_jsx(A,{}),'b']})"
+        );
+        assert_eq!(
+            transform_jsx("<div {...a} key=\"b\" />; <div key=\"c\" {...d} />;", automatic()),
+            "import {jsx as _jsx} from 'react/jsx-runtime';\
+            import {createElement as _createElement} from 'react';\
+            _createElement('div',{...a,key:'b'});_jsx('div',{...d},'c')"
+        );
+        let preact = ReactJsxOptions { import_source: Some("preact".to_string()), ..automatic() };
+        assert_eq!(
+            transform_jsx("<div />;", preact),
+            "import {jsx as _jsx} from 'preact/jsx-runtime';_jsx('div',{})"
+        );
+        let script = SourceType::builder().script().jsx().build();
+        let options =
+            TransformOptions { react_jsx: Some(automatic()), ..TransformOptions::default() };
+        assert_eq!(
+            transform_source("<div />;", script, options),
+            "var {jsx:_jsx}=require('react/jsx-runtime');_jsx('div',{})"
+        );
+    }
+
+    #[test]
+    fn jsx_development() {
+        let classic = ReactJsxOptions { development: true, ..ReactJsxOptions::default() };
+        assert_eq!(
+            transform_jsx("<div>\n  <br />\n</div>;", classic),
+            "var _jsxFileName='src/App.jsx';\
+            React.createElement('div',{__self:this,__source:{fileName:_jsxFileName,lineNumber:1,columnNumber:1}},\
+            React.createElement('br',{__self:this,__source:{fileName:_jsxFileName,lineNumber:2,columnNumber:3}}))"
+        );
+        let automatic = ReactJsxOptions { development: true, ..automatic() };
+        assert_eq!(
+            transform_jsx("<A key={b}>{c}{d}</A>;", automatic.clone()),
+            "import {jsxDEV as _jsxDEV} from 'react/jsx-dev-runtime';var _jsxFileName='src/App.jsx';\
+            _jsxDEV(A,{children:[c,d]},b,true,{fileName:_jsxFileName,lineNumber:1,columnNumber:1},this)"
+        );
+        // `this` can't be referenced before `super()`
+        assert_eq!(
+            transform_jsx(
+                "class A extends B { constructor() { super(<a />); } m() { <b />; } }",
+                automatic
+            ),
+            "import {jsxDEV as _jsxDEV} from 'react/jsx-dev-runtime';var _jsxFileName='src/App.jsx';\
+            class A extends B{constructor(){super(_jsxDEV('a',{},void 0,false,{fileName:_jsxFileName,lineNumber:1,columnNumber:43}))}\
+            m(){_jsxDEV('b',{},void 0,false,{fileName:_jsxFileName,lineNumber:1,columnNumber:59},this)}}"
+        );
+    }
+
+    #[test]
+    fn jsx_typescript() {
+        let tsx = SourceType::builder().module().typescript().jsx().build();
+        let options = TransformOptions {
+            react_jsx: Some(ReactJsxOptions {
+                pragma: Some("h".to_string()),
+                ..ReactJsxOptions::default()
+            }),
+            ..TransformOptions::default()
+        };
+        assert_eq!(
+            transform_source(
+                "import { h } from 'preact'; import { A, B } from 'a'; <A<B> a={b as any} />;",
+                tsx,
+                options
+            ),
+            "import {h} from 'preact';import {A} from 'a';h(A,{a:b})"
+        );
+    }
+
+    #[test]
+    fn strip_flow_types() {
+        let source_type = SourceType::builder().module().build();
+        assert_eq!(
+            transform_source(
+                "// @flow
+                import type { Node } from 'react';
+                import { a, type B } from 'a';
+                type Props = {| +b: ?B |};
+                export opaque type Id: string = string;
+                function f<T: Object>(props: Props, c: ?T): Node { return a(props, c); }",
+                source_type,
+                TransformOptions::default()
+            ),
+            "import {a} from 'a';function f(props,c){return a(props,c)}"
+        );
+    }
+
+    fn transform_target(source_text: &str, target: TransformTarget) -> String {
+        let source_type = SourceType::builder().module().build();
+        let options = TransformOptions { target, ..TransformOptions::default() };
+        transform_source(source_text, source_type, options)
+    }
+
+    #[test]
+    fn lower_nullish_coalescing() {
+        let target = TransformTarget::ES2019;
+        assert_eq!(transform_target("a ?? b;", target), "a!==null&&a!==void 0?a:b");
+        assert_eq!(
+            transform_target("let x = f() ?? c; x = a.b ?? c;", target),
+            "var _f,_a$b;let x=(_f=f())!==null&&_f!==void 0?_f:c;\
+            x=(_a$b=a.b)!==null&&_a$b!==void 0?_a$b:c"
+        );
+        assert_eq!(
+            transform_target("function f() { return g(a ?? b, c() ?? d); }", target),
+            "function f(){var _c;return g(a!==null&&a!==void 0?a:b,\
+            (_c=c())!==null&&_c!==void 0?_c:d)}"
+        );
+    }
+
+    #[test]
+    fn lower_optional_chaining() {
+        let target = TransformTarget::ES2019;
+        assert_eq!(transform_target("a?.b;", target), "a===null||a===void 0?void 0:a.b");
+        assert_eq!(
+            transform_target("a.b?.c.d; f()?.[g()];", target),
+            "var _a$b,_f;(_a$b=a.b)===null||_a$b===void 0?void 0:_a$b.c.d;\
+            (_f=f())===null||_f===void 0?void 0:_f[g()]"
+        );
+        assert_eq!(
+            transform_target("a?.b?.(); a.b?.(c);", target),
+            "var _a$b,_a$b2;a===null||a===void 0||(_a$b=a.b)===null||_a$b===void 0?void 0:\
+            _a$b.call(a);(_a$b2=a.b)===null||_a$b2===void 0?void 0:_a$b2.call(a,c)"
+        );
+        assert_eq!(
+            transform_target("class A extends B { m() { super.m?.(); } }", target),
+            "class A extends B{m(){var _super$m;(_super$m=super.m)===null||_super$m===void 0?\
+            void 0:_super$m.call(this)}}"
+        );
+        assert_eq!(
+            transform_target("delete a?.b; (a?.b).c;", target),
+            "a===null||a===void 0?true:delete a.b;(a===null||a===void 0?void 0:a.b).c"
+        );
+    }
+
+    #[test]
+    fn lower_logical_assignment() {
+        let target = TransformTarget::ES2020;
+        assert_eq!(transform_target("a ||= b; a.b &&= c;", target), "a||(a=b);a.b&&(a.b=c)");
+        assert_eq!(
+            transform_target("f().g[h()] ??= d;", target),
+            "var _f$g,_h;(_f$g=f().g)[(_h=h())]??(_f$g[_h]=d)"
+        );
+        assert_eq!(
+            transform_target("a ??= b;", TransformTarget::ES2019),
+            "a!==null&&a!==void 0?a:(a=b)"
+        );
+    }
+
+    #[test]
+    fn lower_exponentiation() {
+        let target = TransformTarget::ES2015;
+        assert_eq!(transform_target("a ** b ** c;", target), "Math.pow(a,Math.pow(b,c))");
+        assert_eq!(
+            transform_target("a.b **= c; f()[g] **= 2;", target),
+            "var _f;a.b=Math.pow(a.b,c);(_f=f())[g]=Math.pow(_f[g],2)"
+        );
+        assert_eq!(transform_target("a ** b;", TransformTarget::ES2016), "a**b");
+    }
+
+    #[test]
+    fn lower_object_rest_spread() {
+        let target = TransformTarget::ES2017;
+        assert_eq!(
+            transform_target("x = { a, ...b, c, ...d }; y = { ...a };", target),
+            "x=Object.assign({a},b,{c},d);y=Object.assign({},a)"
+        );
+
+        let helper = "function _objectWithoutProperties(source,excluded){";
+        let cases = [
+            (
+                "const { a, ...b } = c;",
+                "const {a}=c,b=_objectWithoutProperties(c,['a'])",
+            ),
+            (
+                "let { [k()]: d, e: { f, ...g }, ...h } = i();",
+                "var _k,_i;let {[(_k=k())]:d,e:_ref}=(_i=i()),h=_objectWithoutProperties(_i,[_k,'e']),\
+                {f}=_ref,g=_objectWithoutProperties(_ref,['f'])",
+            ),
+            (
+                "({ a, ...b } = c);",
+                "({a}=c,b=_objectWithoutProperties(c,['a']),c)",
+            ),
+            (
+                "for (const { a, ...b } of c) f();",
+                "for(const _ref of c){const {a}=_ref,b=_objectWithoutProperties(_ref,['a']);f()}",
+            ),
+            (
+                "try {} catch ({ a, ...b }) {}",
+                "try{}catch(_ref){let {a}=_ref,b=_objectWithoutProperties(_ref,['a'])}",
+            ),
+            (
+                "function f({ a, ...b }, [{ c, ...d }] = []) {} let g = ({ ...a }) => a;",
+                "function f(_ref,_ref2=[]){var {a}=_ref,b=_objectWithoutProperties(_ref,['a']),\
+                [_ref3]=_ref2,{c}=_ref3,d=_objectWithoutProperties(_ref3,['c'])}\
+                let g=(_ref)=>{var a=_objectWithoutProperties(_ref,[]);return a}",
+            ),
+        ];
+        for (source_text, expected) in cases {
+            let output = transform_target(source_text, target);
+            assert!(output.starts_with(helper), "{output}");
+            assert!(output.ends_with(&format!("return target}}{expected}")), "{output}");
+        }
+    }
+
+    #[test]
+    fn lower_optional_catch_binding() {
+        assert_eq!(
+            transform_target("try { a() } catch { b() }", TransformTarget::ES2018),
+            "try{a()}catch(_unused){b()}"
+        );
+    }
+
+    #[test]
+    fn lower_async_functions() {
+        let target = TransformTarget::ES2016;
+        let helper = "function _asyncToGenerator(self,args,body){";
+        let cases = [
+            (
+                "async function f(a) { 'use strict'; await a; return await b; }",
+                "function f(a){\"use strict\";return _asyncToGenerator(this,arguments,function*(){\
+                yield a;return yield b})}",
+            ),
+            (
+                "let g = async () => await this.a;",
+                "let g=()=>_asyncToGenerator(this,void 0,function*(){return yield this.a})",
+            ),
+            (
+                "function f() { return async (x) => { await arguments[0]; }; }",
+                "function f(){return (x)=>_asyncToGenerator(this,arguments,function*(){\
+                yield arguments[0]})}",
+            ),
+        ];
+        for (source_text, expected) in cases {
+            let output = transform_target(source_text, target);
+            assert!(output.starts_with(helper), "{output}");
+            assert!(output.ends_with(&format!("next()}})}}{expected}")), "{output}");
+        }
+        assert_eq!(
+            transform_target("async function* f() { await a; }", target),
+            "async function*f(){await a}"
+        );
+    }
+
+    /// The output without the declarations of the helpers, which are checked by name
+    fn transform_target_with_helpers(
+        source_text: &str,
+        target: TransformTarget,
+        helpers: &[&str],
+    ) -> String {
+        let allocator = Allocator::default();
+        let source_type = SourceType::builder().module().build();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let program = allocator.alloc(ret.program);
+        let options = TransformOptions { target, ..TransformOptions::default() };
+        Transformer::new(&allocator, source_text, Path::new("src/App.js"), options).build(program);
+        remove_helpers(source_text, program, helpers);
+        let printer_options =
+            PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        Printer::new(source_text.len(), printer_options).build(program)
+    }
+
+    #[test]
+    fn lower_class_fields() {
+        let target = TransformTarget::ES2021;
+        assert_eq!(
+            transform_target_with_helpers(
+                "class A { a = 1; b; [c()] = 2; 'd' = 3; #e = 4; static f = this.a; static { g(this); } }",
+                target,
+                &["classPrivateFieldInit"]
+            ),
+            "var _e,_c;_e=new WeakMap();_c=c();class A{constructor(){this.a=1;this.b=void 0;\
+            this[_c]=2;this['d']=3;_classPrivateFieldInit(this,_e,{writable:true,value:4})}}\
+            A.f=A.a;(()=>{g(A)})()"
+        );
+        assert_eq!(
+            transform_target(
+                "class A extends B { a = 1; constructor(x) { super(x); } } class C extends D { a = 1; }",
+                target
+            ),
+            "class A extends B{constructor(x){super(x);this.a=1}}\
+            class C extends D{constructor(..._args){super(..._args);this.a=1}}"
+        );
+        assert_eq!(
+            transform_target(
+                "class A extends B { a = 1; constructor() { x ? super() : super(1); } }",
+                target
+            ),
+            "class A extends B{constructor(){x?(super(),this.a=1,this):(super(1),this.a=1,this)}}"
+        );
+        assert_eq!(
+            transform_target(
+                "let A = class B { static a = B; static b = () => this; c = 1 };\
+                export default class { static a = 1 }",
+                target
+            ),
+            "var _B;let A=(_B=class B{constructor(){this.c=1}},_B.a=_B,_B.b=()=>_B,_B);\
+            export default class _default{}_default.a=1"
+        );
+        assert_eq!(
+            transform_target_with_helpers(
+                "class A extends B { static f = super.x; static { super.y(); super.z = 1; } }",
+                target,
+                &["superPropSet"]
+            ),
+            "class A extends B{}A.f=Reflect.get(Object.getPrototypeOf(A),'x',A);\
+            (()=>{Reflect.get(Object.getPrototypeOf(A),'y',A).call(A);\
+            _superPropSet(Object.getPrototypeOf(A),'z',A,1)})()"
+        );
+    }
+
+    #[test]
+    fn lower_private_members() {
+        let target = TransformTarget::ES2021;
+        assert_eq!(
+            transform_target_with_helpers(
+                "class A { #a = 1; static #c = 2; f(o) { this.#a += 1; o.#a++; --o().#a; [this.#a] = [3]; return #a in o && A.#c; } }",
+                target,
+                &["classPrivateFieldInit", "classPrivateFieldGet", "classPrivateFieldSet", "toSetter"]
+            ),
+            "var _a,_c;_a=new WeakMap();_c=new WeakMap();\
+            class A{constructor(){_classPrivateFieldInit(this,_a,{writable:true,value:1})}\
+            f(o){var _o$a,_o$a2,_o,_o$a3;\
+            _classPrivateFieldSet(this,_a,_classPrivateFieldGet(this,_a)+1);\
+            (_classPrivateFieldSet(o,_a,(_o$a=_classPrivateFieldGet(o,_a),_o$a2=_o$a++,_o$a)),_o$a2);\
+            _classPrivateFieldSet((_o=o()),_a,(_o$a3=_classPrivateFieldGet(_o,_a),--_o$a3));\
+            [_toSetter(_classPrivateFieldSet,[this,_a])._]=[3];\
+            return _a.has(o)&&_classPrivateFieldGet(A,_c)}}\
+            _classPrivateFieldInit(A,_c,{writable:true,value:2})"
+        );
+        assert_eq!(
+            transform_target_with_helpers(
+                "class A { #m() { return this.#b; } get #b() { return 1; } set #b(v) {} f(o) { o.#m(); o.#m`t`; o().#b = 2; o?.#m(); } }",
+                target,
+                &["classPrivateFieldGet", "classPrivateFieldInit", "classPrivateFieldSet"]
+            ),
+            "var _m,_b,_m2,_get_b,_set_b;_m=new WeakMap();_b=new WeakMap();\
+            _m2=function(){return _classPrivateFieldGet(this,_b)};_get_b=function(){return 1};\
+            _set_b=function(v){};class A{constructor(){_classPrivateFieldInit(this,_m,{value:_m2});\
+            _classPrivateFieldInit(this,_b,{get:_get_b,set:_set_b})}\
+            f(o){_classPrivateFieldGet(o,_m).call(o);_classPrivateFieldGet(o,_m).bind(o)`t`;\
+            _classPrivateFieldSet(o(),_b,2);\
+            (o===null||o===void 0?void 0:_classPrivateFieldGet(o,_m).call(o))}}"
+        );
+        assert_eq!(
+            transform_target_with_helpers(
+                "let C = class D { #m() { return D; } static #n() {} }",
+                target,
+                &["classPrivateFieldInit"]
+            ),
+            "var _D,_m,_n,_m2,_n2;let C=(_m=new WeakMap(),_n=new WeakMap(),_m2=function(){return _D},\
+            _n2=function(){},_D=class D{constructor(){_classPrivateFieldInit(this,_m,{value:_m2})}},\
+            _classPrivateFieldInit(_D,_n,{value:_n2}),_D)"
+        );
+        assert_eq!(
+            transform_target_with_helpers(
+                "class A extends B { #m() { return super.m(); } }",
+                target,
+                &["classPrivateFieldInit"]
+            ),
+            "var _m,_m2;_m=new WeakMap();\
+            _m2=function(){return Reflect.get(Object.getPrototypeOf(A.prototype),'m',this).call(this)};\
+            class A extends B{constructor(..._args){super(..._args);\
+            _classPrivateFieldInit(this,_m,{value:_m2})}}"
+        );
+    }
+
+    fn transform_decorators(
+        source_text: &str,
+        flavor: DecoratorFlavor,
+        helpers: &[&str],
+    ) -> String {
+        transform_decorators_with(source_text, flavor, TransformTarget::ESNext, helpers)
+    }
+
+    /// The output without the declarations of the helpers, which are checked by name
+    fn transform_decorators_with(
+        source_text: &str,
+        flavor: DecoratorFlavor,
+        target: TransformTarget,
+        helpers: &[&str],
+    ) -> String {
+        let source_type = match flavor {
+            DecoratorFlavor::Legacy => SourceType::builder().module().typescript().build(),
+            DecoratorFlavor::Standard => SourceType::builder().module().build(),
+        };
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).decorators(flavor).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let program = allocator.alloc(ret.program);
+        let options =
+            TransformOptions { target, decorators: Some(flavor), ..TransformOptions::default() };
+        Transformer::new(&allocator, source_text, Path::new("src/App.js"), options).build(program);
+        remove_helpers(source_text, program, helpers);
+        let printer_options =
+            PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
+        Printer::new(source_text.len(), printer_options).build(program)
+    }
+
+    fn remove_helpers(source_text: &str, program: &mut Program, helpers: &[&str]) {
+        let names = program.body.drain(..helpers.len()).map(|stmt| match stmt {
+            Statement::Declaration(Declaration::FunctionDeclaration(func)) => {
+                func.id.as_ref().unwrap().name.to_string()
+            }
+            _ => panic!("{source_text}: expected the declarations of {helpers:?}"),
+        });
+        assert!(names.eq(helpers.iter().map(|helper| format!("_{helper}"))));
+    }
+
+    #[test]
+    fn lower_legacy_decorators() {
+        let flavor = DecoratorFlavor::Legacy;
+        let helpers = ["param", "decorate"];
+        assert_eq!(
+            transform_decorators(
+                "@a class A { constructor(@b x) {} @c @d m(@e y) {} @f static g = 1; @h [i()] = 2 }",
+                flavor,
+                &helpers
+            ),
+            "var _i;class A{constructor(x){}m(y){}static g=1;[_i=i()]=2}\
+            _decorate([c,d,_param(0,e)],A.prototype,'m',null);\
+            _decorate([h],A.prototype,_i,void 0);_decorate([f],A,'g',void 0);\
+            A=_decorate([a,_param(0,b)],A)"
+        );
+        assert_eq!(
+            transform_decorators(
+                "@a export default class { @b.c() m() {} }",
+                flavor,
+                &helpers[1..]
+            ),
+            "export default class _default{m(){}}\
+            _decorate([b.c()],_default.prototype,'m',null);_default=_decorate([a],_default)"
+        );
+    }
+
+    #[test]
+    fn lower_standard_decorators() {
+        let flavor = DecoratorFlavor::Standard;
+        assert_eq!(
+            transform_decorators(
+                "@a class A { @b m() {} @c.d x = 1; @e static accessor y; #z = 2; accessor [w()] }",
+                flavor,
+                &["applyDecs", "applyClassDecs"]
+            ),
+            "var _c$d,_init_x,_init_y,_w2,_initProto;_c$d=c.d;\
+            class A{static{[_init_x,_init_y,_initProto]=_applyDecs(this,[[[b],2,'m',false],\
+            [[_c$d],0,'x',false],[[e],1,'y',true]])}\
+            m(){}x=(_initProto(this),_init_x(this,1));\
+            static #_y=_init_y(this,void 0);static get y(){return this.#_y}\
+            static set y(value){this.#_y=value}#z=2;\
+            #_w;get [_w2=w()](){return this.#_w}set [_w2](value){this.#_w=value}}\
+            A=_applyClassDecs(A,[a])"
+        );
+        assert_eq!(
+            transform_decorators(
+                "let A = @a class { @b static m() {} }",
+                flavor,
+                &["applyDecs", "applyClassDecs"]
+            ),
+            "let A=_applyClassDecs(class{static{_applyDecs(this,[[[b],2,'m',true]])}static m(){}},[a])"
+        );
+        assert_eq!(
+            transform_decorators("class A extends B { @a get m() {} }", flavor, &["applyDecs"]),
+            "var _initProto;class A extends B{constructor(..._args){super(..._args);\
+            _initProto(this)}static{[_initProto]=_applyDecs(this,[[[a],3,'m',false]])}get m(){}}"
+        );
+        assert_eq!(
+            transform_decorators_with(
+                "@a class A { @b x; } let C = class { @d static y = 1; }",
+                flavor,
+                TransformTarget::ES2021,
+                &["applyDecs", "applyClassDecs"]
+            ),
+            "var _init_y,_Class,_init_x,_initProto;\
+            class A{constructor(){this.x=(_initProto(this),_init_x(this,void 0))}}\
+            (()=>{[_init_x,_initProto]=_applyDecs(A,[[[b],0,'x',false]])})();\
+            A=_applyClassDecs(A,[a]);\
+            let C=(_Class=class{},(()=>{[_init_y]=_applyDecs(_Class,[[[d],0,'y',true]])})(),\
+            _Class.y=_init_y(_Class,1),_Class)"
+        );
+    }
+
+    #[test]
+    fn temporary_names() {
+        let target = TransformTarget::ES2019;
+        assert_eq!(
+            transform_target(
+                "function f() { return g() ?? 1; } function h() { return g() ?? _g; }",
+                target
+            ),
+            "function f(){var _g;return (_g=g())!==null&&_g!==void 0?_g:1}\
+            function h(){var _g2;return (_g2=g())!==null&&_g2!==void 0?_g2:_g}"
+        );
+        assert_eq!(
+            transform_target("function f(_g) { return g() ?? (() => { let _g2; }); }", target),
+            "function f(_g){var _g3;return (_g3=g())!==null&&_g3!==void 0?_g3:(()=>{let _g2})}"
+        );
+    }
+
+    #[test]
+    fn lower_nested() {
+        assert_eq!(
+            transform_target("function f() { return a?.b ?? c; }", TransformTarget::ES2019),
+            "function f(){var _ref;return (_ref=(a===null||a===void 0?void 0:a.b))!==null&&\
+            _ref!==void 0?_ref:c}"
+        );
+        assert_eq!(
+            transform_target("class A { a = b ?? c; }", TransformTarget::ES2019),
+            "class A{constructor(){this.a=b!==null&&b!==void 0?b:c}}"
+        );
+        assert_eq!(
+            transform_target("a?.b ?? c; class A { static a = 1 }", TransformTarget::ES2022),
+            "a?.b??c;class A{static a=1}"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! ES2017: async functions
+//!
+//! The body of an async function becomes a generator, run by the `_asyncToGenerator` helper
+//! with the `this` and `arguments` of the function, and `await` becomes `yield`:
+//!
+//! * `async function f() { await a }` becomes
+//!   `function f() { return _asyncToGenerator(this, arguments, function* () { yield a }) }`
+//! * `async () => await this.a` becomes
+//!   `() => _asyncToGenerator(this, void 0, function* () { return yield this.a })`,
+//!   an arrow passes its `arguments` only when it uses them
+//!
+//! Async generators and `super` in async methods are kept.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, Atom, Span};
+
+use super::{helpers::Helper, Lowering};
+
+impl<'a> Lowering<'a> {
+    pub(super) fn lower_await(&self, expr: AwaitExpression<'a>) -> Expression<'a> {
+        let expr = self.ast.yield_expression(expr.span, false, Some(expr.argument));
+        self.parenthesized(expr)
+    }
+
+    pub(super) fn lower_async_function(&mut self, func: &mut Function<'a>) {
+        let Some(body) = &mut func.body else { return };
+        func.r#async = false;
+        let span = body.span;
+        let statements = self.ast.move_statement_vec(&mut body.statements);
+        let arguments = self.identifier(span, &Atom::from("arguments"));
+        let call = self.async_to_generator(span, arguments, statements);
+        body.statements.push(self.ast.return_statement(span, Some(call)));
+    }
+
+    pub(super) fn lower_async_arrow(&mut self, arrow: &mut ArrowExpression<'a>) {
+        arrow.r#async = false;
+        self.ensure_block_body(arrow);
+        let span = arrow.body.span;
+        let mut statements = self.ast.move_statement_vec(&mut arrow.body.statements);
+        let mut finder = ArgumentsFinder(false);
+        finder.visit_statements(&mut statements);
+        let arguments = if finder.0 {
+            self.identifier(span, &Atom::from("arguments"))
+        } else {
+            self.void_zero(span)
+        };
+        let call = self.async_to_generator(span, arguments, statements);
+        arrow.expression = true;
+        arrow.body.statements.push(self.ast.expression_statement(span, call));
+    }
+
+    /// `_asyncToGenerator(this, arguments, function* () { statements })`
+    fn async_to_generator(
+        &mut self,
+        span: Span,
+        arguments: Expression<'a>,
+        statements: oxc_allocator::Vec<'a, Statement<'a>>,
+    ) -> Expression<'a> {
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec(),
+        );
+        let body = self.ast.function_body(span, self.ast.new_vec(), statements);
+        let generator = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            true,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let callee = self.helper(span, Helper::AsyncToGenerator);
+        let this = self.ast.this_expression(span);
+        self.call(span, callee, [this, arguments, self.ast.function_expression(generator)])
+    }
+}
+
+/// Whether an arrow refers to the `arguments` of its enclosing function
+struct ArgumentsFinder(bool);
+
+impl<'a> VisitMut<'a> for ArgumentsFinder {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if ident.name == "arguments" {
+            self.0 = true;
+        }
+    }
+
+    // Functions have their own `arguments`
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+}
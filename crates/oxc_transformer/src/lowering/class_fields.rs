@@ -0,0 +1,585 @@
+//! ES2022: class fields and static blocks
+//!
+//! * `class A { a = 1 }` becomes `class A { constructor() { this.a = 1 } }`, instance fields are
+//!   assigned at the start of the constructor, or after `super()` in a derived class
+//! * `class A { static a = this.b }` becomes `class A {} A.a = A.b`
+//! * `class A { static { f(this) } }` becomes `class A {} (() => { f(A) })()`
+//! * the class expression `class { static a = 1 }` becomes
+//!   `(_Class = class {}, _Class.a = 1, _Class)`
+//!
+//! Fields are assigned, not defined, like TypeScript does without `useDefineForClassFields`.
+//! Computed keys are evaluated before the class. Private members are stored in `WeakMap`s,
+//! see [`super::private_members`].
+
+use oxc_allocator::{Box, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, AstBuilder, Atom, CloneIn, GetSpan, Span};
+
+use super::{
+    helpers::Helper,
+    names::name_hint,
+    private_members::{PrivateNames, PrivateReferences},
+    Lowering,
+};
+
+/// Whether `class` has fields, static blocks or private methods to lower
+pub fn has_fields(class: &Class) -> bool {
+    class.body.body.iter().any(|element| match element {
+        ClassElement::PropertyDefinition(_) | ClassElement::StaticBlock(_) => true,
+        ClassElement::MethodDefinition(def) => def.key.is_private_identifier(),
+        _ => false,
+    })
+}
+
+/// The property of the instance or class a field is assigned to
+enum FieldKey<'a> {
+    Static(IdentifierName),
+    Computed(Expression<'a>),
+    /// The `WeakMap` of a private field
+    Private(Atom),
+}
+
+/// A private method or accessor moved out of the class
+struct PrivateMethod {
+    span: Span,
+    r#static: bool,
+    name: Atom,
+    /// The properties of its descriptor, `value` or `get` and `set`, with the moved functions
+    properties: std::vec::Vec<(&'static str, Atom)>,
+}
+
+impl<'a> Lowering<'a> {
+    /// Class declarations are followed by the initializers of their static members
+    pub(super) fn lower_class_declarations(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        if !stmts
+            .iter_mut()
+            .any(|stmt| declared_class(stmt).map_or(false, |class| has_fields(class)))
+        {
+            return;
+        }
+        let mut body = self.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let Some(class) = declared_class(&mut stmt).filter(|class| has_fields(class)) else {
+                body.push(stmt);
+                continue;
+            };
+            // `export default class {}`
+            let span = Span::new(class.span.start, class.span.start);
+            let id = class.id.get_or_insert_with(|| BindingIdentifier {
+                span,
+                name: self.names.generate("default"),
+            });
+            let name = id.name.clone();
+            let (before, after) = self.lower_class(class, Some(&name));
+            for expr in before {
+                body.push(self.ast.expression_statement(expr.span(), expr));
+            }
+            body.push(stmt);
+            for expr in after {
+                body.push(self.ast.expression_statement(expr.span(), expr));
+            }
+        }
+        *stmts = body;
+    }
+
+    pub(super) fn lower_class_expression(
+        &mut self,
+        mut class: Box<'a, Class<'a>>,
+    ) -> Expression<'a> {
+        let span = class.span;
+        // Static members and private methods are moved out of the class, they refer to it by name
+        let needs_name = class.body.body.iter().any(|element| match element {
+            ClassElement::PropertyDefinition(def) => def.r#static,
+            ClassElement::MethodDefinition(def) => def.key.is_private_identifier(),
+            ClassElement::StaticBlock(_) => true,
+            _ => false,
+        });
+        let name = needs_name.then(|| {
+            let hint = class.id.as_ref().map_or("Class", |id| id.name.as_str());
+            self.temporary(hint)
+        });
+        let (mut expressions, after) = self.lower_class(&mut class, name.as_ref());
+        let class = self.ast.class_expression(class);
+        let Some(name) = name else {
+            if expressions.is_empty() {
+                return class;
+            }
+            expressions.push(class);
+            let expressions = Vec::from_iter_in(expressions, self.ast.allocator);
+            let sequence = self.ast.sequence_expression(span, expressions);
+            return self.parenthesized(sequence);
+        };
+        expressions.push(self.assign(span, &name, class));
+        expressions.extend(after);
+        expressions.push(self.identifier(span, &name));
+        let expressions = Vec::from_iter_in(expressions, self.ast.allocator);
+        let sequence = self.ast.sequence_expression(span, expressions);
+        self.parenthesized(sequence)
+    }
+
+    /// Removes the fields, static blocks and private methods of `class`, returns the expressions
+    /// evaluated before the class and the initializers of the static members referring to the
+    /// class by `name`. Static members are kept without a `name`.
+    fn lower_class(
+        &mut self,
+        class: &mut Class<'a>,
+        name: Option<&Atom>,
+    ) -> (std::vec::Vec<Expression<'a>>, std::vec::Vec<Expression<'a>>) {
+        // The inner binding of a class expression is not visible outside of the class
+        let inner_name = class.id.as_ref().map(|id| id.name.clone()).filter(|id| Some(id) != name);
+        let mut before = vec![];
+        let private_names = self.private_names(class, &mut before);
+        let mut fields = vec![];
+        let mut after = vec![];
+        let mut methods: std::vec::Vec<PrivateMethod> = vec![];
+        let mut elements = self.ast.new_vec_with_capacity(class.body.body.len());
+        for element in class.body.body.drain(..) {
+            match (element, name) {
+                (ClassElement::PropertyDefinition(def), Some(name)) if def.r#static => {
+                    let def = def.unbox();
+                    let key = self.field_key(def.key, def.computed, &private_names, &mut before);
+                    let mut value = def.value.unwrap_or_else(|| self.void_zero(def.span));
+                    let home = self.home(def.span, name, true);
+                    PrivateReferences::new(self, &private_names, Some(home))
+                        .visit_expression(&mut value);
+                    StaticThis::new(&self.ast, name, inner_name.as_ref())
+                        .visit_expression(&mut value);
+                    let object = self.identifier(def.span, name);
+                    after.push(self.field_assignment(def.span, object, key, value));
+                }
+                (ClassElement::PropertyDefinition(def), _) if !def.r#static => {
+                    let def = def.unbox();
+                    let key = self.field_key(def.key, def.computed, &private_names, &mut before);
+                    let value = def.value.unwrap_or_else(|| self.void_zero(def.span));
+                    let object = self.ast.this_expression(def.span);
+                    fields.push(self.field_assignment(def.span, object, key, value));
+                }
+                (ClassElement::StaticBlock(block), Some(name)) => {
+                    let StaticBlock { span, body } = block.unbox();
+                    let params = self.ast.formal_parameters(
+                        span,
+                        FormalParameterKind::ArrowFormalParameters,
+                        self.ast.new_vec(),
+                    );
+                    let body = self.ast.function_body(span, self.ast.new_vec(), body);
+                    let arrow = self
+                        .ast
+                        .arrow_expression(span, false, false, false, params, body, None, None);
+                    let callee = self.parenthesized(arrow);
+                    let mut call = self.call(span, callee, []);
+                    let home = self.home(span, name, true);
+                    PrivateReferences::new(self, &private_names, Some(home))
+                        .visit_expression(&mut call);
+                    StaticThis::new(&self.ast, name, inner_name.as_ref())
+                        .visit_expression(&mut call);
+                    after.push(call);
+                }
+                // `#m() {}` becomes `_m2 = function () {}`
+                (ClassElement::MethodDefinition(def), Some(name))
+                    if def.key.is_private_identifier() =>
+                {
+                    let MethodDefinition { span, key, mut value, kind, r#static, .. } = def.unbox();
+                    let PropertyKey::PrivateIdentifier(ident) = key else { unreachable!() };
+                    let home = self.home(span, name, r#static);
+                    PrivateReferences::new(self, &private_names, Some(home))
+                        .visit_moved_function(&mut value);
+                    if inner_name.is_some() {
+                        StaticThis::new(&self.ast, name, inner_name.as_ref())
+                            .visit_function(&mut value);
+                    }
+                    let (property, hint) = match kind {
+                        MethodDefinitionKind::Get => ("get", format!("get_{}", ident.name)),
+                        MethodDefinitionKind::Set => ("set", format!("set_{}", ident.name)),
+                        _ => ("value", ident.name.to_string()),
+                    };
+                    let function = self.temporary(&hint);
+                    before.push(self.assign(span, &function, self.ast.function_expression(value)));
+                    // A getter and a setter are stored together
+                    if let Some(method) =
+                        methods.iter_mut().find(|method| method.name == ident.name)
+                    {
+                        method.properties.push((property, function));
+                    } else {
+                        let properties = vec![(property, function)];
+                        methods.push(PrivateMethod {
+                            span,
+                            r#static,
+                            name: ident.name.clone(),
+                            properties,
+                        });
+                    }
+                }
+                (element, _) => elements.push(element),
+            }
+        }
+        class.body.body = elements;
+
+        // The private methods of an object are stored before its fields are initialized
+        let mut instance_methods = vec![];
+        let mut static_methods = vec![];
+        for PrivateMethod { span, r#static, name: private, properties } in methods {
+            let properties = properties
+                .iter()
+                .map(|(property, function)| (*property, self.identifier(span, function)));
+            let descriptor = self.descriptor(span, properties);
+            let map = private_map(&private_names, &private);
+            if r#static {
+                let Some(name) = name else { continue };
+                let object = self.identifier(span, name);
+                static_methods.push(self.private_field_init(span, object, &map, descriptor));
+            } else {
+                let object = self.ast.this_expression(span);
+                instance_methods.push(self.private_field_init(span, object, &map, descriptor));
+            }
+        }
+        fields.splice(0..0, instance_methods);
+        after.splice(0..0, static_methods);
+
+        if !fields.is_empty() {
+            self.insert_instance_fields(class, fields);
+        }
+        if !private_names.is_empty() {
+            PrivateReferences::new(self, &private_names, None).visit_class_body(&mut class.body);
+        }
+        (before, after)
+    }
+
+    /// `_a = new WeakMap()` stores each private name of `class`, returns the private names
+    /// with their `WeakMap`
+    fn private_names(
+        &mut self,
+        class: &Class<'a>,
+        before: &mut std::vec::Vec<Expression<'a>>,
+    ) -> std::vec::Vec<(Atom, Atom)> {
+        let mut names: std::vec::Vec<(Atom, Atom)> = vec![];
+        for element in &class.body.body {
+            let key = match element {
+                ClassElement::PropertyDefinition(def) => &def.key,
+                ClassElement::MethodDefinition(def) => &def.key,
+                _ => continue,
+            };
+            let PropertyKey::PrivateIdentifier(ident) = key else { continue };
+            if names.iter().any(|(name, _)| *name == ident.name) {
+                continue;
+            }
+            let map = self.temporary(&ident.name);
+            let callee = self.identifier(ident.span, &Atom::from("WeakMap"));
+            let weak_map = self.ast.new_expression(ident.span, callee, self.ast.new_vec(), None);
+            before.push(self.assign(ident.span, &map, weak_map));
+            names.push((ident.name.clone(), map));
+        }
+        names
+    }
+
+    /// The object `super` refers to in the members moved out of the class,
+    /// `Object.getPrototypeOf(A)` for static members and `Object.getPrototypeOf(A.prototype)`
+    /// for instance members
+    fn home(&self, span: Span, name: &Atom, r#static: bool) -> Expression<'a> {
+        let mut class = self.identifier(span, name);
+        if !r#static {
+            class = self.static_member(span, class, "prototype");
+        }
+        let object = self.identifier(span, &Atom::from("Object"));
+        let callee = self.static_member(span, object, "getPrototypeOf");
+        self.call(span, callee, [class])
+    }
+
+    /// `{writable: true, value: 1}`
+    fn descriptor(
+        &self,
+        span: Span,
+        properties: impl IntoIterator<Item = (&'static str, Expression<'a>)>,
+    ) -> Expression<'a> {
+        let properties = properties.into_iter().map(|(name, value)| {
+            let key = IdentifierName { span, name: Atom::from(name) };
+            ObjectProperty::Property(self.ast.property(
+                span,
+                PropertyKind::Init,
+                PropertyKey::Identifier(self.ast.alloc(key)),
+                PropertyValue::Expression(value),
+                false,
+                false,
+                false,
+            ))
+        });
+        let properties = Vec::from_iter_in(properties, self.ast.allocator);
+        self.ast.object_expression(span, properties, None)
+    }
+
+    /// `_classPrivateFieldInit(object, _a, descriptor)`
+    fn private_field_init(
+        &mut self,
+        span: Span,
+        object: Expression<'a>,
+        map: &Atom,
+        descriptor: Expression<'a>,
+    ) -> Expression<'a> {
+        let callee = self.helper(span, Helper::ClassPrivateFieldInit);
+        let map = self.identifier(span, map);
+        self.call(span, callee, [object, map, descriptor])
+    }
+
+    /// A computed key which is not a literal is evaluated once, before the class
+    fn field_key(
+        &mut self,
+        key: PropertyKey<'a>,
+        computed: bool,
+        private_names: &PrivateNames,
+        before: &mut std::vec::Vec<Expression<'a>>,
+    ) -> FieldKey<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => FieldKey::Static(ident.unbox()),
+            PropertyKey::Expression(expr) if !computed || expr.is_literal_expression() => {
+                FieldKey::Computed(expr)
+            }
+            PropertyKey::Expression(expr) => {
+                let span = expr.span();
+                let name = self.temporary(&name_hint(&expr));
+                before.push(self.assign(span, &name, expr));
+                FieldKey::Computed(self.identifier(span, &name))
+            }
+            PropertyKey::PrivateIdentifier(ident) => {
+                FieldKey::Private(private_map(private_names, &ident.name))
+            }
+        }
+    }
+
+    /// `object.key = value`, or `_classPrivateFieldInit(object, _a, {writable: true, value})`
+    fn field_assignment(
+        &mut self,
+        span: Span,
+        object: Expression<'a>,
+        key: FieldKey<'a>,
+        mut value: Expression<'a>,
+    ) -> Expression<'a> {
+        self.unwrap_parenthesized(&mut value);
+        let member = match key {
+            FieldKey::Private(map) => {
+                let writable =
+                    self.ast.literal_boolean_expression(BooleanLiteral { span, value: true });
+                let descriptor = self.descriptor(span, [("writable", writable), ("value", value)]);
+                return self.private_field_init(span, object, &map, descriptor);
+            }
+            FieldKey::Static(property) => {
+                MemberExpression::StaticMemberExpression(StaticMemberExpression {
+                    span,
+                    object,
+                    property,
+                    optional: false,
+                })
+            }
+            FieldKey::Computed(expression) => {
+                MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
+                    span,
+                    object,
+                    expression,
+                    optional: false,
+                })
+            }
+        };
+        let target = SimpleAssignmentTarget::MemberAssignmentTarget(self.ast.alloc(member));
+        self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        )
+    }
+
+    /// Instance fields are assigned when `this` is initialized: at the start of the constructor,
+    /// or after `super()` in a derived class
+    pub(super) fn insert_instance_fields(
+        &mut self,
+        class: &mut Class<'a>,
+        fields: std::vec::Vec<Expression<'a>>,
+    ) {
+        let derived = class.super_class.is_some();
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(def)
+                if def.kind == MethodDefinitionKind::Constructor =>
+            {
+                Some(&mut *def.value)
+            }
+            _ => None,
+        });
+        if let Some(constructor) = constructor {
+            let Some(body) = &mut constructor.body else { return };
+            let index = if derived {
+                body.statements.iter().position(is_super_call).map(|index| index + 1)
+            } else {
+                Some(0)
+            };
+            if let Some(index) = index {
+                let stmts =
+                    fields.into_iter().map(|expr| self.ast.expression_statement(expr.span(), expr));
+                body.statements.splice(index..index, stmts);
+            } else {
+                SuperCallReplacer { ast: &self.ast, fields: &fields }.visit_function_body(body);
+            }
+            return;
+        }
+
+        // `constructor(..._args) { super(..._args) }`
+        let span = Span::new(class.body.span.start, class.body.span.start);
+        let mut params = self.ast.new_vec();
+        let mut statements = self.ast.new_vec();
+        if derived {
+            let args = self.names.generate("args");
+            let rest = self.ast.rest_element(span, self.binding(span, &args));
+            let pattern = self.ast.rest_element_pattern(rest);
+            params.push(self.ast.formal_parameter(span, pattern, None, false, self.ast.new_vec()));
+            let spread = self.ast.spread_element(span, self.identifier(span, &args));
+            let arguments = self.ast.new_vec_single(Argument::SpreadElement(spread));
+            let call =
+                self.ast.call_expression(span, self.ast.super_(span), arguments, false, None);
+            statements.push(self.ast.expression_statement(span, call));
+        }
+        for expr in fields {
+            statements.push(self.ast.expression_statement(expr.span(), expr));
+        }
+        let params =
+            self.ast.formal_parameters(span, FormalParameterKind::UniqueFormalParameters, params);
+        let body = self.ast.function_body(span, self.ast.new_vec(), statements);
+        let value = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let key = IdentifierName { span, name: Atom::from("constructor") };
+        let constructor = MethodDefinition {
+            span,
+            key: PropertyKey::Identifier(self.ast.alloc(key)),
+            value,
+            kind: MethodDefinitionKind::Constructor,
+            computed: false,
+            r#static: false,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        };
+        class.body.body.insert(0, ClassElement::MethodDefinition(self.ast.alloc(constructor)));
+    }
+}
+
+/// The class declared by `stmt`
+pub(super) fn declared_class<'s, 'a>(stmt: &'s mut Statement<'a>) -> Option<&'s mut Class<'a>> {
+    match stmt {
+        Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(class),
+        Statement::ModuleDeclaration(decl) => match &mut decl.kind {
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) => match &mut decl.declaration {
+                Some(Declaration::ClassDeclaration(class)) => Some(class),
+                _ => None,
+            },
+            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => Some(class),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The `WeakMap` storing the private name `name`
+fn private_map(private_names: &PrivateNames, name: &Atom) -> Atom {
+    private_names
+        .iter()
+        .find_map(|(private, map)| (private == name).then(|| map.clone()))
+        .unwrap_or_default()
+}
+
+fn is_super_call(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ExpressionStatement(stmt)
+        if matches!(&stmt.expression, Expression::CallExpression(call)
+            if matches!(call.callee, Expression::Super(_))))
+}
+
+/// Replaces `this` and the inner name of a class expression with the class name in the
+/// initializers of static members, which are moved out of the class. Private methods are moved
+/// too, they keep their `this`.
+struct StaticThis<'a, 'r> {
+    ast: &'r AstBuilder<'a>,
+    class: &'r Atom,
+    inner_name: Option<&'r Atom>,
+    /// Functions and classes have their own `this`
+    in_function: bool,
+}
+
+impl<'a, 'r> StaticThis<'a, 'r> {
+    fn new(ast: &'r AstBuilder<'a>, class: &'r Atom, inner_name: Option<&'r Atom>) -> Self {
+        Self { ast, class, inner_name, in_function: false }
+    }
+}
+
+impl<'a> VisitMut<'a> for StaticThis<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::ThisExpression(this) if !self.in_function => {
+                let ident = IdentifierReference { span: this.span, name: self.class.clone() };
+                *expr = self.ast.identifier_expression(ident);
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        if Some(&ident.name) == self.inner_name {
+            ident.name = self.class.clone();
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        let in_function = std::mem::replace(&mut self.in_function, true);
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        self.in_function = in_function;
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_class_heritage(super_class);
+        }
+        let in_function = std::mem::replace(&mut self.in_function, true);
+        self.visit_class_body(&mut class.body);
+        self.in_function = in_function;
+    }
+}
+
+/// `super()` nested in the constructor of a derived class becomes `(super(), this.a = 1, this)`
+struct SuperCallReplacer<'a, 'r> {
+    ast: &'r AstBuilder<'a>,
+    fields: &'r [Expression<'a>],
+}
+
+impl<'a> VisitMut<'a> for SuperCallReplacer<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        if let Expression::CallExpression(call) = expr
+            && matches!(call.callee, Expression::Super(_))
+        {
+            let span = call.span;
+            let mut expressions = self.ast.new_vec_with_capacity(self.fields.len() + 2);
+            expressions.push(self.ast.move_expression(expr));
+            expressions.extend(self.fields.iter().map(|field| field.clone_in(self.ast.allocator)));
+            expressions.push(self.ast.this_expression(span));
+            let sequence = self.ast.sequence_expression(span, expressions);
+            *expr = self.ast.parenthesized_expression(Span::new(span.start, span.start), sequence);
+        }
+    }
+
+    // Only arrows share the `super` of the constructor
+    fn visit_function(&mut self, _func: &mut Function<'a>) {}
+
+    fn visit_class(&mut self, _class: &mut Class<'a>) {}
+}
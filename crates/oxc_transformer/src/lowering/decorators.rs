@@ -0,0 +1,567 @@
+//! Decorators
+//!
+//! Legacy decorators are applied after the class, like `tsc --experimentalDecorators` does:
+//!
+//! * `class A { @a m() {} }` becomes `class A { m() {} } _decorate([a], A.prototype, "m", null)`
+//! * `class A { @a static b; }` becomes `class A { static b; } _decorate([a], A, "b", void 0)`
+//! * `class A { m(@a b) {} }` becomes `class A { m(b) {} }` and
+//!   `_decorate([_param(0, a)], A.prototype, "m", null)`
+//! * `@a class A {}` becomes `class A {} A = _decorate([a], A)`, the decorators of the
+//!   constructor parameters are applied with the class decorators
+//!
+//! Standard decorators of members are applied by a static block added at the start of the class,
+//! which stores the initializers of the decorated fields:
+//!
+//! * `class A { @a m() {} }` becomes
+//!   `class A { static { [_initProto] = _applyDecs(this, [[[a], 2, "m", false]]) } m() {} }`,
+//!   the first instance field calls `_initProto(this)` before its value is evaluated
+//! * `class A { @a b = 1 }` becomes `class A { static { ... } b = _init_b(this, 1) }`
+//! * `class A { accessor b = 1 }` becomes
+//!   `class A { #_b = 1; get b() { return this.#_b } set b(value) { this.#_b = value } }`
+//! * `@a class A {}` becomes `class A {} A = _applyClassDecs(A, [a])`,
+//!   the class expression `@a class {}` becomes `_applyClassDecs(class {}, [a])`
+//!
+//! Standard decorator expressions are evaluated before the class. Decorated private members are
+//! kept, and the name of a decorated class refers to the undecorated class inside of its body.
+
+use oxc_allocator::{Box, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Atom, CloneIn, GetSpan, Span};
+use oxc_parser::DecoratorFlavor;
+
+use super::{class_fields::declared_class, helpers::Helper, names::name_hint, Lowering};
+
+/// Whether `class` has decorators to lower, or `accessor` properties with standard decorators
+pub fn has_decorators(class: &Class, flavor: DecoratorFlavor) -> bool {
+    !class.decorators.is_empty()
+        || class.body.body.iter().any(|element| match element {
+            ClassElement::MethodDefinition(def) => {
+                !def.key.is_private_identifier()
+                    && (!def.decorators.is_empty()
+                        || def.value.params.items.iter().any(|param| !param.decorators.is_empty()))
+            }
+            ClassElement::PropertyDefinition(def) => {
+                !def.key.is_private_identifier() && !def.decorators.is_empty()
+            }
+            ClassElement::AccessorProperty(accessor) => {
+                flavor == DecoratorFlavor::Standard && !accessor.key.is_private_identifier()
+            }
+            _ => false,
+        })
+}
+
+impl<'a> Lowering<'a> {
+    /// Decorated class declarations are followed by the application of their decorators
+    pub(super) fn lower_decorated_classes(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let Some(flavor) = self.decorators else { return };
+        if !stmts
+            .iter_mut()
+            .any(|stmt| declared_class(stmt).map_or(false, |class| has_decorators(class, flavor)))
+        {
+            return;
+        }
+        let mut body = self.ast.new_vec_with_capacity(stmts.len());
+        for mut stmt in stmts.drain(..) {
+            let Some(class) =
+                declared_class(&mut stmt).filter(|class| has_decorators(class, flavor))
+            else {
+                body.push(stmt);
+                continue;
+            };
+            // `export default @a class {}`
+            let span = Span::new(class.span.start, class.span.start);
+            if flavor == DecoratorFlavor::Legacy || !class.decorators.is_empty() {
+                class.id.get_or_insert_with(|| BindingIdentifier {
+                    span,
+                    name: self.names.generate("default"),
+                });
+            }
+            let name = class.id.as_ref().map(|id| id.name.clone());
+            let (before, after) = match (flavor, name) {
+                (DecoratorFlavor::Legacy, Some(name)) => {
+                    (vec![], self.lower_legacy_decorators(class, &name))
+                }
+                (_, name) => {
+                    let (before, decorators) = self.lower_standard_decorators(class);
+                    let after = decorators.zip(name).map(|(decorators, name)| {
+                        let callee = self.helper(span, Helper::ApplyClassDecs);
+                        let call =
+                            self.call(span, callee, [self.identifier(span, &name), decorators]);
+                        self.assign(span, &name, call)
+                    });
+                    (before, after.into_iter().collect())
+                }
+            };
+            for expr in before {
+                body.push(self.ast.expression_statement(expr.span(), expr));
+            }
+            body.push(stmt);
+            for expr in after {
+                body.push(self.ast.expression_statement(expr.span(), expr));
+            }
+        }
+        *stmts = body;
+    }
+
+    /// The class expression `@a class { @b m() {} }` with standard decorators becomes
+    /// `(_b = b, _applyClassDecs(class { static { ... } m() {} }, [a]))`
+    pub(super) fn lower_decorated_class_expression(
+        &mut self,
+        mut class: Box<'a, Class<'a>>,
+    ) -> Expression<'a> {
+        let span = class.span;
+        let (mut expressions, decorators) = self.lower_standard_decorators(&mut class);
+        let mut class = if self.is_lowered(super::TransformTarget::ES2022)
+            && super::class_fields::has_fields(&class)
+        {
+            self.lower_class_expression(class)
+        } else {
+            self.ast.class_expression(class)
+        };
+        if let Some(decorators) = decorators {
+            self.unwrap_parenthesized(&mut class);
+            let callee = self.helper(span, Helper::ApplyClassDecs);
+            class = self.call(span, callee, [class, decorators]);
+        }
+        if expressions.is_empty() {
+            return class;
+        }
+        expressions.push(class);
+        let expressions = Vec::from_iter_in(expressions, self.ast.allocator);
+        let sequence = self.ast.sequence_expression(span, expressions);
+        self.parenthesized(sequence)
+    }
+
+    /// Removes the legacy decorators of `class`, returns the calls applying them
+    fn lower_legacy_decorators(
+        &mut self,
+        class: &mut Class<'a>,
+        name: &Atom,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let span = Span::new(class.span.start, class.span.start);
+        let mut class_decorators = take_decorators(&mut class.decorators);
+        let mut instance = vec![];
+        let mut statics = vec![];
+        for element in class.body.body.iter_mut() {
+            let (span, computed, r#static, mut decorators, params, descriptor, key) = match element
+            {
+                ClassElement::MethodDefinition(def) => {
+                    let decorators = take_decorators(&mut def.decorators);
+                    let params = self.take_parameter_decorators(&mut def.value.params);
+                    if def.kind == MethodDefinitionKind::Constructor {
+                        class_decorators.extend(params);
+                        continue;
+                    }
+                    let null = self.ast.literal_null_expression(NullLiteral { span: def.span });
+                    (def.span, def.computed, def.r#static, decorators, params, null, &mut def.key)
+                }
+                ClassElement::PropertyDefinition(def) => {
+                    let decorators = take_decorators(&mut def.decorators);
+                    let void = self.void_zero(def.span);
+                    (def.span, def.computed, def.r#static, decorators, vec![], void, &mut def.key)
+                }
+                _ => continue,
+            };
+            decorators.extend(params);
+            if decorators.is_empty() {
+                continue;
+            }
+            let key = self.decorated_key(key, computed);
+            let object = self.identifier(span, name);
+            let target =
+                if r#static { object } else { self.static_member(span, object, "prototype") };
+            let callee = self.helper(span, Helper::Decorate);
+            let decorators = self.array(span, decorators);
+            let call = self.call(span, callee, [decorators, target, key, descriptor]);
+            if r#static {
+                statics.push(call);
+            } else {
+                instance.push(call);
+            }
+        }
+        instance.extend(statics);
+        if !class_decorators.is_empty() {
+            let callee = self.helper(span, Helper::Decorate);
+            let decorators = self.array(span, class_decorators);
+            let call = self.call(span, callee, [decorators, self.identifier(span, name)]);
+            instance.push(self.assign(span, name, call));
+        }
+        instance
+    }
+
+    /// Adds a static block applying the standard decorators of the members of `class`,
+    /// returns the expressions evaluated before the class and the decorators of the class
+    fn lower_standard_decorators(
+        &mut self,
+        class: &mut Class<'a>,
+    ) -> (std::vec::Vec<Expression<'a>>, Option<Expression<'a>>) {
+        let span = Span::new(class.span.start, class.span.start);
+        let mut before = vec![];
+        let class_decorators = self.evaluate_decorators(&mut class.decorators, &mut before);
+        let mut entries = vec![];
+        let mut initializers = vec![];
+        let mut decorated_instance = false;
+        let mut elements = self.ast.new_vec_with_capacity(class.body.body.len());
+        for element in class.body.body.drain(..) {
+            match element {
+                ClassElement::MethodDefinition(mut def)
+                    if !def.decorators.is_empty() && !def.key.is_private_identifier() =>
+                {
+                    let kind = match def.kind {
+                        MethodDefinitionKind::Get => 3,
+                        MethodDefinitionKind::Set => 4,
+                        _ => 2,
+                    };
+                    let decorators = self.evaluate_decorators(&mut def.decorators, &mut before);
+                    let computed = def.computed;
+                    let key = self.decorated_key(&mut def.key, computed);
+                    entries.push(self.decoration(def.span, decorators, kind, key, def.r#static));
+                    decorated_instance |= !def.r#static;
+                    elements.push(ClassElement::MethodDefinition(def));
+                }
+                ClassElement::PropertyDefinition(mut def)
+                    if !def.decorators.is_empty() && !def.key.is_private_identifier() =>
+                {
+                    let decorators = self.evaluate_decorators(&mut def.decorators, &mut before);
+                    let initializer = self.temporary(&format!("init_{}", key_hint(&def.key)));
+                    let computed = def.computed;
+                    let key = self.decorated_key(&mut def.key, computed);
+                    entries.push(self.decoration(def.span, decorators, 0, key, def.r#static));
+                    decorated_instance |= !def.r#static;
+                    let value = def.value.take().unwrap_or_else(|| self.void_zero(def.span));
+                    def.value = Some(self.initialize(def.span, &initializer, value));
+                    initializers.push(initializer);
+                    elements.push(ClassElement::PropertyDefinition(def));
+                }
+                ClassElement::AccessorProperty(accessor)
+                    if !accessor.key.is_private_identifier() =>
+                {
+                    let AccessorProperty {
+                        span,
+                        mut key,
+                        mut value,
+                        computed,
+                        r#static,
+                        mut decorators,
+                    } = accessor.unbox();
+                    let hint = key_hint(&key);
+                    let storage = self.names.generate(&hint);
+                    let name = self.decorated_key(&mut key, computed);
+                    if !decorators.is_empty() {
+                        let decorators = self.evaluate_decorators(&mut decorators, &mut before);
+                        let initializer = self.temporary(&format!("init_{hint}"));
+                        let key = name.clone_in(self.ast.allocator);
+                        entries.push(self.decoration(span, decorators, 1, key, r#static));
+                        decorated_instance |= !r#static;
+                        let init = value.unwrap_or_else(|| self.void_zero(span));
+                        value = Some(self.initialize(span, &initializer, init));
+                        initializers.push(initializer);
+                    }
+                    let setter_key = if computed {
+                        PropertyKey::Expression(name)
+                    } else {
+                        key.clone_in(self.ast.allocator)
+                    };
+                    elements.extend(self.accessor_storage(
+                        span, key, setter_key, computed, r#static, &storage, value,
+                    ));
+                }
+                element => elements.push(element),
+            }
+        }
+        class.body.body = elements;
+        if !entries.is_empty() {
+            self.apply_member_decorators(class, entries, &initializers, decorated_instance);
+        }
+
+        let class_decorators =
+            (!class_decorators.is_empty()).then(|| self.array(span, class_decorators));
+        (before, class_decorators)
+    }
+
+    /// `static { [_init_a, _initProto] = _applyDecs(this, [...]) }` at the start of the class,
+    /// the initializers of the decorated fields and instance members are assigned
+    fn apply_member_decorators(
+        &mut self,
+        class: &mut Class<'a>,
+        entries: std::vec::Vec<Expression<'a>>,
+        initializers: &[Atom],
+        decorated_instance: bool,
+    ) {
+        let span = Span::new(class.span.start, class.span.start);
+        let init_proto = decorated_instance.then(|| self.temporary("initProto"));
+        let callee = self.helper(span, Helper::ApplyDecs);
+        let entries = self.array(span, entries);
+        let mut expr = self.call(span, callee, [self.ast.this_expression(span), entries]);
+        if !initializers.is_empty() || init_proto.is_some() {
+            let targets = initializers.iter().chain(&init_proto).map(|name| {
+                let target =
+                    AssignmentTarget::SimpleAssignmentTarget(self.identifier_target(span, name));
+                Some(AssignmentTargetMaybeDefault::AssignmentTarget(self.ast.alloc(target)))
+            });
+            let pattern = ArrayAssignmentTarget {
+                span,
+                elements: Vec::from_iter_in(targets, self.ast.allocator),
+                rest: None,
+                trailing_comma: None,
+            };
+            let target = AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ArrayAssignmentTarget(self.ast.alloc(pattern)),
+            );
+            expr = self.ast.assignment_expression(span, AssignmentOperator::Assign, target, expr);
+        }
+        let block = self.ast.new_vec_single(self.ast.expression_statement(span, expr));
+        class.body.body.insert(0, self.ast.static_block(span, block));
+        if let Some(init_proto) = init_proto {
+            self.initialize_proto(class, &init_proto);
+        }
+    }
+
+    /// `_param(0, a)` for each decorator of the parameters, which are removed
+    fn take_parameter_decorators(
+        &mut self,
+        params: &mut FormalParameters<'a>,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let mut decorators = vec![];
+        for (index, param) in params.items.iter_mut().enumerate() {
+            for decorator in take_decorators(&mut param.decorators) {
+                let span = decorator.span();
+                let callee = self.helper(span, Helper::Param);
+                let index = self.number_literal(span, index);
+                decorators.push(self.call(span, callee, [index, decorator]));
+            }
+        }
+        decorators
+    }
+
+    /// Standard decorators are evaluated before the class, the decorators which are not
+    /// identifiers are stored in temporary variables
+    fn evaluate_decorators(
+        &mut self,
+        decorators: &mut Vec<'a, Decorator<'a>>,
+        before: &mut std::vec::Vec<Expression<'a>>,
+    ) -> std::vec::Vec<Expression<'a>> {
+        let decorators = take_decorators(decorators);
+        decorators
+            .into_iter()
+            .map(|expr| {
+                if matches!(expr, Expression::Identifier(_)) {
+                    return expr;
+                }
+                let span = expr.span();
+                let name = self.temporary(&name_hint(&expr));
+                before.push(self.assign(span, &name, expr));
+                self.identifier(span, &name)
+            })
+            .collect()
+    }
+
+    /// The name of a decorated member: `"a"` for the key `a`, and `_a` for the computed key
+    /// `[a()]`, which becomes `[_a = a()]`
+    fn decorated_key(&mut self, key: &mut PropertyKey<'a>, computed: bool) -> Expression<'a> {
+        match key {
+            PropertyKey::Identifier(ident) => self.string_literal(ident.span, &ident.name),
+            PropertyKey::Expression(expr) if !computed || expr.is_literal_expression() => {
+                expr.clone_in(self.ast.allocator)
+            }
+            PropertyKey::Expression(expr) => {
+                let span = expr.span();
+                let name = self.temporary(&name_hint(expr));
+                let value = self.ast.move_expression(expr);
+                *expr = self.assign(span, &name, value);
+                self.identifier(span, &name)
+            }
+            PropertyKey::PrivateIdentifier(_) => unreachable!("decorated private members are kept"),
+        }
+    }
+
+    /// `[[a, b], kind, name, isStatic]`, the kind is 0 for a field, 1 for an accessor,
+    /// 2 for a method, 3 for a getter and 4 for a setter
+    fn decoration(
+        &self,
+        span: Span,
+        decorators: std::vec::Vec<Expression<'a>>,
+        kind: usize,
+        name: Expression<'a>,
+        r#static: bool,
+    ) -> Expression<'a> {
+        let decorators = self.array(span, decorators);
+        let kind = self.number_literal(span, kind);
+        let r#static =
+            self.ast.literal_boolean_expression(BooleanLiteral { span, value: r#static });
+        self.array(span, [decorators, kind, name, r#static])
+    }
+
+    /// `_init_a(this, value)`
+    fn initialize(&self, span: Span, initializer: &Atom, value: Expression<'a>) -> Expression<'a> {
+        let callee = self.identifier(span, initializer);
+        self.call(span, callee, [self.ast.this_expression(span), value])
+    }
+
+    /// The initializers added by the decorators of instance members run before the first
+    /// instance field is initialized: `a = 1` becomes `a = (_initProto(this), 1)`
+    fn initialize_proto(&mut self, class: &mut Class<'a>, init_proto: &Atom) {
+        let span = Span::new(class.body.span.start, class.body.span.start);
+        let call =
+            self.call(span, self.identifier(span, init_proto), [self.ast.this_expression(span)]);
+        let field = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::PropertyDefinition(def) if !def.r#static => Some(def),
+            _ => None,
+        });
+        let Some(field) = field else {
+            self.insert_instance_fields(class, vec![call]);
+            return;
+        };
+        let value = field.value.take().unwrap_or_else(|| self.void_zero(field.span));
+        let expressions = Vec::from_iter_in([call, value], self.ast.allocator);
+        let sequence = self.ast.sequence_expression(field.span, expressions);
+        field.value = Some(self.parenthesized(sequence));
+    }
+
+    /// `accessor a = 1` becomes the private field `#_a = 1` with a getter and a setter
+    #[allow(clippy::too_many_arguments)]
+    fn accessor_storage(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        setter_key: PropertyKey<'a>,
+        computed: bool,
+        r#static: bool,
+        storage: &Atom,
+        value: Option<Expression<'a>>,
+    ) -> [ClassElement<'a>; 3] {
+        let field = PropertyDefinition {
+            span,
+            key: PropertyKey::PrivateIdentifier(
+                self.ast.alloc(PrivateIdentifier { span, name: storage.clone() }),
+            ),
+            value,
+            computed: false,
+            r#static,
+            declare: false,
+            r#override: false,
+            optional: false,
+            definite: false,
+            readonly: false,
+            type_annotation: None,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        };
+        let storage = || {
+            let field = PrivateIdentifier { span, name: storage.clone() };
+            self.ast.private_field_expression(span, self.ast.this_expression(span), field, false)
+        };
+
+        // `get a() { return this.#_a }`
+        let getter = self.ast.return_statement(span, Some(storage()));
+        let getter =
+            self.method(span, key, computed, r#static, MethodDefinitionKind::Get, None, getter);
+
+        // `set a(value) { this.#_a = value }`
+        let value = Atom::from("value");
+        let target = match storage() {
+            Expression::MemberExpression(member) => {
+                SimpleAssignmentTarget::MemberAssignmentTarget(member)
+            }
+            _ => unreachable!(),
+        };
+        let assign = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            self.identifier(span, &value),
+        );
+        let setter = self.ast.expression_statement(span, assign);
+        let setter = self.method(
+            span,
+            setter_key,
+            computed,
+            r#static,
+            MethodDefinitionKind::Set,
+            Some(&value),
+            setter,
+        );
+
+        [ClassElement::PropertyDefinition(self.ast.alloc(field)), getter, setter]
+    }
+
+    /// A getter or a setter with a single statement
+    #[allow(clippy::too_many_arguments)]
+    fn method(
+        &self,
+        span: Span,
+        key: PropertyKey<'a>,
+        computed: bool,
+        r#static: bool,
+        kind: MethodDefinitionKind,
+        param: Option<&Atom>,
+        stmt: Statement<'a>,
+    ) -> ClassElement<'a> {
+        let mut params = self.ast.new_vec();
+        if let Some(param) = param {
+            let pattern = self.binding(span, param);
+            params.push(self.ast.formal_parameter(span, pattern, None, false, self.ast.new_vec()));
+        }
+        let params =
+            self.ast.formal_parameters(span, FormalParameterKind::UniqueFormalParameters, params);
+        let body = self.ast.function_body(span, self.ast.new_vec(), self.ast.new_vec_single(stmt));
+        let value = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let method = MethodDefinition {
+            span,
+            key,
+            value,
+            kind,
+            computed,
+            r#static,
+            r#override: false,
+            optional: false,
+            accessibility: None,
+            decorators: self.ast.new_vec(),
+        };
+        ClassElement::MethodDefinition(self.ast.alloc(method))
+    }
+
+    fn array(
+        &self,
+        span: Span,
+        elements: impl IntoIterator<Item = Expression<'a>>,
+    ) -> Expression<'a> {
+        let elements = Vec::from_iter_in(
+            elements.into_iter().map(|expr| Some(Argument::Expression(expr))),
+            self.ast.allocator,
+        );
+        self.ast.array_expression(span, elements, None)
+    }
+}
+
+/// The expressions of `decorators`, which are removed
+fn take_decorators<'a>(decorators: &mut Vec<'a, Decorator<'a>>) -> std::vec::Vec<Expression<'a>> {
+    decorators
+        .drain(..)
+        .map(|decorator| match decorator.expression {
+            Expression::ParenthesizedExpression(paren) => paren.unbox().expression,
+            expr => expr,
+        })
+        .collect()
+}
+
+/// Hint for the names of the variables of a member: `_init_a` for `a`
+fn key_hint(key: &PropertyKey) -> String {
+    match (key.static_name(), key) {
+        (Some(name), _) => name.to_string(),
+        (None, PropertyKey::Expression(expr)) => name_hint(expr),
+        (None, _) => "ref".to_string(),
+    }
+}
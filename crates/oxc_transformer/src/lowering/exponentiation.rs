@@ -0,0 +1,46 @@
+//! ES2016: exponentiation operator
+//!
+//! * `a ** b` becomes `Math.pow(a, b)`
+//! * `a.b **= c` becomes `a.b = Math.pow(a.b, c)`
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Atom, Span};
+
+use super::{simple_target_to_expression, Lowering};
+
+impl<'a> Lowering<'a> {
+    pub(super) fn lower_exponentiation(&self, expr: BinaryExpression<'a>) -> Expression<'a> {
+        self.math_pow(expr.span, expr.left, expr.right)
+    }
+
+    pub(super) fn lower_exponentiation_assignment(
+        &mut self,
+        expr: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let span = expr.span;
+        let AssignmentTarget::SimpleAssignmentTarget(target) = expr.left else {
+            unreachable!("`**=` is assigned to a simple target");
+        };
+        let (target, read) = self.memoize_target(target);
+        let value = self.math_pow(span, simple_target_to_expression(read), expr.right);
+        let assign = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        );
+        self.parenthesized(assign)
+    }
+
+    /// `Math.pow(base, exponent)`
+    fn math_pow(
+        &self,
+        span: Span,
+        base: Expression<'a>,
+        exponent: Expression<'a>,
+    ) -> Expression<'a> {
+        let math = self.identifier(span, &Atom::from("Math"));
+        let callee = self.static_member(span, math, "pow");
+        self.call(span, callee, [base, exponent])
+    }
+}
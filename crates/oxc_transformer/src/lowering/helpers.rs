@@ -0,0 +1,397 @@
+//! Runtime helpers, declared at the top of the file when they are used
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, Atom, SourceType, Span};
+use oxc_parser::Parser;
+
+use super::Lowering;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Helper {
+    /// `_asyncToGenerator(this, arguments, function* () {})` runs the generator,
+    /// resuming it with the value of each promise it yields
+    AsyncToGenerator,
+    /// `_objectWithoutProperties(source, ['a', 'b'])` copies the own enumerable properties
+    /// of `source` except the excluded ones
+    ObjectWithoutProperties,
+    /// `_decorate([a, b], A.prototype, "m", null)` applies legacy decorators to a member,
+    /// `_decorate([a, b], A)` to a class, like TypeScript's `__decorate`
+    Decorate,
+    /// `_param(0, a)` decorates the first parameter of a method, like TypeScript's `__param`
+    Param,
+    /// `_applyDecs(A, [[[a, b], 2, "m", false]])` applies standard decorators to the members of
+    /// a class, returns the initializers of the decorated fields and the instance initializer
+    ApplyDecs,
+    /// `_applyClassDecs(A, [a, b])` applies standard decorators to a class, returns the new class
+    ApplyClassDecs,
+    /// `_classPrivateFieldInit(this, _a, {writable: true, value: 1})` stores the descriptor of
+    /// a private member of an object in the `WeakMap` of its name
+    ClassPrivateFieldInit,
+    /// `_classPrivateFieldGet(o, _a)` reads the private member of `o` stored in `_a`
+    ClassPrivateFieldGet,
+    /// `_classPrivateFieldSet(o, _a, 1)` writes the private member of `o` stored in `_a`
+    ClassPrivateFieldSet,
+    /// `_superPropSet(Object.getPrototypeOf(A), "a", this, 1)` assigns `super.a` outside of
+    /// the class
+    SuperPropSet,
+    /// `_toSetter(_classPrivateFieldSet, [o, _a])._` is an assignment target calling the setter
+    /// with the arguments and the assigned value
+    ToSetter,
+}
+
+impl Helper {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::AsyncToGenerator => "asyncToGenerator",
+            Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::Decorate => "decorate",
+            Self::Param => "param",
+            Self::ApplyDecs => "applyDecs",
+            Self::ApplyClassDecs => "applyClassDecs",
+            Self::ClassPrivateFieldInit => "classPrivateFieldInit",
+            Self::ClassPrivateFieldGet => "classPrivateFieldGet",
+            Self::ClassPrivateFieldSet => "classPrivateFieldSet",
+            Self::SuperPropSet => "superPropSet",
+            Self::ToSetter => "toSetter",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Self::AsyncToGenerator => ASYNC_TO_GENERATOR,
+            Self::ObjectWithoutProperties => OBJECT_WITHOUT_PROPERTIES,
+            Self::Decorate => DECORATE,
+            Self::Param => PARAM,
+            Self::ApplyDecs => APPLY_DECS,
+            Self::ApplyClassDecs => APPLY_CLASS_DECS,
+            Self::ClassPrivateFieldInit => CLASS_PRIVATE_FIELD_INIT,
+            Self::ClassPrivateFieldGet => CLASS_PRIVATE_FIELD_GET,
+            Self::ClassPrivateFieldSet => CLASS_PRIVATE_FIELD_SET,
+            Self::SuperPropSet => SUPER_PROP_SET,
+            Self::ToSetter => TO_SETTER,
+        }
+    }
+}
+
+const ASYNC_TO_GENERATOR: &str = r#"function asyncToGenerator(self, args, body) {
+  return new Promise(function (resolve, reject) {
+    var generator = body.apply(self, args);
+    function step(method, arg) {
+      try {
+        var result = generator[method](arg);
+      } catch (error) {
+        reject(error);
+        return;
+      }
+      if (result.done) {
+        resolve(result.value);
+      } else {
+        Promise.resolve(result.value).then(next, throwError);
+      }
+    }
+    function next(value) {
+      step("next", value);
+    }
+    function throwError(error) {
+      step("throw", error);
+    }
+    next();
+  });
+}"#;
+
+const OBJECT_WITHOUT_PROPERTIES: &str = r#"function objectWithoutProperties(source, excluded) {
+  if (source == null) throw new TypeError("Cannot destructure " + source);
+  excluded = excluded.map(function (key) {
+    return typeof key === "symbol" ? key : String(key);
+  });
+  var keys = Object.keys(source);
+  if (Object.getOwnPropertySymbols) {
+    keys = keys.concat(Object.getOwnPropertySymbols(source).filter(function (key) {
+      return Object.prototype.propertyIsEnumerable.call(source, key);
+    }));
+  }
+  var target = {};
+  for (var i = 0; i < keys.length; i++) {
+    if (excluded.indexOf(keys[i]) < 0) target[keys[i]] = source[keys[i]];
+  }
+  return target;
+}"#;
+
+const DECORATE: &str = r#"function decorate(decorators, target, key, desc) {
+  var c = arguments.length;
+  var r = c < 3 ? target : desc === null ? (desc = Object.getOwnPropertyDescriptor(target, key)) : desc;
+  for (var i = decorators.length - 1; i >= 0; i--) {
+    var d = decorators[i];
+    if (d) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+  }
+  if (c > 3 && r) Object.defineProperty(target, key, r);
+  return r;
+}"#;
+
+const PARAM: &str = r#"function param(index, decorator) {
+  return function (target, key) {
+    decorator(target, key, index);
+  };
+}"#;
+
+const APPLY_DECS: &str = r#"function applyDecs(Class, elements) {
+  var kinds = ["field", "accessor", "method", "getter", "setter"];
+  var inits = [];
+  var protoInitializers = [];
+  var staticInitializers = [];
+  function decorate(decorator, value, kind, name, isStatic) {
+    var done = false;
+    var access = {
+      has: function (object) {
+        return name in object;
+      },
+    };
+    if (kind !== 4) {
+      access.get = function (object) {
+        return object[name];
+      };
+    }
+    if (kind < 2 || kind === 4) {
+      access.set = function (object, value) {
+        object[name] = value;
+      };
+    }
+    var context = {
+      kind: kinds[kind],
+      name: name,
+      static: isStatic,
+      private: false,
+      access: access,
+      addInitializer: function (initializer) {
+        if (done) throw new TypeError("addInitializer can only be called while the decorator runs");
+        if (typeof initializer !== "function") throw new TypeError("An initializer must be a function");
+        (isStatic ? staticInitializers : protoInitializers).push(initializer);
+      },
+    };
+    try {
+      return decorator(value, context);
+    } finally {
+      done = true;
+    }
+  }
+  function initialize(initializers) {
+    return function (instance, value) {
+      for (var i = 0; i < initializers.length; i++) value = initializers[i].call(instance, value);
+      return value;
+    };
+  }
+  for (var i = 0; i < elements.length; i++) {
+    var decorators = elements[i][0];
+    var kind = elements[i][1];
+    var name = elements[i][2];
+    var isStatic = elements[i][3];
+    var target = isStatic ? Class : Class.prototype;
+    var desc = kind > 0 ? Object.getOwnPropertyDescriptor(target, name) : undefined;
+    var value = kind === 1 ? { get: desc.get, set: desc.set } : kind === 2 ? desc.value : kind === 3 ? desc.get : kind === 4 ? desc.set : undefined;
+    var initializers = [];
+    for (var j = decorators.length - 1; j >= 0; j--) {
+      var result = decorate(decorators[j], value, kind, name, isStatic);
+      if (result === undefined) continue;
+      if (kind === 0) {
+        if (typeof result !== "function") throw new TypeError("A field decorator must return a function or undefined");
+        initializers.push(result);
+      } else if (kind === 1) {
+        if (typeof result !== "object" || result === null) throw new TypeError("An accessor decorator must return an object or undefined");
+        value = { get: result.get !== undefined ? result.get : value.get, set: result.set !== undefined ? result.set : value.set };
+        if (result.init !== undefined) initializers.push(result.init);
+      } else {
+        if (typeof result !== "function") throw new TypeError("A method decorator must return a function or undefined");
+        value = result;
+      }
+    }
+    if (kind < 2) inits.push(initialize(initializers));
+    if (kind === 1) {
+      Object.defineProperty(target, name, { get: value.get, set: value.set, configurable: true });
+    } else if (kind > 1) {
+      desc[kind === 2 ? "value" : kind === 3 ? "get" : "set"] = value;
+      Object.defineProperty(target, name, desc);
+    }
+  }
+  for (var k = 0; k < staticInitializers.length; k++) staticInitializers[k].call(Class);
+  inits.push(function (instance) {
+    for (var i = 0; i < protoInitializers.length; i++) protoInitializers[i].call(instance);
+  });
+  return inits;
+}"#;
+
+const APPLY_CLASS_DECS: &str = r#"function applyClassDecs(Class, decorators) {
+  var name = Class.name;
+  var initializers = [];
+  function decorate(decorator, value) {
+    var done = false;
+    try {
+      return decorator(value, {
+        kind: "class",
+        name: name,
+        addInitializer: function (initializer) {
+          if (done) throw new TypeError("addInitializer can only be called while the decorator runs");
+          if (typeof initializer !== "function") throw new TypeError("An initializer must be a function");
+          initializers.push(initializer);
+        },
+      });
+    } finally {
+      done = true;
+    }
+  }
+  for (var i = decorators.length - 1; i >= 0; i--) {
+    var result = decorate(decorators[i], Class);
+    if (result === undefined) continue;
+    if (typeof result !== "function") throw new TypeError("A class decorator must return a function or undefined");
+    Class = result;
+  }
+  for (var j = 0; j < initializers.length; j++) initializers[j].call(Class);
+  return Class;
+}"#;
+
+const CLASS_PRIVATE_FIELD_INIT: &str = r#"function classPrivateFieldInit(receiver, map, descriptor) {
+  if (map.has(receiver)) throw new TypeError("Cannot initialize a private member twice on the same object");
+  map.set(receiver, descriptor);
+}"#;
+
+const CLASS_PRIVATE_FIELD_GET: &str = r#"function classPrivateFieldGet(receiver, map) {
+  if (!map.has(receiver)) throw new TypeError("Cannot read a private member from an object whose class did not declare it");
+  var descriptor = map.get(receiver);
+  if ("value" in descriptor) return descriptor.value;
+  if (!descriptor.get) throw new TypeError("Cannot read a private accessor without a getter");
+  return descriptor.get.call(receiver);
+}"#;
+
+const CLASS_PRIVATE_FIELD_SET: &str = r#"function classPrivateFieldSet(receiver, map, value) {
+  if (!map.has(receiver)) throw new TypeError("Cannot write a private member to an object whose class did not declare it");
+  var descriptor = map.get(receiver);
+  if (descriptor.writable) {
+    descriptor.value = value;
+  } else if (descriptor.set) {
+    descriptor.set.call(receiver, value);
+  } else {
+    throw new TypeError("value" in descriptor ? "Cannot assign to a private method" : "Cannot write a private accessor without a setter");
+  }
+  return value;
+}"#;
+
+const SUPER_PROP_SET: &str = r#"function superPropSet(home, key, receiver, value) {
+  if (!Reflect.set(home, key, value, receiver)) throw new TypeError("Cannot assign to read only property " + String(key));
+  return value;
+}"#;
+
+const TO_SETTER: &str = r"function toSetter(set, args) {
+  return {
+    set _(value) {
+      set.apply(void 0, args.concat([value]));
+    }
+  };
+}";
+
+impl<'a> Lowering<'a> {
+    /// Reference to `helper`, which is declared when the file has been lowered
+    pub(super) fn helper(&mut self, span: Span, helper: Helper) -> Expression<'a> {
+        let name = if let Some((_, name)) = self.helpers.iter().find(|(h, _)| *h == helper) {
+            name.clone()
+        } else {
+            let name = self.names.generate_top_level(helper.name());
+            self.helpers.push((helper, name.clone()));
+            name
+        };
+        self.identifier(span, &name)
+    }
+
+    /// `function _name(...) {...}`, parsed from the source of the helper
+    pub(super) fn helper_declaration(&self, helper: Helper, name: &Atom) -> Statement<'a> {
+        let ret = Parser::new(self.ast.allocator, helper.source(), SourceType::default()).parse();
+        debug_assert!(ret.errors.is_empty());
+        let mut program = ret.program;
+        ClearSpans.visit_program(&mut program);
+        let mut stmt = program.body.pop().unwrap();
+        if let Statement::Declaration(Declaration::FunctionDeclaration(func)) = &mut stmt {
+            func.id = Some(BindingIdentifier { span: Span::default(), name: name.clone() });
+        }
+        stmt
+    }
+}
+
+/// Clears the spans of the helper's statements and expressions, which are offsets in the source
+/// of the helper. The printer would place the comments and source mappings of the file in them.
+struct ClearSpans;
+
+impl<'a> VisitMut<'a> for ClearSpans {
+    fn visit_statement(&mut self, stmt: &mut Statement<'a>) {
+        let span = match stmt {
+            Statement::BreakStatement(stmt) => Some(&mut stmt.span),
+            Statement::ContinueStatement(stmt) => Some(&mut stmt.span),
+            Statement::DebuggerStatement(stmt) => Some(&mut stmt.span),
+            Statement::DoWhileStatement(stmt) => Some(&mut stmt.span),
+            Statement::EmptyStatement(stmt) => Some(&mut stmt.span),
+            Statement::ExpressionStatement(stmt) => Some(&mut stmt.span),
+            Statement::ForInStatement(stmt) => Some(&mut stmt.span),
+            Statement::ForOfStatement(stmt) => Some(&mut stmt.span),
+            Statement::ForStatement(stmt) => Some(&mut stmt.span),
+            Statement::IfStatement(stmt) => Some(&mut stmt.span),
+            Statement::LabeledStatement(stmt) => Some(&mut stmt.span),
+            Statement::ReturnStatement(stmt) => Some(&mut stmt.span),
+            Statement::SwitchStatement(stmt) => Some(&mut stmt.span),
+            Statement::ThrowStatement(stmt) => Some(&mut stmt.span),
+            Statement::TryStatement(stmt) => Some(&mut stmt.span),
+            Statement::WhileStatement(stmt) => Some(&mut stmt.span),
+            Statement::WithStatement(stmt) => Some(&mut stmt.span),
+            Statement::Declaration(Declaration::VariableDeclaration(decl)) => Some(&mut decl.span),
+            Statement::Declaration(Declaration::FunctionDeclaration(func)) => Some(&mut func.span),
+            Statement::Declaration(Declaration::ClassDeclaration(class)) => Some(&mut class.span),
+            // Block statements are cleared by `visit_block_statement`
+            _ => None,
+        };
+        if let Some(span) = span {
+            *span = Span::default();
+        }
+        self.visit_statement_match(stmt);
+    }
+
+    fn visit_block_statement(&mut self, stmt: &mut BlockStatement<'a>) {
+        stmt.span = Span::default();
+        self.visit_statements(&mut stmt.body);
+    }
+
+    fn visit_function_body(&mut self, body: &mut FunctionBody<'a>) {
+        body.span = Span::default();
+        self.visit_statements(&mut body.statements);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let span = match expr {
+            Expression::BooleanLiteral(lit) => Some(&mut lit.span),
+            Expression::NullLiteral(lit) => Some(&mut lit.span),
+            Expression::NumberLiteral(lit) => Some(&mut lit.span),
+            Expression::StringLiteral(lit) => Some(&mut lit.span),
+            Expression::Identifier(ident) => Some(&mut ident.span),
+            Expression::ArrayExpression(expr) => Some(&mut expr.span),
+            Expression::AssignmentExpression(expr) => Some(&mut expr.span),
+            Expression::BinaryExpression(expr) => Some(&mut expr.span),
+            Expression::CallExpression(expr) => Some(&mut expr.span),
+            Expression::ConditionalExpression(expr) => Some(&mut expr.span),
+            Expression::FunctionExpression(func) => Some(&mut func.span),
+            Expression::LogicalExpression(expr) => Some(&mut expr.span),
+            Expression::MemberExpression(member) => Some(match &mut **member {
+                MemberExpression::ComputedMemberExpression(expr) => &mut expr.span,
+                MemberExpression::StaticMemberExpression(expr) => &mut expr.span,
+                MemberExpression::PrivateFieldExpression(expr) => &mut expr.span,
+            }),
+            Expression::NewExpression(expr) => Some(&mut expr.span),
+            Expression::ObjectExpression(expr) => Some(&mut expr.span),
+            Expression::ParenthesizedExpression(expr) => Some(&mut expr.span),
+            Expression::SequenceExpression(expr) => Some(&mut expr.span),
+            Expression::ThisExpression(expr) => Some(&mut expr.span),
+            Expression::UnaryExpression(expr) => Some(&mut expr.span),
+            Expression::UpdateExpression(expr) => Some(&mut expr.span),
+            // Not used by the helpers
+            _ => None,
+        };
+        if let Some(span) = span {
+            *span = Span::default();
+        }
+        self.visit_expression_match(expr);
+    }
+}
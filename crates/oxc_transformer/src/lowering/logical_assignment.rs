@@ -0,0 +1,43 @@
+//! ES2021: logical assignment operators
+//!
+//! * `a ||= b` becomes `a || (a = b)`, `&&=` and `??=` likewise
+//! * `a().b ||= c` becomes `(_a = a()).b || (_a.b = c)`
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::ast::*;
+
+use super::{simple_target_to_expression, Lowering, TransformTarget};
+
+impl<'a> Lowering<'a> {
+    pub(super) fn lower_logical_assignment(
+        &mut self,
+        expr: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let span = expr.span;
+        let AssignmentTarget::SimpleAssignmentTarget(target) = expr.left else {
+            unreachable!("logical assignments are assigned to a simple target");
+        };
+        let operator = match expr.operator {
+            AssignmentOperator::LogicalAnd => LogicalOperator::And,
+            AssignmentOperator::LogicalOr => LogicalOperator::Or,
+            _ => LogicalOperator::Coalesce,
+        };
+        let (read, write) = self.memoize_target(target);
+        let assign = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(write),
+            expr.right,
+        );
+        let logical = LogicalExpression {
+            span,
+            left: simple_target_to_expression(read),
+            operator,
+            right: self.parenthesized(assign),
+        };
+        if operator == LogicalOperator::Coalesce && self.is_lowered(TransformTarget::ES2020) {
+            return self.lower_nullish_coalescing(logical);
+        }
+        self.parenthesized(Expression::LogicalExpression(self.ast.alloc(logical)))
+    }
+}
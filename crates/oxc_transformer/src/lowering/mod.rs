@@ -0,0 +1,605 @@
+//! Syntax lowering
+//!
+//! Rewrites the syntax newer than the [`TransformTarget`] with older syntax,
+//! like `@babel/preset-env` and `tsc --target` do:
+//!
+//! * ES2022: class fields, static blocks and private members, see [`class_fields`] and
+//!   [`private_members`]
+//! * ES2021: logical assignment, `a ||= b` becomes `a || (a = b)`
+//! * ES2020: nullish coalescing and optional chaining, see [`nullish_coalescing`] and
+//!   [`optional_chaining`]
+//! * ES2019: optional catch binding, `catch {}` becomes `catch (_unused) {}`
+//! * ES2018: object rest and spread properties, see [`object_rest_spread`]
+//! * ES2017: async functions and arrows, see [`async_functions`]
+//! * ES2016: exponentiation, `a ** b` becomes `Math.pow(a, b)`
+//!
+//! Decorators are lowered when their flavour is set with [`Lowering::decorators`], whatever the
+//! target, see [`decorators`].
+//!
+//! Nodes are lowered after their children. The code produced for a feature is lowered again
+//! when it uses another feature newer than the target: `a ??= b` becomes `a ?? (a = b)`,
+//! then a conditional expression.
+//!
+//! An expression which is evaluated once but used twice is stored in a temporary variable,
+//! declared with `var` at the top of the enclosing function. Temporary variables get names
+//! which do not clash with the bindings in scope of the function, helpers names which are not
+//! bound anywhere in the file, see [`names`].
+//!
+//! Async generators, `for await` and `super` in async methods are kept.
+
+mod async_functions;
+mod class_fields;
+mod decorators;
+mod exponentiation;
+mod helpers;
+mod logical_assignment;
+mod names;
+mod nullish_coalescing;
+mod object_rest_spread;
+mod optional_chaining;
+mod private_members;
+
+use oxc_allocator::{Allocator, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, AstBuilder, Atom, CloneIn, GetSpan, Span};
+use oxc_parser::DecoratorFlavor;
+
+use self::{
+    helpers::Helper,
+    names::{name_hint, UniqueNames},
+    object_rest_spread::has_binding_rest,
+};
+
+/// Version of ECMAScript the output must run on, newer syntax is lowered
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TransformTarget {
+    ES2015,
+    ES2016,
+    ES2017,
+    ES2018,
+    ES2019,
+    ES2020,
+    ES2021,
+    ES2022,
+    /// Keep all the syntax
+    #[default]
+    ESNext,
+}
+
+pub struct Lowering<'a> {
+    ast: AstBuilder<'a>,
+    target: TransformTarget,
+    decorators: Option<DecoratorFlavor>,
+    names: UniqueNames,
+
+    /// Temporary variables of each function being visited, the first are the program's
+    temporaries: std::vec::Vec<std::vec::Vec<Atom>>,
+
+    /// Helpers called by the lowered code with their names, in order of first use
+    helpers: std::vec::Vec<(Helper, Atom)>,
+
+    /// Whether `await` becomes `yield` in each function being visited
+    async_functions: std::vec::Vec<bool>,
+}
+
+impl<'a> Lowering<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator, target: TransformTarget) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            target,
+            decorators: None,
+            names: UniqueNames::default(),
+            temporaries: vec![],
+            helpers: vec![],
+            async_functions: vec![],
+        }
+    }
+
+    /// Lower the decorators of this flavour, they are kept when `None`
+    #[must_use]
+    pub fn decorators(mut self, flavor: Option<DecoratorFlavor>) -> Self {
+        self.decorators = flavor;
+        self
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        // Class fields are the newest syntax lowered
+        if self.target >= TransformTarget::ES2022 && self.decorators.is_none() {
+            return;
+        }
+        // The semantic analysis borrows the program for the lifetime of the allocator,
+        // it analyses a copy of the program being lowered
+        let copy = self.ast.allocator.alloc(program.clone_in(self.ast.allocator));
+        self.names = UniqueNames::new(copy);
+        self.temporaries.push(vec![]);
+        self.visit_program(program);
+        let temporaries = self.temporaries.pop().unwrap_or_default();
+
+        let span = Span::new(program.span.start, program.span.start);
+        let mut body = self.ast.new_vec();
+        for (helper, name) in &self.helpers {
+            body.push(self.helper_declaration(*helper, name));
+        }
+        if let Some(decl) = self.temporaries_declaration(span, &temporaries) {
+            body.push(decl);
+        }
+        if !body.is_empty() {
+            body.extend(self.ast.move_statement_vec(&mut program.body));
+            program.body = body;
+        }
+    }
+
+    /// Whether the syntax introduced in `version` is lowered
+    fn is_lowered(&self, version: TransformTarget) -> bool {
+        self.target < version
+    }
+
+    fn transform_expression(&mut self, expr: &mut Expression<'a>) {
+        let lowered = match expr {
+            Expression::AssignmentExpression(assign) => match assign.operator {
+                AssignmentOperator::LogicalAnd
+                | AssignmentOperator::LogicalOr
+                | AssignmentOperator::LogicalNullish => self.is_lowered(TransformTarget::ES2021),
+                AssignmentOperator::Exponential => self.is_lowered(TransformTarget::ES2016),
+                AssignmentOperator::Assign => {
+                    self.is_lowered(TransformTarget::ES2018)
+                        && object_rest_spread::has_target_rest(&assign.left)
+                }
+                _ => false,
+            },
+            Expression::LogicalExpression(logical) => {
+                logical.operator == LogicalOperator::Coalesce
+                    && self.is_lowered(TransformTarget::ES2020)
+            }
+            Expression::ChainExpression(_) => self.is_lowered(TransformTarget::ES2020),
+            Expression::BinaryExpression(binary) => {
+                binary.operator == BinaryOperator::Exponential
+                    && self.is_lowered(TransformTarget::ES2016)
+            }
+            Expression::ObjectExpression(object) => {
+                self.is_lowered(TransformTarget::ES2018)
+                    && object
+                        .properties
+                        .iter()
+                        .any(|property| matches!(property, ObjectProperty::SpreadProperty(_)))
+            }
+            Expression::AwaitExpression(_) => self.async_functions.last() == Some(&true),
+            Expression::ClassExpression(class) => {
+                self.decorates(class)
+                    || self.is_lowered(TransformTarget::ES2022) && class_fields::has_fields(class)
+            }
+            _ => false,
+        };
+        if !lowered {
+            return;
+        }
+        *expr = match self.ast.move_expression(expr) {
+            Expression::AssignmentExpression(assign) => {
+                let assign = assign.unbox();
+                match assign.operator {
+                    AssignmentOperator::Exponential => self.lower_exponentiation_assignment(assign),
+                    AssignmentOperator::Assign => self.lower_object_rest_assignment(assign),
+                    _ => self.lower_logical_assignment(assign),
+                }
+            }
+            Expression::LogicalExpression(logical) => {
+                self.lower_nullish_coalescing(logical.unbox())
+            }
+            Expression::ChainExpression(chain) => self.lower_optional_chain(chain.unbox()),
+            Expression::BinaryExpression(binary) => self.lower_exponentiation(binary.unbox()),
+            Expression::ObjectExpression(object) => self.lower_object_spread(object.unbox()),
+            Expression::AwaitExpression(expr) => self.lower_await(expr.unbox()),
+            Expression::ClassExpression(class) if self.decorates(&class) => {
+                self.lower_decorated_class_expression(class)
+            }
+            Expression::ClassExpression(class) => self.lower_class_expression(class),
+            _ => unreachable!(),
+        };
+    }
+
+    /// Whether the class expression `class` has standard decorators to lower,
+    /// legacy decorators are only valid on class declarations
+    fn decorates(&self, class: &Class<'a>) -> bool {
+        self.decorators == Some(DecoratorFlavor::Standard)
+            && decorators::has_decorators(class, DecoratorFlavor::Standard)
+    }
+
+    /// Declare a temporary variable in the function being visited
+    fn temporary(&mut self, hint: &str) -> Atom {
+        let name = self.names.generate(hint);
+        if let Some(temporaries) = self.temporaries.last_mut() {
+            temporaries.push(name.clone());
+        }
+        name
+    }
+
+    /// Evaluate `expr` once and reference its value again: returns `(_a = expr)` and `_a`,
+    /// or `expr` and a copy of it when it is an identifier or a literal
+    fn memoize(&mut self, expr: Expression<'a>) -> (Expression<'a>, Expression<'a>) {
+        if is_reusable(&expr) {
+            let copy = expr.clone_in(self.ast.allocator);
+            return (expr, copy);
+        }
+        let span = expr.span();
+        let name = self.temporary(&name_hint(&expr));
+        let assign = self.assign(span, &name, expr);
+        (self.parenthesized(assign), self.identifier(span, &name))
+    }
+
+    /// Assign to `target` after reading it: returns the target evaluating its object and
+    /// computed property once, and a copy of it referencing their values.
+    /// `a.b` is used as is, `a().b` becomes `(_a = a()).b` and `_a.b`.
+    fn memoize_target(
+        &mut self,
+        target: SimpleAssignmentTarget<'a>,
+    ) -> (SimpleAssignmentTarget<'a>, SimpleAssignmentTarget<'a>) {
+        let SimpleAssignmentTarget::MemberAssignmentTarget(mut member) = target else {
+            let copy = target.clone_in(self.ast.allocator);
+            return (target, copy);
+        };
+        let object = self.ast.move_expression(member_object_mut(&mut member));
+        let (object, object_copy) = self.memoize(object);
+        *member_object_mut(&mut member) = object;
+        let copy = match &mut *member {
+            MemberExpression::ComputedMemberExpression(expr) => {
+                let property = self.ast.move_expression(&mut expr.expression);
+                let (property, property_copy) = self.memoize(property);
+                expr.expression = property;
+                MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
+                    span: expr.span,
+                    object: object_copy,
+                    expression: property_copy,
+                    optional: false,
+                })
+            }
+            MemberExpression::StaticMemberExpression(expr) => {
+                MemberExpression::StaticMemberExpression(StaticMemberExpression {
+                    span: expr.span,
+                    object: object_copy,
+                    property: expr.property.clone(),
+                    optional: false,
+                })
+            }
+            MemberExpression::PrivateFieldExpression(expr) => {
+                MemberExpression::PrivateFieldExpression(PrivateFieldExpression {
+                    span: expr.span,
+                    object: object_copy,
+                    field: expr.field.clone(),
+                    optional: false,
+                })
+            }
+        };
+        (
+            SimpleAssignmentTarget::MemberAssignmentTarget(member),
+            SimpleAssignmentTarget::MemberAssignmentTarget(self.ast.alloc(copy)),
+        )
+    }
+
+    /// `var _a, _b;`
+    fn temporaries_declaration(&self, span: Span, temporaries: &[Atom]) -> Option<Statement<'a>> {
+        if temporaries.is_empty() {
+            return None;
+        }
+        let kind = VariableDeclarationKind::Var;
+        let declarations = Vec::from_iter_in(
+            temporaries.iter().map(|name| {
+                self.ast.variable_declarator(span, kind, self.binding(span, name), None, false)
+            }),
+            self.ast.allocator,
+        );
+        let decl = self.ast.variable_declaration(span, kind, declarations, Modifiers::empty());
+        Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+    }
+
+    fn declare_temporaries(&self, body: &mut FunctionBody<'a>, temporaries: &[Atom]) {
+        let span = Span::new(body.span.start, body.span.start);
+        if let Some(decl) = self.temporaries_declaration(span, temporaries) {
+            body.statements.insert(0, decl);
+        }
+    }
+
+    /// `() => a` becomes `() => { return a }` so statements can be added to the body
+    fn ensure_block_body(&self, arrow: &mut ArrowExpression<'a>) {
+        if !arrow.expression {
+            return;
+        }
+        arrow.expression = false;
+        if let Some(Statement::ExpressionStatement(stmt)) = arrow.body.statements.pop() {
+            let stmt = stmt.unbox();
+            arrow.body.statements.push(self.ast.return_statement(stmt.span, Some(stmt.expression)));
+        }
+    }
+
+    /// Lowered expressions binding looser than a member access are parenthesized, as the printer
+    /// does not add parentheses. The parentheses have an empty span, to remove them again where
+    /// they are not needed.
+    fn parenthesized(&self, expr: Expression<'a>) -> Expression<'a> {
+        let start = expr.span().start;
+        self.ast.parenthesized_expression(Span::new(start, start), expr)
+    }
+
+    /// Remove the parentheses added by [`Self::parenthesized`] around an expression
+    /// in a position where any expression except a sequence can be written
+    fn unwrap_parenthesized(&self, expr: &mut Expression<'a>) {
+        if let Expression::ParenthesizedExpression(paren) = expr
+            && paren.span.is_empty()
+            && !matches!(paren.expression, Expression::SequenceExpression(_))
+        {
+            *expr = self.ast.move_expression(&mut paren.expression);
+        }
+    }
+
+    fn identifier(&self, span: Span, name: &Atom) -> Expression<'a> {
+        self.ast.identifier_expression(IdentifierReference { span, name: name.clone() })
+    }
+
+    fn identifier_target(&self, span: Span, name: &Atom) -> SimpleAssignmentTarget<'a> {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(
+            self.ast.alloc(IdentifierReference { span, name: name.clone() }),
+        )
+    }
+
+    fn binding(&self, span: Span, name: &Atom) -> BindingPattern<'a> {
+        let ident = self.ast.binding_identifier(BindingIdentifier { span, name: name.clone() });
+        self.ast.binding_pattern(ident, None, false)
+    }
+
+    /// `name = value`
+    fn assign(&self, span: Span, name: &Atom, value: Expression<'a>) -> Expression<'a> {
+        self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(self.identifier_target(span, name)),
+            value,
+        )
+    }
+
+    fn static_member(&self, span: Span, object: Expression<'a>, property: &str) -> Expression<'a> {
+        let property = IdentifierName { span, name: Atom::from(property) };
+        self.ast.static_member_expression(span, object, property, false)
+    }
+
+    fn call(
+        &self,
+        span: Span,
+        callee: Expression<'a>,
+        arguments: impl IntoIterator<Item = Expression<'a>>,
+    ) -> Expression<'a> {
+        let arguments =
+            Vec::from_iter_in(arguments.into_iter().map(Argument::Expression), self.ast.allocator);
+        self.ast.call_expression(span, callee, arguments, false, None)
+    }
+
+    fn string_literal(&self, span: Span, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral { span, value: Atom::from(value) })
+    }
+
+    fn number_literal(&self, span: Span, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let value = value as f64;
+        self.ast.literal_number_expression(NumberLiteral::new(
+            span,
+            value,
+            raw,
+            NumberBase::Decimal,
+        ))
+    }
+
+    fn void_zero(&self, span: Span) -> Expression<'a> {
+        let zero = self.number_literal(span, 0);
+        self.ast.unary_expression(span, UnaryOperator::Void, true, zero)
+    }
+
+    /// `first === null || reference === void 0`, or `first !== null && reference !== void 0`
+    /// when `negated`
+    fn nullish_test(
+        &self,
+        span: Span,
+        first: Expression<'a>,
+        reference: Expression<'a>,
+        negated: bool,
+    ) -> Expression<'a> {
+        let (equality, logical) = if negated {
+            (BinaryOperator::StrictInequality, LogicalOperator::And)
+        } else {
+            (BinaryOperator::StrictEquality, LogicalOperator::Or)
+        };
+        let null = self.ast.literal_null_expression(NullLiteral { span });
+        let is_null = self.ast.binary_expression(span, first, equality, null);
+        let is_undefined =
+            self.ast.binary_expression(span, reference, equality, self.void_zero(span));
+        self.ast.logical_expression(span, is_null, logical, is_undefined)
+    }
+}
+
+impl<'a> VisitMut<'a> for Lowering<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        for stmt in stmts.iter_mut() {
+            self.visit_statement(stmt);
+        }
+        self.lower_decorated_classes(stmts);
+        if self.is_lowered(TransformTarget::ES2022) {
+            self.lower_class_declarations(stmts);
+        }
+    }
+
+    fn visit_expression_statement(&mut self, stmt: &mut ExpressionStatement<'a>) {
+        self.visit_expression(&mut stmt.expression);
+        self.unwrap_parenthesized(&mut stmt.expression);
+    }
+
+    fn visit_return_statement(&mut self, stmt: &mut ReturnStatement<'a>) {
+        if let Some(argument) = &mut stmt.argument {
+            self.visit_expression(argument);
+            self.unwrap_parenthesized(argument);
+        }
+    }
+
+    fn visit_variable_declaration(&mut self, decl: &mut VariableDeclaration<'a>) {
+        for declarator in decl.declarations.iter_mut() {
+            self.visit_variable_declarator(declarator);
+            if let Some(init) = &mut declarator.init {
+                self.unwrap_parenthesized(init);
+            }
+        }
+        if self.is_lowered(TransformTarget::ES2018)
+            && decl.declarations.iter().any(|d| d.init.is_some() && has_binding_rest(&d.id))
+        {
+            self.lower_declarators_rest(decl);
+        }
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.visit_for_statement_left(&mut stmt.left);
+        self.visit_expression(&mut stmt.right);
+        self.visit_statement(&mut stmt.body);
+        if self.is_lowered(TransformTarget::ES2018) {
+            self.lower_for_left_rest(&mut stmt.left, &mut stmt.body);
+        }
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.visit_for_statement_left(&mut stmt.left);
+        self.visit_expression(&mut stmt.right);
+        self.visit_statement(&mut stmt.body);
+        if self.is_lowered(TransformTarget::ES2018) {
+            self.lower_for_left_rest(&mut stmt.left, &mut stmt.body);
+        }
+    }
+
+    fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        if let Some(param) = &mut clause.param {
+            self.visit_pattern(param);
+        }
+        self.visit_statements(&mut clause.body.body);
+        if clause.param.is_none() && self.is_lowered(TransformTarget::ES2019) {
+            let span = Span::new(clause.span.start, clause.span.start);
+            let name = self.names.generate("unused");
+            clause.param = Some(self.binding(span, &name));
+        } else if self.is_lowered(TransformTarget::ES2018) {
+            self.lower_catch_parameter_rest(clause);
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        // Temporary variables of the parameters are declared in the enclosing function,
+        // the variables declared in the body are not visible to the parameters
+        self.visit_formal_parameters(&mut func.params);
+        let Some(body) = &mut func.body else { return };
+        let lower_async =
+            func.r#async && !func.generator && self.is_lowered(TransformTarget::ES2017);
+        self.async_functions.push(lower_async);
+        self.temporaries.push(vec![]);
+        self.names.enter_function(func.span);
+        self.visit_function_body(body);
+        if self.is_lowered(TransformTarget::ES2018) {
+            self.lower_parameters_rest(&mut func.params, body);
+        }
+        self.names.leave_function();
+        let temporaries = self.temporaries.pop().unwrap_or_default();
+        self.declare_temporaries(body, &temporaries);
+        self.async_functions.pop();
+        if lower_async {
+            self.lower_async_function(func);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, arrow: &mut ArrowExpression<'a>) {
+        self.visit_formal_parameters(&mut arrow.params);
+        let lower_async = arrow.r#async && self.is_lowered(TransformTarget::ES2017);
+        self.async_functions.push(lower_async);
+        self.temporaries.push(vec![]);
+        self.names.enter_function(arrow.span);
+        self.visit_function_body(&mut arrow.body);
+        if self.is_lowered(TransformTarget::ES2018)
+            && arrow.params.items.iter().any(|param| has_binding_rest(&param.pattern))
+        {
+            self.ensure_block_body(arrow);
+            self.lower_parameters_rest(&mut arrow.params, &mut arrow.body);
+        }
+        self.names.leave_function();
+        let temporaries = self.temporaries.pop().unwrap_or_default();
+        if !temporaries.is_empty() {
+            self.ensure_block_body(arrow);
+            self.declare_temporaries(&mut arrow.body, &temporaries);
+        }
+        self.async_functions.pop();
+        if lower_async {
+            self.lower_async_arrow(arrow);
+        }
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        // `delete a?.b` is lowered with the chain, it is `true` when `a` is nullish
+        if self.is_lowered(TransformTarget::ES2020)
+            && let Expression::UnaryExpression(unary) = expr
+            && unary.operator == UnaryOperator::Delete
+            && let Expression::ChainExpression(chain) = &mut unary.argument
+        {
+            self.visit_chain_expression(chain);
+            let span = unary.span;
+            let Expression::ChainExpression(chain) = self.ast.move_expression(&mut unary.argument)
+            else {
+                unreachable!()
+            };
+            *expr = self.lower_optional_delete(span, chain.unbox());
+            return;
+        }
+        self.visit_expression_match(expr);
+        self.transform_expression(expr);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &mut AssignmentExpression<'a>) {
+        self.visit_assignment_target(&mut expr.left);
+        self.visit_expression(&mut expr.right);
+        self.unwrap_parenthesized(&mut expr.right);
+    }
+
+    fn visit_argument(&mut self, arg: &mut Argument<'a>) {
+        match arg {
+            Argument::SpreadElement(spread) => self.visit_spread_element(spread),
+            Argument::Expression(expr) => {
+                self.visit_expression(expr);
+                self.unwrap_parenthesized(expr);
+            }
+        }
+    }
+
+    fn visit_parenthesized_expression(&mut self, expr: &mut ParenthesizedExpression<'a>) {
+        self.visit_expression(&mut expr.expression);
+        // `((a, b))`
+        if let Expression::ParenthesizedExpression(paren) = &mut expr.expression
+            && paren.span.is_empty()
+        {
+            expr.expression = self.ast.move_expression(&mut paren.expression);
+        }
+    }
+}
+
+/// Expressions which can be evaluated again instead of being stored in a temporary variable
+fn is_reusable(expr: &Expression) -> bool {
+    matches!(expr, Expression::Identifier(_) | Expression::ThisExpression(_) | Expression::Super(_))
+        || expr.is_literal_expression()
+}
+
+fn member_object_mut<'m, 'a>(member: &'m mut MemberExpression<'a>) -> &'m mut Expression<'a> {
+    match member {
+        MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+        MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+        MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+    }
+}
+
+fn simple_target_to_expression(target: SimpleAssignmentTarget) -> Expression {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => Expression::Identifier(ident),
+        SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+            Expression::MemberExpression(member)
+        }
+        SimpleAssignmentTarget::TSAsExpression(expr) => Expression::TSAsExpression(expr),
+        SimpleAssignmentTarget::TSNonNullExpression(expr) => Expression::TSNonNullExpression(expr),
+        SimpleAssignmentTarget::TSTypeAssertion(expr) => Expression::TSTypeAssertion(expr),
+    }
+}
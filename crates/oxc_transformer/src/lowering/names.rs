@@ -0,0 +1,171 @@
+//! Names of the temporary variables and helpers
+
+use std::rc::Rc;
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, AstKind, Atom, Span};
+use oxc_semantic::SemanticBuilder;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Generates names which do not clash with the bindings of the file.
+///
+/// A name generated for a function is not bound in the function, in the scopes enclosing it
+/// or in the scopes nested in it, nor read from the global scope in the function. Declaring it
+/// in the function neither shadows a binding or global the code refers to nor is shadowed by
+/// one, sibling functions can get the same names.
+#[derive(Default)]
+pub struct UniqueNames {
+    scopes: Vec<ScopeNames>,
+
+    /// Scope of each function and arrow, by span
+    function_scopes: FxHashMap<(u32, u32), usize>,
+
+    /// Scopes of the functions being visited, the first is the program's
+    current: Vec<usize>,
+}
+
+struct ScopeNames {
+    parent: Option<usize>,
+
+    /// Names bound in the scope, including the generated ones
+    bindings: FxHashSet<Atom>,
+
+    /// Names bound or read from the global scope in the scope or in a scope nested in it
+    nested: FxHashSet<Atom>,
+}
+
+impl UniqueNames {
+    pub fn new<'a>(program: &'a Program<'a>) -> Self {
+        let semantic =
+            SemanticBuilder::new(program.source_type).build(program, Rc::default()).semantic;
+        let scopes = semantic.scopes();
+        let mut names = Self::default();
+
+        // Enclosing scopes are added before the scopes nested in them
+        let mut indexes = FxHashMap::default();
+        let mut stack = vec![(scopes.root_scope_id(), None)];
+        while let Some((scope_id, parent)) = stack.pop() {
+            let index = names.scopes.len();
+            indexes.insert(scope_id, index);
+            names.scopes.push(ScopeNames {
+                parent,
+                bindings: FxHashSet::default(),
+                nested: FxHashSet::default(),
+            });
+            for name in scopes[scope_id].bindings().keys() {
+                names.declare(index, name);
+            }
+            stack.extend(scopes.children(scope_id).map(|child_id| (child_id.into(), Some(index))));
+        }
+
+        for reference in semantic.symbols().references().filter(|reference| reference.is_global()) {
+            names.nest(indexes[&reference.scope_id()], reference.name());
+        }
+        for node in semantic.nodes().iter() {
+            let span = match node.get().kind() {
+                AstKind::Function(func) => func.span,
+                AstKind::ArrowExpression(arrow) => arrow.span,
+                _ => continue,
+            };
+            let index = indexes[&node.get().scope_id()];
+            names.function_scopes.insert((span.start, span.end), index);
+        }
+        names.current.push(0);
+        names
+    }
+
+    /// Generate the names of the function at `span` until [`Self::leave_function`].
+    /// Functions added by the transformer get the names of the enclosing function.
+    pub fn enter_function(&mut self, span: Span) {
+        let scope = self
+            .function_scopes
+            .get(&(span.start, span.end))
+            .or_else(|| self.current.last())
+            .copied()
+            .unwrap_or_default();
+        self.current.push(scope);
+    }
+
+    pub fn leave_function(&mut self) {
+        self.current.pop();
+    }
+
+    /// Name of a binding of the function being visited.
+    /// `_hint`, or `_hint2`, `_hint3`... when it is taken, like Babel's `generateUid`
+    pub fn generate(&mut self, hint: &str) -> Atom {
+        let scope = self.current.last().copied();
+        self.generate_in(scope, hint)
+    }
+
+    /// Name of a top level binding, which is not bound anywhere in the file
+    pub fn generate_top_level(&mut self, hint: &str) -> Atom {
+        let scope = (!self.scopes.is_empty()).then_some(0);
+        self.generate_in(scope, hint)
+    }
+
+    fn generate_in(&mut self, scope: Option<usize>, hint: &str) -> Atom {
+        let hint: String = hint
+            .trim_start_matches('_')
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+            .collect();
+        let hint = if hint.is_empty() { "ref" } else { hint.as_str() };
+        let mut name = Atom::from(format!("_{hint}"));
+        let mut i = 2;
+        while !self.is_available(scope, &name) {
+            name = Atom::from(format!("_{hint}{i}"));
+            i += 1;
+        }
+        if let Some(scope) = scope {
+            self.declare(scope, &name);
+        }
+        name
+    }
+
+    fn is_available(&self, scope: Option<usize>, name: &Atom) -> bool {
+        let Some(scope) = scope else { return true };
+        !self.scopes[scope].nested.contains(name)
+            && !self.ancestors(scope).any(|scope| self.scopes[scope].bindings.contains(name))
+    }
+
+    fn declare(&mut self, scope: usize, name: &Atom) {
+        self.scopes[scope].bindings.insert(name.clone());
+        self.nest(scope, name);
+    }
+
+    /// `name` is bound or read in `scope`, add it to the names nested in the enclosing scopes
+    fn nest(&mut self, scope: usize, name: &Atom) {
+        let mut scope = Some(scope);
+        while let Some(index) = scope {
+            let names = &mut self.scopes[index];
+            names.nested.insert(name.clone());
+            scope = names.parent;
+        }
+    }
+
+    /// `scope` and the scopes enclosing it
+    fn ancestors(&self, scope: usize) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(Some(scope), |scope| self.scopes[*scope].parent)
+    }
+}
+
+/// Hint for the name of the variable storing `expr`: `_a$b` for `a.b`
+pub fn name_hint(expr: &Expression) -> String {
+    match expr {
+        Expression::Identifier(ident) => ident.name.to_string(),
+        Expression::ThisExpression(_) => "this".to_string(),
+        Expression::Super(_) => "super".to_string(),
+        Expression::MemberExpression(member) => match &**member {
+            MemberExpression::StaticMemberExpression(member) => {
+                format!("{}${}", name_hint(&member.object), member.property.name)
+            }
+            MemberExpression::PrivateFieldExpression(member) => {
+                format!("{}${}", name_hint(&member.object), member.field.name)
+            }
+            MemberExpression::ComputedMemberExpression(member) => name_hint(&member.object),
+        },
+        Expression::CallExpression(call) => name_hint(&call.callee),
+        Expression::ParenthesizedExpression(expr) => name_hint(&expr.expression),
+        _ => "ref".to_string(),
+    }
+}
@@ -0,0 +1,23 @@
+//! ES2020: nullish coalescing operator
+//!
+//! * `a ?? b` becomes `a !== null && a !== void 0 ? a : b`
+//! * `a() ?? b` becomes `(_a = a()) !== null && _a !== void 0 ? _a : b`
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, CloneIn};
+
+use super::Lowering;
+
+impl<'a> Lowering<'a> {
+    pub(super) fn lower_nullish_coalescing(
+        &mut self,
+        expr: LogicalExpression<'a>,
+    ) -> Expression<'a> {
+        let span = expr.span;
+        let (first, reference) = self.memoize(expr.left);
+        let value = reference.clone_in(self.ast.allocator);
+        let test = self.nullish_test(span, first, reference, true);
+        let conditional = self.ast.conditional_expression(span, test, value, expr.right);
+        self.parenthesized(conditional)
+    }
+}
@@ -0,0 +1,463 @@
+//! ES2018: object rest and spread properties
+//!
+//! * `{ a, ...b, c }` becomes `Object.assign({ a }, b, { c })`
+//! * `const { a, ...b } = c` becomes `const { a } = c, b = _objectWithoutProperties(c, ['a'])`
+//! * `({ a, ...b } = c)` becomes `({ a } = c, b = _objectWithoutProperties(c, ['a']), c)`
+//!
+//! Parameters, `catch` clauses and the heads of `for-in` and `for-of` loops with a rest element
+//! bind a temporary variable instead, destructured at the start of their body.
+//! A pattern with a rest element nested in another pattern is replaced with a temporary variable
+//! too, destructured after the pattern.
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, Atom, CloneIn, GetSpan, Span};
+
+use super::{helpers::Helper, is_reusable, names::name_hint, Lowering};
+
+impl<'a> Lowering<'a> {
+    pub(super) fn lower_object_spread(&self, object: ObjectExpression<'a>) -> Expression<'a> {
+        let span = object.span;
+        let mut arguments = vec![];
+        let mut properties = self.ast.new_vec();
+        for property in object.properties {
+            match property {
+                ObjectProperty::SpreadProperty(spread) => {
+                    // The first argument is the new object the others are copied to
+                    if arguments.is_empty() || !properties.is_empty() {
+                        let properties = std::mem::replace(&mut properties, self.ast.new_vec());
+                        arguments.push(self.ast.object_expression(span, properties, None));
+                    }
+                    arguments.push(spread.unbox().argument);
+                }
+                property @ ObjectProperty::Property(_) => properties.push(property),
+            }
+        }
+        if !properties.is_empty() {
+            arguments.push(self.ast.object_expression(span, properties, None));
+        }
+        let object = self.identifier(span, &Atom::from("Object"));
+        let callee = self.static_member(span, object, "assign");
+        self.call(span, callee, arguments)
+    }
+
+    pub(super) fn lower_declarators_rest(&mut self, decl: &mut VariableDeclaration<'a>) {
+        let kind = decl.kind;
+        let mut declarations = self.ast.new_vec_with_capacity(decl.declarations.len());
+        for mut declarator in decl.declarations.drain(..) {
+            if has_binding_rest(&declarator.id)
+                && let Some(init) = declarator.init.take()
+            {
+                self.lower_binding(kind, declarator.id, init, &mut declarations);
+            } else {
+                declarations.push(declarator);
+            }
+        }
+        decl.declarations = declarations;
+    }
+
+    pub(super) fn lower_parameters_rest(
+        &mut self,
+        params: &mut FormalParameters<'a>,
+        body: &mut FunctionBody<'a>,
+    ) {
+        let mut nested = vec![];
+        for param in params.items.iter_mut() {
+            self.replace_rest_pattern(&mut param.pattern, &mut nested);
+        }
+        let span = Span::new(body.span.start, body.span.start);
+        if let Some(decl) = self.nested_declaration(span, VariableDeclarationKind::Var, nested) {
+            body.statements.insert(0, decl);
+        }
+    }
+
+    pub(super) fn lower_catch_parameter_rest(&mut self, clause: &mut CatchClause<'a>) {
+        let Some(param) = &mut clause.param else { return };
+        let mut nested = vec![];
+        self.replace_rest_pattern(param, &mut nested);
+        let span = Span::new(clause.body.span.start, clause.body.span.start);
+        if let Some(decl) = self.nested_declaration(span, VariableDeclarationKind::Let, nested) {
+            clause.body.body.insert(0, decl);
+        }
+    }
+
+    pub(super) fn lower_for_left_rest(
+        &mut self,
+        left: &mut ForStatementLeft<'a>,
+        body: &mut Statement<'a>,
+    ) {
+        let span = Span::new(body.span().start, body.span().start);
+        let stmt = match left {
+            ForStatementLeft::VariableDeclaration(decl) => {
+                let mut nested = vec![];
+                for declarator in decl.declarations.iter_mut() {
+                    self.replace_rest_pattern(&mut declarator.id, &mut nested);
+                }
+                self.nested_declaration(span, decl.kind, nested)
+            }
+            ForStatementLeft::AssignmentTarget(target) => {
+                if !has_target_rest(target) {
+                    return;
+                }
+                let name = self.temporary("ref");
+                let temporary =
+                    AssignmentTarget::SimpleAssignmentTarget(self.identifier_target(span, &name));
+                let target = std::mem::replace(target, temporary);
+                let mut expressions = vec![];
+                self.lower_assignment_target(
+                    target,
+                    self.identifier(span, &name),
+                    &mut expressions,
+                );
+                let expressions = Vec::from_iter_in(expressions, self.ast.allocator);
+                let sequence = self.ast.sequence_expression(span, expressions);
+                let expr = self.ast.parenthesized_expression(span, sequence);
+                Some(self.ast.expression_statement(span, expr))
+            }
+        };
+        let Some(stmt) = stmt else { return };
+        if let Statement::BlockStatement(block) = body {
+            block.body.insert(0, stmt);
+        } else {
+            let span = body.span();
+            let statements = self.ast.new_vec_single(stmt);
+            let mut block = self.ast.block(span, statements);
+            block.body.push(self.ast.move_statement(body));
+            *body = self.ast.block_statement(block);
+        }
+    }
+
+    pub(super) fn lower_object_rest_assignment(
+        &mut self,
+        expr: AssignmentExpression<'a>,
+    ) -> Expression<'a> {
+        let span = expr.span;
+        let mut expressions = vec![];
+        // The value of the assignment is its right side
+        let value = if is_reusable(&expr.right) {
+            expr.right
+        } else {
+            let name = self.temporary(&name_hint(&expr.right));
+            expressions.push(self.assign(span, &name, expr.right));
+            self.identifier(span, &name)
+        };
+        let result = value.clone_in(self.ast.allocator);
+        self.lower_assignment_target(expr.left, value, &mut expressions);
+        expressions.push(result);
+        let expressions = Vec::from_iter_in(expressions, self.ast.allocator);
+        let sequence = self.ast.sequence_expression(span, expressions);
+        self.parenthesized(sequence)
+    }
+
+    /// Splits `pattern = init` into declarators without rest elements
+    fn lower_binding(
+        &mut self,
+        kind: VariableDeclarationKind,
+        mut pattern: BindingPattern<'a>,
+        init: Expression<'a>,
+        declarations: &mut Vec<'a, VariableDeclarator<'a>>,
+    ) {
+        let span = pattern.span();
+        let mut nested = vec![];
+        match &mut pattern.kind {
+            BindingPatternKind::ObjectPattern(object) => {
+                for property in object.properties.iter_mut() {
+                    if let ObjectPatternProperty::Property(property) = property
+                        && let PropertyValue::Pattern(value) = &mut property.value
+                    {
+                        self.replace_rest_pattern(value, &mut nested);
+                    }
+                }
+            }
+            BindingPatternKind::ArrayPattern(array) => {
+                for element in array.elements.iter_mut().flatten() {
+                    self.replace_rest_pattern(element, &mut nested);
+                }
+            }
+            _ => {}
+        }
+
+        if let BindingPatternKind::ObjectPattern(object) = &mut pattern.kind
+            && let Some(ObjectPatternProperty::RestElement(_)) = object.properties.last()
+        {
+            let Some(ObjectPatternProperty::RestElement(rest)) = object.properties.pop() else {
+                unreachable!()
+            };
+            let mut excluded = vec![];
+            for property in object.properties.iter_mut() {
+                if let ObjectPatternProperty::Property(property) = property {
+                    excluded.push(self.excluded_key(&mut property.key));
+                }
+            }
+            let rest = rest.unbox().argument;
+            if object.properties.is_empty() {
+                let value = self.object_without_properties(span, init, excluded);
+                declarations.push(self.ast.variable_declarator(
+                    span,
+                    kind,
+                    rest,
+                    Some(value),
+                    false,
+                ));
+            } else {
+                let (init, reference) = self.memoize(init);
+                let value = self.object_without_properties(span, reference, excluded);
+                declarations.push(self.ast.variable_declarator(
+                    span,
+                    kind,
+                    pattern,
+                    Some(init),
+                    false,
+                ));
+                declarations.push(self.ast.variable_declarator(
+                    span,
+                    kind,
+                    rest,
+                    Some(value),
+                    false,
+                ));
+            }
+        } else {
+            declarations.push(self.ast.variable_declarator(span, kind, pattern, Some(init), false));
+        }
+
+        for (pattern, name) in nested {
+            let init = self.identifier(span, &name);
+            self.lower_binding(kind, pattern, init, declarations);
+        }
+    }
+
+    /// Replaces `pattern`, or the pattern with a default value, with a temporary binding
+    /// when it has a rest element
+    fn replace_rest_pattern(
+        &mut self,
+        pattern: &mut BindingPattern<'a>,
+        nested: &mut std::vec::Vec<(BindingPattern<'a>, Atom)>,
+    ) {
+        let pattern = match &mut pattern.kind {
+            BindingPatternKind::AssignmentPattern(assign) => &mut assign.left,
+            BindingPatternKind::RestElement(rest) => &mut rest.argument,
+            _ => pattern,
+        };
+        if !has_binding_rest(pattern) {
+            return;
+        }
+        let name = self.names.generate("ref");
+        let binding = self.binding(pattern.span(), &name);
+        nested.push((std::mem::replace(pattern, binding), name));
+    }
+
+    /// Declares the patterns replaced with temporary bindings
+    fn nested_declaration(
+        &mut self,
+        span: Span,
+        kind: VariableDeclarationKind,
+        nested: std::vec::Vec<(BindingPattern<'a>, Atom)>,
+    ) -> Option<Statement<'a>> {
+        if nested.is_empty() {
+            return None;
+        }
+        let mut declarations = self.ast.new_vec();
+        for (pattern, name) in nested {
+            let init = self.identifier(span, &name);
+            self.lower_binding(kind, pattern, init, &mut declarations);
+        }
+        let decl = self.ast.variable_declaration(span, kind, declarations, Modifiers::empty());
+        Some(Statement::Declaration(Declaration::VariableDeclaration(decl)))
+    }
+
+    /// Splits `target = value` into assignments without rest elements
+    fn lower_assignment_target(
+        &mut self,
+        mut target: AssignmentTarget<'a>,
+        value: Expression<'a>,
+        expressions: &mut std::vec::Vec<Expression<'a>>,
+    ) {
+        let span = target.span();
+        let mut nested = vec![];
+        match &mut target {
+            AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ObjectAssignmentTarget(object),
+            ) => {
+                for property in object.properties.iter_mut() {
+                    if let AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) =
+                        property
+                    {
+                        self.replace_rest_target(
+                            maybe_default_target(&mut property.binding),
+                            &mut nested,
+                        );
+                    }
+                }
+            }
+            AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ArrayAssignmentTarget(array),
+            ) => {
+                for element in array.elements.iter_mut().flatten() {
+                    self.replace_rest_target(maybe_default_target(element), &mut nested);
+                }
+                if let Some(rest) = &mut array.rest {
+                    self.replace_rest_target(rest, &mut nested);
+                }
+            }
+            AssignmentTarget::SimpleAssignmentTarget(_) => {}
+        }
+
+        if let AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ObjectAssignmentTarget(object),
+        ) = &mut target
+            && let Some(rest) = object.rest.take()
+        {
+            let mut excluded = vec![];
+            for property in object.properties.iter_mut() {
+                let key = match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                        self.string_literal(ident.span, &ident.binding.name)
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        self.excluded_key(&mut property.name)
+                    }
+                };
+                excluded.push(key);
+            }
+            if object.properties.is_empty() {
+                let value = self.object_without_properties(span, value, excluded);
+                expressions.push(self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    rest,
+                    value,
+                ));
+            } else {
+                let (value, reference) = self.memoize(value);
+                let rest_value = self.object_without_properties(span, reference, excluded);
+                expressions.push(self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    target,
+                    value,
+                ));
+                expressions.push(self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    rest,
+                    rest_value,
+                ));
+            }
+        } else {
+            expressions.push(self.ast.assignment_expression(
+                span,
+                AssignmentOperator::Assign,
+                target,
+                value,
+            ));
+        }
+
+        for (target, name) in nested {
+            let value = self.identifier(span, &name);
+            self.lower_assignment_target(target, value, expressions);
+        }
+    }
+
+    /// Replaces `target` with a temporary variable when it has a rest element
+    fn replace_rest_target(
+        &mut self,
+        target: &mut AssignmentTarget<'a>,
+        nested: &mut std::vec::Vec<(AssignmentTarget<'a>, Atom)>,
+    ) {
+        if !has_target_rest(target) {
+            return;
+        }
+        let name = self.temporary("ref");
+        let temporary =
+            AssignmentTarget::SimpleAssignmentTarget(self.identifier_target(target.span(), &name));
+        nested.push((std::mem::replace(target, temporary), name));
+    }
+
+    /// The name of a property excluded from the rest element, a computed key is evaluated once
+    fn excluded_key(&mut self, key: &mut PropertyKey<'a>) -> Expression<'a> {
+        let span = key.span();
+        if let Some(name) = key.static_name() {
+            return self.string_literal(span, &name);
+        }
+        let PropertyKey::Expression(key) = key else {
+            unreachable!("private names can't be destructured");
+        };
+        let (first, reference) = self.memoize(self.ast.move_expression(key));
+        *key = first;
+        reference
+    }
+
+    /// `_objectWithoutProperties(source, [excluded])`
+    fn object_without_properties(
+        &mut self,
+        span: Span,
+        source: Expression<'a>,
+        excluded: std::vec::Vec<Expression<'a>>,
+    ) -> Expression<'a> {
+        let excluded = Vec::from_iter_in(
+            excluded.into_iter().map(|key| Some(Argument::Expression(key))),
+            self.ast.allocator,
+        );
+        let excluded = self.ast.array_expression(span, excluded, None);
+        let callee = self.helper(span, Helper::ObjectWithoutProperties);
+        self.call(span, callee, [source, excluded])
+    }
+}
+
+/// Whether `pattern` has an object rest element
+pub fn has_binding_rest(pattern: &BindingPattern) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().any(|property| match property {
+                ObjectPatternProperty::RestElement(_) => true,
+                ObjectPatternProperty::Property(property) => {
+                    matches!(&property.value, PropertyValue::Pattern(value) if has_binding_rest(value))
+                }
+            })
+        }
+        BindingPatternKind::ArrayPattern(array) => array.elements.iter().flatten().any(has_binding_rest),
+        BindingPatternKind::RestElement(rest) => has_binding_rest(&rest.argument),
+        BindingPatternKind::AssignmentPattern(assign) => has_binding_rest(&assign.left),
+    }
+}
+
+/// Whether `target` has an object rest element
+pub fn has_target_rest(target: &AssignmentTarget) -> bool {
+    let has_maybe_default_rest = |target: &AssignmentTargetMaybeDefault| match target {
+        AssignmentTargetMaybeDefault::AssignmentTarget(target) => has_target_rest(target),
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            has_target_rest(&target.binding)
+        }
+    };
+    match target {
+        AssignmentTarget::SimpleAssignmentTarget(_) => false,
+        AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ObjectAssignmentTarget(object),
+        ) => {
+            object.rest.is_some()
+                || object.properties.iter().any(|property| match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(_) => false,
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(property) => {
+                        has_maybe_default_rest(&property.binding)
+                    }
+                })
+        }
+        AssignmentTarget::AssignmentTargetPattern(
+            AssignmentTargetPattern::ArrayAssignmentTarget(array),
+        ) => {
+            array.elements.iter().flatten().any(has_maybe_default_rest)
+                || array.rest.as_ref().map_or(false, has_target_rest)
+        }
+    }
+}
+
+fn maybe_default_target<'m, 'a>(
+    target: &'m mut AssignmentTargetMaybeDefault<'a>,
+) -> &'m mut AssignmentTarget<'a> {
+    match target {
+        AssignmentTargetMaybeDefault::AssignmentTarget(target) => target,
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => &mut target.binding,
+    }
+}
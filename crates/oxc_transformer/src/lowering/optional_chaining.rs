@@ -0,0 +1,141 @@
+//! ES2020: optional chaining
+//!
+//! * `a?.b` becomes `a === null || a === void 0 ? void 0 : a.b`
+//! * `a.b?.()` becomes `(_a$b = a.b) === null || _a$b === void 0 ? void 0 : _a$b.call(a)`
+//! * `delete a?.b` becomes `a === null || a === void 0 ? true : delete a.b`
+//!
+//! The checks of a chain are joined, the first nullish link short-circuits the whole chain.
+
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, CloneIn, GetSpan, Span};
+
+use super::{member_object_mut, Lowering};
+
+impl<'a> Lowering<'a> {
+    pub(super) fn lower_optional_chain(&mut self, chain: ChainExpression<'a>) -> Expression<'a> {
+        let span = chain.span;
+        let mut checks = vec![];
+        let value =
+            self.lower_chain_element(chain_element_expression(chain.expression), &mut checks);
+        let nullish = self.void_zero(span);
+        self.optional_chain(span, checks, nullish, value)
+    }
+
+    pub(super) fn lower_optional_delete(
+        &mut self,
+        span: Span,
+        chain: ChainExpression<'a>,
+    ) -> Expression<'a> {
+        let mut checks = vec![];
+        let value =
+            self.lower_chain_element(chain_element_expression(chain.expression), &mut checks);
+        let value = self.ast.unary_expression(span, UnaryOperator::Delete, true, value);
+        let nullish = self.ast.literal_boolean_expression(BooleanLiteral { span, value: true });
+        self.optional_chain(span, checks, nullish, value)
+    }
+
+    /// `checks ? nullish : value`
+    fn optional_chain(
+        &self,
+        span: Span,
+        checks: std::vec::Vec<Expression<'a>>,
+        nullish: Expression<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let Some(test) = checks.into_iter().reduce(|left, right| {
+            self.ast.logical_expression(span, left, LogicalOperator::Or, right)
+        }) else {
+            // A chain without optional links
+            return value;
+        };
+        let conditional = self.ast.conditional_expression(span, test, nullish, value);
+        self.parenthesized(conditional)
+    }
+
+    /// Removes the optional links of a chain, adding their checks to `checks`
+    fn lower_chain_element(
+        &mut self,
+        expr: Expression<'a>,
+        checks: &mut std::vec::Vec<Expression<'a>>,
+    ) -> Expression<'a> {
+        match expr {
+            Expression::MemberExpression(mut member) => {
+                let object = self.ast.move_expression(member_object_mut(&mut member));
+                let object = self.lower_chain_element(object, checks);
+                let object = if take_optional(&mut member) {
+                    self.nullish_check(object, checks)
+                } else {
+                    object
+                };
+                *member_object_mut(&mut member) = object;
+                Expression::MemberExpression(member)
+            }
+            Expression::CallExpression(mut call) => {
+                let callee = self.ast.move_expression(&mut call.callee);
+                call.callee = match callee {
+                    // `a.b?.()` is called with `a` as `this`
+                    Expression::MemberExpression(mut member) if call.optional => {
+                        let object = self.ast.move_expression(member_object_mut(&mut member));
+                        let object = self.lower_chain_element(object, checks);
+                        let object = if take_optional(&mut member) {
+                            self.nullish_check(object, checks)
+                        } else {
+                            object
+                        };
+                        let (object, receiver) = if let Expression::Super(_) = object {
+                            let receiver = self.ast.this_expression(object.span());
+                            (object, receiver)
+                        } else {
+                            self.memoize(object)
+                        };
+                        *member_object_mut(&mut member) = object;
+                        let function =
+                            self.nullish_check(Expression::MemberExpression(member), checks);
+                        call.arguments.insert(0, Argument::Expression(receiver));
+                        self.static_member(call.span, function, "call")
+                    }
+                    callee => {
+                        let callee = self.lower_chain_element(callee, checks);
+                        if call.optional {
+                            self.nullish_check(callee, checks)
+                        } else {
+                            callee
+                        }
+                    }
+                };
+                call.optional = false;
+                Expression::CallExpression(call)
+            }
+            expr => expr,
+        }
+    }
+
+    /// Adds the check of `expr` being nullish, returns the reference to its value
+    fn nullish_check(
+        &mut self,
+        expr: Expression<'a>,
+        checks: &mut std::vec::Vec<Expression<'a>>,
+    ) -> Expression<'a> {
+        let span = expr.span();
+        let (first, reference) = self.memoize(expr);
+        let value = reference.clone_in(self.ast.allocator);
+        checks.push(self.nullish_test(span, first, reference, false));
+        value
+    }
+}
+
+fn chain_element_expression(elem: ChainElement) -> Expression {
+    match elem {
+        ChainElement::CallExpression(call) => Expression::CallExpression(call),
+        ChainElement::MemberExpression(member) => Expression::MemberExpression(member),
+    }
+}
+
+fn take_optional(member: &mut MemberExpression) -> bool {
+    let optional = match member {
+        MemberExpression::ComputedMemberExpression(expr) => &mut expr.optional,
+        MemberExpression::StaticMemberExpression(expr) => &mut expr.optional,
+        MemberExpression::PrivateFieldExpression(expr) => &mut expr.optional,
+    };
+    std::mem::take(optional)
+}
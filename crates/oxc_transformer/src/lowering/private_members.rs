@@ -0,0 +1,454 @@
+//! ES2022: private class members
+//!
+//! Each private name of a class is stored in a `WeakMap`, from the instances, or the class for
+//! static members, to a property descriptor:
+//!
+//! * the field `#a = 1` is stored in `_a = new WeakMap()` by the constructor with
+//!   `_classPrivateFieldInit(this, _a, {writable: true, value: 1})`
+//! * the method `#m() {}` is moved before the class as `_m2 = function () {}`, and stored with
+//!   `{value: _m2}`. Getters and setters are stored together, `{get: _get_a, set: _set_a}`.
+//! * `this.#a` becomes `_classPrivateFieldGet(this, _a)`, `this.#a = 1` becomes
+//!   `_classPrivateFieldSet(this, _a, 1)`, `o.#m()` becomes `_classPrivateFieldGet(o, _m).call(o)`
+//! * `#a in o` becomes `_a.has(o)`
+//!
+//! Private methods and the initializers of static members are moved out of the class, where
+//! `super.a` becomes `Reflect.get(Object.getPrototypeOf(A), "a", this)`.
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, Atom, CloneIn, GetSpan, Span};
+
+use super::{helpers::Helper, names::name_hint, Lowering};
+
+/// The private names of a class with the `WeakMap` storing each of them
+pub(super) type PrivateNames = [(Atom, Atom)];
+
+/// A member read and written with calls
+enum Reference<'a> {
+    /// `object.#a` stored in `map`
+    Private { object: Expression<'a>, map: Atom },
+    /// `super[key]`
+    Super { key: Expression<'a> },
+}
+
+/// Rewrites the references to the private members of a class, and `super` in the members moved
+/// out of the class
+pub(super) struct PrivateReferences<'l, 'a> {
+    lowering: &'l mut Lowering<'a>,
+    names: &'l PrivateNames,
+    /// `Object.getPrototypeOf(A)` or `Object.getPrototypeOf(A.prototype)` in a moved member,
+    /// `super` is kept in the class when `None`
+    home: Option<Expression<'a>>,
+    /// Functions and classes, except arrows, have their own `super`
+    in_function: bool,
+}
+
+impl<'l, 'a> PrivateReferences<'l, 'a> {
+    pub(super) fn new(
+        lowering: &'l mut Lowering<'a>,
+        names: &'l PrivateNames,
+        home: Option<Expression<'a>>,
+    ) -> Self {
+        Self { lowering, names, home, in_function: false }
+    }
+
+    /// A private method moved out of the class, which keeps its `super`
+    pub(super) fn visit_moved_function(&mut self, func: &mut Function<'a>) {
+        // Temporary variables of the parameters are declared in the enclosing function
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.lowering.temporaries.push(vec![]);
+            self.lowering.names.enter_function(func.span);
+            self.visit_function_body(body);
+            self.lowering.names.leave_function();
+            let temporaries = self.lowering.temporaries.pop().unwrap_or_default();
+            self.lowering.declare_temporaries(body, &temporaries);
+        }
+    }
+
+    fn map(&self, name: &Atom) -> Option<&Atom> {
+        self.names.iter().find(|(private, _)| private == name).map(|(_, map)| map)
+    }
+
+    fn is_reference(&self, member: &MemberExpression<'a>) -> bool {
+        match member {
+            MemberExpression::PrivateFieldExpression(expr) => self.map(&expr.field.name).is_some(),
+            member => {
+                matches!(member.object(), Expression::Super(_))
+                    && self.home.is_some()
+                    && !self.in_function
+            }
+        }
+    }
+
+    /// Whether a link of the chain `expr` is a reference
+    fn has_reference(&self, expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::MemberExpression(member) => {
+                self.is_reference(member) || self.has_reference(member.object())
+            }
+            Expression::CallExpression(call) => self.has_reference(&call.callee),
+            _ => false,
+        }
+    }
+
+    fn is_reference_target(&self, target: &SimpleAssignmentTarget<'a>) -> bool {
+        matches!(target, SimpleAssignmentTarget::MemberAssignmentTarget(member)
+            if self.is_reference(member))
+    }
+
+    /// Visits the object and the computed property of the reference `member`
+    fn reference(&mut self, member: MemberExpression<'a>) -> Reference<'a> {
+        match member {
+            MemberExpression::PrivateFieldExpression(mut expr) => {
+                self.visit_expression(&mut expr.object);
+                let map = self.map(&expr.field.name).cloned().unwrap_or_default();
+                Reference::Private { object: expr.object, map }
+            }
+            MemberExpression::StaticMemberExpression(expr) => {
+                let key = self.lowering.string_literal(expr.property.span, &expr.property.name);
+                Reference::Super { key }
+            }
+            MemberExpression::ComputedMemberExpression(mut expr) => {
+                self.visit_expression(&mut expr.expression);
+                Reference::Super { key: expr.expression }
+            }
+        }
+    }
+
+    fn target_reference(&mut self, target: SimpleAssignmentTarget<'a>) -> Reference<'a> {
+        let SimpleAssignmentTarget::MemberAssignmentTarget(member) = target else {
+            unreachable!("references are members")
+        };
+        self.reference(member.unbox())
+    }
+
+    /// Evaluate the object or the key of `reference` once, returns a copy referencing its value
+    fn memoize(&mut self, reference: Reference<'a>) -> (Reference<'a>, Reference<'a>) {
+        match reference {
+            Reference::Private { object, map } => {
+                let (object, copy) = self.lowering.memoize(object);
+                (
+                    Reference::Private { object, map: map.clone() },
+                    Reference::Private { object: copy, map },
+                )
+            }
+            Reference::Super { key } => {
+                let (key, copy) = self.lowering.memoize(key);
+                (Reference::Super { key }, Reference::Super { key: copy })
+            }
+        }
+    }
+
+    /// The reference with the receiver of a call to its value
+    fn receiver(
+        &mut self,
+        span: Span,
+        reference: Reference<'a>,
+    ) -> (Reference<'a>, Expression<'a>) {
+        match reference {
+            Reference::Private { object, map } => {
+                let (object, receiver) = self.lowering.memoize(object);
+                (Reference::Private { object, map }, receiver)
+            }
+            reference @ Reference::Super { .. } => {
+                (reference, self.lowering.ast.this_expression(span))
+            }
+        }
+    }
+
+    fn home(&self) -> Expression<'a> {
+        self.home.as_ref().map(|home| home.clone_in(self.lowering.ast.allocator)).unwrap()
+    }
+
+    /// `_classPrivateFieldGet(o, _a)` or `Reflect.get(home, "a", this)`
+    fn get(&mut self, span: Span, reference: Reference<'a>) -> Expression<'a> {
+        match reference {
+            Reference::Private { object, map } => {
+                let callee = self.lowering.helper(span, Helper::ClassPrivateFieldGet);
+                let map = self.lowering.identifier(span, &map);
+                self.lowering.call(span, callee, [object, map])
+            }
+            Reference::Super { key } => {
+                let reflect = self.lowering.identifier(span, &Atom::from("Reflect"));
+                let callee = self.lowering.static_member(span, reflect, "get");
+                let this = self.lowering.ast.this_expression(span);
+                self.lowering.call(span, callee, [self.home(), key, this])
+            }
+        }
+    }
+
+    /// `_classPrivateFieldSet(o, _a, value)` or `_superPropSet(home, "a", this, value)`
+    fn set(
+        &mut self,
+        span: Span,
+        reference: Reference<'a>,
+        value: Expression<'a>,
+    ) -> Expression<'a> {
+        let (helper, arguments) = self.setter_arguments(span, reference);
+        let callee = self.lowering.helper(span, helper);
+        self.lowering.call(span, callee, arguments.into_iter().chain([value]))
+    }
+
+    fn setter_arguments(
+        &self,
+        span: Span,
+        reference: Reference<'a>,
+    ) -> (Helper, std::vec::Vec<Expression<'a>>) {
+        match reference {
+            Reference::Private { object, map } => {
+                let map = self.lowering.identifier(span, &map);
+                (Helper::ClassPrivateFieldSet, vec![object, map])
+            }
+            Reference::Super { key } => {
+                let this = self.lowering.ast.this_expression(span);
+                (Helper::SuperPropSet, vec![self.home(), key, this])
+            }
+        }
+    }
+
+    fn lower_assignment(&mut self, assign: AssignmentExpression<'a>) -> Expression<'a> {
+        let AssignmentExpression { span, operator, left, mut right } = assign;
+        let AssignmentTarget::SimpleAssignmentTarget(target) = left else {
+            unreachable!("references are simple targets")
+        };
+        let reference = self.target_reference(target);
+        self.visit_expression(&mut right);
+        self.lowering.unwrap_parenthesized(&mut right);
+        let operator = match operator {
+            AssignmentOperator::Assign => return self.set(span, reference, right),
+            AssignmentOperator::LogicalAnd => Err(LogicalOperator::And),
+            AssignmentOperator::LogicalOr => Err(LogicalOperator::Or),
+            AssignmentOperator::LogicalNullish => Err(LogicalOperator::Coalesce),
+            AssignmentOperator::Addition => Ok(BinaryOperator::Addition),
+            AssignmentOperator::Subtraction => Ok(BinaryOperator::Subtraction),
+            AssignmentOperator::Multiplication => Ok(BinaryOperator::Multiplication),
+            AssignmentOperator::Division => Ok(BinaryOperator::Division),
+            AssignmentOperator::Remainder => Ok(BinaryOperator::Remainder),
+            AssignmentOperator::ShiftLeft => Ok(BinaryOperator::ShiftLeft),
+            AssignmentOperator::ShiftRight => Ok(BinaryOperator::ShiftRight),
+            AssignmentOperator::ShiftRightZeroFill => Ok(BinaryOperator::ShiftRightZeroFill),
+            AssignmentOperator::BitwiseOR => Ok(BinaryOperator::BitwiseOR),
+            AssignmentOperator::BitwiseXOR => Ok(BinaryOperator::BitwiseXOR),
+            AssignmentOperator::BitwiseAnd => Ok(BinaryOperator::BitwiseAnd),
+            AssignmentOperator::Exponential => Ok(BinaryOperator::Exponential),
+        };
+        let (reference, copy) = self.memoize(reference);
+        match operator {
+            // `o.#a += 1` becomes `set(o, _a, get(o, _a) + 1)`
+            Ok(operator) => {
+                let value = self.get(span, copy);
+                let value = self.lowering.ast.binary_expression(span, value, operator, right);
+                self.set(span, reference, value)
+            }
+            // `o.#a ||= 1` becomes `get(o, _a) || set(o, _a, 1)`,
+            // the logical assignments are lowered before when the target is older
+            Err(operator) => {
+                let value = self.get(span, reference);
+                let set = self.set(span, copy, right);
+                let logical = self.lowering.ast.logical_expression(span, value, operator, set);
+                self.lowering.parenthesized(logical)
+            }
+        }
+    }
+
+    /// `++o.#a` becomes `set(o, _a, (_o$a = get(o, _a), ++_o$a))`,
+    /// `o.#a++` becomes `(set(o, _a, (_o$a = get(o, _a), _o$a2 = _o$a++, _o$a)), _o$a2)`
+    fn lower_update(&mut self, update: UpdateExpression<'a>) -> Expression<'a> {
+        let UpdateExpression { span, operator, prefix, argument } = update;
+        let hint = name_hint(&super::simple_target_to_expression(
+            argument.clone_in(self.lowering.ast.allocator),
+        ));
+        let reference = self.target_reference(argument);
+        let (reference, copy) = self.memoize(reference);
+        let value = self.lowering.temporary(&hint);
+        let current = self.get(span, copy);
+        let mut expressions = self.lowering.ast.new_vec();
+        expressions.push(self.lowering.assign(span, &value, current));
+        let target = self.lowering.identifier_target(span, &value);
+        let update = self.lowering.ast.update_expression(span, operator, prefix, target);
+        if prefix {
+            expressions.push(update);
+            let sequence = self.lowering.ast.sequence_expression(span, expressions);
+            let sequence = self.lowering.parenthesized(sequence);
+            return self.set(span, reference, sequence);
+        }
+        let old = self.lowering.temporary(&hint);
+        expressions.push(self.lowering.assign(span, &old, update));
+        expressions.push(self.lowering.identifier(span, &value));
+        let sequence = self.lowering.ast.sequence_expression(span, expressions);
+        let sequence = self.lowering.parenthesized(sequence);
+        let set = self.set(span, reference, sequence);
+        let old = self.lowering.identifier(span, &old);
+        let expressions = Vec::from_iter_in([set, old], self.lowering.ast.allocator);
+        let sequence = self.lowering.ast.sequence_expression(span, expressions);
+        self.lowering.parenthesized(sequence)
+    }
+
+    /// `o.#m(a)` becomes `get(_o = o, _m).call(_o, a)`
+    fn lower_call(&mut self, mut call: CallExpression<'a>) -> Expression<'a> {
+        let span = call.span;
+        let Expression::MemberExpression(member) =
+            self.lowering.ast.move_expression(&mut call.callee)
+        else {
+            unreachable!("references are members")
+        };
+        let reference = self.reference(member.unbox());
+        let (reference, receiver) = self.receiver(span, reference);
+        let function = self.get(span, reference);
+        call.callee = self.lowering.static_member(span, function, "call");
+        for argument in call.arguments.iter_mut() {
+            self.visit_argument(argument);
+        }
+        call.arguments.insert(0, Argument::Expression(receiver));
+        Expression::CallExpression(self.lowering.ast.alloc(call))
+    }
+
+    /// ``o.#m`a` `` becomes ``get(_o = o, _m).bind(_o)`a` ``
+    fn lower_tagged_template(
+        &mut self,
+        mut tagged: TaggedTemplateExpression<'a>,
+    ) -> Expression<'a> {
+        let span = tagged.span;
+        let Expression::MemberExpression(member) =
+            self.lowering.ast.move_expression(&mut tagged.tag)
+        else {
+            unreachable!("references are members")
+        };
+        let reference = self.reference(member.unbox());
+        let (reference, receiver) = self.receiver(span, reference);
+        let function = self.get(span, reference);
+        let bind = self.lowering.static_member(span, function, "bind");
+        tagged.tag = self.lowering.call(span, bind, [receiver]);
+        self.visit_template_literal(&mut tagged.quasi);
+        Expression::TaggedTemplateExpression(self.lowering.ast.alloc(tagged))
+    }
+}
+
+impl<'a> VisitMut<'a> for PrivateReferences<'_, 'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let lowered = match expr {
+            Expression::MemberExpression(member) => self.is_reference(member),
+            Expression::ChainExpression(chain) => match &chain.expression {
+                ChainElement::CallExpression(call) => self.has_reference(&call.callee),
+                ChainElement::MemberExpression(member) => {
+                    self.is_reference(member) || self.has_reference(member.object())
+                }
+            },
+            Expression::AssignmentExpression(assign) => matches!(&assign.left,
+                AssignmentTarget::SimpleAssignmentTarget(target)
+                    if self.is_reference_target(target)),
+            Expression::UpdateExpression(update) => self.is_reference_target(&update.argument),
+            Expression::CallExpression(call) => matches!(&call.callee,
+                Expression::MemberExpression(member) if self.is_reference(member)),
+            Expression::TaggedTemplateExpression(tagged) => matches!(&tagged.tag,
+                Expression::MemberExpression(member) if self.is_reference(member)),
+            Expression::PrivateInExpression(expr) => self.map(&expr.left.name).is_some(),
+            _ => false,
+        };
+        if !lowered {
+            self.visit_expression_match(expr);
+            return;
+        }
+        *expr = match self.lowering.ast.move_expression(expr) {
+            Expression::MemberExpression(member) => {
+                let span = member.span();
+                let reference = self.reference(member.unbox());
+                self.get(span, reference)
+            }
+            // The links of the chain are references after the chain is lowered
+            Expression::ChainExpression(chain) => {
+                let mut expr = self.lowering.lower_optional_chain(chain.unbox());
+                self.visit_expression(&mut expr);
+                expr
+            }
+            Expression::AssignmentExpression(assign) => self.lower_assignment(assign.unbox()),
+            Expression::UpdateExpression(update) => self.lower_update(update.unbox()),
+            Expression::CallExpression(call) => self.lower_call(call.unbox()),
+            Expression::TaggedTemplateExpression(tagged) => {
+                self.lower_tagged_template(tagged.unbox())
+            }
+            Expression::PrivateInExpression(expr) => {
+                let PrivateInExpression { span, left, mut right, .. } = expr.unbox();
+                self.visit_expression(&mut right);
+                let map = self.map(&left.name).cloned().unwrap_or_default();
+                let map = self.lowering.identifier(left.span, &map);
+                let has = self.lowering.static_member(span, map, "has");
+                self.lowering.call(span, has, [right])
+            }
+            _ => unreachable!(),
+        };
+    }
+
+    /// A reference assigned by destructuring or a `for-in`/`for-of` statement becomes
+    /// `_toSetter(_classPrivateFieldSet, [o, _a])._`
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        if !self.is_reference_target(target) {
+            match target {
+                SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                    self.visit_identifier_reference(ident);
+                }
+                SimpleAssignmentTarget::MemberAssignmentTarget(member) => {
+                    self.visit_member_expression(member);
+                }
+                SimpleAssignmentTarget::TSAsExpression(expr) => {
+                    self.visit_expression(&mut expr.expression);
+                }
+                SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+                    self.visit_expression(&mut expr.expression);
+                }
+                SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+                    self.visit_expression(&mut expr.expression);
+                }
+            }
+            return;
+        }
+        let span = target.span();
+        let placeholder = self.lowering.identifier_target(span, &Atom::default());
+        let reference = self.target_reference(std::mem::replace(target, placeholder));
+        let (helper, arguments) = self.setter_arguments(span, reference);
+        let set = self.lowering.helper(span, helper);
+        let arguments = Vec::from_iter_in(
+            arguments.into_iter().map(|argument| Some(Argument::Expression(argument))),
+            self.lowering.ast.allocator,
+        );
+        let arguments = self.lowering.ast.array_expression(span, arguments, None);
+        let callee = self.lowering.helper(span, Helper::ToSetter);
+        let setter = self.lowering.call(span, callee, [set, arguments]);
+        let member = MemberExpression::StaticMemberExpression(StaticMemberExpression {
+            span,
+            object: setter,
+            property: IdentifierName { span, name: Atom::from("_") },
+            optional: false,
+        });
+        *target = SimpleAssignmentTarget::MemberAssignmentTarget(self.lowering.ast.alloc(member));
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        let in_function = std::mem::replace(&mut self.in_function, true);
+        self.visit_moved_function(func);
+        self.in_function = in_function;
+    }
+
+    fn visit_arrow_expression(&mut self, arrow: &mut ArrowExpression<'a>) {
+        self.visit_formal_parameters(&mut arrow.params);
+        self.lowering.temporaries.push(vec![]);
+        self.lowering.names.enter_function(arrow.span);
+        self.visit_function_body(&mut arrow.body);
+        self.lowering.names.leave_function();
+        let temporaries = self.lowering.temporaries.pop().unwrap_or_default();
+        if !temporaries.is_empty() {
+            self.lowering.ensure_block_body(arrow);
+            self.lowering.declare_temporaries(&mut arrow.body, &temporaries);
+        }
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_class_heritage(super_class);
+        }
+        let in_function = std::mem::replace(&mut self.in_function, true);
+        self.visit_class_body(&mut class.body);
+        self.in_function = in_function;
+    }
+}
@@ -0,0 +1,697 @@
+//! React JSX
+//!
+//! Lowers JSX elements and fragments to function calls, producing the same code as
+//! `@babel/plugin-transform-react-jsx`:
+//!
+//! * the classic runtime calls `React.createElement(type, props, ...children)`,
+//!   the functions are configured with `pragma` and `pragma_frag`
+//! * the automatic runtime calls `jsx(type, props, key)` imported from `react/jsx-runtime`,
+//!   children are passed in `props` and several children are created with `jsxs`.
+//!   A `key` after a spread attribute can't be told apart from `props.key`,
+//!   so these elements fall back to `createElement` imported from `react`
+//! * the development mode passes the location of the element (`__source`) and the component
+//!   rendering it (`__self`) to React, for its warnings
+//!
+//! HTML entities are decoded and the whitespace of text children is collapsed like React does:
+//! lines are trimmed and joined with a space, lines only containing whitespace are removed.
+
+mod entities;
+
+use std::path::Path;
+
+use oxc_allocator::{Allocator, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, AstBuilder, Atom, Span};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsxRuntime {
+    /// `React.createElement(type, props, ...children)`
+    #[default]
+    Classic,
+    /// `jsx(type, props, key)` imported from `react/jsx-runtime`, available since React 17
+    Automatic,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ReactJsxOptions {
+    pub runtime: JsxRuntime,
+
+    /// Pass `__source` and `__self` to React, and use `react/jsx-dev-runtime` with the
+    /// automatic runtime. Should only be enabled in development builds.
+    pub development: bool,
+
+    /// Module the automatic runtime is imported from, `react` by default:
+    /// the functions are imported from `react/jsx-runtime`
+    pub import_source: Option<String>,
+
+    /// Function creating the elements with the classic runtime, `React.createElement` by default
+    pub pragma: Option<String>,
+
+    /// Component of the fragments with the classic runtime, `React.Fragment` by default
+    pub pragma_frag: Option<String>,
+}
+
+impl ReactJsxOptions {
+    fn import_source(&self) -> &str {
+        self.import_source.as_deref().unwrap_or("react")
+    }
+
+    fn pragma(&self) -> &str {
+        self.pragma.as_deref().unwrap_or("React.createElement")
+    }
+
+    fn pragma_frag(&self) -> &str {
+        self.pragma_frag.as_deref().unwrap_or("React.Fragment")
+    }
+}
+
+/// Name of the variable holding the file name for `__source`
+const FILE_NAME_VAR: &str = "_jsxFileName";
+
+pub struct ReactJsx<'a> {
+    ast: AstBuilder<'a>,
+    options: ReactJsxOptions,
+    file_name: String,
+
+    /// Offsets of the start of each line, for the line and column numbers of `__source`
+    line_starts: std::vec::Vec<usize>,
+    source_text: &'a str,
+
+    /// Names imported by the automatic runtime, in order of first use.
+    /// They are imported as `_name`.
+    imports: std::vec::Vec<&'static str>,
+
+    /// Whether `_jsxFileName` is referenced
+    uses_file_name: bool,
+
+    /// Whether `this` can be passed as `__self` in each function being visited
+    this_allowed: std::vec::Vec<bool>,
+
+    /// Whether each class being visited extends another class
+    derived_classes: std::vec::Vec<bool>,
+}
+
+impl<'a> ReactJsx<'a> {
+    #[must_use]
+    pub fn new(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_path: &Path,
+        options: ReactJsxOptions,
+    ) -> Self {
+        let line_starts = if options.development { line_starts(source_text) } else { vec![] };
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            file_name: source_path.to_string_lossy().into_owned(),
+            line_starts,
+            source_text,
+            imports: vec![],
+            uses_file_name: false,
+            this_allowed: vec![],
+            derived_classes: vec![],
+        }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        self.visit_program(program);
+
+        let span = Span::new(program.span.start, program.span.start);
+        let mut body = self.ast.new_vec();
+        self.push_imports(span, program.source_type.is_module(), &mut body);
+        if self.uses_file_name {
+            let value = self.string_literal(span, &self.file_name);
+            body.push(self.variable(span, self.binding(span, FILE_NAME_VAR), value));
+        }
+        if !body.is_empty() {
+            body.extend(self.ast.move_statement_vec(&mut program.body));
+            program.body = body;
+        }
+    }
+
+    /// `import { jsx as _jsx } from 'react/jsx-runtime'` in modules,
+    /// `var { jsx: _jsx } = require('react/jsx-runtime')` in scripts
+    fn push_imports(&self, span: Span, is_module: bool, out: &mut Vec<'a, Statement<'a>>) {
+        let runtime_source = if self.options.development {
+            format!("{}/jsx-dev-runtime", self.options.import_source())
+        } else {
+            format!("{}/jsx-runtime", self.options.import_source())
+        };
+        let (create_element, runtime): (std::vec::Vec<_>, std::vec::Vec<_>) =
+            self.imports.iter().copied().partition(|name| *name == "createElement");
+        for (names, source) in
+            [(runtime, runtime_source.as_str()), (create_element, self.options.import_source())]
+        {
+            if names.is_empty() {
+                continue;
+            }
+            if is_module {
+                let specifiers = names.iter().map(|name| {
+                    ImportDeclarationSpecifier::ImportSpecifier(ImportSpecifier {
+                        span,
+                        imported: ModuleExportName::Identifier(IdentifierName {
+                            span,
+                            name: Atom::from(*name),
+                        }),
+                        local: BindingIdentifier { span, name: local_name(name) },
+                        import_kind: ImportOrExportKind::Value,
+                    })
+                });
+                let specifiers = Vec::from_iter_in(specifiers, self.ast.allocator);
+                let source = StringLiteral { span, value: Atom::from(source) };
+                let import = self.ast.import_declaration(specifiers, source, None, None);
+                out.push(
+                    self.ast
+                        .module_declaration(span, ModuleDeclarationKind::ImportDeclaration(import)),
+                );
+            } else {
+                let properties = names.iter().map(|name| {
+                    let key = PropertyKey::Identifier(
+                        self.ast.alloc(IdentifierName { span, name: Atom::from(*name) }),
+                    );
+                    let value = PropertyValue::Pattern(self.binding(span, &local_name(name)));
+                    ObjectPatternProperty::Property(self.ast.property(
+                        span,
+                        PropertyKind::Init,
+                        key,
+                        value,
+                        false,
+                        false,
+                        false,
+                    ))
+                });
+                let properties = Vec::from_iter_in(properties, self.ast.allocator);
+                let pattern = self.ast.binding_pattern(
+                    self.ast.object_pattern(span, properties),
+                    None,
+                    false,
+                );
+                let require = self.ast.call_expression(
+                    span,
+                    self.identifier(span, "require"),
+                    self.ast
+                        .new_vec_single(Argument::Expression(self.string_literal(span, source))),
+                    false,
+                    None,
+                );
+                out.push(self.variable(span, pattern, require));
+            }
+        }
+    }
+
+    fn transform_element(&mut self, elem: JSXElement<'a>) -> Expression<'a> {
+        let opening_element = elem.opening_element.unbox();
+        let element_type = self.element_type(opening_element.name);
+        self.create_element(elem.span, element_type, opening_element.attributes, elem.children)
+    }
+
+    fn transform_fragment(&mut self, fragment: JSXFragment<'a>) -> Expression<'a> {
+        let span = fragment.span;
+        let element_type = match self.options.runtime {
+            JsxRuntime::Classic => self.pragma_expression(span, self.options.pragma_frag()),
+            JsxRuntime::Automatic => self.import(span, "Fragment"),
+        };
+        self.create_element(span, element_type, self.ast.new_vec(), fragment.children)
+    }
+
+    fn create_element(
+        &mut self,
+        span: Span,
+        element_type: Expression<'a>,
+        attributes: Vec<'a, JSXAttributeItem<'a>>,
+        children: Vec<'a, JSXChild<'a>>,
+    ) -> Expression<'a> {
+        if self.options.runtime == JsxRuntime::Automatic && !has_key_after_spread(&attributes) {
+            return self.create_automatic_element(span, element_type, attributes, children);
+        }
+
+        let mut properties = self.transform_attributes(attributes, None);
+        if self.options.development {
+            if self.is_this_allowed() {
+                let this = self.ast.this_expression(span);
+                properties.push(self.object_property(span, "__self", this));
+            }
+            let source = self.source_object(span);
+            properties.push(self.object_property(span, "__source", source));
+        }
+
+        let mut arguments = self.ast.new_vec_single(Argument::Expression(element_type));
+        arguments.push(Argument::Expression(if properties.is_empty() {
+            self.ast.literal_null_expression(NullLiteral { span })
+        } else {
+            self.ast.object_expression(span, properties, None)
+        }));
+        for child in children {
+            if let Some(child) = self.transform_child(child) {
+                arguments.push(child);
+            }
+        }
+
+        let callee = match self.options.runtime {
+            JsxRuntime::Classic => self.pragma_expression(span, self.options.pragma()),
+            JsxRuntime::Automatic => self.import(span, "createElement"),
+        };
+        self.ast.call_expression(span, callee, arguments, false, None)
+    }
+
+    /// `jsx(type, { ...props, children }, key)`, or in development
+    /// `jsxDEV(type, { ...props, children }, key, isStaticChildren, source, this)`
+    fn create_automatic_element(
+        &mut self,
+        span: Span,
+        element_type: Expression<'a>,
+        attributes: Vec<'a, JSXAttributeItem<'a>>,
+        children: Vec<'a, JSXChild<'a>>,
+    ) -> Expression<'a> {
+        let mut key = None;
+        let mut properties = self.transform_attributes(attributes, Some(&mut key));
+
+        let mut children = children
+            .into_iter()
+            .filter_map(|child| self.transform_child(child))
+            .collect::<std::vec::Vec<_>>();
+        let is_static_children = children.len() > 1;
+        if children.len() == 1
+            && let Some(Argument::Expression(_)) = children.last()
+        {
+            let Some(Argument::Expression(child)) = children.pop() else { unreachable!() };
+            properties.push(self.object_property(span, "children", child));
+        } else if !children.is_empty() {
+            let elements = Vec::from_iter_in(children.into_iter().map(Some), self.ast.allocator);
+            let children = self.ast.array_expression(span, elements, None);
+            properties.push(self.object_property(span, "children", children));
+        }
+
+        let mut arguments = self.ast.new_vec_single(Argument::Expression(element_type));
+        arguments.push(Argument::Expression(self.ast.object_expression(span, properties, None)));
+
+        if !self.options.development {
+            if let Some(key) = key {
+                arguments.push(Argument::Expression(key));
+            }
+            let callee = self.import(span, if is_static_children { "jsxs" } else { "jsx" });
+            return self.ast.call_expression(span, callee, arguments, false, None);
+        }
+
+        let key = key.unwrap_or_else(|| self.void_zero(span));
+        arguments.push(Argument::Expression(key));
+        arguments.push(Argument::Expression(
+            self.ast.literal_boolean_expression(BooleanLiteral { span, value: is_static_children }),
+        ));
+        arguments.push(Argument::Expression(self.source_object(span)));
+        if self.is_this_allowed() {
+            arguments.push(Argument::Expression(self.ast.this_expression(span)));
+        }
+        let callee = self.import(span, "jsxDEV");
+        self.ast.call_expression(span, callee, arguments, false, None)
+    }
+
+    /// The `key` attribute is moved to `key` when given
+    fn transform_attributes(
+        &mut self,
+        attributes: Vec<'a, JSXAttributeItem<'a>>,
+        mut key: Option<&mut Option<Expression<'a>>>,
+    ) -> Vec<'a, ObjectProperty<'a>> {
+        let mut properties = self.ast.new_vec_with_capacity(attributes.len());
+        for attribute in attributes {
+            let attribute = match attribute {
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let JSXSpreadAttribute { span, mut argument } = spread.unbox();
+                    self.visit_expression(&mut argument);
+                    properties.push(ObjectProperty::SpreadProperty(
+                        self.ast.spread_element(span, argument),
+                    ));
+                    continue;
+                }
+                JSXAttributeItem::Attribute(attribute) => attribute.unbox(),
+            };
+            let span = attribute.span;
+            // `<div a={} />`
+            let Some(value) = self.transform_attribute_value(span, attribute.value) else {
+                continue;
+            };
+            let name = match attribute.name {
+                JSXAttributeName::Identifier(ident) => ident.name,
+                JSXAttributeName::NamespacedName(name) => {
+                    Atom::from(format!("{}:{}", name.namespace.name, name.property.name))
+                }
+            };
+            if name == "key"
+                && let Some(key) = key.as_deref_mut()
+            {
+                *key = Some(value);
+                continue;
+            }
+            properties.push(self.object_property(span, &name, value));
+        }
+        properties
+    }
+
+    fn transform_attribute_value(
+        &mut self,
+        span: Span,
+        value: Option<JSXAttributeValue<'a>>,
+    ) -> Option<Expression<'a>> {
+        let value = match value {
+            None => self.ast.literal_boolean_expression(BooleanLiteral { span, value: true }),
+            Some(JSXAttributeValue::StringLiteral(literal)) => {
+                let value = decode_entities(&collapse_line_breaks(&literal.value));
+                self.string_literal(literal.span, &value)
+            }
+            Some(JSXAttributeValue::ExpressionContainer(container)) => {
+                let JSXExpression::Expression(mut expr) = container.expression else { return None };
+                self.visit_expression(&mut expr);
+                expr
+            }
+            Some(JSXAttributeValue::Element(elem)) => self.transform_element(elem.unbox()),
+            Some(JSXAttributeValue::Fragment(fragment)) => {
+                self.transform_fragment(fragment.unbox())
+            }
+        };
+        Some(value)
+    }
+
+    fn transform_child(&mut self, child: JSXChild<'a>) -> Option<Argument<'a>> {
+        let expr = match child {
+            JSXChild::Text(text) => {
+                let value = clean_text(&text.value);
+                if value.is_empty() {
+                    return None;
+                }
+                self.string_literal(text.span, &decode_entities(&value))
+            }
+            JSXChild::Element(elem) => self.transform_element(elem.unbox()),
+            JSXChild::Fragment(fragment) => self.transform_fragment(fragment.unbox()),
+            JSXChild::ExpressionContainer(container) => {
+                // `{/* comment */}`
+                let JSXExpression::Expression(mut expr) = container.expression else { return None };
+                self.visit_expression(&mut expr);
+                expr
+            }
+            JSXChild::Spread(spread) => {
+                let JSXSpreadChild { span, mut expression } = spread;
+                self.visit_expression(&mut expression);
+                return Some(Argument::SpreadElement(self.ast.spread_element(span, expression)));
+            }
+        };
+        Some(Argument::Expression(expr))
+    }
+
+    /// Lowercase names are intrinsic elements like `div`, which are passed as strings
+    fn element_type(&self, name: JSXElementName<'a>) -> Expression<'a> {
+        match name {
+            JSXElementName::Identifier(ident) => {
+                if ident.name == "this" {
+                    self.ast.this_expression(ident.span)
+                } else if ident.name.starts_with(|c: char| c.is_ascii_lowercase())
+                    || ident.name.contains('-')
+                {
+                    self.string_literal(ident.span, &ident.name)
+                } else {
+                    self.identifier(ident.span, &ident.name)
+                }
+            }
+            JSXElementName::NamespacedName(name) => {
+                let value = format!("{}:{}", name.namespace.name, name.property.name);
+                self.string_literal(name.span, &value)
+            }
+            JSXElementName::MemberExpression(expr) => self.member_expression(expr.unbox()),
+        }
+    }
+
+    fn member_expression(&self, expr: JSXMemberExpression<'a>) -> Expression<'a> {
+        let object = match expr.object {
+            JSXMemberExpressionObject::Identifier(ident) if ident.name == "this" => {
+                self.ast.this_expression(ident.span)
+            }
+            JSXMemberExpressionObject::Identifier(ident) => {
+                self.identifier(ident.span, &ident.name)
+            }
+            JSXMemberExpressionObject::MemberExpression(expr) => {
+                self.member_expression(expr.unbox())
+            }
+        };
+        let property = IdentifierName { span: expr.property.span, name: expr.property.name };
+        self.ast.static_member_expression(expr.span, object, property, false)
+    }
+
+    /// `React.createElement` -> `React.createElement`
+    fn pragma_expression(&self, span: Span, pragma: &str) -> Expression<'a> {
+        let mut parts = pragma.split('.');
+        let object = match parts.next() {
+            Some("this") => self.ast.this_expression(span),
+            object => self.identifier(span, object.unwrap_or_default()),
+        };
+        parts.fold(object, |object, property| {
+            let property = IdentifierName { span, name: Atom::from(property) };
+            self.ast.static_member_expression(span, object, property, false)
+        })
+    }
+
+    /// Reference to `name` imported from the automatic runtime
+    fn import(&mut self, span: Span, name: &'static str) -> Expression<'a> {
+        if !self.imports.contains(&name) {
+            self.imports.push(name);
+        }
+        self.identifier(span, &local_name(name))
+    }
+
+    /// `{ fileName: _jsxFileName, lineNumber: 1, columnNumber: 1 }`
+    fn source_object(&mut self, span: Span) -> Expression<'a> {
+        self.uses_file_name = true;
+        let offset = span.start as usize;
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let line_start = self.line_starts[line - 1];
+        // Columns are counted in UTF-16 code units like in JavaScript
+        let column = self.source_text[line_start..offset].encode_utf16().count() + 1;
+
+        let mut properties = self.ast.new_vec_with_capacity(3);
+        let file_name = self.identifier(span, FILE_NAME_VAR);
+        properties.push(self.object_property(span, "fileName", file_name));
+        let line_number = self.number_literal(span, line);
+        properties.push(self.object_property(span, "lineNumber", line_number));
+        let column_number = self.number_literal(span, column);
+        properties.push(self.object_property(span, "columnNumber", column_number));
+        self.ast.object_expression(span, properties, None)
+    }
+
+    fn is_this_allowed(&self) -> bool {
+        self.this_allowed.last().copied().unwrap_or(true)
+    }
+
+    fn visit_function_with_this(&mut self, func: &mut Function<'a>, this_allowed: bool) {
+        self.this_allowed.push(this_allowed);
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        self.this_allowed.pop();
+    }
+
+    /// `name: value`, the name is quoted when it is not an identifier (`aria-label`)
+    fn object_property(&self, span: Span, name: &str, value: Expression<'a>) -> ObjectProperty<'a> {
+        let key = if name.contains(['-', ':']) {
+            PropertyKey::Expression(self.string_literal(span, name))
+        } else {
+            PropertyKey::Identifier(self.ast.alloc(IdentifierName { span, name: Atom::from(name) }))
+        };
+        ObjectProperty::Property(self.ast.property(
+            span,
+            PropertyKind::Init,
+            key,
+            PropertyValue::Expression(value),
+            false,
+            false,
+            false,
+        ))
+    }
+
+    fn variable(&self, span: Span, id: BindingPattern<'a>, init: Expression<'a>) -> Statement<'a> {
+        let kind = VariableDeclarationKind::Var;
+        let declarator = self.ast.variable_declarator(span, kind, id, Some(init), false);
+        let declarations = self.ast.new_vec_single(declarator);
+        let decl = self.ast.variable_declaration(span, kind, declarations, Modifiers::empty());
+        Statement::Declaration(Declaration::VariableDeclaration(decl))
+    }
+
+    fn binding(&self, span: Span, name: &str) -> BindingPattern<'a> {
+        let ident = self.ast.binding_identifier(BindingIdentifier { span, name: Atom::from(name) });
+        self.ast.binding_pattern(ident, None, false)
+    }
+
+    fn identifier(&self, span: Span, name: &str) -> Expression<'a> {
+        self.ast.identifier_expression(IdentifierReference { span, name: Atom::from(name) })
+    }
+
+    fn string_literal(&self, span: Span, value: &str) -> Expression<'a> {
+        self.ast.literal_string_expression(StringLiteral { span, value: Atom::from(value) })
+    }
+
+    fn number_literal(&self, span: Span, value: usize) -> Expression<'a> {
+        let raw = self.ast.new_str(&value.to_string());
+        #[allow(clippy::cast_precision_loss)]
+        let value = value as f64;
+        self.ast.literal_number_expression(NumberLiteral::new(
+            span,
+            value,
+            raw,
+            NumberBase::Decimal,
+        ))
+    }
+
+    fn void_zero(&self, span: Span) -> Expression<'a> {
+        let zero = self.number_literal(span, 0);
+        self.ast.unary_expression(span, UnaryOperator::Void, true, zero)
+    }
+}
+
+impl<'a> VisitMut<'a> for ReactJsx<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        match expr {
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => {
+                *expr = match self.ast.move_expression(expr) {
+                    Expression::JSXElement(elem) => self.transform_element(elem.unbox()),
+                    Expression::JSXFragment(fragment) => self.transform_fragment(fragment.unbox()),
+                    _ => unreachable!(),
+                };
+            }
+            _ => self.visit_expression_match(expr),
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        self.visit_function_with_this(func, true);
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        for decorator in class.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_class_heritage(super_class);
+        }
+        self.derived_classes.push(class.super_class.is_some());
+        self.visit_class_body(&mut class.body);
+        self.derived_classes.pop();
+    }
+
+    fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        // `this` can't be referenced before `super()` in the constructor of a derived class
+        let this_allowed = def.kind != MethodDefinitionKind::Constructor
+            || self.derived_classes.last() != Some(&true);
+        self.visit_function_with_this(&mut def.value, this_allowed);
+    }
+}
+
+fn local_name(name: &str) -> Atom {
+    Atom::from(format!("_{name}"))
+}
+
+/// The element must be created with `createElement` to keep the order of `key` and the spread
+fn has_key_after_spread(attributes: &[JSXAttributeItem]) -> bool {
+    attributes
+        .iter()
+        .skip_while(|attribute| matches!(attribute, JSXAttributeItem::Attribute(_)))
+        .any(|attribute| {
+            matches!(attribute, JSXAttributeItem::Attribute(attribute)
+                if matches!(&attribute.name, JSXAttributeName::Identifier(ident) if ident.name == "key"))
+        })
+}
+
+fn line_starts(source_text: &str) -> std::vec::Vec<usize> {
+    let mut line_starts = vec![0];
+    let mut chars = source_text.char_indices().peekable();
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '\r' if matches!(chars.peek(), Some((_, '\n'))) => {}
+            '\n' | '\r' | '\u{2028}' | '\u{2029}' => line_starts.push(offset + c.len_utf8()),
+            _ => {}
+        }
+    }
+    line_starts
+}
+
+/// Trims the lines of a text child and joins the lines which are not empty with a space,
+/// see `cleanJSXElementLiteralChild` in Babel
+fn clean_text(text: &str) -> String {
+    let lines = text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).replace('\t', " "))
+        .collect::<std::vec::Vec<_>>();
+    let last_non_empty_line =
+        lines.iter().rposition(|line| line.chars().any(|c| c != ' ')).unwrap_or_default();
+
+    let mut cleaned = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        let mut line = line.as_str();
+        if i != 0 {
+            line = line.trim_start_matches(' ');
+        }
+        if i != lines.len() - 1 {
+            line = line.trim_end_matches(' ');
+        }
+        if !line.is_empty() {
+            cleaned.push_str(line);
+            if i != last_non_empty_line {
+                cleaned.push(' ');
+            }
+        }
+    }
+    cleaned
+}
+
+/// Replaces the line breaks followed by whitespace with a space in attribute strings
+fn collapse_line_breaks(value: &str) -> String {
+    let mut collapsed = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' && chars.peek().map_or(false, |c| c.is_whitespace()) {
+            while chars.peek().map_or(false, |c| c.is_whitespace()) {
+                chars.next();
+            }
+            collapsed.push(' ');
+        } else {
+            collapsed.push(c);
+        }
+    }
+    collapsed
+}
+
+/// `&amp;`, `&#38;` and `&#x26;` -> `&`, unknown entities are kept as is
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let entity = rest.find(';').and_then(|end| decode_entity(&rest[..end]).map(|c| (c, end)));
+        if let Some((c, end)) = entity {
+            decoded.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            decoded.push('&');
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    let Some(number) = entity.strip_prefix('#') else { return entities::entity(entity) };
+    let code = if let Some(hex) = number.strip_prefix('x') {
+        if hex.is_empty() || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        u32::from_str_radix(hex, 16).ok()?
+    } else {
+        if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        number.parse().ok()?
+    };
+    char::from_u32(code)
+}
@@ -0,0 +1,372 @@
+//! Enums
+//!
+//! ```ts
+//! enum E { A, B = A + 1, C = 'c' }
+//! ```
+//! is lowered to
+//! ```js
+//! var E;
+//! (function (E) {
+//!   E[E["A"] = 0] = "A";
+//!   E[E["B"] = 1] = "B";
+//!   E["C"] = "c";
+//! })(E || (E = {}));
+//! ```
+//! Members with a constant initializer are evaluated, references to earlier members in the
+//! other initializers become `E.A`.
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, AstBuilder, Atom, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{references::ReferenceRewriter, static_member, Export, TypeScript};
+
+#[derive(Debug, Clone)]
+pub enum ConstantValue {
+    Number(f64),
+    String(Atom),
+}
+
+/// Constant values of the members of an enum
+pub type EnumMembers = FxHashMap<Atom, ConstantValue>;
+
+impl<'a> TypeScript<'a> {
+    pub(super) fn transform_enum(
+        &mut self,
+        decl: &mut TSEnumDeclaration<'a>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        if decl.modifiers.contains(ModifierKind::Declare) {
+            return;
+        }
+        // Uses of a const enum are inlined, so it only exists at runtime when exported
+        if decl.modifiers.contains(ModifierKind::Const) && matches!(export, Export::None) {
+            return;
+        }
+
+        let span = decl.span;
+        let name = decl.id.name.clone();
+        let mut members = self.enums.remove(&name).unwrap_or_default();
+        // Names of the members declared so far, referenced as `E.A` in the computed initializers
+        let mut member_names = FxHashSet::default();
+        let mut body = self.ast.new_vec_with_capacity(decl.members.len());
+        let mut previous: Option<(Atom, Option<ConstantValue>)> = None;
+
+        for member in decl.members.iter_mut() {
+            let Some(member_name) = member_name(&member.id) else { continue };
+            let constant = member.initializer.as_ref().map_or_else(
+                || next_value(previous.as_ref().map(|(_, value)| value)),
+                |init| evaluate(init, &name, &members, &self.enums),
+            );
+
+            let (value, is_string) = if let Some(constant) = &constant {
+                let value = constant_to_expression(&self.ast, member.span, constant);
+                (value, matches!(constant, ConstantValue::String(_)))
+            } else {
+                let value = if let Some(init) = &mut member.initializer {
+                    self.visit_expression(init);
+                    let mut init = self.ast.move_expression(init);
+                    ReferenceRewriter::new(&self.ast, &member_names, &name)
+                        .visit_expression(&mut init);
+                    init
+                } else {
+                    let (previous_name, _) = previous.as_ref().unwrap();
+                    self.increment_member(member.span, &name, previous_name)
+                };
+                // A template literal is a string, which has no reverse mapping
+                let is_string = matches!(value, Expression::TemplateLiteral(_));
+                (value, is_string)
+            };
+            let expr = self.member_assignment(member.span, &name, &member_name, value, is_string);
+            body.push(self.ast.expression_statement(member.span, expr));
+
+            if let Some(constant) = &constant {
+                members.insert(member_name.clone(), constant.clone());
+            }
+            member_names.insert(member_name.clone());
+            previous = Some((member_name, constant));
+        }
+
+        self.enums.insert(name.clone(), members);
+        self.push_object_initializer(span, &name, body, export, out);
+    }
+
+    /// `1 + E.previous`
+    fn increment_member(&self, span: Span, name: &Atom, previous: &Atom) -> Expression<'a> {
+        let one = self.ast.literal_number_expression(NumberLiteral::new(
+            span,
+            1.0,
+            "1",
+            NumberBase::Decimal,
+        ));
+        let object = super::identifier(&self.ast, span, name);
+        let previous =
+            Expression::MemberExpression(static_member(&self.ast, span, object, previous));
+        self.ast.binary_expression(span, one, BinaryOperator::Addition, previous)
+    }
+
+    /// `E[E["A"] = value] = "A"`, or `E["A"] = value` for strings
+    fn member_assignment(
+        &self,
+        span: Span,
+        name: &Atom,
+        member: &Atom,
+        value: Expression<'a>,
+        is_string: bool,
+    ) -> Expression<'a> {
+        let target =
+            SimpleAssignmentTarget::MemberAssignmentTarget(self.enum_member(span, name, member));
+        let assignment = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        );
+        if is_string {
+            return assignment;
+        }
+        let object = super::identifier(&self.ast, span, name);
+        let target =
+            self.ast.alloc(MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
+                span,
+                object,
+                expression: assignment,
+                optional: false,
+            }));
+        let reverse =
+            self.ast.literal_string_expression(StringLiteral { span, value: member.clone() });
+        self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(
+                SimpleAssignmentTarget::MemberAssignmentTarget(target),
+            ),
+            reverse,
+        )
+    }
+
+    /// `E["A"]`
+    fn enum_member(
+        &self,
+        span: Span,
+        name: &Atom,
+        member: &Atom,
+    ) -> oxc_allocator::Box<'a, MemberExpression<'a>> {
+        let object = super::identifier(&self.ast, span, name);
+        let expression =
+            self.ast.literal_string_expression(StringLiteral { span, value: member.clone() });
+        self.ast.alloc(MemberExpression::ComputedMemberExpression(ComputedMemberExpression {
+            span,
+            object,
+            expression,
+            optional: false,
+        }))
+    }
+}
+
+/// Evaluates the members of a `const enum`, which are all constant
+pub(super) fn evaluate_const_enum(
+    decl: &TSEnumDeclaration,
+    enums: &FxHashMap<Atom, EnumMembers>,
+) -> EnumMembers {
+    let name = &decl.id.name;
+    let mut members = enums.get(name).cloned().unwrap_or_default();
+    let mut previous = None;
+    for member in &decl.members {
+        let Some(member_name) = member_name(&member.id) else { continue };
+        let value = member.initializer.as_ref().map_or_else(
+            || next_value(previous.as_ref()),
+            |init| evaluate(init, name, &members, enums),
+        );
+        if let Some(value) = &value {
+            members.insert(member_name, value.clone());
+        }
+        previous = Some(value);
+    }
+    members
+}
+
+fn member_name(name: &TSEnumMemberName) -> Option<Atom> {
+    match name {
+        TSEnumMemberName::Identifier(ident) => Some(ident.name.clone()),
+        TSEnumMemberName::StringLiteral(lit) => Some(lit.value.clone()),
+        TSEnumMemberName::NumberLiteral(lit) => Some(Atom::from(lit.raw)),
+        TSEnumMemberName::ComputedPropertyName(Expression::StringLiteral(lit)) => {
+            Some(lit.value.clone())
+        }
+        TSEnumMemberName::ComputedPropertyName(_) => None,
+    }
+}
+
+/// Value of a member without an initializer: `0` for the first member,
+/// one more than the previous member when it is a number
+fn next_value(previous: Option<&Option<ConstantValue>>) -> Option<ConstantValue> {
+    match previous {
+        None => Some(ConstantValue::Number(0.0)),
+        Some(Some(ConstantValue::Number(value))) => Some(ConstantValue::Number(value + 1.0)),
+        Some(_) => None,
+    }
+}
+
+/// Evaluates a constant enum member initializer,
+/// see <https://www.typescriptlang.org/docs/handbook/enums.html#computed-and-constant-members>
+pub(super) fn evaluate(
+    expr: &Expression,
+    enum_name: &Atom,
+    members: &EnumMembers,
+    enums: &FxHashMap<Atom, EnumMembers>,
+) -> Option<ConstantValue> {
+    match expr {
+        Expression::NumberLiteral(lit) => Some(ConstantValue::Number(lit.value.into_inner())),
+        Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.clone())),
+        Expression::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+            lit.quasis.first()?.value.cooked.clone().map(ConstantValue::String)
+        }
+        Expression::ParenthesizedExpression(expr) => {
+            evaluate(&expr.expression, enum_name, members, enums)
+        }
+        Expression::Identifier(ident) => match ident.name.as_str() {
+            _ if members.contains_key(&ident.name) => members.get(&ident.name).cloned(),
+            "Infinity" => Some(ConstantValue::Number(f64::INFINITY)),
+            "NaN" => Some(ConstantValue::Number(f64::NAN)),
+            _ => None,
+        },
+        // `E.A`, `E["A"]`, `F.A` of another enum
+        Expression::MemberExpression(member) => {
+            let (object, property) = match &**member {
+                MemberExpression::StaticMemberExpression(expr) => {
+                    (&expr.object, &expr.property.name)
+                }
+                MemberExpression::ComputedMemberExpression(expr) => match &expr.expression {
+                    Expression::StringLiteral(lit) => (&expr.object, &lit.value),
+                    _ => return None,
+                },
+                MemberExpression::PrivateFieldExpression(_) => return None,
+            };
+            let Expression::Identifier(object) = object else { return None };
+            let members =
+                if object.name == *enum_name { members } else { enums.get(&object.name)? };
+            members.get(property).cloned()
+        }
+        Expression::UnaryExpression(expr) => {
+            let ConstantValue::Number(value) = evaluate(&expr.argument, enum_name, members, enums)?
+            else {
+                return None;
+            };
+            match expr.operator {
+                UnaryOperator::UnaryPlus => Some(ConstantValue::Number(value)),
+                UnaryOperator::UnaryNegation => Some(ConstantValue::Number(-value)),
+                UnaryOperator::BitwiseNot => {
+                    Some(ConstantValue::Number(f64::from(!to_int32(value))))
+                }
+                _ => None,
+            }
+        }
+        Expression::BinaryExpression(expr) => {
+            let left = evaluate(&expr.left, enum_name, members, enums)?;
+            let right = evaluate(&expr.right, enum_name, members, enums)?;
+            match (left, right) {
+                (ConstantValue::Number(left), ConstantValue::Number(right)) => {
+                    evaluate_number(expr.operator, left, right).map(ConstantValue::Number)
+                }
+                (left, right) if expr.operator == BinaryOperator::Addition => {
+                    Some(ConstantValue::String(Atom::from(format!(
+                        "{}{}",
+                        constant_to_string(&left),
+                        constant_to_string(&right)
+                    ))))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[allow(clippy::float_cmp)]
+fn evaluate_number(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+    let shift = to_uint32(right) & 31;
+    let value = match operator {
+        BinaryOperator::Addition => left + right,
+        BinaryOperator::Subtraction => left - right,
+        BinaryOperator::Multiplication => left * right,
+        BinaryOperator::Division => left / right,
+        BinaryOperator::Remainder => left % right,
+        // `1 ** NaN` and `(-1) ** Infinity` are `NaN` in JavaScript
+        BinaryOperator::Exponential
+            if right.is_nan() || (left.abs() == 1.0 && right.is_infinite()) =>
+        {
+            f64::NAN
+        }
+        BinaryOperator::Exponential => left.powf(right),
+        BinaryOperator::ShiftLeft => f64::from(to_int32(left) << shift),
+        BinaryOperator::ShiftRight => f64::from(to_int32(left) >> shift),
+        BinaryOperator::ShiftRightZeroFill => f64::from(to_uint32(left) >> shift),
+        BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+        BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+        BinaryOperator::BitwiseXOR => f64::from(to_int32(left) ^ to_int32(right)),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn constant_to_string(value: &ConstantValue) -> String {
+    match value {
+        ConstantValue::Number(value) => number_to_string(*value),
+        ConstantValue::String(value) => value.to_string(),
+    }
+}
+
+/// `1`, `-1`, `"a"`, `NaN`, `Infinity`
+pub(super) fn constant_to_expression<'a>(
+    ast: &AstBuilder<'a>,
+    span: Span,
+    value: &ConstantValue,
+) -> Expression<'a> {
+    let value = match value {
+        ConstantValue::String(value) => {
+            return ast.literal_string_expression(StringLiteral { span, value: value.clone() });
+        }
+        ConstantValue::Number(value) => *value,
+    };
+    let literal = if value.is_nan() {
+        return super::identifier(ast, span, &Atom::from("NaN"));
+    } else if value.is_infinite() {
+        super::identifier(ast, span, &Atom::from("Infinity"))
+    } else {
+        let raw = ast.new_str(&number_to_string(value.abs()));
+        ast.literal_number_expression(NumberLiteral::new(
+            span,
+            value.abs(),
+            raw,
+            NumberBase::Decimal,
+        ))
+    };
+    if value.is_sign_negative() {
+        ast.unary_expression(span, UnaryOperator::UnaryNegation, true, literal)
+    } else {
+        literal
+    }
+}
+
+/// [`Number::toString`](https://tc39.es/ecma262/#sec-numeric-types-number-tostring)
+fn number_to_string(value: f64) -> String {
+    ryu_js::Buffer::new().format(value).to_string()
+}
+
+/// [`ToInt32`](https://tc39.es/ecma262/#sec-toint32)
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    (value.trunc().rem_euclid(4_294_967_296.0) as u32) as i32
+}
+
+/// [`ToUint32`](https://tc39.es/ecma262/#sec-touint32)
+#[allow(clippy::cast_sign_loss)]
+fn to_uint32(value: f64) -> u32 {
+    to_int32(value) as u32
+}
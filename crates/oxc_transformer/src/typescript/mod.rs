@@ -0,0 +1,863 @@
+//! TypeScript
+//!
+//! Removes the TypeScript syntax and lowers the declarations which exist at runtime,
+//! producing the same code as `tsc --isolatedModules` and `@babel/preset-typescript`:
+//!
+//! * type annotations, type only declarations, `declare` statements, overloads and abstract
+//!   class members are removed
+//! * imports only used as types are removed, see [`TypeScriptOptions`]
+//! * parameter properties are assigned at the start of the constructor
+//! * `enum` and `namespace` declarations are lowered to functions,
+//!   see [`enums`] and [`namespace`]
+//! * members of the `const enum`s declared in the file are inlined where they are used
+//!
+//! Flow files are parsed into the same nodes, so their types are removed by this pass as well.
+//!
+//! Files are transformed one at a time without type information, so bindings are matched
+//! by name: an import is kept when a value of the same name is referenced anywhere in the file.
+
+mod enums;
+mod namespace;
+mod references;
+
+use oxc_allocator::{Allocator, Box, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, visit_mut::VisitMut, AstBuilder, Atom, GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use self::{
+    enums::EnumMembers,
+    references::{collect_type_names, ReferenceCollector},
+};
+
+/// How the imports only used as types are emitted,
+/// see <https://www.typescriptlang.org/tsconfig#importsNotUsedAsValues>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImportsNotUsedAsValues {
+    /// Remove the import statement
+    #[default]
+    Remove,
+    /// Keep the import statement for its side effects, `import { A } from 'a'` becomes `import 'a'`
+    Preserve,
+    /// Emitted like `Preserve`, `tsc` additionally reports the imports
+    /// which should be written with `import type`
+    Error,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TypeScriptOptions {
+    /// Only remove the imports and exports marked with `type`, keeping the unused ones,
+    /// see <https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax>.
+    /// `imports_not_used_as_values` is ignored when set.
+    pub verbatim_module_syntax: bool,
+
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+}
+
+/// Where a declaration is exported to
+#[derive(Clone, Copy)]
+enum Export<'n> {
+    None,
+    /// `export` from the module
+    Module,
+    /// `export` from the namespace with the given name
+    Namespace(&'n Atom),
+}
+
+pub struct TypeScript<'a> {
+    ast: AstBuilder<'a>,
+    options: TypeScriptOptions,
+
+    /// Names referenced as values anywhere in the file
+    value_references: FxHashSet<Atom>,
+
+    /// Top level names only declared as types, their exports are removed
+    type_names: FxHashSet<Atom>,
+
+    /// Constant members of the `const enum`s of the file and of the enums lowered so far
+    enums: FxHashMap<Atom, EnumMembers>,
+
+    /// Names of the `const enum`s of the file, uses of their members are inlined
+    const_enums: FxHashSet<Atom>,
+
+    /// Names of the functions, classes, enums and namespaces declared in the statement list
+    /// being transformed. An enum or namespace merging into one of them is not declared again.
+    declared_names: FxHashSet<Atom>,
+
+    /// Names of the namespaces being lowered, from the outermost one
+    namespace_path: std::vec::Vec<Atom>,
+
+    /// Exported variables of each namespace by path (`A.B`),
+    /// references to them are rewritten in all the blocks of a merged namespace
+    namespace_exports: FxHashMap<String, FxHashSet<Atom>>,
+}
+
+impl<'a> TypeScript<'a> {
+    #[must_use]
+    pub fn new(allocator: &'a Allocator, options: TypeScriptOptions) -> Self {
+        Self {
+            ast: AstBuilder::new(allocator),
+            options,
+            value_references: FxHashSet::default(),
+            type_names: FxHashSet::default(),
+            enums: FxHashMap::default(),
+            const_enums: FxHashSet::default(),
+            declared_names: FxHashSet::default(),
+            namespace_path: vec![],
+            namespace_exports: FxHashMap::default(),
+        }
+    }
+
+    pub fn build(mut self, program: &mut Program<'a>) {
+        let mut collector = ReferenceCollector::default();
+        collector.visit_program(program);
+        self.value_references = collector.value_references;
+        self.enums = collector.enums;
+        self.const_enums = collector.const_enums;
+        self.type_names = collect_type_names(&program.body);
+
+        let has_module_syntax = program.body.iter().any(is_module_syntax);
+        self.visit_program(program);
+
+        // Keep the file a module when all of its imports and exports are removed, like `tsc` does
+        if has_module_syntax
+            && program.source_type.is_module()
+            && !program.body.iter().any(is_module_syntax)
+        {
+            let span = Span::new(program.span.end, program.span.end);
+            let export =
+                self.ast.export_named_declaration(None, self.ast.new_vec(), None, None, None);
+            program.body.push(
+                self.ast.module_declaration(
+                    span,
+                    ModuleDeclarationKind::ExportNamedDeclaration(export),
+                ),
+            );
+        }
+    }
+
+    fn transform_statements(
+        &mut self,
+        stmts: Vec<'a, Statement<'a>>,
+        namespace: Option<&Atom>,
+    ) -> Vec<'a, Statement<'a>> {
+        let declared_names = std::mem::take(&mut self.declared_names);
+        let mut new_stmts = self.ast.new_vec_with_capacity(stmts.len());
+        for stmt in stmts {
+            match stmt {
+                Statement::Declaration(decl) => {
+                    self.transform_declaration(decl, Export::None, &mut new_stmts);
+                }
+                Statement::ModuleDeclaration(decl) => {
+                    if let Some(namespace) = namespace {
+                        self.transform_namespace_export(decl, namespace, &mut new_stmts);
+                    } else {
+                        self.transform_module_declaration(decl, &mut new_stmts);
+                    }
+                }
+                mut stmt => {
+                    self.visit_statement(&mut stmt);
+                    new_stmts.push(stmt);
+                }
+            }
+        }
+        self.declared_names = declared_names;
+        new_stmts
+    }
+
+    fn transform_declaration(
+        &mut self,
+        decl: Declaration<'a>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let span = decl.span();
+        match decl {
+            Declaration::VariableDeclaration(mut decl) => {
+                if decl.modifiers.contains(ModifierKind::Declare) {
+                    return;
+                }
+                self.visit_variable_declaration(&mut decl);
+                self.push_declaration(span, Declaration::VariableDeclaration(decl), export, out);
+            }
+            Declaration::FunctionDeclaration(mut func) => {
+                if func.is_typescript_syntax() {
+                    return;
+                }
+                self.visit_function(&mut func);
+                let name = func.id.as_ref().map(|id| id.name.clone());
+                self.push_declaration(span, Declaration::FunctionDeclaration(func), export, out);
+                self.declare_exported_name(span, name, export, out);
+            }
+            Declaration::ClassDeclaration(mut class) => {
+                if class.is_declare() {
+                    return;
+                }
+                self.visit_class(&mut class);
+                let name = class.id.as_ref().map(|id| id.name.clone());
+                self.push_declaration(span, Declaration::ClassDeclaration(class), export, out);
+                self.declare_exported_name(span, name, export, out);
+            }
+            Declaration::TSEnumDeclaration(mut decl) => self.transform_enum(&mut decl, export, out),
+            Declaration::TSModuleDeclaration(mut decl) => {
+                self.transform_namespace(&mut decl, export, out);
+            }
+            Declaration::TSImportEqualsDeclaration(decl) => {
+                self.transform_import_equals(&decl, export, out);
+            }
+            Declaration::TSTypeAliasDeclaration(_)
+            | Declaration::TSInterfaceDeclaration(_)
+            | Declaration::FlowOpaqueType(_) => {}
+        }
+    }
+
+    fn push_declaration(
+        &self,
+        span: Span,
+        decl: Declaration<'a>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let stmt = if matches!(export, Export::Module) {
+            let export =
+                self.ast.export_named_declaration(Some(decl), self.ast.new_vec(), None, None, None);
+            self.ast.module_declaration(span, ModuleDeclarationKind::ExportNamedDeclaration(export))
+        } else {
+            Statement::Declaration(decl)
+        };
+        out.push(stmt);
+    }
+
+    /// Records a function or class name, and assigns it to the namespace it is exported from
+    fn declare_exported_name(
+        &mut self,
+        span: Span,
+        name: Option<Atom>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let Some(name) = name else { return };
+        if let Export::Namespace(namespace) = export {
+            let value = identifier(&self.ast, span, &name);
+            out.push(self.namespace_assignment(span, namespace, &name, value));
+        }
+        self.declared_names.insert(name);
+    }
+
+    /// `namespace.name = value;`
+    fn namespace_assignment(
+        &self,
+        span: Span,
+        namespace: &Atom,
+        name: &Atom,
+        value: Expression<'a>,
+    ) -> Statement<'a> {
+        let object = identifier(&self.ast, span, namespace);
+        let target = SimpleAssignmentTarget::MemberAssignmentTarget(static_member(
+            &self.ast, span, object, name,
+        ));
+        let expr = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target),
+            value,
+        );
+        self.ast.expression_statement(span, expr)
+    }
+
+    /// `var name = init`
+    fn variable_declaration(
+        &self,
+        span: Span,
+        kind: VariableDeclarationKind,
+        name: &Atom,
+        init: Option<Expression<'a>>,
+    ) -> Declaration<'a> {
+        let binding = BindingIdentifier { span, name: name.clone() };
+        let id = self.ast.binding_pattern(self.ast.binding_identifier(binding), None, false);
+        let declarator = self.ast.variable_declarator(span, kind, id, init, false);
+        let declarations = self.ast.new_vec_single(declarator);
+        Declaration::VariableDeclaration(self.ast.variable_declaration(
+            span,
+            kind,
+            declarations,
+            Modifiers::empty(),
+        ))
+    }
+
+    /// Declares `name` unless a merged declaration already did, then pushes
+    /// `(function (name) { body })(name || (name = {}))`, which creates the object of an enum or
+    /// namespace or adds to the existing one.
+    /// Inside a namespace, the object is also a member of the namespace:
+    /// `(function (name) { body })(name = namespace.name || (namespace.name = {}))`.
+    fn push_object_initializer(
+        &mut self,
+        span: Span,
+        name: &Atom,
+        body: Vec<'a, Statement<'a>>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        if self.declared_names.insert(name.clone()) {
+            let kind = if matches!(export, Export::Namespace(_)) {
+                VariableDeclarationKind::Let
+            } else {
+                VariableDeclarationKind::Var
+            };
+            let decl = self.variable_declaration(span, kind, name, None);
+            self.push_declaration(span, decl, export, out);
+        }
+
+        let argument = match export {
+            Export::Namespace(namespace) => {
+                let member = || {
+                    let object = identifier(&self.ast, span, namespace);
+                    SimpleAssignmentTarget::MemberAssignmentTarget(static_member(
+                        &self.ast, span, object, name,
+                    ))
+                };
+                self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::SimpleAssignmentTarget(identifier_target(
+                        &self.ast, span, name,
+                    )),
+                    self.or_empty_object(span, &member),
+                )
+            }
+            _ => self.or_empty_object(span, &|| identifier_target(&self.ast, span, name)),
+        };
+
+        let binding = BindingIdentifier { span, name: name.clone() };
+        let pattern = self.ast.binding_pattern(self.ast.binding_identifier(binding), None, false);
+        let param = self.ast.formal_parameter(span, pattern, None, false, self.ast.new_vec());
+        let params = self.ast.formal_parameters(
+            span,
+            FormalParameterKind::FormalParameter,
+            self.ast.new_vec_single(param),
+        );
+        let body = self.ast.function_body(span, self.ast.new_vec(), body);
+        let func = self.ast.function(
+            FunctionType::FunctionExpression,
+            span,
+            None,
+            false,
+            false,
+            false,
+            params,
+            Some(body),
+            None,
+            None,
+            Modifiers::empty(),
+        );
+        let callee = self.ast.parenthesized_expression(span, self.ast.function_expression(func));
+        let arguments = self.ast.new_vec_single(Argument::Expression(argument));
+        let call = self.ast.call_expression(span, callee, arguments, false, None);
+        out.push(self.ast.expression_statement(span, call));
+    }
+
+    /// `target || (target = {})`
+    fn or_empty_object(
+        &self,
+        span: Span,
+        target: &dyn Fn() -> SimpleAssignmentTarget<'a>,
+    ) -> Expression<'a> {
+        let empty_object = self.ast.object_expression(span, self.ast.new_vec(), None);
+        let assignment = self.ast.assignment_expression(
+            span,
+            AssignmentOperator::Assign,
+            AssignmentTarget::SimpleAssignmentTarget(target()),
+            empty_object,
+        );
+        self.ast.logical_expression(
+            span,
+            simple_target_to_expression(target()),
+            LogicalOperator::Or,
+            self.ast.parenthesized_expression(span, assignment),
+        )
+    }
+
+    fn transform_module_declaration(
+        &mut self,
+        mut decl: Box<'a, ModuleDeclaration<'a>>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let span = decl.span;
+        let keep = match &mut decl.kind {
+            ModuleDeclarationKind::ImportDeclaration(decl) => self.transform_import(decl),
+            ModuleDeclarationKind::ExportAllDeclaration(decl) => {
+                decl.export_kind != Some(ImportOrExportKind::Type)
+            }
+            ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                if decl.export_kind == Some(ImportOrExportKind::Type) {
+                    false
+                } else if let Some(declaration) = decl.declaration.take() {
+                    self.transform_declaration(declaration, Export::Module, out);
+                    false
+                } else {
+                    self.transform_export_specifiers(decl)
+                }
+            }
+            ModuleDeclarationKind::ExportDefaultDeclaration(decl) => match &mut decl.declaration {
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => false,
+                ExportDefaultDeclarationKind::FunctionDeclaration(func)
+                    if func.is_typescript_syntax() =>
+                {
+                    false
+                }
+                ExportDefaultDeclarationKind::TSEnumDeclaration(enum_decl) => {
+                    self.transform_enum(enum_decl, Export::None, out);
+                    let name = identifier(&self.ast, span, &enum_decl.id.name);
+                    decl.declaration = ExportDefaultDeclarationKind::Expression(name);
+                    true
+                }
+                _ => {
+                    self.visit_export_default_declaration(decl);
+                    true
+                }
+            },
+            // `export = a` -> `module.exports = a`
+            ModuleDeclarationKind::TSExportAssignment(decl) => {
+                self.visit_expression(&mut decl.expression);
+                let module = identifier(&self.ast, span, &Atom::from("module"));
+                let target = SimpleAssignmentTarget::MemberAssignmentTarget(static_member(
+                    &self.ast,
+                    span,
+                    module,
+                    &Atom::from("exports"),
+                ));
+                let expr = self.ast.assignment_expression(
+                    span,
+                    AssignmentOperator::Assign,
+                    AssignmentTarget::SimpleAssignmentTarget(target),
+                    self.ast.move_expression(&mut decl.expression),
+                );
+                out.push(self.ast.expression_statement(span, expr));
+                false
+            }
+            ModuleDeclarationKind::TSNamespaceExportDeclaration(_) => false,
+        };
+        if keep {
+            out.push(Statement::ModuleDeclaration(decl));
+        }
+    }
+
+    /// Removes the specifiers only used as types, returns whether the import is kept
+    fn transform_import(&self, decl: &mut ImportDeclaration<'a>) -> bool {
+        if decl.import_kind == Some(ImportOrExportKind::Type) {
+            return false;
+        }
+        // `import 'a'` is only imported for its side effects
+        if decl.specifiers.is_empty() {
+            return true;
+        }
+        let verbatim_module_syntax = self.options.verbatim_module_syntax;
+        decl.specifiers.retain(|specifier| {
+            let (local, import_kind) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    (&specifier.local, specifier.import_kind)
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    (&specifier.local, ImportOrExportKind::Value)
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    (&specifier.local, ImportOrExportKind::Value)
+                }
+            };
+            import_kind.is_value()
+                && (verbatim_module_syntax || self.value_references.contains(&local.name))
+        });
+        !decl.specifiers.is_empty()
+            || verbatim_module_syntax
+            || self.options.imports_not_used_as_values != ImportsNotUsedAsValues::Remove
+    }
+
+    /// Removes the exports of types, returns whether the export is kept
+    fn transform_export_specifiers(&self, decl: &mut ExportNamedDeclaration<'a>) -> bool {
+        // `export {}`
+        if decl.specifiers.is_empty() {
+            return true;
+        }
+        let is_local = decl.source.is_none();
+        decl.specifiers.retain(|specifier| {
+            specifier.export_kind.is_value()
+                && !(is_local && self.type_names.contains(specifier.local.name()))
+        });
+        !decl.specifiers.is_empty()
+    }
+
+    /// `import a = require('a')` -> `var a = require('a')`, `import a = A.B` -> `var a = A.B`
+    fn transform_import_equals(
+        &self,
+        decl: &TSImportEqualsDeclaration<'a>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let name = &decl.id.name;
+        if decl.import_kind.is_type()
+            || (matches!(export, Export::None)
+                && !self.options.verbatim_module_syntax
+                && !self.value_references.contains(name))
+        {
+            return;
+        }
+
+        let span = decl.span;
+        let init = match &*decl.module_reference {
+            TSModuleReference::TypeName(type_name) => self.type_name_to_expression(type_name),
+            TSModuleReference::ExternalModuleReference(reference) => {
+                let callee = identifier(&self.ast, span, &Atom::from("require"));
+                let source = self.ast.literal_string_expression(reference.expression.clone());
+                let arguments = self.ast.new_vec_single(Argument::Expression(source));
+                self.ast.call_expression(span, callee, arguments, false, None)
+            }
+        };
+        if let Export::Namespace(namespace) = export {
+            out.push(self.namespace_assignment(span, namespace, name, init));
+        } else {
+            let decl =
+                self.variable_declaration(span, VariableDeclarationKind::Var, name, Some(init));
+            self.push_declaration(span, decl, export, out);
+        }
+    }
+
+    fn type_name_to_expression(&self, type_name: &TSTypeName<'a>) -> Expression<'a> {
+        match type_name {
+            TSTypeName::IdentifierName(ident) => identifier(&self.ast, ident.span, &ident.name),
+            TSTypeName::QualifiedName(name) => {
+                let object = self.type_name_to_expression(&name.left);
+                Expression::MemberExpression(static_member(
+                    &self.ast,
+                    name.span,
+                    object,
+                    &name.right.name,
+                ))
+            }
+        }
+    }
+
+    /// `constructor(private a) {}` -> `constructor(a) { this.a = a; }`
+    fn transform_parameter_properties(&self, class: &mut Class<'a>) {
+        let has_super_class = class.super_class.is_some();
+        let Some(constructor) = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(def)
+                if def.kind == MethodDefinitionKind::Constructor =>
+            {
+                Some(&mut *def.value)
+            }
+            _ => None,
+        }) else {
+            return;
+        };
+
+        let names = constructor
+            .params
+            .items
+            .iter()
+            .filter(|param| param.accessibility.is_some() || param.readonly)
+            .filter_map(|param| match &param.pattern.kind {
+                BindingPatternKind::BindingIdentifier(ident) => Some(ident),
+                BindingPatternKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+                    BindingPatternKind::BindingIdentifier(ident) => Some(ident),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .map(|ident| (ident.span, ident.name.clone()));
+        let Some(body) = &mut constructor.body else { return };
+
+        // Properties can only be assigned after `super()` is called
+        let index = if has_super_class {
+            body.statements.iter().position(is_super_call).map_or(0, |index| index + 1)
+        } else {
+            0
+        };
+        for (offset, (span, name)) in names.enumerate() {
+            let object = self.ast.this_expression(span);
+            let target = SimpleAssignmentTarget::MemberAssignmentTarget(static_member(
+                &self.ast, span, object, &name,
+            ));
+            let expr = self.ast.assignment_expression(
+                span,
+                AssignmentOperator::Assign,
+                AssignmentTarget::SimpleAssignmentTarget(target),
+                identifier(&self.ast, span, &name),
+            );
+            body.statements.insert(index + offset, self.ast.expression_statement(span, expr));
+        }
+    }
+
+    /// Removes the TypeScript expression wrappers and inlines the members of const enums
+    fn transform_expression(&self, expr: &mut Expression<'a>) {
+        let inner = match expr {
+            Expression::TSAsExpression(expr) => self.ast.move_expression(&mut expr.expression),
+            Expression::TSTypeAssertion(expr) => self.ast.move_expression(&mut expr.expression),
+            Expression::TSNonNullExpression(expr) => self.ast.move_expression(&mut expr.expression),
+            Expression::TSInstantiationExpression(expr) => {
+                self.ast.move_expression(&mut expr.expression)
+            }
+            Expression::MemberExpression(member) => {
+                if let Some(value) = self.inline_const_enum_member(member) {
+                    *expr = value;
+                } else {
+                    // `E.A.toString()` -> `(-1).toString()`
+                    let object = member_object_mut(member);
+                    if matches!(
+                        object,
+                        Expression::NumberLiteral(_) | Expression::UnaryExpression(_)
+                    ) {
+                        let span = object.span();
+                        let object_expr = self.ast.move_expression(object);
+                        *object = self.ast.parenthesized_expression(span, object_expr);
+                    }
+                }
+                return;
+            }
+            _ => return,
+        };
+        // `<T>{}` in an arrow function body or at the start of a statement needs parentheses
+        *expr = if matches!(inner, Expression::ObjectExpression(_)) {
+            self.ast.parenthesized_expression(inner.span(), inner)
+        } else {
+            inner
+        };
+    }
+
+    /// `E.A` and `E['A']` of a const enum -> the value of `A`
+    fn inline_const_enum_member(&self, member: &MemberExpression<'a>) -> Option<Expression<'a>> {
+        let (object, property) = match member {
+            MemberExpression::StaticMemberExpression(expr) => (&expr.object, &expr.property.name),
+            MemberExpression::ComputedMemberExpression(expr) => match &expr.expression {
+                Expression::StringLiteral(lit) => (&expr.object, &lit.value),
+                _ => return None,
+            },
+            MemberExpression::PrivateFieldExpression(_) => return None,
+        };
+        let Expression::Identifier(ident) = object else { return None };
+        if !self.const_enums.contains(&ident.name) {
+            return None;
+        }
+        let value = self.enums.get(&ident.name)?.get(property)?;
+        Some(enums::constant_to_expression(&self.ast, member.span(), value))
+    }
+}
+
+impl<'a> VisitMut<'a> for TypeScript<'a> {
+    fn visit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>) {
+        let old_stmts = self.ast.move_statement_vec(stmts);
+        *stmts = self.transform_statements(old_stmts, None);
+    }
+
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        self.visit_expression_match(expr);
+        self.transform_expression(expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        // `a! = b` -> `a = b`
+        let expr = match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.visit_identifier_reference(ident);
+                return;
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(expr) => {
+                self.visit_member_expression(expr);
+                return;
+            }
+            SimpleAssignmentTarget::TSAsExpression(expr) => &mut expr.expression,
+            SimpleAssignmentTarget::TSNonNullExpression(expr) => &mut expr.expression,
+            SimpleAssignmentTarget::TSTypeAssertion(expr) => &mut expr.expression,
+        };
+        self.visit_expression(expr);
+        match self.ast.move_expression(expr) {
+            Expression::Identifier(ident) => {
+                *target = SimpleAssignmentTarget::AssignmentTargetIdentifier(ident);
+            }
+            Expression::MemberExpression(expr) => {
+                *target = SimpleAssignmentTarget::MemberAssignmentTarget(expr);
+            }
+            other => *expr = other,
+        }
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        func.type_parameters = None;
+        func.return_type = None;
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        expr.type_parameters = None;
+        expr.return_type = None;
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+    }
+
+    fn visit_formal_parameter(&mut self, param: &mut FormalParameter<'a>) {
+        // Parameter properties are already assigned in the constructor, see `visit_class`
+        param.accessibility = None;
+        param.readonly = false;
+        for decorator in param.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_pattern(&mut param.pattern);
+    }
+
+    fn visit_pattern(&mut self, pat: &mut BindingPattern<'a>) {
+        pat.type_annotation = None;
+        pat.optional = false;
+        match &mut pat.kind {
+            BindingPatternKind::BindingIdentifier(ident) => self.visit_binding_identifier(ident),
+            BindingPatternKind::ObjectPattern(pat) => self.visit_object_pattern(pat),
+            BindingPatternKind::ArrayPattern(pat) => self.visit_array_pattern(pat),
+            BindingPatternKind::RestElement(pat) => self.visit_rest_element(pat),
+            BindingPatternKind::AssignmentPattern(pat) => self.visit_assignment_pattern(pat),
+        }
+    }
+
+    fn visit_variable_declarator(&mut self, declarator: &mut VariableDeclarator<'a>) {
+        declarator.definite = false;
+        self.visit_pattern(&mut declarator.id);
+        if let Some(init) = &mut declarator.init {
+            self.visit_expression(init);
+        }
+    }
+
+    fn visit_class(&mut self, class: &mut Class<'a>) {
+        class.type_parameters = None;
+        class.super_type_parameters = None;
+        class.implements = None;
+        class.body.body.retain(|element| match element {
+            // overloads
+            ClassElement::MethodDefinition(def) => def.value.body.is_some(),
+            ClassElement::PropertyDefinition(def) => !def.declare,
+            ClassElement::StaticBlock(_)
+            | ClassElement::AccessorProperty(_)
+            | ClassElement::InvalidClassElement(_) => true,
+            ClassElement::TSAbstractMethodDefinition(_)
+            | ClassElement::TSAbstractPropertyDefinition(_)
+            | ClassElement::TSIndexSignature(_) => false,
+        });
+        self.transform_parameter_properties(class);
+
+        for decorator in class.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        if let Some(super_class) = &mut class.super_class {
+            self.visit_class_heritage(super_class);
+        }
+        self.visit_class_body(&mut class.body);
+    }
+
+    fn visit_method_definition(&mut self, def: &mut MethodDefinition<'a>) {
+        def.accessibility = None;
+        def.optional = false;
+        def.r#override = false;
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        self.visit_function(&mut def.value);
+    }
+
+    fn visit_property_definition(&mut self, def: &mut PropertyDefinition<'a>) {
+        def.accessibility = None;
+        def.type_annotation = None;
+        def.optional = false;
+        def.definite = false;
+        def.readonly = false;
+        def.r#override = false;
+        for decorator in def.decorators.iter_mut() {
+            self.visit_decorator(decorator);
+        }
+        self.visit_property_key(&mut def.key);
+        if let Some(value) = &mut def.value {
+            self.visit_expression(value);
+        }
+    }
+
+    fn visit_call_expression(&mut self, expr: &mut CallExpression<'a>) {
+        expr.type_parameters = None;
+        self.visit_expression(&mut expr.callee);
+        for arg in expr.arguments.iter_mut() {
+            self.visit_argument(arg);
+        }
+    }
+
+    fn visit_new_expression(&mut self, expr: &mut NewExpression<'a>) {
+        expr.type_parameters = None;
+        self.visit_expression(&mut expr.callee);
+        for arg in expr.arguments.iter_mut() {
+            self.visit_argument(arg);
+        }
+    }
+
+    fn visit_tagged_template_expression(&mut self, expr: &mut TaggedTemplateExpression<'a>) {
+        expr.type_parameters = None;
+        self.visit_expression(&mut expr.tag);
+        self.visit_template_literal(&mut expr.quasi);
+    }
+}
+
+/// `import` and `export` statements, `export =` is emitted as `module.exports =`
+fn is_module_syntax(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ModuleDeclaration(decl)
+        if !matches!(decl.kind, ModuleDeclarationKind::TSExportAssignment(_)))
+}
+
+/// `super(...);`
+fn is_super_call(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ExpressionStatement(stmt)
+        if matches!(&stmt.expression, Expression::CallExpression(call)
+            if matches!(call.callee, Expression::Super(_))))
+}
+
+fn member_object_mut<'m, 'a>(member: &'m mut MemberExpression<'a>) -> &'m mut Expression<'a> {
+    match member {
+        MemberExpression::ComputedMemberExpression(expr) => &mut expr.object,
+        MemberExpression::StaticMemberExpression(expr) => &mut expr.object,
+        MemberExpression::PrivateFieldExpression(expr) => &mut expr.object,
+    }
+}
+
+fn simple_target_to_expression(target: SimpleAssignmentTarget) -> Expression {
+    match target {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => Expression::Identifier(ident),
+        SimpleAssignmentTarget::MemberAssignmentTarget(expr) => Expression::MemberExpression(expr),
+        SimpleAssignmentTarget::TSAsExpression(expr) => Expression::TSAsExpression(expr),
+        SimpleAssignmentTarget::TSNonNullExpression(expr) => Expression::TSNonNullExpression(expr),
+        SimpleAssignmentTarget::TSTypeAssertion(expr) => Expression::TSTypeAssertion(expr),
+    }
+}
+
+fn identifier<'a>(ast: &AstBuilder<'a>, span: Span, name: &Atom) -> Expression<'a> {
+    ast.identifier_expression(IdentifierReference { span, name: name.clone() })
+}
+
+fn identifier_target<'a>(
+    ast: &AstBuilder<'a>,
+    span: Span,
+    name: &Atom,
+) -> SimpleAssignmentTarget<'a> {
+    SimpleAssignmentTarget::AssignmentTargetIdentifier(
+        ast.alloc(IdentifierReference { span, name: name.clone() }),
+    )
+}
+
+/// `object.property`
+fn static_member<'a>(
+    ast: &AstBuilder<'a>,
+    span: Span,
+    object: Expression<'a>,
+    property: &Atom,
+) -> Box<'a, MemberExpression<'a>> {
+    ast.alloc(MemberExpression::StaticMemberExpression(StaticMemberExpression {
+        span,
+        object,
+        property: IdentifierName { span, name: property.clone() },
+        optional: false,
+    }))
+}
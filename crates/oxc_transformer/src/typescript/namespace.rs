@@ -0,0 +1,128 @@
+//! Namespaces
+//!
+//! ```ts
+//! namespace N { export const a = 1; export function f() { return a; } }
+//! ```
+//! is lowered to
+//! ```js
+//! var N;
+//! (function (N) {
+//!   N.a = 1;
+//!   function f() { return N.a; }
+//!   N.f = f;
+//! })(N || (N = {}));
+//! ```
+//! Exported variables are properties of the namespace object, so their references become
+//! `N.a`, including the references in the other blocks merged into the namespace.
+//! Namespaces only containing types are removed.
+
+use oxc_allocator::{Box, Vec};
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{ast::*, syntax_directed_operations::BoundNames, visit_mut::VisitMut, Atom};
+
+use super::{identifier, references::ReferenceRewriter, Export, TypeScript};
+
+impl<'a> TypeScript<'a> {
+    pub(super) fn transform_namespace(
+        &mut self,
+        decl: &mut TSModuleDeclaration<'a>,
+        export: Export<'_>,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        // `declare namespace N {}`, `declare module 'a' {}` and `declare global {}`
+        let TSModuleDeclarationName::Identifier(ident) = &decl.id else { return };
+        if decl.modifiers.contains(ModifierKind::Declare) {
+            return;
+        }
+        let name = ident.name.clone();
+        self.namespace_path.push(name.clone());
+
+        let mut body = match &mut decl.body {
+            TSModuleDeclarationBody::TSModuleBlock(block) => {
+                let stmts = self.ast.move_statement_vec(&mut block.body);
+                self.transform_statements(stmts, Some(&name))
+            }
+            // `namespace A.B {}`
+            TSModuleDeclarationBody::TSModuleDeclaration(decl) => {
+                let declared_names = std::mem::take(&mut self.declared_names);
+                let mut body = self.ast.new_vec();
+                self.transform_namespace(decl, Export::Namespace(&name), &mut body);
+                self.declared_names = declared_names;
+                body
+            }
+        };
+
+        if let Some(exports) = self.namespace_exports.get(&self.namespace_path.join(".")) {
+            let mut rewriter = ReferenceRewriter::new(&self.ast, exports, &name);
+            for stmt in body.iter_mut() {
+                rewriter.visit_statement(stmt);
+            }
+        }
+        self.namespace_path.pop();
+
+        if !body.is_empty() {
+            self.push_object_initializer(decl.span, &name, body, export, out);
+        }
+    }
+
+    /// `export` in a namespace
+    pub(super) fn transform_namespace_export(
+        &mut self,
+        mut decl: Box<'a, ModuleDeclaration<'a>>,
+        namespace: &Atom,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        let ModuleDeclarationKind::ExportNamedDeclaration(decl) = &mut decl.kind else { return };
+        match decl.declaration.take() {
+            Some(Declaration::VariableDeclaration(decl)) => {
+                self.transform_namespace_variable(decl, namespace, out);
+            }
+            Some(decl) => self.transform_declaration(decl, Export::Namespace(namespace), out),
+            None => {}
+        }
+    }
+
+    /// `export const a = 1, { b } = c` -> `N.a = 1; const { b } = c; N.b = b;`
+    fn transform_namespace_variable(
+        &mut self,
+        mut decl: Box<'a, VariableDeclaration<'a>>,
+        namespace: &Atom,
+        out: &mut Vec<'a, Statement<'a>>,
+    ) {
+        if decl.modifiers.contains(ModifierKind::Declare) {
+            return;
+        }
+        self.visit_variable_declaration(&mut decl);
+        let path = self.namespace_path.join(".");
+        let kind = decl.kind;
+        for declarator in decl.declarations.drain(..) {
+            if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind {
+                self.namespace_exports.entry(path.clone()).or_default().insert(ident.name.clone());
+                if let Some(init) = declarator.init {
+                    out.push(self.namespace_assignment(
+                        declarator.span,
+                        namespace,
+                        &ident.name,
+                        init,
+                    ));
+                }
+                continue;
+            }
+            // Destructured variables are declared, then copied to the namespace
+            let span = declarator.span;
+            let names = declarator
+                .id
+                .bound_names()
+                .into_iter()
+                .map(|ident| (ident.span, ident.name.clone()))
+                .collect::<std::vec::Vec<_>>();
+            let declarations = self.ast.new_vec_single(declarator);
+            let var = self.ast.variable_declaration(span, kind, declarations, Modifiers::empty());
+            out.push(Statement::Declaration(Declaration::VariableDeclaration(var)));
+            for (span, name) in names {
+                let value = identifier(&self.ast, span, &name);
+                out.push(self.namespace_assignment(span, namespace, &name, value));
+            }
+        }
+    }
+}
@@ -0,0 +1,465 @@
+//! Name based reference tracking, as type information is not available when files are
+//! transformed one at a time.
+
+use oxc_allocator::Vec;
+#[allow(clippy::wildcard_imports)]
+use oxc_ast::{
+    ast::*, syntax_directed_operations::BoundNames, visit_mut::VisitMut, AstBuilder, Atom,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::{
+    enums::{evaluate_const_enum, EnumMembers},
+    identifier, static_member,
+};
+
+/// Collects the names referenced as values, skipping the types,
+/// and evaluates the `const enum`s so their uses can be inlined
+#[derive(Default)]
+pub struct ReferenceCollector {
+    pub value_references: FxHashSet<Atom>,
+    pub enums: FxHashMap<Atom, EnumMembers>,
+    pub const_enums: FxHashSet<Atom>,
+}
+
+impl<'a> VisitMut<'a> for ReferenceCollector {
+    fn visit_identifier_reference(&mut self, ident: &mut IdentifierReference) {
+        self.value_references.insert(ident.name.clone());
+    }
+
+    fn visit_export_named_declaration(&mut self, decl: &mut ExportNamedDeclaration<'a>) {
+        // `export { a }` references the local `a`
+        if decl.source.is_none() && decl.export_kind != Some(ImportOrExportKind::Type) {
+            for specifier in decl.specifiers.iter().filter(|s| s.export_kind.is_value()) {
+                self.value_references.insert(specifier.local.name().clone());
+            }
+        }
+        if let Some(declaration) = &mut decl.declaration {
+            self.visit_declaration(declaration);
+        }
+    }
+
+    fn visit_ts_import_equals_declaration(&mut self, decl: &mut TSImportEqualsDeclaration<'a>) {
+        // `import a = A.B` references `A`
+        if decl.import_kind.is_value()
+            && let TSModuleReference::TypeName(type_name) = &*decl.module_reference
+        {
+            let mut type_name = type_name;
+            while let TSTypeName::QualifiedName(name) = type_name {
+                type_name = &name.left;
+            }
+            if let TSTypeName::IdentifierName(ident) = type_name {
+                self.value_references.insert(ident.name.clone());
+            }
+        }
+    }
+
+    fn visit_jsx_element(&mut self, elem: &mut JSXElement<'a>) {
+        // The classic runtime calls `React.createElement`
+        self.value_references.insert(Atom::from("React"));
+        self.visit_jsx_opening_element(&mut elem.opening_element);
+        for child in elem.children.iter_mut() {
+            self.visit_jsx_child(child);
+        }
+    }
+
+    fn visit_jsx_fragment(&mut self, elem: &mut JSXFragment<'a>) {
+        self.value_references.insert(Atom::from("React"));
+        for child in elem.children.iter_mut() {
+            self.visit_jsx_child(child);
+        }
+    }
+
+    fn visit_jsx_element_name(&mut self, name: &mut JSXElementName<'a>) {
+        match name {
+            // `<div>` is an intrinsic element, `<Foo>` references `Foo`
+            JSXElementName::Identifier(ident) => {
+                if !ident.name.starts_with(|c: char| c.is_ascii_lowercase()) {
+                    self.value_references.insert(ident.name.clone());
+                }
+            }
+            JSXElementName::MemberExpression(expr) => {
+                let mut object = &expr.object;
+                while let JSXMemberExpressionObject::MemberExpression(expr) = object {
+                    object = &expr.object;
+                }
+                if let JSXMemberExpressionObject::Identifier(ident) = object {
+                    self.value_references.insert(ident.name.clone());
+                }
+            }
+            JSXElementName::NamespacedName(_) => {}
+        }
+    }
+
+    fn visit_enum(&mut self, decl: &mut TSEnumDeclaration<'a>) {
+        if decl.modifiers.contains(ModifierKind::Const) {
+            let members = evaluate_const_enum(decl, &self.enums);
+            self.enums.insert(decl.id.name.clone(), members);
+            self.const_enums.insert(decl.id.name.clone());
+        }
+        for member in decl.members.iter_mut() {
+            self.visit_enum_member(member);
+        }
+    }
+
+    fn visit_ts_type_annotation(&mut self, _annotation: &mut TSTypeAnnotation<'a>) {}
+
+    fn visit_ts_type(&mut self, _ty: &mut TSType<'a>) {}
+
+    fn visit_ts_type_alias_declaration(&mut self, _decl: &mut TSTypeAliasDeclaration<'a>) {}
+
+    fn visit_ts_interface_declaration(&mut self, _decl: &mut TSInterfaceDeclaration<'a>) {}
+
+    fn visit_ts_type_parameter_declaration(&mut self, _ty: &mut TSTypeParameterDeclaration<'a>) {}
+
+    fn visit_ts_type_parameter_instantiation(
+        &mut self,
+        _ty: &mut TSTypeParameterInstantiation<'a>,
+    ) {
+    }
+}
+
+/// Top level names only declared as types
+pub fn collect_type_names(stmts: &[Statement]) -> FxHashSet<Atom> {
+    let mut types = FxHashSet::default();
+    let mut values = FxHashSet::default();
+    for stmt in stmts {
+        let decl = match stmt {
+            Statement::Declaration(decl) => decl,
+            Statement::ModuleDeclaration(decl) => match &decl.kind {
+                ModuleDeclarationKind::ImportDeclaration(decl) => {
+                    let is_type = decl.import_kind == Some(ImportOrExportKind::Type);
+                    for specifier in &decl.specifiers {
+                        let (local, import_kind) = match specifier {
+                            ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                                (&specifier.local, specifier.import_kind)
+                            }
+                            ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                                (&specifier.local, ImportOrExportKind::Value)
+                            }
+                            ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                                (&specifier.local, ImportOrExportKind::Value)
+                            }
+                        };
+                        if is_type || import_kind.is_type() {
+                            types.insert(local.name.clone());
+                        } else {
+                            values.insert(local.name.clone());
+                        }
+                    }
+                    continue;
+                }
+                ModuleDeclarationKind::ExportNamedDeclaration(decl) => {
+                    let Some(decl) = &decl.declaration else { continue };
+                    decl
+                }
+                _ => continue,
+            },
+            _ => continue,
+        };
+        match decl {
+            Declaration::TSInterfaceDeclaration(decl) => {
+                types.insert(decl.id.name.clone());
+            }
+            Declaration::TSTypeAliasDeclaration(decl) => {
+                types.insert(decl.id.name.clone());
+            }
+            Declaration::FlowOpaqueType(decl) => {
+                types.insert(decl.id.name.clone());
+            }
+            Declaration::TSImportEqualsDeclaration(decl) => {
+                if decl.import_kind.is_type() {
+                    types.insert(decl.id.name.clone());
+                } else {
+                    values.insert(decl.id.name.clone());
+                }
+            }
+            Declaration::TSEnumDeclaration(decl) => {
+                values.insert(decl.id.name.clone());
+            }
+            Declaration::TSModuleDeclaration(decl) => {
+                values.insert(decl.id.name().clone());
+            }
+            decl => {
+                values.extend(decl.bound_names().into_iter().map(|ident| ident.name.clone()));
+            }
+        }
+    }
+    types.retain(|name| !values.contains(name));
+    types
+}
+
+/// Rewrites the references to `names` into members of `object`: `a` -> `N.a`.
+/// Names declared in nested scopes shadow `names` and are left alone.
+pub struct ReferenceRewriter<'a, 'r> {
+    ast: &'r AstBuilder<'a>,
+    names: &'r FxHashSet<Atom>,
+    object: &'r Atom,
+    scopes: std::vec::Vec<FxHashSet<Atom>>,
+}
+
+impl<'a, 'r> ReferenceRewriter<'a, 'r> {
+    pub fn new(ast: &'r AstBuilder<'a>, names: &'r FxHashSet<Atom>, object: &'r Atom) -> Self {
+        Self { ast, names, object, scopes: vec![] }
+    }
+
+    fn is_rewritten(&self, name: &Atom) -> bool {
+        self.names.contains(name) && !self.scopes.iter().any(|scope| scope.contains(name))
+    }
+
+    fn member(&self, ident: &IdentifierReference) -> oxc_allocator::Box<'a, MemberExpression<'a>> {
+        let object = identifier(self.ast, ident.span, self.object);
+        static_member(self.ast, ident.span, object, &ident.name)
+    }
+}
+
+impl<'a> VisitMut<'a> for ReferenceRewriter<'a, '_> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        if let Expression::Identifier(ident) = expr
+            && self.is_rewritten(&ident.name)
+        {
+            *expr = Expression::MemberExpression(self.member(ident));
+            return;
+        }
+        self.visit_expression_match(expr);
+    }
+
+    fn visit_simple_assignment_target(&mut self, target: &mut SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                if self.is_rewritten(&ident.name) {
+                    *target = SimpleAssignmentTarget::MemberAssignmentTarget(self.member(ident));
+                }
+            }
+            SimpleAssignmentTarget::MemberAssignmentTarget(expr) => {
+                self.visit_member_expression(expr);
+            }
+            SimpleAssignmentTarget::TSAsExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSNonNullExpression(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+            SimpleAssignmentTarget::TSTypeAssertion(expr) => {
+                self.visit_expression(&mut expr.expression);
+            }
+        }
+    }
+
+    // `({ a } = b)` -> `({ a: N.a } = b)`
+    fn visit_assignment_target_property(&mut self, property: &mut AssignmentTargetProperty<'a>) {
+        match property {
+            AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) => {
+                if let Some(init) = &mut ident.init {
+                    self.visit_expression(init);
+                }
+                if !self.is_rewritten(&ident.binding.name) {
+                    return;
+                }
+                let span = ident.span;
+                let name = PropertyKey::Identifier(self.ast.alloc(IdentifierName {
+                    span: ident.binding.span,
+                    name: ident.binding.name.clone(),
+                }));
+                let target = AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(self.member(&ident.binding)),
+                );
+                let binding = match ident.init.take() {
+                    Some(init) => AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(
+                        self.ast.alloc(AssignmentTargetWithDefault { span, binding: target, init }),
+                    ),
+                    None => AssignmentTargetMaybeDefault::AssignmentTarget(self.ast.alloc(target)),
+                };
+                *property = AssignmentTargetProperty::AssignmentTargetPropertyProperty(
+                    self.ast.alloc(AssignmentTargetPropertyProperty { span, name, binding }),
+                );
+            }
+            AssignmentTargetProperty::AssignmentTargetPropertyProperty(prop) => {
+                self.visit_property_key(&mut prop.name);
+                self.visit_assignment_target_maybe_default(&mut prop.binding);
+            }
+        }
+    }
+
+    // `{ a }` -> `{ a: N.a }`
+    fn visit_property(&mut self, prop: &mut Property<'a>) {
+        if prop.shorthand
+            && let PropertyValue::Expression(Expression::Identifier(ident)) = &prop.value
+            && self.is_rewritten(&ident.name)
+        {
+            prop.shorthand = false;
+        }
+        self.visit_property_key(&mut prop.key);
+        self.visit_property_value(&mut prop.value);
+    }
+
+    fn visit_function(&mut self, func: &mut Function<'a>) {
+        let mut scope = FxHashSet::default();
+        scope.extend(func.id.iter().map(|id| id.name.clone()));
+        scope.extend(func.params.bound_names().into_iter().map(|ident| ident.name.clone()));
+        if let Some(body) = &func.body {
+            collect_declared_names(&body.statements, &mut scope);
+        }
+        self.scopes.push(scope);
+        self.visit_formal_parameters(&mut func.params);
+        if let Some(body) = &mut func.body {
+            self.visit_function_body(body);
+        }
+        self.scopes.pop();
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &mut ArrowExpression<'a>) {
+        let mut scope = FxHashSet::default();
+        scope.extend(expr.params.bound_names().into_iter().map(|ident| ident.name.clone()));
+        collect_declared_names(&expr.body.statements, &mut scope);
+        self.scopes.push(scope);
+        self.visit_formal_parameters(&mut expr.params);
+        self.visit_function_body(&mut expr.body);
+        self.scopes.pop();
+    }
+
+    fn visit_block_statement(&mut self, stmt: &mut BlockStatement<'a>) {
+        let mut scope = FxHashSet::default();
+        collect_lexical_names(&stmt.body, &mut scope);
+        self.scopes.push(scope);
+        self.visit_statements(&mut stmt.body);
+        self.scopes.pop();
+    }
+
+    fn visit_catch_clause(&mut self, clause: &mut CatchClause<'a>) {
+        let mut scope = FxHashSet::default();
+        scope.extend(clause.param.bound_names().into_iter().map(|ident| ident.name.clone()));
+        collect_lexical_names(&clause.body.body, &mut scope);
+        self.scopes.push(scope);
+        if let Some(param) = &mut clause.param {
+            self.visit_pattern(param);
+        }
+        self.visit_statements(&mut clause.body.body);
+        self.scopes.pop();
+    }
+
+    fn visit_for_statement(&mut self, stmt: &mut ForStatement<'a>) {
+        let mut scope = FxHashSet::default();
+        if let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init
+            && decl.kind != VariableDeclarationKind::Var
+        {
+            scope.extend(decl.bound_names().into_iter().map(|ident| ident.name.clone()));
+        }
+        self.scopes.push(scope);
+        if let Some(init) = &mut stmt.init {
+            self.visit_for_statement_init(init);
+        }
+        if let Some(test) = &mut stmt.test {
+            self.visit_expression(test);
+        }
+        if let Some(update) = &mut stmt.update {
+            self.visit_expression(update);
+        }
+        self.visit_statement(&mut stmt.body);
+        self.scopes.pop();
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &mut ForInStatement<'a>) {
+        self.scopes.push(for_left_names(&stmt.left));
+        self.visit_for_statement_left(&mut stmt.left);
+        self.visit_expression(&mut stmt.right);
+        self.visit_statement(&mut stmt.body);
+        self.scopes.pop();
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &mut ForOfStatement<'a>) {
+        self.scopes.push(for_left_names(&stmt.left));
+        self.visit_for_statement_left(&mut stmt.left);
+        self.visit_expression(&mut stmt.right);
+        self.visit_statement(&mut stmt.body);
+        self.scopes.pop();
+    }
+}
+
+fn for_left_names(left: &ForStatementLeft) -> FxHashSet<Atom> {
+    match left {
+        ForStatementLeft::VariableDeclaration(decl)
+            if decl.kind != VariableDeclarationKind::Var =>
+        {
+            decl.bound_names().into_iter().map(|ident| ident.name.clone()).collect()
+        }
+        _ => FxHashSet::default(),
+    }
+}
+
+/// Names declared by the statements of a block
+fn collect_lexical_names(stmts: &Vec<Statement>, names: &mut FxHashSet<Atom>) {
+    for stmt in stmts {
+        let Statement::Declaration(decl) = stmt else { continue };
+        if !matches!(decl, Declaration::VariableDeclaration(decl)
+            if decl.kind == VariableDeclarationKind::Var)
+        {
+            names.extend(decl.bound_names().into_iter().map(|ident| ident.name.clone()));
+        }
+    }
+}
+
+/// Names declared by the statements of a function body, including the hoisted `var`s
+fn collect_declared_names(stmts: &Vec<Statement>, names: &mut FxHashSet<Atom>) {
+    collect_lexical_names(stmts, names);
+    for stmt in stmts {
+        collect_var_names(stmt, names);
+    }
+}
+
+fn collect_var_names(stmt: &Statement, names: &mut FxHashSet<Atom>) {
+    let var_names = |decl: &VariableDeclaration, names: &mut FxHashSet<Atom>| {
+        if decl.kind == VariableDeclarationKind::Var {
+            names.extend(decl.bound_names().into_iter().map(|ident| ident.name.clone()));
+        }
+    };
+    match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => var_names(decl, names),
+        Statement::BlockStatement(stmt) => {
+            stmt.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+        }
+        Statement::IfStatement(stmt) => {
+            collect_var_names(&stmt.consequent, names);
+            if let Some(alternate) = &stmt.alternate {
+                collect_var_names(alternate, names);
+            }
+        }
+        Statement::ForStatement(stmt) => {
+            if let Some(ForStatementInit::VariableDeclaration(decl)) = &stmt.init {
+                var_names(decl, names);
+            }
+            collect_var_names(&stmt.body, names);
+        }
+        Statement::ForInStatement(stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+                var_names(decl, names);
+            }
+            collect_var_names(&stmt.body, names);
+        }
+        Statement::ForOfStatement(stmt) => {
+            if let ForStatementLeft::VariableDeclaration(decl) = &stmt.left {
+                var_names(decl, names);
+            }
+            collect_var_names(&stmt.body, names);
+        }
+        Statement::WhileStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::DoWhileStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::WithStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::LabeledStatement(stmt) => collect_var_names(&stmt.body, names),
+        Statement::TryStatement(stmt) => {
+            stmt.block.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+            if let Some(handler) = &stmt.handler {
+                handler.body.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+            }
+            if let Some(finalizer) = &stmt.finalizer {
+                finalizer.body.iter().for_each(|stmt| collect_var_names(stmt, names));
+            }
+        }
+        Statement::SwitchStatement(stmt) => {
+            for case in &stmt.cases {
+                case.consequent.iter().for_each(|stmt| collect_var_names(stmt, names));
+            }
+        }
+        _ => {}
+    }
+}
@@ -28,6 +28,9 @@ pub struct Options {
     pub jsx: Option<bool>,
 
     pub eslintrc: Option<String>,
+
+    /// Return the AST in the estree (typescript-estree for TypeScript) format
+    pub estree: Option<bool>,
 }
 
 #[wasm_bindgen]
@@ -46,5 +49,11 @@ pub fn main(text: &str, js_options: &JsValue) -> JsValue {
 
     let driver = Driver::new();
 
-    driver.run(&path_str, text, source_type, &options.eslintrc.unwrap_or_default())
+    driver.run(
+        &path_str,
+        text,
+        source_type,
+        &options.eslintrc.unwrap_or_default(),
+        options.estree.unwrap_or_default(),
+    )
 }
@@ -1,5 +1,15 @@
 use std::{path::PathBuf, str::FromStr, time::Duration};
 
+/// Libraries used by the benchmark and the minifier size report,
+/// with their measurement time in seconds.
+pub const LIBRARIES: [(u64, &str); 5] = [
+    (10, "https://cdn.jsdelivr.net/npm/pdfjs-dist@2.12.313/build/pdf.js"),
+    (10, "https://cdn.jsdelivr.net/npm/lodash@4.17.0/lodash.js"),
+    (10, "https://cdn.jsdelivr.net/npm/d3@7.1.1/dist/d3.js"),
+    (20, "https://cdn.jsdelivr.net/npm/typescript@4.6.2/lib/typescript.js"),
+    (20, "https://cdn.jsdelivr.net/npm/babylonjs@4.2.1/babylon.max.js"),
+];
+
 pub struct Code {
     pub url: &'static str,
     pub file_name: String,
@@ -16,7 +16,7 @@ use std::{
 use criterion::{BenchmarkId, Criterion, Throughput};
 use oxc_allocator::Allocator;
 use oxc_ast::SourceType;
-use oxc_benchmark::Code;
+use oxc_benchmark::{Code, LIBRARIES};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use pico_args::Arguments;
@@ -24,13 +24,10 @@ use pico_args::Arguments;
 /// # Errors
 /// # Panics
 pub fn main() -> Result<(), String> {
-    let codes = vec![
-        Code::new(10, "https://cdn.jsdelivr.net/npm/pdfjs-dist@2.12.313/build/pdf.js")?,
-        Code::new(10, "https://cdn.jsdelivr.net/npm/lodash@4.17.0/lodash.js")?,
-        Code::new(10, "https://cdn.jsdelivr.net/npm/d3@7.1.1/dist/d3.js")?,
-        Code::new(20, "https://cdn.jsdelivr.net/npm/typescript@4.6.2/lib/typescript.js")?,
-        Code::new(20, "https://cdn.jsdelivr.net/npm/babylonjs@4.2.1/babylon.max.js")?,
-    ];
+    let codes = LIBRARIES
+        .iter()
+        .map(|(measurement_seconds, url)| Code::new(*measurement_seconds, url))
+        .collect::<Result<Vec<_>, _>>()?;
 
     let mut args = Arguments::from_env();
 
@@ -97,7 +94,8 @@ fn bench_semantic(criterion: &mut Criterion, codes: &[Code]) {
                 let trivias = Rc::new(ret.trivias);
                 b.iter(|| {
                     let _semantic = SemanticBuilder::new(source_type)
-                        .build(black_box(program), trivias.clone());
+                        .build(black_box(program), trivias.clone())
+                        .semantic;
                 });
             },
         );
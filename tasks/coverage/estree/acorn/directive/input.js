@@ -0,0 +1,3 @@
+"use strict";
+foo:
+for (;;) break foo;
@@ -0,0 +1 @@
+import a from "a" with { type: "json" };
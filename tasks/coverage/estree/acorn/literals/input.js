@@ -0,0 +1 @@
+a = [1, "s", null, true, /x/gi];
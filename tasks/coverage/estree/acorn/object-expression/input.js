@@ -0,0 +1 @@
+({ b: 1, c() {}, ...d, get e() { return 1; } });
@@ -0,0 +1,2 @@
+var s = "ü😀";
+s;
@@ -0,0 +1 @@
+import a from "a" assert { type: "json" };
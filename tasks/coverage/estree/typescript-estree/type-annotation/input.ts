@@ -0,0 +1 @@
+let a: string = b as any;
@@ -0,0 +1,121 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::SourceType;
+use oxc_parser::Parser;
+use serde_json::Value;
+
+use crate::project_root;
+use crate::suite::{Case, Suite, TestResult};
+
+/// Each fixture is a directory with an `input.{js,mjs,ts}` and the `output.json` produced by
+/// acorn (`acorn/`) or typescript-estree (`typescript-estree/`).
+const FIXTURES_PATH: &str = "tasks/coverage/estree";
+
+pub struct ESTreeSuite<T: Case> {
+    test_root: PathBuf,
+    test_cases: Vec<T>,
+}
+
+impl<T: Case> Default for ESTreeSuite<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Case> ESTreeSuite<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { test_root: project_root().join(FIXTURES_PATH), test_cases: vec![] }
+    }
+}
+
+impl<T: Case> Suite<T> for ESTreeSuite<T> {
+    fn get_test_root(&self) -> &Path {
+        &self.test_root
+    }
+
+    fn skip_test_path(&self, path: &Path) -> bool {
+        path.file_stem().map_or(true, |stem| stem != "input")
+    }
+
+    fn save_test_cases(&mut self, cases: Vec<T>) {
+        self.test_cases = cases;
+    }
+
+    fn get_test_cases(&self) -> &Vec<T> {
+        &self.test_cases
+    }
+}
+
+pub struct ESTreeCase {
+    path: PathBuf,
+    code: String,
+    result: TestResult,
+}
+
+impl Case for ESTreeCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { path, code, result: TestResult::ToBeRun }
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn test_result(&self) -> &TestResult {
+        &self.result
+    }
+
+    fn run(&mut self) {
+        let mut source_type = SourceType::from_path(&self.path).unwrap();
+        // acorn fixtures are parsed with `sourceType: "script"`, except for `.mjs` modules
+        if source_type.is_javascript() && self.path.extension().map_or(true, |ext| ext != "mjs") {
+            source_type.set_script();
+        }
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &self.code, source_type).parse();
+        if !ret.errors.is_empty() {
+            let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+            self.result = TestResult::ParseError(errors.join("\n"));
+            return;
+        }
+
+        let output_path =
+            project_root().join(FIXTURES_PATH).join(&self.path).with_file_name("output.json");
+        let expected: Value =
+            serde_json::from_str(&fs::read_to_string(output_path).unwrap()).unwrap();
+        let actual = ret.program.to_estree_value(&self.code).unwrap();
+
+        self.result = if is_subset(&expected, &actual) {
+            TestResult::Passed
+        } else {
+            TestResult::Mismatch(
+                serde_json::to_string_pretty(&actual).unwrap(),
+                serde_json::to_string_pretty(&expected).unwrap(),
+            )
+        };
+    }
+}
+
+/// Every property in the reference output must be present in ours,
+/// but extra properties (e.g. from newer spec versions) are allowed.
+fn is_subset(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => expected
+            .iter()
+            .all(|(key, value)| actual.get(key).map_or(false, |actual| is_subset(value, actual))),
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected.iter().zip(actual).all(|(expected, actual)| is_subset(expected, actual))
+        }
+        _ => expected == actual,
+    }
+}
@@ -1,6 +1,6 @@
 use oxc_coverage::{
-    AppArgs, BabelCase, BabelSuite, PrinterTest262Case, Suite, Test262Case, Test262Suite,
-    TypeScriptCase, TypeScriptSuite,
+    AppArgs, BabelCase, BabelSuite, ESTreeCase, ESTreeSuite, PrinterTest262Case,
+    PrinterTypeScriptCase, Suite, Test262Case, Test262Suite, TypeScriptCase, TypeScriptSuite,
 };
 use pico_args::Arguments;
 
@@ -30,6 +30,11 @@ fn main() {
 
     let run_printer = || {
         Test262Suite::<PrinterTest262Case>::new().run("Printer", &args);
+        TypeScriptSuite::<PrinterTypeScriptCase>::new().run("PrinterTypeScript", &args);
+    };
+
+    let run_estree = || {
+        ESTreeSuite::<ESTreeCase>::new().run("ESTree", &args);
     };
 
     match task {
@@ -37,11 +42,13 @@ fn main() {
         "babel" => run_babel(),
         "ts" | "typescript" => run_typescript(),
         "printer" => run_printer(),
+        "estree" => run_estree(),
         _ => {
             run_test262();
             run_babel();
             run_typescript();
             run_printer();
+            run_estree();
         }
     };
 }
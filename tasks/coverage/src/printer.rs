@@ -7,6 +7,7 @@ use oxc_printer::{Printer, PrinterOptions};
 
 use crate::suite::{Case, TestResult};
 use crate::test262::{Test262Case, TestFlag};
+use crate::typescript::TypeScriptCase;
 
 pub struct PrinterTest262Case {
     base: Test262Case,
@@ -47,6 +48,14 @@ impl Case for PrinterTest262Case {
         let printer_options =
             PrinterOptions { minify_whitespace: true, ..PrinterOptions::default() };
         let result = self.get_result(printer_options);
+
+        if !matches!(result, TestResult::Passed) {
+            self.base.set_result(result);
+            return;
+        }
+
+        // Test comment preservation
+        let result = self.get_comments_result();
         self.base.set_result(result);
     }
 }
@@ -55,13 +64,7 @@ impl PrinterTest262Case {
     fn get_result(&self, options: PrinterOptions) -> TestResult {
         let allocator = Allocator::default();
         let source_text = self.base.code().to_string();
-        let source_type = {
-            let mut builder = SourceType::builder();
-            if self.base.meta().flags.contains(&TestFlag::Module) {
-                builder = builder.module();
-            }
-            builder.build()
-        };
+        let source_type = self.source_type();
         let program1 = Parser::new(&allocator, &source_text, source_type).parse().program;
         let source_text1 = Printer::new(source_text.len(), options).build(&program1);
         let program2 = Parser::new(&allocator, &source_text1, source_type).parse().program;
@@ -72,4 +75,100 @@ impl PrinterTest262Case {
             TestResult::Mismatch(source_text1.to_string(), source_text2)
         }
     }
+
+    /// All comments are printed, and printing them again is idempotent.
+    fn get_comments_result(&self) -> TestResult {
+        let allocator = Allocator::default();
+        let source_text = self.base.code();
+        let source_type = self.source_type();
+        let options = PrinterOptions::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let source_text1 = Printer::new(source_text.len(), options)
+            .with_comments(source_text, &ret.trivias)
+            .build(&ret.program);
+        let ret1 = Parser::new(&allocator, &source_text1, source_type).parse();
+        if ret1.trivias.comments().count() != ret.trivias.comments().count() {
+            return TestResult::Mismatch(source_text1.clone(), source_text.to_string());
+        }
+        let source_text2 = Printer::new(source_text1.len(), options)
+            .with_comments(&source_text1, &ret1.trivias)
+            .build(&ret1.program);
+        if source_text1 == source_text2 {
+            TestResult::Passed
+        } else {
+            TestResult::Mismatch(source_text1.clone(), source_text2)
+        }
+    }
+
+    fn source_type(&self) -> SourceType {
+        let mut builder = SourceType::builder();
+        if self.base.meta().flags.contains(&TestFlag::Module) {
+            builder = builder.module();
+        }
+        builder.build()
+    }
+}
+
+pub struct PrinterTypeScriptCase {
+    base: TypeScriptCase,
+}
+
+impl Case for PrinterTypeScriptCase {
+    fn new(path: PathBuf, code: String) -> Self {
+        Self { base: TypeScriptCase::new(path, code) }
+    }
+
+    fn code(&self) -> &str {
+        self.base.code()
+    }
+
+    fn path(&self) -> &Path {
+        self.base.path()
+    }
+
+    fn test_result(&self) -> &TestResult {
+        self.base.test_result()
+    }
+
+    fn skip_test_case(&self) -> bool {
+        self.base.skip_test_case() || self.base.should_fail()
+    }
+
+    fn run(&mut self) {
+        for minify_whitespace in [false, true] {
+            let options = PrinterOptions {
+                minify_whitespace,
+                enable_typescript: true,
+                ..PrinterOptions::default()
+            };
+            let result = self.get_result(options);
+            if !matches!(result, TestResult::Passed) {
+                self.base.set_result(result);
+                return;
+            }
+        }
+        self.base.set_result(TestResult::Passed);
+    }
+}
+
+impl PrinterTypeScriptCase {
+    /// The printed TypeScript parses without errors, and printing it again is idempotent.
+    fn get_result(&self, options: PrinterOptions) -> TestResult {
+        let allocator = Allocator::default();
+        let source_text = self.base.code();
+        let source_type = self.base.source_type();
+        let program1 = Parser::new(&allocator, source_text, source_type).parse().program;
+        let source_text1 = Printer::new(source_text.len(), options).build(&program1);
+        let ret = Parser::new(&allocator, &source_text1, source_type).parse();
+        if !ret.errors.is_empty() {
+            let errors = ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+            return TestResult::ParseError(errors.join("\n"));
+        }
+        let source_text2 = Printer::new(source_text1.len(), options).build(&ret.program);
+        if source_text1 == source_text2 {
+            TestResult::Passed
+        } else {
+            TestResult::Mismatch(source_text1.clone(), source_text2)
+        }
+    }
 }
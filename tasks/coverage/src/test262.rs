@@ -92,9 +92,7 @@ impl<T: Case> Suite<T> for Test262Suite<T> {
     fn skip_test_path(&self, path: &Path) -> bool {
         let path = path.to_string_lossy();
         // ignore fixtures
-        path.contains("_FIXTURE") ||
-        // ignore regexp as we don't have a regexp parser for now
-        path.contains("literals/regexp")
+        path.contains("_FIXTURE")
     }
 
     fn save_test_cases(&mut self, cases: Vec<T>) {
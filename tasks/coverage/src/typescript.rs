@@ -98,9 +98,22 @@ impl Case for TypeScriptCase {
     }
 
     fn run(&mut self) {
+        self.result = self.execute(self.source_type());
+    }
+}
+
+impl TypeScriptCase {
+    pub fn set_result(&mut self, result: TestResult) {
+        self.result = result;
+    }
+
+    /// # Panics
+    /// Invalid file extension
+    #[must_use]
+    pub fn source_type(&self) -> SourceType {
         let mut source_type = SourceType::from_path(self.path()).unwrap();
         self.meta.derive_options(&mut source_type);
-        self.result = self.execute(source_type);
+        source_type
     }
 }
 
@@ -0,0 +1,43 @@
+use oxc_ast::SourceType;
+use oxc_benchmark::{Code, LIBRARIES};
+use oxc_cli::gzip_size;
+use oxc_minifier::{Minifier, MinifierOptions};
+
+/// # Errors
+/// # Panics
+pub fn main() -> Result<(), String> {
+    let mut rows = vec![];
+    for (_, url) in LIBRARIES {
+        let (file_name, source_text) = Code::get_source_text(url)?;
+        let source_type = SourceType::from_path(&file_name).unwrap();
+        let whitespace = minify(&source_text, source_type, MinifierOptions::default())?;
+        let options = MinifierOptions { compress: true, ..MinifierOptions::default() };
+        let compressed = minify(&source_text, source_type, options)?;
+        rows.push((
+            file_name,
+            source_text.len(),
+            whitespace.len(),
+            compressed.len(),
+            gzip_size(&compressed),
+        ));
+    }
+
+    println!();
+    println!("| File | Original | Whitespace | Compress | Gzip |");
+    println!("| ---- | -------- | ---------- | -------- | ---- |");
+    for (file_name, original, whitespace, compressed, gzip) in rows {
+        println!("| {file_name} | {original} | {whitespace} | {compressed} | {gzip} |");
+    }
+
+    Ok(())
+}
+
+fn minify(
+    source_text: &str,
+    source_type: SourceType,
+    options: MinifierOptions,
+) -> Result<String, String> {
+    Minifier::new(source_text, source_type, options)
+        .build()
+        .map_err(|errors| format!("{} syntax errors", errors.len()))
+}
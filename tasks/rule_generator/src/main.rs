@@ -92,7 +92,7 @@ fn main() {
 
     let program = allocator.alloc(ret.program);
     let trivias = Rc::new(ret.trivias);
-    let semantic = SemanticBuilder::new(source_type).build(program, trivias);
+    let semantic = SemanticBuilder::new(source_type).build(program, trivias).semantic;
 
     let tests_object = semantic.nodes().iter().find_map(|node| match node.get().kind() {
         AstKind::ExpressionStatement(stmt) => match &stmt.expression {